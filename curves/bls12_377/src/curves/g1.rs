@@ -8,11 +8,15 @@ use ark_ec::{
             Affine as TEAffine, MontCurveConfig, Projective as TEProjective, TECurveConfig,
         },
     },
-    scalar_mul::glv::GLVConfig,
+    scalar_mul::{
+        self,
+        glv::{self, GLVConfig},
+    },
     CurveConfig,
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
-use ark_std::{ops::Neg, One};
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, ops::Neg, vec::Vec};
 
 use super::g1_swu_iso::{SwuIsoConfig, ISOGENY_MAP_TO_G1};
 use crate::{Fq, Fr};
@@ -45,6 +49,9 @@ impl SWCurveConfig for Config {
     /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
     const GENERATOR: G1SWAffine = G1SWAffine::new_unchecked(G1_GENERATOR_X, G1_GENERATOR_Y);
 
+    /// EFFECTIVE_COFACTOR = x - 1 = 9586122913090633728
+    const EFFECTIVE_COFACTOR: &'static [u64] = &[0x8508c00000000000];
+
     #[inline(always)]
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
@@ -56,13 +63,37 @@ impl SWCurveConfig for Config {
         GLVConfig::glv_mul_projective(*p, s)
     }
 
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Fr]) -> Result<G1Projective, usize> {
+        glv::msm(bases, scalars)
+    }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1SWAffine) -> bool {
+        bls12::g1::is_in_correct_subgroup_assuming_on_curve::<crate::Config>(p)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G1SWAffine) -> G1SWAffine {
         // Using the effective cofactor.
         //
         // It is enough to multiply by (x - 1), instead of (x - 1)^2 / 3
-        let h_eff = x_minus_one().into_bigint();
-        <Config as SWCurveConfig>::mul_affine(p, h_eff.as_ref()).into()
+        <Config as SWCurveConfig>::mul_affine(p, Self::EFFECTIVE_COFACTOR).into()
+    }
+
+    #[inline]
+    fn clear_cofactor_projective(p: &G1Projective) -> G1Projective {
+        // As in `clear_cofactor`. This avoids the GLV multiplication of
+        // `mul_projective`, which is only correct in the prime-order subgroup.
+        scalar_mul::sw_double_and_add_projective(p, Self::EFFECTIVE_COFACTOR)
+    }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<G1SWAffine>, SerializationError> {
+        G1SWAffine::deserialize_default_compressed_batch(reader, count, validate)
     }
 }
 
@@ -94,11 +125,6 @@ impl GLVConfig for Config {
     }
 }
 
-fn x_minus_one() -> Fr {
-    const X: Fr = Fr::from_sign_and_limbs(!crate::Config::X_IS_NEGATIVE, crate::Config::X);
-    X - Fr::one()
-}
-
 pub type G1SWAffine = SWAffine<Config>;
 pub type G1TEAffine = TEAffine<Config>;
 pub type G1TEProjective = TEProjective<Config>;
@@ -167,6 +193,14 @@ impl TECurveConfig for Config {
     fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
         elem.neg()
     }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<G1TEAffine>, SerializationError> {
+        G1TEAffine::deserialize_default_compressed_batch(reader, count, validate)
+    }
 }
 
 // BLS12-377::G1 also has a Montgomery form.
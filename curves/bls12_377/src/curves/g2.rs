@@ -1,14 +1,14 @@
 use ark_ec::{
     bls12,
-    bls12::Bls12Config,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective, SWCurveConfig},
-    AffineRepr, CurveConfig, CurveGroup, PrimeGroup,
+    CurveConfig,
 };
 
-use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
-use ark_std::ops::Neg;
+use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField, Zero};
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
 
 use crate::*;
 
@@ -62,39 +62,48 @@ impl SWCurveConfig for Config {
     /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
     const GENERATOR: G2Affine = G2Affine::new_unchecked(G2_GENERATOR_X, G2_GENERATOR_Y);
 
+    /// EFFECTIVE_COFACTOR = h_eff = (3 * x^2 - 3) * COFACTOR, which
+    /// [`Self::clear_cofactor`] computes with the endomorphism ψ
+    /// = 2184281851404362281745678896208201217529168937398889823122388972044920455466061193066642594716782535371685695462977913482353329054629394593897466405313745845350270778934587237043056714711040
+    #[rustfmt::skip]
+    const EFFECTIVE_COFACTOR: &'static [u64] = &[
+        0x1e34800000000000,
+        0xcf664765b0000003,
+        0x8e8e73ad8a538800,
+        0x78ba279637388559,
+        0xb85860aaaad29276,
+        0xf7ee7c4b03103b45,
+        0x8f6ade35a5c7d769,
+        0xa951764c46f4edd2,
+        0x53648d3d9502abfb,
+        0x001f60243677e306,
+    ];
+
     #[inline(always)]
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
 
+    fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<crate::Config>(point)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G2Affine) -> G2Affine {
-        // Based on Section 4.1 of https://eprint.iacr.org/2017/419.pdf
-        // [h(ψ)]P = [x^2 − x − 1]P + [x − 1]ψ(P) + (ψ^2)(2P)
-
-        let x: &'static [u64] = crate::Config::X;
-        let p_projective = p.into_group();
-
-        // [x]P
-        let x_p = Config::mul_affine(p, x);
-        // ψ(P)
-        let psi_p = p_power_endomorphism(p);
-        // (ψ^2)(2P)
-        let mut psi2_p2 = double_p_power_endomorphism(&p_projective.double());
-
-        // tmp = [x]P + ψ(P)
-        let mut tmp = x_p;
-        tmp += &psi_p;
-
-        // tmp2 = [x^2]P + [x]ψ(P)
-        let mut tmp2: Projective<Config> = tmp;
-        tmp2 = tmp2.mul_bigint(x);
-
-        // add up all the terms
-        psi2_p2 += tmp2;
-        psi2_p2 -= x_p;
-        psi2_p2 += &-psi_p;
-        (psi2_p2 - p_projective).into_affine()
+        bls12::g2::clear_cofactor::<crate::Config>(p)
+    }
+
+    #[inline]
+    fn clear_cofactor_projective(p: &G2Projective) -> G2Projective {
+        bls12::g2::clear_cofactor_projective::<crate::Config>(p)
+    }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<G2Affine>, SerializationError> {
+        G2Affine::deserialize_default_compressed_batch(reader, count, validate)
     }
 }
 
@@ -148,7 +157,7 @@ pub const G2_GENERATOR_Y_C0: Fq = MontFp!("6316029476829207320938136194393519890
 pub const G2_GENERATOR_Y_C1: Fq = MontFp!("149157405641012693445398062341192467754805999074082136895788947234480009303640899064710353187729182149407503257491");
 
 // PSI_X = u^((p-1)/3)
-const P_POWER_ENDOMORPHISM_COEFF_0 : Fq2 = Fq2::new(
+pub(crate) const P_POWER_ENDOMORPHISM_COEFF_0 : Fq2 = Fq2::new(
     MontFp!(
         "80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410946"
     ),
@@ -156,56 +165,12 @@ const P_POWER_ENDOMORPHISM_COEFF_0 : Fq2 = Fq2::new(
 );
 
 // PSI_Y = u^((p-1)/2)
-const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
+pub(crate) const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
     MontFp!(
         "216465761340224619389371505802605247630151569547285782856803747159100223055385581585702401816380679166954762214499"),
         Fq::ZERO,
     );
 
-// PSI_2_X = u^((p^2 - 1)/3)
-const DOUBLE_P_POWER_ENDOMORPHISM_COEFF_0: Fq2 = Fq2::new(
-        MontFp!("80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945"),
-        Fq::ZERO
-    );
-
-/// psi(x,y) is the untwist-Frobenius-twist endomorhism on E'(Fq2)
-fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
-    // The p-power endomorphism for G2 is defined as follows:
-    // 1. Note that G2 is defined on curve E': y^2 = x^3 + 1/u.
-    //    To map a point (x, y) in E' to (s, t) in E,
-    //    one set s = x * (u ^ (1/3)), t = y * (u ^ (1/2)),
-    //    because E: y^2 = x^3 + 1.
-    // 2. Apply the Frobenius endomorphism (s, t) => (s', t'),
-    //    another point on curve E, where s' = s^p, t' = t^p.
-    // 3. Map the point from E back to E'; that is,
-    //    one set x' = s' / ((u) ^ (1/3)), y' = t' / ((u) ^ (1/2)).
-    //
-    // To sum up, it maps
-    // (x,y) -> (x^p * (u ^ ((p-1)/3)), y^p * (u ^ ((p-1)/2)))
-    // as implemented in the code as follows.
-
-    let mut res = *p;
-    res.x.frobenius_map_in_place(1);
-    res.y.frobenius_map_in_place(1);
-
-    res.x *= P_POWER_ENDOMORPHISM_COEFF_0;
-    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
-
-    res
-}
-
-/// For a p-power endomorphism psi(P), compute psi(psi(P))
-fn double_p_power_endomorphism(p: &Projective<Config>) -> Projective<Config> {
-    // p_power_endomorphism(&p_power_endomorphism(&p.into_affine())).into()
-    let mut res = *p;
-
-    res.x *= DOUBLE_P_POWER_ENDOMORPHISM_COEFF_0;
-    // u^((p^2 - 1)/2) == -1
-    res.y = res.y.neg();
-
-    res
-}
-
 impl WBConfig for Config {
     type IsogenousCurve = SwuIsoConfig;
 
@@ -216,6 +181,7 @@ impl WBConfig for Config {
 mod test {
 
     use super::*;
+    use ark_ec::AffineRepr;
     use ark_std::{rand::Rng, UniformRand};
 
     fn sample_unchecked() -> Affine<g2::Config> {
@@ -235,27 +201,16 @@ mod test {
     #[test]
     fn test_psi_2() {
         let p = sample_unchecked();
-        let psi_p = p_power_endomorphism(&p);
-        let psi2_p_composed = p_power_endomorphism(&psi_p);
-        let psi2_p_optimised = double_p_power_endomorphism(&p.into());
+        let psi_p = bls12::g2::mul_by_char::<crate::Config>(p);
+        let psi2_p_composed = bls12::g2::mul_by_char::<crate::Config>(psi_p);
+        let psi2_p_optimised = bls12::g2::double_mul_by_char::<crate::Config>(p.into());
 
         assert_eq!(psi2_p_composed, psi2_p_optimised);
     }
 
     #[test]
     fn test_cofactor_clearing() {
-        let h_eff = &[
-            0x1e34800000000000,
-            0xcf664765b0000003,
-            0x8e8e73ad8a538800,
-            0x78ba279637388559,
-            0xb85860aaaad29276,
-            0xf7ee7c4b03103b45,
-            0x8f6ade35a5c7d769,
-            0xa951764c46f4edd2,
-            0x53648d3d9502abfb,
-            0x1f60243677e306,
-        ];
+        let h_eff = g2::Config::EFFECTIVE_COFACTOR;
         const SAMPLES: usize = 10;
         for _ in 0..SAMPLES {
             let p: Affine<g2::Config> = sample_unchecked();
@@ -21,6 +21,8 @@ impl Bls12Config for Config {
     /// `x` is positive.
     const X_IS_NEGATIVE: bool = false;
     const TWIST_TYPE: TwistType = TwistType::D;
+    const TWIST_MUL_BY_Q_X: Fq2 = g2::P_POWER_ENDOMORPHISM_COEFF_0;
+    const TWIST_MUL_BY_Q_Y: Fq2 = g2::P_POWER_ENDOMORPHISM_COEFF_1;
     type Fp = Fq;
     type Fp2Config = Fq2Config;
     type Fp6Config = Fq6Config;
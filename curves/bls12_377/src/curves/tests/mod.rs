@@ -10,6 +10,109 @@ test_group!(g2_glv; G2Projective; glv);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12377G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12377G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
 
+#[test]
+fn test_g1_subgroup_check_via_endomorphism() {
+    use crate::{Fq, Fr, G1Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{PrimeField, UniformRand};
+    use ark_std::{rand::Rng, test_rng, Zero};
+
+    let mut rng = test_rng();
+    let p = G1Projective::rand(&mut rng).into_affine();
+    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+
+    loop {
+        let x = Fq::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G1Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::MODULUS).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(p
+                    .clear_cofactor()
+                    .is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_subgroup_check_via_endomorphism() {
+    use crate::{Fq2, Fr, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{PrimeField, UniformRand};
+    use ark_std::{rand::Rng, test_rng, Zero};
+
+    let mut rng = test_rng();
+    let p = G2Projective::rand(&mut rng).into_affine();
+    assert!(p.is_in_correct_subgroup_assuming_on_curve());
+
+    loop {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::MODULUS).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                assert!(p
+                    .clear_cofactor()
+                    .is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_batch_subgroup_check() {
+    use crate::{Fq2, Fr, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{PrimeField, UniformRand};
+    use ark_std::{test_rng, vec::Vec};
+
+    // The G2 cofactor has no factors below 2^16, so this takes the batched
+    // path rather than checking the points one by one.
+    let mut rng = test_rng();
+    let points = (0..50)
+        .map(|_| G2Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    assert!(G2Affine::batch_is_in_correct_subgroup(&points, &mut rng));
+
+    let not_in_subgroup = loop {
+        let x = Fq2::rand(&mut rng);
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, false) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                break p;
+            }
+        }
+    };
+    // Keep only the component outside the prime-order subgroup.
+    let torsion = not_in_subgroup.mul_bigint(Fr::MODULUS).into_affine();
+    for bad in [not_in_subgroup, torsion] {
+        let mut batch = points.clone();
+        batch[17] = bad;
+        assert!(!G2Affine::batch_is_in_correct_subgroup(&batch, &mut rng));
+    }
+}
+
+#[test]
+fn test_twist_mul_by_q_matches_frobenius_coefficients() {
+    use crate::{Config, Fq12Config, Fq6Config};
+    use ark_ec::bls12::Bls12Config;
+    use ark_ff::{Field, Fp12Config, Fp6Config};
+
+    // For a D-type twist, these are `ξ^((p - 1) / 3)` and `ξ^((p - 1) / 2)`.
+    assert_eq!(
+        Config::TWIST_MUL_BY_Q_X,
+        Fq6Config::FROBENIUS_COEFF_FP6_C1[1]
+    );
+    assert_eq!(
+        Config::TWIST_MUL_BY_Q_Y,
+        Fq12Config::FROBENIUS_COEFF_FP12_C1[1].pow([3])
+    );
+}
+
 #[cfg(test)]
 mod test {
     use ark_ec::{
@@ -3,18 +3,22 @@ use ark_ec::{
     bls12::Bls12Config,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
-    scalar_mul::glv::GLVConfig,
+    scalar_mul::{
+        self,
+        glv::{self, GLVConfig},
+    },
     short_weierstrass::{Affine, SWCurveConfig},
-    AffineRepr, PrimeGroup,
+    AffineRepr,
 };
 use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField, Zero};
-use ark_serialize::{Compress, SerializationError};
-use ark_std::{ops::Neg, One};
+use ark_serialize::{Compress, SerializationError, Valid};
+use ark_std::vec::Vec;
 
 use super::g1_swu_iso;
 use crate::{
     util::{
-        read_g1_compressed, read_g1_uncompressed, serialize_fq, EncodingFlags, G1_SERIALIZED_SIZE,
+        read_g1_compressed, read_g1_compressed_batch, read_g1_uncompressed, serialize_fq,
+        EncodingFlags, G1_SERIALIZED_SIZE,
     },
     Fq, Fr,
 };
@@ -48,6 +52,9 @@ impl SWCurveConfig for Config {
     /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
     const GENERATOR: G1Affine = G1Affine::new_unchecked(G1_GENERATOR_X, G1_GENERATOR_Y);
 
+    /// EFFECTIVE_COFACTOR = 1 - x = 15132376222941642753
+    const EFFECTIVE_COFACTOR: &'static [u64] = &[0xd201000000010001];
+
     #[inline(always)]
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
@@ -60,22 +67,13 @@ impl SWCurveConfig for Config {
     }
 
     #[inline]
-    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
-        // Algorithm from Section 6 of https://eprint.iacr.org/2021/1130.
-        //
-        // Check that endomorphism_p(P) == -[X^2]P
-
-        // An early-out optimization described in Section 6.
-        // If uP == P but P != point of infinity, then the point is not in the right
-        // subgroup.
-        let x_times_p = p.mul_bigint(crate::Config::X);
-        if x_times_p.eq(p) && !p.infinity {
-            return false;
-        }
+    fn msm(bases: &[G1Affine], scalars: &[Fr]) -> Result<G1Projective, usize> {
+        glv::msm(bases, scalars)
+    }
 
-        let minus_x_squared_times_p = x_times_p.mul_bigint(crate::Config::X).neg();
-        let endomorphism_p = endomorphism(p);
-        minus_x_squared_times_p.eq(&endomorphism_p)
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        bls12::g1::is_in_correct_subgroup_assuming_on_curve::<crate::Config>(p)
     }
 
     #[inline]
@@ -84,8 +82,14 @@ impl SWCurveConfig for Config {
         // Section 5 of https://eprint.iacr.org/2019/403.pdf.
         //
         // It is enough to multiply by (1 - x), instead of (x - 1)^2 / 3
-        let h_eff = one_minus_x().into_bigint();
-        Config::mul_affine(&p, h_eff.as_ref()).into()
+        Config::mul_affine(&p, Self::EFFECTIVE_COFACTOR).into()
+    }
+
+    #[inline]
+    fn clear_cofactor_projective(p: &G1Projective) -> G1Projective {
+        // As in `clear_cofactor`. This avoids the GLV multiplication of
+        // `mul_projective`, which is only correct in the prime-order subgroup.
+        scalar_mul::sw_double_and_add_projective(p, Self::EFFECTIVE_COFACTOR)
     }
 
     fn deserialize_with_mode<R: ark_serialize::Read>(
@@ -99,13 +103,28 @@ impl SWCurveConfig for Config {
             read_g1_uncompressed(&mut reader)?
         };
 
-        if validate == ark_serialize::Validate::Yes && !p.is_in_correct_subgroup_assuming_on_curve()
-        {
-            return Err(SerializationError::InvalidData);
+        // Compressed points are on the curve by construction, but uncompressed
+        // ones must be checked before the subgroup check, which assumes it.
+        if validate == ark_serialize::Validate::Yes {
+            p.check()?;
         }
         Ok(p)
     }
 
+    /// Reads the Zcash encodings of all points first, and then computes their
+    /// y-coordinates at once with a batched square root.
+    fn deserialize_compressed_batch<R: ark_serialize::Read>(
+        reader: R,
+        count: usize,
+        validate: ark_serialize::Validate,
+    ) -> Result<Vec<Affine<Self>>, ark_serialize::SerializationError> {
+        let points = read_g1_compressed_batch(reader, count)?;
+        if validate == ark_serialize::Validate::Yes {
+            G1Affine::batch_check(points.iter())?;
+        }
+        Ok(points)
+    }
+
     fn serialize_with_mode<W: ark_serialize::Write>(
         item: &Affine<Self>,
         mut writer: W,
@@ -177,11 +196,6 @@ impl GLVConfig for Config {
     }
 }
 
-fn one_minus_x() -> Fr {
-    const X: Fr = Fr::from_sign_and_limbs(!crate::Config::X_IS_NEGATIVE, crate::Config::X);
-    Fr::one() - X
-}
-
 // Parameters from the [IETF draft v16, section E.2](https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-11-isogeny-map-for-bls12-381).
 impl WBConfig for Config {
     type IsogenousCurve = g1_swu_iso::SwuIsoConfig;
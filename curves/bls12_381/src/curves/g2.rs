@@ -1,23 +1,21 @@
-use ark_std::ops::Neg;
-
 use ark_ec::{
     bls12,
-    bls12::Bls12Config,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective, SWCurveConfig},
-    AffineRepr, CurveGroup, PrimeGroup,
+    AffineRepr,
 };
-use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
-use ark_serialize::{Compress, SerializationError};
+use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField, Zero};
+use ark_serialize::{Compress, SerializationError, Valid};
+use ark_std::vec::Vec;
 
 use super::{
     g2_swu_iso,
     util::{serialize_fq, EncodingFlags, G2_SERIALIZED_SIZE},
 };
 use crate::{
-    util::{read_g2_compressed, read_g2_uncompressed},
+    util::{read_g2_compressed, read_g2_compressed_batch, read_g2_uncompressed},
     *,
 };
 
@@ -62,58 +60,41 @@ impl SWCurveConfig for Config {
     /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
     const GENERATOR: G2Affine = G2Affine::new_unchecked(G2_GENERATOR_X, G2_GENERATOR_Y);
 
+    /// EFFECTIVE_COFACTOR = h_eff = (3 * x^2 - 3) * COFACTOR, from RFC 9380,
+    /// Section 8.8.2, which [`Self::clear_cofactor`] computes with the
+    /// endomorphism ψ
+    /// = 209869847837335686905080341498658477663839067235703451875306851526599783796572738804459333109033834234622528588876978987822447936461846631641690358257586228683615991308971558879306463436166481
+    #[rustfmt::skip]
+    const EFFECTIVE_COFACTOR: &'static [u64] = &[
+        0xe8020005aaa95551,
+        0x59894c0adebbf6b4,
+        0xe954cbc06689f6a3,
+        0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0,
+        0x329c2f178731db95,
+        0x9986ff031508ffe1,
+        0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528,
+        0x0bc69f08f2ee75b3,
+    ];
+
     #[inline(always)]
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
 
     fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
-        // Algorithm from Section 4 of https://eprint.iacr.org/2021/1130.
-        //
-        // Checks that [p]P = [X]P
-
-        let mut x_times_point = point.mul_bigint(crate::Config::X);
-        if crate::Config::X_IS_NEGATIVE {
-            x_times_point = -x_times_point;
-        }
-
-        let p_times_point = p_power_endomorphism(point);
-
-        x_times_point.eq(&p_times_point)
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<crate::Config>(point)
     }
 
     #[inline]
     fn clear_cofactor(p: &G2Affine) -> G2Affine {
-        // Based on Section 4.1 of https://eprint.iacr.org/2017/419.pdf
-        // [h(ψ)]P = [x^2 − x − 1]P + [x − 1]ψ(P) + (ψ^2)(2P)
-
-        // x = -15132376222941642752
-        // When multiplying, use -c1 instead, and then negate the result. That's much
-        // more efficient, since the scalar -c1 has less limbs and a much lower Hamming
-        // weight.
-        let x: &'static [u64] = crate::Config::X;
-        let p_projective = p.into_group();
-
-        // [x]P
-        let x_p = Config::mul_affine(p, &x).neg();
-        // ψ(P)
-        let psi_p = p_power_endomorphism(&p);
-        // (ψ^2)(2P)
-        let mut psi2_p2 = double_p_power_endomorphism(&p_projective.double());
-
-        // tmp = [x]P + ψ(P)
-        let mut tmp = x_p.clone();
-        tmp += &psi_p;
-
-        // tmp2 = [x^2]P + [x]ψ(P)
-        let mut tmp2: Projective<Config> = tmp;
-        tmp2 = tmp2.mul_bigint(x).neg();
-
-        // add up all the terms
-        psi2_p2 += tmp2;
-        psi2_p2 -= x_p;
-        psi2_p2 += &-psi_p;
-        (psi2_p2 - p_projective).into_affine()
+        bls12::g2::clear_cofactor::<crate::Config>(p)
+    }
+
+    #[inline]
+    fn clear_cofactor_projective(p: &G2Projective) -> G2Projective {
+        bls12::g2::clear_cofactor_projective::<crate::Config>(p)
     }
 
     fn deserialize_with_mode<R: ark_serialize::Read>(
@@ -127,13 +108,28 @@ impl SWCurveConfig for Config {
             read_g2_uncompressed(&mut reader)?
         };
 
-        if validate == ark_serialize::Validate::Yes && !p.is_in_correct_subgroup_assuming_on_curve()
-        {
-            return Err(SerializationError::InvalidData);
+        // Compressed points are on the curve by construction, but uncompressed
+        // ones must be checked before the subgroup check, which assumes it.
+        if validate == ark_serialize::Validate::Yes {
+            p.check()?;
         }
         Ok(p)
     }
 
+    /// Reads the Zcash encodings of all points first, and then computes their
+    /// y-coordinates at once with a batched square root.
+    fn deserialize_compressed_batch<R: ark_serialize::Read>(
+        reader: R,
+        count: usize,
+        validate: ark_serialize::Validate,
+    ) -> Result<Vec<Affine<Self>>, ark_serialize::SerializationError> {
+        let points = read_g2_compressed_batch(reader, count)?;
+        if validate == ark_serialize::Validate::Yes {
+            G2Affine::batch_check(points.iter())?;
+        }
+        Ok(points)
+    }
+
     fn serialize_with_mode<W: ark_serialize::Write>(
         item: &Affine<Self>,
         mut writer: W,
@@ -236,7 +232,7 @@ pub const G2_GENERATOR_Y_C0: Fq = MontFp!("1985150602287291935568054521177171638
 pub const G2_GENERATOR_Y_C1: Fq = MontFp!("927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582");
 
 // PSI_X = 1/(u+1)^((p-1)/3)
-const P_POWER_ENDOMORPHISM_COEFF_0 : Fq2 = Fq2::new(
+pub(crate) const P_POWER_ENDOMORPHISM_COEFF_0 : Fq2 = Fq2::new(
     Fq::ZERO,
     MontFp!(
                 "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939437"
@@ -244,57 +240,13 @@ const P_POWER_ENDOMORPHISM_COEFF_0 : Fq2 = Fq2::new(
 );
 
 // PSI_Y = 1/(u+1)^((p-1)/2)
-const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
+pub(crate) const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
     MontFp!(
                 "2973677408986561043442465346520108879172042883009249989176415018091420807192182638567116318576472649347015917690530"),
     MontFp!(
        "1028732146235106349975324479215795277384839936929757896155643118032610843298655225875571310552543014690878354869257")
 );
 
-// PSI_2_X = (u+1)^((1-p^2)/3)
-const DOUBLE_P_POWER_ENDOMORPHISM_COEFF_0: Fq2 = Fq2::new(
-    MontFp!("4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436"),
-    Fq::ZERO
-);
-
-/// psi(P) is the untwist-Frobenius-twist endomorphism on E'(Fq2)
-fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
-    // The p-power endomorphism for G2 is defined as follows:
-    // 1. Note that G2 is defined on curve E': y^2 = x^3 + 4(u+1).
-    //    To map a point (x, y) in E' to (s, t) in E,
-    //    set s = x / ((u+1) ^ (1/3)), t = y / ((u+1) ^ (1/2)),
-    //    because E: y^2 = x^3 + 4.
-    // 2. Apply the Frobenius endomorphism (s, t) => (s', t'),
-    //    another point on curve E, where s' = s^p, t' = t^p.
-    // 3. Map the point from E back to E'; that is,
-    //    set x' = s' * ((u+1) ^ (1/3)), y' = t' * ((u+1) ^ (1/2)).
-    //
-    // To sum up, it maps
-    // (x,y) -> (x^p / ((u+1)^((p-1)/3)), y^p / ((u+1)^((p-1)/2)))
-    // as implemented in the code as follows.
-
-    let mut res = *p;
-    res.x.frobenius_map_in_place(1);
-    res.y.frobenius_map_in_place(1);
-
-    let tmp_x = res.x.clone();
-    res.x.c0 = -P_POWER_ENDOMORPHISM_COEFF_0.c1 * &tmp_x.c1;
-    res.x.c1 = P_POWER_ENDOMORPHISM_COEFF_0.c1 * &tmp_x.c0;
-    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
-
-    res
-}
-
-/// For a p-power endomorphism psi(P), compute psi(psi(P))
-fn double_p_power_endomorphism(p: &Projective<Config>) -> Projective<Config> {
-    let mut res = *p;
-
-    res.x *= DOUBLE_P_POWER_ENDOMORPHISM_COEFF_0;
-    res.y = res.y.neg();
-
-    res
-}
-
 // Parameters from the [IETF draft v16, section E.3](https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-3-isogeny-map-for-bls12-381).
 impl WBConfig for Config {
     type IsogenousCurve = g2_swu_iso::SwuIsoConfig;
@@ -326,9 +278,9 @@ mod test {
     #[test]
     fn test_psi_2() {
         let p = sample_unchecked();
-        let psi_p = p_power_endomorphism(&p);
-        let psi2_p_composed = p_power_endomorphism(&psi_p);
-        let psi2_p_optimised = double_p_power_endomorphism(&p.into());
+        let psi_p = bls12::g2::mul_by_char::<crate::Config>(p);
+        let psi2_p_composed = bls12::g2::mul_by_char::<crate::Config>(psi_p);
+        let psi2_p_optimised = bls12::g2::double_mul_by_char::<crate::Config>(p.into());
 
         assert_eq!(psi2_p_composed, psi2_p_optimised);
     }
@@ -337,18 +289,7 @@ mod test {
     fn test_cofactor_clearing() {
         // multiplying by h_eff and clearing the cofactor by the efficient
         // endomorphism-based method should yield the same result.
-        let h_eff: &'static [u64] = &[
-            0xe8020005aaa95551,
-            0x59894c0adebbf6b4,
-            0xe954cbc06689f6a3,
-            0x2ec0ec69d7477c1a,
-            0x6d82bf015d1212b0,
-            0x329c2f178731db95,
-            0x9986ff031508ffe1,
-            0x88e2a8e9145ad768,
-            0x584c6a0ea91b3528,
-            0xbc69f08f2ee75b3,
-        ];
+        let h_eff = g2::Config::EFFECTIVE_COFACTOR;
 
         const SAMPLES: usize = 10;
         for _ in 0..SAMPLES {
@@ -1,6 +1,6 @@
 use ark_ec::bls12::{Bls12, Bls12Config, TwistType};
 
-use crate::{Fq, Fq12Config, Fq2Config, Fq6Config};
+use crate::{Fq, Fq12Config, Fq2, Fq2Config, Fq6Config};
 
 pub mod g1;
 pub mod g2;
@@ -25,6 +25,8 @@ impl Bls12Config for Config {
     const X: &'static [u64] = &[0xd201000000010000];
     const X_IS_NEGATIVE: bool = true;
     const TWIST_TYPE: TwistType = TwistType::M;
+    const TWIST_MUL_BY_Q_X: Fq2 = g2::P_POWER_ENDOMORPHISM_COEFF_0;
+    const TWIST_MUL_BY_Q_Y: Fq2 = g2::P_POWER_ENDOMORPHISM_COEFF_1;
     type Fp = Fq;
     type Fp2Config = Fq2Config;
     type Fp6Config = Fq6Config;
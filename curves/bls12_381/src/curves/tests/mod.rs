@@ -1,8 +1,11 @@
 use ark_algebra_test_templates::*;
 use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
 use ark_ff::{fields::Field, One, UniformRand, Zero};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
-use ark_std::{rand::Rng, test_rng, vec};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+    ValidationError,
+};
+use ark_std::{rand::Rng, test_rng, vec, vec::Vec};
 
 use crate::{Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 
@@ -14,6 +17,8 @@ test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_381>; msm);
 test_pairing!(pairing; crate::Bls12_381);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12381G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12381G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
+test_h2c!(g1_e2c; "./src/curves/tests"; "BLS12381G1"; crate::g1::Config; crate::Fq; crate::Fq; 1; "NU"; encode);
+test_h2c!(g2_e2c; "./src/curves/tests"; "BLS12381G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2; "NU"; encode);
 
 #[test]
 fn test_g1_endomorphism_beta() {
@@ -66,6 +71,27 @@ fn test_g2_subgroup_non_membership_via_endomorphism() {
     }
 }
 
+#[test]
+fn test_twist_mul_by_q_matches_frobenius_coefficients() {
+    use crate::{Config, Fq12Config, Fq6Config};
+    use ark_ec::bls12::Bls12Config;
+    use ark_ff::{Fp12Config, Fp6Config};
+
+    // For an M-type twist, these are the inverses of `ξ^((p - 1) / 3)` and
+    // `ξ^((p - 1) / 2)`.
+    assert_eq!(
+        Config::TWIST_MUL_BY_Q_X,
+        Fq6Config::FROBENIUS_COEFF_FP6_C1[1].inverse().unwrap()
+    );
+    assert_eq!(
+        Config::TWIST_MUL_BY_Q_Y,
+        Fq12Config::FROBENIUS_COEFF_FP12_C1[1]
+            .pow([3])
+            .inverse()
+            .unwrap()
+    );
+}
+
 // Test vectors and macro adapted from https://github.com/zkcrypto/bls12_381/blob/e224ad4ea1babfc582ccd751c2bf128611d10936/src/tests/mod.rs
 macro_rules! test_vectors {
     ($projective:ident, $affine:ident, $compress:expr, $expected:ident) => {
@@ -121,3 +147,144 @@ fn g2_uncompressed_valid_test_vectors() {
     let bytes: &'static [u8] = include_bytes!("g2_uncompressed_valid_test_vectors.dat");
     test_vectors!(G2Projective, G2Affine, Compress::No, bytes);
 }
+
+// Encodings of the generators and of the identity, as produced by blst, py_ecc
+// and the Ethereum consensus specifications.
+#[test]
+fn zcash_encoding_of_generators() {
+    fn check<A: AffineRepr>(p: A, compressed: &str, uncompressed_len: usize) {
+        let compressed = hex::decode(compressed).unwrap();
+        let mut bytes = vec![];
+        p.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes, compressed);
+        assert_eq!(A::deserialize_compressed(&bytes[..]).unwrap(), p);
+
+        let mut bytes = vec![];
+        p.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), uncompressed_len);
+        // The uncompressed encoding starts with x, without the compression flag.
+        assert_eq!(bytes[0], compressed[0] & 0b0001_1111);
+        assert_eq!(bytes[1..compressed.len()], compressed[1..]);
+        assert_eq!(A::deserialize_uncompressed(&bytes[..]).unwrap(), p);
+
+        let mut bytes = vec![];
+        A::zero().serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes[0], 0xc0);
+        assert!(bytes[1..].iter().all(|&b| b == 0));
+        let mut bytes = vec![];
+        A::zero().serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(bytes[0], 0x40);
+        assert!(bytes[1..].iter().all(|&b| b == 0));
+    }
+
+    check(
+        G1Affine::generator(),
+        "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        96,
+    );
+    check(
+        G2Affine::generator(),
+        "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+        192,
+    );
+}
+
+#[test]
+fn uncompressed_points_off_the_curve_are_rejected() {
+    fn check<A: AffineRepr>(p: A) {
+        let mut bytes = vec![];
+        p.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(matches!(
+            A::deserialize_uncompressed(&bytes[..]),
+            Err(SerializationError::ValidationError(
+                ValidationError::NotOnCurve
+            ))
+        ));
+        assert_eq!(
+            A::deserialize_uncompressed_unchecked(&bytes[..]).unwrap(),
+            p
+        );
+    }
+
+    check(G1Affine::new_unchecked(Fq::one(), Fq::one()));
+    check(G2Affine::new_unchecked(Fq2::one(), Fq2::one()));
+}
+
+#[test]
+fn malformed_encodings_report_validation_errors() {
+    fn validation_error<A: AffineRepr>(bytes: &[u8], compress: Compress) -> ValidationError {
+        match A::deserialize_with_mode(bytes, compress, Validate::Yes) {
+            Err(SerializationError::ValidationError(e)) => e,
+            other => panic!("expected a validation error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    // The point at infinity with a non-zero x-coordinate.
+    let mut bytes = vec![0u8; 48];
+    bytes[0] = 0xc0;
+    bytes[47] = 1;
+    assert_eq!(
+        validation_error::<G1Affine>(&bytes, Compress::Yes),
+        ValidationError::InvalidInfinityFlags
+    );
+    // The point at infinity with the sort flag set.
+    let mut bytes = vec![0u8; 96];
+    bytes[0] = 0xe0;
+    assert_eq!(
+        validation_error::<G2Affine>(&bytes, Compress::Yes),
+        ValidationError::InvalidInfinityFlags
+    );
+
+    // An x-coordinate of 2^381 - 1, which exceeds the modulus.
+    let mut bytes = vec![0xff; 48];
+    bytes[0] = 0x9f;
+    assert_eq!(
+        validation_error::<G1Affine>(&bytes, Compress::Yes),
+        ValidationError::NonCanonicalFieldEncoding
+    );
+
+    // An x-coordinate for which `x^3 + 4` is not a square.
+    let x = loop {
+        let x = Fq::rand(&mut test_rng());
+        if G1Affine::get_point_from_x_unchecked(x, false).is_none() {
+            break x;
+        }
+    };
+    let mut bytes = vec![];
+    G1Affine::new_unchecked(x, Fq::one())
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    assert_eq!(
+        validation_error::<G1Affine>(&bytes, Compress::Yes),
+        ValidationError::NotOnCurve
+    );
+}
+
+#[test]
+fn compressed_batch_deserialization_uses_the_zcash_encoding() {
+    let mut rng = test_rng();
+    let mut points = (0..20)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    points[3] = G1Affine::zero();
+    let mut bytes = vec![];
+    for p in &points {
+        p.serialize_compressed(&mut bytes).unwrap();
+    }
+    assert_eq!(
+        G1Affine::deserialize_compressed_batch(&bytes[..], points.len(), Validate::Yes).unwrap(),
+        points
+    );
+
+    let points = (0..20)
+        .map(|_| G2Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let mut bytes = vec![];
+    for p in &points {
+        p.serialize_compressed(&mut bytes).unwrap();
+    }
+    assert_eq!(
+        G2Affine::deserialize_compressed_batch(&bytes[..], points.len(), Validate::Yes).unwrap(),
+        points
+    );
+}
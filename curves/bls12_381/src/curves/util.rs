@@ -1,6 +1,10 @@
-use ark_ec::{short_weierstrass::Affine, AffineRepr};
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
 use ark_ff::{BigInteger384, PrimeField};
-use ark_serialize::SerializationError;
+use ark_serialize::{SerializationError, ValidationError};
+use ark_std::vec::Vec;
 
 use crate::{g1::Config as G1Config, g2::Config as G2Config, Fq, Fq2, G1Affine, G2Affine};
 
@@ -24,8 +28,13 @@ impl EncodingFlags {
         let is_infinity = infinity_flag_set == 1;
         let is_lexographically_largest = sort_flag_set == 1;
 
-        if is_lexographically_largest && (!is_compressed || is_infinity) {
-            return Err(SerializationError::InvalidData);
+        if is_lexographically_largest {
+            if is_infinity {
+                return Err(ValidationError::InvalidInfinityFlags.into());
+            }
+            if !is_compressed {
+                return Err(SerializationError::UnexpectedFlags);
+            }
         }
 
         Ok(Self {
@@ -101,13 +110,36 @@ fn read_bytes_with_offset(bytes: &[u8], offset: usize, mask: bool) -> [u8; G1_SE
 }
 
 pub(crate) fn read_g1_compressed<R: ark_serialize::Read>(
-    mut reader: R,
+    reader: R,
 ) -> Result<Affine<G1Config>, ark_serialize::SerializationError> {
+    match read_g1_compressed_x(reader)? {
+        Some((x, greatest)) => {
+            Ok(G1Affine::get_point_from_x_unchecked(x, greatest)
+                .ok_or(ValidationError::NotOnCurve)?)
+        },
+        None => Ok(G1Affine::zero()),
+    }
+}
+
+/// Reads `count` compressed G1 points, computing their y-coordinates at once
+/// with [`Affine::get_points_from_x_unchecked_batch`].
+pub(crate) fn read_g1_compressed_batch<R: ark_serialize::Read>(
+    reader: R,
+    count: usize,
+) -> Result<Vec<Affine<G1Config>>, ark_serialize::SerializationError> {
+    read_compressed_batch(reader, count, |r| read_g1_compressed_x(r))
+}
+
+/// Reads the x-coordinate of a compressed G1 point and whether its
+/// y-coordinate is the lexicographically largest one, or `None` for the point
+/// at infinity.
+fn read_g1_compressed_x<R: ark_serialize::Read>(
+    mut reader: R,
+) -> Result<Option<(Fq, bool)>, ark_serialize::SerializationError> {
     let mut bytes = [0u8; G1_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
-        .ok()
-        .ok_or(SerializationError::InvalidData)?;
+        .map_err(|_| SerializationError::InvalidData)?;
 
     // Obtain the three flags from the start of the byte sequence
     let flags = EncodingFlags::get_flags(&bytes[..])?;
@@ -123,17 +155,14 @@ pub(crate) fn read_g1_compressed<R: ark_serialize::Read>(
     if flags.is_infinity {
         // Check that the `x` co-ordinate was `0`
         if x_bytes != [0u8; 48] {
-            return Err(SerializationError::InvalidData);
+            return Err(ValidationError::InvalidInfinityFlags.into());
         }
 
-        return Ok(G1Affine::zero());
+        return Ok(None);
     }
 
-    let x = deserialize_fq(x_bytes).ok_or(SerializationError::InvalidData)?;
-    let p = G1Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
-        .ok_or(SerializationError::InvalidData)?;
-
-    Ok(p)
+    let x = deserialize_fq(x_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
+    Ok(Some((x, flags.is_lexographically_largest)))
 }
 
 pub(crate) fn read_g1_uncompressed<R: ark_serialize::Read>(
@@ -157,23 +186,47 @@ pub(crate) fn read_g1_uncompressed<R: ark_serialize::Read>(
 
     if flags.is_infinity {
         if x_bytes != [0u8; 48] || y_bytes != [0u8; 48] {
-            return Err(SerializationError::InvalidData);
+            return Err(ValidationError::InvalidInfinityFlags.into());
         }
         return Ok(G1Affine::zero());
     }
 
     // Attempt to obtain the x-coordinate
-    let x = deserialize_fq(x_bytes).ok_or(SerializationError::InvalidData)?;
+    let x = deserialize_fq(x_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
     // Attempt to obtain the y-coordinate
-    let y = deserialize_fq(y_bytes).ok_or(SerializationError::InvalidData)?;
+    let y = deserialize_fq(y_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
     let p = G1Affine::new_unchecked(x, y);
 
     Ok(p)
 }
 
 pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
-    mut reader: R,
+    reader: R,
 ) -> Result<Affine<G2Config>, ark_serialize::SerializationError> {
+    match read_g2_compressed_x(reader)? {
+        Some((x, greatest)) => {
+            Ok(G2Affine::get_point_from_x_unchecked(x, greatest)
+                .ok_or(ValidationError::NotOnCurve)?)
+        },
+        None => Ok(G2Affine::zero()),
+    }
+}
+
+/// Reads `count` compressed G2 points, computing their y-coordinates at once
+/// with [`Affine::get_points_from_x_unchecked_batch`].
+pub(crate) fn read_g2_compressed_batch<R: ark_serialize::Read>(
+    reader: R,
+    count: usize,
+) -> Result<Vec<Affine<G2Config>>, ark_serialize::SerializationError> {
+    read_compressed_batch(reader, count, |r| read_g2_compressed_x(r))
+}
+
+/// Reads the x-coordinate of a compressed G2 point and whether its
+/// y-coordinate is the lexicographically largest one, or `None` for the point
+/// at infinity.
+fn read_g2_compressed_x<R: ark_serialize::Read>(
+    mut reader: R,
+) -> Result<Option<(Fq2, bool)>, ark_serialize::SerializationError> {
     let mut bytes = [0u8; G2_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -192,20 +245,40 @@ pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
 
     if flags.is_infinity {
         if xc1_bytes != [0u8; 48] || xc0_bytes != [0u8; 48] {
-            return Err(SerializationError::InvalidData);
+            return Err(ValidationError::InvalidInfinityFlags.into());
         }
-        return Ok(G2Affine::zero());
+        return Ok(None);
     }
 
     // Attempt to obtain the x-coordinate
-    let xc1 = deserialize_fq(xc1_bytes).ok_or(SerializationError::InvalidData)?;
-    let xc0 = deserialize_fq(xc0_bytes).ok_or(SerializationError::InvalidData)?;
+    let xc1 = deserialize_fq(xc1_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
+    let xc0 = deserialize_fq(xc0_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
     let x = Fq2::new(xc0, xc1);
 
-    let p = G2Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
-        .ok_or(SerializationError::InvalidData)?;
+    Ok(Some((x, flags.is_lexographically_largest)))
+}
 
-    Ok(p)
+/// Reads `count` compressed points with `read_x`, which returns the
+/// x-coordinate and sort flag of a point, or `None` for the point at infinity.
+fn read_compressed_batch<P: SWCurveConfig, R: ark_serialize::Read>(
+    mut reader: R,
+    count: usize,
+    mut read_x: impl FnMut(&mut R) -> Result<Option<(P::BaseField, bool)>, SerializationError>,
+) -> Result<Vec<Affine<P>>, SerializationError> {
+    let xs = (0..count)
+        .map(|_| read_x(&mut reader))
+        .collect::<Result<Vec<_>, _>>()?;
+    let finite_xs = xs.iter().flatten().copied().collect::<Vec<_>>();
+    let mut finite_points = Affine::<P>::get_points_from_x_unchecked_batch(&finite_xs).into_iter();
+    xs.into_iter()
+        .map(|x| match x {
+            Some(_) => Ok(finite_points
+                .next()
+                .flatten()
+                .ok_or(ValidationError::NotOnCurve)?),
+            None => Ok(Affine::identity()),
+        })
+        .collect()
 }
 
 pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
@@ -236,15 +309,15 @@ pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
             || yc1_bytes != [0u8; 48]
             || yc0_bytes != [0u8; 48]
         {
-            return Err(SerializationError::InvalidData);
+            return Err(ValidationError::InvalidInfinityFlags.into());
         }
         return Ok(G2Affine::zero());
     }
 
-    let xc1 = deserialize_fq(xc1_bytes).ok_or(SerializationError::InvalidData)?;
-    let xc0 = deserialize_fq(xc0_bytes).ok_or(SerializationError::InvalidData)?;
-    let yc1 = deserialize_fq(yc1_bytes).ok_or(SerializationError::InvalidData)?;
-    let yc0 = deserialize_fq(yc0_bytes).ok_or(SerializationError::InvalidData)?;
+    let xc1 = deserialize_fq(xc1_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
+    let xc0 = deserialize_fq(xc0_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
+    let yc1 = deserialize_fq(yc1_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
+    let yc0 = deserialize_fq(yc0_bytes).ok_or(ValidationError::NonCanonicalFieldEncoding)?;
 
     // Attempt to obtain the x-coordinate
     let x = Fq2::new(xc0, xc1);
@@ -23,6 +23,13 @@
 //! * valuation(r - 1, 2) = 32
 //! * G1 curve equation: y^2 = x^3 + 4
 //! * G2 curve equation: y^2 = x^3 + Fq2(4, 4)
+//!
+//! Points are serialized in the format of the [Zcash library](https://github.com/zkcrypto/bls12_381),
+//! which is also used by blst, py_ecc and Ethereum clients: the coordinates are
+//! big-endian, with `c1` before `c0` in `Fq2`, and the three most significant
+//! bits of the first byte respectively flag compression, the point at infinity,
+//! and, for compressed points, whether `y` is the lexicographically largest of
+//! its two possible values.
 
 #[cfg(feature = "curve")]
 mod curves;
@@ -1,12 +1,13 @@
 use ark_ec::{
     bn,
+    hashing::curve_maps::svdw::SvdWConfig,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
-    scalar_mul::glv::GLVConfig,
+    scalar_mul::glv::{self, GLVConfig},
     short_weierstrass::{Affine, Projective},
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
-use crate::{Fq, Fr};
+use crate::{Fq, Fr, G1Projective};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Config;
@@ -48,6 +49,11 @@ impl SWCurveConfig for Config {
         GLVConfig::glv_mul_projective(*p, s)
     }
 
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Fr]) -> Result<G1Projective, usize> {
+        glv::msm(bases, scalars)
+    }
+
     #[inline]
     fn is_in_correct_subgroup_assuming_on_curve(_p: &G1Affine) -> bool {
         // G1 = E(Fq) so if the point is on the curve, it is also in the subgroup.
@@ -82,6 +88,27 @@ impl GLVConfig for Config {
     }
 }
 
+// Parameters of the RFC 9380 suite `BN254G1_XMD:SHA-256_SVDW_RO_`, with the
+// smallest valid `Z`, found with `find_z_svdw` of `curve_map_parameter_helper.sage`.
+impl SvdWConfig for Config {
+    /// Z = 1
+    const Z: Fq = Fq::ONE;
+
+    /// C1 = g(Z) = 4
+    const C1: Fq = MontFp!("4");
+
+    /// C2 = -Z / 2
+    const C2: Fq =
+        MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291");
+
+    /// C3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A)), with sgn0(C3) == 0
+    const C3: Fq = MontFp!("8815841940592487685674414971303048083897117035520822607866");
+
+    /// C4 = -4 * g(Z) / (3 * Z^2 + 4 * A)
+    const C4: Fq =
+        MontFp!("7296080957279758407415468581752425029565437052432607887563012631548408736189");
+}
+
 /// G1_GENERATOR_X = 1
 pub const G1_GENERATOR_X: Fq = Fq::ONE;
 
@@ -1,10 +1,10 @@
-use ark_ec::AffineRepr;
 use ark_ec::{
+    bn,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
 };
-use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
+use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField, Zero};
 
 use crate::{Fq, Fq2, Fr};
 
@@ -52,13 +52,7 @@ impl SWCurveConfig for Config {
     }
 
     fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
-        // Subgroup check from section 4.3 of https://eprint.iacr.org/2022/352.pdf.
-        //
-        // Checks that [p]P = [6X^2]P
-
-        let x_times_point = point.mul_bigint(SIX_X_SQUARED);
-        let p_times_point = p_power_endomorphism(point);
-        x_times_point.eq(&p_times_point)
+        bn::g2::is_in_correct_subgroup_assuming_on_curve::<crate::Config>(point)
     }
 }
 
@@ -114,40 +108,13 @@ pub const G2_GENERATOR_Y_C0: Fq =
 pub const G2_GENERATOR_Y_C1: Fq =
     MontFp!("4082367875863433681332203403145435568316851327593401208105741076214120093531");
 
-// PSI_X = (u+9)^((p-1)/3) = TWIST_MUL_BY_Q_X
-const P_POWER_ENDOMORPHISM_COEFF_0: Fq2 = Fq2::new(
-    MontFp!("21575463638280843010398324269430826099269044274347216827212613867836435027261"),
-    MontFp!("10307601595873709700152284273816112264069230130616436755625194854815875713954"),
-);
-
-// PSI_Y = (u+9)^((p-1)/2) = TWIST_MUL_BY_Q_Y
-const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
-    MontFp!("2821565182194536844548159561693502659359617185244120367078079554186484126554"),
-    MontFp!("3505843767911556378687030309984248845540243509899259641013678093033130930403"),
-);
-
-// Integer representation of 6x^2 = t - 1
-const SIX_X_SQUARED: [u64; 2] = [17887900258952609094, 8020209761171036667];
-
-/// psi(P) is the untwist-Frobenius-twist endomorphism on E'(Fq2)
-fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
-    // Maps (x,y) -> (x^p * (u+9)^((p-1)/3), y^p * (u+9)^((p-1)/2))
-
-    let mut res = *p;
-    res.x.frobenius_map_in_place(1);
-    res.y.frobenius_map_in_place(1);
-
-    res.x *= P_POWER_ENDOMORPHISM_COEFF_0;
-    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
-
-    res
-}
-
 #[cfg(test)]
 mod test {
 
     use super::*;
     use crate::g2;
+    use ark_ec::AffineRepr;
+    use ark_ff::Field;
     use ark_std::{rand::Rng, UniformRand};
 
     fn sample_unchecked() -> Affine<g2::Config> {
@@ -183,6 +150,10 @@ mod test {
                 naive_is_in_subgroup_assuming_on_curve(&p),
                 p.is_in_correct_subgroup_assuming_on_curve()
             );
+            assert_eq!(
+                p.mul_bigint(Fr::MODULUS).is_zero(),
+                bn::g2::is_in_correct_subgroup_assuming_on_curve::<crate::Config>(&p)
+            );
 
             let cleared = p.clear_cofactor();
             assert!(cleared.is_in_correct_subgroup_assuming_on_curve());
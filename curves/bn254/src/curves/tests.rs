@@ -9,3 +9,35 @@ test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bn254>; msm);
 test_pairing!(pairing; crate::Bn254);
 test_group!(g1_glv; G1Projective; glv);
 test_group!(g2_glv; G2Projective; glv);
+// RFC 9380 defines no test vectors for BN254: these were generated with a
+// separate implementation of its `expand_message_xmd`, `hash_to_field` and
+// Appendix F.1 SvdW procedures, under the usual `QUUX-V01-CS02-with-` DSTs.
+test_h2c!(g1_h2c; "./src/curves/tests"; "BN254G1"; crate::g1::Config; crate::Fq; crate::Fq; 1; "RO"; hash; "SVDW"; ark_ec::hashing::curve_maps::svdw::SvdWMap<crate::g1::Config>);
+test_h2c!(g1_e2c; "./src/curves/tests"; "BN254G1"; crate::g1::Config; crate::Fq; crate::Fq; 1; "NU"; encode; "SVDW"; ark_ec::hashing::curve_maps::svdw::SvdWMap<crate::g1::Config>);
+
+#[test]
+fn test_final_exponentiation_hard_parts() {
+    use ark_ff::CyclotomicMultSubgroup;
+    use ark_std::{test_rng, UniformRand};
+
+    use crate::Fq12;
+
+    // 2z(6z^2 + 3z + 1), the power by which the default hard part differs
+    // from the classic one.
+    let exp: &[u64] = &[0x2e5d4e223ddedaf4, 0x1ea96b02d9d9e38d, 0x3bec47df15e307c8];
+
+    let mut rng = test_rng();
+    for _ in 0..5 {
+        let f = Fq12::rand(&mut rng);
+        let f = Bn254::final_exponentiation_easy_part(f).unwrap();
+        let default = Bn254::final_exponentiation_hard_part(&f);
+        assert_eq!(
+            Bn254::final_exponentiation_hard_part_classic(&f).cyclotomic_exp(exp),
+            default
+        );
+        assert_eq!(
+            Bn254::final_exponentiation_hard_part_compressed(&f),
+            default
+        );
+    }
+}
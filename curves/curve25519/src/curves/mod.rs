@@ -4,6 +4,8 @@ use ark_ec::{
     twisted_edwards::{Affine, MontCurveConfig, MontgomeryAffine, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
 
 #[cfg(test)]
 mod tests;
@@ -47,6 +49,14 @@ impl TECurveConfig for Curve25519Config {
     const GENERATOR: EdwardsAffine = EdwardsAffine::new_unchecked(GENERATOR_X, GENERATOR_Y);
 
     type MontCurveConfig = Curve25519Config;
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        Affine::deserialize_default_compressed_batch(reader, count, validate)
+    }
 }
 
 impl MontCurveConfig for Curve25519Config {
@@ -1,4 +1,42 @@
 use crate::*;
 use ark_algebra_test_templates::*;
+use ark_std::vec::*;
 
 test_group!(te; EdwardsProjective; te);
+
+/// The X25519 test vectors of RFC 7748, Section 5.2.
+#[test]
+fn test_x25519() {
+    use ark_ec::twisted_edwards::MontgomeryXOnly;
+    use ark_ff::{BigInteger, PrimeField};
+
+    let x25519 = |scalar: &str, u: &str| {
+        let mut scalar = hex::decode(scalar).unwrap();
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let limbs: Vec<u64> = scalar
+            .chunks(8)
+            .map(|c| u64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        let mut u = hex::decode(u).unwrap();
+        u[31] &= 127;
+        let u = MontgomeryXOnly::<Curve25519Config>::from_x(Fq::from_le_bytes_mod_order(&u));
+        let x = u.ladder(&limbs, 255).to_x().unwrap_or_default();
+        hex::encode(x.into_bigint().to_bytes_le())
+    };
+    assert_eq!(
+        x25519(
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+        ),
+        "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552"
+    );
+    assert_eq!(
+        x25519(
+            "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+            "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+        ),
+        "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957"
+    );
+}
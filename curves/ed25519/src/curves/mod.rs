@@ -4,6 +4,8 @@ use ark_ec::{
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
 
 #[cfg(test)]
 mod tests;
@@ -45,6 +47,14 @@ impl TECurveConfig for EdwardsConfig {
     fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
         -elem
     }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        Affine::deserialize_default_compressed_batch(reader, count, validate)
+    }
 }
 
 // We want to emphasize that this Montgomery curve is not Curve25519.
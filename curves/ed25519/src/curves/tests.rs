@@ -1,4 +1,70 @@
 use crate::*;
 use ark_algebra_test_templates::*;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_serialize::{SerializationError, Validate, ValidationError};
+use ark_std::{test_rng, vec, UniformRand};
 
 test_group!(te; EdwardsProjective; te);
+
+// The public keys of tests 1 and 2 of RFC 8032, Section 7.1, and the base point.
+const RFC8032_ENCODINGS: [&str; 3] = [
+    "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+    "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+    "5866666666666666666666666666666666666666666666666666666666666666",
+];
+
+fn deserialize_rfc8032(encoding: &str) -> Result<EdwardsAffine, SerializationError> {
+    EdwardsAffine::deserialize_rfc8032(&hex::decode(encoding).unwrap()[..], Validate::Yes)
+}
+
+#[test]
+fn rfc8032_encoding() {
+    for encoding in RFC8032_ENCODINGS {
+        let p = deserialize_rfc8032(encoding).unwrap();
+        let mut bytes = vec![];
+        p.serialize_rfc8032(&mut bytes).unwrap();
+        assert_eq!(hex::encode(bytes), encoding);
+    }
+    assert_eq!(
+        deserialize_rfc8032(RFC8032_ENCODINGS[2]).unwrap(),
+        EdwardsAffine::generator()
+    );
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = EdwardsProjective::rand(&mut rng).into_affine();
+        let mut bytes = vec![];
+        p.serialize_rfc8032(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), EdwardsAffine::rfc8032_size());
+        assert_eq!(
+            EdwardsAffine::deserialize_rfc8032(&bytes[..], Validate::Yes).unwrap(),
+            p
+        );
+    }
+}
+
+#[test]
+fn rfc8032_invalid_encodings() {
+    fn validation_error(encoding: &str) -> Option<ValidationError> {
+        match deserialize_rfc8032(encoding) {
+            Err(SerializationError::ValidationError(e)) => Some(e),
+            _ => None,
+        }
+    }
+
+    // y = p is not a canonical encoding.
+    assert_eq!(
+        validation_error("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+        Some(ValidationError::NonCanonicalFieldEncoding)
+    );
+    // The identity has x = 0, so its sign bit must not be set.
+    assert_eq!(
+        deserialize_rfc8032("0100000000000000000000000000000000000000000000000000000000000000")
+            .unwrap(),
+        EdwardsAffine::zero()
+    );
+    assert_eq!(
+        validation_error("0100000000000000000000000000000000000000000000000000000000000080"),
+        Some(ValidationError::NotOnCurve)
+    );
+}
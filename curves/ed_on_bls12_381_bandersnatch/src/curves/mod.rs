@@ -5,6 +5,8 @@ use ark_ec::{
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::{AdditiveGroup, MontFp};
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
 
 use crate::{Fq, Fr};
 
@@ -87,6 +89,14 @@ impl TECurveConfig for BandersnatchConfig {
     fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
         -(elem.double().double() + elem)
     }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        Affine::deserialize_default_compressed_batch(reader, count, validate)
+    }
 }
 
 impl MontCurveConfig for BandersnatchConfig {
@@ -18,7 +18,7 @@ fn test_fq_add() {
     )
     .unwrap();
     let f2 = Fq::from_str(
-        "39786307610986038981023499868190793548353538256264351797285876981647142458383",
+        "17898064739146763758777094122933518459805173855848317453587672795071333962766",
     )
     .unwrap();
     let f3 = Fq::from_str(
@@ -49,11 +49,11 @@ fn test_fq_add_one() {
 #[test]
 fn test_fq_mul() {
     let f1 = Fq::from_str(
-        "24703123148064348394273033316595937198355721297494556079070134653139656190956",
+        "2814880276225073172026627571338662109807356897078521735371930466563847695339",
     )
     .unwrap();
     let f2 = Fq::from_str(
-        "38196797080882758914424853878212529985425118523754343117256179679117054302131",
+        "16308554209043483692178448132955254896876754123338308773557975492541245806514",
     )
     .unwrap();
     let f3 = Fq::from_str(
@@ -69,17 +69,16 @@ fn test_fq_mul() {
 #[test]
 fn test_fq_triple_mul() {
     let f1 = Fq::from_str(
-        "23834398828139479510988224171342199299644042568628082836691700490363123893905",
+        "1946155956300204288741818426084924211095678168212048492993496303787315398288",
     )
     .unwrap();
     let f2 = Fq::from_str(
-        "48343809612844640454129919255697536258606705076971130519928764925719046689317",
-    )
-    .unwrap();
-    let f3 = Fq::from_str(
-        "22704845471524346880579660022678666462201713488283356385810726260959369106033",
+        "4567323869166090009637107765182986081509976276139061832532356552567429698083",
     )
     .unwrap();
+    let f3 =
+        Fq::from_str("816602599685071658333254277421391373653349087867322042112522074383560610416")
+            .unwrap();
     let f4 = Fq::from_str(
         "7747776931431194635550680695131420638163057297019399136408144301550822179875",
     )
@@ -93,11 +92,11 @@ fn test_fq_triple_mul() {
 #[test]
 fn test_fq_div() {
     let f1 = Fq::from_str(
-        "31892744363926593013886463524057935370302352424137349660481695792871889573091",
+        "10004501492087317791640057778800660281753988023721315316783491606296081077474",
     )
     .unwrap();
     let f2 = Fq::from_str(
-        "47695868328933459965610498875668250916462767196500056002116961816137113470902",
+        "3919382585254909521117687385153700739366038395667987314720553442985496479668",
     )
     .unwrap();
     let f3 = Fq::from_str(
@@ -133,7 +132,7 @@ fn test_fq_sub() {
 #[test]
 fn test_fq_double_in_place_thrice() {
     let mut f1 = Fq::from_str(
-        "32768907806651393940832831055386272949401004221411141755415956893066040832473",
+        "10880664934812118718586425310128997860852639820995107411717752706490232336856",
     )
     .unwrap();
     let f3 = Fq::from_str(
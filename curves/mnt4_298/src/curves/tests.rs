@@ -5,3 +5,16 @@ test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<MNT4_298>; msm);
 test_pairing!(pairing; crate::MNT4_298);
+
+#[test]
+fn test_g2_prepared_off_the_curve_is_rejected() {
+    use ark_ec::{mnt4::MNT4Config, AffineRepr};
+    use ark_ff::{Field, One};
+    use ark_serialize::Valid;
+
+    let mut prepared = G2Prepared::from(G2Affine::generator());
+    assert!(prepared.check().is_ok());
+    prepared.x += Fq2::one();
+    prepared.x_over_twist = prepared.x * Config::TWIST.inverse().unwrap();
+    assert!(prepared.check().is_err());
+}
@@ -0,0 +1,580 @@
+//! Constant-time maps to curves, enabled by the `ct` feature.
+//!
+//! [`SWUMap::map_to_curve`], [`SvdWMap::map_to_curve`],
+//! [`WBMap::map_to_curve`] and [`Elligator2Map::map_to_curve`] branch on
+//! the exceptional cases of the maps and on whether `g(x1)` is square, and
+//! use the variable-time [`Field::inverse`], [`Field::sqrt`] and
+//! [`Field::legendre`]. Their [`MapToCurveCt::map_to_curve_ct`]
+//! counterparts instead follow the straight-line procedures of
+//! [\[HSSWW23\], Section 6], with conditional selection rather than
+//! branches, and the constant-time field operations of [`CtField`].
+//! They output the same points.
+//!
+//! [`MapToCurveBasedHasher::hash_ct`] and
+//! [`MapToCurveBasedHasher::encode_ct`] hash messages to curves with these
+//! maps, and then add the points, clear the cofactor and normalize the
+//! result with the constant-time operations of [`CurveGroupCt`]. They are
+//! suitable for hashing secret messages, provided that the arithmetic of
+//! the base field is constant-time, as it is for the prime fields using
+//! [`MontBackend`](ark_ff::MontBackend) and their quadratic extensions with
+//! the `ct` feature of `ark-ff`.
+//!
+//! - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+//!
+//! [`MapToCurveBasedHasher::hash_ct`]: crate::hashing::map_to_curve_hasher::MapToCurveBasedHasher::hash_ct
+//! [`MapToCurveBasedHasher::encode_ct`]: crate::hashing::map_to_curve_hasher::MapToCurveBasedHasher::encode_ct
+use ark_ff::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    AdditiveGroup, BigInteger, Field, Fp, FpConfig, PrimeField, QuadExtConfig, QuadExtField,
+};
+use num_traits::Zero;
+
+use super::{
+    elligator2::{Elligator2Config, Elligator2Map},
+    svdw::{SvdWConfig, SvdWMap},
+    swu::{SWUConfig, SWUMap},
+    wb::{WBConfig, WBMap},
+};
+use crate::{
+    hashing::map_to_curve_hasher::MapToCurve,
+    models::{
+        short_weierstrass::{
+            homogeneous::Homogeneous, isogeny::evaluate, Affine, Projective, SWCurveConfig,
+        },
+        twisted_edwards::{self, MontCurveConfig, MontgomeryAffine, TECurveConfig},
+    },
+    scalar_mul::montgomery_ladder,
+    CurveGroup,
+};
+
+/// Trait for maps to curves with a constant-time counterpart of
+/// [`MapToCurve::map_to_curve`].
+pub trait MapToCurveCt<T: CurveGroup>: MapToCurve<T> {
+    /// Computes [`MapToCurve::map_to_curve`] in constant time.
+    fn map_to_curve_ct(element: T::BaseField) -> T::Affine;
+}
+
+/// Fields with the constant-time operations of [\[HSSWW23\], Section 4].
+///
+/// Prime fields compute them with the constants of [`PrimeField`], and
+/// quadratic extensions with the operations of their base field, so that
+/// no constants of the field are computed at runtime.
+pub trait CtField: Field + ConditionallySelectable + ConstantTimeEq {
+    /// Returns `Choice(1)` if `self` is zero.
+    fn is_zero_ct(&self) -> Choice {
+        self.ct_eq(&Self::ZERO)
+    }
+
+    /// The `inv0` function, which returns the inverse of `self`, or zero if
+    /// `self` is zero.
+    fn inv0_ct(&self) -> Self;
+
+    /// The `is_square` function, which returns `Choice(1)` if `self` is a
+    /// square, including zero.
+    fn is_square_ct(&self) -> Choice;
+
+    /// Returns a square root of `self`, which must be a square.
+    fn sqrt_ct(&self) -> Self;
+
+    /// The `sgn0` function of [\[HSSWW23\], Section 4.1], which agrees
+    /// with [`super::parity`].
+    fn sgn0_ct(&self) -> Choice;
+}
+
+impl<P: FpConfig<N>, const N: usize> CtField for Fp<P, N> {
+    /// Computes `self^(p - 2)` with [`Fp::ct_inverse`].
+    fn inv0_ct(&self) -> Self {
+        self.ct_inverse().unwrap_or(Self::ZERO)
+    }
+
+    /// Computes the Legendre symbol `self^((p - 1) / 2)`.
+    fn is_square_ct(&self) -> Choice {
+        let legendre = self.pow(Self::MODULUS_MINUS_ONE_DIV_TWO);
+        legendre.ct_eq(&Self::ONE) | legendre.ct_is_zero()
+    }
+
+    /// Computes the root with the constant-time Tonelli-Shanks of
+    /// [`Fp::ct_sqrt`].
+    fn sqrt_ct(&self) -> Self {
+        self.ct_sqrt().unwrap_or(Self::ZERO)
+    }
+
+    fn sgn0_ct(&self) -> Choice {
+        Choice::from(self.into_bigint().is_odd() as u8)
+    }
+}
+
+impl<P: QuadExtConfig> CtField for QuadExtField<P>
+where
+    P::BaseField: CtField,
+{
+    /// Computes `(c0 - c1 * X) / (c0^2 - NONRESIDUE * c1^2)`, with a single
+    /// `inv0` in the base field.
+    fn inv0_ct(&self) -> Self {
+        let norm_inv = self.norm().inv0_ct();
+        Self::new(self.c0 * norm_inv, -(self.c1 * norm_inv))
+    }
+
+    /// An element of a quadratic extension is a square if and only if its
+    /// norm is a square in the base field.
+    fn is_square_ct(&self) -> Choice {
+        self.norm().is_square_ct()
+    }
+
+    /// Computes the root `x0 + x1 * X` with square roots in the base field.
+    fn sqrt_ct(&self) -> Self {
+        // If `self = (x0 + x1 * X)^2`, then `c0 = x0^2 + NONRESIDUE * x1^2`,
+        // `c1 = 2 * x0 * x1` and the norm of `self` is
+        // `(x0^2 - NONRESIDUE * x1^2)^2`, so `x0^2` is the one of
+        // `(c0 ± sqrt(norm)) / 2` which is a non-zero square, unless `x0` is
+        // zero. The inverses of public constants need not be constant-time.
+        let two_inv = P::BaseField::ONE.double().inverse().unwrap();
+        let nonresidue_inv = P::NONRESIDUE.inverse().unwrap();
+
+        let sqrt_norm = self.norm().sqrt_ct();
+        let delta_1 = (self.c0 + sqrt_norm) * two_inv;
+        let delta_2 = (self.c0 - sqrt_norm) * two_inv;
+        let delta_1_is_root = delta_1.is_square_ct() & !delta_1.is_zero_ct();
+        let delta = P::BaseField::conditional_select(&delta_2, &delta_1, delta_1_is_root);
+
+        // Otherwise, `self = NONRESIDUE * x1^2`.
+        let x0_is_zero = !delta.is_square_ct() | delta.is_zero_ct();
+        let square =
+            P::BaseField::conditional_select(&delta, &(self.c0 * nonresidue_inv), x0_is_zero);
+        let root = square.sqrt_ct();
+
+        let x0 = P::BaseField::conditional_select(&root, &P::BaseField::ZERO, x0_is_zero);
+        let x1 = P::BaseField::conditional_select(
+            &(self.c1 * root.double().inv0_ct()),
+            &root,
+            x0_is_zero,
+        );
+        Self::new(x0, x1)
+    }
+
+    fn sgn0_ct(&self) -> Choice {
+        self.c0.sgn0_ct() | (self.c0.is_zero_ct() & self.c1.sgn0_ct())
+    }
+}
+
+/// Curve groups with the constant-time group operations used by
+/// [`MapToCurveBasedHasher::hash_ct`] and
+/// [`MapToCurveBasedHasher::encode_ct`] after the maps to the curve.
+///
+/// [`MapToCurveBasedHasher::hash_ct`]: crate::hashing::map_to_curve_hasher::MapToCurveBasedHasher::hash_ct
+/// [`MapToCurveBasedHasher::encode_ct`]: crate::hashing::map_to_curve_hasher::MapToCurveBasedHasher::encode_ct
+pub trait CurveGroupCt: CurveGroup {
+    /// Converts `p` to the group in constant time.
+    fn from_affine_ct(p: &Self::Affine) -> Self;
+
+    /// Computes `self + other` in constant time, with complete addition
+    /// formulas.
+    fn add_ct(&self, other: &Self) -> Self;
+
+    /// Clears the cofactor of `self` in constant time, with the Montgomery
+    /// ladder over all the bits of the public scalar by which the cofactor
+    /// clearing of the curve multiplies.
+    fn clear_cofactor_ct(&self) -> Self;
+
+    /// Computes [`CurveGroup::into_affine`] in constant time, with a single
+    /// [`CtField::inv0_ct`].
+    fn into_affine_ct(&self) -> Self::Affine;
+}
+
+/// The additions use the complete formulas of [`SWCurveConfig::COMPLETE_ADDITION`]
+/// in homogeneous coordinates, as [`Projective::mul_ct`] does, so they are
+/// correct on curves without points of order two, such as the curves of odd
+/// order.
+impl<P: SWCurveConfig> CurveGroupCt for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    fn from_affine_ct(p: &Affine<P>) -> Self {
+        Homogeneous::from_affine_ct(p).into_jacobian_ct()
+    }
+
+    fn add_ct(&self, other: &Self) -> Self {
+        Homogeneous::from_jacobian(self)
+            .add(&Homogeneous::from_jacobian(other))
+            .into_jacobian_ct()
+    }
+
+    /// Multiplies `self` by [`SWCurveConfig::EFFECTIVE_COFACTOR`].
+    fn clear_cofactor_ct(&self) -> Self {
+        let h_eff = P::EFFECTIVE_COFACTOR;
+        montgomery_ladder(
+            Homogeneous::ZERO,
+            Homogeneous::from_jacobian(self),
+            h_eff,
+            64 * h_eff.len(),
+            Homogeneous::add,
+        )
+        .into_jacobian_ct()
+    }
+
+    fn into_affine_ct(&self) -> Affine<P> {
+        // `(X, Y, Z)` represents `(X / Z^2, Y / Z^3)`, and the canonical zero
+        // `(1, 1, 0)` maps to the identity `(0, 0)`.
+        let z_inv = self.z.inv0_ct();
+        let z_inv_square = z_inv.square();
+        Affine {
+            x: self.x * z_inv_square,
+            y: self.y * z_inv_square * z_inv,
+            infinity: self.z.is_zero_ct().into(),
+        }
+    }
+}
+
+/// The additions use the unified formulas of [`twisted_edwards::Projective`],
+/// as [`twisted_edwards::Projective::mul_ct`] does, so they are correct when
+/// `a` is a square and `d` is a non-square in the base field.
+impl<P: TECurveConfig> CurveGroupCt for twisted_edwards::Projective<P>
+where
+    P::BaseField: CtField,
+{
+    fn from_affine_ct(p: &twisted_edwards::Affine<P>) -> Self {
+        Self::from(*p)
+    }
+
+    fn add_ct(&self, other: &Self) -> Self {
+        *self + other
+    }
+
+    /// Multiplies `self` by the cofactor, as the default
+    /// [`TECurveConfig::clear_cofactor`] does.
+    fn clear_cofactor_ct(&self) -> Self {
+        montgomery_ladder(
+            Self::zero(),
+            *self,
+            P::COFACTOR,
+            64 * P::COFACTOR.len(),
+            |a, b| *a + b,
+        )
+    }
+
+    fn into_affine_ct(&self) -> twisted_edwards::Affine<P> {
+        let z_inv = self.z.inv0_ct();
+        twisted_edwards::Affine::new_unchecked(self.x * z_inv, self.y * z_inv)
+    }
+}
+
+impl<P: SWUConfig> MapToCurveCt<Projective<P>> for SWUMap<P>
+where
+    P::BaseField: CtField,
+{
+    /// Follows [\[HSSWW23\], Section 6.6.2].
+    fn map_to_curve_ct(element: P::BaseField) -> Affine<P> {
+        let (a, b, z) = (P::COEFF_A, P::COEFF_B, P::ZETA);
+        let g = |x: P::BaseField| (x.square() + a) * x + b;
+
+        // The inverses of public constants need not be constant-time.
+        let a_inv = a.inverse().expect("SWU requires a != 0");
+        let minus_b_over_a = -b * a_inv;
+        let b_over_z_a = b * a_inv * z.inverse().expect("ZETA is a non-square");
+
+        // 1. tv1 = inv0(Z^2 * u^4 + Z * u^2)
+        let z_u2 = z * element.square();
+        let tv1 = (z_u2.square() + z_u2).inv0_ct();
+        // 2. x1 = (-B / A) * (1 + tv1)
+        // 3. If tv1 == 0, set x1 = B / (Z * A)
+        let x1 = P::BaseField::conditional_select(
+            &(minus_b_over_a * (P::BaseField::ONE + tv1)),
+            &b_over_z_a,
+            tv1.is_zero_ct(),
+        );
+        // 4. gx1 = x1^3 + A * x1 + B
+        let gx1 = g(x1);
+        // 5. x2 = Z * u^2 * x1
+        let x2 = z_u2 * x1;
+        // 6. gx2 = x2^3 + A * x2 + B
+        let gx2 = g(x2);
+        // 7. If is_square(gx1), set x = x1 and y = sqrt(gx1)
+        // 8. Else set x = x2 and y = sqrt(gx2)
+        let gx1_is_square = gx1.is_square_ct();
+        let x = P::BaseField::conditional_select(&x2, &x1, gx1_is_square);
+        let y2 = P::BaseField::conditional_select(&gx2, &gx1, gx1_is_square);
+        let y = y2.sqrt_ct();
+        // 9. If sgn0(u) != sgn0(y), set y = -y
+        let y = P::BaseField::conditional_select(&y, &-y, element.sgn0_ct() ^ y.sgn0_ct());
+
+        let point_on_curve = Affine::new_unchecked(x, y);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "swu mapped to a point off the curve"
+        );
+        point_on_curve
+    }
+}
+
+impl<P: Elligator2Config> Elligator2Map<P>
+where
+    P::BaseField: CtField,
+{
+    /// Computes [`Self::map_to_montgomery`] in constant time, following
+    /// [\[HSSWW23\], Section 6.7.1].
+    pub fn map_to_montgomery_ct(element: P::BaseField) -> MontgomeryAffine<P> {
+        let k = <P as MontCurveConfig>::COEFF_B;
+        let j_on_k = P::COEFF_A_OVER_COEFF_B;
+        let ksq_inv = P::ONE_OVER_COEFF_B_SQUARE;
+        let g = |x: P::BaseField| (x.square() + j_on_k * x + ksq_inv) * x;
+
+        // 1. x1 = -(J / K) * inv0(1 + Z * u^2)
+        // 2. If x1 == 0, set x1 = -(J / K)
+        let x1 = -j_on_k * (P::BaseField::ONE + P::Z * element.square()).inv0_ct();
+        let x1 = P::BaseField::conditional_select(&x1, &-j_on_k, x1.is_zero_ct());
+        // 3. gx1 = x1^3 + (J / K) * x1^2 + x1 / K^2
+        let gx1 = g(x1);
+        // 4. x2 = -x1 - (J / K)
+        let x2 = -x1 - j_on_k;
+        // 5. gx2 = x2^3 + (J / K) * x2^2 + x2 / K^2
+        let gx2 = g(x2);
+        // 6. If is_square(gx1), set x = x1, y = sqrt(gx1) with sgn0(y) == 1.
+        // 7. Else set x = x2, y = sqrt(gx2) with sgn0(y) == 0.
+        let gx1_is_square = gx1.is_square_ct();
+        let x = P::BaseField::conditional_select(&x2, &x1, gx1_is_square);
+        let y2 = P::BaseField::conditional_select(&gx2, &gx1, gx1_is_square);
+        let y = y2.sqrt_ct();
+        let y = P::BaseField::conditional_select(&y, &-y, gx1_is_square ^ y.sgn0_ct());
+        // 8. s = x * K
+        // 9. t = y * K
+        MontgomeryAffine::new(x * k, y * k)
+    }
+}
+
+impl<P: Elligator2Config> MapToCurveCt<twisted_edwards::Projective<P>> for Elligator2Map<P>
+where
+    P::BaseField: CtField,
+{
+    /// Applies the rational map of [\[HSSWW23\], Appendix D.1] to
+    /// [`Elligator2Map::map_to_montgomery_ct`].
+    fn map_to_curve_ct(element: P::BaseField) -> twisted_edwards::Affine<P> {
+        let MontgomeryAffine { x: s, y: t } = Self::map_to_montgomery_ct(element);
+        let one = P::BaseField::ONE;
+
+        // 1. tv1 = s + 1
+        // 2. tv2 = tv1 * t
+        // 3. tv2 = inv0(tv2)
+        let tv1 = s + one;
+        let tv2 = (tv1 * t).inv0_ct();
+        // 4. v = tv2 * tv1 * s
+        // 5. w = tv2 * t * (s - 1)
+        // 6. e = tv2 == 0, in which case the output is the identity.
+        let is_exceptional = tv2.is_zero_ct();
+        let v = tv2 * tv1 * s;
+        let w = P::BaseField::conditional_select(&(tv2 * t * (s - one)), &one, is_exceptional);
+
+        let point_on_curve = twisted_edwards::Affine::new_unchecked(v, w);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "Elligator2 mapped to a point off the curve"
+        );
+        point_on_curve
+    }
+}
+
+impl<P: SvdWConfig> MapToCurveCt<Projective<P>> for SvdWMap<P>
+where
+    P::BaseField: CtField,
+{
+    /// Follows the straight-line procedure of [\[HSSWW23\], Appendix F.1].
+    fn map_to_curve_ct(element: P::BaseField) -> Affine<P> {
+        let (c1, c2, c3, c4) = (P::C1, P::C2, P::C3, P::C4);
+        let g = |x: P::BaseField| (x.square() + P::COEFF_A) * x + P::COEFF_B;
+        let one = P::BaseField::ONE;
+
+        // tv1 = u^2 * c1, tv2 = 1 + tv1, tv1 = 1 - tv1
+        let tv1 = element.square() * c1;
+        let tv2 = one + tv1;
+        let tv1 = one - tv1;
+        // tv3 = inv0(tv1 * tv2)
+        let tv3 = (tv1 * tv2).inv0_ct();
+        // tv4 = u * tv1 * tv3 * c3
+        let tv4 = element * tv1 * tv3 * c3;
+
+        // x1 = c2 - tv4, e1 = is_square(g(x1))
+        let x1 = c2 - tv4;
+        let e1 = g(x1).is_square_ct();
+        // x2 = c2 + tv4, e2 = is_square(g(x2)) AND NOT e1
+        let x2 = c2 + tv4;
+        let e2 = g(x2).is_square_ct() & !e1;
+        // x3 = Z + c4 * (tv2^2 * tv3)^2
+        let x3 = P::Z + c4 * (tv2.square() * tv3).square();
+
+        // x = CMOV(x3, x1, e1), x = CMOV(x, x2, e2)
+        let x = P::BaseField::conditional_select(&x3, &x1, e1);
+        let x = P::BaseField::conditional_select(&x, &x2, e2);
+        let y = g(x).sqrt_ct();
+        // y = CMOV(-y, y, sgn0(u) == sgn0(y))
+        let y = P::BaseField::conditional_select(&y, &-y, element.sgn0_ct() ^ y.sgn0_ct());
+
+        let point_on_curve = Affine::new_unchecked(x, y);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "SvdW mapped to a point off the curve"
+        );
+        point_on_curve
+    }
+}
+
+impl<P: WBConfig> MapToCurveCt<Projective<P>> for WBMap<P>
+where
+    P::BaseField: CtField,
+{
+    /// Applies [`WBConfig::ISOGENY_MAP`] to the constant-time SWU map, with a
+    /// single inversion for both denominators of the isogeny. The points of
+    /// its kernel map to the identity.
+    fn map_to_curve_ct(element: P::BaseField) -> Affine<P> {
+        let Affine { x, y, .. } = SWUMap::<P::IsogenousCurve>::map_to_curve_ct(element);
+        let map = P::ISOGENY_MAP;
+
+        let x_den = evaluate(map.x_map_denominator, x);
+        let y_den = evaluate(map.y_map_denominator, x);
+        let inv = (x_den * y_den).inv0_ct();
+        let img_x = evaluate(map.x_map_numerator, x) * y_den * inv;
+        let img_y = evaluate(map.y_map_numerator, x) * y * x_den * inv;
+
+        // Both coordinates are zero when `inv` is, as for the identity.
+        let point_on_curve = Affine {
+            x: img_x,
+            y: img_y,
+            infinity: inv.is_zero_ct().into(),
+        };
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "WB mapped to a point off the curve"
+        );
+        point_on_curve
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{
+        ark_ec::hashing::{
+            curve_maps::{
+                ct::{CtField, MapToCurveCt},
+                elligator2::Elligator2Map,
+                parity,
+                wb::WBMap,
+            },
+            map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+            HashToCurve,
+        },
+        ark_ff::{field_hashers::DefaultFieldHasher, AdditiveGroup, Field},
+        bls12_381, ed_on_bls12_381,
+    };
+    use sha2::Sha256;
+
+    /// Checks the operations of [`CtField`] against the variable-time ones,
+    /// including on the elements of the base field and their multiples by
+    /// the non-residue, which are the special cases of the square roots in
+    /// quadratic extensions.
+    macro_rules! test_ct_field {
+        ($name:ident, $field:ty, $from_base:expr) => {
+            #[test]
+            fn $name() {
+                let rng = &mut test_rng();
+                let mut elements = vec![<$field>::ZERO, <$field>::ONE, -<$field>::ONE];
+                for _ in 0..10 {
+                    elements.push(<$field>::rand(rng));
+                    elements.extend($from_base(rng));
+                }
+                for a in elements {
+                    assert_eq!(a.inv0_ct(), a.inverse().unwrap_or(<$field>::ZERO));
+                    assert_eq!(bool::from(a.is_square_ct()), !a.legendre().is_qnr());
+                    assert_eq!(bool::from(a.sgn0_ct()), parity(&a));
+                    let square = a.square();
+                    assert!(bool::from(square.is_square_ct()));
+                    assert_eq!(square.sqrt_ct().square(), square);
+                }
+            }
+        };
+    }
+
+    test_ct_field!(test_ct_field_bls12_381_fq, bls12_381::Fq, |_| []);
+    test_ct_field!(test_ct_field_bls12_381_fr, ed_on_bls12_381::Fq, |_| []);
+    test_ct_field!(
+        test_ct_field_bls12_381_fq2,
+        bls12_381::Fq2,
+        |rng: &mut _| {
+            let c = bls12_381::Fq::rand(rng);
+            [
+                bls12_381::Fq2::new(c, bls12_381::Fq::ZERO),
+                bls12_381::Fq2::new(bls12_381::Fq::ZERO, c),
+            ]
+        }
+    );
+
+    /// Checks that the constant-time map agrees with the map, on the
+    /// BLS12-381 base field and its quadratic extension, of two-adicity 1
+    /// and 3, and on the BLS12-381 scalar field, of two-adicity 32, on which
+    /// the constant-time Tonelli-Shanks loops.
+    macro_rules! test_map_to_curve_ct {
+        ($name:ident, $map:ty, $field:ty) => {
+            #[test]
+            fn $name() {
+                let rng = &mut test_rng();
+                let inputs = [<$field>::ZERO, <$field>::ONE, -<$field>::ONE];
+                let inputs = inputs
+                    .into_iter()
+                    .chain((0..20).map(|_| <$field>::rand(rng)));
+                for u in inputs {
+                    assert_eq!(<$map>::map_to_curve_ct(u), <$map>::map_to_curve(u).unwrap());
+                }
+            }
+        };
+    }
+
+    test_map_to_curve_ct!(
+        test_map_to_curve_ct_bls12_381_g1,
+        WBMap<bls12_381::g1::Config>,
+        bls12_381::Fq
+    );
+    test_map_to_curve_ct!(
+        test_map_to_curve_ct_bls12_381_g2,
+        WBMap<bls12_381::g2::Config>,
+        bls12_381::Fq2
+    );
+    test_map_to_curve_ct!(
+        test_map_to_curve_ct_jubjub,
+        Elligator2Map<ed_on_bls12_381::EdwardsConfig>,
+        ed_on_bls12_381::Fq
+    );
+
+    /// Checks that the constant-time hashes agree with the usual ones,
+    /// including the cofactor clearing.
+    macro_rules! test_hash_ct {
+        ($name:ident, $group:ty, $map:ty) => {
+            #[test]
+            fn $name() {
+                let hasher =
+                    MapToCurveBasedHasher::<$group, DefaultFieldHasher<Sha256, 128>, $map>::new(
+                        b"ct-test",
+                    )
+                    .unwrap();
+                let msgs: [&[u8]; 3] = [b"", b"abc", &[0xa5; 512]];
+                for msg in msgs {
+                    assert_eq!(hasher.hash_ct(msg).unwrap(), hasher.hash(msg).unwrap());
+                    assert_eq!(hasher.encode_ct(msg).unwrap(), hasher.encode(msg).unwrap());
+                }
+            }
+        };
+    }
+
+    test_hash_ct!(
+        test_hash_ct_bls12_381_g1,
+        bls12_381::G1Projective,
+        WBMap<bls12_381::g1::Config>
+    );
+    test_hash_ct!(
+        test_hash_ct_bls12_381_g2,
+        bls12_381::G2Projective,
+        WBMap<bls12_381::g2::Config>
+    );
+    test_hash_ct!(
+        test_hash_ct_jubjub,
+        ed_on_bls12_381::Projective,
+        Elligator2Map<ed_on_bls12_381::EdwardsConfig>
+    );
+}
@@ -1,5 +1,6 @@
-use crate::models::twisted_edwards::{MontCurveConfig, TECurveConfig};
-use ark_ff::{Field, One, Zero};
+use crate::models::twisted_edwards::{MontCurveConfig, MontgomeryAffine, TECurveConfig};
+use ark_ff::{batch_inversion, Field, One, Zero};
+use ark_std::vec::*;
 use core::marker::PhantomData;
 
 use crate::{
@@ -62,6 +63,46 @@ impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
 
     /// Map an arbitrary base field element `element` to a curve point.
     fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let point_on_curve = Self::map_to_montgomery(element).to_twisted_edwards_on::<P>();
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "Elligator2 mapped to a point off the curve"
+        );
+        Ok(point_on_curve)
+    }
+
+    /// Map a batch of base field elements to curve points, sharing the field
+    /// inversions of the map to the Montgomery curve, and of the conversion
+    /// to the twisted Edwards curve, across the batch, and computing the
+    /// square roots with [`Field::batch_sqrt`].
+    fn map_to_curve_batch(elements: &[P::BaseField]) -> Result<Vec<Affine<P>>, HashToCurveError> {
+        let points = Self::map_to_montgomery_batch(elements);
+        let mut den_invs: Vec<_> = points
+            .iter()
+            .map(|p| p.twisted_edwards_denominator())
+            .collect();
+        batch_inversion(&mut den_invs);
+        Ok(points
+            .iter()
+            .zip(den_invs)
+            .map(|(p, den_inv)| {
+                let den_inv = (!den_inv.is_zero()).then_some(den_inv);
+                let point_on_curve = p.to_twisted_edwards_with_inverse::<P>(den_inv);
+                debug_assert!(
+                    point_on_curve.is_on_curve(),
+                    "Elligator2 mapped to a point off the curve"
+                );
+                point_on_curve
+            })
+            .collect())
+    }
+}
+
+impl<P: Elligator2Config> Elligator2Map<P> {
+    /// Maps `element` to a point on the Montgomery curve defined by `P`,
+    /// following [\[HSSWW23\]] Section 6.7.1. [`MapToCurve::map_to_curve`]
+    /// then applies [`MontgomeryAffine::to_twisted_edwards`] to this point.
+    pub fn map_to_montgomery(element: P::BaseField) -> MontgomeryAffine<P> {
         // 1. x1 = -(J / K) * inv0(1 + Z * u^2)
         // 2. If x1 == 0, set x1 = -(J / K)
         // 3. gx1 = x1^3 + (J / K) * x1^2 + x1 / K^2
@@ -73,20 +114,74 @@ impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
         // 9. t = y * K
         // 10. return (s, t)
 
+        let den_inv = Self::x1_denominator(element)
+            .inverse()
+            .expect("the denominator of x1 is non-zero. Q.E.D");
+        let [(x1, gx1), (x2, gx2)] = Self::candidates(den_inv);
+        match gx1.sqrt() {
+            Some(y1) => Self::montgomery_point(x1, y1, true),
+            None => {
+                let y2 = gx2
+                    .sqrt()
+                    .expect("gx2 is a quadratic residue because gx1 is not. Q.E.D");
+                Self::montgomery_point(x2, y2, false)
+            },
+        }
+    }
+
+    /// Computes [`Self::map_to_montgomery`] for a batch of elements, with a
+    /// single field inversion, and the square roots of `gx1`, then of `gx2`
+    /// where `gx1` is not square, computed with [`Field::batch_sqrt`].
+    fn map_to_montgomery_batch(elements: &[P::BaseField]) -> Vec<MontgomeryAffine<P>> {
+        let mut den_invs: Vec<_> = elements.iter().map(|&e| Self::x1_denominator(e)).collect();
+        batch_inversion(&mut den_invs);
+        let candidates: Vec<_> = den_invs.into_iter().map(Self::candidates).collect();
+
+        let gx1s: Vec<_> = candidates.iter().map(|[(_, gx1), _]| *gx1).collect();
+        let gx1_roots = P::BaseField::batch_sqrt(&gx1s);
+        let gx2s: Vec<_> = candidates
+            .iter()
+            .zip(&gx1_roots)
+            .filter(|(_, y1)| y1.is_none())
+            .map(|([_, (_, gx2)], _)| *gx2)
+            .collect();
+        let mut gx2_roots = P::BaseField::batch_sqrt(&gx2s).into_iter();
+
+        candidates
+            .into_iter()
+            .zip(gx1_roots)
+            .map(|([(x1, _), (x2, _)], y1)| match y1 {
+                Some(y1) => Self::montgomery_point(x1, y1, true),
+                None => {
+                    let y2 = gx2_roots
+                        .next()
+                        .flatten()
+                        .expect("gx2 is a quadratic residue because gx1 is not. Q.E.D");
+                    Self::montgomery_point(x2, y2, false)
+                },
+            })
+            .collect()
+    }
+
+    /// Returns the denominator `1 + Z * u^2` of `x1`, or one if it is zero.
+    fn x1_denominator(element: P::BaseField) -> P::BaseField {
+        let den_1 = <P::BaseField as One>::one() + P::Z * element.square();
+        if den_1.is_zero() {
+            <P::BaseField as One>::one()
+        } else {
+            den_1
+        }
+    }
+
+    /// Returns `(x1, gx1)` and `(x2, gx2)`, given the inverse of
+    /// [`Self::x1_denominator`].
+    fn candidates(den_inv: P::BaseField) -> [(P::BaseField, P::BaseField); 2] {
         // ark a is irtf J
         // ark b is irtf k
-        let k = <P as MontCurveConfig>::COEFF_B;
         let j_on_k = P::COEFF_A_OVER_COEFF_B;
         let ksq_inv = P::ONE_OVER_COEFF_B_SQUARE;
 
-        let den_1 = <P::BaseField as One>::one() + P::Z * element.square();
-
-        let x1 = -j_on_k
-            / (if den_1.is_zero() {
-                <P::BaseField as One>::one()
-            } else {
-                den_1
-            });
+        let x1 = -j_on_k * den_inv;
         let x1sq = x1.square();
         let x1cb = x1sq * x1;
         let gx1 = x1cb + j_on_k * x1sq + x1 * ksq_inv;
@@ -96,54 +191,17 @@ impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
         let x2cb = x2sq * x2;
         let gx2 = x2cb + j_on_k * x2sq + x2 * ksq_inv;
 
-        let (x, mut y, sgn0) = if gx1.legendre().is_qr() {
-            (
-                x1,
-                gx1.sqrt()
-                    .expect("We have checked that gx1 is a quadratic residue. Q.E.D"),
-                true,
-            )
-        } else {
-            (
-                x2,
-                gx2.sqrt()
-                    .expect("gx2 is a quadratic residue because gx1 is not. Q.E.D"),
-                false,
-            )
-        };
+        [(x1, gx1), (x2, gx2)]
+    }
 
+    /// Returns the point `(x * K, y * K)`, choosing the sign of `y` so that
+    /// `sgn0(y) == sgn0`.
+    fn montgomery_point(x: P::BaseField, mut y: P::BaseField, sgn0: bool) -> MontgomeryAffine<P> {
         if parity(&y) != sgn0 {
             y = -y;
         }
-
-        let s = x * k;
-        let t = y * k;
-
-        // `(s, t)` is an affine point on the Montgomery curve.
-        // Ideally, the TECurve would come with a mapping to its Montgomery curve,
-        // so we could just call that mapping here.
-        // This is currently not supported in arkworks, so
-        // we just implement the rational map here from [\[HSSWW23\]] Appendix D
-
-        let tv1 = s + <P::BaseField as One>::one();
-        let tv2 = tv1 * t;
-        let (v, w) = if tv2.is_zero() {
-            (<P::BaseField as Zero>::zero(), <P::BaseField as One>::one())
-        } else {
-            let tv2_inv = tv2
-                .inverse()
-                .expect("None zero element has inverse. Q.E.D.");
-            let v = tv2_inv * tv1 * s;
-            let w = tv2_inv * t * (s - <P::BaseField as One>::one());
-            (v, w)
-        };
-
-        let point_on_curve = Affine::<P>::new_unchecked(v, w);
-        debug_assert!(
-            point_on_curve.is_on_curve(),
-            "Elligator2 mapped to a point off the curve"
-        );
-        Ok(point_on_curve)
+        let k = <P as MontCurveConfig>::COEFF_B;
+        MontgomeryAffine::new(x * k, y * k)
     }
 }
 
@@ -311,4 +369,53 @@ mod test {
             "a constant hash function is not good."
         );
     }
+
+    /// The map to the Montgomery curve lands on the curve, and composing it
+    /// with the rational map gives the map to the twisted Edwards curve.
+    #[test]
+    fn map_field_to_montgomery_elligator2() {
+        for u in 0..101u64 {
+            let u = F101::from(u);
+            let p = Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_montgomery(u);
+            assert!(p.is_on_curve());
+            assert_eq!(
+                p.to_twisted_edwards(),
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve(u).unwrap()
+            );
+        }
+    }
+
+    /// Mapping the whole field in a batch must agree with mapping each
+    /// element on its own.
+    #[test]
+    fn map_field_to_curve_elligator2_batch() {
+        let elements: Vec<F101> = (0..101u64).map(F101::from).collect();
+        let batch =
+            Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve_batch(&elements).unwrap();
+        for (element, point) in elements.into_iter().zip(batch) {
+            assert_eq!(
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve(element).unwrap(),
+                point
+            );
+        }
+    }
+
+    /// The constant-time maps must agree with the maps on the whole field.
+    #[cfg(feature = "ct")]
+    #[test]
+    fn map_field_to_curve_elligator2_ct() {
+        use crate::hashing::curve_maps::ct::MapToCurveCt;
+
+        for u in 0..101u64 {
+            let u = F101::from(u);
+            assert_eq!(
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_montgomery_ct(u),
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_montgomery(u)
+            );
+            assert_eq!(
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve_ct(u),
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve(u).unwrap()
+            );
+        }
+    }
 }
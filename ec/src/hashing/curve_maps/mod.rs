@@ -1,5 +1,8 @@
 use ark_ff::{BigInteger, Field, PrimeField, Zero};
+#[cfg(feature = "ct")]
+pub mod ct;
 pub mod elligator2;
+pub mod svdw;
 pub mod swu;
 pub mod wb;
 
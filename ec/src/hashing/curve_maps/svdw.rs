@@ -0,0 +1,314 @@
+use crate::models::short_weierstrass::SWCurveConfig;
+use ark_ff::{batch_inversion, AdditiveGroup, Field, One, Zero};
+use ark_std::{vec, vec::*};
+use core::marker::PhantomData;
+
+use crate::{
+    hashing::{curve_maps::parity, map_to_curve_hasher::MapToCurve, HashToCurveError},
+    models::short_weierstrass::{Affine, Projective},
+};
+
+/// Trait defining the necessary parameters for the Shallue–van de Woestijne
+/// hash-to-curve method for curves of Weierstrass form
+/// `y^2 = x^3 + a*x + b`, from [\[SvdW06\]], according to [\[HSSWW23\]].
+///
+/// Unlike the simplified SWU map, this map applies to every short
+/// Weierstrass curve, including those with `a = 0` or `b = 0`, at the cost
+/// of a few more operations per map.
+///
+/// - [\[SvdW06\]] <https://doi.org/10.1007/11792086_36>
+/// - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+pub trait SvdWConfig: SWCurveConfig {
+    /// An element of the base field satisfying the conditions of
+    /// [\[HSSWW23\], Section 6.6.1]: with `g(x) = x^3 + a*x + b`, `g(Z)` and
+    /// `3 * Z^2 + 4 * a` are non-zero, `-(3 * Z^2 + 4 * a) / (4 * g(Z))` is a
+    /// non-zero square, and at least one of `g(Z)` and `g(-Z / 2)` is square.
+    /// [\[HSSWW23\]] mandates picking such a `Z` with the lowest absolute value.
+    const Z: Self::BaseField;
+
+    /// This must be equal to `g(Z)`.
+    const C1: Self::BaseField;
+
+    /// This must be equal to `-Z / 2`.
+    const C2: Self::BaseField;
+
+    /// This must be equal to `sqrt(-g(Z) * (3 * Z^2 + 4 * a))`, with
+    /// `sgn0(C3) == 0`.
+    const C3: Self::BaseField;
+
+    /// This must be equal to `-4 * g(Z) / (3 * Z^2 + 4 * a)`.
+    const C4: Self::BaseField;
+}
+
+/// Represents the SvdW hash-to-curve map defined by `P`.
+pub struct SvdWMap<P: SvdWConfig>(PhantomData<fn() -> P>);
+
+impl<P: SvdWConfig> SvdWMap<P> {
+    /// Evaluates `g(x) = x^3 + a*x + b`.
+    fn g(x: P::BaseField) -> P::BaseField {
+        (x.square() + P::COEFF_A) * x + P::COEFF_B
+    }
+}
+
+impl<P: SvdWConfig> MapToCurve<Projective<P>> for SvdWMap<P> {
+    /// Checks if `P` represents a valid map.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        let invalid_z =
+            || HashToCurveError::MapToCurveError("Z does not satisfy the SvdW requirements".into());
+        let g_z = Self::g(P::Z);
+        let h_z = P::Z.square() * P::BaseField::from(3u8) + P::COEFF_A.double().double();
+        if g_z.is_zero() {
+            return Err(invalid_z());
+        }
+        let h_z_inv = h_z.inverse().ok_or_else(invalid_z)?;
+        let c4 = -g_z.double().double() * h_z_inv;
+        // c4 is the inverse of -(3 * Z^2 + 4 * A) / (4 * g(Z)), which must be a
+        // non-zero square.
+        if !c4.legendre().is_qr() {
+            return Err(invalid_z());
+        }
+        let mut c3 = (-g_z * h_z).sqrt().ok_or_else(invalid_z)?;
+        if parity(&c3) {
+            c3 = -c3;
+        }
+
+        debug_assert!(
+            g_z.legendre().is_qr() || Self::g(P::C2).legendre().is_qr(),
+            "one of g(Z) and g(-Z / 2) should be a square for the SvdW map"
+        );
+        debug_assert_eq!(P::C1, g_z, "C1 is not equal to g(Z)");
+        debug_assert_eq!(P::C2.double(), -P::Z, "C2 is not equal to -Z / 2");
+        debug_assert_eq!(
+            P::C3,
+            c3,
+            "C3 is not equal to sqrt(-g(Z) * (3 * Z^2 + 4 * A)) with sgn0(C3) == 0"
+        );
+        debug_assert_eq!(
+            P::C4,
+            c4,
+            "C4 is not equal to -4 * g(Z) / (3 * Z^2 + 4 * A)"
+        );
+        Ok(())
+    }
+
+    /// Map an arbitrary base field element to a curve point, following the
+    /// straight-line procedure of [\[HSSWW23\], Appendix F.1].
+    fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        // tv3 = inv0(tv1 * tv2)
+        let tv3 = Self::denominator(element).inverse().unwrap_or_default();
+        let [x1, x2, x3] = Self::candidates(element, tv3);
+
+        // x = x1 if g(x1) is square, else x2 if g(x2) is square, else x3.
+        let (x, y) = [x1, x2]
+            .into_iter()
+            .find_map(|x| Some((x, Self::g(x).sqrt()?)))
+            .unwrap_or_else(|| {
+                let y = Self::g(x3)
+                    .sqrt()
+                    .expect("one of g(x1), g(x2) and g(x3) is a square. Q.E.D");
+                (x3, y)
+            });
+        Ok(Self::point(element, x, y))
+    }
+
+    /// Map a batch of base field elements to curve points, computing the
+    /// inverses required by all of them with a single field inversion, and
+    /// the square roots of `g(x1)`, then of `g(x2)` and `g(x3)` for the
+    /// elements that need them, with [`Field::batch_sqrt`].
+    fn map_to_curve_batch(elements: &[P::BaseField]) -> Result<Vec<Affine<P>>, HashToCurveError> {
+        // Zero elements are left unchanged by the batch inversion, as by inv0.
+        let mut tv3s: Vec<_> = elements.iter().map(|&e| Self::denominator(e)).collect();
+        batch_inversion(&mut tv3s);
+        let candidates: Vec<_> = elements
+            .iter()
+            .zip(tv3s)
+            .map(|(&element, tv3)| Self::candidates(element, tv3))
+            .collect();
+
+        let mut points = vec![None; elements.len()];
+        for i in 0..3 {
+            let pending: Vec<_> = (0..elements.len())
+                .filter(|&j| points[j].is_none())
+                .collect();
+            let gs: Vec<_> = pending.iter().map(|&j| Self::g(candidates[j][i])).collect();
+            for (&j, y) in pending.iter().zip(P::BaseField::batch_sqrt(&gs)) {
+                points[j] = y.map(|y| (candidates[j][i], y));
+            }
+        }
+
+        Ok(elements
+            .iter()
+            .zip(points)
+            .map(|(&element, point)| {
+                let (x, y) = point.expect("one of g(x1), g(x2) and g(x3) is a square. Q.E.D");
+                Self::point(element, x, y)
+            })
+            .collect())
+    }
+}
+
+impl<P: SvdWConfig> SvdWMap<P> {
+    /// Computes `tv1 * tv2 = (1 - u^2 * c1) * (1 + u^2 * c1)`, the value
+    /// inverted by the map.
+    fn denominator(element: P::BaseField) -> P::BaseField {
+        let tv1 = element.square() * P::C1;
+        (P::BaseField::one() - tv1) * (P::BaseField::one() + tv1)
+    }
+
+    /// Computes the candidates `x1`, `x2` and `x3` for the `x`-coordinate,
+    /// given `tv3 = inv0(tv1 * tv2)`.
+    fn candidates(element: P::BaseField, tv3: P::BaseField) -> [P::BaseField; 3] {
+        let (c1, c2, c3, c4) = (P::C1, P::C2, P::C3, P::C4);
+
+        // tv1 = u^2 * c1
+        let tv1 = element.square() * c1;
+        // tv2 = 1 + tv1
+        let tv2 = P::BaseField::one() + tv1;
+        // tv1 = 1 - tv1
+        let tv1 = P::BaseField::one() - tv1;
+        // tv4 = u * tv1 * tv3 * c3
+        let tv4 = element * tv1 * tv3 * c3;
+
+        // x1 = c2 - tv4
+        let x1 = c2 - tv4;
+        // x2 = c2 + tv4
+        let x2 = c2 + tv4;
+        // x3 = Z + c4 * (tv2^2 * tv3)^2
+        let x3 = P::Z + c4 * (tv2.square() * tv3).square();
+        [x1, x2, x3]
+    }
+
+    /// Returns the point `(x, y)`, after fixing the sign of `y` to match that
+    /// of `element`.
+    fn point(element: P::BaseField, x: P::BaseField, mut y: P::BaseField) -> Affine<P> {
+        if parity(&y) != parity(&element) {
+            y = -y;
+        }
+
+        let point_on_curve = Affine::<P>::new_unchecked(x, y);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "SvdW mapped to a point off the curve"
+        );
+        point_on_curve
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        hashing::{map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+        CurveConfig,
+    };
+    use ark_ff::field_hashers::DefaultFieldHasher;
+    use ark_std::vec::*;
+
+    use super::*;
+    use ark_ff::{fields::Fp64, MontBackend, MontFp};
+    use sha2::Sha256;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "127"]
+    #[generator = "6"]
+    pub struct F127Config;
+    pub type F127 = Fp64<MontBackend<F127Config, 1>>;
+
+    struct TestSvdWMapToCurveConfig;
+
+    impl CurveConfig for TestSvdWMapToCurveConfig {
+        const COFACTOR: &'static [u64] = &[1];
+
+        const COFACTOR_INV: F127 = MontFp!("1");
+
+        type BaseField = F127;
+        type ScalarField = F127;
+    }
+
+    /// y^2 = x^3 + 3, which has `a = 0` and 127 points, and so has neither an
+    /// SWU map nor a convenient isogeny.
+    impl SWCurveConfig for TestSvdWMapToCurveConfig {
+        const COEFF_A: F127 = MontFp!("0");
+
+        const COEFF_B: F127 = MontFp!("3");
+
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(MontFp!("1"), MontFp!("2"));
+    }
+
+    /// The smallest valid `Z`, found with `find_z_svdw` of [\[HSSWW23\], Appendix H.1].
+    impl SvdWConfig for TestSvdWMapToCurveConfig {
+        const Z: F127 = MontFp!("1");
+
+        const C1: F127 = MontFp!("4");
+
+        const C2: F127 = MontFp!("63");
+
+        const C3: F127 = MontFp!("78");
+
+        const C4: F127 = MontFp!("37");
+    }
+
+    #[test]
+    fn hash_arbitrary_string_to_curve_svdw() {
+        let test_svdw_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestSvdWMapToCurveConfig>,
+            DefaultFieldHasher<Sha256, 128>,
+            SvdWMap<TestSvdWMapToCurveConfig>,
+        >::new(&[1])
+        .unwrap();
+
+        let hash_result = test_svdw_to_curve_hasher
+            .hash(b"if you stick a Babel fish in your ear you can instantly understand anything said to you in any form of language.")
+            .expect("fail to hash the string to curve");
+
+        assert!(
+            hash_result.is_on_curve(),
+            "hash results into a point off the curve"
+        );
+    }
+
+    /// Map the whole field to the curve: every element must map to a point
+    /// on the curve, and the map must hit many different points.
+    #[test]
+    fn map_field_to_curve_svdw() {
+        SvdWMap::<TestSvdWMapToCurveConfig>::check_parameters().unwrap();
+
+        let mut map_range: Vec<Affine<TestSvdWMapToCurveConfig>> = vec![];
+        for u in 0..127u64 {
+            let p = SvdWMap::<TestSvdWMapToCurveConfig>::map_to_curve(F127::from(u)).unwrap();
+            assert!(p.is_on_curve());
+            map_range.push(p);
+        }
+        map_range.sort_by_key(|p| (p.x, p.y));
+        map_range.dedup();
+        assert_eq!(map_range.len(), 67);
+    }
+
+    /// Mapping the whole field in a batch must agree with mapping each
+    /// element on its own.
+    #[test]
+    fn map_field_to_curve_svdw_batch() {
+        let elements: Vec<F127> = (0..127u64).map(F127::from).collect();
+        let batch = SvdWMap::<TestSvdWMapToCurveConfig>::map_to_curve_batch(&elements).unwrap();
+        for (element, point) in elements.into_iter().zip(batch) {
+            assert_eq!(
+                SvdWMap::<TestSvdWMapToCurveConfig>::map_to_curve(element).unwrap(),
+                point
+            );
+        }
+    }
+
+    /// The constant-time map must agree with the map on the whole field.
+    #[cfg(feature = "ct")]
+    #[test]
+    fn map_field_to_curve_svdw_ct() {
+        use crate::hashing::curve_maps::ct::MapToCurveCt;
+
+        for u in 0..127u64 {
+            let u = F127::from(u);
+            assert_eq!(
+                SvdWMap::<TestSvdWMapToCurveConfig>::map_to_curve_ct(u),
+                SvdWMap::<TestSvdWMapToCurveConfig>::map_to_curve(u).unwrap()
+            );
+        }
+    }
+}
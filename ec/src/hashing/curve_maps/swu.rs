@@ -1,5 +1,6 @@
 use crate::models::short_weierstrass::SWCurveConfig;
-use ark_ff::{Field, One, Zero};
+use ark_ff::{batch_inversion, Field, One, Zero};
+use ark_std::vec::*;
 use core::marker::PhantomData;
 
 use crate::{
@@ -43,6 +44,76 @@ impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
     /// Based on
     /// <https://github.com/zcash/pasta_curves/blob/main/src/hashtocurve.rs>.
     fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let (_, _, div) = Self::x1(element);
+        let div3_inv = (div.square() * div)
+            .inverse()
+            .expect("we have checked that neither a or ZETA are zero. Q.E.D.");
+        let gx1 = Self::gx1(element, div3_inv);
+        let (y1, gx1_square) = match gx1.sqrt() {
+            Some(y1) => (y1, true),
+            None => {
+                let y1 = (P::ZETA * gx1).sqrt().expect(
+                    "ZETA * gx1 is a quadratic residue because legard is multiplicative. Q.E.D",
+                );
+                (y1, false)
+            },
+        };
+        Ok(Self::map_to_curve_with_sqrt(
+            element, div3_inv, y1, gx1_square,
+        ))
+    }
+
+    /// Map a batch of base field elements to curve points, computing the
+    /// inverses required by all of them with a single field inversion, and
+    /// their square roots with [`Field::batch_sqrt`]: first those of `gx1`,
+    /// then those of `ZETA * gx1` for the elements whose `gx1` is not square.
+    fn map_to_curve_batch(elements: &[P::BaseField]) -> Result<Vec<Affine<P>>, HashToCurveError> {
+        let mut div3_invs: Vec<_> = elements
+            .iter()
+            .map(|&element| {
+                let (_, _, div) = Self::x1(element);
+                div.square() * div
+            })
+            .collect();
+        batch_inversion(&mut div3_invs);
+
+        let gx1s: Vec<_> = elements
+            .iter()
+            .zip(&div3_invs)
+            .map(|(&element, &div3_inv)| Self::gx1(element, div3_inv))
+            .collect();
+        let gx1_roots = P::BaseField::batch_sqrt(&gx1s);
+        let zeta_gx1s: Vec<_> = gx1s
+            .iter()
+            .zip(&gx1_roots)
+            .filter(|(_, y1)| y1.is_none())
+            .map(|(&gx1, _)| P::ZETA * gx1)
+            .collect();
+        let mut zeta_gx1_roots = P::BaseField::batch_sqrt(&zeta_gx1s).into_iter();
+
+        Ok(elements
+            .iter()
+            .zip(div3_invs)
+            .zip(gx1_roots)
+            .map(|((&element, div3_inv), y1)| {
+                let (y1, gx1_square) = match y1 {
+                    Some(y1) => (y1, true),
+                    None => {
+                        let y1 = zeta_gx1_roots.next().flatten().expect(
+                            "ZETA * gx1 is a quadratic residue because legard is multiplicative. Q.E.D",
+                        );
+                        (y1, false)
+                    },
+                };
+                Self::map_to_curve_with_sqrt(element, div3_inv, y1, gx1_square)
+            })
+            .collect())
+    }
+}
+
+impl<P: SWUConfig> SWUMap<P> {
+    /// Computes `Z * u^2` and the numerator and denominator of `x1`.
+    fn x1(element: P::BaseField) -> (P::BaseField, P::BaseField, P::BaseField) {
         // 1. tv1 = inv0(Z^2 * u^4 + Z * u^2)
         // 2. x1 = (-B / A) * (1 + tv1)
         // 3. If tv1 == 0, set x1 = B / (Z * A)
@@ -64,18 +135,37 @@ impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
         // Using the "here" names:
         //    x1 = num_x1/div      = [B*(Z^2 * u^4 + Z * u^2 + 1)] / [-A*(Z^2 * u^4 + Z * u^2]
         //   gx1 = num_gx1/div_gx1 = [num_x1^3 + A * num_x1 * div^2 + B * div^3] / div^3
-        let a = P::COEFF_A;
-        let b = P::COEFF_B;
-
         let zeta_u2 = P::ZETA * element.square();
         let ta = zeta_u2.square() + zeta_u2;
-        let num_x1 = b * (ta + <P::BaseField as One>::one());
-        let div = a * if ta.is_zero() { P::ZETA } else { -ta };
+        let num_x1 = P::COEFF_B * (ta + <P::BaseField as One>::one());
+        let div = P::COEFF_A * if ta.is_zero() { P::ZETA } else { -ta };
+        (zeta_u2, num_x1, div)
+    }
 
-        let num2_x1 = num_x1.square();
+    /// Computes `gx1 = g(x1)`, given the inverse of `div^3`.
+    fn gx1(element: P::BaseField, div3_inv: P::BaseField) -> P::BaseField {
+        let (_, num_x1, div) = Self::x1(element);
         let div2 = div.square();
         let div3 = div2 * div;
-        let num_gx1 = (num2_x1 + a * div2) * num_x1 + b * div3;
+        debug_assert!(
+            !div3.is_zero(),
+            "we have checked that neither a or ZETA are zero. Q.E.D."
+        );
+        let num_gx1 = (num_x1.square() + P::COEFF_A * div2) * num_x1 + P::COEFF_B * div3;
+        num_gx1 * div3_inv
+    }
+
+    /// Maps `element` to the curve, given the inverse of `div^3` and `y1`,
+    /// which is a square root of `gx1` if `gx1_square`, and of `ZETA * gx1`
+    /// otherwise, so that the map itself needs no inversion or square root.
+    fn map_to_curve_with_sqrt(
+        element: P::BaseField,
+        div3_inv: P::BaseField,
+        y1: P::BaseField,
+        gx1_square: bool,
+    ) -> Affine<P> {
+        let (zeta_u2, num_x1, div) = Self::x1(element);
+        let div2 = div.square();
 
         // 5. x2 = Z * u^2 * x1
         let num_x2 = zeta_u2 * num_x1; // same div
@@ -83,28 +173,7 @@ impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
         // 6. gx2 = x2^3 + A * x2 + B  [optimized out; see below]
         // 7. If is_square(gx1), set x = x1 and y = sqrt(gx1)
         // 8. Else set x = x2 and y = sqrt(gx2)
-        let gx1_square;
-        let gx1;
-
-        debug_assert!(
-            !div3.is_zero(),
-            "we have checked that neither a or ZETA are zero. Q.E.D."
-        );
-        let y1: P::BaseField = {
-            gx1 = num_gx1 / div3;
-            if gx1.legendre().is_qr() {
-                gx1_square = true;
-                gx1.sqrt()
-                    .expect("We have checked that gx1 is a quadratic residue. Q.E.D")
-            } else {
-                let zeta_gx1 = P::ZETA * gx1;
-                gx1_square = false;
-                zeta_gx1.sqrt().expect(
-                    "ZETA * gx1 is a quadratic residue because legard is multiplicative. Q.E.D",
-                )
-            }
-        };
-
+        //
         // This magic also comes from a generalization of [WB2019, section 4.2].
         //
         // The Sarkar square root algorithm with input s gives us a square root of
@@ -125,7 +194,7 @@ impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
         let num_x = if gx1_square { num_x1 } else { num_x2 };
         let y = if gx1_square { y1 } else { y2 };
 
-        let x_affine = num_x / div;
+        let x_affine = num_x * div2 * div3_inv;
         let y_affine = if parity(&y) != parity(&element) {
             -y
         } else {
@@ -136,7 +205,7 @@ impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
             point_on_curve.is_on_curve(),
             "swu mapped to a point off the curve"
         );
-        Ok(point_on_curve)
+        point_on_curve
     }
 }
 
@@ -161,16 +230,17 @@ mod test {
     type DefaultHasher = fnv::FnvHasher;
 
     use crate::{
-        hashing::{map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
-        CurveConfig,
+        hashing::{map_to_curve_hasher::MapToCurveBasedHasher, AlgebraicHashToCurve, HashToCurve},
+        CurveConfig, CurveGroup,
     };
-    use ark_ff::field_hashers::DefaultFieldHasher;
+    use ark_ff::field_hashers::{AlgebraicHashToField, DefaultFieldHasher, XofFieldHasher};
     use ark_std::vec::*;
 
     use super::*;
     use ark_ff::{fields::Fp64, MontBackend, MontFp};
     use hashbrown::HashMap;
     use sha2::Sha256;
+    use sha3::Shake128;
 
     #[derive(ark_ff::MontConfig)]
     #[modulus = "127"]
@@ -260,6 +330,108 @@ mod test {
         );
     }
 
+    /// Same as above, but hashing to the field with `expand_message_xof`.
+    #[test]
+    fn hash_arbitrary_string_to_curve_swu_xof() {
+        let test_swu_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestSWUMapToCurveConfig>,
+            XofFieldHasher<Shake128, 128>,
+            SWUMap<TestSWUMapToCurveConfig>,
+        >::new(&[1])
+        .unwrap();
+
+        let hash_result = test_swu_to_curve_hasher
+            .hash(b"if you stick a Babel fish in your ear you can instantly understand anything said to you in any form of language.")
+            .expect("fail to hash the string to curve");
+
+        assert!(
+            hash_result.is_on_curve(),
+            "hash results into a point off the curve"
+        );
+    }
+
+    /// A toy algebraic sponge over `F127` standing in for Poseidon, with a
+    /// state of two elements, rate one, and rounds
+    /// `(s0, s1) -> ((s0 + s1)^5 + c, (s0 + 2 * s1)^5)`.
+    struct ToySponge {
+        round_constants: [F127; 2],
+    }
+
+    impl AlgebraicHashToField<F127> for ToySponge {
+        fn hash_elements_to_field<const N: usize>(&self, msg: &[F127]) -> [F127; N] {
+            let permute = |s: &mut [F127; 2]| {
+                for c in self.round_constants {
+                    *s = [(s[0] + s[1]).pow([5]) + c, (s[0] + s[1] + s[1]).pow([5])];
+                }
+            };
+            let mut state = [F127::from(msg.len() as u64), F127::zero()];
+            for m in msg {
+                state[0] += m;
+                permute(&mut state);
+            }
+            ark_std::array::from_fn(|_| {
+                permute(&mut state);
+                state[0]
+            })
+        }
+    }
+
+    /// Hash field elements to the curve with an algebraic sponge, reusing the
+    /// same map to curve as the byte-oriented hashers.
+    #[test]
+    fn hash_field_elements_to_curve_swu() {
+        let round_constants = [MontFp!("3"), MontFp!("14")];
+        let test_swu_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestSWUMapToCurveConfig>,
+            ToySponge,
+            SWUMap<TestSWUMapToCurveConfig>,
+        >::from_field_hasher(ToySponge { round_constants })
+        .unwrap();
+
+        let msg = [F127::from(42), F127::from(7), F127::from(0)];
+        let [u0, u1] = ToySponge { round_constants }.hash_elements_to_field::<2>(&msg);
+        let expected = (SWUMap::<TestSWUMapToCurveConfig>::map_to_curve(u0).unwrap()
+            + SWUMap::<TestSWUMapToCurveConfig>::map_to_curve(u1).unwrap())
+        .into_affine();
+
+        let hash_result = AlgebraicHashToCurve::hash(&test_swu_to_curve_hasher, &msg)
+            .expect("fail to hash the field elements to curve");
+        assert!(
+            hash_result.is_on_curve(),
+            "hash results into a point off the curve"
+        );
+        assert_eq!(hash_result, expected);
+
+        let [u] = ToySponge { round_constants }.hash_elements_to_field::<1>(&msg);
+        let encode_result = AlgebraicHashToCurve::encode(&test_swu_to_curve_hasher, &msg).unwrap();
+        assert_eq!(
+            encode_result,
+            SWUMap::<TestSWUMapToCurveConfig>::map_to_curve(u).unwrap()
+        );
+
+        #[cfg(feature = "ct")]
+        {
+            assert_eq!(
+                test_swu_to_curve_hasher.hash_elements_ct(&msg).unwrap(),
+                hash_result
+            );
+            assert_eq!(
+                test_swu_to_curve_hasher.encode_elements_ct(&msg).unwrap(),
+                encode_result
+            );
+        }
+
+        let msgs: [&[F127]; 3] = [&msg, &msg[..1], &[]];
+        let hash_results = AlgebraicHashToCurve::hash_batch(&test_swu_to_curve_hasher, &msgs)
+            .expect("fail to hash the field elements to curve");
+        for (msg, hash_result) in msgs.iter().zip(hash_results) {
+            assert_eq!(
+                AlgebraicHashToCurve::hash(&test_swu_to_curve_hasher, msg).unwrap(),
+                hash_result
+            );
+        }
+    }
+
     /// Use a simple SWU compatible curve and map the whole field to it. We observe
     /// the map behaviour. Specifically, the map should be non-constant, all
     /// elements should be mapped to curve successfully. everything can be mapped
@@ -291,4 +463,33 @@ mod test {
             "a constant hash function is not good."
         );
     }
+
+    /// Mapping the whole field in a batch must agree with mapping each
+    /// element on its own.
+    #[test]
+    fn map_field_to_curve_swu_batch() {
+        let elements: Vec<F127> = (0..127u64).map(F127::from).collect();
+        let batch = SWUMap::<TestSWUMapToCurveConfig>::map_to_curve_batch(&elements).unwrap();
+        for (element, point) in elements.into_iter().zip(batch) {
+            assert_eq!(
+                SWUMap::<TestSWUMapToCurveConfig>::map_to_curve(element).unwrap(),
+                point
+            );
+        }
+    }
+
+    /// The constant-time map must agree with the map on the whole field.
+    #[cfg(feature = "ct")]
+    #[test]
+    fn map_field_to_curve_swu_ct() {
+        use crate::hashing::curve_maps::ct::MapToCurveCt;
+
+        for u in 0..127u64 {
+            let u = F127::from(u);
+            assert_eq!(
+                SWUMap::<TestSWUMapToCurveConfig>::map_to_curve_ct(u),
+                SWUMap::<TestSWUMapToCurveConfig>::map_to_curve(u).unwrap()
+            );
+        }
+    }
 }
@@ -1,69 +1,19 @@
 use core::marker::PhantomData;
 
 use crate::{models::short_weierstrass::SWCurveConfig, CurveConfig};
-use ark_ff::batch_inversion;
-use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
 
 use crate::{
     hashing::{map_to_curve_hasher::MapToCurve, HashToCurveError},
     models::short_weierstrass::{Affine, Projective},
-    AffineRepr,
+    AffineRepr, CurveGroup,
 };
+use ark_std::vec::*;
+
+pub use crate::models::short_weierstrass::IsogenyMap;
 
 use super::swu::{SWUConfig, SWUMap};
 type BaseField<MP> = <MP as CurveConfig>::BaseField;
 
-/// [`IsogenyMap`] defines an isogeny between curves of
-/// form `Phi(x, y) := (a(x), b(x)*y).
-/// The `x` coordinate of the codomain point only depends on the
-/// `x`-coordinate of the domain point, and the
-/// `y`-coordinate of the codomain point is a multiple of the `y`-coordinate of the domain point.
-/// The multiplier depends on the `x`-coordinate of the domain point.
-/// All isogeny maps of curves of short Weierstrass form can be written in this way. See
-/// [\[Ga18]\]. Theorem 9.7.5 for details.
-///
-/// We assume that `Domain` and `Codomain` have the same `BaseField` but we use both
-/// `BaseField<Domain>` and `BaseField<Codomain>` in our fields' definitions to avoid
-/// using `PhantomData`
-///
-/// - [\[Ga18]\] Galbraith, S. D. (2018). Mathematics of public key cryptography.
-pub struct IsogenyMap<
-    'a,
-    Domain: SWCurveConfig,
-    Codomain: SWCurveConfig<BaseField = BaseField<Domain>>,
-> {
-    pub x_map_numerator: &'a [BaseField<Domain>],
-    pub x_map_denominator: &'a [BaseField<Codomain>],
-
-    pub y_map_numerator: &'a [BaseField<Domain>],
-    pub y_map_denominator: &'a [BaseField<Codomain>],
-}
-
-impl<'a, Domain, Codomain> IsogenyMap<'a, Domain, Codomain>
-where
-    Domain: SWCurveConfig,
-    Codomain: SWCurveConfig<BaseField = BaseField<Domain>>,
-{
-    fn apply(&self, domain_point: Affine<Domain>) -> Result<Affine<Codomain>, HashToCurveError> {
-        match domain_point.xy() {
-            Some((x, y)) => {
-                let x_num = DensePolynomial::from_coefficients_slice(self.x_map_numerator);
-                let x_den = DensePolynomial::from_coefficients_slice(self.x_map_denominator);
-
-                let y_num = DensePolynomial::from_coefficients_slice(self.y_map_numerator);
-                let y_den = DensePolynomial::from_coefficients_slice(self.y_map_denominator);
-
-                let mut v: [BaseField<Domain>; 2] = [x_den.evaluate(&x), y_den.evaluate(&x)];
-                batch_inversion(&mut v);
-                let img_x = x_num.evaluate(&x) * v[0];
-                let img_y = (y_num.evaluate(&x) * y) * v[1];
-                Ok(Affine::<Codomain>::new_unchecked(img_x, img_y))
-            },
-            None => Ok(Affine::identity()),
-        }
-    }
-}
-
 /// Trait defining the necessary parameters for the WB hash-to-curve method
 /// for the curves of Weierstrass form of:
 /// of y^2 = x^3 + a*x + b where b != 0 but `a` can be zero like BLS-381 curve.
@@ -86,13 +36,9 @@ pub struct WBMap<P: WBConfig> {
 impl<P: WBConfig> MapToCurve<Projective<P>> for WBMap<P> {
     /// Checks if `P` represents a valid map.
     fn check_parameters() -> Result<(), HashToCurveError> {
-        match P::ISOGENY_MAP.apply(P::IsogenousCurve::GENERATOR) {
-            Ok(point_on_curve) => {
-                debug_assert!(point_on_curve.is_on_curve(),
-			      "the isogeny maps the generator of its domain: {} into {} which does not belong to its codomain.",P::IsogenousCurve::GENERATOR, point_on_curve);
-            },
-            Err(e) => return Err(e),
-        }
+        let point_on_curve = P::ISOGENY_MAP.evaluate(&P::IsogenousCurve::GENERATOR);
+        debug_assert!(point_on_curve.is_on_curve(),
+		      "the isogeny maps the generator of its domain: {} into {} which does not belong to its codomain.",P::IsogenousCurve::GENERATOR, point_on_curve);
 
         SWUMap::<P::IsogenousCurve>::check_parameters().unwrap(); // Or ?
         Ok(())
@@ -106,7 +52,19 @@ impl<P: WBConfig> MapToCurve<Projective<P>> for WBMap<P> {
     ) -> Result<Affine<P>, HashToCurveError> {
         // first we need to map the field point to the isogenous curve
         let point_on_isogenious_curve = SWUMap::<P::IsogenousCurve>::map_to_curve(element).unwrap();
-        P::ISOGENY_MAP.apply(point_on_isogenious_curve)
+        Ok(P::ISOGENY_MAP.evaluate(&point_on_isogenious_curve))
+    }
+
+    /// Map a batch of field elements to curve points, evaluating the isogeny
+    /// in projective coordinates so that the whole batch shares a single
+    /// inversion besides the one of the SWU map.
+    fn map_to_curve_batch(elements: &[BaseField<P>]) -> Result<Vec<Affine<P>>, HashToCurveError> {
+        let points_on_isogenous_curve = SWUMap::<P::IsogenousCurve>::map_to_curve_batch(elements)?;
+        let points_on_curve: Vec<_> = points_on_isogenous_curve
+            .iter()
+            .map(|p| P::ISOGENY_MAP.evaluate_projective(&(*p).into()))
+            .collect();
+        Ok(Projective::normalize_batch(&points_on_curve))
     }
 }
 
@@ -330,4 +288,57 @@ mod test {
             "hash results into a point off the curve"
         );
     }
+
+    /// Hashing a batch of messages must agree with hashing each of them.
+    #[test]
+    fn hash_batch_matches_hash_wb() {
+        use sha2::Sha256;
+        let test_wb_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestWBF127MapToCurveConfig>,
+            DefaultFieldHasher<Sha256, 128>,
+            WBMap<TestWBF127MapToCurveConfig>,
+        >::new(&[1])
+        .unwrap();
+
+        let msgs: [&[u8]; 4] = [b"", b"abc", b"abcdef0123456789", &[0xa5; 512]];
+        let hashes = test_wb_to_curve_hasher.hash_batch(&msgs).unwrap();
+        assert_eq!(hashes.len(), msgs.len());
+        for (msg, hash) in msgs.iter().zip(hashes) {
+            assert_eq!(test_wb_to_curve_hasher.hash(msg).unwrap(), hash);
+        }
+    }
+
+    /// The constant-time map and hashes must agree with the usual ones.
+    #[cfg(feature = "ct")]
+    #[test]
+    fn map_field_to_curve_wb_ct() {
+        use crate::hashing::{curve_maps::ct::MapToCurveCt, map_to_curve_hasher::MapToCurve};
+        use sha2::Sha256;
+
+        for u in 0..127u64 {
+            let u = F127::from(u);
+            assert_eq!(
+                WBMap::<TestWBF127MapToCurveConfig>::map_to_curve_ct(u),
+                WBMap::<TestWBF127MapToCurveConfig>::map_to_curve(u).unwrap()
+            );
+        }
+
+        let test_wb_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestWBF127MapToCurveConfig>,
+            DefaultFieldHasher<Sha256, 128>,
+            WBMap<TestWBF127MapToCurveConfig>,
+        >::new(&[1])
+        .unwrap();
+        let msgs: [&[u8]; 3] = [b"", b"abc", &[0xa5; 512]];
+        for msg in msgs {
+            assert_eq!(
+                test_wb_to_curve_hasher.hash_ct(msg).unwrap(),
+                test_wb_to_curve_hasher.hash(msg).unwrap()
+            );
+            assert_eq!(
+                test_wb_to_curve_hasher.encode_ct(msg).unwrap(),
+                test_wb_to_curve_hasher.encode(msg).unwrap()
+            );
+        }
+    }
 }
@@ -1,9 +1,11 @@
+#[cfg(feature = "ct")]
+use crate::hashing::curve_maps::ct::{CurveGroupCt, MapToCurveCt};
 use crate::{
-    hashing::{HashToCurve, HashToCurveError},
+    hashing::{AlgebraicHashToCurve, HashToCurve, HashToCurveError},
     AffineRepr, CurveGroup,
 };
-use ark_ff::field_hashers::HashToField;
-use ark_std::marker::PhantomData;
+use ark_ff::field_hashers::{AlgebraicHashToField, HashToField};
+use ark_std::{marker::PhantomData, vec::*};
 
 /// Trait for mapping a random field element to a random curve point.
 pub trait MapToCurve<T: CurveGroup>: Sized {
@@ -12,36 +14,200 @@ pub trait MapToCurve<T: CurveGroup>: Sized {
 
     /// Map an arbitrary field element to a corresponding curve point.
     fn map_to_curve(point: T::BaseField) -> Result<T::Affine, HashToCurveError>;
+
+    /// Map a batch of field elements to the corresponding curve points. Maps
+    /// should override this to share the field inversions across the batch.
+    fn map_to_curve_batch(points: &[T::BaseField]) -> Result<Vec<T::Affine>, HashToCurveError> {
+        points.iter().map(|&p| Self::map_to_curve(p)).collect()
+    }
 }
 
 /// Helper struct that can be used to construct elements on the elliptic curve
 /// from arbitrary messages, by first hashing the message onto a field element
 /// and then mapping it to the elliptic curve defined over that field.
+///
+/// The field hasher is either a byte-oriented [`HashToField`], as in the IETF
+/// hash to curve specification, which makes this a [`HashToCurve`], or an
+/// [`AlgebraicHashToField`] such as a Poseidon or Rescue sponge, supplied with
+/// [`Self::from_field_hasher`], which makes this an [`AlgebraicHashToCurve`].
 pub struct MapToCurveBasedHasher<T, H2F, M2C>
 where
     T: CurveGroup,
-    H2F: HashToField<T::BaseField>,
     M2C: MapToCurve<T>,
 {
     field_hasher: H2F,
     _phantom: PhantomData<(T, M2C)>,
 }
 
-impl<T, H2F, M2C> HashToCurve<T> for MapToCurveBasedHasher<T, H2F, M2C>
+impl<T, H2F, M2C> MapToCurveBasedHasher<T, H2F, M2C>
 where
     T: CurveGroup,
-    H2F: HashToField<T::BaseField>,
     M2C: MapToCurve<T>,
 {
-    fn new(domain: &[u8]) -> Result<Self, HashToCurveError> {
+    /// Builds a hasher around a field hasher instantiated by the caller, e.g.,
+    /// an algebraic sponge with its own parameters.
+    pub fn from_field_hasher(field_hasher: H2F) -> Result<Self, HashToCurveError> {
         #[cfg(test)]
         M2C::check_parameters()?;
         Ok(MapToCurveBasedHasher {
-            field_hasher: H2F::new(domain),
+            field_hasher,
             _phantom: PhantomData,
         })
     }
 
+    /// Steps 2 to 6 of the IETF hash_to_curve, mapping the two field elements
+    /// `u` to the curve, adding them and clearing the cofactor.
+    fn map_to_curve_ro(u: [T::BaseField; 2]) -> Result<T::Affine, HashToCurveError> {
+        let rand_curve_elem_0 = M2C::map_to_curve(u[0])?;
+        let rand_curve_elem_1 = M2C::map_to_curve(u[1])?;
+
+        let mut rand_curve_elem = rand_curve_elem_0 + rand_curve_elem_1;
+        rand_curve_elem.clear_cofactor_in_place();
+
+        Ok(rand_curve_elem.into_affine())
+    }
+
+    /// Step 1 of the IETF encode_to_curve is hashing to the field element
+    /// `u`; this performs the remaining steps, mapping `u` to the curve and
+    /// clearing the cofactor.
+    fn map_to_curve_nu(u: T::BaseField) -> Result<T::Affine, HashToCurveError> {
+        Ok(M2C::map_to_curve(u)?.clear_cofactor())
+    }
+
+    /// Steps 2 to 6 of the IETF hash_to_curve for a batch of messages, given
+    /// the two field elements of each message in `rand_field_elems`, sharing
+    /// the field inversions of the maps to the curve and of the final
+    /// conversion to affine coordinates across the whole batch.
+    fn map_to_curve_ro_batch(
+        rand_field_elems: &[T::BaseField],
+    ) -> Result<Vec<T::Affine>, HashToCurveError> {
+        let rand_curve_elems = M2C::map_to_curve_batch(rand_field_elems)?;
+
+        let rand_curve_elems: Vec<T> = rand_curve_elems
+            .chunks_exact(2)
+            .map(|pair| {
+                let mut rand_curve_elem = pair[0] + pair[1];
+                rand_curve_elem.clear_cofactor_in_place();
+                rand_curve_elem
+            })
+            .collect();
+        Ok(T::normalize_batch(&rand_curve_elems))
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<T, H2F, M2C> MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: CurveGroupCt,
+    M2C: MapToCurveCt<T>,
+{
+    /// Performs the steps of [`Self::map_to_curve_ro`] in constant time.
+    fn map_to_curve_ro_ct([u0, u1]: [T::BaseField; 2]) -> T::Affine {
+        let q0 = T::from_affine_ct(&M2C::map_to_curve_ct(u0));
+        let q1 = T::from_affine_ct(&M2C::map_to_curve_ct(u1));
+        q0.add_ct(&q1).clear_cofactor_ct().into_affine_ct()
+    }
+
+    /// Performs the steps of [`Self::map_to_curve_nu`] in constant time.
+    fn map_to_curve_nu_ct(u: T::BaseField) -> T::Affine {
+        let q = T::from_affine_ct(&M2C::map_to_curve_ct(u));
+        q.clear_cofactor_ct().into_affine_ct()
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<T, H2F, M2C> MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: CurveGroupCt,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurveCt<T>,
+{
+    /// Produce a hash of the message as in [`HashToCurve::hash`], but in
+    /// constant time, for hashing secret messages. This uses the
+    /// constant-time [`MapToCurveCt::map_to_curve_ct`], and adds the two
+    /// points, clears the cofactor and converts the result to affine
+    /// coordinates with the constant-time operations of [`CurveGroupCt`].
+    pub fn hash_ct(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        Ok(Self::map_to_curve_ro_ct(
+            self.field_hasher.hash_to_field::<2>(msg),
+        ))
+    }
+
+    /// Produce an encoding of the message as in [`HashToCurve::encode`], but
+    /// in constant time, as in [`Self::hash_ct`].
+    pub fn encode_ct(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        let [u] = self.field_hasher.hash_to_field::<1>(msg);
+        Ok(Self::map_to_curve_nu_ct(u))
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<T, H2F, M2C> MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: CurveGroupCt,
+    H2F: AlgebraicHashToField<T::BaseField>,
+    M2C: MapToCurveCt<T>,
+{
+    /// Produce a hash of a message made of base field elements as in
+    /// [`AlgebraicHashToCurve::hash`], but in constant time, as in
+    /// [`Self::hash_ct`].
+    pub fn hash_elements_ct(&self, msg: &[T::BaseField]) -> Result<T::Affine, HashToCurveError> {
+        Ok(Self::map_to_curve_ro_ct(
+            self.field_hasher.hash_elements_to_field::<2>(msg),
+        ))
+    }
+
+    /// Produce an encoding of a message made of base field elements as in
+    /// [`AlgebraicHashToCurve::encode`], but in constant time, as in
+    /// [`Self::hash_ct`].
+    pub fn encode_elements_ct(&self, msg: &[T::BaseField]) -> Result<T::Affine, HashToCurveError> {
+        let [u] = self.field_hasher.hash_elements_to_field::<1>(msg);
+        Ok(Self::map_to_curve_nu_ct(u))
+    }
+}
+
+impl<T, H2F, M2C> AlgebraicHashToCurve<T> for MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: CurveGroup,
+    H2F: AlgebraicHashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    /// Produce a hash of a message made of base field elements, as in
+    /// [`HashToCurve::hash`] but hashing to the field with the algebraic
+    /// field hasher.
+    fn hash(&self, msg: &[T::BaseField]) -> Result<T::Affine, HashToCurveError> {
+        Self::map_to_curve_ro(self.field_hasher.hash_elements_to_field::<2>(msg))
+    }
+
+    /// Produce an encoding of a message made of base field elements, as in
+    /// [`HashToCurve::encode`] but hashing to the field with the algebraic
+    /// field hasher.
+    fn encode(&self, msg: &[T::BaseField]) -> Result<T::Affine, HashToCurveError> {
+        let [rand_field_elem] = self.field_hasher.hash_elements_to_field::<1>(msg);
+        Self::map_to_curve_nu(rand_field_elem)
+    }
+
+    /// Produce the hashes of a batch of messages, as in
+    /// [`HashToCurve::hash_batch`].
+    fn hash_batch(&self, msgs: &[&[T::BaseField]]) -> Result<Vec<T::Affine>, HashToCurveError> {
+        let rand_field_elems: Vec<_> = msgs
+            .iter()
+            .flat_map(|msg| self.field_hasher.hash_elements_to_field::<2>(msg))
+            .collect();
+        Self::map_to_curve_ro_batch(&rand_field_elems)
+    }
+}
+
+impl<T, H2F, M2C> HashToCurve<T> for MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: CurveGroup,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    fn new(domain: &[u8]) -> Result<Self, HashToCurveError> {
+        Self::from_field_hasher(H2F::new(domain))
+    }
+
     /// Produce a hash of the message, using the hash to field and map to curve
     /// traits. This uses the IETF hash to curve's specification for Random
     /// oracle encoding (hash_to_curve) defined by combining these components.
@@ -56,14 +222,35 @@ where
         // 5. P = clear_cofactor(R)
         // 6. return P
 
-        let rand_field_elems = self.field_hasher.hash_to_field::<2>(msg);
+        Self::map_to_curve_ro(self.field_hasher.hash_to_field::<2>(msg))
+    }
 
-        let rand_curve_elem_0 = M2C::map_to_curve(rand_field_elems[0])?;
-        let rand_curve_elem_1 = M2C::map_to_curve(rand_field_elems[1])?;
+    /// Produce an encoding of the message with a single evaluation of the map
+    /// to curve. This uses the IETF hash to curve's specification for
+    /// Nonuniform encoding (encode_to_curve), about twice as fast as
+    /// [`Self::hash`].
+    /// See <https://www.rfc-editor.org/rfc/rfc9380.html#section-3>
+    fn encode(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        // IETF spec of encode_to_curve, from hash_to_field and map_to_curve
+        // sub-components
+        // 1. u = hash_to_field(msg, 1)
+        // 2. Q = map_to_curve(u[0])
+        // 3. P = clear_cofactor(Q)
+        // 4. return P
+
+        let [rand_field_elem] = self.field_hasher.hash_to_field::<1>(msg);
 
-        let rand_curve_elem = (rand_curve_elem_0 + rand_curve_elem_1).into();
-        let rand_subgroup_elem = rand_curve_elem.clear_cofactor();
+        Self::map_to_curve_nu(rand_field_elem)
+    }
 
-        Ok(rand_subgroup_elem)
+    /// Produce the hashes of a batch of messages, as in [`Self::hash`], but
+    /// sharing the field inversions of the maps to the curve and of the final
+    /// conversion to affine coordinates across the whole batch.
+    fn hash_batch(&self, msgs: &[&[u8]]) -> Result<Vec<T::Affine>, HashToCurveError> {
+        let rand_field_elems: Vec<_> = msgs
+            .iter()
+            .flat_map(|msg| self.field_hasher.hash_to_field::<2>(msg))
+            .collect();
+        Self::map_to_curve_ro_batch(&rand_field_elems)
     }
 }
@@ -1,5 +1,5 @@
 use crate::CurveGroup;
-use ark_std::string::*;
+use ark_std::{string::*, vec::*};
 use core::fmt;
 
 pub mod curve_maps;
@@ -14,6 +14,54 @@ pub trait HashToCurve<T: CurveGroup>: Sized {
     /// The output of the hash is a curve point in the prime order subgroup
     /// of the given elliptic curve.
     fn hash(&self, message: &[u8]) -> Result<T::Affine, HashToCurveError>;
+
+    /// Produce an encoding of the message, which also depends on the domain,
+    /// in the prime order subgroup of the given elliptic curve. Unlike
+    /// [`Self::hash`], the output need not be uniformly distributed, as in
+    /// the nonuniform (`_NU_`) suites of RFC 9380: this should only be used
+    /// by protocols that do not need a random oracle to the curve.
+    ///
+    /// Defaults to [`Self::hash`], whose outputs are also valid encodings;
+    /// implementations should override it with a cheaper encoding.
+    fn encode(&self, message: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        self.hash(message)
+    }
+
+    /// Produce the hashes of a batch of messages, equal to hashing each of
+    /// them with [`Self::hash`]. Implementations may override this to share
+    /// work, such as field inversions, across the batch.
+    fn hash_batch(&self, messages: &[&[u8]]) -> Result<Vec<T::Affine>, HashToCurveError> {
+        messages.iter().map(|msg| self.hash(msg)).collect()
+    }
+}
+
+/// Trait for hashing messages made of base field elements to a group element
+/// on an elliptic curve, with an algebraic field hasher such as a Poseidon or
+/// Rescue sponge, which makes the hash cheap to verify inside an arithmetic
+/// circuit over the base field.
+///
+/// Unlike [`HashToCurve`], implementors are built around a field hasher that
+/// the caller instantiates with its own parameters, rather than from a
+/// domain.
+pub trait AlgebraicHashToCurve<T: CurveGroup>: Sized {
+    /// Produce a hash of the message, as in [`HashToCurve::hash`]. The output
+    /// of the hash is a curve point in the prime order subgroup of the given
+    /// elliptic curve.
+    fn hash(&self, message: &[T::BaseField]) -> Result<T::Affine, HashToCurveError>;
+
+    /// Produce an encoding of the message, as in [`HashToCurve::encode`].
+    ///
+    /// Defaults to [`Self::hash`], whose outputs are also valid encodings;
+    /// implementations should override it with a cheaper encoding.
+    fn encode(&self, message: &[T::BaseField]) -> Result<T::Affine, HashToCurveError> {
+        self.hash(message)
+    }
+
+    /// Produce the hashes of a batch of messages, equal to hashing each of
+    /// them with [`Self::hash`], as in [`HashToCurve::hash_batch`].
+    fn hash_batch(&self, messages: &[&[T::BaseField]]) -> Result<Vec<T::Affine>, HashToCurveError> {
+        messages.iter().map(|msg| self.hash(msg)).collect()
+    }
 }
 
 /// This is an error that could occur during the hash to curve process
@@ -18,6 +18,7 @@
 extern crate ark_std;
 
 use ark_ff::{
+    field_hashers::HashToField,
     fields::{Field, PrimeField},
     UniformRand,
 };
@@ -28,9 +29,12 @@ use ark_std::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     vec::*,
 };
-pub use scalar_mul::{variable_base::VariableBaseMSM, ScalarMul};
+pub use scalar_mul::{fixed_base::FixedBaseMSM, variable_base::VariableBaseMSM, ScalarMul};
 use zeroize::Zeroize;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 pub use ark_ff::AdditiveGroup;
 
 pub mod models;
@@ -69,6 +73,30 @@ pub trait PrimeGroup: AdditiveGroup<Scalar = Self::ScalarField> {
         }
         res
     }
+
+    /// Hashes `msg` to an element of the scalar field, with the domain
+    /// separation tag `dst`, using the field hasher `H`. With
+    /// [`DefaultFieldHasher`](ark_ff::field_hashers::DefaultFieldHasher),
+    /// this is `hash_to_field(msg, 1)` of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html) with
+    /// `expand_message_xmd`, as used for Fiat–Shamir challenges.
+    ///
+    /// ```
+    /// use ark_ec::PrimeGroup;
+    /// use ark_ff::field_hashers::DefaultFieldHasher;
+    /// use ark_test_curves::bls12_381::G1Projective as G;
+    /// use sha2::Sha256;
+    ///
+    /// let challenge = G::hash_to_scalar::<DefaultFieldHasher<Sha256>>(b"transcript", b"MY-PROTOCOL-V1");
+    /// # let _ = challenge;
+    /// ```
+    fn hash_to_scalar<H: HashToField<Self::ScalarField>>(
+        msg: &[u8],
+        dst: &[u8],
+    ) -> Self::ScalarField {
+        let [scalar] = H::new(dst).hash_to_field::<1>(msg);
+        scalar
+    }
 }
 
 /// An opaque representation of an elliptic curve group element that is suitable
@@ -112,6 +140,20 @@ pub trait CurveGroup:
     fn into_affine(self) -> Self::Affine {
         self.into()
     }
+
+    /// Performs cofactor clearing in place.
+    /// The default method normalizes `self` and calls
+    /// [`AffineRepr::clear_cofactor`]. Some curve models can avoid the
+    /// normalization.
+    fn clear_cofactor_in_place(&mut self) {
+        *self = self.into_affine().clear_cofactor().into();
+    }
+
+    /// Performs cofactor clearing in place on each of `points`, in parallel
+    /// if the `parallel` feature is enabled.
+    fn batch_clear_cofactor(points: &mut [Self]) {
+        ark_std::cfg_iter_mut!(points).for_each(Self::clear_cofactor_in_place);
+    }
 }
 
 /// The canonical representation of an elliptic curve group element.
@@ -242,6 +284,48 @@ where
         ScalarField = <Self::E2 as CurveGroup>::BaseField,
     >;
     type E2: CurveGroup;
+
+    /// Converts a scalar of `E1` to a scalar of `E2`, i.e. to an element of
+    /// the base field of `E1`, by reinterpreting its canonical
+    /// representative. This is the conversion needed to, e.g., use a
+    /// challenge sampled on one side of the cycle on the other.
+    ///
+    /// Returns `None` if the representative is not less than the modulus
+    /// of the scalar field of `E2`, in which case it has no exact image.
+    fn e1_scalar_to_e2_scalar(
+        s: &<Self::E1 as PrimeGroup>::ScalarField,
+    ) -> Option<<Self::E2 as PrimeGroup>::ScalarField> {
+        convert_prime_field(s)
+    }
+
+    /// Converts a scalar of `E2` to a scalar of `E1`, i.e. to an element of
+    /// the base field of `E2`. See [`Self::e1_scalar_to_e2_scalar`].
+    fn e2_scalar_to_e1_scalar(
+        s: &<Self::E2 as PrimeGroup>::ScalarField,
+    ) -> Option<<Self::E1 as PrimeGroup>::ScalarField> {
+        convert_prime_field(s)
+    }
+
+    /// Checks that the curves are consistent with forming a cycle, i.e.
+    /// that the generator of each curve is non-zero and is annihilated by
+    /// the modulus of its scalar field, which is the modulus of the base
+    /// field of the other curve. Since the moduli are prime, this implies
+    /// that the order of each curve is a multiple of the size of the base
+    /// field of the other.
+    fn is_consistent() -> bool {
+        let r1 = <<Self::E1 as PrimeGroup>::ScalarField as PrimeField>::MODULUS;
+        let r2 = <<Self::E2 as PrimeGroup>::ScalarField as PrimeField>::MODULUS;
+        let g1 = Self::E1::generator();
+        let g2 = Self::E2::generator();
+        !g1.is_zero() && !g2.is_zero() && g1.mul_bigint(r1).is_zero() && g2.mul_bigint(r2).is_zero()
+    }
+}
+
+/// Maps `x` to the element of `F2` with the same canonical representative,
+/// if there is one.
+fn convert_prime_field<F1: PrimeField, F2: PrimeField>(x: &F1) -> Option<F2> {
+    let bigint: num_bigint::BigUint = x.into_bigint().into();
+    (bigint < F2::MODULUS.into()).then(|| F2::from(bigint))
 }
 
 /// A cycle of curves where both curves are pairing-friendly.
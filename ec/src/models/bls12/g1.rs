@@ -1,5 +1,6 @@
 use crate::{
     bls12::Bls12Config,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
     AffineRepr, CurveGroup,
 };
@@ -49,3 +50,31 @@ impl<P: Bls12Config> Default for G1Prepared<P> {
         G1Prepared(G1Affine::<P>::generator())
     }
 }
+
+/// Checks that `p`, assumed to be on the curve, is in the prime-order
+/// subgroup of G1, with the algorithm of Section 6 of
+/// [\[Sco21\]](https://eprint.iacr.org/2021/1130): `p` is in G1 if and only if
+/// `σ(p) = -[x²]p`, where `σ(x, y) = (βx, y)` is the GLV endomorphism and
+/// `x` is the BLS parameter [`Bls12Config::X`]. This costs two
+/// multiplications by `x`, rather than a multiplication by the subgroup
+/// order.
+///
+/// The endomorphism of `P::G1Config` must act on G1 as multiplication by
+/// `-x²`, i.e. [`GLVConfig::LAMBDA`] must be `-x²`. Curves opt in by calling
+/// this from [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`].
+///
+/// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`]: crate::short_weierstrass::SWCurveConfig::is_in_correct_subgroup_assuming_on_curve
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Config>(p: &G1Affine<P>) -> bool
+where
+    P::G1Config: GLVConfig,
+{
+    // An early-out optimization described in Section 6: if [x]P = P but P is
+    // not zero, then P is not in G1.
+    let x_times_p = p.mul_bigint(P::X);
+    if x_times_p == *p && !p.is_zero() {
+        return false;
+    }
+
+    let minus_x_squared_times_p = -x_times_p.mul_bigint(P::X);
+    minus_x_squared_times_p == P::G1Config::endomorphism_affine(p)
+}
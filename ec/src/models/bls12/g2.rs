@@ -1,19 +1,25 @@
-use ark_ff::{AdditiveGroup, BitIteratorBE, Field, Fp2};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::{vec::*, One};
+use ark_ff::{
+    fields::{fp12_2over3over2::Fp12, fp6_3over2::Fp6},
+    AdditiveGroup, BitIteratorBE, Field, Fp2,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    ValidationError,
+};
+use ark_std::{vec::*, One, Zero};
 use educe::Educe;
 
 use crate::{
     bls12::{Bls12Config, TwistType},
     models::short_weierstrass::SWCurveConfig,
     short_weierstrass::{Affine, Projective},
-    AffineRepr, CurveGroup,
+    AffineRepr, CurveGroup, PrimeGroup,
 };
 
 pub type G2Affine<P> = Affine<<P as Bls12Config>::G2Config>;
 pub type G2Projective<P> = Projective<<P as Bls12Config>::G2Config>;
 
-#[derive(Educe, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Educe, CanonicalSerialize)]
 #[educe(Clone, Debug, PartialEq, Eq)]
 pub struct G2Prepared<P: Bls12Config> {
     /// Stores the coefficients of the line evaluations as calculated in
@@ -36,6 +42,65 @@ pub struct G2HomProjective<P: Bls12Config> {
     z: Fp2<P::Fp2Config>,
 }
 
+impl<P: Bls12Config> G2Prepared<P> {
+    /// Recovers the prepared point from the line of the first doubling step,
+    /// which is computed from `(x, y, 1)`, so that `h = 2 * y` and `j = x²`.
+    fn point(&self) -> Option<G2Affine<P>> {
+        let (c0, c1, c2) = self.ell_coeffs.first()?;
+        let neg_h = match P::TWIST_TYPE {
+            TwistType::M => c2,
+            TwistType::D => c0,
+        };
+        let two = P::Fp::one().double();
+        let mut y = -*neg_h;
+        y.mul_assign_by_fp(&two.inverse().unwrap());
+        let mut x_squared = *c1;
+        x_squared.mul_assign_by_fp(&(two + P::Fp::one()).inverse().unwrap());
+        let x = x_squared.sqrt()?;
+        [x, -x]
+            .into_iter()
+            .map(|x| G2Affine::<P>::new_unchecked(x, y))
+            .find(|q| q.is_on_curve())
+    }
+}
+
+impl<P: Bls12Config> Valid for G2Prepared<P> {
+    /// Checks that the line coefficients are those of a point of G2, by
+    /// recovering the point from the first doubling step, checking it, and
+    /// preparing it again.
+    fn check(&self) -> Result<(), SerializationError> {
+        let expected = if self.infinity {
+            Self::from(G2Affine::<P>::identity())
+        } else {
+            let q = self.point().ok_or(ValidationError::NotOnCurve)?;
+            q.check()?;
+            Self::from(q)
+        };
+        if expected == *self {
+            Ok(())
+        } else {
+            Err(ValidationError::InvalidPrecomputation.into())
+        }
+    }
+}
+
+impl<P: Bls12Config> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let prepared = Self {
+            ell_coeffs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            infinity: bool::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            prepared.check()?;
+        }
+        Ok(prepared)
+    }
+}
+
 impl<P: Bls12Config> Default for G2Prepared<P> {
     fn default() -> Self {
         Self::from(G2Affine::<P>::generator())
@@ -147,3 +212,124 @@ impl<P: Bls12Config> G2HomProjective<P> {
         }
     }
 }
+
+/// Maps `p` from the twist to the curve `y^2 = x^3 + b` over `Fp12`, where
+/// `b` is the coefficient of G1, returning `None` for the point at infinity.
+///
+/// With `w` the generator of `Fp12` over `Fp6`, so that `w^6` is the
+/// non-residue `ξ` of `Fp6` over `Fp2`, this is `(x / w^2, y / w^3)` for an
+/// M-type twist and `(x w^2, y w^3)` for a D-type twist. It lets a config's
+/// [`Bls12Config::TWIST_TYPE`] and ψ coefficients be checked against each
+/// other: the untwist of `ψ(p)` is the Frobenius of the untwist of `p`.
+pub fn untwist<P: Bls12Config>(
+    p: &G2Affine<P>,
+) -> Option<(Fp12<P::Fp12Config>, Fp12<P::Fp12Config>)> {
+    let (x, y) = p.xy()?;
+    let w = Fp12::<P::Fp12Config>::new(Fp6::zero(), Fp6::one());
+    let mut w2 = w.square();
+    let mut w3 = w2 * &w;
+    if let TwistType::M = P::TWIST_TYPE {
+        w2 = w2.inverse().unwrap();
+        w3 = w3.inverse().unwrap();
+    }
+    let embed = |c: Fp2<P::Fp2Config>| {
+        Fp12::<P::Fp12Config>::new(Fp6::new(c, Fp2::zero(), Fp2::zero()), Fp6::zero())
+    };
+    Some((embed(x) * &w2, embed(y) * &w3))
+}
+
+/// Computes `ψ(r)`, where `ψ` is the untwist-Frobenius-twist endomorphism.
+pub fn mul_by_char<P: Bls12Config>(r: G2Affine<P>) -> G2Affine<P> {
+    // multiply by field characteristic
+
+    let mut s = r;
+    s.x.frobenius_map_in_place(1);
+    s.x *= &P::TWIST_MUL_BY_Q_X;
+    s.y.frobenius_map_in_place(1);
+    s.y *= &P::TWIST_MUL_BY_Q_Y;
+
+    s
+}
+
+/// Computes `ψ(r)` in Jacobian coordinates. Since the Frobenius map is a field
+/// automorphism, it commutes with the division by powers of `z`.
+fn mul_by_char_projective<P: Bls12Config>(r: G2Projective<P>) -> G2Projective<P> {
+    let mut s = r;
+    s.x.frobenius_map_in_place(1);
+    s.x *= &P::TWIST_MUL_BY_Q_X;
+    s.y.frobenius_map_in_place(1);
+    s.y *= &P::TWIST_MUL_BY_Q_Y;
+    s.z.frobenius_map_in_place(1);
+    s
+}
+
+/// Computes `ψ²(r)`. Since the Frobenius map of `Fp2` is an involution, `ψ²`
+/// multiplies the coordinates by the norms of the twist coefficients, and so
+/// can be computed in Jacobian coordinates.
+pub fn double_mul_by_char<P: Bls12Config>(r: G2Projective<P>) -> G2Projective<P> {
+    let mut s = r;
+    s.x.mul_assign_by_basefield(&P::TWIST_MUL_BY_Q_X.norm());
+    s.y.mul_assign_by_basefield(&P::TWIST_MUL_BY_Q_Y.norm());
+    s
+}
+
+/// Computes `[x]r`, where `x` is the signed BLS parameter.
+fn mul_by_x<P: Bls12Config>(r: &G2Projective<P>) -> G2Projective<P> {
+    let x_times_r = r.mul_bigint(P::X);
+    if P::X_IS_NEGATIVE {
+        -x_times_r
+    } else {
+        x_times_r
+    }
+}
+
+/// Checks that `p`, assumed to be on the curve, is in the prime-order
+/// subgroup of G2, with the algorithm of Section 4 of
+/// [\[Sco21\]](https://eprint.iacr.org/2021/1130): `p` is in G2 if and only if
+/// `ψ(p) = [x]p`, where `ψ` is the untwist-Frobenius-twist endomorphism and
+/// `x` is the BLS parameter [`Bls12Config::X`]. This costs a multiplication
+/// by `x`, rather than a multiplication by the subgroup order.
+///
+/// Curves opt in by calling this from
+/// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`].
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Config>(p: &G2Affine<P>) -> bool {
+    let mut x_times_p = p.mul_bigint(P::X);
+    if P::X_IS_NEGATIVE {
+        x_times_p = -x_times_p;
+    }
+    x_times_p == mul_by_char::<P>(*p)
+}
+
+/// Maps `p` to the prime-order subgroup of G2, with the endomorphism-based
+/// cofactor clearing of [\[BP17\]](https://eprint.iacr.org/2017/419)
+/// (Section 4.1), which computes
+/// `[h(ψ)]p = [x² - x - 1]p + [x - 1]ψ(p) + ψ²(2p)`. This is equivalent to
+/// a multiplication by the effective cofactor `h_eff` of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380), but costs two
+/// multiplications by `x` rather than one by the much larger `h_eff`.
+///
+/// Curves opt in by calling this from [`SWCurveConfig::clear_cofactor`].
+pub fn clear_cofactor<P: Bls12Config>(p: &G2Affine<P>) -> G2Affine<P> {
+    clear_cofactor_projective::<P>(&p.into_group()).into_affine()
+}
+
+/// Computes [`clear_cofactor`] in Jacobian coordinates.
+///
+/// Curves opt in by calling this from
+/// [`SWCurveConfig::clear_cofactor_projective`].
+pub fn clear_cofactor_projective<P: Bls12Config>(p: &G2Projective<P>) -> G2Projective<P> {
+    // [x]P
+    let x_p = mul_by_x::<P>(p);
+    // ψ(P)
+    let psi_p = mul_by_char_projective::<P>(*p);
+    // ψ²(2P)
+    let mut res = double_mul_by_char::<P>(p.double());
+
+    // [x²]P + [x]ψ(P)
+    res += mul_by_x::<P>(&(x_p + psi_p));
+
+    res -= x_p;
+    res -= psi_p;
+    res -= *p;
+    res
+}
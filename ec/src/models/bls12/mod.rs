@@ -1,6 +1,6 @@
 use crate::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
-    pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    pairing::{fill_chunk, MillerLoopOutput, Pairing, PairingOutput},
     AffineRepr,
 };
 use ark_ff::{
@@ -33,6 +33,15 @@ pub trait Bls12Config: 'static + Sized {
     const X_IS_NEGATIVE: bool;
     /// What kind of twist is this?
     const TWIST_TYPE: TwistType;
+    /// The coefficients by which the untwist-Frobenius-twist endomorphism
+    /// `ψ(x, y) = (x^p * TWIST_MUL_BY_Q_X, y^p * TWIST_MUL_BY_Q_Y)` of G2
+    /// multiplies the Frobenius of each coordinate. For a D-type twist with
+    /// non-residue `ξ`, these are `ξ^((p - 1) / 3)` and `ξ^((p - 1) / 2)`,
+    /// i.e. `Fp6Config::FROBENIUS_COEFF_FP6_C1[1]` and the cube of
+    /// `Fp12Config::FROBENIUS_COEFF_FP12_C1[1]`; for an M-type twist, they
+    /// are their inverses.
+    const TWIST_MUL_BY_Q_X: Fp2<Self::Fp2Config>;
+    const TWIST_MUL_BY_Q_Y: Fp2<Self::Fp2Config>;
 
     type Fp: PrimeField + Into<<Self::Fp as PrimeField>::BigInt>;
     type Fp2Config: Fp2Config<Fp = Self::Fp>;
@@ -63,21 +72,7 @@ pub trait Bls12Config: 'static + Sized {
             .collect::<Vec<_>>();
 
         let mut f = cfg_chunks_mut!(pairs, 4)
-            .map(|pairs| {
-                let mut f = <Bls12<Self> as Pairing>::TargetField::one();
-                for i in BitIteratorBE::without_leading_zeros(Self::X).skip(1) {
-                    f.square_in_place();
-                    for (p, coeffs) in pairs.iter_mut() {
-                        Bls12::<Self>::ell(&mut f, &coeffs.next().unwrap(), &p.0);
-                    }
-                    if i {
-                        for (p, coeffs) in pairs.iter_mut() {
-                            Bls12::<Self>::ell(&mut f, &coeffs.next().unwrap(), &p.0);
-                        }
-                    }
-                }
-                f
-            })
+            .map(Bls12::<Self>::miller_loop_chunk)
             .product::<<Bls12<Self> as Pairing>::TargetField>();
 
         if Self::X_IS_NEGATIVE {
@@ -86,74 +81,53 @@ pub trait Bls12Config: 'static + Sized {
         MillerLoopOutput(f)
     }
 
+    /// Computes the Miller loop as in [`Self::multi_miller_loop`], but takes
+    /// the pairs four at a time from `pairs`, without collecting them.
+    fn multi_miller_loop_prepared(
+        pairs: impl IntoIterator<Item = (G1Prepared<Self>, G2Prepared<Self>)>,
+    ) -> MillerLoopOutput<Bls12<Self>> {
+        let mut pairs = pairs
+            .into_iter()
+            .filter(|(p, q)| !p.is_zero() && !q.is_zero())
+            .map(|(p, q)| (p, q.ell_coeffs.into_iter()));
+
+        let mut f = <Bls12<Self> as Pairing>::TargetField::one();
+        let mut chunk: [(G1Prepared<Self>, IntoIter<g2::EllCoeff<Self>>); 4] = Default::default();
+        loop {
+            let len = fill_chunk(&mut chunk, &mut pairs);
+            if len == 0 {
+                break;
+            }
+            f *= Bls12::<Self>::miller_loop_chunk(&mut chunk[..len]);
+        }
+
+        if Self::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+        MillerLoopOutput(f)
+    }
+
+    /// Computes the hard part of the final exponentiation, i.e., raises an
+    /// element of the cyclotomic subgroup to a multiple of
+    /// `(p^4 - p^2 + 1) / r`.
+    ///
+    /// Defaults to [`Bls12::final_exponentiation_hard_part`]; curves may
+    /// select [`Bls12::final_exponentiation_hard_part_fuentes`] instead, which
+    /// computes the same power with a different addition chain, or
+    /// [`Bls12::final_exponentiation_hard_part_compressed`], which computes
+    /// the same chain with compressed cyclotomic squarings.
+    fn final_exponentiation_hard_part(f: &Fp12<Self::Fp12Config>) -> Fp12<Self::Fp12Config> {
+        Bls12::<Self>::final_exponentiation_hard_part(f)
+    }
+
     fn final_exponentiation(
         f: MillerLoopOutput<Bls12<Self>>,
     ) -> Option<PairingOutput<Bls12<Self>>> {
         // Computing the final exponentiation following
         // https://eprint.iacr.org/2020/875
         // Adapted from the implementation in https://github.com/ConsenSys/gurvy/pull/29
-
-        // f1 = r.cyclotomic_inverse_in_place() = f^(p^6)
-        let f = f.0;
-        let mut f1 = f;
-        f1.cyclotomic_inverse_in_place();
-
-        f.inverse().map(|mut f2| {
-            // f2 = f^(-1);
-            // r = f^(p^6 - 1)
-            let mut r = f1 * &f2;
-
-            // f2 = f^(p^6 - 1)
-            f2 = r;
-            // r = f^((p^6 - 1)(p^2))
-            r.frobenius_map_in_place(2);
-
-            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
-            // r = f^((p^6 - 1)(p^2 + 1))
-            r *= &f2;
-
-            // Hard part of the final exponentiation:
-            // t[0].CyclotomicSquare(&result)
-            let mut y0 = r.cyclotomic_square();
-            // t[1].Expt(&result)
-            let mut y1 = Fp12::zero();
-            Bls12::<Self>::exp_by_x(&r, &mut y1);
-            // t[2].InverseUnitary(&result)
-            let mut y2 = r;
-            y2.cyclotomic_inverse_in_place();
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // t[2].Expt(&t[1])
-            Bls12::<Self>::exp_by_x(&y1, &mut y2);
-            // t[1].InverseUnitary(&t[1])
-            y1.cyclotomic_inverse_in_place();
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // t[2].Expt(&t[1])
-            Bls12::<Self>::exp_by_x(&y1, &mut y2);
-            // t[1].Frobenius(&t[1])
-            y1.frobenius_map_in_place(1);
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // result.Mul(&result, &t[0])
-            r *= &y0;
-            // t[0].Expt(&t[1])
-            Bls12::<Self>::exp_by_x(&y1, &mut y0);
-            // t[2].Expt(&t[0])
-            Bls12::<Self>::exp_by_x(&y0, &mut y2);
-            // t[0].FrobeniusSquare(&t[1])
-            y0 = y1;
-            y0.frobenius_map_in_place(2);
-            // t[1].InverseUnitary(&t[1])
-            y1.cyclotomic_inverse_in_place();
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // t[1].Mul(&t[1], &t[0])
-            y1 *= &y0;
-            // result.Mul(&result, &t[1])
-            r *= &y1;
-            PairingOutput(r)
-        })
+        Bls12::<Self>::final_exponentiation_easy_part(f.0)
+            .map(|r| PairingOutput(Self::final_exponentiation_hard_part(&r)))
     }
 }
 
@@ -170,6 +144,26 @@ pub use self::{
 pub struct Bls12<P: Bls12Config>(PhantomData<fn() -> P>);
 
 impl<P: Bls12Config> Bls12<P> {
+    /// Computes the Miller loop over `pairs`, without the final inversion
+    /// for negative `x`, sharing the squarings across the pairs.
+    fn miller_loop_chunk(
+        pairs: &mut [(G1Prepared<P>, IntoIter<g2::EllCoeff<P>>)],
+    ) -> Fp12<P::Fp12Config> {
+        let mut f = Fp12::<P::Fp12Config>::one();
+        for i in BitIteratorBE::without_leading_zeros(P::X).skip(1) {
+            f.square_in_place();
+            for (p, coeffs) in pairs.iter_mut() {
+                Self::ell(&mut f, &coeffs.next().unwrap(), &p.0);
+            }
+            if i {
+                for (p, coeffs) in pairs.iter_mut() {
+                    Self::ell(&mut f, &coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+        f
+    }
+
     // Evaluate the line function at point p.
     fn ell(f: &mut Fp12<P::Fp12Config>, coeffs: &g2::EllCoeff<P>, p: &G1Affine<P>) {
         let mut c0 = coeffs.0;
@@ -191,6 +185,204 @@ impl<P: Bls12Config> Bls12<P> {
         }
     }
 
+    /// Computes `f^exp` for `f` in the target group, or returns `None` if
+    /// `P::X` does not fit in a single limb.
+    ///
+    /// The Frobenius acts on the target group as exponentiation by
+    /// `p ≡ x (mod r)`, so writing `exp mod r = k_0 + k_1 |x| + k_2 |x|^2 +
+    /// k_3 |x|^3` with 64-bit digits `k_i` turns the exponentiation into a
+    /// four-way multi-exponentiation with a quarter of the squarings.
+    ///
+    /// The squarings are the Granger-Scott cyclotomic squarings of
+    /// [`CyclotomicMultSubgroup::cyclotomic_square_in_place`]. Karabina's
+    /// compressed squarings are not used: they only pay off for long runs of
+    /// squarings, which must then be decompressed with an inversion, whereas
+    /// here a random exponent leaves a multiplication after all but one in
+    /// sixteen squarings.
+    fn target_exp_by_frobenius(
+        f: &Fp12<P::Fp12Config>,
+        exp: &[u64],
+    ) -> Option<Fp12<P::Fp12Config>> {
+        let [x] = P::X else {
+            return None;
+        };
+        let bytes = exp.iter().flat_map(|l| l.to_le_bytes()).collect::<Vec<_>>();
+        let k = <Self as Pairing>::ScalarField::from_le_bytes_mod_order(&bytes).into_bigint();
+        let mut k = k.as_ref().to_vec();
+
+        let mut digits = [0u64; 4];
+        for digit in &mut digits[..3] {
+            // Divide `k` by `|x|` in place, keeping the remainder as the digit.
+            let mut rem = 0u128;
+            for limb in k.iter_mut().rev() {
+                let cur = (rem << 64) | u128::from(*limb);
+                *limb = (cur / u128::from(*x)) as u64;
+                rem = cur % u128::from(*x);
+            }
+            *digit = rem as u64;
+        }
+        if k[1..].iter().any(|l| *l != 0) {
+            return None;
+        }
+        digits[3] = k[0];
+
+        // `bases[i] = f^(|x|^i)`.
+        let mut bases = [*f; 4];
+        for i in 1..4 {
+            bases[i] = bases[i - 1];
+            bases[i].frobenius_map_in_place(1);
+            if P::X_IS_NEGATIVE {
+                bases[i].cyclotomic_inverse_in_place();
+            }
+        }
+        // `table[m]` is the product of the `bases[i]` for the bits `i` set in `m`.
+        let mut table = [Fp12::<P::Fp12Config>::one(); 16];
+        for m in 1..16usize {
+            let i = m.trailing_zeros() as usize;
+            table[m] = table[m & (m - 1)] * bases[i];
+        }
+
+        let mut result = Fp12::<P::Fp12Config>::one();
+        for bit in (0..64).rev() {
+            result.cyclotomic_square_in_place();
+            let m = digits
+                .iter()
+                .enumerate()
+                .fold(0, |m, (i, d)| m | (((d >> bit) & 1) as usize) << i);
+            if m != 0 {
+                result *= &table[m];
+            }
+        }
+        Some(result)
+    }
+
+    /// Computes the easy part of the final exponentiation,
+    /// `f^((p^6 - 1)(p^2 + 1))`, or returns `None` if `f` is zero.
+    pub fn final_exponentiation_easy_part(f: Fp12<P::Fp12Config>) -> Option<Fp12<P::Fp12Config>> {
+        // f1 = r.cyclotomic_inverse_in_place() = f^(p^6)
+        let mut f1 = f;
+        f1.cyclotomic_inverse_in_place();
+
+        f.inverse().map(|mut f2| {
+            // f2 = f^(-1);
+            // r = f^(p^6 - 1)
+            let mut r = f1 * &f2;
+
+            // f2 = f^(p^6 - 1)
+            f2 = r;
+            // r = f^((p^6 - 1)(p^2))
+            r.frobenius_map_in_place(2);
+
+            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
+            // r = f^((p^6 - 1)(p^2 + 1))
+            r *= &f2;
+            r
+        })
+    }
+
+    /// Raises `f` to the power `3(p^4 - p^2 + 1) / r`, following
+    /// <https://eprint.iacr.org/2020/875>.
+    pub fn final_exponentiation_hard_part(f: &Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        Self::final_exponentiation_hard_part_with(f, Self::exp_by_x)
+    }
+
+    /// Computes the same power as [`Self::final_exponentiation_hard_part`],
+    /// but exponentiates by `x` with [`Fp12::cyclotomic_exp_compressed`],
+    /// which is faster when `x` has few set bits, as for BLS12-381.
+    pub fn final_exponentiation_hard_part_compressed(
+        f: &Fp12<P::Fp12Config>,
+    ) -> Fp12<P::Fp12Config> {
+        Self::final_exponentiation_hard_part_with(f, Self::exp_by_x_compressed)
+    }
+
+    /// Computes [`Self::final_exponentiation_hard_part`], exponentiating by
+    /// `x` with `exp_by_x`.
+    fn final_exponentiation_hard_part_with(
+        f: &Fp12<P::Fp12Config>,
+        exp_by_x: fn(&Fp12<P::Fp12Config>, &mut Fp12<P::Fp12Config>),
+    ) -> Fp12<P::Fp12Config> {
+        let mut r = *f;
+        // Hard part of the final exponentiation:
+        // t[0].CyclotomicSquare(&result)
+        let mut y0 = r.cyclotomic_square();
+        // t[1].Expt(&result)
+        let mut y1 = Fp12::zero();
+        exp_by_x(&r, &mut y1);
+        // t[2].InverseUnitary(&result)
+        let mut y2 = r;
+        y2.cyclotomic_inverse_in_place();
+        // t[1].Mul(&t[1], &t[2])
+        y1 *= &y2;
+        // t[2].Expt(&t[1])
+        exp_by_x(&y1, &mut y2);
+        // t[1].InverseUnitary(&t[1])
+        y1.cyclotomic_inverse_in_place();
+        // t[1].Mul(&t[1], &t[2])
+        y1 *= &y2;
+        // t[2].Expt(&t[1])
+        exp_by_x(&y1, &mut y2);
+        // t[1].Frobenius(&t[1])
+        y1.frobenius_map_in_place(1);
+        // t[1].Mul(&t[1], &t[2])
+        y1 *= &y2;
+        // result.Mul(&result, &t[0])
+        r *= &y0;
+        // t[0].Expt(&t[1])
+        exp_by_x(&y1, &mut y0);
+        // t[2].Expt(&t[0])
+        exp_by_x(&y0, &mut y2);
+        // t[0].FrobeniusSquare(&t[1])
+        y0 = y1;
+        y0.frobenius_map_in_place(2);
+        // t[1].InverseUnitary(&t[1])
+        y1.cyclotomic_inverse_in_place();
+        // t[1].Mul(&t[1], &t[2])
+        y1 *= &y2;
+        // t[1].Mul(&t[1], &t[0])
+        y1 *= &y0;
+        // result.Mul(&result, &t[1])
+        r *= &y1;
+        r
+    }
+
+    /// Raises `f` to the power `3(p^4 - p^2 + 1) / r`, following
+    /// Fuentes-Castañeda et al. "Faster hashing to G2", which writes this
+    /// exponent as `λ_0 + λ_1 p + λ_2 p^2 + λ_3 p^3` with
+    /// `λ_3 = (x - 1)^2`, `λ_2 = λ_3 x`, `λ_1 = λ_2 x - λ_3` and
+    /// `λ_0 = λ_1 x + 3`.
+    pub fn final_exponentiation_hard_part_fuentes(f: &Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        let mut t = Fp12::zero();
+        // a = f^(x - 1)
+        Self::exp_by_x(f, &mut t);
+        let mut f_inv = *f;
+        f_inv.cyclotomic_inverse_in_place();
+        let a = t * &f_inv;
+        // l3 = f^((x - 1)^2)
+        let mut l3 = Fp12::zero();
+        Self::exp_by_x(&a, &mut l3);
+        let mut a_inv = a;
+        a_inv.cyclotomic_inverse_in_place();
+        l3 *= &a_inv;
+        // l2 = f^(λ_3 x)
+        let mut l2 = Fp12::zero();
+        Self::exp_by_x(&l3, &mut l2);
+        // l1 = f^(λ_2 x - λ_3)
+        let mut l1 = Fp12::zero();
+        Self::exp_by_x(&l2, &mut l1);
+        let mut l3_inv = l3;
+        l3_inv.cyclotomic_inverse_in_place();
+        l1 *= &l3_inv;
+        // l0 = f^(λ_1 x + 3)
+        let mut l0 = Fp12::zero();
+        Self::exp_by_x(&l1, &mut l0);
+        l0 *= &(f.cyclotomic_square() * f);
+
+        l1.frobenius_map_in_place(1);
+        l2.frobenius_map_in_place(2);
+        l3.frobenius_map_in_place(3);
+        l0 * &l1 * &l2 * &l3
+    }
+
     // Exponentiates `f` by `Self::X`, and stores the result in `result`.
     fn exp_by_x(f: &Fp12<P::Fp12Config>, result: &mut Fp12<P::Fp12Config>) {
         *result = f.cyclotomic_exp(P::X);
@@ -198,6 +390,14 @@ impl<P: Bls12Config> Bls12<P> {
             result.cyclotomic_inverse_in_place();
         }
     }
+
+    // As `exp_by_x`, with compressed cyclotomic squarings.
+    fn exp_by_x_compressed(f: &Fp12<P::Fp12Config>, result: &mut Fp12<P::Fp12Config>) {
+        *result = f.cyclotomic_exp_compressed(P::X);
+        if P::X_IS_NEGATIVE {
+            result.cyclotomic_inverse_in_place();
+        }
+    }
 }
 
 impl<P: Bls12Config> Pairing for Bls12<P> {
@@ -218,7 +418,20 @@ impl<P: Bls12Config> Pairing for Bls12<P> {
         P::multi_miller_loop(a, b)
     }
 
+    fn multi_miller_loop_prepared(
+        pairs: impl IntoIterator<Item = (Self::G1Prepared, Self::G2Prepared)>,
+    ) -> MillerLoopOutput<Self> {
+        P::multi_miller_loop_prepared(pairs)
+    }
+
     fn final_exponentiation(f: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
         P::final_exponentiation(f)
     }
+
+    fn target_mul_bigint(f: &PairingOutput<Self>, exp: impl AsRef<[u64]>) -> PairingOutput<Self> {
+        let exp = exp.as_ref();
+        PairingOutput(
+            Self::target_exp_by_frobenius(&f.0, exp).unwrap_or_else(|| f.0.cyclotomic_exp(exp)),
+        )
+    }
 }
@@ -2,7 +2,10 @@ use ark_ff::{
     fields::{Field, Fp2},
     AdditiveGroup,
 };
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    ValidationError,
+};
 use ark_std::vec::*;
 use educe::Educe;
 use num_traits::One;
@@ -11,13 +14,13 @@ use crate::{
     bn::{BnConfig, TwistType},
     models::short_weierstrass::SWCurveConfig,
     short_weierstrass::{Affine, Projective},
-    AffineRepr, CurveGroup,
+    AffineRepr, CurveGroup, PrimeGroup,
 };
 
 pub type G2Affine<P> = Affine<<P as BnConfig>::G2Config>;
 pub type G2Projective<P> = Projective<<P as BnConfig>::G2Config>;
 
-#[derive(Educe, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Educe, CanonicalSerialize)]
 #[educe(Clone, Debug, PartialEq, Eq)]
 pub struct G2Prepared<P: BnConfig> {
     /// Stores the coefficients of the line evaluations as calculated in
@@ -90,6 +93,65 @@ impl<P: BnConfig> G2HomProjective<P> {
     }
 }
 
+impl<P: BnConfig> G2Prepared<P> {
+    /// Recovers the prepared point from the line of the first doubling step,
+    /// which is computed from `(x, y, 1)`, so that `h = 2 * y` and `j = x²`.
+    fn point(&self) -> Option<G2Affine<P>> {
+        let (c0, c1, c2) = self.ell_coeffs.first()?;
+        let neg_h = match P::TWIST_TYPE {
+            TwistType::M => c2,
+            TwistType::D => c0,
+        };
+        let two = P::Fp::one().double();
+        let mut y = -*neg_h;
+        y.mul_assign_by_fp(&two.inverse().unwrap());
+        let mut x_squared = *c1;
+        x_squared.mul_assign_by_fp(&(two + P::Fp::one()).inverse().unwrap());
+        let x = x_squared.sqrt()?;
+        [x, -x]
+            .into_iter()
+            .map(|x| G2Affine::<P>::new_unchecked(x, y))
+            .find(|q| q.is_on_curve())
+    }
+}
+
+impl<P: BnConfig> Valid for G2Prepared<P> {
+    /// Checks that the line coefficients are those of a point of G2, by
+    /// recovering the point from the first doubling step, checking it, and
+    /// preparing it again.
+    fn check(&self) -> Result<(), SerializationError> {
+        let expected = if self.infinity {
+            Self::from(G2Affine::<P>::identity())
+        } else {
+            let q = self.point().ok_or(ValidationError::NotOnCurve)?;
+            q.check()?;
+            Self::from(q)
+        };
+        if expected == *self {
+            Ok(())
+        } else {
+            Err(ValidationError::InvalidPrecomputation.into())
+        }
+    }
+}
+
+impl<P: BnConfig> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let prepared = Self {
+            ell_coeffs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            infinity: bool::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            prepared.check()?;
+        }
+        Ok(prepared)
+    }
+}
+
 impl<P: BnConfig> Default for G2Prepared<P> {
     fn default() -> Self {
         Self::from(G2Affine::<P>::generator())
@@ -179,3 +241,20 @@ fn mul_by_char<P: BnConfig>(r: G2Affine<P>) -> G2Affine<P> {
 
     s
 }
+
+/// Checks that `p`, assumed to be on the curve, is in the prime-order
+/// subgroup of G2, with the test of Section 4.3 of
+/// [\[HGP22\]](https://eprint.iacr.org/2022/352): `p` is in G2 if and only if
+/// `ψ(p) = [6x²]p`, where `ψ` is the untwist-Frobenius-twist endomorphism and
+/// `x` is the BN parameter [`BnConfig::X`]. This costs two multiplications
+/// by `x`, rather than a multiplication by the subgroup order.
+///
+/// Curves opt in by calling this from
+/// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`].
+pub fn is_in_correct_subgroup_assuming_on_curve<P: BnConfig>(p: &G2Affine<P>) -> bool {
+    let x_squared_times_p = p.mul_bigint(P::X).mul_bigint(P::X);
+    let mut six_x_squared_times_p = x_squared_times_p.double();
+    six_x_squared_times_p += x_squared_times_p;
+    six_x_squared_times_p.double_in_place();
+    six_x_squared_times_p == mul_by_char::<P>(*p)
+}
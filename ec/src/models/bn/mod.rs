@@ -1,6 +1,6 @@
 use crate::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
-    pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    pairing::{fill_chunk, MillerLoopOutput, Pairing, PairingOutput},
 };
 use ark_ff::{
     fields::{
@@ -65,26 +65,7 @@ pub trait BnConfig: 'static + Sized {
             .collect::<Vec<_>>();
 
         let mut f = cfg_chunks_mut!(pairs, 4)
-            .map(|pairs| {
-                let mut f = <Bn<Self> as Pairing>::TargetField::one();
-                for i in (1..Self::ATE_LOOP_COUNT.len()).rev() {
-                    if i != Self::ATE_LOOP_COUNT.len() - 1 {
-                        f.square_in_place();
-                    }
-
-                    for (p, coeffs) in pairs.iter_mut() {
-                        Bn::<Self>::ell(&mut f, &coeffs.next().unwrap(), &p.0);
-                    }
-
-                    let bit = Self::ATE_LOOP_COUNT[i - 1];
-                    if bit == 1 || bit == -1 {
-                        for (p, coeffs) in pairs.iter_mut() {
-                            Bn::<Self>::ell(&mut f, &coeffs.next().unwrap(), &p.0);
-                        }
-                    }
-                }
-                f
-            })
+            .map(Bn::<Self>::miller_loop_chunk)
             .product::<<Bn<Self> as Pairing>::TargetField>();
 
         if Self::X_IS_NEGATIVE {
@@ -102,68 +83,55 @@ pub trait BnConfig: 'static + Sized {
         MillerLoopOutput(f)
     }
 
-    #[allow(clippy::let_and_return)]
-    fn final_exponentiation(f: MillerLoopOutput<Bn<Self>>) -> Option<PairingOutput<Bn<Self>>> {
-        // Easy part: result = elt^((q^6-1)*(q^2+1)).
-        // Follows, e.g., Beuchat et al page 9, by computing result as follows:
-        //   elt^((q^6-1)*(q^2+1)) = (conj(elt) * elt^(-1))^(q^2+1)
-        let f = f.0;
-
-        // f1 = r.cyclotomic_inverse_in_place() = f^(p^6)
-        let mut f1 = f;
-        f1.cyclotomic_inverse_in_place();
+    /// Computes the Miller loop as in [`Self::multi_miller_loop`], but takes
+    /// the pairs four at a time from `pairs`, without collecting them.
+    fn multi_miller_loop_prepared(
+        pairs: impl IntoIterator<Item = (G1Prepared<Self>, G2Prepared<Self>)>,
+    ) -> MillerLoopOutput<Bn<Self>> {
+        let mut pairs = pairs
+            .into_iter()
+            .filter(|(p, q)| !p.is_zero() && !q.is_zero())
+            .map(|(p, q)| (p, q.ell_coeffs.into_iter()));
 
-        f.inverse().map(|mut f2| {
-            // f2 = f^(-1);
-            // r = f^(p^6 - 1)
-            let mut r = f1 * &f2;
+        // The final two lines of each pair are not affected by the inversion
+        // for negative `x`, and so are accumulated separately.
+        let mut f = <Bn<Self> as Pairing>::TargetField::one();
+        let mut final_lines = <Bn<Self> as Pairing>::TargetField::one();
+        let mut chunk: [(G1Prepared<Self>, IntoIter<g2::EllCoeff<Self>>); 4] = Default::default();
+        loop {
+            let len = fill_chunk(&mut chunk, &mut pairs);
+            if len == 0 {
+                break;
+            }
+            f *= Bn::<Self>::miller_loop_chunk(&mut chunk[..len]);
+            for (p, coeffs) in &mut chunk[..len] {
+                Bn::<Self>::ell(&mut final_lines, &coeffs.next().unwrap(), &p.0);
+                Bn::<Self>::ell(&mut final_lines, &coeffs.next().unwrap(), &p.0);
+            }
+        }
 
-            // f2 = f^(p^6 - 1)
-            f2 = r;
-            // r = f^((p^6 - 1)(p^2))
-            r.frobenius_map_in_place(2);
+        if Self::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+        MillerLoopOutput(f * final_lines)
+    }
 
-            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
-            // r = f^((p^6 - 1)(p^2 + 1))
-            r *= &f2;
+    /// Computes the hard part of the final exponentiation, i.e., raises an
+    /// element of the cyclotomic subgroup to a multiple of
+    /// `(p^4 - p^2 + 1) / r`.
+    ///
+    /// Defaults to [`Bn::final_exponentiation_hard_part`]; curves may select
+    /// [`Bn::final_exponentiation_hard_part_classic`] instead, which changes
+    /// the pairing by a fixed power, so all parties must agree on it, or
+    /// [`Bn::final_exponentiation_hard_part_compressed`], which computes the
+    /// same power as the default with compressed cyclotomic squarings.
+    fn final_exponentiation_hard_part(f: &Fp12<Self::Fp12Config>) -> Fp12<Self::Fp12Config> {
+        Bn::<Self>::final_exponentiation_hard_part(f)
+    }
 
-            // Hard part follows Laura Fuentes-Castaneda et al. "Faster hashing to G2"
-            // by computing:
-            //
-            // result = elt^(q^3 * (12*z^3 + 6z^2 + 4z - 1) +
-            //               q^2 * (12*z^3 + 6z^2 + 6z) +
-            //               q   * (12*z^3 + 6z^2 + 4z) +
-            //               1   * (12*z^3 + 12z^2 + 6z + 1))
-            // which equals
-            //
-            // result = elt^( 2z * ( 6z^2 + 3z + 1 ) * (q^4 - q^2 + 1)/r ).
-
-            let y0 = Bn::<Self>::exp_by_neg_x(r);
-            let y1 = y0.cyclotomic_square();
-            let y2 = y1.cyclotomic_square();
-            let mut y3 = y2 * &y1;
-            let y4 = Bn::<Self>::exp_by_neg_x(y3);
-            let y5 = y4.cyclotomic_square();
-            let mut y6 = Bn::<Self>::exp_by_neg_x(y5);
-            y3.cyclotomic_inverse_in_place();
-            y6.cyclotomic_inverse_in_place();
-            let y7 = y6 * &y4;
-            let mut y8 = y7 * &y3;
-            let y9 = y8 * &y1;
-            let y10 = y8 * &y4;
-            let y11 = y10 * &r;
-            let mut y12 = y9;
-            y12.frobenius_map_in_place(1);
-            let y13 = y12 * &y11;
-            y8.frobenius_map_in_place(2);
-            let y14 = y8 * &y13;
-            r.cyclotomic_inverse_in_place();
-            let mut y15 = r * &y9;
-            y15.frobenius_map_in_place(3);
-            let y16 = y15 * &y14;
-
-            PairingOutput(y16)
-        })
+    fn final_exponentiation(f: MillerLoopOutput<Bn<Self>>) -> Option<PairingOutput<Bn<Self>>> {
+        Bn::<Self>::final_exponentiation_easy_part(f.0)
+            .map(|r| PairingOutput(Self::final_exponentiation_hard_part(&r)))
     }
 }
 
@@ -180,6 +148,32 @@ pub use self::{
 pub struct Bn<P: BnConfig>(PhantomData<fn() -> P>);
 
 impl<P: BnConfig> Bn<P> {
+    /// Computes the Miller loop over `pairs`, without the inversion for
+    /// negative `x` and the final two lines, sharing the squarings across
+    /// the pairs.
+    fn miller_loop_chunk(
+        pairs: &mut [(G1Prepared<P>, IntoIter<g2::EllCoeff<P>>)],
+    ) -> Fp12<P::Fp12Config> {
+        let mut f = Fp12::<P::Fp12Config>::one();
+        for i in (1..P::ATE_LOOP_COUNT.len()).rev() {
+            if i != P::ATE_LOOP_COUNT.len() - 1 {
+                f.square_in_place();
+            }
+
+            for (p, coeffs) in pairs.iter_mut() {
+                Self::ell(&mut f, &coeffs.next().unwrap(), &p.0);
+            }
+
+            let bit = P::ATE_LOOP_COUNT[i - 1];
+            if bit == 1 || bit == -1 {
+                for (p, coeffs) in pairs.iter_mut() {
+                    Self::ell(&mut f, &coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+        f
+    }
+
     /// Evaluates the line function at point p.
     fn ell(f: &mut Fp12<P::Fp12Config>, coeffs: &g2::EllCoeff<P>, p: &G1Affine<P>) {
         let mut c0 = coeffs.0;
@@ -200,6 +194,132 @@ impl<P: BnConfig> Bn<P> {
         }
     }
 
+    /// Computes the easy part of the final exponentiation,
+    /// `f^((p^6 - 1)(p^2 + 1))`, or returns `None` if `f` is zero.
+    pub fn final_exponentiation_easy_part(f: Fp12<P::Fp12Config>) -> Option<Fp12<P::Fp12Config>> {
+        // Easy part: result = elt^((q^6-1)*(q^2+1)).
+        // Follows, e.g., Beuchat et al page 9, by computing result as follows:
+        //   elt^((q^6-1)*(q^2+1)) = (conj(elt) * elt^(-1))^(q^2+1)
+
+        // f1 = r.cyclotomic_inverse_in_place() = f^(p^6)
+        let mut f1 = f;
+        f1.cyclotomic_inverse_in_place();
+
+        f.inverse().map(|mut f2| {
+            // f2 = f^(-1);
+            // r = f^(p^6 - 1)
+            let mut r = f1 * &f2;
+
+            // f2 = f^(p^6 - 1)
+            f2 = r;
+            // r = f^((p^6 - 1)(p^2))
+            r.frobenius_map_in_place(2);
+
+            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
+            // r = f^((p^6 - 1)(p^2 + 1))
+            r *= &f2;
+            r
+        })
+    }
+
+    /// Raises `f` to the power `2z(6z^2 + 3z + 1)(p^4 - p^2 + 1) / r`.
+    pub fn final_exponentiation_hard_part(f: &Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        Self::final_exponentiation_hard_part_with(f, Self::exp_by_neg_x)
+    }
+
+    /// Computes the same power as [`Self::final_exponentiation_hard_part`],
+    /// but exponentiates by `z` with [`Fp12::cyclotomic_exp_compressed`],
+    /// which is faster when `z` has few set bits.
+    pub fn final_exponentiation_hard_part_compressed(
+        f: &Fp12<P::Fp12Config>,
+    ) -> Fp12<P::Fp12Config> {
+        Self::final_exponentiation_hard_part_with(f, Self::exp_by_neg_x_compressed)
+    }
+
+    /// Computes [`Self::final_exponentiation_hard_part`], exponentiating by
+    /// `-z` with `exp_by_neg_x`.
+    fn final_exponentiation_hard_part_with(
+        f: &Fp12<P::Fp12Config>,
+        exp_by_neg_x: fn(Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config>,
+    ) -> Fp12<P::Fp12Config> {
+        // Hard part follows Laura Fuentes-Castaneda et al. "Faster hashing to G2"
+        // by computing:
+        //
+        // result = elt^(q^3 * (12*z^3 + 6z^2 + 4z - 1) +
+        //               q^2 * (12*z^3 + 6z^2 + 6z) +
+        //               q   * (12*z^3 + 6z^2 + 4z) +
+        //               1   * (12*z^3 + 12z^2 + 6z + 1))
+        // which equals
+        //
+        // result = elt^( 2z * ( 6z^2 + 3z + 1 ) * (q^4 - q^2 + 1)/r ).
+        let mut r = *f;
+        let y0 = exp_by_neg_x(r);
+        let y1 = y0.cyclotomic_square();
+        let y2 = y1.cyclotomic_square();
+        let mut y3 = y2 * &y1;
+        let y4 = exp_by_neg_x(y3);
+        let y5 = y4.cyclotomic_square();
+        let mut y6 = exp_by_neg_x(y5);
+        y3.cyclotomic_inverse_in_place();
+        y6.cyclotomic_inverse_in_place();
+        let y7 = y6 * &y4;
+        let mut y8 = y7 * &y3;
+        let y9 = y8 * &y1;
+        let y10 = y8 * &y4;
+        let y11 = y10 * &r;
+        let mut y12 = y9;
+        y12.frobenius_map_in_place(1);
+        let y13 = y12 * &y11;
+        y8.frobenius_map_in_place(2);
+        let y14 = y8 * &y13;
+        r.cyclotomic_inverse_in_place();
+        let mut y15 = r * &y9;
+        y15.frobenius_map_in_place(3);
+        y15 * &y14
+    }
+
+    /// Raises `f` to the power `(p^4 - p^2 + 1) / r`, following Scott et al.
+    /// "On the final exponentiation for calculating pairings on ordinary
+    /// elliptic curves", which writes the exponent in base `p` with
+    /// coefficients that are polynomials in `z`.
+    pub fn final_exponentiation_hard_part_classic(f: &Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        let exp_by_x = |f: Fp12<P::Fp12Config>| {
+            let mut f = Self::exp_by_neg_x(f);
+            f.cyclotomic_inverse_in_place();
+            f
+        };
+        let frobenius = |f: Fp12<P::Fp12Config>, power: usize| {
+            let mut f = f;
+            f.frobenius_map_in_place(power);
+            f
+        };
+        let conjugate = |f: Fp12<P::Fp12Config>| {
+            let mut f = f;
+            f.cyclotomic_inverse_in_place();
+            f
+        };
+
+        let fu = exp_by_x(*f);
+        let fu2 = exp_by_x(fu);
+        let fu3 = exp_by_x(fu2);
+
+        let y0 = frobenius(*f, 1) * frobenius(*f, 2) * frobenius(*f, 3);
+        let y1 = conjugate(*f);
+        let y2 = frobenius(fu2, 2);
+        let y3 = conjugate(frobenius(fu, 1));
+        let y4 = conjugate(fu * frobenius(fu2, 1));
+        let y5 = conjugate(fu2);
+        let y6 = conjugate(fu3 * frobenius(fu3, 1));
+
+        let mut t0 = y6.cyclotomic_square() * y4 * y5;
+        let mut t1 = y3 * y5 * t0;
+        t0 *= y2;
+        t1 = (t1.cyclotomic_square() * t0).cyclotomic_square();
+        t0 = t1 * y1;
+        t1 *= y0;
+        t0.cyclotomic_square() * t1
+    }
+
     fn exp_by_neg_x(mut f: Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
         f = f.cyclotomic_exp(P::X);
         if !P::X_IS_NEGATIVE {
@@ -207,6 +327,14 @@ impl<P: BnConfig> Bn<P> {
         }
         f
     }
+
+    fn exp_by_neg_x_compressed(mut f: Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        f = f.cyclotomic_exp_compressed(P::X);
+        if !P::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+        f
+    }
 }
 
 impl<P: BnConfig> Pairing for Bn<P> {
@@ -227,6 +355,12 @@ impl<P: BnConfig> Pairing for Bn<P> {
         P::multi_miller_loop(a, b)
     }
 
+    fn multi_miller_loop_prepared(
+        pairs: impl IntoIterator<Item = (Self::G1Prepared, Self::G2Prepared)>,
+    ) -> MillerLoopOutput<Self> {
+        P::multi_miller_loop_prepared(pairs)
+    }
+
     fn final_exponentiation(f: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
         P::final_exponentiation(f)
     }
@@ -0,0 +1,404 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    ValidationError,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Write},
+    ops::{Add, Mul, Neg, Sub},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    vec::*,
+};
+use educe::Educe;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
+
+use super::{JQCurveConfig, Projective};
+use crate::{models::validate_point, short_weierstrass, short_weierstrass::SWFlags, AffineRepr};
+
+/// Affine coordinates for a point on a Jacobi quartic curve, over the
+/// base field `P::BaseField`.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Affine<P: JQCurveConfig> {
+    /// X coordinate of the point represented as a field element
+    pub x: P::BaseField,
+    /// Y coordinate of the point represented as a field element
+    pub y: P::BaseField,
+}
+
+impl<P: JQCurveConfig> Display for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.is_zero() {
+            true => write!(f, "infinity"),
+            false => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+impl<P: JQCurveConfig> Debug for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.is_zero() {
+            true => write!(f, "infinity"),
+            false => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+impl<P: JQCurveConfig> PartialEq<Projective<P>> for Affine<P> {
+    fn eq(&self, other: &Projective<P>) -> bool {
+        self.into_group() == *other
+    }
+}
+
+impl<P: JQCurveConfig> Affine<P> {
+    /// Construct a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField) -> Self {
+        Self { x, y }
+    }
+
+    /// Construct a new group element in a way while enforcing that points are in
+    /// the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        let p = Self::new_unchecked(x, y);
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        p
+    }
+
+    /// Like [`Self::new`], but returns why the point is invalid instead of
+    /// panicking.
+    pub fn try_new(x: P::BaseField, y: P::BaseField) -> Result<Self, ValidationError> {
+        let point = Self::new_unchecked(x, y);
+        validate_point(point.is_on_curve(), || {
+            point.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(point)
+    }
+
+    /// Construct the identity of the group
+    pub const fn zero() -> Self {
+        Self::new_unchecked(P::BaseField::ZERO, P::BaseField::ONE)
+    }
+
+    /// Is this point the identity?
+    pub fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_one()
+    }
+
+    /// Attempts to construct an affine point given an x-coordinate. The
+    /// point is not guaranteed to be in the prime order subgroup.
+    ///
+    /// If and only if `greatest` is set will the lexicographically
+    /// largest y-coordinate be selected.
+    pub fn get_point_from_x_unchecked(x: P::BaseField, greatest: bool) -> Option<Self> {
+        Self::get_ys_from_x_unchecked(x).map(|(smaller, larger)| {
+            if greatest {
+                Self::new_unchecked(x, larger)
+            } else {
+                Self::new_unchecked(x, smaller)
+            }
+        })
+    }
+
+    /// Returns the two possible y-coordinates corresponding to the given
+    /// x-coordinate, sorted by lexicographical order, or `None` if the
+    /// x-coordinate corresponds to a non-curve point.
+    ///
+    /// Y^2 = d * X^4 + 2 * a * X^2 + 1
+    pub fn get_ys_from_x_unchecked(x: P::BaseField) -> Option<(P::BaseField, P::BaseField)> {
+        Self::curve_rhs(x).sqrt().map(|y| {
+            let neg_y = -y;
+            if y <= neg_y {
+                (y, neg_y)
+            } else {
+                (neg_y, y)
+            }
+        })
+    }
+
+    /// Computes `d * x⁴ + 2 * a * x² + 1`.
+    fn curve_rhs(x: P::BaseField) -> P::BaseField {
+        let x2 = x.square();
+        P::COEFF_D * x2.square() + P::mul_by_a(x2).double() + P::BaseField::one()
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    pub fn is_on_curve(&self) -> bool {
+        self.y.square() == Self::curve_rhs(self.x)
+    }
+
+    /// Maps `self` to the birationally equivalent short Weierstrass curve
+    /// `P::SWCurveConfig`. This is a group isomorphism.
+    ///
+    /// The point `(x, y)` maps to `(2 * (s + a) - 4 * a / 3, 2 * t)`, where
+    /// `s = (y + 1) / x²` and `t = x * (s² - d)`. The identity maps to the
+    /// point at infinity, and the point `(0, -1)` of order two maps to
+    /// `(-4 * a / 3, 0)`.
+    ///
+    /// The base field must not have characteristic 2 or 3.
+    pub fn to_weierstrass(&self) -> short_weierstrass::Affine<P::SWCurveConfig> {
+        let shift = Self::weierstrass_shift();
+        if self.x.is_zero() {
+            return if self.y.is_one() {
+                short_weierstrass::Affine::identity()
+            } else {
+                short_weierstrass::Affine::new_unchecked(-shift, P::BaseField::ZERO)
+            };
+        }
+        // s = (y + 1) / x^2
+        let s = (self.y + P::BaseField::one()) * self.x.square().inverse().unwrap();
+        // t = x * (s^2 - d)
+        let t = self.x * (s.square() - P::COEFF_D);
+        short_weierstrass::Affine::new_unchecked((s + P::COEFF_A).double() - shift, t.double())
+    }
+
+    /// Maps a point on the birationally equivalent short Weierstrass curve
+    /// to `self`. This is the inverse of [`Self::to_weierstrass`].
+    ///
+    /// The base field must not have characteristic 2 or 3.
+    pub fn from_weierstrass(p: short_weierstrass::Affine<P::SWCurveConfig>) -> Self {
+        if p.infinity {
+            return Self::zero();
+        }
+        let u = p.x + Self::weierstrass_shift();
+        if u.is_zero() && p.y.is_zero() {
+            return Self::new_unchecked(P::BaseField::ZERO, -P::BaseField::ONE);
+        }
+        let two_inv = P::BaseField::from(2u8).inverse().unwrap();
+        // s = u / 2 - a
+        let s = u * two_inv - P::COEFF_A;
+        // x = t / (s^2 - d), where t = v / 2, and s^2 != d since d is not a square
+        let x = p.y * two_inv * (s.square() - P::COEFF_D).inverse().unwrap();
+        // y = s * x^2 - 1
+        let y = s * x.square() - P::BaseField::one();
+        Self::new_unchecked(x, y)
+    }
+
+    /// Returns `4 * a / 3`, the shift between the x-coordinate of the short
+    /// Weierstrass curve and that of the curve `v² = u * (u² - 4 * a * u + 4 * (a² - d))`.
+    fn weierstrass_shift() -> P::BaseField {
+        let three_inv = P::BaseField::from(3u8).inverse().unwrap();
+        P::COEFF_A.double().double() * three_inv
+    }
+}
+
+impl<P: JQCurveConfig> Affine<P> {
+    /// Checks if `self` is in the subgroup having order equaling that of
+    /// `P::ScalarField` given it is on the curve.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        P::is_in_correct_subgroup_assuming_on_curve(self)
+    }
+}
+
+impl<P: JQCurveConfig> AffineRepr for Affine<P> {
+    type Config = P;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Group = Projective<P>;
+
+    fn xy(&self) -> Option<(Self::BaseField, Self::BaseField)> {
+        (!self.is_zero()).then(|| (self.x, self.y))
+    }
+
+    fn generator() -> Self {
+        P::GENERATOR
+    }
+
+    fn zero() -> Self {
+        Self::new_unchecked(P::BaseField::ZERO, P::BaseField::ONE)
+    }
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        P::BaseField::from_random_bytes_with_flags::<SWFlags>(bytes).and_then(|(x, flags)| {
+            flags
+                .is_positive()
+                .and_then(|y_is_positive| Self::get_point_from_x_unchecked(x, !y_is_positive))
+        })
+    }
+
+    fn mul_bigint(&self, by: impl AsRef<[u64]>) -> Self::Group {
+        P::mul_affine(self, by.as_ref())
+    }
+
+    /// Multiplies this element by the cofactor and output the
+    /// resulting projective element.
+    #[must_use]
+    fn mul_by_cofactor_to_group(&self) -> Self::Group {
+        P::mul_affine(self, Self::Config::COFACTOR)
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// Some curves can implement a more efficient algorithm.
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
+    }
+}
+
+impl<P: JQCurveConfig> Zeroize for Affine<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+impl<P: JQCurveConfig> Neg for Affine<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new_unchecked(-self.x, self.y)
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: T) -> Self::Output {
+        let mut copy = self.into_group();
+        copy += other.borrow();
+        copy
+    }
+}
+
+impl<P: JQCurveConfig> Add<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: Projective<P>) -> Projective<P> {
+        other + self
+    }
+}
+
+impl<'a, P: JQCurveConfig> Add<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: &'a Projective<P>) -> Projective<P> {
+        *other + self
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Self>> Sub<T> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: T) -> Self::Output {
+        let mut copy = self.into_group();
+        copy -= other.borrow();
+        copy
+    }
+}
+
+impl<P: JQCurveConfig> Sub<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: Projective<P>) -> Projective<P> {
+        self + (-other)
+    }
+}
+
+impl<'a, P: JQCurveConfig> Sub<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: &'a Projective<P>) -> Projective<P> {
+        self + (-*other)
+    }
+}
+
+impl<P: JQCurveConfig> Default for Affine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: JQCurveConfig> Distribution<Affine<P>> for Standard {
+    /// Generates a uniformly random instance of the curve.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine<P> {
+        loop {
+            let x = P::BaseField::rand(rng);
+            let greatest = rng.gen();
+
+            if let Some(p) = Affine::get_point_from_x_unchecked(x, greatest) {
+                return p.mul_by_cofactor();
+            }
+        }
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Affine<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn mul(self, other: T) -> Self::Output {
+        self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+// The projective point X, Y, T, Z is represented in the affine
+// coordinates as X/Z, Y/Z.
+impl<P: JQCurveConfig> From<Projective<P>> for Affine<P> {
+    fn from(p: Projective<P>) -> Affine<P> {
+        if p.z.is_one() {
+            // If Z is one, the point is already normalized.
+            Affine::new_unchecked(p.x, p.y)
+        } else {
+            // Z is nonzero, since all points are affine.
+            let z_inv = p.z.inverse().unwrap();
+            let x = p.x * &z_inv;
+            let y = p.y * &z_inv;
+            Affine::new_unchecked(x, y)
+        }
+    }
+}
+
+impl<P: JQCurveConfig> CanonicalSerialize for Affine<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), SerializationError> {
+        P::serialize_with_mode(self, writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        P::serialized_size(compress)
+    }
+}
+
+impl<P: JQCurveConfig> Valid for Affine<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        validate_point(self.is_on_curve(), || {
+            self.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(())
+    }
+}
+
+impl<P: JQCurveConfig> CanonicalDeserialize for Affine<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        P::deserialize_with_mode(reader, compress, validate)
+    }
+}
+
+impl<M: JQCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        let mut x_fe = self.x.to_field_elements()?;
+        let y_fe = self.y.to_field_elements()?;
+        x_fe.extend_from_slice(&y_fe);
+        Some(x_fe)
+    }
+}
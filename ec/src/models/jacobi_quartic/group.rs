@@ -0,0 +1,467 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    io::{Read, Write},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    vec::*,
+    One, Zero,
+};
+
+use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
+
+use educe::Educe;
+use zeroize::Zeroize;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{Affine, JQCurveConfig};
+use crate::{
+    scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
+    AffineRepr, CurveGroup, PrimeGroup,
+};
+
+/// `Projective` implements extended coordinates `(X : Y : T : Z)` on Jacobi
+/// quartic curves, which represent the affine point `(X / Z, Y / Z)`, and
+/// satisfy `X² = T * Z`, i.e. `T / Z = x²`.
+///
+/// This implementation uses unified addition formulae, which are complete
+/// when `d` is not a square.
+#[derive(Educe)]
+#[educe(Copy, Clone, Eq(bound(P: JQCurveConfig)), Debug)]
+#[must_use]
+pub struct Projective<P: JQCurveConfig> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub t: P::BaseField,
+    pub z: P::BaseField,
+}
+
+impl<P: JQCurveConfig> PartialEq<Affine<P>> for Projective<P> {
+    fn eq(&self, other: &Affine<P>) -> bool {
+        *self == other.into_group()
+    }
+}
+
+impl<P: JQCurveConfig> Display for Projective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", Affine::from(*self))
+    }
+}
+
+impl<P: JQCurveConfig> PartialEq for Projective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        // Z is never zero, so no special cases are needed.
+        // x1/z1 == x2/z2  <==> x1 * z2 == x2 * z1
+        (self.x * &other.z) == (other.x * &self.z) && (self.y * &other.z) == (other.y * &self.z)
+    }
+}
+
+impl<P: JQCurveConfig> Hash for Projective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state)
+    }
+}
+
+impl<P: JQCurveConfig> Distribution<Projective<P>> for Standard {
+    /// Generates a uniformly random instance of the curve.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Projective<P> {
+        loop {
+            let x = P::BaseField::rand(rng);
+            let greatest = rng.gen();
+
+            if let Some(p) = Affine::get_point_from_x_unchecked(x, greatest) {
+                return p.mul_by_cofactor_to_group();
+            }
+        }
+    }
+}
+
+impl<P: JQCurveConfig> Default for Projective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: JQCurveConfig> Projective<P> {
+    /// Construct a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(
+        x: P::BaseField,
+        y: P::BaseField,
+        t: P::BaseField,
+        z: P::BaseField,
+    ) -> Self {
+        Self { x, y, t, z }
+    }
+
+    /// Construct a new group element in a way while enforcing that points are in
+    /// the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField, t: P::BaseField, z: P::BaseField) -> Self {
+        let p = Self::new_unchecked(x, y, t, z).into_affine();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        p.into()
+    }
+}
+impl<P: JQCurveConfig> Zeroize for Projective<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.t.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: JQCurveConfig> Zero for Projective<P> {
+    fn zero() -> Self {
+        Self::new_unchecked(
+            P::BaseField::zero(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+            P::BaseField::one(),
+        )
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y == self.z && !self.y.is_zero() && self.t.is_zero()
+    }
+}
+
+impl<P: JQCurveConfig> AdditiveGroup for Projective<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self = Self::new_unchecked(
+        P::BaseField::ZERO,
+        P::BaseField::ONE,
+        P::BaseField::ZERO,
+        P::BaseField::ONE,
+    );
+
+    fn double_in_place(&mut self) -> &mut Self {
+        // This is the unified addition formula below, specialized to doubling.
+
+        // A = X1^2
+        let a = self.x.square();
+        // B = Y1^2
+        let b = self.y.square();
+        // C = T1^2
+        let c = self.t.square();
+        // D = Z1^2
+        let d = self.z.square();
+        // E = 2*X1*Y1
+        let e = (self.x + &self.y).square() - &a - &b;
+        // F = D - d*C
+        let dc = P::COEFF_D * &c;
+        let f = d - &dc;
+        // G = D + d*C
+        let g = d + &dc;
+        // H = 2*T1*Z1
+        let h = (self.t + &self.z).square() - &c - &d;
+        // X3 = E*F
+        self.x = e * &f;
+        // Y3 = (B + 2*a*A)*G + 2*d*A*H
+        self.y = (b + &P::mul_by_a(a).double()) * &g + &(P::COEFF_D * &a * &h).double();
+        // T3 = E^2
+        self.t = e.square();
+        // Z3 = F^2
+        self.z = f.square();
+
+        self
+    }
+}
+
+impl<P: JQCurveConfig> PrimeGroup for Projective<P> {
+    type ScalarField = P::ScalarField;
+
+    fn generator() -> Self {
+        Affine::generator().into()
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+        P::mul_projective(self, other.as_ref())
+    }
+}
+
+impl<P: JQCurveConfig> CurveGroup for Projective<P> {
+    type Config = P;
+    type BaseField = P::BaseField;
+    type Affine = Affine<P>;
+    type FullGroup = Affine<P>;
+
+    fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
+        // A projective curve element (x, y, t, z) is normalized
+        // to its affine representation, by the conversion
+        // (x, y, t, z) -> (x/z, y/z, t/z, 1)
+        // Batch normalizing N Jacobi quartic curve elements costs:
+        //     1 inversion + 5N field multiplications
+        // (batch inversion requires 3N multiplications + 1 inversion)
+        ark_ff::thread_pool::in_thread_pool(|| {
+            let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+            ark_ff::batch_inversion(&mut z_s);
+
+            // Perform affine transformations
+            ark_std::cfg_iter!(v)
+                .zip(z_s)
+                .map(|(g, z)| {
+                    let x = g.x * &z;
+                    let y = g.y * &z;
+                    Affine::new_unchecked(x, y)
+                })
+                .collect()
+        })
+    }
+}
+
+impl<P: JQCurveConfig> Neg for Projective<P> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        self.x = -self.x;
+        self
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
+    fn add_assign(&mut self, other: T) {
+        let other = other.borrow();
+        // The unified addition formula below, with Z2 = 1 and T2 = X2^2.
+
+        // A = X1*X2
+        let a = self.x * &other.x;
+        // B = Y1*Y2
+        let b = self.y * &other.y;
+        // C = T1*X2^2
+        let x2_sq = other.x.square();
+        let c = self.t * &x2_sq;
+        // D = Z1
+        let d = self.z;
+        // E = X1*Y2 + Y1*X2
+        let e = (self.x + &self.y) * &(other.x + &other.y) - &a - &b;
+        // F = D - d*C
+        let dc = P::COEFF_D * &c;
+        let f = d - &dc;
+        // G = D + d*C
+        let g = d + &dc;
+        // H = T1 + Z1*X2^2
+        let h = self.t + &(self.z * &x2_sq);
+        // X3 = E*F
+        self.x = e * &f;
+        // Y3 = (B + 2*a*A)*G + 2*d*A*H
+        self.y = (b + &P::mul_by_a(a).double()) * &g + &(P::COEFF_D * &a * &h).double();
+        // T3 = E^2
+        self.t = e.square();
+        // Z3 = F^2
+        self.z = f.square();
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Affine<P>>> Add<T> for Projective<P> {
+    type Output = Self;
+    fn add(mut self, other: T) -> Self {
+        let other = other.borrow();
+        self += other;
+        self
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Affine<P>>> SubAssign<T> for Projective<P> {
+    fn sub_assign(&mut self, other: T) {
+        *self += -(*other.borrow());
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Affine<P>>> Sub<T> for Projective<P> {
+    type Output = Self;
+    fn sub(mut self, other: T) -> Self {
+        self -= other.borrow();
+        self
+    }
+}
+ark_ff::impl_additive_ops_from_ref!(Projective, JQCurveConfig);
+
+impl<'a, P: JQCurveConfig> Add<&'a Self> for Projective<P> {
+    type Output = Self;
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: JQCurveConfig> Sub<&'a Self> for Projective<P> {
+    type Output = Self;
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: JQCurveConfig> AddAssign<&'a Self> for Projective<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        // The affine addition law is
+        //   x3 = (x1 * y2 + y1 * x2) / (1 - d * x1^2 * x2^2),
+        //   y3 = ((y1 * y2 + 2 * a * x1 * x2) * (1 + d * x1^2 * x2^2)
+        //         + 2 * d * x1 * x2 * (x1^2 + x2^2)) / (1 - d * x1^2 * x2^2)^2,
+        // which, in extended coordinates, becomes the following.
+
+        // A = X1 * X2
+        let a = self.x * &other.x;
+
+        // B = Y1 * Y2
+        let b = self.y * &other.y;
+
+        // C = T1 * T2
+        let c = self.t * &other.t;
+
+        // D = Z1 * Z2
+        let d = self.z * &other.z;
+
+        // E = X1 * Y2 + Y1 * X2
+        let e = (self.x + &self.y) * &(other.x + &other.y) - &a - &b;
+
+        // F = D - d * C
+        let dc = P::COEFF_D * &c;
+        let f = d - &dc;
+
+        // G = D + d * C
+        let g = d + &dc;
+
+        // H = T1 * Z2 + Z1 * T2
+        let h = (self.t + &self.z) * &(other.t + &other.z) - &c - &d;
+
+        // X3 = E * F
+        self.x = e * &f;
+
+        // Y3 = (B + 2 * a * A) * G + 2 * d * A * H
+        self.y = (b + &P::mul_by_a(a).double()) * &g + &(P::COEFF_D * &a * &h).double();
+
+        // T3 = E^2
+        self.t = e.square();
+
+        // Z3 = F^2
+        self.z = f.square();
+    }
+}
+
+impl<'a, P: JQCurveConfig> SubAssign<&'a Self> for Projective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += -(*other);
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<P::ScalarField>> MulAssign<T> for Projective<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: JQCurveConfig, T: Borrow<Affine<P>>> ark_std::iter::Sum<T> for Projective<P> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, x| acc + x.borrow())
+    }
+}
+
+// The affine point (X, Y) is represented in the extended coordinates
+// with Z = 1.
+impl<P: JQCurveConfig> From<Affine<P>> for Projective<P> {
+    fn from(p: Affine<P>) -> Projective<P> {
+        Self::new_unchecked(p.x, p.y, p.x.square(), P::BaseField::one())
+    }
+}
+
+impl<P: JQCurveConfig> CanonicalSerialize for Projective<P> {
+    #[allow(unused_qualifications)]
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let aff = Affine::<P>::from(*self);
+        P::serialize_with_mode(&aff, writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        P::serialized_size(compress)
+    }
+}
+
+impl<P: JQCurveConfig> Valid for Projective<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.into_affine().check()
+    }
+
+    fn batch_check<'a>(
+        batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), SerializationError>
+    where
+        Self: 'a,
+    {
+        let batch = batch.copied().collect::<Vec<_>>();
+        let batch = Self::normalize_batch(&batch);
+        Affine::batch_check(batch.iter())
+    }
+}
+
+impl<P: JQCurveConfig> CanonicalDeserialize for Projective<P> {
+    #[allow(unused_qualifications)]
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let aff = P::deserialize_with_mode(reader, compress, validate)?;
+        Ok(aff.into())
+    }
+}
+
+impl<M: JQCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        Affine::from(*self).to_field_elements()
+    }
+}
+
+impl<P: JQCurveConfig> ScalarMul for Projective<P> {
+    type MulBase = Affine<P>;
+    const NEGATION_IS_CHEAP: bool = true;
+
+    fn batch_convert_to_mul_base(bases: &[Self]) -> Vec<Self::MulBase> {
+        Self::normalize_batch(bases)
+    }
+}
+
+impl<P: JQCurveConfig> VariableBaseMSM for Projective<P> {
+    fn msm(bases: &[Self::MulBase], bigints: &[Self::ScalarField]) -> Result<Self, usize> {
+        P::msm(bases, bigints)
+    }
+}
@@ -0,0 +1,174 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, SerializationError, Valid, Validate, ValidationError,
+};
+use ark_std::io::{Read, Write};
+
+use crate::{
+    scalar_mul::variable_base::VariableBaseMSM,
+    short_weierstrass::{SWCurveConfig, SWFlags},
+    AffineRepr,
+};
+use num_traits::Zero;
+
+use ark_ff::{fields::Field, AdditiveGroup};
+
+mod affine;
+pub use affine::*;
+
+mod group;
+pub use group::*;
+
+/// Constants and convenience functions that collectively define the [Jacobi quartic model](https://eprint.iacr.org/2009/312)
+/// of the curve. In this model, the curve equation is
+/// `y² = d * x⁴ + 2 * a * x² + 1`, for constants `a` and `d`, and the
+/// identity is the point `(0, 1)`.
+///
+/// The addition formulae of this model, in extended coordinates, are
+/// unified, i.e. they also compute doublings. They are moreover complete
+/// if `d` is not a square in the base field, in which case all points of
+/// the curve are affine. The model assumes that `d` is not a square.
+///
+/// Every Jacobi quartic has the point `(0, -1)` of order two, so the
+/// cofactor is even.
+pub trait JQCurveConfig: super::CurveConfig {
+    /// Coefficient `a` of the curve equation.
+    const COEFF_A: Self::BaseField;
+    /// Coefficient `d` of the curve equation.
+    const COEFF_D: Self::BaseField;
+    /// Generator of the prime-order subgroup.
+    const GENERATOR: Affine<Self>;
+
+    /// Model parameters for the short Weierstrass curve that is birationally
+    /// equivalent to this curve, i.e. the curve `y² = x³ + A * x + B` with
+    /// `A = -4 * (a² + 3 * d) / 3` and `B = 16 * a * (a² - 9 * d) / 27`.
+    /// See [`Affine::to_weierstrass`].
+    type SWCurveConfig: SWCurveConfig<BaseField = Self::BaseField, ScalarField = Self::ScalarField>;
+
+    /// Helper method for computing `elem * Self::COEFF_A`.
+    ///
+    /// The default implementation should be overridden only if
+    /// the product can be computed faster than standard field multiplication
+    /// (eg: via doubling if `COEFF_A == 2`, or if `COEFF_A.is_zero()`).
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        elem * Self::COEFF_A
+    }
+
+    /// Checks that the current point is in the prime order subgroup given
+    /// the point on the curve.
+    fn is_in_correct_subgroup_assuming_on_curve(item: &Affine<Self>) -> bool {
+        Self::mul_affine(item, Self::ScalarField::characteristic()).is_zero()
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// For some curve families though, it is sufficient to multiply
+    /// by a smaller scalar.
+    fn clear_cofactor(item: &Affine<Self>) -> Affine<Self> {
+        item.mul_by_cofactor()
+    }
+
+    /// Default implementation of group multiplication for projective
+    /// coordinates
+    fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
+        let mut res = Projective::<Self>::zero();
+        for b in ark_ff::BitIteratorBE::without_leading_zeros(scalar) {
+            res.double_in_place();
+            if b {
+                res += base;
+            }
+        }
+
+        res
+    }
+
+    /// Default implementation of group multiplication for affine
+    /// coordinates
+    fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
+        let mut res = Projective::<Self>::zero();
+        for b in ark_ff::BitIteratorBE::without_leading_zeros(scalar) {
+            res.double_in_place();
+            if b {
+                res += base
+            }
+        }
+
+        res
+    }
+
+    /// Default implementation for multi scalar multiplication
+    fn msm(
+        bases: &[Affine<Self>],
+        scalars: &[Self::ScalarField],
+    ) -> Result<Projective<Self>, usize> {
+        (bases.len() == scalars.len())
+            .then(|| VariableBaseMSM::msm_unchecked(bases, scalars))
+            .ok_or(bases.len().min(scalars.len()))
+    }
+
+    /// If uncompressed, serializes both x and y coordinates.
+    /// If compressed, serializes x coordinate with a bit to encode whether y is positive.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        item: &Affine<Self>,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), SerializationError> {
+        let flags = SWFlags::from_y_coordinate(item.y);
+        match compress {
+            Compress::Yes => item.x.serialize_with_flags(writer, flags),
+            Compress::No => {
+                item.x.serialize_uncompressed(&mut writer)?;
+                item.y.serialize_uncompressed(&mut writer)
+            },
+        }
+    }
+
+    /// If `validate` is `Yes`, calls `check()` to make sure the element is valid.
+    ///
+    /// Uses `Affine::get_ys_from_x_unchecked()` for the compressed version.
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Affine<Self>, SerializationError> {
+        let (x, y) = match compress {
+            Compress::Yes => {
+                let (x, flags): (_, SWFlags) =
+                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+                let is_positive = flags
+                    .is_positive()
+                    .ok_or(ValidationError::InvalidInfinityFlags)?;
+                let (y, neg_y) = Affine::<Self>::get_ys_from_x_unchecked(x)
+                    .ok_or(ValidationError::NotOnCurve)?;
+                if is_positive {
+                    (x, y)
+                } else {
+                    (x, neg_y)
+                }
+            },
+            Compress::No => {
+                let x: Self::BaseField =
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+                let y: Self::BaseField =
+                    CanonicalDeserialize::deserialize_uncompressed(&mut reader)?;
+                (x, y)
+            },
+        };
+        let point = Affine::<Self>::new_unchecked(x, y);
+        if let Validate::Yes = validate {
+            point.check()?;
+        }
+        Ok(point)
+    }
+
+    #[inline]
+    fn serialized_size(compress: Compress) -> usize {
+        let zero = Self::BaseField::zero();
+        match compress {
+            Compress::Yes => zero.serialized_size_with_flags::<SWFlags>(),
+            Compress::No => zero.uncompressed_size() + zero.uncompressed_size(),
+        }
+    }
+}
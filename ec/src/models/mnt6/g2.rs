@@ -5,15 +5,18 @@ use crate::{
     AffineRepr, CurveGroup,
 };
 use ark_ff::fields::{Field, Fp3};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    ValidationError,
+};
 use ark_std::{ops::Neg, vec::*};
 use educe::Educe;
-use num_traits::One;
+use num_traits::{One, Zero};
 
 pub type G2Affine<P> = Affine<<P as MNT6Config>::G2Config>;
 pub type G2Projective<P> = Projective<<P as MNT6Config>::G2Config>;
 
-#[derive(Educe, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Educe, CanonicalSerialize)]
 #[educe(Clone, Debug, PartialEq, Eq)]
 pub struct G2Prepared<P: MNT6Config> {
     pub x: Fp3<P::Fp3Config>,
@@ -24,6 +27,48 @@ pub struct G2Prepared<P: MNT6Config> {
     pub addition_coefficients: Vec<AteAdditionCoefficients<P>>,
 }
 
+impl<P: MNT6Config> Valid for G2Prepared<P> {
+    /// Checks that `(x, y)` is a point of G2, and that the twisted coordinates
+    /// and the coefficients are those computed from it.
+    fn check(&self) -> Result<(), SerializationError> {
+        // The preparation of the point at infinity has `x = y = 0`, which is
+        // not on the curve since its coefficient `b` is non-zero.
+        let g = if self.x.is_zero() && self.y.is_zero() {
+            G2Affine::<P>::identity()
+        } else {
+            let g = G2Affine::<P>::new_unchecked(self.x, self.y);
+            g.check()?;
+            g
+        };
+        if Self::from(g) == *self {
+            Ok(())
+        } else {
+            Err(ValidationError::InvalidPrecomputation.into())
+        }
+    }
+}
+
+impl<P: MNT6Config> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let prepared = Self {
+            x: Fp3::deserialize_with_mode(&mut reader, compress, validate)?,
+            y: Fp3::deserialize_with_mode(&mut reader, compress, validate)?,
+            x_over_twist: Fp3::deserialize_with_mode(&mut reader, compress, validate)?,
+            y_over_twist: Fp3::deserialize_with_mode(&mut reader, compress, validate)?,
+            double_coefficients: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            addition_coefficients: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            prepared.check()?;
+        }
+        Ok(prepared)
+    }
+}
+
 impl<P: MNT6Config> Default for G2Prepared<P> {
     fn default() -> Self {
         Self::from(G2Affine::<P>::generator())
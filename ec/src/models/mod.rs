@@ -1,4 +1,5 @@
 use ark_ff::{Field, PrimeField};
+use ark_serialize::ValidationError;
 
 pub mod bls12;
 pub mod bn;
@@ -6,8 +7,10 @@ pub mod bw6;
 pub mod mnt4;
 pub mod mnt6;
 
+pub mod jacobi_quartic;
 pub mod short_weierstrass;
 pub mod twisted_edwards;
+pub mod twisted_hessian;
 
 /// Elliptic curves can be represented via different "models" with varying
 /// efficiency properties.
@@ -30,3 +33,18 @@ pub trait CurveConfig: Send + Sync + Sized + 'static {
         Self::COFACTOR[0] == 1 && Self::COFACTOR.iter().skip(1).all(|&e| e == 0)
     }
 }
+
+/// Returns why a point is invalid, if it is. Subgroup membership, which is
+/// the expensive check, is only computed for points on the curve.
+pub(crate) fn validate_point(
+    is_on_curve: bool,
+    is_in_correct_subgroup: impl FnOnce() -> bool,
+) -> Result<(), ValidationError> {
+    if !is_on_curve {
+        Err(ValidationError::NotOnCurve)
+    } else if !is_in_correct_subgroup() {
+        Err(ValidationError::NotInSubgroup)
+    } else {
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, Compress,
+    SerializationError, Valid, Validate, ValidationError,
 };
 use ark_std::{
     borrow::Borrow,
+    cfg_iter,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     io::{Read, Write},
     ops::{Add, Mul, Neg, Sub},
@@ -20,7 +22,15 @@ use educe::Educe;
 use zeroize::Zeroize;
 
 use super::{Projective, SWCurveConfig, SWFlags};
-use crate::AffineRepr;
+use crate::{models::validate_point, AffineRepr, CurveGroup, VariableBaseMSM};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "std")]
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+#[cfg(feature = "std")]
+use sha2::{Digest, Sha256};
 
 /// Affine coordinates for a point on an elliptic curve in short Weierstrass
 /// form, over the base field `P::BaseField`.
@@ -74,6 +84,16 @@ impl<P: SWCurveConfig> Affine<P> {
         point
     }
 
+    /// Like [`Self::new`], but returns why the point is invalid instead of
+    /// panicking.
+    pub fn try_new(x: P::BaseField, y: P::BaseField) -> Result<Self, ValidationError> {
+        let point = Self::new_unchecked(x, y);
+        validate_point(point.is_on_curve(), || {
+            point.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(point)
+    }
+
     /// Constructs a group element from x and y coordinates.
     ///
     /// # Warning
@@ -120,21 +140,113 @@ impl<P: SWCurveConfig> Affine<P> {
     /// The results are sorted by lexicographical order.
     /// This means that, if `P::BaseField: PrimeField`, the results are sorted as integers.
     pub fn get_ys_from_x_unchecked(x: P::BaseField) -> Option<(P::BaseField, P::BaseField)> {
-        // Compute the curve equation x^3 + Ax + B.
+        let y = Self::x3_plus_ax_plus_b(x).sqrt()?;
+        Some(Self::sort_ys(y))
+    }
+
+    /// Computes [`Self::get_point_from_x_unchecked`] for every pair
+    /// `(x, greatest)` of `xs`, computing all square roots at once with
+    /// [`Field::batch_sqrt`].
+    pub fn get_points_from_x_unchecked_batch(xs: &[(P::BaseField, bool)]) -> Vec<Option<Self>> {
+        let rhs = xs
+            .iter()
+            .map(|(x, _)| Self::x3_plus_ax_plus_b(*x))
+            .collect::<Vec<_>>();
+        P::BaseField::batch_sqrt(&rhs)
+            .into_iter()
+            .zip(xs)
+            .map(|(y, &(x, greatest))| {
+                let (smaller, larger) = Self::sort_ys(y?);
+                let y = if greatest { larger } else { smaller };
+                Some(Self::new_unchecked(x, y))
+            })
+            .collect()
+    }
+
+    /// Computes the right-hand side of the curve equation, `x^3 + Ax + B`.
+    fn x3_plus_ax_plus_b(x: P::BaseField) -> P::BaseField {
         // Since Rust does not optimise away additions with zero, we explicitly check
         // for that case here, and avoid multiplication by `a` if possible.
         let mut x3_plus_ax_plus_b = P::add_b(x.square() * x);
         if !P::COEFF_A.is_zero() {
             x3_plus_ax_plus_b += P::mul_by_a(x)
         };
-        let y = x3_plus_ax_plus_b.sqrt()?;
+        x3_plus_ax_plus_b
+    }
+
+    /// Returns `y` and `-y`, sorted by lexicographical order.
+    fn sort_ys(y: P::BaseField) -> (P::BaseField, P::BaseField) {
         let neg_y = -y;
         match y < neg_y {
-            true => Some((y, neg_y)),
-            false => Some((neg_y, y)),
+            true => (y, neg_y),
+            false => (neg_y, y),
         }
     }
 
+    /// Deserializes `count` consecutive points in the compressed encoding of
+    /// [`SWCurveConfig::serialize_with_mode`], with
+    /// [`SWCurveConfig::deserialize_compressed_batch`]. This returns the same
+    /// points as deserializing each point in turn.
+    pub fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Self>, SerializationError> {
+        P::deserialize_compressed_batch(reader, count, validate)
+    }
+
+    /// Deserializes `count` consecutive points in the compressed encoding of
+    /// the default [`SWCurveConfig::serialize_with_mode`], computing the
+    /// square roots of all points at once with
+    /// [`Self::get_points_from_x_unchecked_batch`], which
+    /// batches the exponentiations of prime fields and the inversions and
+    /// base field square roots of quadratic extensions.
+    ///
+    /// If `validate` is `Yes`, the points are then checked with
+    /// [`Valid::batch_check`]. This returns the same points as deserializing
+    /// each point in turn, but is faster when `count` is large. It must not be
+    /// used for configs that override [`SWCurveConfig::serialize_with_mode`].
+    pub fn deserialize_default_compressed_batch<R: Read>(
+        mut reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Self>, SerializationError> {
+        let mut encodings = Vec::with_capacity(count);
+        for _ in 0..count {
+            let (x, flags): (P::BaseField, SWFlags) =
+                CanonicalDeserializeWithFlags::deserialize_with_flags(&mut reader)?;
+            if flags.is_infinity() && validate == Validate::Yes && !x.is_zero() {
+                return Err(ValidationError::InvalidInfinityFlags.into());
+            }
+            encodings.push((x, flags));
+        }
+
+        // `SWFlags::YIsPositive` selects the smaller y-coordinate.
+        let xs = encodings
+            .iter()
+            .filter(|(_, flags)| !flags.is_infinity())
+            .map(|(x, flags)| (*x, flags.is_positive() != Some(true)))
+            .collect::<Vec<_>>();
+        let mut finite_points = Self::get_points_from_x_unchecked_batch(&xs).into_iter();
+        let points = encodings
+            .into_iter()
+            .map(|(_, flags)| {
+                if flags.is_infinity() {
+                    return Ok(Self::identity());
+                }
+                Ok(finite_points
+                    .next()
+                    .flatten()
+                    .ok_or(ValidationError::NotOnCurve)?)
+            })
+            .collect::<Result<Vec<_>, SerializationError>>()?;
+
+        if validate == Validate::Yes {
+            Self::batch_check(points.iter())?;
+        }
+        Ok(points)
+    }
+
     /// Checks if `self` is a valid point on the curve.
     pub fn is_on_curve(&self) -> bool {
         if !self.infinity {
@@ -167,6 +279,103 @@ impl<P: SWCurveConfig> Affine<P> {
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
         P::is_in_correct_subgroup_assuming_on_curve(self)
     }
+
+    /// Checks if all `points`, which are assumed to be on the curve, are in
+    /// the subgroup having order equaling that of `P::ScalarField`, with a
+    /// random linear combination: the check passes if `Σ cᵢ * pointsᵢ` is in
+    /// the subgroup for random `cᵢ`, which costs one MSM and one subgroup
+    /// check rather than one subgroup check per point.
+    ///
+    /// If some point is not in the subgroup, a single combination is in the
+    /// subgroup with probability at most `1 / ℓ`, where `ℓ` is the smallest
+    /// prime factor of the cofactor. The check is repeated until the
+    /// soundness error is below `2^-128`, i.e. about `128 / log2(ℓ)` times,
+    /// so this is most effective when the cofactor has no small factors. If
+    /// this would take more than eight combinations, as for cofactors with
+    /// small factors, the points are instead checked one by one.
+    ///
+    /// In particular, the G1 and G2 cofactors of BLS12-381 and the G1
+    /// cofactor of BLS12-377 have small factors, so their points are always
+    /// checked one by one, with the endomorphism-based check of
+    /// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`]. The G2
+    /// cofactor of BLS12-377 has no factor below `2^16`, so its points are
+    /// checked in a batch.
+    pub fn batch_is_in_correct_subgroup<R: Rng + ?Sized>(points: &[Self], rng: &mut R) -> bool {
+        if points.is_empty() || P::cofactor_is_one() {
+            return true;
+        }
+
+        let rounds = soundness_rounds(P::COFACTOR);
+        if rounds > MAX_SOUNDNESS_ROUNDS {
+            return cfg_iter!(points).all(Self::is_in_correct_subgroup_assuming_on_curve);
+        }
+
+        let full_size_coeffs = P::ScalarField::MODULUS_BIT_SIZE <= 128;
+        (0..rounds).all(|_| {
+            // Coefficients of 128 bits suffice, and halve the cost of the MSM.
+            let coeffs = points
+                .iter()
+                .map(|_| match full_size_coeffs {
+                    true => P::ScalarField::rand(rng),
+                    false => P::ScalarField::from(rng.gen::<u128>()),
+                })
+                .collect::<Vec<_>>();
+            let combination: Projective<P> = VariableBaseMSM::msm_unchecked(points, &coeffs);
+            combination
+                .into_affine()
+                .is_in_correct_subgroup_assuming_on_curve()
+        })
+    }
+}
+
+/// Returns an RNG seeded with a SHA-256 hash of the uncompressed encodings of
+/// `points`, from which [`Valid::batch_check`] draws the coefficients of its
+/// random linear combinations.
+#[cfg(feature = "std")]
+fn batch_check_rng<P: SWCurveConfig>(points: &[Affine<P>]) -> StdRng {
+    let mut hasher = Sha256::new();
+    hasher.update(b"ark-ec short Weierstrass batch_check");
+    let mut bytes = Vec::new();
+    for p in points {
+        bytes.clear();
+        p.serialize_uncompressed(&mut bytes).unwrap();
+        hasher.update(&bytes);
+    }
+    StdRng::from_seed(hasher.finalize().into())
+}
+
+/// The largest number of random linear combinations for which
+/// [`Affine::batch_is_in_correct_subgroup`] is faster than checking the points
+/// one by one. This is the number of combinations needed when the cofactor
+/// has no factors below `2^16`.
+const MAX_SOUNDNESS_ROUNDS: usize = 8;
+
+/// Returns the smallest factor of the cofactor, given as little-endian limbs,
+/// if it is below `2^16`.
+fn small_cofactor_factor(cofactor: &[u64]) -> Option<u64> {
+    (2..1u64 << 16).find(|&d| {
+        let rem = cofactor.iter().rev().fold(0u128, |rem, &limb| {
+            ((rem << 64) | u128::from(limb)) % u128::from(d)
+        });
+        rem == 0
+    })
+}
+
+/// Returns the number of random linear combinations for which the soundness
+/// error of [`Affine::batch_is_in_correct_subgroup`] is below `2^-128`, given
+/// the cofactor as little-endian limbs.
+fn soundness_rounds(cofactor: &[u64]) -> usize {
+    // A lower bound on the smallest prime factor of the cofactor.
+    let bound = u128::from(small_cofactor_factor(cofactor).unwrap_or(1 << 16));
+
+    // The smallest number of rounds such that bound^rounds >= 2^128.
+    let mut rounds = 1;
+    let mut error_inv = bound;
+    while let Some(e) = error_inv.checked_mul(bound) {
+        error_inv = e;
+        rounds += 1;
+    }
+    rounds + 1
 }
 
 impl<P: SWCurveConfig> Zeroize for Affine<P> {
@@ -370,11 +579,41 @@ impl<P: SWCurveConfig> CanonicalSerialize for Affine<P> {
 
 impl<P: SWCurveConfig> Valid for Affine<P> {
     fn check(&self) -> Result<(), SerializationError> {
-        if self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve() {
-            Ok(())
-        } else {
-            Err(SerializationError::InvalidData)
+        validate_point(self.is_on_curve(), || {
+            self.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(())
+    }
+
+    /// Checks that all points are on the curve, in parallel, and then that
+    /// they are in the prime-order subgroup.
+    ///
+    /// The subgroup checks are batched with
+    /// [`Affine::batch_is_in_correct_subgroup`], which needs at most eight
+    /// MSMs and otherwise checks the points one by one, in parallel. The
+    /// coefficients of the random linear combinations are derived from a
+    /// SHA-256 hash of the points, so the outcome is deterministic and the
+    /// points cannot be chosen after the coefficients. Without the `std`
+    /// feature, the points are checked one by one.
+    fn batch_check<'a>(
+        batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), SerializationError>
+    where
+        Self: 'a,
+    {
+        let points = batch.copied().collect::<Vec<_>>();
+        if !cfg_iter!(points).all(Self::is_on_curve) {
+            return Err(ValidationError::NotOnCurve.into());
         }
+        #[cfg(feature = "std")]
+        let in_subgroup =
+            Self::batch_is_in_correct_subgroup(&points, &mut batch_check_rng(&points));
+        #[cfg(not(feature = "std"))]
+        let in_subgroup = cfg_iter!(points).all(Self::is_in_correct_subgroup_assuming_on_curve);
+        if !in_subgroup {
+            return Err(ValidationError::NotInSubgroup.into());
+        }
+        Ok(())
     }
 }
 
@@ -402,3 +641,16 @@ where
         Some(x)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{soundness_rounds, MAX_SOUNDNESS_ROUNDS};
+
+    #[test]
+    fn soundness_rounds_depend_on_smallest_factor() {
+        // 65537 is a prime above 2^16.
+        assert_eq!(soundness_rounds(&[65537]), MAX_SOUNDNESS_ROUNDS);
+        assert_eq!(soundness_rounds(&[0, 1]), 128);
+        assert!(soundness_rounds(&[3 * 65537]) > MAX_SOUNDNESS_ROUNDS);
+    }
+}
@@ -0,0 +1,178 @@
+//! Constant-time scalar multiplication on short Weierstrass curves, enabled
+//! by the `ct` feature.
+//!
+//! The Jacobian formulae of [`Projective`] are incomplete, and branch on the
+//! points being equal or zero, so [`Projective::mul_ct`] instead runs the
+//! Montgomery ladder on homogeneous projective coordinates, with the
+//! complete addition formulae of Renes, Costello and Batina.
+use ark_ff::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    AdditiveGroup, Field, PrimeField,
+};
+
+use super::{homogeneous::Homogeneous, Affine, Projective, SWCurveConfig};
+use crate::scalar_mul::montgomery_ladder;
+
+impl<P: SWCurveConfig> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let infinity = u8::conditional_select(&(a.infinity as u8), &(b.infinity as u8), choice);
+        Self {
+            x: P::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: P::BaseField::conditional_select(&a.y, &b.y, choice),
+            infinity: infinity != 0,
+        }
+    }
+}
+
+impl<P: SWCurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: SWCurveConfig> Affine<P>
+where
+    P::BaseField: ConditionallySelectable + ConstantTimeEq,
+{
+    /// Computes `self * scalar` in constant time; see
+    /// [`Projective::mul_ct`].
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Projective<P> {
+        Homogeneous::from_affine_ct(self)
+            .mul_ct(scalar)
+            .into_jacobian_ct()
+    }
+}
+
+impl<P: SWCurveConfig> Projective<P>
+where
+    P::BaseField: ConditionallySelectable + ConstantTimeEq,
+{
+    /// Computes `self * scalar` in constant time, for use with secret
+    /// scalars, e.g. in key exchange and signing.
+    ///
+    /// Unlike `self * scalar`, which skips the leading zeros of the scalar
+    /// and the additions for its zero bits, this runs the Montgomery ladder
+    /// over all `MODULUS_BIT_SIZE` bits of the scalar, with complete
+    /// addition formulae that do not branch on the points being equal or
+    /// zero. The running time is thus independent of the scalar, provided
+    /// that the arithmetic of the base field is constant-time, as it is for
+    /// the prime fields and their extensions with the `ct` feature of
+    /// `ark-ff`.
+    ///
+    /// The addition formulae are complete on curves without points of order
+    /// two, i.e. on which `x³ + a * x + b` has no roots in the base field.
+    /// This holds for all curves of odd order, and in particular for
+    /// prime-order curves. On other curves, the result may be wrong.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_std::UniformRand;
+    /// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+    ///
+    /// let mut rng = ark_std::test_rng();
+    /// let point = G::rand(&mut rng);
+    /// let secret = Fr::rand(&mut rng);
+    /// assert_eq!(point.mul_ct(&secret), point * secret);
+    /// ```
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        Homogeneous::from_jacobian(self)
+            .mul_ct(scalar)
+            .into_jacobian_ct()
+    }
+}
+
+impl<P: SWCurveConfig> Homogeneous<P>
+where
+    P::BaseField: ConditionallySelectable + ConstantTimeEq,
+{
+    /// Converts from affine coordinates like [`Self::from_affine`], but
+    /// selects zero without branching on the infinity flag.
+    pub(crate) fn from_affine_ct(p: &Affine<P>) -> Self {
+        Self::conditional_select(
+            &Self::new(p.x, p.y, P::BaseField::ONE),
+            &Self::ZERO,
+            Choice::from(p.infinity as u8),
+        )
+    }
+
+    /// Converts to Jacobian coordinates like [`Self::into_jacobian`], but
+    /// selects the canonical zero without branching on `Z`.
+    pub(crate) fn into_jacobian_ct(self) -> Projective<P> {
+        let p = Projective::new_unchecked(self.x * self.z, self.y * self.z.square(), self.z);
+        Projective::conditional_select(&p, &Projective::ZERO, self.z.ct_eq(&P::BaseField::ZERO))
+    }
+
+    fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        montgomery_ladder(
+            Self::ZERO,
+            *self,
+            scalar.into_bigint().as_ref(),
+            P::ScalarField::MODULUS_BIT_SIZE as usize,
+            Self::add,
+        )
+    }
+}
+
+impl<P: SWCurveConfig> ConditionallySelectable for Homogeneous<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand, Zero};
+    use ark_test_curves::{
+        ark_ff::{AdditiveGroup, Field, PrimeField},
+        bls12_381::{Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+    };
+
+    macro_rules! test_mul_ct {
+        ($name:ident, $affine:ty, $projective:ty) => {
+            #[test]
+            fn $name() {
+                let rng = &mut test_rng();
+                let scalars = [
+                    Fr::ZERO,
+                    Fr::ONE,
+                    -Fr::ONE,
+                    Fr::from(2u8),
+                    Fr::from(Fr::MODULUS_BIT_SIZE),
+                ];
+                let scalars = scalars.into_iter().chain((0..20).map(|_| Fr::rand(rng)));
+                for scalar in scalars {
+                    let point = <$projective>::rand(rng);
+                    let expected = point * scalar;
+                    assert_eq!(point.mul_ct(&scalar), expected);
+                    let affine = <$affine>::from(point);
+                    assert_eq!(affine.mul_ct(&scalar), expected);
+                    assert!(<$projective>::zero().mul_ct(&scalar).is_zero());
+                    assert!(<$affine>::identity().mul_ct(&scalar).is_zero());
+                }
+            }
+        };
+    }
+
+    test_mul_ct!(test_mul_ct_g1, G1Affine, G1Projective);
+    test_mul_ct!(test_mul_ct_g2, G2Affine, G2Projective);
+}
@@ -1,4 +1,8 @@
-use super::{Affine, SWCurveConfig};
+use super::{
+    homogeneous::Homogeneous,
+    jacobian::{self, ConfigCoeffA},
+    Affine, SWCurveConfig,
+};
 use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
@@ -168,105 +172,21 @@ impl<P: SWCurveConfig> AdditiveGroup for Projective<P> {
     /// implementation uses the following specialized doubling formulae:
     /// * [`P::A` is zero](http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l)
     /// * [`P::A` is not zero](https://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#doubling-dbl-2007-bl)
+    ///
+    /// If [`SWCurveConfig::COMPLETE_ADDITION`] is set, this instead computes
+    /// `self + self` with the complete addition formulae.
     fn double_in_place(&mut self) -> &mut Self {
-        if self.is_zero() {
+        if P::COMPLETE_ADDITION {
+            let p = Homogeneous::from_jacobian(self);
+            *self = p.add(&p).into_jacobian();
             return self;
         }
 
-        if P::COEFF_A == P::BaseField::ZERO {
-            // A = X1^2
-            let mut a = self.x;
-            a.square_in_place();
-
-            // B = Y1^2
-            let mut b = self.y;
-            b.square_in_place();
-
-            // C = B^2
-            let mut c = b;
-            c.square_in_place();
-
-            // D = 2*((X1+B)^2-A-C)
-            //   = 2 * (X1 + Y1^2)^2 - A - C
-            //   = 2 * 2 * X1 * Y1^2
-            let d = if [1, 2].contains(&P::BaseField::extension_degree()) {
-                let mut d = self.x;
-                d *= &b;
-                d.double_in_place().double_in_place();
-                d
-            } else {
-                let mut d = self.x;
-                d += &b;
-                d.square_in_place();
-                d -= a;
-                d -= c;
-                d.double_in_place();
-                d
-            };
-
-            // E = 3*A
-            let e = a + &*a.double_in_place();
-
-            // Z3 = 2*Y1*Z1
-            self.z *= &self.y;
-            self.z.double_in_place();
-
-            // F = E^2
-            // X3 = F-2*D
-            self.x = e;
-            self.x.square_in_place();
-            self.x -= &d.double();
-
-            // Y3 = E*(D-X3)-8*C
-            self.y = d;
-            self.y -= &self.x;
-            self.y *= &e;
-            self.y -= c.double_in_place().double_in_place().double_in_place();
-            self
-        } else {
-            // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
-            // XX = X1^2
-            let xx = self.x.square();
-
-            // YY = Y1^2
-            let yy = self.y.square();
-
-            // YYYY = YY^2
-            let mut yyyy = yy;
-            yyyy.square_in_place();
-
-            // ZZ = Z1^2
-            let mut zz = self.z;
-            zz.square_in_place();
-
-            // S = 2*((X1+YY)^2-XX-YYYY)
-            let s = ((self.x + &yy).square() - &xx - &yyyy).double();
-
-            // M = 3*XX+a*ZZ^2
-            let mut m = xx;
-            m.double_in_place();
-            m += &xx;
-            m += &P::mul_by_a(zz.square());
-
-            // T = M^2-2*S
-            // X3 = T
-            self.x = m;
-            self.x.square_in_place();
-            self.x -= s.double();
-
-            // Z3 = (Y1+Z1)^2-YY-ZZ
-            // Can be calculated as Z3 = 2*Y1*Z1, and this is faster.
-            self.z *= self.y;
-            self.z.double_in_place();
-
-            // Y3 = M*(S-X3)-8*YYYY
-            self.y = s;
-            self.y -= &self.x;
-            self.y *= &m;
-            self.y -= yyyy.double_in_place().double_in_place().double_in_place();
-
-            self
-        }
+        jacobian::double_in_place(
+            &ConfigCoeffA::<P>::new(),
+            (&mut self.x, &mut self.y, &mut self.z),
+        );
+        self
     }
 }
 
@@ -304,22 +224,29 @@ impl<P: SWCurveConfig> CurveGroup for Projective<P> {
     /// of these operations)
     #[inline]
     fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
-        let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
-        ark_ff::batch_inversion(&mut z_s);
+        ark_ff::thread_pool::in_thread_pool(|| {
+            let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+            ark_ff::batch_inversion(&mut z_s);
+
+            // Perform affine transformations
+            ark_std::cfg_iter!(v)
+                .zip(z_s)
+                .map(|(g, z)| match g.is_zero() {
+                    true => Affine::identity(),
+                    false => {
+                        let z2 = z.square();
+                        let x = g.x * z2;
+                        let y = g.y * z2 * z;
+                        Affine::new_unchecked(x, y)
+                    },
+                })
+                .collect()
+        })
+    }
 
-        // Perform affine transformations
-        ark_std::cfg_iter!(v)
-            .zip(z_s)
-            .map(|(g, z)| match g.is_zero() {
-                true => Affine::identity(),
-                false => {
-                    let z2 = z.square();
-                    let x = g.x * z2;
-                    let y = g.y * z2 * z;
-                    Affine::new_unchecked(x, y)
-                },
-            })
-            .collect()
+    #[inline]
+    fn clear_cofactor_in_place(&mut self) {
+        *self = P::clear_cofactor_projective(self);
     }
 }
 
@@ -334,80 +261,25 @@ impl<P: SWCurveConfig> Neg for Projective<P> {
 }
 
 impl<P: SWCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
-    /// Using <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl>
+    /// Using <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl>,
+    /// or the complete addition formulae if [`SWCurveConfig::COMPLETE_ADDITION`]
+    /// is set.
     fn add_assign(&mut self, other: T) {
         let other = other.borrow();
-        if let Some((other_x, other_y)) = other.xy() {
-            if self.is_zero() {
-                self.x = other_x;
-                self.y = other_y;
-                self.z = P::BaseField::one();
-                return;
-            }
+        if P::COMPLETE_ADDITION {
+            *self = Homogeneous::from_jacobian(self)
+                .add(&Homogeneous::from_affine(other))
+                .into_jacobian();
+            return;
+        }
 
-            // Z1Z1 = Z1^2
-            let mut z1z1 = self.z;
-            z1z1.square_in_place();
-
-            // U2 = X2*Z1Z1
-            let mut u2 = other_x;
-            u2 *= &z1z1;
-
-            // S2 = Y2*Z1*Z1Z1
-            let mut s2 = self.z;
-            s2 *= &other_y;
-            s2 *= &z1z1;
-
-            if self.x == u2 {
-                if self.y == s2 {
-                    // The two points are equal, so we double.
-                    self.double_in_place();
-                } else {
-                    // a + (-a) = 0
-                    *self = Self::zero()
-                }
-            } else {
-                // H = U2-X1
-                let mut h = u2;
-                h -= &self.x;
-
-                // HH = H^2
-                let mut hh = h;
-                hh.square_in_place();
-
-                // I = 4*HH
-                let mut i = hh;
-                i.double_in_place().double_in_place();
-
-                // J = -H*I
-                let mut j = h;
-                j.neg_in_place();
-                j *= &i;
-
-                // r = 2*(S2-Y1)
-                let mut r = s2;
-                r -= &self.y;
-                r.double_in_place();
-
-                // V = X1*I
-                let mut v = self.x;
-                v *= &i;
-
-                // X3 = r^2 + J - 2*V
-                self.x = r.square();
-                self.x += &j;
-                self.x -= &v.double();
-
-                // Y3 = r*(V-X3) + 2*Y1*J
-                v -= &self.x;
-                self.y.double_in_place();
-                self.y = P::BaseField::sum_of_products(&[r, self.y], &[v, j]);
-
-                // Z3 = 2 * Z1 * H;
-                // Can alternatively be computed as (Z1+H)^2-Z1Z1-HH, but the latter is slower.
-                self.z *= &h;
-                self.z.double_in_place();
-            }
+        if let Some((other_x, other_y)) = other.xy() {
+            jacobian::add_assign_mixed(
+                &ConfigCoeffA::<P>::new(),
+                (&mut self.x, &mut self.y, &mut self.z),
+                other_x,
+                other_y,
+            );
         }
     }
 }
@@ -449,91 +321,18 @@ impl<'a, P: SWCurveConfig> Add<&'a Self> for Projective<P> {
 
 impl<'a, P: SWCurveConfig> AddAssign<&'a Self> for Projective<P> {
     fn add_assign(&mut self, other: &'a Self) {
-        if self.is_zero() {
-            *self = *other;
+        if P::COMPLETE_ADDITION {
+            *self = Homogeneous::from_jacobian(self)
+                .add(&Homogeneous::from_jacobian(other))
+                .into_jacobian();
             return;
         }
 
-        if other.is_zero() {
-            return;
-        }
-
-        // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl
-        // Works for all curves.
-
-        // Z1Z1 = Z1^2
-        let z1z1 = self.z.square();
-
-        // Z2Z2 = Z2^2
-        let z2z2 = other.z.square();
-
-        // U1 = X1*Z2Z2
-        let mut u1 = self.x;
-        u1 *= &z2z2;
-
-        // U2 = X2*Z1Z1
-        let mut u2 = other.x;
-        u2 *= &z1z1;
-
-        // S1 = Y1*Z2*Z2Z2
-        let mut s1 = self.y;
-        s1 *= &other.z;
-        s1 *= &z2z2;
-
-        // S2 = Y2*Z1*Z1Z1
-        let mut s2 = other.y;
-        s2 *= &self.z;
-        s2 *= &z1z1;
-
-        if u1 == u2 {
-            if s1 == s2 {
-                // The two points are equal, so we double.
-                self.double_in_place();
-            } else {
-                // a + (-a) = 0
-                *self = Self::zero();
-            }
-        } else {
-            // H = U2-U1
-            let mut h = u2;
-            h -= &u1;
-
-            // I = (2*H)^2
-            let mut i = h;
-            i.double_in_place().square_in_place();
-
-            // J = -H*I
-            let mut j = h;
-            j.neg_in_place();
-            j *= &i;
-
-            // r = 2*(S2-S1)
-            let mut r = s2;
-            r -= &s1;
-            r.double_in_place();
-
-            // V = U1*I
-            let mut v = u1;
-            v *= &i;
-
-            // X3 = r^2 + J - 2*V
-            self.x = r;
-            self.x.square_in_place();
-            self.x += &j;
-            self.x -= &(v.double());
-
-            // Y3 = r*(V - X3) + 2*S1*J
-            v -= &self.x;
-            self.y = s1;
-            self.y.double_in_place();
-            self.y = P::BaseField::sum_of_products(&[r, self.y], &[v, j]);
-
-            // Z3 = ((Z1+Z2)^2 - Z1Z1 - Z2Z2)*H
-            // This is equal to Z3 = 2 * Z1 * Z2 * H, and computing it this way is faster.
-            self.z *= other.z;
-            self.z.double_in_place();
-            self.z *= &h;
-        }
+        jacobian::add_assign(
+            &ConfigCoeffA::<P>::new(),
+            (&mut self.x, &mut self.y, &mut self.z),
+            (other.x, other.y, other.z),
+        );
     }
 }
 
@@ -650,6 +449,17 @@ impl<P: SWCurveConfig> VariableBaseMSM for Projective<P> {
     fn msm(bases: &[Self::MulBase], bigints: &[Self::ScalarField]) -> Result<Self, usize> {
         P::msm(bases, bigints)
     }
+
+    fn msm_bigint_with_bits(
+        bases: &[Self::MulBase],
+        bigints: &[<Self::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
+        window: usize,
+    ) -> Self {
+        crate::scalar_mul::variable_base::batch_affine::msm_bigint_batch_affine(
+            bases, bigints, num_bits, window,
+        )
+    }
 }
 
 impl<P: SWCurveConfig, T: Borrow<Affine<P>>> core::iter::Sum<T> for Projective<P> {
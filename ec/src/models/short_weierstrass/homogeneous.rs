@@ -0,0 +1,182 @@
+//! Homogeneous projective coordinates, with the complete addition formulae
+//! of Renes, Costello and Batina.
+//!
+//! These are used by [`Projective`] when [`SWCurveConfig::COMPLETE_ADDITION`]
+//! is set, and by the constant-time scalar multiplication of the `ct`
+//! feature.
+use ark_ff::{AdditiveGroup, Field};
+use educe::Educe;
+
+use super::{Affine, Projective, SWCurveConfig};
+
+/// A point in homogeneous projective coordinates `(X : Y : Z)`, which
+/// represent the affine point `(X / Z, Y / Z)`, or zero if `Z = 0`.
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+pub(crate) struct Homogeneous<P: SWCurveConfig> {
+    pub(crate) x: P::BaseField,
+    pub(crate) y: P::BaseField,
+    pub(crate) z: P::BaseField,
+}
+
+impl<P: SWCurveConfig> Homogeneous<P> {
+    pub(crate) const ZERO: Self =
+        Self::new(P::BaseField::ZERO, P::BaseField::ONE, P::BaseField::ZERO);
+
+    pub(crate) const fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts from affine coordinates.
+    pub(crate) fn from_affine(p: &Affine<P>) -> Self {
+        if p.infinity {
+            Self::ZERO
+        } else {
+            Self::new(p.x, p.y, P::BaseField::ONE)
+        }
+    }
+
+    /// Converts from Jacobian coordinates, in which `(X, Y, Z)` represents
+    /// `(X / Z², Y / Z³)`. The zero point `(1, 1, 0)` maps to `(0 : 1 : 0)`.
+    pub(crate) fn from_jacobian(p: &Projective<P>) -> Self {
+        Self::new(p.x * p.z, p.y, p.z.square() * p.z)
+    }
+
+    /// Converts to Jacobian coordinates.
+    ///
+    /// Zero maps to the canonical zero `(1, 1, 0)`, rather than to
+    /// `(0, 0, 0)`, which [`Self::from_jacobian`] would not map back to a
+    /// valid point.
+    pub(crate) fn into_jacobian(self) -> Projective<P> {
+        if self.z.is_zero() {
+            Projective::ZERO
+        } else {
+            Projective::new_unchecked(self.x * self.z, self.y * self.z.square(), self.z)
+        }
+    }
+
+    /// Computes `self + other` with the complete addition formulae for
+    /// curves with arbitrary `a`, from [\[RCB15\]](https://eprint.iacr.org/2015/1060)
+    /// (Algorithm 1).
+    ///
+    /// These formulae are complete on curves without points of order two,
+    /// i.e. they compute the correct result for all pairs of points,
+    /// including doublings and additions of zero.
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let b3 = P::COEFF_B.double() + P::COEFF_B;
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let mut t0 = x1 * x2;
+        let mut t1 = y1 * y2;
+        let mut t2 = z1 * z2;
+        let mut t3 = (x1 + y1) * (x2 + y2);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        t4 = (x1 + z1) * (x2 + z2);
+        let mut t5 = t0 + t2;
+        t4 -= t5;
+        t5 = (y1 + z1) * (y2 + z2);
+        let mut x3 = t1 + t2;
+        t5 -= x3;
+        let mut z3 = P::mul_by_a(t4);
+        x3 = b3 * t2;
+        z3 += x3;
+        x3 = t1 - z3;
+        z3 += t1;
+        let mut y3 = x3 * z3;
+        t1 = t0.double() + t0;
+        t2 = P::mul_by_a(t2);
+        t4 *= b3;
+        t1 += t2;
+        t2 = P::mul_by_a(t0 - t2);
+        t4 += t2;
+        t0 = t1 * t4;
+        y3 += t0;
+        t0 = t5 * t4;
+        x3 *= t3;
+        x3 -= t0;
+        t0 = t3 * t1;
+        z3 *= t5;
+        z3 += t0;
+        Self::new(x3, y3, z3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand, Zero};
+    use ark_test_curves::{
+        ark_ec::{
+            short_weierstrass::{Affine, Projective, SWCurveConfig},
+            AdditiveGroup, CurveConfig, CurveGroup,
+        },
+        bls12_381::{g1, Fq, Fr, G1Projective},
+    };
+
+    /// BLS12-381 G1, with the complete addition formulae.
+    #[derive(Clone, Default, PartialEq, Eq)]
+    struct CompleteConfig;
+
+    impl CurveConfig for CompleteConfig {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+
+        const COFACTOR: &'static [u64] = g1::Config::COFACTOR;
+        const COFACTOR_INV: Fr = g1::Config::COFACTOR_INV;
+    }
+
+    impl SWCurveConfig for CompleteConfig {
+        const COEFF_A: Fq = <g1::Config as SWCurveConfig>::COEFF_A;
+        const COEFF_B: Fq = <g1::Config as SWCurveConfig>::COEFF_B;
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(
+            <g1::Config as SWCurveConfig>::GENERATOR.x,
+            <g1::Config as SWCurveConfig>::GENERATOR.y,
+        );
+        const COMPLETE_ADDITION: bool = true;
+    }
+
+    type CompleteProjective = Projective<CompleteConfig>;
+    type CompleteAffine = Affine<CompleteConfig>;
+
+    fn to_complete(p: G1Projective) -> CompleteProjective {
+        CompleteProjective::new_unchecked(p.x, p.y, p.z)
+    }
+
+    fn from_complete(p: CompleteProjective) -> G1Projective {
+        G1Projective::new_unchecked(p.x, p.y, p.z)
+    }
+
+    #[test]
+    fn test_complete_addition() {
+        let rng = &mut test_rng();
+        let zero = CompleteProjective::zero();
+        assert!(zero.double().is_zero());
+        assert!((zero + zero).is_zero());
+        assert!((zero + CompleteAffine::identity()).is_zero());
+
+        for _ in 0..20 {
+            let a = G1Projective::rand(rng);
+            let b = G1Projective::rand(rng);
+            let (ca, cb) = (to_complete(a), to_complete(b));
+            let b_affine = cb.into_affine();
+            let a_affine = ca.into_affine();
+
+            assert_eq!(from_complete(ca + cb), a + b);
+            assert_eq!(from_complete(ca + ca), a.double());
+            assert_eq!(from_complete(ca.double()), a.double());
+            assert!((ca - ca).is_zero());
+            assert_eq!(ca + zero, ca);
+            assert_eq!(zero + ca, ca);
+
+            assert_eq!(from_complete(ca + b_affine), a + b);
+            assert_eq!(from_complete(ca + a_affine), a.double());
+            assert!((ca - a_affine).is_zero());
+            assert_eq!(ca + CompleteAffine::identity(), ca);
+            assert_eq!(zero + b_affine, cb);
+
+            let s = Fr::rand(rng);
+            assert_eq!(from_complete(ca * s), a * s);
+        }
+    }
+}
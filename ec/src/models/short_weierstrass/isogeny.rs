@@ -0,0 +1,442 @@
+//! Isogenies between short Weierstrass curves, given by their rational maps,
+//! and their construction from a kernel point with Vélu's formulae.
+use ark_ff::{batch_inversion, AdditiveGroup, Field};
+use ark_std::{fmt, vec::*, Zero};
+use educe::Educe;
+
+use super::{Affine, Projective, SWCurveConfig};
+use crate::{AffineRepr, CurveConfig, CurveGroup};
+
+type BaseField<P> = <P as CurveConfig>::BaseField;
+
+/// [`IsogenyMap`] defines an isogeny between curves of
+/// form `Phi(x, y) := (a(x), b(x)*y).
+/// The `x` coordinate of the codomain point only depends on the
+/// `x`-coordinate of the domain point, and the
+/// `y`-coordinate of the codomain point is a multiple of the `y`-coordinate of the domain point.
+/// The multiplier depends on the `x`-coordinate of the domain point.
+/// All isogeny maps of curves of short Weierstrass form can be written in this way. See
+/// [\[Ga18]\]. Theorem 9.7.5 for details.
+///
+/// The coefficients of each polynomial are given from the constant term
+/// upwards. [`Isogeny`] is the owned counterpart of this type.
+///
+/// We assume that `Domain` and `Codomain` have the same `BaseField` but we use both
+/// `BaseField<Domain>` and `BaseField<Codomain>` in our fields' definitions to avoid
+/// using `PhantomData`
+///
+/// - [\[Ga18]\] Galbraith, S. D. (2018). Mathematics of public key cryptography.
+pub struct IsogenyMap<
+    'a,
+    Domain: SWCurveConfig,
+    Codomain: SWCurveConfig<BaseField = BaseField<Domain>>,
+> {
+    pub x_map_numerator: &'a [BaseField<Domain>],
+    pub x_map_denominator: &'a [BaseField<Codomain>],
+
+    pub y_map_numerator: &'a [BaseField<Domain>],
+    pub y_map_denominator: &'a [BaseField<Codomain>],
+}
+
+impl<'a, Domain, Codomain> IsogenyMap<'a, Domain, Codomain>
+where
+    Domain: SWCurveConfig,
+    Codomain: SWCurveConfig<BaseField = BaseField<Domain>>,
+{
+    /// Evaluates the isogeny at `p`. Points of the kernel, at which the
+    /// denominators vanish, map to the identity.
+    pub fn evaluate(&self, p: &Affine<Domain>) -> Affine<Codomain> {
+        let Some((x, y)) = p.xy() else {
+            return Affine::identity();
+        };
+        let mut v = [
+            evaluate(self.x_map_denominator, x),
+            evaluate(self.y_map_denominator, x),
+        ];
+        if v.iter().any(Zero::is_zero) {
+            return Affine::identity();
+        }
+        batch_inversion(&mut v);
+        let img_x = evaluate(self.x_map_numerator, x) * v[0];
+        let img_y = evaluate(self.y_map_numerator, x) * y * v[1];
+        Affine::new_unchecked(img_x, img_y)
+    }
+
+    /// Evaluates the isogeny at `p` in Jacobian coordinates, without any
+    /// inversions.
+    pub fn evaluate_projective(&self, p: &Projective<Domain>) -> Projective<Codomain> {
+        if p.is_zero() {
+            return Projective::zero();
+        }
+        // With x = X / Z², each polynomial is evaluated as a homogeneous
+        // polynomial in X and Z² of the largest degree among the four, so
+        // that the powers of Z² cancel in the quotients.
+        let degree = [
+            self.x_map_numerator,
+            self.x_map_denominator,
+            self.y_map_numerator,
+            self.y_map_denominator,
+        ]
+        .iter()
+        .map(|c| c.len().saturating_sub(1))
+        .max()
+        .unwrap_or(0);
+        let z2 = p.z.square();
+        let mut z2_powers = Vec::with_capacity(degree + 1);
+        z2_powers.push(BaseField::<Domain>::ONE);
+        for i in 0..degree {
+            z2_powers.push(z2_powers[i] * z2);
+        }
+        let evaluate_homogeneous = |coeffs: &[BaseField<Domain>]| {
+            (0..=degree)
+                .rev()
+                .fold(BaseField::<Domain>::ZERO, |acc, i| {
+                    let c = coeffs.get(i).copied().unwrap_or_default();
+                    acc * p.x + c * z2_powers[degree - i]
+                })
+        };
+
+        // x' = a / b and y' = c / e.
+        let a = evaluate_homogeneous(self.x_map_numerator);
+        let b = evaluate_homogeneous(self.x_map_denominator);
+        let c = evaluate_homogeneous(self.y_map_numerator) * p.y;
+        let e = evaluate_homogeneous(self.y_map_denominator) * z2 * p.z;
+        let z = b * e;
+        if z.is_zero() {
+            return Projective::zero();
+        }
+        let be2 = b * e.square();
+        Projective::new_unchecked(a * be2, c * b.square() * be2, z)
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `x`.
+pub(crate) fn evaluate<F: Field>(coeffs: &[F], x: F) -> F {
+    coeffs.iter().rev().fold(F::ZERO, |acc, c| acc * x + c)
+}
+
+/// Errors that may occur when constructing an [`Isogeny`] from its kernel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IsogenyError {
+    /// The kernel generator does not satisfy the curve equation.
+    KernelNotOnCurve,
+    /// The order of the kernel generator exceeds
+    /// [`Isogeny::MAX_VELU_DEGREE`].
+    KernelTooLarge,
+    /// The coefficients of the curve computed by Vélu's formulae differ
+    /// from those of the codomain.
+    CodomainMismatch,
+}
+
+impl ark_std::error::Error for IsogenyError {}
+
+impl fmt::Display for IsogenyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            IsogenyError::KernelNotOnCurve => write!(f, "kernel point is not on the curve"),
+            IsogenyError::KernelTooLarge => write!(f, "kernel is too large for Vélu's formulae"),
+            IsogenyError::CodomainMismatch => {
+                write!(f, "codomain does not match the isogenous curve")
+            },
+        }
+    }
+}
+
+/// An isogeny between short Weierstrass curves, given by its rational maps
+/// as in [`IsogenyMap`], but with owned coefficients.
+#[derive(Educe)]
+#[educe(Clone, Debug, PartialEq, Eq)]
+pub struct Isogeny<Domain: SWCurveConfig, Codomain: SWCurveConfig<BaseField = BaseField<Domain>>> {
+    pub x_map_numerator: Vec<BaseField<Domain>>,
+    pub x_map_denominator: Vec<BaseField<Codomain>>,
+
+    pub y_map_numerator: Vec<BaseField<Domain>>,
+    pub y_map_denominator: Vec<BaseField<Codomain>>,
+}
+
+impl<Domain, Codomain> Isogeny<Domain, Codomain>
+where
+    Domain: SWCurveConfig,
+    Codomain: SWCurveConfig<BaseField = BaseField<Domain>>,
+{
+    /// The largest order of a kernel accepted by [`Self::from_kernel`].
+    pub const MAX_VELU_DEGREE: usize = 1 << 10;
+
+    /// Constructs the isogeny with the cyclic kernel generated by `kernel`,
+    /// with Vélu's formulae (see Theorem 12.16 of Washington, *Elliptic
+    /// Curves: Number Theory and Cryptography*).
+    ///
+    /// The isogeny is normalized, and so its codomain is determined by the
+    /// kernel: an error is returned if `Codomain` is not that curve. The
+    /// cost is quadratic in the order of `kernel`, which is thus limited to
+    /// [`Self::MAX_VELU_DEGREE`].
+    pub fn from_kernel(kernel: &Affine<Domain>) -> Result<Self, IsogenyError> {
+        if !kernel.is_on_curve() {
+            return Err(IsogenyError::KernelNotOnCurve);
+        }
+
+        // The non-zero points of the kernel.
+        let mut points = Vec::new();
+        let mut q = kernel.into_group();
+        while !q.is_zero() {
+            // With `q`, the kernel contains at least `points.len() + 1` points
+            // besides the identity.
+            if points.len() + 2 > Self::MAX_VELU_DEGREE {
+                return Err(IsogenyError::KernelTooLarge);
+            }
+            points.push(q.into_affine());
+            q += kernel;
+        }
+        let degree = points.len() + 1;
+
+        // The x-map is `x + Σ v_Q / (x - x_Q) + u_Q / (x - x_Q)²`, summing
+        // over the points of order two, and one of each pair `±Q` of the
+        // other points; its denominator is `D = Π (x - x_Q)^{e_Q}`, with
+        // `e_Q = 1` for points of order two and `e_Q = 2` otherwise.
+        let three = BaseField::<Domain>::from(3u64);
+        let (mut v, mut w) = (BaseField::<Domain>::ZERO, BaseField::<Domain>::ZERO);
+        let mut terms = Vec::new();
+        let mut den = vec![BaseField::<Domain>::ONE];
+        for (i, q) in points.iter().enumerate() {
+            let two_torsion = q.y.is_zero();
+            if !two_torsion && i + 1 >= degree - (i + 1) {
+                continue;
+            }
+            let g_x = three * q.x.square() + Domain::COEFF_A;
+            let v_q = if two_torsion { g_x } else { g_x.double() };
+            let u_q = q.y.square().double().double();
+            v += v_q;
+            w += u_q + q.x * v_q;
+
+            let linear = [-q.x, BaseField::<Domain>::ONE];
+            den = poly_mul(&den, &linear);
+            if !two_torsion {
+                den = poly_mul(&den, &linear);
+            }
+            terms.push((q.x, two_torsion, v_q, u_q));
+        }
+
+        let coeff_a = Domain::COEFF_A - v * BaseField::<Domain>::from(5u64);
+        let coeff_b = Domain::COEFF_B - w * BaseField::<Domain>::from(7u64);
+        if coeff_a != Codomain::COEFF_A || coeff_b != Codomain::COEFF_B {
+            return Err(IsogenyError::CodomainMismatch);
+        }
+
+        let mut num = poly_mul(&den, &[BaseField::<Domain>::ZERO, BaseField::<Domain>::ONE]);
+        for (x_q, two_torsion, v_q, u_q) in terms {
+            let den_1 = div_by_linear(&den, x_q);
+            poly_add_scaled(&mut num, &den_1, v_q);
+            if !two_torsion {
+                poly_add_scaled(&mut num, &div_by_linear(&den_1, x_q), u_q);
+            }
+        }
+
+        // Since the isogeny is normalized, the y-map is `y` times the
+        // derivative of the x-map, `(N' * D - N * D') / D²`.
+        let mut y_num = poly_mul(&derivative(&num), &den);
+        poly_add_scaled(
+            &mut y_num,
+            &poly_mul(&num, &derivative(&den)),
+            -BaseField::<Domain>::ONE,
+        );
+        let y_den = poly_mul(&den, &den);
+
+        Ok(Self {
+            x_map_numerator: num,
+            x_map_denominator: den,
+            y_map_numerator: y_num,
+            y_map_denominator: y_den,
+        })
+    }
+
+    /// Returns a view of the rational maps of `self`.
+    pub fn as_map(&self) -> IsogenyMap<'_, Domain, Codomain> {
+        IsogenyMap {
+            x_map_numerator: &self.x_map_numerator,
+            x_map_denominator: &self.x_map_denominator,
+            y_map_numerator: &self.y_map_numerator,
+            y_map_denominator: &self.y_map_denominator,
+        }
+    }
+
+    /// Evaluates the isogeny at `p`, as in [`IsogenyMap::evaluate`].
+    pub fn evaluate(&self, p: &Affine<Domain>) -> Affine<Codomain> {
+        self.as_map().evaluate(p)
+    }
+
+    /// Evaluates the isogeny at `p`, as in [`IsogenyMap::evaluate_projective`].
+    pub fn evaluate_projective(&self, p: &Projective<Domain>) -> Projective<Codomain> {
+        self.as_map().evaluate_projective(p)
+    }
+}
+
+impl<'a, Domain, Codomain> From<IsogenyMap<'a, Domain, Codomain>> for Isogeny<Domain, Codomain>
+where
+    Domain: SWCurveConfig,
+    Codomain: SWCurveConfig<BaseField = BaseField<Domain>>,
+{
+    fn from(map: IsogenyMap<'a, Domain, Codomain>) -> Self {
+        Self {
+            x_map_numerator: map.x_map_numerator.to_vec(),
+            x_map_denominator: map.x_map_denominator.to_vec(),
+            y_map_numerator: map.y_map_numerator.to_vec(),
+            y_map_denominator: map.y_map_denominator.to_vec(),
+        }
+    }
+}
+
+fn poly_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut res = vec![F::ZERO; a.len() + b.len() - 1];
+    for (i, a_i) in a.iter().enumerate() {
+        for (j, b_j) in b.iter().enumerate() {
+            res[i + j] += *a_i * b_j;
+        }
+    }
+    res
+}
+
+/// Sets `a += scalar * b`.
+fn poly_add_scaled<F: Field>(a: &mut Vec<F>, b: &[F], scalar: F) {
+    if a.len() < b.len() {
+        a.resize(b.len(), F::ZERO);
+    }
+    for (a_i, b_i) in a.iter_mut().zip(b) {
+        *a_i += scalar * b_i;
+    }
+}
+
+fn derivative<F: Field>(a: &[F]) -> Vec<F> {
+    let mut res = a
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, a_i)| F::from(i as u64) * a_i)
+        .collect::<Vec<_>>();
+    if res.is_empty() {
+        res.push(F::ZERO);
+    }
+    res
+}
+
+/// Divides `a` by `x - root`, which is assumed to divide it exactly.
+fn div_by_linear<F: Field>(a: &[F], root: F) -> Vec<F> {
+    let mut res = vec![F::ZERO; a.len() - 1];
+    let mut carry = F::ZERO;
+    for i in (1..a.len()).rev() {
+        carry = a[i] + carry * root;
+        res[i - 1] = carry;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{fields::Fp64, MontBackend, MontFp};
+    use ark_std::{test_rng, UniformRand};
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "127"]
+    #[generator = "3"]
+    pub struct F127Config;
+    pub type F127 = Fp64<MontBackend<F127Config, 1>>;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "23"]
+    #[generator = "5"]
+    pub struct F23Config;
+    pub type F23 = Fp64<MontBackend<F23Config, 1>>;
+
+    /// y² = x³ + 3 * x + 18 over F127, which has 115 = 5 * 23 points.
+    struct DomainConfig;
+
+    impl CurveConfig for DomainConfig {
+        type BaseField = F127;
+        type ScalarField = F23;
+
+        const COFACTOR: &'static [u64] = &[5];
+        const COFACTOR_INV: F23 = MontFp!("14");
+    }
+
+    impl SWCurveConfig for DomainConfig {
+        const COEFF_A: F127 = MontFp!("3");
+        const COEFF_B: F127 = MontFp!("18");
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(MontFp!("24"), MontFp!("43"));
+    }
+
+    /// y² = x³ + 124 * x + 22 over F127, the codomain of the 5-isogeny with
+    /// kernel generated by (63, 119).
+    struct CodomainConfig;
+
+    impl CurveConfig for CodomainConfig {
+        type BaseField = F127;
+        type ScalarField = F23;
+
+        const COFACTOR: &'static [u64] = &[5];
+        const COFACTOR_INV: F23 = MontFp!("14");
+    }
+
+    impl SWCurveConfig for CodomainConfig {
+        const COEFF_A: F127 = MontFp!("124");
+        const COEFF_B: F127 = MontFp!("22");
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(MontFp!("10"), MontFp!("105"));
+    }
+
+    const KERNEL: Affine<DomainConfig> = Affine::new_unchecked(MontFp!("63"), MontFp!("119"));
+
+    fn random_point(rng: &mut impl ark_std::rand::Rng) -> Affine<DomainConfig> {
+        loop {
+            let x = F127::rand(rng);
+            if let Some(p) = Affine::get_point_from_x_unchecked(x, bool::rand(rng)) {
+                return p;
+            }
+        }
+    }
+
+    #[test]
+    fn velu_isogeny() {
+        let rng = &mut test_rng();
+        let isogeny = Isogeny::<DomainConfig, CodomainConfig>::from_kernel(&KERNEL).unwrap();
+        assert_eq!(isogeny.x_map_numerator.len(), 6);
+
+        let mut k = KERNEL.into_group();
+        for _ in 0..5 {
+            assert!(isogeny.evaluate(&k.into_affine()).is_zero());
+            assert!(isogeny.evaluate_projective(&k).is_zero());
+            k += KERNEL;
+        }
+
+        for _ in 0..20 {
+            let (p, q) = (random_point(rng), random_point(rng));
+            let (p_img, q_img) = (isogeny.evaluate(&p), isogeny.evaluate(&q));
+            assert!(p_img.is_on_curve());
+            assert_eq!(
+                isogeny.evaluate(&(p + q).into_affine()),
+                (p_img + q_img).into_affine()
+            );
+
+            // Doubling leaves the point with `z != 1`.
+            let p_double = p.into_group().double();
+            assert_eq!(
+                isogeny.evaluate_projective(&p_double),
+                isogeny.evaluate(&p_double.into_affine())
+            );
+        }
+        assert!(isogeny
+            .evaluate(&DomainConfig::GENERATOR)
+            .is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn velu_codomain_mismatch() {
+        assert_eq!(
+            Isogeny::<DomainConfig, DomainConfig>::from_kernel(&KERNEL),
+            Err(IsogenyError::CodomainMismatch)
+        );
+        let not_on_curve = Affine::new_unchecked(F127::ONE, F127::ONE);
+        assert_eq!(
+            Isogeny::<DomainConfig, CodomainConfig>::from_kernel(&not_on_curve),
+            Err(IsogenyError::KernelNotOnCurve)
+        );
+    }
+}
@@ -0,0 +1,328 @@
+//! The Jacobian addition and doubling formulae of short Weierstrass curves,
+//! shared by [`Projective`](super::Projective), whose curve is fixed by an
+//! [`SWCurveConfig`], and by [`RuntimeProjective`](super::RuntimeProjective),
+//! whose curve is given by [`SWCurveParams`](super::SWCurveParams).
+//!
+//! A point `(X, Y, Z)` is passed as its three coordinates, and is the point at
+//! infinity if `Z = 0`.
+use ark_ff::{AdditiveGroup, Field};
+use ark_std::{marker::PhantomData, Zero};
+
+use super::SWCurveConfig;
+
+/// The coefficient `a` of the curve equation `y² = x³ + a * x + b`, which is
+/// the only curve parameter that the formulae depend on.
+pub(crate) trait CoeffA<F: Field> {
+    /// Whether `a` is zero, in which case doubling uses faster formulae.
+    fn is_zero(&self) -> bool;
+
+    /// Computes `a * elem`.
+    fn mul_by_a(&self, elem: F) -> F;
+}
+
+/// The coefficient `a` of the curve defined by `P`.
+pub(crate) struct ConfigCoeffA<P>(PhantomData<P>);
+
+impl<P> ConfigCoeffA<P> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: SWCurveConfig> CoeffA<P::BaseField> for ConfigCoeffA<P> {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        P::COEFF_A == P::BaseField::ZERO
+    }
+
+    #[inline(always)]
+    fn mul_by_a(&self, elem: P::BaseField) -> P::BaseField {
+        P::mul_by_a(elem)
+    }
+}
+
+/// Sets `(x, y, z)` to twice itself, using the following doubling formulae:
+/// * [`a` is zero](http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l)
+/// * [`a` is not zero](https://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#doubling-dbl-2007-bl)
+#[inline(always)]
+pub(crate) fn double_in_place<F: Field>(
+    coeff_a: &impl CoeffA<F>,
+    (x, y, z): (&mut F, &mut F, &mut F),
+) {
+    if z.is_zero() {
+        return;
+    }
+
+    if coeff_a.is_zero() {
+        // A = X1^2
+        let mut a = *x;
+        a.square_in_place();
+
+        // B = Y1^2
+        let mut b = *y;
+        b.square_in_place();
+
+        // C = B^2
+        let mut c = b;
+        c.square_in_place();
+
+        // D = 2*((X1+B)^2-A-C)
+        //   = 2 * (X1 + Y1^2)^2 - A - C
+        //   = 2 * 2 * X1 * Y1^2
+        let d = if [1, 2].contains(&F::extension_degree()) {
+            let mut d = *x;
+            d *= &b;
+            d.double_in_place().double_in_place();
+            d
+        } else {
+            let mut d = *x;
+            d += &b;
+            d.square_in_place();
+            d -= a;
+            d -= c;
+            d.double_in_place();
+            d
+        };
+
+        // E = 3*A
+        let e = a + &*a.double_in_place();
+
+        // Z3 = 2*Y1*Z1
+        *z *= &*y;
+        z.double_in_place();
+
+        // F = E^2
+        // X3 = F-2*D
+        *x = e;
+        x.square_in_place();
+        *x -= &d.double();
+
+        // Y3 = E*(D-X3)-8*C
+        *y = d;
+        *y -= &*x;
+        *y *= &e;
+        *y -= c.double_in_place().double_in_place().double_in_place();
+    } else {
+        // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+        // XX = X1^2
+        let xx = x.square();
+
+        // YY = Y1^2
+        let yy = y.square();
+
+        // YYYY = YY^2
+        let mut yyyy = yy;
+        yyyy.square_in_place();
+
+        // ZZ = Z1^2
+        let mut zz = *z;
+        zz.square_in_place();
+
+        // S = 2*((X1+YY)^2-XX-YYYY)
+        let s = ((*x + &yy).square() - &xx - &yyyy).double();
+
+        // M = 3*XX+a*ZZ^2
+        let mut m = xx;
+        m.double_in_place();
+        m += &xx;
+        m += &coeff_a.mul_by_a(zz.square());
+
+        // T = M^2-2*S
+        // X3 = T
+        *x = m;
+        x.square_in_place();
+        *x -= s.double();
+
+        // Z3 = (Y1+Z1)^2-YY-ZZ
+        // Can be calculated as Z3 = 2*Y1*Z1, and this is faster.
+        *z *= *y;
+        z.double_in_place();
+
+        // Y3 = M*(S-X3)-8*YYYY
+        *y = s;
+        *y -= &*x;
+        *y *= &m;
+        *y -= yyyy.double_in_place().double_in_place().double_in_place();
+    }
+}
+
+/// Sets `(x, y, z)` to its sum with the affine point `(other_x, other_y)`,
+/// which must not be the point at infinity, using
+/// <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl>.
+#[inline(always)]
+pub(crate) fn add_assign_mixed<F: Field>(
+    coeff_a: &impl CoeffA<F>,
+    (x, y, z): (&mut F, &mut F, &mut F),
+    other_x: F,
+    other_y: F,
+) {
+    if z.is_zero() {
+        *x = other_x;
+        *y = other_y;
+        *z = F::ONE;
+        return;
+    }
+
+    // Z1Z1 = Z1^2
+    let mut z1z1 = *z;
+    z1z1.square_in_place();
+
+    // U2 = X2*Z1Z1
+    let mut u2 = other_x;
+    u2 *= &z1z1;
+
+    // S2 = Y2*Z1*Z1Z1
+    let mut s2 = *z;
+    s2 *= &other_y;
+    s2 *= &z1z1;
+
+    if *x == u2 {
+        if *y == s2 {
+            // The two points are equal, so we double.
+            double_in_place(coeff_a, (x, y, z));
+        } else {
+            // a + (-a) = 0
+            *x = F::ONE;
+            *y = F::ONE;
+            *z = F::ZERO;
+        }
+    } else {
+        // H = U2-X1
+        let mut h = u2;
+        h -= &*x;
+
+        // HH = H^2
+        let mut hh = h;
+        hh.square_in_place();
+
+        // I = 4*HH
+        let mut i = hh;
+        i.double_in_place().double_in_place();
+
+        // J = -H*I
+        let mut j = h;
+        j.neg_in_place();
+        j *= &i;
+
+        // r = 2*(S2-Y1)
+        let mut r = s2;
+        r -= &*y;
+        r.double_in_place();
+
+        // V = X1*I
+        let mut v = *x;
+        v *= &i;
+
+        // X3 = r^2 + J - 2*V
+        *x = r.square();
+        *x += &j;
+        *x -= &v.double();
+
+        // Y3 = r*(V-X3) + 2*Y1*J
+        v -= &*x;
+        y.double_in_place();
+        *y = F::sum_of_products(&[r, *y], &[v, j]);
+
+        // Z3 = 2 * Z1 * H;
+        // Can alternatively be computed as (Z1+H)^2-Z1Z1-HH, but the latter is slower.
+        *z *= &h;
+        z.double_in_place();
+    }
+}
+
+/// Sets `(x, y, z)` to its sum with `(other_x, other_y, other_z)`, using
+/// <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl>,
+/// which works for all curves.
+#[inline(always)]
+pub(crate) fn add_assign<F: Field>(
+    coeff_a: &impl CoeffA<F>,
+    (x, y, z): (&mut F, &mut F, &mut F),
+    (other_x, other_y, other_z): (F, F, F),
+) {
+    if z.is_zero() {
+        *x = other_x;
+        *y = other_y;
+        *z = other_z;
+        return;
+    }
+
+    if other_z.is_zero() {
+        return;
+    }
+
+    // Z1Z1 = Z1^2
+    let z1z1 = z.square();
+
+    // Z2Z2 = Z2^2
+    let z2z2 = other_z.square();
+
+    // U1 = X1*Z2Z2
+    let mut u1 = *x;
+    u1 *= &z2z2;
+
+    // U2 = X2*Z1Z1
+    let mut u2 = other_x;
+    u2 *= &z1z1;
+
+    // S1 = Y1*Z2*Z2Z2
+    let mut s1 = *y;
+    s1 *= &other_z;
+    s1 *= &z2z2;
+
+    // S2 = Y2*Z1*Z1Z1
+    let mut s2 = other_y;
+    s2 *= &*z;
+    s2 *= &z1z1;
+
+    if u1 == u2 {
+        if s1 == s2 {
+            // The two points are equal, so we double.
+            double_in_place(coeff_a, (x, y, z));
+        } else {
+            // a + (-a) = 0
+            *x = F::ONE;
+            *y = F::ONE;
+            *z = F::ZERO;
+        }
+    } else {
+        // H = U2-U1
+        let mut h = u2;
+        h -= &u1;
+
+        // I = (2*H)^2
+        let mut i = h;
+        i.double_in_place().square_in_place();
+
+        // J = -H*I
+        let mut j = h;
+        j.neg_in_place();
+        j *= &i;
+
+        // r = 2*(S2-S1)
+        let mut r = s2;
+        r -= &s1;
+        r.double_in_place();
+
+        // V = U1*I
+        let mut v = u1;
+        v *= &i;
+
+        // X3 = r^2 + J - 2*V
+        *x = r;
+        x.square_in_place();
+        *x += &j;
+        *x -= &(v.double());
+
+        // Y3 = r*(V - X3) + 2*S1*J
+        v -= &*x;
+        *y = s1;
+        y.double_in_place();
+        *y = F::sum_of_products(&[r, *y], &[v, j]);
+
+        // Z3 = ((Z1+Z2)^2 - Z1Z1 - Z2Z2)*H
+        // This is equal to Z3 = 2 * Z1 * Z2 * H, and computing it this way is faster.
+        *z *= other_z;
+        z.double_in_place();
+        *z *= &h;
+    }
+}
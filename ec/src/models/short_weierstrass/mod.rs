@@ -1,8 +1,11 @@
 use ark_serialize::{
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, Compress, SerializationError, Valid, Validate,
+    CanonicalSerializeWithFlags, Compress, SerializationError, Valid, Validate, ValidationError,
+};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
 };
-use ark_std::io::{Read, Write};
 
 use ark_ff::{fields::Field, AdditiveGroup};
 
@@ -17,9 +20,22 @@ use num_traits::Zero;
 mod affine;
 pub use affine::*;
 
+#[cfg(feature = "ct")]
+mod ct;
+
 mod group;
 pub use group::*;
 
+pub(crate) mod homogeneous;
+
+pub(crate) mod isogeny;
+pub use isogeny::*;
+
+mod jacobian;
+
+mod runtime;
+pub use runtime::*;
+
 mod serialization_flags;
 pub use serialization_flags::*;
 
@@ -34,6 +50,28 @@ pub trait SWCurveConfig: super::CurveConfig {
     /// Generator of the prime-order subgroup.
     const GENERATOR: Affine<Self>;
 
+    /// Whether additions and doublings of [`Projective`] points use the
+    /// complete formulae of [\[RCB15\]](https://eprint.iacr.org/2015/1060),
+    /// rather than the default Jacobian formulae.
+    ///
+    /// The Jacobian formulae are faster, but branch on their inputs being
+    /// zero, equal or opposite. The complete formulae compute the correct
+    /// result for all inputs without special cases, which is what circuits
+    /// and constant-time code compute, but cost a conversion to and from
+    /// homogeneous coordinates for each operation. They are complete on
+    /// curves without points of order two, i.e. on which `x³ + a * x + b`
+    /// has no roots in the base field.
+    const COMPLETE_ADDITION: bool = false;
+
+    /// The scalar `h_eff` by which [`Self::clear_cofactor`] multiplies, which
+    /// defaults to the cofactor, as for the default [`Self::clear_cofactor`].
+    ///
+    /// Curves that override [`Self::clear_cofactor`] with the multiplication
+    /// by a smaller effective cofactor, or by an endomorphism computing it,
+    /// must set this to that scalar. It is used to clear the cofactor in
+    /// constant time, as a multiplication by a public scalar.
+    const EFFECTIVE_COFACTOR: &'static [u64] = Self::COFACTOR;
+
     /// Helper method for computing `elem * Self::COEFF_A`.
     ///
     /// The default implementation should be overridden only if
@@ -86,6 +124,16 @@ pub trait SWCurveConfig: super::CurveConfig {
         item.mul_by_cofactor()
     }
 
+    /// Performs cofactor clearing on a projective point, and must agree with
+    /// [`Self::clear_cofactor`].
+    /// The default method multiplies by the cofactor, as the default
+    /// [`Self::clear_cofactor`] does, without normalizing `item`, so that
+    /// callers such as hash-to-curve can normalize a whole batch at once.
+    /// Curves that override [`Self::clear_cofactor`] must override this too.
+    fn clear_cofactor_projective(item: &Projective<Self>) -> Projective<Self> {
+        Self::mul_projective(item, Self::COFACTOR)
+    }
+
     /// Default implementation of group multiplication for projective
     /// coordinates
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
@@ -146,15 +194,11 @@ pub trait SWCurveConfig: super::CurveConfig {
                 let (x, flags): (_, SWFlags) =
                     CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
                 match flags {
-                    SWFlags::PointAtInfinity => (
-                        Affine::<Self>::identity().x,
-                        Affine::<Self>::identity().y,
-                        flags,
-                    ),
+                    SWFlags::PointAtInfinity => (x, Self::BaseField::zero(), flags),
                     _ => {
                         let is_positive = flags.is_positive().unwrap();
                         let (y, neg_y) = Affine::<Self>::get_ys_from_x_unchecked(x)
-                            .ok_or(SerializationError::InvalidData)?;
+                            .ok_or(ValidationError::NotOnCurve)?;
                         if is_positive {
                             (x, y, flags)
                         } else {
@@ -172,6 +216,9 @@ pub trait SWCurveConfig: super::CurveConfig {
             },
         };
         if flags.is_infinity() {
+            if matches!(validate, Validate::Yes) && !(x.is_zero() && y.is_zero()) {
+                return Err(ValidationError::InvalidInfinityFlags.into());
+            }
             Ok(Affine::<Self>::identity())
         } else {
             let point = Affine::<Self>::new_unchecked(x, y);
@@ -182,6 +229,23 @@ pub trait SWCurveConfig: super::CurveConfig {
         }
     }
 
+    /// Deserializes `count` consecutive points in the compressed encoding of
+    /// [`Self::serialize_with_mode`].
+    ///
+    /// The default implementation calls [`Self::deserialize_with_mode`] for
+    /// each point. Configs that keep the default encoding can override this
+    /// with [`Affine::deserialize_default_compressed_batch`], which shares the
+    /// square roots across the points.
+    fn deserialize_compressed_batch<R: Read>(
+        mut reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        (0..count)
+            .map(|_| Self::deserialize_with_mode(&mut reader, Compress::Yes, validate))
+            .collect()
+    }
+
     #[inline]
     fn serialized_size(compress: Compress) -> usize {
         let zero = Self::BaseField::zero();
@@ -0,0 +1,527 @@
+//! Short Weierstrass curves whose parameters are only known at runtime.
+//!
+//! [`SWCurveConfig`] fixes the curve coefficients, the generator and the
+//! subgroup order at compile time, which requires a new type per curve.
+//! [`SWCurveParams`] instead stores these in an instance, so that curves can
+//! be loaded from configuration, e.g. user-supplied Weierstrass curves over
+//! a fixed base field. Points are plain coordinates, and all arithmetic that
+//! depends on the curve goes through the parameters, which use the same
+//! Jacobian formulae as [`Projective`](super::Projective).
+//!
+//! Since [`CurveGroup`](crate::CurveGroup) and [`SWCurveConfig`] take their
+//! parameters from the type, runtime curves cannot implement them. Points of
+//! a curve that also has an [`SWCurveConfig`] convert to and from [`Affine`].
+use ark_ff::{AdditiveGroup, BitIteratorBE, Field, PrimeField};
+use ark_std::{fmt, ops::Neg, vec::Vec, Zero};
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+use super::{
+    jacobian::{self, CoeffA},
+    Affine, SWCurveConfig,
+};
+
+/// Affine coordinates for a point on a runtime-parameterized short
+/// Weierstrass curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RuntimeAffine<F: Field> {
+    /// X coordinate of the point represented as a field element
+    pub x: F,
+    /// Y coordinate of the point represented as a field element
+    pub y: F,
+    /// Flag determining if the point is the point at infinity.
+    pub infinity: bool,
+}
+
+impl<F: Field> RuntimeAffine<F> {
+    /// Constructs a point from its coordinates, without checking that it is
+    /// on any curve.
+    pub const fn new_unchecked(x: F, y: F) -> Self {
+        Self {
+            x,
+            y,
+            infinity: false,
+        }
+    }
+
+    /// Returns the point at infinity.
+    pub const fn identity() -> Self {
+        Self {
+            x: F::ZERO,
+            y: F::ZERO,
+            infinity: true,
+        }
+    }
+
+    /// Is this point the point at infinity?
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    /// Converts to Jacobian coordinates.
+    pub fn into_group(self) -> RuntimeProjective<F> {
+        self.into()
+    }
+}
+
+impl<F: Field> Neg for RuntimeAffine<F> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.y.neg_in_place();
+        self
+    }
+}
+
+impl<F: Field> Zeroize for RuntimeAffine<F> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.infinity.zeroize();
+    }
+}
+
+impl<F: Field> From<RuntimeProjective<F>> for RuntimeAffine<F> {
+    fn from(p: RuntimeProjective<F>) -> Self {
+        p.into_affine()
+    }
+}
+
+impl<P: SWCurveConfig> From<Affine<P>> for RuntimeAffine<P::BaseField> {
+    fn from(p: Affine<P>) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            infinity: p.infinity,
+        }
+    }
+}
+
+impl<P: SWCurveConfig> From<RuntimeAffine<P::BaseField>> for Affine<P> {
+    /// Converts `p` to a point of the curve defined by `P`, without checking
+    /// that `p` lies on that curve.
+    fn from(p: RuntimeAffine<P::BaseField>) -> Self {
+        if p.infinity {
+            Self::identity()
+        } else {
+            Self::new_unchecked(p.x, p.y)
+        }
+    }
+}
+
+/// Jacobian coordinates for a point on a runtime-parameterized short
+/// Weierstrass curve, i.e. `(X, Y, Z)` represents the affine point
+/// `(X / Z², Y / Z³)`, or the point at infinity if `Z = 0`.
+#[derive(Clone, Copy, Debug)]
+pub struct RuntimeProjective<F: Field> {
+    /// `X / Z²` projection of the affine `X`
+    pub x: F,
+    /// `Y / Z³` projection of the affine `Y`
+    pub y: F,
+    /// Projective multiplicative inverse. Will be `0` only at infinity.
+    pub z: F,
+}
+
+impl<F: Field> RuntimeProjective<F> {
+    /// The point at infinity.
+    pub const ZERO: Self = Self::new_unchecked(F::ONE, F::ONE, F::ZERO);
+
+    /// Constructs a point from its coordinates, without checking that it is
+    /// on any curve.
+    pub const fn new_unchecked(x: F, y: F, z: F) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Is this point the point at infinity?
+    pub fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Normalizes to affine coordinates.
+    pub fn into_affine(self) -> RuntimeAffine<F> {
+        if self.is_zero() {
+            RuntimeAffine::identity()
+        } else if self.z.is_one() {
+            RuntimeAffine::new_unchecked(self.x, self.y)
+        } else {
+            let zinv = self.z.inverse().unwrap();
+            let zinv_squared = zinv.square();
+            RuntimeAffine::new_unchecked(self.x * zinv_squared, self.y * zinv_squared * zinv)
+        }
+    }
+}
+
+impl<F: Field> Default for RuntimeProjective<F> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F: Field> PartialEq for RuntimeProjective<F> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() || other.is_zero() {
+            return self.is_zero() && other.is_zero();
+        }
+
+        // The points (X, Y, Z) and (X', Y', Z') are equal when
+        // X * Z'^2 = X' * Z^2 and Y * Z'^3 = Y' * Z^3.
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        self.x * z2z2 == other.x * z1z1 && self.y * (z2z2 * other.z) == other.y * (z1z1 * self.z)
+    }
+}
+
+impl<F: Field> Eq for RuntimeProjective<F> {}
+
+impl<F: Field> Neg for RuntimeProjective<F> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.y.neg_in_place();
+        self
+    }
+}
+
+impl<F: Field> Zeroize for RuntimeProjective<F> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<F: Field> From<RuntimeAffine<F>> for RuntimeProjective<F> {
+    fn from(p: RuntimeAffine<F>) -> Self {
+        if p.infinity {
+            Self::ZERO
+        } else {
+            Self::new_unchecked(p.x, p.y, F::ONE)
+        }
+    }
+}
+
+/// An error that can occur when constructing [`SWCurveParams`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CurveParamsError {
+    /// The discriminant `4 * a³ + 27 * b²` is zero.
+    SingularCurve,
+    /// The generator does not satisfy the curve equation.
+    GeneratorNotOnCurve,
+    /// The generator is the point at infinity, or is not annihilated by the
+    /// subgroup order.
+    InvalidGenerator,
+    /// The subgroup order or the cofactor is zero.
+    InvalidOrder,
+}
+
+impl ark_std::error::Error for CurveParamsError {}
+
+impl fmt::Display for CurveParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CurveParamsError::SingularCurve => write!(f, "curve is singular"),
+            CurveParamsError::GeneratorNotOnCurve => write!(f, "generator is not on the curve"),
+            CurveParamsError::InvalidGenerator => {
+                write!(f, "generator is not of the given subgroup order")
+            },
+            CurveParamsError::InvalidOrder => write!(f, "subgroup order or cofactor is zero"),
+        }
+    }
+}
+
+/// The parameters of the short Weierstrass curve `y² = x³ + a * x + b` over
+/// `F`, together with a generator of a subgroup of prime order `r`, and the
+/// cofactor `h` of that subgroup.
+///
+/// This is the runtime counterpart of [`SWCurveConfig`]. Since the subgroup
+/// order is not known at compile time, scalars are given as little-endian
+/// `u64` limbs or as [`BigUint`]s, rather than as elements of a scalar field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SWCurveParams<F: Field> {
+    coeff_a: F,
+    coeff_b: F,
+    generator: RuntimeAffine<F>,
+    order: Vec<u64>,
+    cofactor: Vec<u64>,
+}
+
+impl<F: Field> SWCurveParams<F> {
+    /// Constructs the parameters of a curve, checking that the curve is
+    /// non-singular, and that `generator` is a point of the curve of order
+    /// dividing `order`.
+    ///
+    /// The primality of `order` is not checked.
+    pub fn new(
+        coeff_a: F,
+        coeff_b: F,
+        generator: RuntimeAffine<F>,
+        order: BigUint,
+        cofactor: BigUint,
+    ) -> Result<Self, CurveParamsError> {
+        let four_a_cubed = coeff_a.square() * coeff_a.double().double();
+        let twenty_seven_b_squared = coeff_b.square() * F::from(27u64);
+        if (four_a_cubed + twenty_seven_b_squared).is_zero() {
+            return Err(CurveParamsError::SingularCurve);
+        }
+        if order.bits() == 0 || cofactor.bits() == 0 {
+            return Err(CurveParamsError::InvalidOrder);
+        }
+
+        let params = Self::new_unchecked(coeff_a, coeff_b, generator, order, cofactor);
+        if !params.is_on_curve(&generator) {
+            return Err(CurveParamsError::GeneratorNotOnCurve);
+        }
+        if generator.is_zero() || !params.is_in_correct_subgroup_assuming_on_curve(&generator) {
+            return Err(CurveParamsError::InvalidGenerator);
+        }
+        Ok(params)
+    }
+
+    /// Constructs the parameters of a curve without any checks.
+    pub fn new_unchecked(
+        coeff_a: F,
+        coeff_b: F,
+        generator: RuntimeAffine<F>,
+        order: BigUint,
+        cofactor: BigUint,
+    ) -> Self {
+        Self {
+            coeff_a,
+            coeff_b,
+            generator,
+            order: order.to_u64_digits(),
+            cofactor: cofactor.to_u64_digits(),
+        }
+    }
+
+    /// Returns the parameters of the curve defined by `P`.
+    pub fn from_config<P: SWCurveConfig<BaseField = F>>() -> Self {
+        Self::new_unchecked(
+            P::COEFF_A,
+            P::COEFF_B,
+            P::GENERATOR.into(),
+            P::ScalarField::MODULUS.into(),
+            biguint_from_limbs(P::COFACTOR),
+        )
+    }
+
+    /// Coefficient `a` of the curve equation.
+    pub fn coeff_a(&self) -> F {
+        self.coeff_a
+    }
+
+    /// Coefficient `b` of the curve equation.
+    pub fn coeff_b(&self) -> F {
+        self.coeff_b
+    }
+
+    /// Generator of the prime-order subgroup.
+    pub fn generator(&self) -> RuntimeAffine<F> {
+        self.generator
+    }
+
+    /// The order of the subgroup generated by [`Self::generator`].
+    pub fn order(&self) -> BigUint {
+        biguint_from_limbs(&self.order)
+    }
+
+    /// The cofactor of the subgroup generated by [`Self::generator`].
+    pub fn cofactor(&self) -> BigUint {
+        biguint_from_limbs(&self.cofactor)
+    }
+
+    /// Checks that `p` is on the curve.
+    pub fn is_on_curve(&self, p: &RuntimeAffine<F>) -> bool {
+        if p.infinity {
+            true
+        } else {
+            let mut x3b = p.x.square() * p.x + self.coeff_b;
+            x3b += self.coeff_a * p.x;
+            p.y.square() == x3b
+        }
+    }
+
+    /// Checks that `p`, assumed to be on the curve, is annihilated by the
+    /// subgroup order.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self, p: &RuntimeAffine<F>) -> bool {
+        self.mul_bigint(&p.into_group(), &self.order).is_zero()
+    }
+
+    /// Checks that `p` is on the curve and in the prime-order subgroup.
+    pub fn check(&self, p: &RuntimeAffine<F>) -> bool {
+        self.is_on_curve(p) && self.is_in_correct_subgroup_assuming_on_curve(p)
+    }
+
+    /// Computes `2 * p`.
+    pub fn double(&self, p: &RuntimeProjective<F>) -> RuntimeProjective<F> {
+        let mut res = *p;
+        jacobian::double_in_place(self, (&mut res.x, &mut res.y, &mut res.z));
+        res
+    }
+
+    /// Computes `p + q`.
+    pub fn add(&self, p: &RuntimeProjective<F>, q: &RuntimeProjective<F>) -> RuntimeProjective<F> {
+        let mut res = *p;
+        jacobian::add_assign(self, (&mut res.x, &mut res.y, &mut res.z), (q.x, q.y, q.z));
+        res
+    }
+
+    /// Computes `p + q` for an affine `q`, which is cheaper than [`Self::add`].
+    pub fn add_affine(
+        &self,
+        p: &RuntimeProjective<F>,
+        q: &RuntimeAffine<F>,
+    ) -> RuntimeProjective<F> {
+        let mut res = *p;
+        if !q.is_zero() {
+            jacobian::add_assign_mixed(self, (&mut res.x, &mut res.y, &mut res.z), q.x, q.y);
+        }
+        res
+    }
+
+    /// Computes `p - q`.
+    pub fn sub(&self, p: &RuntimeProjective<F>, q: &RuntimeProjective<F>) -> RuntimeProjective<F> {
+        self.add(p, &-*q)
+    }
+
+    /// Computes `scalar * p`, where `scalar` is given as little-endian
+    /// `u64` limbs.
+    pub fn mul_bigint(
+        &self,
+        p: &RuntimeProjective<F>,
+        scalar: impl AsRef<[u64]>,
+    ) -> RuntimeProjective<F> {
+        let mut res = RuntimeProjective::ZERO;
+        for b in BitIteratorBE::without_leading_zeros(scalar) {
+            res = self.double(&res);
+            if b {
+                res = self.add(&res, p);
+            }
+        }
+        res
+    }
+
+    /// Computes `scalar * p`.
+    pub fn mul(&self, p: &RuntimeProjective<F>, scalar: &BigUint) -> RuntimeProjective<F> {
+        self.mul_bigint(p, scalar.to_u64_digits())
+    }
+
+    /// Multiplies `p` by the cofactor, mapping points of the curve to the
+    /// prime-order subgroup.
+    pub fn clear_cofactor(&self, p: &RuntimeAffine<F>) -> RuntimeAffine<F> {
+        self.mul_bigint(&p.into_group(), &self.cofactor)
+            .into_affine()
+    }
+}
+
+impl<F: Field> CoeffA<F> for SWCurveParams<F> {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.coeff_a.is_zero()
+    }
+
+    #[inline(always)]
+    fn mul_by_a(&self, elem: F) -> F {
+        self.coeff_a * elem
+    }
+}
+
+/// Converts little-endian `u64` limbs to a [`BigUint`].
+fn biguint_from_limbs(limbs: &[u64]) -> BigUint {
+    BigUint::new(
+        limbs
+            .iter()
+            .flat_map(|&l| [l as u32, (l >> 32) as u32])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{
+        ark_ec::{
+            short_weierstrass::{CurveParamsError, RuntimeAffine, SWCurveConfig, SWCurveParams},
+            AdditiveGroup, CurveGroup,
+        },
+        ark_ff::{Field, PrimeField},
+        bls12_381::{g1, Fq, Fr, G1Affine, G1Projective},
+    };
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_runtime_params_match_config() {
+        let rng = &mut test_rng();
+        let params = SWCurveParams::from_config::<g1::Config>();
+        let order: BigUint = Fr::MODULUS.into();
+        let checked = SWCurveParams::new(
+            params.coeff_a(),
+            params.coeff_b(),
+            params.generator(),
+            order.clone(),
+            params.cofactor(),
+        )
+        .unwrap();
+        assert_eq!(checked, params);
+        assert_eq!(params.order(), order);
+
+        for _ in 0..10 {
+            let a = G1Projective::rand(rng);
+            let b = G1Projective::rand(rng);
+            let s = Fr::rand(rng);
+            let (ra, rb) = (
+                RuntimeAffine::from(a.into_affine()).into_group(),
+                RuntimeAffine::from(b.into_affine()).into_group(),
+            );
+
+            let sum = params.add(&ra, &rb).into_affine();
+            assert_eq!(sum, RuntimeAffine::from((a + b).into_affine()));
+            assert_eq!(
+                params
+                    .add_affine(&ra, &b.into_affine().into())
+                    .into_affine(),
+                sum
+            );
+            assert_eq!(G1Affine::from(sum), (a + b).into_affine());
+            assert_eq!(params.double(&ra), params.add(&ra, &ra));
+            assert_eq!(
+                params.double(&ra).into_affine(),
+                RuntimeAffine::from(a.double().into_affine())
+            );
+            assert!(params.sub(&ra, &ra).is_zero());
+            assert_eq!(
+                params.mul_bigint(&ra, s.into_bigint()).into_affine(),
+                RuntimeAffine::from((a * s).into_affine())
+            );
+            assert!(params.check(&ra.into_affine()));
+        }
+
+        let g = params.generator();
+        assert!(params.mul(&g.into_group(), &order).is_zero());
+    }
+
+    #[test]
+    fn test_runtime_params_rejects_invalid() {
+        let g = RuntimeAffine::from(g1::Config::GENERATOR);
+        let b = <g1::Config as SWCurveConfig>::COEFF_B;
+        let order: BigUint = Fr::MODULUS.into();
+        let cofactor = BigUint::from_slice(&[1]);
+
+        assert_eq!(
+            SWCurveParams::new(Fq::ZERO, Fq::ZERO, g, order.clone(), cofactor.clone()),
+            Err(CurveParamsError::SingularCurve)
+        );
+        assert_eq!(
+            SWCurveParams::new(Fq::ONE, b, g, order.clone(), cofactor.clone()),
+            Err(CurveParamsError::GeneratorNotOnCurve)
+        );
+        assert_eq!(
+            SWCurveParams::new(Fq::ZERO, b, g, order.clone() + 1u32, cofactor.clone()),
+            Err(CurveParamsError::InvalidGenerator)
+        );
+        assert_eq!(
+            SWCurveParams::new(Fq::ZERO, b, RuntimeAffine::identity(), order, cofactor),
+            Err(CurveParamsError::InvalidGenerator)
+        );
+    }
+}
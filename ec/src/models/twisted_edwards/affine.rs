@@ -1,5 +1,6 @@
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, Compress,
+    SerializationError, Valid, Validate, ValidationError,
 };
 use ark_std::{
     borrow::Borrow,
@@ -16,10 +17,13 @@ use educe::Educe;
 use num_traits::{One, Zero};
 use zeroize::Zeroize;
 
-use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
+use ark_ff::{
+    batch_inversion, fields::Field, AdditiveGroup, BigInteger, PrimeField, ToConstraintField,
+    UniformRand,
+};
 
 use super::{Projective, TECurveConfig, TEFlags};
-use crate::AffineRepr;
+use crate::{models::validate_point, AffineRepr};
 
 /// Affine coordinates for a point on a twisted Edwards curve, over the
 /// base field `P::BaseField`.
@@ -73,6 +77,16 @@ impl<P: TECurveConfig> Affine<P> {
         p
     }
 
+    /// Like [`Self::new`], but returns why the point is invalid instead of
+    /// panicking.
+    pub fn try_new(x: P::BaseField, y: P::BaseField) -> Result<Self, ValidationError> {
+        let point = Self::new_unchecked(x, y);
+        validate_point(point.is_on_curve(), || {
+            point.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(point)
+    }
+
     /// Construct the identity of the group
     pub const fn zero() -> Self {
         Self::new_unchecked(P::BaseField::ZERO, P::BaseField::ONE)
@@ -135,6 +149,75 @@ impl<P: TECurveConfig> Affine<P> {
             })
     }
 
+    /// Deserializes `count` consecutive points in the compressed encoding of
+    /// [`TECurveConfig::serialize_with_mode`], with
+    /// [`TECurveConfig::deserialize_compressed_batch`]. This returns the same
+    /// points as deserializing each point in turn.
+    pub fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Self>, SerializationError> {
+        P::deserialize_compressed_batch(reader, count, validate)
+    }
+
+    /// Deserializes `count` consecutive points in the compressed encoding of
+    /// the default [`TECurveConfig::serialize_with_mode`], computing the
+    /// inversions and square roots of all points at once with
+    /// [`batch_inversion`] and [`Field::batch_sqrt`], which batches the
+    /// exponentiations of prime fields.
+    ///
+    /// If `validate` is `Yes`, the points are then checked with
+    /// [`Valid::batch_check`]. This returns the same points as deserializing
+    /// each point in turn, but is faster when `count` is large. It must not be
+    /// used for configs that override [`TECurveConfig::serialize_with_mode`].
+    pub fn deserialize_default_compressed_batch<R: Read>(
+        mut reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Self>, SerializationError> {
+        let mut encodings = Vec::with_capacity(count);
+        for _ in 0..count {
+            let (y, flags): (P::BaseField, TEFlags) =
+                CanonicalDeserializeWithFlags::deserialize_with_flags(&mut reader)?;
+            encodings.push((y, flags));
+        }
+
+        // X^2 = (1 - Y^2) / (a - d * Y^2), as in `get_xs_from_y_unchecked`.
+        let mut denominators = encodings
+            .iter()
+            .map(|(y, _)| P::COEFF_A - (y.square() * P::COEFF_D))
+            .collect::<Vec<_>>();
+        if denominators.iter().any(Zero::is_zero) {
+            return Err(ValidationError::NotOnCurve.into());
+        }
+        batch_inversion(&mut denominators);
+        let x2s = encodings
+            .iter()
+            .zip(&denominators)
+            .map(|((y, _), denom_inv)| (P::BaseField::one() - y.square()) * denom_inv)
+            .collect::<Vec<_>>();
+
+        let points = encodings
+            .into_iter()
+            .zip(P::BaseField::batch_sqrt(&x2s))
+            .map(|((y, flags), x)| {
+                let x = x.ok_or(ValidationError::NotOnCurve)?;
+                let neg_x = -x;
+                let (smaller, larger) = if x <= neg_x { (x, neg_x) } else { (neg_x, x) };
+                match flags.is_negative() {
+                    true => Ok(Self::new_unchecked(larger, y)),
+                    false => Ok(Self::new_unchecked(smaller, y)),
+                }
+            })
+            .collect::<Result<Vec<_>, SerializationError>>()?;
+
+        if validate == Validate::Yes {
+            Self::batch_check(points.iter())?;
+        }
+        Ok(points)
+    }
+
     /// Checks that the current point is on the elliptic curve.
     pub fn is_on_curve(&self) -> bool {
         let x2 = self.x.square();
@@ -147,12 +230,105 @@ impl<P: TECurveConfig> Affine<P> {
     }
 }
 
+impl<P: TECurveConfig> Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    /// The size in bytes of the encoding of [RFC 8032], which has room for
+    /// `y` and one sign bit: 32 bytes for Ed25519 and 57 bytes for Ed448.
+    ///
+    /// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+    pub fn rfc8032_size() -> usize {
+        P::BaseField::MODULUS_BIT_SIZE as usize / 8 + 1
+    }
+
+    /// Serializes `self` in the encoding of Ed25519 and Ed448 points of
+    /// [RFC 8032, Section 5.1.2]: `y` in little-endian on
+    /// [`Self::rfc8032_size`] bytes, with the least significant bit of `x`
+    /// copied into the most significant bit of the final byte.
+    ///
+    /// This differs from the compressed encoding of
+    /// [`TECurveConfig::serialize_with_mode`], which flags whether `x` is the
+    /// lexicographically largest of its two possible values rather than
+    /// whether it is odd.
+    ///
+    /// [RFC 8032, Section 5.1.2]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.2
+    pub fn serialize_rfc8032<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        let mut bytes = self.y.into_bigint().to_bytes_le();
+        bytes.resize(Self::rfc8032_size(), 0);
+        *bytes.last_mut().unwrap() |= (self.x.into_bigint().is_odd() as u8) << 7;
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Deserializes a point in the encoding of [RFC 8032, Section 5.1.3],
+    /// rejecting non-canonical encodings of `y`, and the encoding of `x = 0`
+    /// with the sign bit set, as the RFC requires.
+    ///
+    /// If `validate` is `Yes`, the point is also checked to be in the
+    /// prime-order subgroup.
+    ///
+    /// [RFC 8032, Section 5.1.3]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.3
+    pub fn deserialize_rfc8032<R: Read>(
+        mut reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = vec![0u8; Self::rfc8032_size()];
+        reader.read_exact(&mut bytes)?;
+
+        // 1. Clear the sign bit of x, and decode y, which must be less than p.
+        let last = bytes.last_mut().unwrap();
+        let x_is_odd = *last >> 7 == 1;
+        *last &= 0x7f;
+        let y = P::BaseField::from_le_bytes_mod_order(&bytes);
+        let mut canonical_bytes = y.into_bigint().to_bytes_le();
+        canonical_bytes.resize(bytes.len(), 0);
+        if canonical_bytes != bytes {
+            return Err(ValidationError::NonCanonicalFieldEncoding.into());
+        }
+
+        // 2. Recover x, then 3. reject x = 0 with the sign bit set and select
+        // the root whose parity is the sign bit.
+        let (x, neg_x) = Self::get_xs_from_y_unchecked(y).ok_or(ValidationError::NotOnCurve)?;
+        if x.is_zero() && x_is_odd {
+            return Err(ValidationError::NotOnCurve.into());
+        }
+        let x = if x.into_bigint().is_odd() == x_is_odd {
+            x
+        } else {
+            neg_x
+        };
+
+        let point = Self::new_unchecked(x, y);
+        if let Validate::Yes = validate {
+            point.check()?;
+        }
+        Ok(point)
+    }
+}
+
 impl<P: TECurveConfig> Affine<P> {
     /// Checks if `self` is in the subgroup having order equaling that of
     /// `P::ScalarField` given it is on the curve.
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
         P::is_in_correct_subgroup_assuming_on_curve(self)
     }
+
+    /// Checks if `self`, which is assumed to be on the curve, has small order,
+    /// i.e. if its order divides the cofactor. Such points, including the
+    /// identity, lie entirely in the torsion subgroup, and so are usually
+    /// rejected as public keys or as inputs to Diffie-Hellman.
+    pub fn has_small_order(&self) -> bool {
+        self.mul_by_cofactor_to_group().is_zero()
+    }
+
+    /// Checks if `self`, which is assumed to be on the curve, is torsion-free,
+    /// i.e. if it has no component in the torsion subgroup of order equaling
+    /// the cofactor. This holds exactly when `self` is in the prime-order
+    /// subgroup.
+    pub fn is_torsion_free(&self) -> bool {
+        self.is_in_correct_subgroup_assuming_on_curve()
+    }
 }
 
 impl<P: TECurveConfig> AffineRepr for Affine<P> {
@@ -327,11 +503,10 @@ impl<P: TECurveConfig> CanonicalSerialize for Affine<P> {
 
 impl<P: TECurveConfig> Valid for Affine<P> {
     fn check(&self) -> Result<(), SerializationError> {
-        if self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve() {
-            Ok(())
-        } else {
-            Err(SerializationError::InvalidData)
-        }
+        validate_point(self.is_on_curve(), || {
+            self.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(())
     }
 }
 
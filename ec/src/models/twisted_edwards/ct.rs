@@ -0,0 +1,111 @@
+//! Constant-time scalar multiplication on twisted Edwards curves, enabled by
+//! the `ct` feature.
+use ark_ff::subtle::{Choice, ConditionallySelectable};
+use ark_ff::PrimeField;
+use num_traits::Zero;
+
+use super::{Affine, Projective, TECurveConfig};
+use crate::scalar_mul::montgomery_ladder;
+
+impl<P: TECurveConfig> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+        )
+    }
+}
+
+impl<P: TECurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: TECurveConfig> Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    /// Computes `self * scalar` in constant time; see
+    /// [`Projective::mul_ct`].
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Projective<P> {
+        Projective::from(*self).mul_ct(scalar)
+    }
+}
+
+impl<P: TECurveConfig> Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    /// Computes `self * scalar` in constant time, for use with secret
+    /// scalars, e.g. in key exchange and signing.
+    ///
+    /// This runs the Montgomery ladder over all `MODULUS_BIT_SIZE` bits of
+    /// the scalar, with the unified addition formulae of [`Projective`],
+    /// which do not branch. The running time is thus independent of the
+    /// scalar, provided that the arithmetic of the base field is
+    /// constant-time, as it is for the prime fields with the `ct` feature of
+    /// `ark-ff`.
+    ///
+    /// The unified formulae are complete when `a` is a square and `d` is a
+    /// non-square in the base field, as for Ed25519 and Jubjub. On other
+    /// curves, the result may be wrong.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_std::UniformRand;
+    /// use ark_test_curves::ed_on_bls12_381::{Fr, Projective};
+    ///
+    /// let mut rng = ark_std::test_rng();
+    /// let point = Projective::rand(&mut rng);
+    /// let secret = Fr::rand(&mut rng);
+    /// assert_eq!(point.mul_ct(&secret), point * secret);
+    /// ```
+    pub fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        montgomery_ladder(
+            Self::zero(),
+            *self,
+            scalar.into_bigint().as_ref(),
+            P::ScalarField::MODULUS_BIT_SIZE as usize,
+            |a, b| *a + b,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand, Zero};
+    use ark_test_curves::{
+        ark_ec::AffineRepr,
+        ark_ff::{AdditiveGroup, Field},
+        ed_on_bls12_381::{Affine, Fr, Projective},
+    };
+
+    #[test]
+    fn test_mul_ct() {
+        let rng = &mut test_rng();
+        let scalars = [Fr::ZERO, Fr::ONE, -Fr::ONE, Fr::from(2u8)];
+        let scalars = scalars.into_iter().chain((0..20).map(|_| Fr::rand(rng)));
+        for scalar in scalars {
+            let point = Projective::rand(rng);
+            let expected = point * scalar;
+            assert_eq!(point.mul_ct(&scalar), expected);
+            assert_eq!(Affine::from(point).mul_ct(&scalar), expected);
+            assert!(Projective::zero().mul_ct(&scalar).is_zero());
+            assert!(Affine::zero().mul_ct(&scalar).is_zero());
+        }
+    }
+}
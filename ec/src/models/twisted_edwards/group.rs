@@ -23,7 +23,7 @@ use zeroize::Zeroize;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::{Affine, MontCurveConfig, TECurveConfig};
+use super::{Affine, MontCurveConfig, TECoordinates, TECurveConfig};
 use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
@@ -33,7 +33,10 @@ use crate::{
 /// as described in [\[HKCD08\]](https://eprint.iacr.org/2008/522.pdf).
 ///
 /// This implementation uses the unified addition formulae from that paper (see
-/// Section 3.1).
+/// Section 3.1). If [`TECurveConfig::COORDINATES`] is
+/// [`TECoordinates::Projective`], it instead uses the projective formulae
+/// of [\[BBJLP08\]](https://eprint.iacr.org/2008/013), and `t` is not
+/// maintained.
 #[derive(Educe)]
 #[educe(Copy, Clone, Eq(bound(P: TECurveConfig)), Debug)]
 #[must_use]
@@ -120,6 +123,75 @@ impl<P: TECurveConfig> Projective<P> {
         p.into()
     }
 }
+impl<P: TECurveConfig> Projective<P> {
+    /// Sets `self = 2 * self` in projective coordinates.
+    fn double_in_place_projective(&mut self) -> &mut Self {
+        // See "Twisted Edwards Curves"
+        // Daniel J. Bernstein, Peter Birkner, Marc Joye, Tanja Lange, and Christiane Peters
+        // Source: https://www.hyperelliptic.org/EFD/g1p/auto-twisted-projective.html#doubling-dbl-2008-bbjlp
+
+        // B = (X1+Y1)^2
+        let b = (self.x + &self.y).square();
+        // C = X1^2
+        let c = self.x.square();
+        // D = Y1^2
+        let d = self.y.square();
+        // E = a*C
+        let e = P::mul_by_a(c);
+        // F = E+D
+        let f = e + &d;
+        // H = Z1^2
+        let h = self.z.square();
+        // J = F-2*H
+        let j = f - &h.double();
+        // X3 = (B-C-D)*J
+        self.x = (b - &c - &d) * &j;
+        // Y3 = F*(E-D)
+        self.y = f * &(e - &d);
+        // Z3 = F*J
+        self.z = f * &j;
+        self.t = P::BaseField::zero();
+
+        self
+    }
+
+    /// Sets `self = self + other` in projective coordinates, where `other`
+    /// is `(X2 : Y2 : Z2)`, and `Z2` is one if `None`.
+    fn add_assign_projective(
+        &mut self,
+        other_x: &P::BaseField,
+        other_y: &P::BaseField,
+        other_z: Option<&P::BaseField>,
+    ) {
+        // See "Twisted Edwards Curves"
+        // Daniel J. Bernstein, Peter Birkner, Marc Joye, Tanja Lange, and Christiane Peters
+        // Source: https://www.hyperelliptic.org/EFD/g1p/auto-twisted-projective.html#addition-add-2008-bbjlp
+        // and https://www.hyperelliptic.org/EFD/g1p/auto-twisted-projective.html#addition-madd-2008-bbjlp
+
+        // A = Z1*Z2
+        let a = other_z.map_or(self.z, |z| self.z * z);
+        // B = A^2
+        let b = a.square();
+        // C = X1*X2
+        let c = self.x * other_x;
+        // D = Y1*Y2
+        let d = self.y * other_y;
+        // E = d*C*D
+        let e = P::COEFF_D * &c * &d;
+        // F = B-E
+        let f = b - &e;
+        // G = B+E
+        let g = b + &e;
+        // X3 = A*F*((X1+Y1)*(X2+Y2)-C-D)
+        self.x = a * &f * &((self.x + &self.y) * &(*other_x + other_y) - &c - &d);
+        // Y3 = A*G*(D-a*C)
+        self.y = a * &g * &(d - &P::mul_by_a(c));
+        // Z3 = F*G
+        self.z = f * &g;
+        self.t = P::BaseField::zero();
+    }
+}
+
 impl<P: TECurveConfig> Zeroize for Projective<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -157,6 +229,10 @@ impl<P: TECurveConfig> AdditiveGroup for Projective<P> {
     );
 
     fn double_in_place(&mut self) -> &mut Self {
+        if matches!(P::COORDINATES, TECoordinates::Projective) {
+            return self.double_in_place_projective();
+        }
+
         // See "Twisted Edwards Curves Revisited"
         // Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
         // 3.3 Doubling in E^e
@@ -217,21 +293,28 @@ impl<P: TECurveConfig> CurveGroup for Projective<P> {
         // Batch normalizing N twisted edwards curve elements costs:
         //     1 inversion + 6N field multiplications
         // (batch inversion requires 3N multiplications + 1 inversion)
-        let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
-        ark_ff::batch_inversion(&mut z_s);
+        ark_ff::thread_pool::in_thread_pool(|| {
+            let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+            ark_ff::batch_inversion(&mut z_s);
+
+            // Perform affine transformations
+            ark_std::cfg_iter!(v)
+                .zip(z_s)
+                .map(|(g, z)| match g.is_zero() {
+                    true => Affine::zero(),
+                    false => {
+                        let x = g.x * &z;
+                        let y = g.y * &z;
+                        Affine::new_unchecked(x, y)
+                    },
+                })
+                .collect()
+        })
+    }
 
-        // Perform affine transformations
-        ark_std::cfg_iter!(v)
-            .zip(z_s)
-            .map(|(g, z)| match g.is_zero() {
-                true => Affine::zero(),
-                false => {
-                    let x = g.x * &z;
-                    let y = g.y * &z;
-                    Affine::new_unchecked(x, y)
-                },
-            })
-            .collect()
+    #[inline]
+    fn clear_cofactor_in_place(&mut self) {
+        *self = P::clear_cofactor_projective(self);
     }
 }
 
@@ -247,6 +330,11 @@ impl<P: TECurveConfig> Neg for Projective<P> {
 impl<P: TECurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
     fn add_assign(&mut self, other: T) {
         let other = other.borrow();
+        if matches!(P::COORDINATES, TECoordinates::Projective) {
+            self.add_assign_projective(&other.x, &other.y, None);
+            return;
+        }
+
         // See "Twisted Edwards Curves Revisited"
         // Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
         // 3.1 Unified Addition in E^e
@@ -322,6 +410,11 @@ impl<'a, P: TECurveConfig> Sub<&'a Self> for Projective<P> {
 
 impl<'a, P: TECurveConfig> AddAssign<&'a Self> for Projective<P> {
     fn add_assign(&mut self, other: &'a Self) {
+        if matches!(P::COORDINATES, TECoordinates::Projective) {
+            self.add_assign_projective(&other.x, &other.y, Some(&other.z));
+            return;
+        }
+
         // See "Twisted Edwards Curves Revisited" (https://eprint.iacr.org/2008/522.pdf)
         // by Huseyin Hisil, Kenneth Koon-Ho Wong, Gary Carter, and Ed Dawson
         // 3.1 Unified Addition in E^e
@@ -420,6 +513,55 @@ impl<P: MontCurveConfig> MontgomeryAffine<P> {
     pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
         Self { x, y }
     }
+
+    /// Checks whether `self` lies on the curve `b * y^2 = x^3 + a * x^2 + x`.
+    pub fn is_on_curve(&self) -> bool {
+        let rhs = (self.x.square() + P::COEFF_A * self.x + P::BaseField::one()) * self.x;
+        P::COEFF_B * self.y.square() == rhs
+    }
+
+    /// Maps `self` to the birationally equivalent twisted Edwards curve
+    /// [`MontCurveConfig::TECurveConfig`], with the rational map
+    /// `(x, y) -> (x / y, (x - 1) / (x + 1))` of
+    /// [RFC 9380, Appendix D.1](https://www.rfc-editor.org/rfc/rfc9380#appendix-D.1).
+    ///
+    /// The twisted Edwards curve must have coefficients `(a + 2) / b` and
+    /// `(a - 2) / b`. As in the RFC, the points with `y = 0` or `x = -1`, where
+    /// the map is undefined, are sent to the identity.
+    pub fn to_twisted_edwards(&self) -> Affine<P::TECurveConfig> {
+        self.to_twisted_edwards_on()
+    }
+
+    /// Computes [`Self::to_twisted_edwards`] for any twisted Edwards config
+    /// over the same field, for configs whose twisted Edwards form is not
+    /// [`MontCurveConfig::TECurveConfig`] itself.
+    pub(crate) fn to_twisted_edwards_on<T: TECurveConfig<BaseField = P::BaseField>>(
+        &self,
+    ) -> Affine<T> {
+        self.to_twisted_edwards_with_inverse(self.twisted_edwards_denominator().inverse())
+    }
+
+    /// Returns `(x + 1) * y`, the denominator of the map of
+    /// [`Self::to_twisted_edwards`], which is zero where the map is undefined.
+    pub(crate) fn twisted_edwards_denominator(&self) -> P::BaseField {
+        (self.x + P::BaseField::one()) * self.y
+    }
+
+    /// Computes [`Self::to_twisted_edwards_on`] given the inverse of
+    /// [`Self::twisted_edwards_denominator`], or `None` if it is zero, so that
+    /// callers can compute the inverses of many points in a batch.
+    pub(crate) fn to_twisted_edwards_with_inverse<T: TECurveConfig<BaseField = P::BaseField>>(
+        &self,
+        den_inv: Option<P::BaseField>,
+    ) -> Affine<T> {
+        match den_inv {
+            Some(den_inv) => Affine::new_unchecked(
+                den_inv * (self.x + P::BaseField::one()) * self.x,
+                den_inv * self.y * (self.x - P::BaseField::one()),
+            ),
+            None => Affine::zero(),
+        }
+    }
 }
 
 impl<P: TECurveConfig> CanonicalSerialize for Projective<P> {
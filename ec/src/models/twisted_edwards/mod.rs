@@ -1,8 +1,11 @@
 use ark_serialize::{
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, Compress, SerializationError, Valid, Validate,
+    CanonicalSerializeWithFlags, Compress, SerializationError, Valid, Validate, ValidationError,
+};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
 };
-use ark_std::io::{Read, Write};
 
 use crate::{scalar_mul::variable_base::VariableBaseMSM, AffineRepr};
 use num_traits::Zero;
@@ -12,12 +15,37 @@ use ark_ff::{fields::Field, AdditiveGroup};
 mod affine;
 pub use affine::*;
 
+#[cfg(feature = "ct")]
+mod ct;
+
 mod group;
 pub use group::*;
 
+mod montgomery;
+pub use montgomery::*;
+
 mod serialization_flags;
 pub use serialization_flags::*;
 
+/// The coordinates in which the group operations of [`Projective`] are
+/// computed, as selected by [`TECurveConfig::COORDINATES`].
+///
+/// Both represent a point as `(X : Y : Z)`, with `x = X / Z` and `y = Y / Z`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TECoordinates {
+    /// Extended coordinates, from [\[HWCD08\]](https://eprint.iacr.org/2008/522),
+    /// which also maintain `T = X * Y / Z`. This saves about one
+    /// multiplication per addition, at the cost of one more multiplication
+    /// per doubling, and so suits addition-heavy workloads, such as MSMs.
+    #[default]
+    Extended,
+    /// Projective coordinates, from [\[BBJLP08\]](https://eprint.iacr.org/2008/013),
+    /// in which the `t` coordinate of [`Projective`] is not maintained, and
+    /// is set to zero by the group operations. This suits doubling-heavy
+    /// workloads, such as double-and-add scalar multiplication.
+    Projective,
+}
+
 /// Constants and convenience functions that collectively define the [Twisted Edwards model](https://www.hyperelliptic.org/EFD/g1p/auto-twisted.html)
 /// of the curve. In this model, the curve equation is
 /// `a * x² + y² = 1 + d * x² * y²`, for constants `a` and `d`.
@@ -29,6 +57,9 @@ pub trait TECurveConfig: super::CurveConfig {
     /// Generator of the prime-order subgroup.
     const GENERATOR: Affine<Self>;
 
+    /// The coordinates used by the arithmetic of [`Projective`].
+    const COORDINATES: TECoordinates = TECoordinates::Extended;
+
     /// Model parameters for the Montgomery curve that is birationally
     /// equivalent to this curve.
     type MontCurveConfig: MontCurveConfig<BaseField = Self::BaseField>;
@@ -57,6 +88,12 @@ pub trait TECurveConfig: super::CurveConfig {
         item.mul_by_cofactor()
     }
 
+    /// Performs cofactor clearing on a projective point, as described for
+    /// [`SWCurveConfig::clear_cofactor_projective`](crate::short_weierstrass::SWCurveConfig::clear_cofactor_projective).
+    fn clear_cofactor_projective(item: &Projective<Self>) -> Projective<Self> {
+        Self::mul_projective(item, Self::COFACTOR)
+    }
+
     /// Default implementation of group multiplication for projective
     /// coordinates
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
@@ -126,7 +163,7 @@ pub trait TECurveConfig: super::CurveConfig {
                 let (y, flags): (_, TEFlags) =
                     CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
                 let (x, neg_x) = Affine::<Self>::get_xs_from_y_unchecked(y)
-                    .ok_or(SerializationError::InvalidData)?;
+                    .ok_or(ValidationError::NotOnCurve)?;
                 if flags.is_negative() {
                     (neg_x, y)
                 } else {
@@ -148,6 +185,23 @@ pub trait TECurveConfig: super::CurveConfig {
         Ok(point)
     }
 
+    /// Deserializes `count` consecutive points in the compressed encoding of
+    /// [`Self::serialize_with_mode`].
+    ///
+    /// The default implementation calls [`Self::deserialize_with_mode`] for
+    /// each point. Configs that keep the default encoding can override this
+    /// with [`Affine::deserialize_default_compressed_batch`], which shares the
+    /// inversions and square roots across the points.
+    fn deserialize_compressed_batch<R: Read>(
+        mut reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        (0..count)
+            .map(|_| Self::deserialize_with_mode(&mut reader, Compress::Yes, validate))
+            .collect()
+    }
+
     #[inline]
     fn serialized_size(compress: Compress) -> usize {
         let zero = Self::BaseField::zero();
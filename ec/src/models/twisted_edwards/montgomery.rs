@@ -0,0 +1,230 @@
+//! x-only arithmetic on Montgomery curves, as used by X25519 and X448.
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use educe::Educe;
+
+use super::{MontCurveConfig, MontgomeryAffine};
+
+/// The x-coordinate of a point on a Montgomery curve, in projective
+/// coordinates `(X : Z)`, which represent `x = X / Z`.
+///
+/// Since `P` and `-P` have the same x-coordinate, x-only points cannot be
+/// added directly. Instead, [`Self::xadd`] computes the sum of two points
+/// given their difference, which is enough for the Montgomery ladder in
+/// [`Self::ladder`]. This is how Diffie-Hellman over Curve25519 and Curve448
+/// is computed, e.g. in [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748).
+///
+/// The formulae do not depend on the coefficient `b` of the curve, and so
+/// also apply to its quadratic twist.
+///
+/// # Example
+/// ```
+/// use ark_ec::twisted_edwards::MontgomeryXOnly;
+/// use ark_test_curves::ed_on_bls12_381::{EdwardsConfig, Fq};
+///
+/// type X = MontgomeryXOnly<EdwardsConfig>;
+///
+/// let p = X::from_x(Fq::from(5u8));
+/// let p2 = p.xdbl();
+/// let p3 = p2.xadd(&p, &p);
+/// assert_eq!(p.ladder([3u64], 2), p3);
+/// assert_eq!(p.ladder([6u64], 3), p3.xdbl());
+/// // Bits past the end of the scalar are zero.
+/// assert_eq!(p.ladder([3u64], 100), p3);
+/// ```
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+#[must_use]
+pub struct MontgomeryXOnly<P: MontCurveConfig> {
+    /// `X / Z` is the x-coordinate of the point.
+    pub x: P::BaseField,
+    /// Will be `0` only at infinity.
+    pub z: P::BaseField,
+}
+
+impl<P: MontCurveConfig> MontgomeryXOnly<P> {
+    /// Constructs a point from its projective x-coordinate `(X : Z)`,
+    /// without checking that it lies on the curve or its twist.
+    pub const fn new_unchecked(x: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, z }
+    }
+
+    /// Constructs the point with x-coordinate `x`.
+    pub fn from_x(x: P::BaseField) -> Self {
+        Self::new_unchecked(x, P::BaseField::one())
+    }
+
+    /// The point at infinity, `(1 : 0)`.
+    pub fn identity() -> Self {
+        Self::new_unchecked(P::BaseField::one(), P::BaseField::zero())
+    }
+
+    /// Returns whether `self` is the point at infinity.
+    pub fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Returns the affine x-coordinate of `self`, or `None` at infinity.
+    pub fn to_x(&self) -> Option<P::BaseField> {
+        self.z.inverse().map(|z_inv| self.x * z_inv)
+    }
+
+    /// Returns `(a + 2) / 4`, the constant of the doubling formula.
+    fn a24() -> P::BaseField {
+        let four = P::BaseField::from(4u8);
+        (P::COEFF_A + P::BaseField::from(2u8)) * four.inverse().unwrap()
+    }
+
+    /// Computes the x-coordinate of `2 * self`.
+    pub fn xdbl(&self) -> Self {
+        self.xdbl_with_a24(&Self::a24())
+    }
+
+    // See https://www.hyperelliptic.org/EFD/g1p/auto-montgom-xz.html#doubling-dbl-1987-m-3
+    fn xdbl_with_a24(&self, a24: &P::BaseField) -> Self {
+        // AA = (X + Z)^2
+        let aa = (self.x + self.z).square();
+        // BB = (X - Z)^2
+        let bb = (self.x - self.z).square();
+        // E = AA - BB
+        let e = aa - bb;
+        // X2 = AA * BB
+        // Z2 = E * (BB + a24 * E)
+        Self::new_unchecked(aa * bb, e * (bb + *a24 * e))
+    }
+
+    /// Computes the x-coordinate of `self + other`, given the x-coordinate
+    /// of `self - other`.
+    ///
+    /// The result is wrong if `difference` is the point at infinity or the
+    /// point `(0, 0)` of order two, i.e. if `self = ±other`, or if they
+    /// differ by `(0, 0)`.
+    // See https://www.hyperelliptic.org/EFD/g1p/auto-montgom-xz.html#diffadd-dadd-1987-m-3
+    pub fn xadd(&self, other: &Self, difference: &Self) -> Self {
+        // DA = (X3 - Z3) * (X2 + Z2)
+        let da = (other.x - other.z) * (self.x + self.z);
+        // CB = (X3 + Z3) * (X2 - Z2)
+        let cb = (other.x + other.z) * (self.x - self.z);
+        // X5 = Z1 * (DA + CB)^2
+        // Z5 = X1 * (DA - CB)^2
+        Self::new_unchecked(
+            difference.z * (da + cb).square(),
+            difference.x * (da - cb).square(),
+        )
+    }
+
+    /// Computes the x-coordinate of `scalar * self` with the Montgomery
+    /// ladder, where `scalar` is an integer with `num_bits` bits, least
+    /// significant limb first. The bits past the end of `scalar` are treated
+    /// as zeros.
+    ///
+    /// As in X25519, the ladder performs one [`Self::xadd`] and one
+    /// [`Self::xdbl`] for each of the `num_bits` bits, including the
+    /// leading zeros. The two points of the ladder are swapped with a
+    /// branch on each bit, though, so for secret scalars, use
+    /// `Self::ladder_ct`, which is available with the `ct` feature.
+    ///
+    /// Like [`Self::xadd`], the ladder may return a wrong result if `self`
+    /// is the point `(0, 0)`, of order two.
+    pub fn ladder(&self, scalar: impl AsRef<[u64]>, num_bits: usize) -> Self {
+        self.ladder_with_swap(scalar.as_ref(), num_bits, |r0, r1, bit| {
+            if bit == 1 {
+                ark_std::mem::swap(r0, r1)
+            }
+        })
+    }
+
+    /// Computes the x-coordinate of `scalar * self`; see [`Self::ladder`].
+    pub fn mul(&self, scalar: &P::ScalarField) -> Self {
+        self.ladder(
+            scalar.into_bigint(),
+            P::ScalarField::MODULUS_BIT_SIZE as usize,
+        )
+    }
+
+    /// The Montgomery ladder, which calls `swap` with `0` or `1` to swap
+    /// the two points when the current bit is set.
+    fn ladder_with_swap(
+        &self,
+        scalar: &[u64],
+        num_bits: usize,
+        swap: impl Fn(&mut Self, &mut Self, u8),
+    ) -> Self {
+        let a24 = Self::a24();
+        // The ladder maintains `r1 = r0 + self`.
+        let mut r0 = Self::identity();
+        let mut r1 = *self;
+        for i in (0..num_bits).rev() {
+            let bit = scalar.get(i / 64).map_or(0, |limb| (limb >> (i % 64)) & 1) as u8;
+            swap(&mut r0, &mut r1, bit);
+            r1 = r0.xadd(&r1, self);
+            r0 = r0.xdbl_with_a24(&a24);
+            swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: MontCurveConfig> MontgomeryXOnly<P>
+where
+    P::BaseField: ark_ff::subtle::ConditionallySelectable,
+{
+    /// Computes the x-coordinate of `scalar * self` in constant time, for
+    /// use with secret scalars, e.g. in X25519.
+    ///
+    /// This is the same as [`Self::ladder`], except that the two points are
+    /// swapped with conditional selection, rather than branches. The
+    /// running time thus only depends on `num_bits`, provided that the
+    /// arithmetic of the base field is constant-time.
+    pub fn ladder_ct(&self, scalar: impl AsRef<[u64]>, num_bits: usize) -> Self {
+        use ark_ff::subtle::{Choice, ConditionallySelectable};
+        self.ladder_with_swap(scalar.as_ref(), num_bits, |r0, r1, bit| {
+            Self::conditional_swap(r0, r1, Choice::from(bit))
+        })
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: MontCurveConfig> ark_ff::subtle::ConditionallySelectable for MontgomeryXOnly<P>
+where
+    P::BaseField: ark_ff::subtle::ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: ark_ff::subtle::Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: MontCurveConfig> PartialEq for MontgomeryXOnly<P> {
+    fn eq(&self, other: &Self) -> bool {
+        // The points (X : Z) and (X' : Z') are equal when X * Z' = X' * Z.
+        self.x * other.z == other.x * self.z
+    }
+}
+
+impl<P: MontCurveConfig> Eq for MontgomeryXOnly<P> {}
+
+impl<P: MontCurveConfig> Display for MontgomeryXOnly<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.to_x() {
+            Some(x) => write!(f, "MontgomeryXOnly(x={})", x),
+            None => write!(f, "MontgomeryXOnly(infinity)"),
+        }
+    }
+}
+
+impl<P: MontCurveConfig> Debug for MontgomeryXOnly<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "MontgomeryXOnly(x={}, z={})", self.x, self.z)
+    }
+}
+
+impl<P: MontCurveConfig> From<MontgomeryAffine<P>> for MontgomeryXOnly<P> {
+    fn from(p: MontgomeryAffine<P>) -> Self {
+        Self::from_x(p.x)
+    }
+}
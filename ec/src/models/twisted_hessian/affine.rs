@@ -0,0 +1,315 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    ValidationError,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Write},
+    ops::{Add, Mul, Neg, Sub},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    vec::*,
+};
+use educe::Educe;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+use ark_ff::{fields::Field, PrimeField, ToConstraintField, UniformRand};
+
+use super::{Projective, THCurveConfig};
+use crate::{models::validate_point, AffineRepr, CurveGroup};
+
+/// Affine coordinates for a point on a twisted Hessian curve, over the
+/// base field `P::BaseField`.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Affine<P: THCurveConfig> {
+    /// X coordinate of the point represented as a field element
+    pub x: P::BaseField,
+    /// Y coordinate of the point represented as a field element
+    pub y: P::BaseField,
+}
+
+impl<P: THCurveConfig> Display for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.is_zero() {
+            true => write!(f, "identity"),
+            false => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+impl<P: THCurveConfig> Debug for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.is_zero() {
+            true => write!(f, "identity"),
+            false => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+impl<P: THCurveConfig> PartialEq<Projective<P>> for Affine<P> {
+    fn eq(&self, other: &Projective<P>) -> bool {
+        self.into_group() == *other
+    }
+}
+
+impl<P: THCurveConfig> Affine<P> {
+    /// Construct a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField) -> Self {
+        Self { x, y }
+    }
+
+    /// Construct a new group element in a way while enforcing that points are in
+    /// the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        let p = Self::new_unchecked(x, y);
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        p
+    }
+
+    /// Like [`Self::new`], but returns why the point is invalid instead of
+    /// panicking.
+    pub fn try_new(x: P::BaseField, y: P::BaseField) -> Result<Self, ValidationError> {
+        let point = Self::new_unchecked(x, y);
+        validate_point(point.is_on_curve(), || {
+            point.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(point)
+    }
+
+    /// Construct the identity of the group
+    pub const fn zero() -> Self {
+        P::IDENTITY
+    }
+
+    /// Is this point the identity?
+    pub fn is_zero(&self) -> bool {
+        self.x.is_zero() && (self.y + P::BaseField::one()).is_zero()
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    pub fn is_on_curve(&self) -> bool {
+        let x3 = self.x.square() * self.x;
+        let y3 = self.y.square() * self.y;
+
+        let lhs = P::mul_by_a(x3) + y3 + P::BaseField::one();
+        let rhs = P::COEFF_D * self.x * self.y;
+
+        lhs == rhs
+    }
+}
+
+impl<P: THCurveConfig> Affine<P> {
+    /// Checks if `self` is in the subgroup having order equaling that of
+    /// `P::ScalarField` given it is on the curve.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        P::is_in_correct_subgroup_assuming_on_curve(self)
+    }
+}
+
+impl<P: THCurveConfig> AffineRepr for Affine<P> {
+    type Config = P;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Group = Projective<P>;
+
+    fn xy(&self) -> Option<(Self::BaseField, Self::BaseField)> {
+        (!self.is_zero()).then(|| (self.x, self.y))
+    }
+
+    fn generator() -> Self {
+        P::GENERATOR
+    }
+
+    fn zero() -> Self {
+        P::IDENTITY
+    }
+
+    /// Returns the multiple of the generator by the scalar encoded in
+    /// `bytes`, if any. Unlike for other models, the bytes are not
+    /// interpreted as a coordinate, since recovering the other coordinate
+    /// requires solving a cubic equation.
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        P::ScalarField::from_random_bytes(bytes).map(|s| (P::GENERATOR * s).into_affine())
+    }
+
+    fn mul_bigint(&self, by: impl AsRef<[u64]>) -> Self::Group {
+        P::mul_affine(self, by.as_ref())
+    }
+
+    /// Multiplies this element by the cofactor and output the
+    /// resulting projective element.
+    #[must_use]
+    fn mul_by_cofactor_to_group(&self) -> Self::Group {
+        P::mul_affine(self, Self::Config::COFACTOR)
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// Some curves can implement a more efficient algorithm.
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
+    }
+}
+
+impl<P: THCurveConfig> Zeroize for Affine<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+impl<P: THCurveConfig> Neg for Affine<P> {
+    type Output = Self;
+
+    /// The negation of `(x, y)` is `(x / y, 1 / y)`, which requires an
+    /// inversion. Note that `y` is never zero when `a` is not a cube.
+    fn neg(self) -> Self {
+        let y_inv = self.y.inverse().unwrap();
+        Self::new_unchecked(self.x * y_inv, y_inv)
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: T) -> Self::Output {
+        let mut copy = self.into_group();
+        copy += other.borrow();
+        copy
+    }
+}
+
+impl<P: THCurveConfig> Add<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: Projective<P>) -> Projective<P> {
+        other + self
+    }
+}
+
+impl<'a, P: THCurveConfig> Add<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: &'a Projective<P>) -> Projective<P> {
+        *other + self
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Self>> Sub<T> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: T) -> Self::Output {
+        let mut copy = self.into_group();
+        copy -= other.borrow();
+        copy
+    }
+}
+
+impl<P: THCurveConfig> Sub<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: Projective<P>) -> Projective<P> {
+        self + (-other)
+    }
+}
+
+impl<'a, P: THCurveConfig> Sub<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: &'a Projective<P>) -> Projective<P> {
+        self + (-*other)
+    }
+}
+
+impl<P: THCurveConfig> Default for Affine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: THCurveConfig> Distribution<Affine<P>> for Standard {
+    /// Generates a uniformly random element of the prime-order subgroup.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine<P> {
+        (P::GENERATOR * P::ScalarField::rand(rng)).into_affine()
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Affine<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn mul(self, other: T) -> Self::Output {
+        self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+// The projective point X, Y, Z is represented in the affine
+// coordinates as X/Z, Y/Z.
+impl<P: THCurveConfig> From<Projective<P>> for Affine<P> {
+    fn from(p: Projective<P>) -> Affine<P> {
+        if p.z.is_one() {
+            // If Z is one, the point is already normalized.
+            Affine::new_unchecked(p.x, p.y)
+        } else {
+            // Z is nonzero, since all points are affine.
+            let z_inv = p.z.inverse().unwrap();
+            let x = p.x * &z_inv;
+            let y = p.y * &z_inv;
+            Affine::new_unchecked(x, y)
+        }
+    }
+}
+
+impl<P: THCurveConfig> CanonicalSerialize for Affine<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), SerializationError> {
+        P::serialize_with_mode(self, writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        P::serialized_size(compress)
+    }
+}
+
+impl<P: THCurveConfig> Valid for Affine<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        validate_point(self.is_on_curve(), || {
+            self.is_in_correct_subgroup_assuming_on_curve()
+        })?;
+        Ok(())
+    }
+}
+
+impl<P: THCurveConfig> CanonicalDeserialize for Affine<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        P::deserialize_with_mode(reader, compress, validate)
+    }
+}
+
+impl<M: THCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        let mut x_fe = self.x.to_field_elements()?;
+        let y_fe = self.y.to_field_elements()?;
+        x_fe.extend_from_slice(&y_fe);
+        Some(x_fe)
+    }
+}
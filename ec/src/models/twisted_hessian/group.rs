@@ -0,0 +1,424 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    io::{Read, Write},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    vec::*,
+    One, Zero,
+};
+
+use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
+
+use educe::Educe;
+use zeroize::Zeroize;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{Affine, THCurveConfig};
+use crate::{
+    scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
+    AffineRepr, CurveGroup, PrimeGroup,
+};
+
+/// `Projective` implements projective coordinates `(X : Y : Z)` on twisted
+/// Hessian curves, which represent the affine point `(X / Z, Y / Z)`, and
+/// satisfy `a * X³ + Y³ + Z³ = d * X * Y * Z`.
+///
+/// This implementation uses the rotated addition formulae from
+/// [\[BCKL15\]](https://eprint.iacr.org/2015/781) (Section 3), which are
+/// complete when `a` is not a cube.
+#[derive(Educe)]
+#[educe(Copy, Clone, Eq(bound(P: THCurveConfig)), Debug)]
+#[must_use]
+pub struct Projective<P: THCurveConfig> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub z: P::BaseField,
+}
+
+impl<P: THCurveConfig> PartialEq<Affine<P>> for Projective<P> {
+    fn eq(&self, other: &Affine<P>) -> bool {
+        *self == other.into_group()
+    }
+}
+
+impl<P: THCurveConfig> Display for Projective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", Affine::from(*self))
+    }
+}
+
+impl<P: THCurveConfig> PartialEq for Projective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        // Z is never zero, so no special cases are needed.
+        // x1/z1 == x2/z2  <==> x1 * z2 == x2 * z1
+        (self.x * &other.z) == (other.x * &self.z) && (self.y * &other.z) == (other.y * &self.z)
+    }
+}
+
+impl<P: THCurveConfig> Hash for Projective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state)
+    }
+}
+
+impl<P: THCurveConfig> Distribution<Projective<P>> for Standard {
+    /// Generates a uniformly random element of the prime-order subgroup.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Projective<P> {
+        P::GENERATOR * P::ScalarField::rand(rng)
+    }
+}
+
+impl<P: THCurveConfig> Default for Projective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: THCurveConfig> Projective<P> {
+    /// Construct a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Construct a new group element in a way while enforcing that points are in
+    /// the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        let p = Self::new_unchecked(x, y, z).into_affine();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        p.into()
+    }
+}
+
+impl<P: THCurveConfig> Zeroize for Projective<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: THCurveConfig> Zero for Projective<P> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && (self.y + &self.z).is_zero() && !self.z.is_zero()
+    }
+}
+
+impl<P: THCurveConfig> AdditiveGroup for Projective<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self = Self::new_unchecked(P::BaseField::ZERO, P::IDENTITY.y, P::BaseField::ONE);
+
+    fn double_in_place(&mut self) -> &mut Self {
+        // See "Twisted Hessian curves" (https://eprint.iacr.org/2015/781)
+        // by Daniel J. Bernstein, Chitchanok Chuengsatiansup, David Kohel, and Tanja Lange
+        // Section 3, doubling
+
+        // A = X1^3
+        let a = self.x.square() * &self.x;
+        // B = Y1^3
+        let b = self.y.square() * &self.y;
+        // C = Z1^3
+        let c = self.z.square() * &self.z;
+        // D = a * A
+        let d = P::mul_by_a(a);
+        // X3 = X1 * (C - B)
+        let x = self.x * &(c - &b);
+        // Y3 = Z1 * (B - D)
+        let y = self.z * &(b - &d);
+        // Z3 = Y1 * (D - C)
+        let z = self.y * &(d - &c);
+
+        self.x = x;
+        self.y = y;
+        self.z = z;
+
+        self
+    }
+}
+
+impl<P: THCurveConfig> PrimeGroup for Projective<P> {
+    type ScalarField = P::ScalarField;
+
+    fn generator() -> Self {
+        Affine::generator().into()
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+        P::mul_projective(self, other.as_ref())
+    }
+}
+
+impl<P: THCurveConfig> CurveGroup for Projective<P> {
+    type Config = P;
+    type BaseField = P::BaseField;
+    type Affine = Affine<P>;
+    type FullGroup = Affine<P>;
+
+    fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
+        // A projective curve element (x, y, z) is normalized
+        // to its affine representation, by the conversion
+        // (x, y, z) -> (x/z, y/z, 1)
+        // Batch normalizing N twisted Hessian curve elements costs:
+        //     1 inversion + 5N field multiplications
+        // (batch inversion requires 3N multiplications + 1 inversion)
+        ark_ff::thread_pool::in_thread_pool(|| {
+            let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+            ark_ff::batch_inversion(&mut z_s);
+
+            // Perform affine transformations
+            ark_std::cfg_iter!(v)
+                .zip(z_s)
+                .map(|(g, z)| {
+                    let x = g.x * &z;
+                    let y = g.y * &z;
+                    Affine::new_unchecked(x, y)
+                })
+                .collect()
+        })
+    }
+}
+
+impl<P: THCurveConfig> Neg for Projective<P> {
+    type Output = Self;
+
+    /// The negation of `(X : Y : Z)` is `(X : Z : Y)`.
+    fn neg(mut self) -> Self {
+        ark_std::mem::swap(&mut self.y, &mut self.z);
+        self
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
+    fn add_assign(&mut self, other: T) {
+        let other = other.borrow();
+        // See "Twisted Hessian curves" (https://eprint.iacr.org/2015/781)
+        // by Daniel J. Bernstein, Chitchanok Chuengsatiansup, David Kohel, and Tanja Lange
+        // Section 3, rotated addition, with Z2 = 1
+
+        // A = X1
+        let a = self.x;
+        // B = Z1
+        let b = self.z;
+        // C = Y1*X2
+        let c = self.y * &other.x;
+        // D = Y1*Y2
+        let d = self.y * &other.y;
+        // E = Z1*Y2
+        let e = self.z * &other.y;
+        // F = a*X1*X2
+        let f = P::mul_by_a(self.x * &other.x);
+        // X3 = A*B-C*D
+        self.x = a * &b - &(c * &d);
+        // Y3 = D*E-F*A
+        self.y = d * &e - &(f * &a);
+        // Z3 = F*C-B*E
+        self.z = f * &c - &(b * &e);
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Affine<P>>> Add<T> for Projective<P> {
+    type Output = Self;
+    fn add(mut self, other: T) -> Self {
+        let other = other.borrow();
+        self += other;
+        self
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Affine<P>>> SubAssign<T> for Projective<P> {
+    fn sub_assign(&mut self, other: T) {
+        *self -= other.borrow().into_group();
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Affine<P>>> Sub<T> for Projective<P> {
+    type Output = Self;
+    fn sub(mut self, other: T) -> Self {
+        self -= other.borrow();
+        self
+    }
+}
+ark_ff::impl_additive_ops_from_ref!(Projective, THCurveConfig);
+
+impl<'a, P: THCurveConfig> Add<&'a Self> for Projective<P> {
+    type Output = Self;
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: THCurveConfig> Sub<&'a Self> for Projective<P> {
+    type Output = Self;
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: THCurveConfig> AddAssign<&'a Self> for Projective<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        // See "Twisted Hessian curves" (https://eprint.iacr.org/2015/781)
+        // by Daniel J. Bernstein, Chitchanok Chuengsatiansup, David Kohel, and Tanja Lange
+        // Section 3, rotated addition
+
+        // A = X1 * Z2
+        let a = self.x * &other.z;
+
+        // B = Z1 * Z2
+        let b = self.z * &other.z;
+
+        // C = Y1 * X2
+        let c = self.y * &other.x;
+
+        // D = Y1 * Y2
+        let d = self.y * &other.y;
+
+        // E = Z1 * Y2
+        let e = self.z * &other.y;
+
+        // F = a * X1 * X2
+        let f = P::mul_by_a(self.x * &other.x);
+
+        // X3 = A * B - C * D
+        self.x = a * &b - &(c * &d);
+
+        // Y3 = D * E - F * A
+        self.y = d * &e - &(f * &a);
+
+        // Z3 = F * C - B * E
+        self.z = f * &c - &(b * &e);
+    }
+}
+
+impl<'a, P: THCurveConfig> SubAssign<&'a Self> for Projective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += -(*other);
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<P::ScalarField>> MulAssign<T> for Projective<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: THCurveConfig, T: Borrow<Affine<P>>> ark_std::iter::Sum<T> for Projective<P> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, x| acc + x.borrow())
+    }
+}
+
+// The affine point (X, Y) is represented in projective coordinates with Z = 1.
+impl<P: THCurveConfig> From<Affine<P>> for Projective<P> {
+    fn from(p: Affine<P>) -> Projective<P> {
+        Self::new_unchecked(p.x, p.y, P::BaseField::one())
+    }
+}
+
+impl<P: THCurveConfig> CanonicalSerialize for Projective<P> {
+    #[allow(unused_qualifications)]
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let aff = Affine::<P>::from(*self);
+        P::serialize_with_mode(&aff, writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        P::serialized_size(compress)
+    }
+}
+
+impl<P: THCurveConfig> Valid for Projective<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.into_affine().check()
+    }
+
+    fn batch_check<'a>(
+        batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), SerializationError>
+    where
+        Self: 'a,
+    {
+        let batch = batch.copied().collect::<Vec<_>>();
+        let batch = Self::normalize_batch(&batch);
+        Affine::batch_check(batch.iter())
+    }
+}
+
+impl<P: THCurveConfig> CanonicalDeserialize for Projective<P> {
+    #[allow(unused_qualifications)]
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let aff = P::deserialize_with_mode(reader, compress, validate)?;
+        Ok(aff.into())
+    }
+}
+
+impl<M: THCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        Affine::from(*self).to_field_elements()
+    }
+}
+
+impl<P: THCurveConfig> ScalarMul for Projective<P> {
+    type MulBase = Affine<P>;
+    // Negating an affine point requires an inversion.
+    const NEGATION_IS_CHEAP: bool = false;
+
+    fn batch_convert_to_mul_base(bases: &[Self]) -> Vec<Self::MulBase> {
+        Self::normalize_batch(bases)
+    }
+}
+
+impl<P: THCurveConfig> VariableBaseMSM for Projective<P> {
+    fn msm(bases: &[Self::MulBase], bigints: &[Self::ScalarField]) -> Result<Self, usize> {
+        P::msm(bases, bigints)
+    }
+}
@@ -0,0 +1,133 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::io::{Read, Write};
+
+use crate::{scalar_mul::variable_base::VariableBaseMSM, AffineRepr};
+use num_traits::Zero;
+
+use ark_ff::{fields::Field, AdditiveGroup};
+
+mod affine;
+pub use affine::*;
+
+mod group;
+pub use group::*;
+
+/// Constants and convenience functions that collectively define the [Twisted Hessian model](https://eprint.iacr.org/2015/781)
+/// of the curve. In this model, the curve equation is
+/// `a * x³ + y³ + 1 = d * x * y`, for constants `a` and `d`, and the identity
+/// is the point `(0, -1)`.
+///
+/// The addition formulae of this model, from [\[BCKL15\]](https://eprint.iacr.org/2015/781),
+/// are complete if `a` is not a cube in the base field, i.e. they compute
+/// the correct result for all pairs of points, including doublings and
+/// additions of the identity. This also implies that all points of the
+/// curve are affine. The model assumes that `a` is not a cube.
+pub trait THCurveConfig: super::CurveConfig {
+    /// Coefficient `a` of the curve equation.
+    const COEFF_A: Self::BaseField;
+    /// Coefficient `d` of the curve equation.
+    const COEFF_D: Self::BaseField;
+    /// Generator of the prime-order subgroup.
+    const GENERATOR: Affine<Self>;
+    /// The identity `(0, -1)` of the curve. This cannot be computed from
+    /// the other constants in a `const` context, since the base field is
+    /// generic.
+    const IDENTITY: Affine<Self>;
+
+    /// Helper method for computing `elem * Self::COEFF_A`.
+    ///
+    /// The default implementation should be overridden only if
+    /// the product can be computed faster than standard field multiplication
+    /// (eg: via doubling if `COEFF_A == 2`, or if `COEFF_A.is_zero()`).
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        elem * Self::COEFF_A
+    }
+
+    /// Checks that the current point is in the prime order subgroup given
+    /// the point on the curve.
+    fn is_in_correct_subgroup_assuming_on_curve(item: &Affine<Self>) -> bool {
+        Self::mul_affine(item, Self::ScalarField::characteristic()).is_zero()
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// For some curve families though, it is sufficient to multiply
+    /// by a smaller scalar.
+    fn clear_cofactor(item: &Affine<Self>) -> Affine<Self> {
+        item.mul_by_cofactor()
+    }
+
+    /// Default implementation of group multiplication for projective
+    /// coordinates
+    fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
+        let mut res = Projective::<Self>::zero();
+        for b in ark_ff::BitIteratorBE::without_leading_zeros(scalar) {
+            res.double_in_place();
+            if b {
+                res += base;
+            }
+        }
+
+        res
+    }
+
+    /// Default implementation of group multiplication for affine
+    /// coordinates
+    fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
+        let mut res = Projective::<Self>::zero();
+        for b in ark_ff::BitIteratorBE::without_leading_zeros(scalar) {
+            res.double_in_place();
+            if b {
+                res += base
+            }
+        }
+
+        res
+    }
+
+    /// Default implementation for multi scalar multiplication
+    fn msm(
+        bases: &[Affine<Self>],
+        scalars: &[Self::ScalarField],
+    ) -> Result<Projective<Self>, usize> {
+        (bases.len() == scalars.len())
+            .then(|| VariableBaseMSM::msm_unchecked(bases, scalars))
+            .ok_or(bases.len().min(scalars.len()))
+    }
+
+    /// Serializes both the x and y coordinates, regardless of `compress`,
+    /// since recovering y from x requires solving a cubic equation.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        item: &Affine<Self>,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        item.x.serialize_with_mode(&mut writer, compress)?;
+        item.y.serialize_with_mode(&mut writer, compress)
+    }
+
+    /// If `validate` is `Yes`, calls `check()` to make sure the element is valid.
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Affine<Self>, SerializationError> {
+        let x = Self::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let y = Self::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let point = Affine::<Self>::new_unchecked(x, y);
+        if let Validate::Yes = validate {
+            point.check()?;
+        }
+        Ok(point)
+    }
+
+    #[inline]
+    fn serialized_size(compress: Compress) -> usize {
+        let zero = Self::BaseField::zero();
+        zero.serialized_size(compress) + zero.serialized_size(compress)
+    }
+}
@@ -1,11 +1,15 @@
-use ark_ff::{AdditiveGroup, CyclotomicMultSubgroup, Field, One, PrimeField};
+use ark_ff::{
+    field_hashers::HashToField, AdditiveGroup, CyclotomicMultSubgroup, Field, One, PrimeField,
+};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    ValidationError,
 };
 use ark_std::{
     borrow::Borrow,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     io::{Read, Write},
+    iter::Product,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     rand::{
         distributions::{Distribution, Standard},
@@ -88,6 +92,20 @@ pub trait Pairing: Sized + 'static + Copy + Debug + Sync + Send + Eq {
         b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
     ) -> MillerLoopOutput<Self>;
 
+    /// Computes the Miller loop over pairs of prepared elements, taken from a
+    /// single iterator.
+    ///
+    /// The default implementation collects the pairs and calls
+    /// [`Self::multi_miller_loop`]. Implementations may override it to
+    /// consume the pairs a few at a time, sharing the squarings of the loop
+    /// across the pairs of each chunk, without collecting them.
+    fn multi_miller_loop_prepared(
+        pairs: impl IntoIterator<Item = (Self::G1Prepared, Self::G2Prepared)>,
+    ) -> MillerLoopOutput<Self> {
+        let (a, b): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        Self::multi_miller_loop(a, b)
+    }
+
     /// Computes the Miller loop over `a` and `b`.
     fn miller_loop(
         a: impl Into<Self::G1Prepared>,
@@ -100,6 +118,17 @@ pub trait Pairing: Sized + 'static + Copy + Debug + Sync + Send + Eq {
     #[must_use]
     fn final_exponentiation(mlo: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>>;
 
+    /// Computes `f^exp` for an element `f` of the target group.
+    ///
+    /// The default implementation uses cyclotomic exponentiation, with the
+    /// Granger-Scott cyclotomic squarings.
+    /// Implementations may override it to reduce `exp` modulo the group order
+    /// and decompose it using endomorphisms of the target group, in which case
+    /// the result is only meaningful for `f` in the target group.
+    fn target_mul_bigint(f: &PairingOutput<Self>, exp: impl AsRef<[u64]>) -> PairingOutput<Self> {
+        PairingOutput(f.0.cyclotomic_exp(exp))
+    }
+
     /// Computes a "product" of pairings.
     fn multi_pairing(
         a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
@@ -115,6 +144,25 @@ pub trait Pairing: Sized + 'static + Copy + Debug + Sync + Send + Eq {
     ) -> PairingOutput<Self> {
         Self::multi_pairing([p], [q])
     }
+
+    /// Hashes `msg` to an element of the scalar field, with the domain
+    /// separation tag `dst`; see [`PrimeGroup::hash_to_scalar`].
+    fn hash_to_scalar<H: HashToField<Self::ScalarField>>(
+        msg: &[u8],
+        dst: &[u8],
+    ) -> Self::ScalarField {
+        Self::G1::hash_to_scalar::<H>(msg, dst)
+    }
+}
+
+/// Moves the next items of `iter` into `chunk`, until either is exhausted,
+/// and returns the number of items moved.
+pub(crate) fn fill_chunk<T>(chunk: &mut [T], iter: &mut impl Iterator<Item = T>) -> usize {
+    chunk
+        .iter_mut()
+        .zip(iter)
+        .map(|(slot, item)| *slot = item)
+        .count()
 }
 
 /// Represents the target group of a pairing. This struct is a
@@ -148,12 +196,28 @@ impl<P: Pairing> CanonicalSerialize for PairingOutput<P> {
     }
 }
 
+impl<P: Pairing> PairingOutput<P> {
+    /// Checks whether `self` lies in the target group, i.e., the subgroup of
+    /// order `P::ScalarField::MODULUS` of the cyclotomic subgroup of
+    /// `P::TargetField`.
+    pub fn is_valid(&self) -> bool {
+        // Elements of the cyclotomic subgroup are inverted by
+        // `cyclotomic_inverse`, which cheaply rules out most other elements
+        // before the exponentiation.
+        let is_cyclotomic = self
+            .0
+            .cyclotomic_inverse()
+            .is_some_and(|inverse| (self.0 * inverse).is_one());
+        is_cyclotomic && self.0.pow(P::ScalarField::characteristic()).is_one()
+    }
+}
+
 impl<P: Pairing> Valid for PairingOutput<P> {
     fn check(&self) -> Result<(), SerializationError> {
-        if self.0.pow(P::ScalarField::characteristic()).is_one() {
+        if self.is_valid() {
             Ok(())
         } else {
-            Err(SerializationError::InvalidData)
+            Err(ValidationError::NotInSubgroup.into())
         }
     }
 }
@@ -287,7 +351,7 @@ impl<P: Pairing> PrimeGroup for PairingOutput<P> {
     }
 
     fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
-        Self(self.0.cyclotomic_exp(other.as_ref()))
+        P::target_mul_bigint(self, other)
     }
 
     fn mul_bits_be(&self, other: impl Iterator<Item = bool>) -> Self {
@@ -302,7 +366,7 @@ impl<P: Pairing> PrimeGroup for PairingOutput<P> {
                     .fold(0, |r, (i, bit)| r | u64::from(*bit) << i)
             })
             .collect::<Vec<_>>();
-        Self(self.0.cyclotomic_exp(&other))
+        self.mul_bigint(&other)
     }
 }
 
@@ -318,11 +382,35 @@ impl<P: Pairing> crate::ScalarMul for PairingOutput<P> {
 impl<P: Pairing> VariableBaseMSM for PairingOutput<P> {}
 
 /// Represents the output of the Miller loop of the pairing.
+///
+/// Miller loop outputs can be multiplied together, and raised to scalars,
+/// before a single final exponentiation: the final exponentiation of a
+/// product is the sum of the pairings of its factors, so protocols can build
+/// up products of pairings and finalize them once.
+///
+/// Miller loop outputs are not canonical: different outputs may finalize to
+/// the same pairing, and in particular an output other than one may finalize
+/// to the identity. They must therefore never be compared or checked
+/// directly; only the result of [`Self::final_exponentiation`] is meaningful.
 #[derive(Educe)]
 #[educe(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[must_use]
 pub struct MillerLoopOutput<P: Pairing>(pub P::TargetField);
 
+impl<P: Pairing> MillerLoopOutput<P> {
+    /// Performs the final exponentiation, as in [`Pairing::final_exponentiation`].
+    pub fn final_exponentiation(self) -> Option<PairingOutput<P>> {
+        P::final_exponentiation(self)
+    }
+}
+
+impl<P: Pairing> Default for MillerLoopOutput<P> {
+    /// Returns the empty product, whose final exponentiation is the identity.
+    fn default() -> Self {
+        Self(P::TargetField::one())
+    }
+}
+
 impl<P: Pairing> Mul<P::ScalarField> for MillerLoopOutput<P> {
     type Output = Self;
 
@@ -331,6 +419,44 @@ impl<P: Pairing> Mul<P::ScalarField> for MillerLoopOutput<P> {
     }
 }
 
+impl<'a, 'b, P: Pairing> Mul<&'b MillerLoopOutput<P>> for &'a MillerLoopOutput<P> {
+    type Output = MillerLoopOutput<P>;
+
+    fn mul(self, other: &'b MillerLoopOutput<P>) -> MillerLoopOutput<P> {
+        MillerLoopOutput(self.0 * other.0)
+    }
+}
+
+impl<'a, P: Pairing> MulAssign<&'a Self> for MillerLoopOutput<P> {
+    fn mul_assign(&mut self, other: &'a Self) {
+        self.0 *= &other.0;
+    }
+}
+
+impl<P: Pairing> MulAssign<Self> for MillerLoopOutput<P> {
+    fn mul_assign(&mut self, other: Self) {
+        self.0 *= other.0;
+    }
+}
+
+impl<P: Pairing> Product for MillerLoopOutput<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |mut acc, f| {
+            acc *= f;
+            acc
+        })
+    }
+}
+
+impl<'a, P: Pairing> Product<&'a Self> for MillerLoopOutput<P> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |mut acc, f| {
+            acc *= f;
+            acc
+        })
+    }
+}
+
 /// Preprocesses a G1 element for use in a pairing.
 pub fn prepare_g1<E: Pairing>(g: impl Into<E::G1Affine>) -> E::G1Prepared {
     let g: E::G1Affine = g.into();
@@ -342,3 +468,81 @@ pub fn prepare_g2<E: Pairing>(g: impl Into<E::G2Affine>) -> E::G2Prepared {
     let g: E::G2Affine = g.into();
     E::G2Prepared::from(g)
 }
+
+/// Accumulates pairing equations and checks them all at once.
+///
+/// Each equation `∏ e(A_i, B_i) = 1` is scaled by an independent random
+/// 128-bit scalar, and the scaled equations are multiplied together, so that
+/// [`Self::finalize`] needs only a single multi-Miller loop and a single final
+/// exponentiation. If any accumulated equation does not hold, the combined
+/// check passes with probability at most `2^-128`.
+#[derive(Educe)]
+#[educe(Clone, Debug, Default)]
+pub struct PairingChecker<E: Pairing> {
+    /// The pairs of all the equations, in order.
+    pairs: Vec<(E::G1Affine, E::G2Affine)>,
+    /// The number of pairs in each equation.
+    lengths: Vec<usize>,
+}
+
+impl<E: Pairing> PairingChecker<E> {
+    /// Creates a checker with no equations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of equations accumulated so far.
+    pub fn len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Returns `true` if no equations have been accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.lengths.is_empty()
+    }
+
+    /// Adds the equation `e(a, b) = e(c, d)`.
+    pub fn add_equation(
+        &mut self,
+        a: impl Into<E::G1Affine>,
+        b: impl Into<E::G2Affine>,
+        c: impl Into<E::G1Affine>,
+        d: impl Into<E::G2Affine>,
+    ) {
+        let c: E::G1 = c.into().into_group();
+        self.add_product([(a.into(), b.into()), ((-c).into(), d.into())]);
+    }
+
+    /// Adds the equation `∏ e(a_i, b_i) = 1`, where `(a_i, b_i)` ranges over
+    /// `pairs`.
+    pub fn add_product(
+        &mut self,
+        pairs: impl IntoIterator<Item = (impl Into<E::G1Affine>, impl Into<E::G2Affine>)>,
+    ) {
+        let start = self.pairs.len();
+        self.pairs
+            .extend(pairs.into_iter().map(|(a, b)| (a.into(), b.into())));
+        self.lengths.push(self.pairs.len() - start);
+    }
+
+    /// Returns `true` if all accumulated equations hold, except with
+    /// probability at most `2^-128`. Returns `true` if there are no equations.
+    pub fn finalize<R: Rng + ?Sized>(self, rng: &mut R) -> bool {
+        let Self { pairs, lengths } = self;
+        let mut pairs = pairs.into_iter();
+        let mut g1 = Vec::with_capacity(pairs.len());
+        let mut g2 = Vec::with_capacity(pairs.len());
+        for (i, len) in lengths.into_iter().enumerate() {
+            // The first equation need not be randomized: scaling all of them
+            // by its (invertible) scalar does not change the result.
+            let r: u128 = if i == 0 { 1 } else { rng.gen() };
+            let r = [r as u64, (r >> 64) as u64];
+            for (a, b) in pairs.by_ref().take(len) {
+                g1.push(a.mul_bigint(r));
+                g2.push(b);
+            }
+        }
+        let g1 = E::G1::normalize_batch(&g1);
+        E::multi_pairing(g1, g2).is_zero()
+    }
+}
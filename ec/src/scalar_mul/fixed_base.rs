@@ -0,0 +1,244 @@
+//! Multi-scalar multiplication with precomputed tables for a fixed set of
+//! bases.
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    cfg_chunks, cfg_chunks_mut, cfg_iter,
+    io::{Read, Write},
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::variable_base::{make_digits, VariableBaseMSM};
+
+/// The largest window size accepted by [`FixedBaseMSM`].
+pub const MAX_WINDOW_SIZE: usize = 24;
+
+/// Precomputed tables for multi-scalar multiplications with a fixed set of
+/// bases, such as the powers of tau of a structured reference string.
+///
+/// For every base `B`, the table holds `2^(c * j) * B` for each window `j`
+/// of `c` bits of the scalars. An MSM then recodes every scalar into signed
+/// digits, and accumulates each table entry in the bucket of its digit, as
+/// in [`VariableBaseMSM::msm`]. Since the windows are already shifted by the
+/// table, all windows share a single set of `2^(c - 1)` buckets, and the
+/// doublings between windows are not needed.
+///
+/// The tables can be serialized, so that they only need to be computed once
+/// for a given set of bases.
+///
+/// # Example
+/// ```
+/// use ark_ec::{scalar_mul::fixed_base::FixedBaseMSM, CurveGroup, VariableBaseMSM};
+/// use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+///
+/// let mut rng = ark_std::test_rng();
+/// let bases = G::normalize_batch(&(0..10).map(|_| G::rand(&mut rng)).collect::<Vec<_>>());
+/// let scalars = (0..10).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+/// let table = FixedBaseMSM::<G>::new(&bases);
+/// assert_eq!(table.msm_fixed(&scalars), G::msm(&bases, &scalars).unwrap());
+/// ```
+pub struct FixedBaseMSM<G: VariableBaseMSM> {
+    window: usize,
+    num_windows: usize,
+    table: Vec<G::MulBase>,
+}
+
+impl<G: VariableBaseMSM> FixedBaseMSM<G> {
+    /// Precomputes the tables for `bases`, with a window size suited to MSMs
+    /// with `bases.len()` scalars.
+    pub fn new(bases: &[G::MulBase]) -> Self {
+        Self::with_window_size(bases, Self::compute_window_size(bases.len()))
+    }
+
+    /// Precomputes the tables for `bases`, with windows of `window` bits.
+    ///
+    /// Larger windows make the tables smaller and the MSMs faster, as long as
+    /// the `2^(window - 1)` buckets are few compared to the table entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is smaller than 2 or larger than
+    /// [`MAX_WINDOW_SIZE`].
+    pub fn with_window_size(bases: &[G::MulBase], window: usize) -> Self {
+        assert!((2..=MAX_WINDOW_SIZE).contains(&window));
+        let num_windows = Self::num_windows(window);
+        let mut table = vec![G::zero(); bases.len() * num_windows];
+        cfg_chunks_mut!(table, num_windows)
+            .zip(cfg_iter!(bases))
+            .for_each(|(row, base)| {
+                let mut cur = G::from(*base);
+                for entry in row {
+                    *entry = cur;
+                    for _ in 0..window {
+                        cur.double_in_place();
+                    }
+                }
+            });
+        Self {
+            window,
+            num_windows,
+            table: G::batch_convert_to_mul_base(&table),
+        }
+    }
+
+    /// Returns the window size that [`Self::new`] uses for `num_bases` bases.
+    pub fn compute_window_size(num_bases: usize) -> usize {
+        if num_bases < 32 {
+            3
+        } else {
+            (super::ln_without_floats(num_bases) + 2).min(MAX_WINDOW_SIZE)
+        }
+    }
+
+    /// The number of bases of the tables.
+    pub fn num_bases(&self) -> usize {
+        self.table.len() / self.num_windows
+    }
+
+    /// The window size of the tables.
+    pub fn window_size(&self) -> usize {
+        self.window
+    }
+
+    /// Computes the inner product between `scalars` and the bases of the
+    /// tables.
+    ///
+    /// If there are fewer scalars than bases, only the first
+    /// `scalars.len()` bases are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more scalars than bases.
+    pub fn msm_fixed(&self, scalars: &[G::ScalarField]) -> G {
+        let bigints = G::ScalarField::into_bigint_batch(scalars);
+        self.msm_fixed_bigint(&bigints)
+    }
+
+    /// Computes the inner product between `bigints` and the bases of the
+    /// tables; see [`Self::msm_fixed`].
+    pub fn msm_fixed_bigint(&self, bigints: &[<G::ScalarField as PrimeField>::BigInt]) -> G {
+        assert!(bigints.len() <= self.num_bases());
+        let size = bigints.len();
+        if size == 0 {
+            return G::zero();
+        }
+        let window = self.window;
+        let num_windows = self.num_windows;
+        // Every thread accumulates a contiguous chunk of the scalars in its
+        // own buckets.
+        #[cfg(feature = "parallel")]
+        let chunk_size = size.div_ceil(rayon::current_num_threads());
+        #[cfg(not(feature = "parallel"))]
+        let chunk_size = size;
+        let table = &self.table[..size * num_windows];
+        cfg_chunks!(bigints, chunk_size)
+            .zip(cfg_chunks!(table, chunk_size * num_windows))
+            .map(|(scalars, rows)| {
+                // The bucket `k` holds the entries whose digit is `±(k + 1)`.
+                let mut buckets = vec![G::zero(); 1 << (window - 1)];
+                for (scalar, row) in scalars.iter().zip(rows.chunks(num_windows)) {
+                    if scalar.is_zero() {
+                        continue;
+                    }
+                    let digits = make_digits(scalar, window, num_windows * window);
+                    for (digit, entry) in digits.zip(row) {
+                        use ark_std::cmp::Ordering;
+                        match 0.cmp(&digit) {
+                            Ordering::Less => buckets[(digit - 1) as usize] += entry,
+                            Ordering::Greater => buckets[(-digit - 1) as usize] -= entry,
+                            Ordering::Equal => (),
+                        }
+                    }
+                }
+
+                let mut running_sum = G::zero();
+                let mut res = G::zero();
+                buckets.into_iter().rev().for_each(|b| {
+                    running_sum += &b;
+                    res += &running_sum;
+                });
+                res
+            })
+            .sum()
+    }
+
+    /// The number of windows of `window` bits in a scalar. Recoding into
+    /// signed digits may carry into the bit above the most significant one,
+    /// so this covers `MODULUS_BIT_SIZE + 1` bits.
+    fn num_windows(window: usize) -> usize {
+        (G::ScalarField::MODULUS_BIT_SIZE as usize + window) / window
+    }
+}
+
+impl<G: VariableBaseMSM> Clone for FixedBaseMSM<G> {
+    fn clone(&self) -> Self {
+        Self {
+            window: self.window,
+            num_windows: self.num_windows,
+            table: self.table.clone(),
+        }
+    }
+}
+
+impl<G: VariableBaseMSM> CanonicalSerialize for FixedBaseMSM<G>
+where
+    G::MulBase: CanonicalSerialize,
+{
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        (self.window as u64).serialize_with_mode(&mut writer, compress)?;
+        self.table.serialize_with_mode(&mut writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        (self.window as u64).serialized_size(compress) + self.table.serialized_size(compress)
+    }
+}
+
+impl<G: VariableBaseMSM> Valid for FixedBaseMSM<G>
+where
+    G::MulBase: Valid,
+{
+    fn check(&self) -> Result<(), SerializationError> {
+        self.table.check()
+    }
+}
+
+impl<G: VariableBaseMSM> CanonicalDeserialize for FixedBaseMSM<G>
+where
+    G::MulBase: CanonicalDeserialize,
+{
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let window = u64::deserialize_with_mode(&mut reader, compress, validate)? as usize;
+        // The shape of the tables is checked regardless of `validate`, since
+        // MSMs rely on it.
+        if !(2..=MAX_WINDOW_SIZE).contains(&window) {
+            return Err(SerializationError::InvalidData);
+        }
+        let num_windows = Self::num_windows(window);
+        let table = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        if table.len() % num_windows != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self {
+            window,
+            num_windows,
+            table,
+        })
+    }
+}
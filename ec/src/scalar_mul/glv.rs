@@ -1,13 +1,20 @@
 use crate::{
     short_weierstrass::{Affine, Projective, SWCurveConfig},
-    AdditiveGroup, CurveGroup,
+    AdditiveGroup, CurveGroup, VariableBaseMSM,
 };
 use ark_ff::{PrimeField, Zero};
-use ark_std::ops::{AddAssign, Neg};
+use ark_std::{
+    cfg_chunks_mut, cfg_iter,
+    ops::{AddAssign, Neg},
+    vec::*,
+};
 use num_bigint::{BigInt, BigUint, Sign};
 use num_integer::Integer;
 use num_traits::{One, Signed};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// The GLV parameters for computing the endomorphism and scalar decomposition.
 pub trait GLVConfig: Send + Sync + 'static + SWCurveConfig {
     /// Constants that are used to calculate `phi(G) := lambda*G`.
@@ -123,6 +130,33 @@ pub trait GLVConfig: Send + Sync + 'static + SWCurveConfig {
         res
     }
 
+    /// Computes a multi-scalar multiplication, by decomposing every scalar
+    /// `k` into `k1 + lambda * k2`, so that every base `P` contributes
+    /// `k1 * P + k2 * phi(P)`. The resulting MSM has twice as many bases,
+    /// but the scalars are about half as long, so it needs half as many
+    /// windows.
+    ///
+    /// If the slices have different lengths, they are chopped to the
+    /// shortest length.
+    fn glv_msm(bases: &[Affine<Self>], scalars: &[Self::ScalarField]) -> Projective<Self> {
+        let size = bases.len().min(scalars.len());
+        let mut glv_bases = vec![Affine::<Self>::identity(); 2 * size];
+        let mut glv_scalars = vec![<Self::ScalarField as PrimeField>::BigInt::zero(); 2 * size];
+        cfg_chunks_mut!(glv_bases, 2)
+            .zip(cfg_chunks_mut!(glv_scalars, 2))
+            .zip(cfg_iter!(bases[..size]))
+            .zip(cfg_iter!(scalars[..size]))
+            .for_each(|(((b, s), base), scalar)| {
+                let ((sgn_k1, k1), (sgn_k2, k2)) = Self::scalar_decomposition(*scalar);
+                let endo_base = Self::endomorphism_affine(base);
+                b[0] = if sgn_k1 { *base } else { -*base };
+                b[1] = if sgn_k2 { endo_base } else { -endo_base };
+                s[0] = k1.into_bigint();
+                s[1] = k2.into_bigint();
+            });
+        Projective::<Self>::msm_bigint(&glv_bases, &glv_scalars)
+    }
+
     fn glv_mul_affine(p: Affine<Self>, k: Self::ScalarField) -> Affine<Self> {
         let ((sgn_k1, k1), (sgn_k2, k2)) = Self::scalar_decomposition(k);
 
@@ -159,3 +193,17 @@ pub trait GLVConfig: Send + Sync + 'static + SWCurveConfig {
         res.into_affine()
     }
 }
+
+/// Computes a multi-scalar multiplication with [`GLVConfig::glv_msm`], or
+/// returns the length of the shorter slice if `bases` and `scalars` have
+/// different lengths.
+///
+/// Curves opt in by calling this from [`SWCurveConfig::msm`].
+pub fn msm<P: GLVConfig>(
+    bases: &[Affine<P>],
+    scalars: &[P::ScalarField],
+) -> Result<Projective<P>, usize> {
+    (bases.len() == scalars.len())
+        .then(|| P::glv_msm(bases, scalars))
+        .ok_or(bases.len().min(scalars.len()))
+}
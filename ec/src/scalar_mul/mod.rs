@@ -1,3 +1,4 @@
+pub mod fixed_base;
 pub mod glv;
 pub mod wnaf;
 
@@ -60,6 +61,36 @@ pub fn sw_double_and_add_projective<P: SWCurveConfig>(
     res
 }
 
+/// The Montgomery ladder for multiplication by a secret scalar.
+///
+/// This computes `scalar * base`, where `scalar` is read as an integer with
+/// `num_bits` bits, performing one addition and one doubling per bit,
+/// regardless of its value. The points are swapped with conditional
+/// selection rather than branches, so the running time does not depend on
+/// the scalar, provided that `add` is branch-free and complete, i.e. it
+/// also handles doublings, and additions of `zero` and of inverses.
+#[cfg(feature = "ct")]
+pub(crate) fn montgomery_ladder<G: ark_ff::subtle::ConditionallySelectable>(
+    zero: G,
+    base: G,
+    scalar: &[u64],
+    num_bits: usize,
+    add: impl Fn(&G, &G) -> G,
+) -> G {
+    // The ladder maintains `r1 = r0 + base`.
+    let mut r0 = zero;
+    let mut r1 = base;
+    for i in (0..num_bits).rev() {
+        let bit = ((scalar[i / 64] >> (i % 64)) & 1) as u8;
+        let choice = ark_ff::subtle::Choice::from(bit);
+        G::conditional_swap(&mut r0, &mut r1, choice);
+        r1 = add(&r0, &r1);
+        r0 = add(&r0, &r0);
+        G::conditional_swap(&mut r0, &mut r1, choice);
+    }
+    r0
+}
+
 pub trait ScalarMul:
     PrimeGroup
     + Add<Self::MulBase, Output = Self>
@@ -157,8 +188,42 @@ pub trait ScalarMul:
     ) -> Vec<Self::MulBase> {
         table.batch_mul(v)
     }
+
+    /// Computes `a * p + b * q`, e.g. to verify ECDSA or Schnorr signatures.
+    ///
+    /// This interleaves the wNAFs of `a` and `b`, so that both
+    /// multiplications share their doublings; see
+    /// [`WnafContext::multi_mul`](wnaf::WnafContext::multi_mul).
+    ///
+    /// # Example
+    /// ```
+    /// use ark_ec::scalar_mul::ScalarMul;
+    /// use ark_std::UniformRand;
+    /// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+    ///
+    /// let mut rng = ark_std::test_rng();
+    /// let (p, q) = (G::rand(&mut rng), G::rand(&mut rng));
+    /// let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+    /// assert_eq!(G::multi_mul2(&a, p, &b, q), p * a + q * b);
+    /// ```
+    fn multi_mul2(a: &Self::ScalarField, p: Self, b: &Self::ScalarField, q: Self) -> Self {
+        Self::multi_mul(&[p, q], &[*a, *b])
+    }
+
+    /// Computes the inner product between `bases` and `scalars`, chopping
+    /// the slices to the shortest length, by interleaving the wNAFs of the
+    /// scalars; see [`WnafContext::multi_mul`](wnaf::WnafContext::multi_mul).
+    ///
+    /// This is meant for a handful of bases; for more, use
+    /// [`VariableBaseMSM::msm`](variable_base::VariableBaseMSM::msm).
+    fn multi_mul(bases: &[Self], scalars: &[Self::ScalarField]) -> Self {
+        wnaf::WnafContext::new(MULTI_MUL_WINDOW_SIZE).multi_mul(bases, scalars)
+    }
 }
 
+/// The window size of the wNAFs in [`ScalarMul::multi_mul`].
+const MULTI_MUL_WINDOW_SIZE: usize = 5;
+
 /// Preprocessing used internally for batch scalar multiplication via [`ScalarMul::batch_mul`].
 /// - `window` is the window size used for the precomputation
 /// - `max_scalar_size` is the maximum size of the scalars that will be multiplied
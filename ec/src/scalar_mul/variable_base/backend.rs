@@ -0,0 +1,174 @@
+//! Pluggable backends for multi-scalar multiplication.
+use ark_ff::PrimeField;
+
+use super::VariableBaseMSM;
+
+/// A backend that computes multi-scalar multiplications in a group `G`.
+///
+/// Crates that implement MSMs on accelerators, e.g. with CUDA or Metal, can
+/// implement this trait for the groups they support, and register the
+/// backend at runtime with [`register_msm_backend`]. From then on,
+/// [`VariableBaseMSM::msm`] and the other MSM methods of `G` use the
+/// backend, so that downstream code does not need to change.
+///
+/// The default implementation is the Pippenger algorithm of
+/// [`VariableBaseMSM`], so a backend can fall back to it for inputs that it
+/// does not accelerate, e.g. small ones, by calling [`CpuMsmBackend`].
+///
+/// # Example
+/// ```
+/// use ark_ec::scalar_mul::variable_base::*;
+/// use ark_ff::PrimeField;
+/// use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::{Fr, G1Affine, G1Projective as G};
+///
+/// struct Accelerator;
+///
+/// impl MsmBackend<G> for Accelerator {
+///     fn msm_bigint(&self, bases: &[G1Affine], bigints: &[<Fr as PrimeField>::BigInt]) -> G {
+///         // Offload large MSMs to the accelerator here.
+///         CpuMsmBackend.msm_bigint(bases, bigints)
+///     }
+/// }
+///
+/// let mut rng = ark_std::test_rng();
+/// let bases = [G1Affine::rand(&mut rng)];
+/// let scalars = [Fr::rand(&mut rng)];
+/// let bigints = [scalars[0].into_bigint()];
+/// assert_eq!(Accelerator.msm_bigint(&bases, &bigints), bases[0] * scalars[0]);
+/// ```
+pub trait MsmBackend<G: VariableBaseMSM>: Send + Sync {
+    /// Computes the inner product between `bigints` and `bases`, chopping
+    /// the slices to the shortest length; see [`VariableBaseMSM::msm_bigint`].
+    fn msm_bigint(
+        &self,
+        bases: &[G::MulBase],
+        bigints: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G {
+        super::msm_bigint_split(bases, bigints, None)
+    }
+}
+
+/// The default [`MsmBackend`], which runs on the CPU.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuMsmBackend;
+
+impl<G: VariableBaseMSM> MsmBackend<G> for CpuMsmBackend {}
+
+#[cfg(feature = "std")]
+pub use registry::*;
+
+#[cfg(feature = "std")]
+mod registry {
+    use super::{MsmBackend, VariableBaseMSM};
+    use std::{
+        any::{Any, TypeId},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, RwLock,
+        },
+    };
+
+    /// The registered backends, each of which is an `Arc<dyn MsmBackend<G>>`
+    /// for the group `G` with the given type id.
+    static BACKENDS: RwLock<Vec<(TypeId, Box<dyn Any + Send + Sync>)>> = RwLock::new(Vec::new());
+
+    /// Whether `BACKENDS` is non-empty, so that MSMs can skip the lock when
+    /// no backend is registered. This is only updated while holding the
+    /// write lock on `BACKENDS`.
+    static HAS_BACKENDS: AtomicBool = AtomicBool::new(false);
+
+    /// Registers `backend` for the MSMs in `G`, replacing the backend that
+    /// was previously registered for `G`, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_ec::scalar_mul::variable_base::*;
+    /// use ark_std::UniformRand;
+    /// use ark_test_curves::bls12_381::{Fr, G1Affine, G1Projective as G};
+    ///
+    /// register_msm_backend::<G>(CpuMsmBackend);
+    /// let mut rng = ark_std::test_rng();
+    /// let bases = [G1Affine::rand(&mut rng)];
+    /// let scalars = [Fr::rand(&mut rng)];
+    /// assert_eq!(G::msm(&bases, &scalars).unwrap(), bases[0] * scalars[0]);
+    /// assert!(unregister_msm_backend::<G>());
+    /// ```
+    pub fn register_msm_backend<G: VariableBaseMSM>(backend: impl MsmBackend<G> + 'static) {
+        let backend: Arc<dyn MsmBackend<G>> = Arc::new(backend);
+        let mut backends = BACKENDS.write().unwrap();
+        backends.retain(|(id, _)| *id != TypeId::of::<G>());
+        backends.push((TypeId::of::<G>(), Box::new(backend)));
+        HAS_BACKENDS.store(true, Ordering::Release);
+    }
+
+    /// Unregisters the backend for `G`, so that MSMs in `G` use
+    /// [`CpuMsmBackend`](super::CpuMsmBackend) again. Returns whether a
+    /// backend was registered.
+    pub fn unregister_msm_backend<G: VariableBaseMSM>() -> bool {
+        let mut backends = BACKENDS.write().unwrap();
+        let len = backends.len();
+        backends.retain(|(id, _)| *id != TypeId::of::<G>());
+        HAS_BACKENDS.store(!backends.is_empty(), Ordering::Release);
+        backends.len() != len
+    }
+
+    /// Returns the backend registered for `G`, if any.
+    pub fn msm_backend<G: VariableBaseMSM>() -> Option<Arc<dyn MsmBackend<G>>> {
+        if !HAS_BACKENDS.load(Ordering::Acquire) {
+            return None;
+        }
+        let backends = BACKENDS.read().unwrap();
+        backends
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<G>())
+            .and_then(|(_, backend)| backend.downcast_ref::<Arc<dyn MsmBackend<G>>>())
+            .cloned()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ark_std::{vec::*, UniformRand};
+    use ark_test_curves::{
+        ark_ec::scalar_mul::variable_base::*,
+        ark_ff::PrimeField,
+        bls12_381::{Fr, G1Affine, G1Projective as G},
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// A backend that counts its calls, and delegates to the CPU.
+    struct Counting(Arc<AtomicUsize>);
+
+    impl MsmBackend<G> for Counting {
+        fn msm_bigint(&self, bases: &[G1Affine], bigints: &[<Fr as PrimeField>::BigInt]) -> G {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            CpuMsmBackend.msm_bigint(bases, bigints)
+        }
+    }
+
+    #[test]
+    fn test_msm_backend() {
+        let mut rng = ark_std::test_rng();
+        let bases = (0..10)
+            .map(|_| G1Affine::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let scalars = (0..10).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let expected: G = CpuMsmBackend.msm_bigint(&bases, &Fr::into_bigint_batch(&scalars));
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        register_msm_backend::<G>(Counting(calls.clone()));
+        assert!(msm_backend::<G>().is_some());
+        assert_eq!(G::msm(&bases, &scalars).unwrap(), expected);
+        // Other tests may use the backend concurrently.
+        assert!(calls.load(Ordering::Relaxed) >= 1);
+
+        assert!(unregister_msm_backend::<G>());
+        assert!(!unregister_msm_backend::<G>());
+        assert!(msm_backend::<G>().is_none());
+        assert_eq!(G::msm(&bases, &scalars).unwrap(), expected);
+    }
+}
@@ -0,0 +1,197 @@
+//! Bucket accumulation in affine coordinates for short Weierstrass curves.
+//!
+//! Adding two affine points costs a single inversion and a few
+//! multiplications. By collecting many independent additions into a batch,
+//! the inversions can be shared with Montgomery's trick, so that each
+//! addition costs about 6 multiplications, instead of the 11 of a mixed
+//! addition in projective coordinates.
+use ark_ff::{batch_inversion, Field, PrimeField, Zero};
+use ark_std::vec::*;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{make_digits, msm_bigint_wnaf};
+use crate::{
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AdditiveGroup,
+};
+
+/// The smallest MSM for which the buckets are accumulated in affine
+/// coordinates. For smaller MSMs, there are too few buckets per window to
+/// batch enough additions.
+const THRESHOLD: usize = 1 << 10;
+
+/// The maximum number of additions that share an inversion.
+const BATCH_SIZE: usize = 1 << 9;
+
+/// Computes an MSM of scalars with at most `num_bits` bits with the bucket
+/// method, using signed digits in windows of `c` bits as in
+/// [`msm_bigint_wnaf`], and accumulating
+/// the buckets in affine coordinates when the MSM is large enough.
+pub(crate) fn msm_bigint_batch_affine<P: SWCurveConfig>(
+    bases: &[Affine<P>],
+    bigints: &[<P::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
+    c: usize,
+) -> Projective<P> {
+    let size = ark_std::cmp::min(bases.len(), bigints.len());
+    if size < THRESHOLD {
+        return msm_bigint_wnaf(bases, bigints, num_bits, c);
+    }
+    let scalars = &bigints[..size];
+    let bases = &bases[..size];
+
+    // Recoding into signed digits may carry into the bit above the most
+    // significant one, so we need digits for `num_bits + 1` bits.
+    let num_bits = num_bits + 1;
+    let digits_count = (num_bits + c - 1) / c;
+    #[cfg(feature = "parallel")]
+    let scalar_digits = scalars
+        .into_par_iter()
+        .flat_map_iter(|s| make_digits(s, c, num_bits))
+        .collect::<Vec<_>>();
+    #[cfg(not(feature = "parallel"))]
+    let scalar_digits = scalars
+        .iter()
+        .flat_map(|s| make_digits(s, c, num_bits))
+        .collect::<Vec<_>>();
+    // The buckets are emptied after each window, so that every thread
+    // allocates them once rather than once per window.
+    let num_buckets = 1 << (c - 1);
+    let window_sum = |buckets: &mut AffineBuckets<P>, i: usize| {
+        for (digits, base) in scalar_digits.chunks(digits_count).zip(bases) {
+            let digit = digits[i];
+            if digit > 0 {
+                buckets.add(digit as usize - 1, *base);
+            } else if digit < 0 {
+                buckets.add((-digit) as usize - 1, -*base);
+            }
+        }
+        buckets.sum()
+    };
+    #[cfg(feature = "parallel")]
+    let window_sums: Vec<_> = (0..digits_count)
+        .into_par_iter()
+        .map_init(|| AffineBuckets::<P>::new(num_buckets), window_sum)
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let window_sums: Vec<_> = {
+        let mut buckets = AffineBuckets::<P>::new(num_buckets);
+        (0..digits_count)
+            .map(|i| window_sum(&mut buckets, i))
+            .collect()
+    };
+
+    // We're traversing windows from high to low.
+    window_sums
+        .iter()
+        .rev()
+        .fold(Projective::<P>::zero(), |mut total, sum_i| {
+            for _ in 0..c {
+                total.double_in_place();
+            }
+            total + sum_i
+        })
+}
+
+/// Buckets of affine points, to which points are added in batches.
+struct AffineBuckets<P: SWCurveConfig> {
+    buckets: Vec<Affine<P>>,
+    /// Whether each bucket has a pending addition in `batch`.
+    in_batch: Vec<bool>,
+    /// The pending additions of a point to a bucket, at most one per bucket.
+    batch: Vec<(usize, Affine<P>)>,
+    /// The sums of the points that were added to a bucket while it already
+    /// had a pending addition. Accumulating them in projective coordinates,
+    /// rather than deferring them to a later batch, bounds the cost of
+    /// inputs whose digits are mostly equal.
+    overflows: Vec<Projective<P>>,
+    /// Scratch space for the denominators of the slopes.
+    denominators: Vec<P::BaseField>,
+}
+
+impl<P: SWCurveConfig> AffineBuckets<P> {
+    fn new(num_buckets: usize) -> Self {
+        Self {
+            buckets: vec![Affine::identity(); num_buckets],
+            in_batch: vec![false; num_buckets],
+            batch: Vec::with_capacity(BATCH_SIZE),
+            overflows: vec![Projective::zero(); num_buckets],
+            denominators: Vec::with_capacity(BATCH_SIZE),
+        }
+    }
+
+    /// Schedules the addition of `point` to the bucket `index`.
+    fn add(&mut self, index: usize, point: Affine<P>) {
+        if point.infinity {
+            return;
+        }
+        if self.in_batch[index] {
+            self.overflows[index] += &point;
+        } else if self.buckets[index].infinity {
+            self.buckets[index] = point;
+        } else {
+            self.in_batch[index] = true;
+            self.batch.push((index, point));
+            if self.batch.len() == BATCH_SIZE {
+                self.flush();
+            }
+        }
+    }
+
+    /// Performs the pending additions, sharing a single inversion.
+    fn flush(&mut self) {
+        // The slope of the line through `p` and `q` is `numerator /
+        // denominator`, where the numerator is computed below, after the
+        // batch inversion. If `p = -q`, the sum is zero, and we use a
+        // denominator of 1 as a placeholder.
+        self.denominators.clear();
+        for (index, q) in &self.batch {
+            let p = &self.buckets[*index];
+            let denominator = if p.x != q.x {
+                q.x - p.x
+            } else if p.y == q.y && !p.y.is_zero() {
+                p.y.double()
+            } else {
+                P::BaseField::ONE
+            };
+            self.denominators.push(denominator);
+        }
+        batch_inversion(&mut self.denominators);
+        for ((index, q), inverse) in self.batch.drain(..).zip(&self.denominators) {
+            self.in_batch[index] = false;
+            let p = &mut self.buckets[index];
+            let slope = if p.x != q.x {
+                (q.y - p.y) * inverse
+            } else if p.y == q.y && !p.y.is_zero() {
+                let x_squared = p.x.square();
+                (x_squared.double() + x_squared + P::COEFF_A) * inverse
+            } else {
+                *p = Affine::identity();
+                continue;
+            };
+            let x = slope.square() - p.x - q.x;
+            p.y = slope * (p.x - x) - p.y;
+            p.x = x;
+        }
+    }
+
+    /// Performs all the scheduled additions, returns the sum of every bucket
+    /// multiplied by its index plus one, and empties the buckets.
+    fn sum(&mut self) -> Projective<P> {
+        self.flush();
+        let mut running_sum = Projective::<P>::zero();
+        let mut res = Projective::<P>::zero();
+        for (b, o) in self.buckets.iter_mut().zip(&mut self.overflows).rev() {
+            running_sum += &*b;
+            if !o.is_zero() {
+                running_sum += &*o;
+            }
+            res += &running_sum;
+            *b = Affine::identity();
+            *o = Projective::zero();
+        }
+        res
+    }
+}
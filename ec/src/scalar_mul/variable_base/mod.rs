@@ -1,9 +1,14 @@
 use ark_ff::prelude::*;
-use ark_std::{borrow::Borrow, cfg_into_iter, iterable::Iterable, vec::*};
+use ark_std::{borrow::Borrow, iterable::Iterable, vec::*};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+pub mod backend;
+pub use backend::*;
+
+pub(crate) mod batch_affine;
+
 pub mod stream_pippenger;
 pub use stream_pippenger::*;
 
@@ -36,9 +41,7 @@ pub trait VariableBaseMSM: ScalarMul {
     ///
     /// Reference: [`VariableBaseMSM::msm`]
     fn msm_unchecked(bases: &[Self::MulBase], scalars: &[Self::ScalarField]) -> Self {
-        let bigints = cfg_into_iter!(scalars)
-            .map(|s| s.into_bigint())
-            .collect::<Vec<_>>();
+        let bigints = Self::ScalarField::into_bigint_batch(scalars);
         Self::msm_bigint(bases, &bigints)
     }
 
@@ -56,17 +59,104 @@ pub trait VariableBaseMSM: ScalarMul {
     }
 
     /// Optimized implementation of multi-scalar multiplication.
+    ///
+    /// This uses the [`MsmBackend`] registered for `Self`, if any, and
+    /// [`CpuMsmBackend`] otherwise, which runs in the thread pool selected by
+    /// [`ark_ff::thread_pool`].
     fn msm_bigint(
         bases: &[Self::MulBase],
         bigints: &[<Self::ScalarField as PrimeField>::BigInt],
+    ) -> Self {
+        #[cfg(feature = "std")]
+        if let Some(backend) = msm_backend::<Self>() {
+            return backend.msm_bigint(bases, bigints);
+        }
+        ark_ff::thread_pool::in_thread_pool(|| {
+            MsmBackend::<Self>::msm_bigint(&CpuMsmBackend, bases, bigints)
+        })
+    }
+
+    /// Computes an MSM of scalars with at most `num_bits` bits with the bucket
+    /// method, with windows of `window` bits. This is used by
+    /// [`CpuMsmBackend`], after setting aside the scalars that are zero or
+    /// one.
+    ///
+    /// Groups can override this to accumulate the buckets more efficiently,
+    /// e.g. short Weierstrass curves accumulate them in affine coordinates.
+    fn msm_bigint_with_bits(
+        bases: &[Self::MulBase],
+        bigints: &[<Self::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
+        window: usize,
     ) -> Self {
         if Self::NEGATION_IS_CHEAP {
-            msm_bigint_wnaf(bases, bigints)
+            msm_bigint_wnaf(bases, bigints, num_bits, window)
         } else {
-            msm_bigint(bases, bigints)
+            msm_bigint(bases, bigints, num_bits, window)
         }
     }
 
+    /// Performs multi-scalar multiplication with the Pippenger algorithm of
+    /// [`CpuMsmBackend`], with the parameters in `config` instead of the
+    /// automatically chosen ones.
+    ///
+    /// Like [`VariableBaseMSM::msm`], this returns an error containing the
+    /// shortest length if `bases` and `scalars` have different lengths.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window size is smaller than 2, or if the thread pool
+    /// cannot be built.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_ec::{scalar_mul::variable_base::MsmConfig, VariableBaseMSM};
+    /// use ark_std::UniformRand;
+    /// use ark_test_curves::bls12_381::{Fr, G1Affine, G1Projective as G};
+    ///
+    /// let mut rng = ark_std::test_rng();
+    /// let bases = (0..100).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+    /// let scalars = (0..100).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    /// let config = MsmConfig {
+    ///     window: Some(4),
+    ///     max_mem: Some(1 << 16),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     G::msm_with_config(&bases, &scalars, &config),
+    ///     G::msm(&bases, &scalars),
+    /// );
+    /// ```
+    fn msm_with_config(
+        bases: &[Self::MulBase],
+        scalars: &[Self::ScalarField],
+        config: &MsmConfig,
+    ) -> Result<Self, usize> {
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        let window = config.window();
+        if let Some(window) = window {
+            assert!(window >= 2, "the window size must be at least 2");
+        }
+        let chunk_size = config.chunk_size::<Self>(scalars.len());
+        let msm = || {
+            bases
+                .chunks(chunk_size)
+                .zip(scalars.chunks(chunk_size))
+                .map(|(bases, scalars)| {
+                    let bigints = Self::ScalarField::into_bigint_batch(scalars);
+                    msm_bigint_split::<Self>(bases, &bigints, window)
+                })
+                .sum::<Self>()
+        };
+        #[cfg(feature = "parallel")]
+        if let Some(threads) = config.threads {
+            return Ok(thread_pool_with(threads).install(msm));
+        }
+        Ok(ark_ff::thread_pool::in_thread_pool(msm))
+    }
+
     /// Streaming multi-scalar multiplication algorithm with hard-coded chunk
     /// size.
     fn msm_chunks<I: ?Sized, J>(bases_stream: &J, scalars_stream: &I) -> Self
@@ -95,30 +185,195 @@ pub trait VariableBaseMSM: ScalarMul {
                 .collect::<Vec<_>>();
             let scalars_step = (&mut scalars)
                 .take(step)
-                .map(|s| s.borrow().into_bigint())
+                .map(|s| *s.borrow())
                 .collect::<Vec<_>>();
+            let scalars_step = Self::ScalarField::into_bigint_batch(&scalars_step);
             result += Self::msm_bigint(bases_step.as_slice(), scalars_step.as_slice());
         }
         result
     }
+
+    /// Streaming multi-scalar multiplication over an iterator of
+    /// (base, scalar) pairs, in memory that does not depend on the number of
+    /// pairs.
+    ///
+    /// Reference: [`StreamingPippenger`]
+    fn msm_stream<I, B, S>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (B, S)>,
+        B: Borrow<Self::MulBase>,
+        S: Borrow<Self::ScalarField>,
+    {
+        let mut pippenger = StreamingPippenger::<Self>::new(1 << 16);
+        for (base, scalar) in pairs {
+            pippenger.add(base, scalar.borrow().into_bigint());
+        }
+        pippenger.finalize()
+    }
 }
 
-// Compute msm using windowed non-adjacent form
-fn msm_bigint_wnaf<V: VariableBaseMSM>(
+/// Parameters for [`VariableBaseMSM::msm_with_config`]. Parameters that are
+/// `None` are chosen automatically.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MsmConfig {
+    /// The window size in bits, which must be at least 2. Larger window sizes
+    /// than [`MsmConfig::MAX_WINDOW`] are clamped to it.
+    pub window: Option<usize>,
+    /// The number of threads. This is only used with the `parallel` feature,
+    /// in which case the MSM runs in a thread pool with that many threads,
+    /// which is built on first use and reused by later MSMs.
+    pub threads: Option<usize>,
+    /// An approximate bound on the memory in bytes used by the MSM, besides
+    /// its inputs. When the bound is too small for a single MSM, the inputs
+    /// are split into chunks whose MSMs are computed one after the other.
+    pub max_mem: Option<usize>,
+}
+
+impl MsmConfig {
+    /// The largest window size, which is the one chosen automatically for
+    /// MSMs of `2^32` scalars. The number of buckets grows exponentially with
+    /// the window size, so that larger windows are never worth it.
+    pub const MAX_WINDOW: usize = 24;
+
+    /// Returns the window size, clamped to [`Self::MAX_WINDOW`].
+    fn window(&self) -> Option<usize> {
+        self.window.map(|window| window.min(Self::MAX_WINDOW))
+    }
+
+    /// Returns the size of the chunks that the MSM of `size` scalars is split
+    /// into, so that the memory used by each chunk is within `max_mem`.
+    fn chunk_size<V: VariableBaseMSM>(&self, size: usize) -> usize {
+        let Some(max_mem) = self.max_mem else {
+            return size.max(1);
+        };
+        // The memory used for the MSM of a chunk, which comprises the bigint
+        // and the digits of every scalar, copies of the bases and scalars for
+        // the small scalars, and the buckets of every window.
+        let memory = |size: usize| {
+            let window = self.window().unwrap_or_else(|| window_size(size));
+            let num_windows = V::ScalarField::MODULUS_BIT_SIZE as usize / window + 1;
+            let bigint = ark_std::mem::size_of::<<V::ScalarField as PrimeField>::BigInt>();
+            let base = ark_std::mem::size_of::<V::MulBase>();
+            let digits = num_windows * ark_std::mem::size_of::<i64>();
+            let buckets = num_windows * (1 << window) * ark_std::mem::size_of::<V>();
+            size * (2 * bigint + base + digits) + buckets
+        };
+        let mut chunk_size = size.max(1);
+        while chunk_size > 1 && memory(chunk_size) > max_mem {
+            chunk_size = (chunk_size + 1) / 2;
+        }
+        chunk_size
+    }
+}
+
+/// Returns the thread pool with `threads` threads used by
+/// [`VariableBaseMSM::msm_with_config`], building it on the first call with
+/// this number of threads.
+#[cfg(feature = "parallel")]
+fn thread_pool_with(threads: usize) -> std::sync::Arc<rayon::ThreadPool> {
+    use std::sync::{Arc, Mutex};
+
+    static POOLS: Mutex<Vec<(usize, Arc<rayon::ThreadPool>)>> = Mutex::new(Vec::new());
+    let mut pools = POOLS.lock().unwrap();
+    if let Some((_, pool)) = pools.iter().find(|(t, _)| *t == threads) {
+        return pool.clone();
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("failed to build the thread pool");
+    let pool = Arc::new(pool);
+    pools.push((threads, pool.clone()));
+    pool
+}
+
+/// Returns the window size for an MSM of `size` scalars.
+fn window_size(size: usize) -> usize {
+    if size < 32 {
+        3
+    } else {
+        super::ln_without_floats(size) + 2
+    }
+}
+
+/// Computes an MSM with the bucket method, after handling the scalars that
+/// are zero or one separately, and grouping the other scalars by whether
+/// they fit into one limb, two limbs, or neither.
+///
+/// Witnesses often consist mostly of such small scalars, for which an MSM
+/// over fewer bits needs fewer windows. When all scalars are large, the
+/// bases and scalars are used directly, without being copied.
+///
+/// The window size is `window` if it is given, and is chosen according to
+/// the size of each group otherwise.
+fn msm_bigint_split<V: VariableBaseMSM>(
     bases: &[V::MulBase],
     bigints: &[<V::ScalarField as PrimeField>::BigInt],
+    window: Option<usize>,
 ) -> V {
     let size = ark_std::cmp::min(bases.len(), bigints.len());
     let scalars = &bigints[..size];
     let bases = &bases[..size];
 
-    let c = if size < 32 {
-        3
-    } else {
-        super::ln_without_floats(size) + 2
+    let num_bits = V::ScalarField::MODULUS_BIT_SIZE as usize;
+    let one = V::ScalarField::one().into_bigint();
+    // The bit sizes of the groups of scalars, which are `None` for the
+    // scalars that are zero or one.
+    let group_bits = [64.min(num_bits), 128.min(num_bits), num_bits];
+    let group = |s: &<V::ScalarField as PrimeField>::BigInt| {
+        if s.is_zero() || *s == one {
+            None
+        } else {
+            let bits = s.num_bits() as usize;
+            Some(group_bits.iter().position(|&b| bits <= b).unwrap())
+        }
     };
+    if scalars.iter().all(|s| group(s) == Some(2)) {
+        let window = window.unwrap_or_else(|| window_size(size));
+        return V::msm_bigint_with_bits(bases, scalars, num_bits, window);
+    }
 
-    let num_bits = V::ScalarField::MODULUS_BIT_SIZE as usize;
+    let mut result = V::zero();
+    let mut groups = [(); 3].map(|_| (Vec::new(), Vec::new()));
+    for (s, b) in scalars.iter().zip(bases) {
+        match group(s) {
+            Some(i) => {
+                groups[i].0.push(*b);
+                groups[i].1.push(*s);
+            },
+            None if s.is_zero() => (),
+            None => result += b,
+        }
+    }
+    for ((bases, scalars), bits) in groups.iter().zip(group_bits) {
+        if !scalars.is_empty() {
+            let window = window.unwrap_or_else(|| window_size(scalars.len()));
+            result += V::msm_bigint_with_bits(bases, scalars, bits, window);
+        }
+    }
+    result
+}
+
+/// Computes an MSM with the bucket method, using signed digits in windows of
+/// `c` bits, for scalars with at most `num_bits` bits.
+///
+/// Every scalar is recoded into digits in `[-2^(c - 1), 2^(c - 1)]`, so that
+/// each window only needs `2^(c - 1)` buckets, half as many as with unsigned
+/// digits: a negative digit subtracts the base from the bucket of its
+/// absolute value. This is worthwhile when negation is cheap.
+fn msm_bigint_wnaf<V: VariableBaseMSM>(
+    bases: &[V::MulBase],
+    bigints: &[<V::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
+    c: usize,
+) -> V {
+    let size = ark_std::cmp::min(bases.len(), bigints.len());
+    let scalars = &bigints[..size];
+    let bases = &bases[..size];
+
+    // Recoding into signed digits may carry into the bit above the most
+    // significant one, so we need digits for `num_bits + 1` bits.
+    let num_bits = num_bits + 1;
     let digits_count = (num_bits + c - 1) / c;
     #[cfg(feature = "parallel")]
     let scalar_digits = scalars
@@ -133,10 +388,10 @@ fn msm_bigint_wnaf<V: VariableBaseMSM>(
     let zero = V::zero();
     let window_sums: Vec<_> = ark_std::cfg_into_iter!(0..digits_count)
         .map(|i| {
-            let mut buckets = vec![zero; 1 << c];
+            // The bucket `j` holds the bases whose `i`-th digit is `±(j + 1)`.
+            let mut buckets = vec![zero; 1 << (c - 1)];
             for (digits, base) in scalar_digits.chunks(digits_count).zip(bases) {
                 use ark_std::cmp::Ordering;
-                // digits is the digits thing of the first scalar?
                 let scalar = digits[i];
                 match 0.cmp(&scalar) {
                     Ordering::Less => buckets[(scalar - 1) as usize] += base,
@@ -172,23 +427,19 @@ fn msm_bigint_wnaf<V: VariableBaseMSM>(
             })
 }
 
-/// Optimized implementation of multi-scalar multiplication.
+/// Optimized implementation of multi-scalar multiplication, for scalars with
+/// at most `num_bits` bits, with windows of `c` bits.
 fn msm_bigint<V: VariableBaseMSM>(
     bases: &[V::MulBase],
     bigints: &[<V::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
+    c: usize,
 ) -> V {
     let size = ark_std::cmp::min(bases.len(), bigints.len());
     let scalars = &bigints[..size];
     let bases = &bases[..size];
     let scalars_and_bases_iter = scalars.iter().zip(bases).filter(|(s, _)| !s.is_zero());
 
-    let c = if size < 32 {
-        3
-    } else {
-        super::ln_without_floats(size) + 2
-    };
-
-    let num_bits = V::ScalarField::MODULUS_BIT_SIZE as usize;
     let one = V::ScalarField::one().into_bigint();
 
     let zero = V::zero();
@@ -270,7 +521,11 @@ fn msm_bigint<V: VariableBaseMSM>(
 }
 
 // From: https://github.com/arkworks-rs/gemini/blob/main/src/kzg/msm/variable_base.rs#L20
-fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<Item = i64> + '_ {
+pub(super) fn make_digits(
+    a: &impl BigInteger,
+    w: usize,
+    num_bits: usize,
+) -> impl Iterator<Item = i64> + '_ {
     let scalar = a.as_ref();
     let radix: u64 = 1 << w;
     let window_mask: u64 = radix - 1;
@@ -289,7 +544,10 @@ fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<
         let u64_idx = bit_offset / 64;
         let bit_idx = bit_offset % 64;
         // Read the bits from the scalar
-        let bit_buf = if bit_idx < 64 - w || u64_idx == scalar.len() - 1 {
+        let bit_buf = if u64_idx >= scalar.len() {
+            // The window lies above the most significant limb.
+            0
+        } else if bit_idx < 64 - w || u64_idx == scalar.len() - 1 {
             // This window's bits are contained in a single u64,
             // or it's the last u64 anyway.
             scalar[u64_idx] >> bit_idx
@@ -311,3 +569,22 @@ fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<
         digit
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MsmConfig;
+
+    #[test]
+    fn test_window_is_clamped() {
+        let config = |window| MsmConfig {
+            window,
+            ..Default::default()
+        };
+        assert_eq!(config(None).window(), None);
+        assert_eq!(config(Some(4)).window(), Some(4));
+        assert_eq!(
+            config(Some(usize::MAX)).window(),
+            Some(MsmConfig::MAX_WINDOW)
+        );
+    }
+}
@@ -1,10 +1,13 @@
 //! A space-efficient implementation of Pippenger's algorithm.
-use ark_ff::{PrimeField, Zero};
+use ark_ff::{BigInteger, PrimeField, Zero};
 
-use ark_std::{borrow::Borrow, vec::*};
+use ark_std::{borrow::Borrow, cfg_chunks_mut, cfg_iter_mut, vec::*};
 use hashbrown::HashMap;
 
-use super::{DefaultHasher, VariableBaseMSM};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{make_digits, DefaultHasher, VariableBaseMSM};
 
 /// Struct for the chunked Pippenger algorithm.
 pub struct ChunkedPippenger<G: VariableBaseMSM> {
@@ -126,3 +129,258 @@ impl<G: VariableBaseMSM> HashMapPippenger<G> {
         self.result
     }
 }
+
+/// Struct for a streaming Pippenger algorithm, which consumes (base, scalar)
+/// pairs in bounded memory.
+///
+/// Unlike [`ChunkedPippenger`], which computes a separate MSM for every chunk
+/// of pairs, the buckets of every window are kept across chunks, so that the
+/// buckets are only summed up once, in [`Self::finalize`]. The memory used
+/// is that of the buffer of `buf_size` pairs, and of the buckets.
+pub struct StreamingPippenger<G: VariableBaseMSM> {
+    scalars_buffer: Vec<<G::ScalarField as PrimeField>::BigInt>,
+    bases_buffer: Vec<G::MulBase>,
+    buckets: Vec<G>,
+    window: usize,
+    num_windows: usize,
+    buf_size: usize,
+}
+
+impl<G: VariableBaseMSM> StreamingPippenger<G> {
+    /// Initialize a streaming Pippenger instance that buffers `buf_size`
+    /// pairs, with a window size suited to MSMs of that size.
+    pub fn new(buf_size: usize) -> Self {
+        Self::with_window_size(buf_size, super::window_size(buf_size))
+    }
+
+    /// Initialize a streaming Pippenger instance that buffers `buf_size`
+    /// pairs, with windows of `window` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf_size` is zero, or if `window` is not in `2..64`.
+    pub fn with_window_size(buf_size: usize, window: usize) -> Self {
+        assert!(buf_size > 0);
+        assert!((2..64).contains(&window));
+        // Recoding into signed digits may carry into the bit above the most
+        // significant one.
+        let num_bits = G::ScalarField::MODULUS_BIT_SIZE as usize + G::NEGATION_IS_CHEAP as usize;
+        let num_windows = (num_bits + window - 1) / window;
+        Self {
+            scalars_buffer: Vec::with_capacity(buf_size),
+            bases_buffer: Vec::with_capacity(buf_size),
+            buckets: vec![G::zero(); num_windows * Self::num_buckets(window)],
+            window,
+            num_windows,
+            buf_size,
+        }
+    }
+
+    /// The number of buckets of each window: digits are signed when negation
+    /// is cheap, and unsigned otherwise.
+    fn num_buckets(window: usize) -> usize {
+        if G::NEGATION_IS_CHEAP {
+            1 << (window - 1)
+        } else {
+            (1 << window) - 1
+        }
+    }
+
+    /// Add a new (base, scalar) pair into the instance.
+    #[inline(always)]
+    pub fn add<B, S>(&mut self, base: B, scalar: S)
+    where
+        B: Borrow<G::MulBase>,
+        S: Borrow<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        self.scalars_buffer.push(*scalar.borrow());
+        self.bases_buffer.push(*base.borrow());
+        if self.scalars_buffer.len() == self.buf_size {
+            self.flush();
+        }
+    }
+
+    /// Accumulate the buffered pairs into the buckets, and clear the buffer.
+    fn flush(&mut self) {
+        let window = self.window;
+        let num_windows = self.num_windows;
+        let mut digits = vec![0i64; self.scalars_buffer.len() * num_windows];
+        cfg_chunks_mut!(digits, num_windows)
+            .zip(&self.scalars_buffer)
+            .for_each(|(digits, scalar)| {
+                if G::NEGATION_IS_CHEAP {
+                    let num_bits = num_windows * window;
+                    for (d, digit) in digits.iter_mut().zip(make_digits(scalar, window, num_bits)) {
+                        *d = digit;
+                    }
+                } else {
+                    let mut scalar = *scalar;
+                    for d in digits {
+                        *d = (scalar.as_ref()[0] % (1 << window)) as i64;
+                        scalar >>= window as u32;
+                    }
+                }
+            });
+
+        let bases = &self.bases_buffer;
+        cfg_chunks_mut!(self.buckets, Self::num_buckets(window))
+            .enumerate()
+            .for_each(|(i, buckets)| {
+                // The bucket `j` holds the bases whose `i`-th digit is
+                // `±(j + 1)`.
+                for (digits, base) in digits.chunks(num_windows).zip(bases) {
+                    use ark_std::cmp::Ordering;
+                    let digit = digits[i];
+                    match 0.cmp(&digit) {
+                        Ordering::Less => buckets[(digit - 1) as usize] += base,
+                        Ordering::Greater => buckets[(-digit - 1) as usize] -= base,
+                        Ordering::Equal => (),
+                    }
+                }
+            });
+        self.scalars_buffer.clear();
+        self.bases_buffer.clear();
+    }
+
+    /// Add the pairs that were added to `other` into this instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instances have different window sizes.
+    pub fn merge(&mut self, other: Self) {
+        assert_eq!(self.window, other.window);
+        cfg_iter_mut!(self.buckets)
+            .zip(&other.buckets)
+            .for_each(|(a, b)| *a += b);
+        for (base, scalar) in other.bases_buffer.iter().zip(&other.scalars_buffer) {
+            self.add(base, scalar);
+        }
+    }
+
+    /// Output the final Pippenger algorithm result.
+    pub fn finalize(mut self) -> G {
+        if !self.scalars_buffer.is_empty() {
+            self.flush();
+        }
+        let mut window_sums = vec![G::zero(); self.num_windows];
+        let num_buckets = Self::num_buckets(self.window);
+        let buckets = &self.buckets;
+        cfg_iter_mut!(window_sums).enumerate().for_each(|(i, res)| {
+            let mut running_sum = G::zero();
+            buckets[i * num_buckets..(i + 1) * num_buckets]
+                .iter()
+                .rev()
+                .for_each(|b| {
+                    running_sum += b;
+                    *res += &running_sum;
+                });
+        });
+
+        // We're traversing windows from high to low.
+        window_sums
+            .iter()
+            .rev()
+            .fold(G::zero(), |mut total, sum_i| {
+                for _ in 0..self.window {
+                    total.double_in_place();
+                }
+                total + sum_i
+            })
+    }
+}
+
+/// An accumulator for an MSM whose (base, scalar) pairs become available
+/// over time, e.g. over the rounds of a recursive prover or a folding
+/// scheme.
+///
+/// The pairs are accumulated into the buckets of a [`StreamingPippenger`],
+/// so that the buckets are summed up and the windows are combined only
+/// once, when the accumulator is finalized, instead of once per round.
+/// Accumulators with the same window size can be merged, e.g. after
+/// accumulating different rounds in parallel.
+///
+/// # Example
+/// ```
+/// use ark_ec::{scalar_mul::variable_base::MsmAccumulator, VariableBaseMSM};
+/// use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::{Fr, G1Affine, G1Projective as G};
+///
+/// let mut rng = ark_std::test_rng();
+/// let bases = (0..20).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+/// let scalars = (0..20).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+///
+/// let mut acc = MsmAccumulator::<G>::new();
+/// for round in 0..4 {
+///     let range = 5 * round..5 * (round + 1);
+///     acc.extend(&bases[range.clone()], &scalars[range]);
+/// }
+/// assert_eq!(acc.finalize(), G::msm(&bases, &scalars).unwrap());
+/// ```
+pub struct MsmAccumulator<G: VariableBaseMSM> {
+    pippenger: StreamingPippenger<G>,
+}
+
+impl<G: VariableBaseMSM> MsmAccumulator<G> {
+    /// The number of pairs that are buffered before being added to the
+    /// buckets.
+    const BUFFER_SIZE: usize = 1 << 12;
+
+    /// Initialize an empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            pippenger: StreamingPippenger::new(Self::BUFFER_SIZE),
+        }
+    }
+
+    /// Initialize an empty accumulator with windows of `window` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not in `2..64`.
+    pub fn with_window_size(window: usize) -> Self {
+        Self {
+            pippenger: StreamingPippenger::with_window_size(Self::BUFFER_SIZE, window),
+        }
+    }
+
+    /// Add a new (base, scalar) pair into the accumulator.
+    #[inline]
+    pub fn push<B, S>(&mut self, base: B, scalar: S)
+    where
+        B: Borrow<G::MulBase>,
+        S: Borrow<G::ScalarField>,
+    {
+        self.pippenger.add(base, scalar.borrow().into_bigint());
+    }
+
+    /// Add the pairs of corresponding elements of `bases` and `scalars` into
+    /// the accumulator. If the slices have different lengths, they are
+    /// chopped to the shortest length.
+    pub fn extend(&mut self, bases: &[G::MulBase], scalars: &[G::ScalarField]) {
+        let size = bases.len().min(scalars.len());
+        let bigints = G::ScalarField::into_bigint_batch(&scalars[..size]);
+        for (base, scalar) in bases.iter().zip(&bigints) {
+            self.pippenger.add(base, scalar);
+        }
+    }
+
+    /// Add the pairs that were added to `other` into this accumulator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the accumulators have different window sizes.
+    pub fn merge(&mut self, other: Self) {
+        self.pippenger.merge(other.pippenger);
+    }
+
+    /// Output the MSM of all the pairs that were added.
+    pub fn finalize(self) -> G {
+        self.pippenger.finalize()
+    }
+}
+
+impl<G: VariableBaseMSM> Default for MsmAccumulator<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
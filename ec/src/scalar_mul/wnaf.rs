@@ -1,9 +1,17 @@
-use crate::PrimeGroup;
+use crate::{PrimeGroup, ScalarMul};
 use ark_ff::{BigInteger, PrimeField};
-use ark_std::vec::*;
+use ark_std::{
+    cfg_iter,
+    ops::{AddAssign, SubAssign},
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// A helper type that contains all the context required for computing
 /// a window NAF multiplication of a group element by a scalar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct WnafContext {
     pub window_size: usize,
 }
@@ -56,6 +64,46 @@ impl WnafContext {
         if 1 << (self.window_size - 1) > base_table.len() {
             return None;
         }
+        Some(self.mul_with_any_table(base_table, scalar))
+    }
+
+    /// Computes the same table as [`Self::table`], with the multiples of
+    /// `base` converted to [`ScalarMul::MulBase`], e.g. to affine
+    /// coordinates. This takes less memory, and makes the additions of the
+    /// table entries cheaper.
+    pub fn affine_table<G: ScalarMul>(&self, base: G) -> Vec<G::MulBase> {
+        G::batch_convert_to_mul_base(&self.table(base))
+    }
+
+    /// Computes scalar multiplication of a group element by `scalar`, like
+    /// [`Self::mul_with_table`], with a table computed by
+    /// [`Self::affine_table`].
+    ///
+    /// Returns `None` if the table is too small.
+    pub fn mul_with_affine_table<G: ScalarMul>(
+        &self,
+        base_table: &[G::MulBase],
+        scalar: &G::ScalarField,
+    ) -> Option<G> {
+        if 1 << (self.window_size - 1) > base_table.len() {
+            return None;
+        }
+        Some(self.mul_with_any_table(base_table, scalar))
+    }
+
+    /// Precomputes a table for `base`, with which `base` can be multiplied
+    /// by many scalars; see [`WnafTable`].
+    pub fn precompute<G: ScalarMul>(&self, base: G) -> WnafTable<G> {
+        WnafTable {
+            context: *self,
+            table: self.affine_table(base),
+        }
+    }
+
+    fn mul_with_any_table<G, T>(&self, base_table: &[T], scalar: &G::ScalarField) -> G
+    where
+        G: PrimeGroup + for<'a> AddAssign<&'a T> + for<'a> SubAssign<&'a T>,
+    {
         let scalar_wnaf = scalar.into_bigint().find_wnaf(self.window_size).unwrap();
 
         let mut result = G::zero();
@@ -78,6 +126,113 @@ impl WnafContext {
             }
         }
 
-        Some(result)
+        result
+    }
+
+    /// Computes the inner product between `bases` and `scalars`, chopping
+    /// the slices to the shortest length.
+    ///
+    /// This uses Straus's algorithm, also known as Shamir's trick, with
+    /// the wNAF of each scalar: the tables of all bases are computed, and
+    /// their entries are added to a single accumulator, so that the
+    /// doublings are shared by all bases. This is faster than separate
+    /// multiplications for a few bases, while [`VariableBaseMSM::msm`] is
+    /// faster for many.
+    ///
+    /// [`VariableBaseMSM::msm`]: crate::VariableBaseMSM::msm
+    pub fn multi_mul<G: ScalarMul>(&self, bases: &[G], scalars: &[G::ScalarField]) -> G {
+        let size = ark_std::cmp::min(bases.len(), scalars.len());
+        let table_size = 1 << (self.window_size - 1);
+        let tables = bases[..size]
+            .iter()
+            .flat_map(|base| self.table(*base))
+            .collect::<Vec<_>>();
+        let tables = G::batch_convert_to_mul_base(&tables);
+        let wnafs = scalars[..size]
+            .iter()
+            .map(|s| s.into_bigint().find_wnaf(self.window_size).unwrap())
+            .collect::<Vec<_>>();
+        let max_len = wnafs.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut result = G::zero();
+        for i in (0..max_len).rev() {
+            result.double_in_place();
+            for (wnaf, table) in wnafs.iter().zip(tables.chunks(table_size)) {
+                match wnaf.get(i) {
+                    Some(&n) if n > 0 => result += &table[(n / 2) as usize],
+                    Some(&n) if n < 0 => result -= &table[((-n) / 2) as usize],
+                    _ => (),
+                }
+            }
+        }
+        result
+    }
+}
+
+/// A table of multiples of a fixed base, with which the base can be
+/// multiplied by many scalars using the wNAF algorithm, without recomputing
+/// the table every time as [`WnafContext::mul`] does.
+///
+/// The table holds `2^(w - 1)` odd multiples of the base, for a window of
+/// size `w`, as [`ScalarMul::MulBase`], e.g. in affine coordinates.
+///
+/// # Example
+/// ```
+/// use ark_ec::scalar_mul::wnaf::WnafTable;
+/// use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+///
+/// let mut rng = ark_std::test_rng();
+/// let base = G::rand(&mut rng);
+/// let table = WnafTable::new(base, 5);
+/// let scalars = (0..10).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+/// for (scalar, product) in scalars.iter().zip(table.batch_mul(&scalars)) {
+///     assert_eq!(product, base * scalar);
+/// }
+/// ```
+pub struct WnafTable<G: ScalarMul> {
+    context: WnafContext,
+    table: Vec<G::MulBase>,
+}
+
+impl<G: ScalarMul> WnafTable<G> {
+    /// Precomputes the table for `base`, for a window of size
+    /// `window_size`. Larger windows need fewer additions per
+    /// multiplication, but the table has `2^(window_size - 1)` entries.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if not `2 <= window_size < 64`
+    pub fn new(base: G, window_size: usize) -> Self {
+        WnafContext::new(window_size).precompute(base)
+    }
+
+    /// The context of the table, which holds its window size.
+    pub fn context(&self) -> WnafContext {
+        self.context
+    }
+
+    /// The multiples of the base in the table.
+    pub fn table(&self) -> &[G::MulBase] {
+        &self.table
+    }
+
+    /// Computes the product of the base and `scalar`.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G {
+        self.context.mul_with_any_table(&self.table, scalar)
+    }
+
+    /// Computes the products of the base and each of the `scalars`.
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G> {
+        cfg_iter!(scalars).map(|s| self.mul(s)).collect()
+    }
+}
+
+impl<G: ScalarMul> Clone for WnafTable<G> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context,
+            table: self.table.clone(),
+        }
     }
 }
@@ -0,0 +1,134 @@
+//! Generation of AArch64 assembly for Montgomery multiplication.
+//!
+//! AArch64 has no instruction that returns the full 128-bit product of two
+//! 64-bit registers: `mul` returns the low half and `umulh` the high half.
+//! Neither instruction touches the condition flags, so each row of partial
+//! products is accumulated by interleaving the multiplications with a single
+//! `adds`/`adcs` carry chain.
+//!
+//! The generated code only uses these scalar instructions, and no NEON
+//! (Advanced SIMD) instructions: NEON multiplies at most 32-bit lanes into
+//! 64-bit products, so a 64-bit limb product would need four of them plus
+//! the carry handling that the scalar carry chain does for free.
+//!
+//! The generated code implements the same "no-carry" variant of CIOS as the
+//! x86-64 backend, and leaves the result in `[0, 2 * MODULUS)`; the caller is
+//! responsible for the final conditional subtraction.
+
+use std::cell::RefCell;
+
+/// The largest number of limbs supported by the AArch64 backend.
+pub const MAX_LIMBS: usize = 6;
+
+/// Registers that can be freely used by the generated code. `x0`-`x3` hold
+/// the inputs, `x18` is reserved on some platforms, and `x19` and `x29` are
+/// reserved by LLVM.
+const REGISTERS: [&str; 23] = [
+    "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14", "x15", "x16", "x17",
+    "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27", "x28",
+];
+
+fn construct_asm_mul(limbs: usize, is_mul: bool) -> (Vec<String>, usize) {
+    let asm_instructions = RefCell::new(Vec::new());
+
+    macro_rules! instruction {
+        ($($arg: tt)*) => {
+            asm_instructions.borrow_mut().push(format!($($arg)*))
+        };
+    }
+
+    let mut registers = REGISTERS.iter().copied();
+    let mut allocate = |n: usize| (&mut registers).take(n).collect::<Vec<_>>();
+    let a = allocate(limbs);
+    let modulus = allocate(limbs);
+    // `t` holds `limbs + 1` words. Rather than shifting `t` by a word at the
+    // end of each iteration, the word at index `j` in iteration `i` is stored
+    // in `t[(i + j) % (limbs + 1)]`.
+    let t = allocate(limbs + 1);
+    let (b_i, k, tmp) = (allocate(1)[0], allocate(1)[0], allocate(1)[0]);
+    let num_registers = 3 * limbs + 4;
+
+    let (a_ptr, b_ptr, modulus_ptr, inv) = ("x0", if is_mul { "x1" } else { "x0" }, "x2", "x3");
+
+    instruction!("// Load `a` and the modulus");
+    for (j, (a_j, modulus_j)) in a.iter().zip(&modulus).enumerate() {
+        instruction!("ldr {}, [{}, #{}]", a_j, a_ptr, 8 * j);
+        instruction!("ldr {}, [{}, #{}]", modulus_j, modulus_ptr, 8 * j);
+    }
+    for t_j in &t {
+        instruction!("mov {}, xzr", t_j);
+    }
+
+    for i in 0..limbs {
+        let t = |j: usize| t[(i + j) % (limbs + 1)];
+
+        instruction!("// t += a * b[{}]", i);
+        instruction!("ldr {}, [{}, #{}]", b_i, b_ptr, 8 * i);
+        for (j, a_j) in a.iter().enumerate() {
+            instruction!("mul {}, {}, {}", tmp, a_j, b_i);
+            let op = if j == 0 { "adds" } else { "adcs" };
+            instruction!("{} {}, {}, {}", op, t(j), t(j), tmp);
+        }
+        instruction!("adc {}, xzr, xzr", t(limbs));
+        for (j, a_j) in a.iter().enumerate() {
+            instruction!("umulh {}, {}, {}", tmp, a_j, b_i);
+            let op = if j == 0 { "adds" } else { "adcs" };
+            instruction!("{} {}, {}, {}", op, t(j + 1), t(j + 1), tmp);
+        }
+
+        instruction!("// t = (t + k * modulus) / 2^64");
+        instruction!("mul {}, {}, {}", k, t(0), inv);
+        for (j, modulus_j) in modulus.iter().enumerate() {
+            instruction!("mul {}, {}, {}", tmp, k, modulus_j);
+            let op = if j == 0 { "adds" } else { "adcs" };
+            instruction!("{} {}, {}, {}", op, t(j), t(j), tmp);
+        }
+        instruction!("adc {}, {}, xzr", t(limbs), t(limbs));
+        for (j, modulus_j) in modulus.iter().enumerate() {
+            instruction!("umulh {}, {}, {}", tmp, k, modulus_j);
+            let op = if j == 0 { "adds" } else { "adcs" };
+            instruction!("{} {}, {}, {}", op, t(j + 1), t(j + 1), tmp);
+        }
+    }
+
+    instruction!("// Store the result into `a`");
+    for j in 0..limbs {
+        let result = t[(limbs + j) % (limbs + 1)];
+        instruction!("str {}, [{}, #{}]", result, a_ptr, 8 * j);
+    }
+    (asm_instructions.into_inner(), num_registers)
+}
+
+pub fn generate_impl(num_limbs: usize, is_mul: bool) -> String {
+    let (asm_instructions, num_registers) = construct_asm_mul(num_limbs, is_mul);
+    let assembly = asm_instructions
+        .iter()
+        .map(|instruction| format!("\"{}\",", instruction))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let b = if is_mul {
+        "inout(\"x1\") b.as_ptr() => _,"
+    } else {
+        ""
+    };
+    let clobbers = REGISTERS
+        .iter()
+        .take(num_registers)
+        .map(|r| format!("out(\"{}\") _,", r))
+        .collect::<Vec<_>>()
+        .join("\n");
+    [
+        "unsafe {".to_string(),
+        "ark_std::arch::asm!(".to_string(),
+        assembly,
+        "inout(\"x0\") a.as_mut_ptr() => _,".to_string(),
+        b.to_string(),
+        "inout(\"x2\") Self::MODULUS.0.as_ptr() => _,".to_string(),
+        "inout(\"x3\") Self::INV => _,".to_string(),
+        clobbers,
+        "options(nostack)".to_string(),
+        ")".to_string(),
+        "}".to_string(),
+    ]
+    .join("\n")
+}
@@ -15,6 +15,10 @@ impl AssemblyVar {
         }
     }
 
+    pub fn is_memory(&self) -> bool {
+        matches!(self, Self::Memory(_))
+    }
+
     pub fn memory_accesses(&self, range: usize) -> Vec<AssemblyVar> {
         (0..range)
             .map(|i| {
@@ -6,6 +6,7 @@ pub struct Context<'a> {
     assembly_instructions: Vec<String>,
     declarations: Vec<Declaration<'a>>,
     used_registers: Vec<Register<'a>>,
+    buffer_len: Option<usize>,
 }
 
 impl<'a> Context<'a> {
@@ -30,6 +31,7 @@ impl<'a> Context<'a> {
             assembly_instructions: Vec::new(),
             declarations: Vec::new(),
             used_registers: Vec::new(),
+            buffer_len: None,
         }
     }
 
@@ -64,11 +66,10 @@ impl<'a> Context<'a> {
         self.declarations.push(declaration);
     }
 
-    pub fn add_buffer(&mut self, extra_reg: usize) {
-        self.append(&format!(
-            "let mut spill_buffer = core::mem::MaybeUninit::<[u64; {}]>::uninit();",
-            extra_reg
-        ));
+    /// Declares a buffer `spill_buffer` of `len` words before the assembly
+    /// block, for values that do not fit in registers.
+    pub fn add_buffer(&mut self, len: usize) {
+        self.buffer_len = Some(len);
     }
 
     pub fn add_asm(&mut self, asm_instructions: &[String]) {
@@ -102,7 +103,13 @@ impl<'a> Context<'a> {
             .join("\n");
         let options = "options(att_syntax)".to_string();
         let assembly = self.instructions_to_string();
+        let buffer = self
+            .buffer_len
+            .map(|len| format!("let mut spill_buffer = [0u64; {}];", len))
+            .unwrap_or_default();
         [
+            "{".to_string(),
+            buffer,
             "unsafe {".to_string(),
             "ark_std::arch::asm!(".to_string(),
             assembly,
@@ -111,6 +118,7 @@ impl<'a> Context<'a> {
             options,
             ")".to_string(),
             "}".to_string(),
+            "}".to_string(),
         ]
         .join("\n")
     }
@@ -14,13 +14,24 @@ use syn::{
     Expr,
 };
 
+mod aarch64;
 mod context;
 use context::{AssemblyVar, Context};
 
 use std::cell::RefCell;
 
+/// The largest number of limbs whose accumulator is kept entirely in
+/// registers by the x86-64 backend.
 const MAX_REGS: usize = 6;
 
+/// The largest number of limbs supported by the x86-64 backend.
+const MAX_LIMBS: usize = 12;
+
+/// For more than `MAX_REGS` limbs, the number of limbs of the accumulator that
+/// are kept in registers. The remaining limbs are kept in a spill buffer, and
+/// are operated on through an additional scratch register.
+const SPILL_REGS: usize = 2;
+
 struct AsmMulInput {
     num_limbs: Box<Expr>,
     a: Expr,
@@ -59,7 +70,7 @@ pub fn x86_64_asm_mul(input: TokenStream) -> TokenStream {
     } else {
         panic!("The number of limbs must be a literal");
     };
-    if num_limbs <= 6 && num_limbs <= 3 * MAX_REGS {
+    if num_limbs <= MAX_LIMBS {
         let impl_block = generate_impl(num_limbs, true);
 
         let inner_ts: Expr = syn::parse_str(&impl_block).unwrap();
@@ -110,7 +121,7 @@ pub fn x86_64_asm_square(input: TokenStream) -> TokenStream {
     } else {
         panic!("The number of limbs must be a literal");
     };
-    if num_limbs <= 6 && num_limbs <= 3 * MAX_REGS {
+    if num_limbs <= MAX_LIMBS {
         let impl_block = generate_impl(num_limbs, false);
 
         let inner_ts: Expr = syn::parse_str(&impl_block).unwrap();
@@ -124,8 +135,81 @@ pub fn x86_64_asm_square(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Generates AArch64 assembly for the Montgomery multiplication of `a` by `b`, using the
+/// scalar `mul` and `umulh` instructions. Generates nothing for more than
+/// six limbs.
+#[proc_macro]
+pub fn aarch64_asm_mul(input: TokenStream) -> TokenStream {
+    let AsmMulInput { num_limbs, a, b } = syn::parse_macro_input!(input);
+    let num_limbs = if let Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(ref lit_int),
+        ..
+    }) = &*num_limbs
+    {
+        lit_int.base10_parse::<usize>().unwrap()
+    } else {
+        panic!("The number of limbs must be a literal");
+    };
+    if num_limbs <= aarch64::MAX_LIMBS {
+        let impl_block = aarch64::generate_impl(num_limbs, true);
+
+        let inner_ts: Expr = syn::parse_str(&impl_block).unwrap();
+        let ts = quote::quote! {
+            let a = &mut #a;
+            let b = &#b;
+            #inner_ts
+        };
+        ts.into()
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Generates AArch64 assembly for the Montgomery squaring of `a`, using the
+/// scalar `mul` and `umulh` instructions. Generates nothing for more than
+/// six limbs.
+#[proc_macro]
+pub fn aarch64_asm_square(input: TokenStream) -> TokenStream {
+    let AsmSquareInput { num_limbs, a } = syn::parse_macro_input!(input);
+    let num_limbs = if let Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(ref lit_int),
+        ..
+    }) = &*num_limbs
+    {
+        lit_int.base10_parse::<usize>().unwrap()
+    } else {
+        panic!("The number of limbs must be a literal");
+    };
+    if num_limbs <= aarch64::MAX_LIMBS {
+        let impl_block = aarch64::generate_impl(num_limbs, false);
+
+        let inner_ts: Expr = syn::parse_str(&impl_block).unwrap();
+        let ts = quote::quote! {
+            let a = &mut #a;
+            #inner_ts
+        };
+        ts.into()
+    } else {
+        TokenStream::new()
+    }
+}
+
 fn construct_asm_mul(ctx: &Context<'_>, limbs: usize) -> Vec<String> {
-    let r: Vec<AssemblyVar> = Context::R.iter().map(|r| (*r).into()).collect();
+    // The accumulator `r`. When it does not fit in registers, its upper limbs
+    // live in the spill buffer, and instructions that cannot take a memory
+    // destination go through `scratch` instead.
+    let r: Vec<AssemblyVar> = if limbs <= MAX_REGS {
+        Context::R.iter().take(limbs).map(|r| (*r).into()).collect()
+    } else {
+        let buf: AssemblyVar = ctx.get_decl("buf").into();
+        Context::R
+            .iter()
+            .take(SPILL_REGS)
+            .map(|r| (*r).into())
+            .chain(buf.memory_accesses(limbs - SPILL_REGS))
+            .collect()
+    };
+    let scratch: AssemblyVar = Context::R[SPILL_REGS].into();
     let rax: AssemblyVar = Context::RAX.into();
     let rcx: AssemblyVar = Context::RCX.into();
     let rdx: AssemblyVar = Context::RDX.into();
@@ -143,38 +227,74 @@ fn construct_asm_mul(ctx: &Context<'_>, limbs: usize) -> Vec<String> {
             .push(format!("// {}", comment));
     };
 
+    // `mulxq`, `adcxq` and `adoxq` require a register destination, and `movq`
+    // cannot move between two memory locations. Since `movq` leaves the flags
+    // untouched, spilled destinations are loaded into `scratch`, updated
+    // there, and stored back.
+    macro_rules! movq {
+        ($a: expr, $b: expr) => {{
+            if $a.is_memory() && $b.is_memory() {
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("movq {}, {}", &$a, &scratch));
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("movq {}, {}", &scratch, &$b));
+            } else {
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("movq {}, {}", &$a, &$b));
+            }
+        }};
+    }
+
     macro_rules! mulxq {
         ($a: expr, $b: expr, $c: expr) => {
-            asm_instructions
-                .borrow_mut()
-                .push(format!("mulxq {}, {}, {}", &$a, &$b, &$c));
+            if $c.is_memory() {
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("mulxq {}, {}, {}", &$a, &$b, &scratch));
+                movq!(scratch, $c);
+            } else {
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("mulxq {}, {}, {}", &$a, &$b, &$c));
+            }
         };
     }
 
     macro_rules! adcxq {
         ($a: expr, $b: expr) => {
-            asm_instructions
-                .borrow_mut()
-                .push(format!("adcxq {}, {}", &$a, &$b));
+            if $b.is_memory() {
+                movq!($b, scratch);
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("adcxq {}, {}", &$a, &scratch));
+                movq!(scratch, $b);
+            } else {
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("adcxq {}, {}", &$a, &$b));
+            }
         };
     }
 
     macro_rules! adoxq {
         ($a: expr, $b: expr) => {
-            asm_instructions
-                .borrow_mut()
-                .push(format!("adoxq {}, {}", &$a, &$b));
+            if $b.is_memory() {
+                movq!($b, scratch);
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("adoxq {}, {}", &$a, &scratch));
+                movq!(scratch, $b);
+            } else {
+                asm_instructions
+                    .borrow_mut()
+                    .push(format!("adoxq {}, {}", &$a, &$b));
+            }
         };
     }
 
-    macro_rules! movq {
-        ($a: expr, $b: expr) => {{
-            asm_instructions
-                .borrow_mut()
-                .push(format!("movq {}, {}", &$a, &$b));
-        }};
-    }
-
     macro_rules! xorq {
         ($a: expr, $b: expr) => {
             asm_instructions
@@ -280,8 +400,8 @@ fn generate_impl(num_limbs: usize, is_mul: bool) -> String {
     ctx.add_declaration("mod_inv", "Self::INV");
 
     if num_limbs > MAX_REGS {
-        ctx.add_buffer(2 * num_limbs);
-        ctx.add_declaration("buf", "&mut spill_buffer");
+        ctx.add_buffer(num_limbs - SPILL_REGS);
+        ctx.add_declaration("buf", "spill_buffer.as_mut_ptr()");
     }
 
     let asm_instructions = construct_asm_mul(&ctx, num_limbs);
@@ -292,7 +412,11 @@ fn generate_impl(num_limbs: usize, is_mul: bool) -> String {
             .iter()
             .copied(),
     );
-    ctx.add_clobbers(Context::R.iter().take(std::cmp::min(num_limbs, 8)).copied());
+    if num_limbs > MAX_REGS {
+        ctx.add_clobbers(Context::R.iter().take(SPILL_REGS + 1).copied());
+    } else {
+        ctx.add_clobbers(Context::R.iter().take(num_limbs).copied());
+    }
     ctx.build()
 }
 
@@ -302,4 +426,16 @@ mod tests {
         let impl_block = super::generate_impl(4, true);
         println!("{}", impl_block);
     }
+
+    #[test]
+    fn expand_spilled_muls() {
+        let impl_block = super::generate_impl(12, true);
+        println!("{}", impl_block);
+    }
+
+    #[test]
+    fn expand_aarch64_muls() {
+        let impl_block = super::aarch64::generate_impl(4, true);
+        println!("{}", impl_block);
+    }
 }
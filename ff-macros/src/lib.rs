@@ -12,13 +12,14 @@ use proc_macro::TokenStream;
 use syn::{Expr, ExprLit, Item, ItemFn, Lit, Meta};
 
 mod montgomery;
+mod tower;
 mod unroll;
 
 pub(crate) mod utils;
 
 #[proc_macro]
 pub fn to_sign_and_limbs(input: TokenStream) -> TokenStream {
-    let num = utils::parse_string(input).expect("expected decimal string");
+    let num = utils::parse_string(input).expect("expected a string literal");
     let (is_positive, limbs) = utils::str_to_limbs(&num);
 
     let limbs: String = limbs.join(", ");
@@ -31,15 +32,31 @@ pub fn to_sign_and_limbs(input: TokenStream) -> TokenStream {
 ///
 /// The attributes available to this macro are
 /// * `modulus`: Specify the prime modulus underlying this prime field.
-/// * `generator`: Specify the generator of the multiplicative subgroup of this
-///   prime field. This value must be a quadratic non-residue in the field.
+/// * `generator` (optional): Specify the generator of the multiplicative
+///   subgroup of this prime field. This value must be a quadratic non-residue
+///   in the field, and, if `small_subgroup_base` is specified, must not be a
+///   `small_subgroup_base`-th power. If omitted, the smallest integer
+///   satisfying these conditions is found at compile time.
 /// * `small_subgroup_base` and `small_subgroup_power` (optional): If the field
 ///   has insufficient two-adicity, specify an additional subgroup of size
 ///   `small_subgroup_base.pow(small_subgroup_power)`.
+/// * `inversion_strategy` (optional): Specify the `InversionStrategy` used
+///   to invert field elements, either `"BinaryEuclid"` (the default) or
+///   `"BernsteinYang"`.
+///
+/// For fields whose two-adicity is larger than 6 (and at most 64), the derived
+/// `SQRT_PRECOMP` uses `SqrtPrecomputation::TonelliShanksWithTables`, with the
+/// tables computed at compile time.
 // This code was adapted from the `PrimeField` Derive Macro in ff-derive.
 #[proc_macro_derive(
     MontConfig,
-    attributes(modulus, generator, small_subgroup_base, small_subgroup_power)
+    attributes(
+        modulus,
+        generator,
+        small_subgroup_base,
+        small_subgroup_power,
+        inversion_strategy
+    )
 )]
 pub fn mont_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     // Parse the type definition
@@ -51,29 +68,104 @@ pub fn mont_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .parse()
         .expect("Modulus should be a number");
 
-    // We may be provided with a generator of p - 1 order. It is required that this
-    // generator be quadratic nonresidue.
-    let generator: BigUint = fetch_attr("generator", &ast.attrs)
-        .expect("Please supply a generator attribute")
-        .parse()
-        .expect("Generator should be a number");
-
     let small_subgroup_base: Option<u32> = fetch_attr("small_subgroup_base", &ast.attrs)
         .map(|s| s.parse().expect("small_subgroup_base should be a number"));
 
     let small_subgroup_power: Option<u32> = fetch_attr("small_subgroup_power", &ast.attrs)
         .map(|s| s.parse().expect("small_subgroup_power should be a number"));
 
+    let inversion_strategy = fetch_attr("inversion_strategy", &ast.attrs).map(|s| {
+        assert!(
+            s == "BinaryEuclid" || s == "BernsteinYang",
+            "inversion_strategy should be either \"BinaryEuclid\" or \"BernsteinYang\""
+        );
+        syn::Ident::new(&s, proc_macro2::Span::call_site())
+    });
+
+    // We may be provided with a generator of p - 1 order. It is required that this
+    // generator be quadratic nonresidue. Otherwise, we search for one.
+    let generator: BigUint = match fetch_attr("generator", &ast.attrs) {
+        Some(generator) => {
+            let generator = generator.parse().expect("Generator should be a number");
+            assert!(
+                montgomery::is_suitable_generator(&generator, &modulus, small_subgroup_base),
+                "The generator should be a quadratic non-residue, and not a \
+                 `small_subgroup_base`-th power if `small_subgroup_base` is specified"
+            );
+            generator
+        },
+        None => montgomery::find_generator(&modulus, small_subgroup_base),
+    };
+
     montgomery::mont_config_helper(
         modulus,
         generator,
         small_subgroup_base,
         small_subgroup_power,
+        inversion_strategy,
         ast.ident,
     )
     .into()
 }
 
+/// Derive the `Fp2Config` trait from a quadratic non-residue.
+///
+/// The attributes available to this macro are
+/// * `fp`: Specify the prime field underlying the extension, which must use
+///   the Montgomery backend.
+/// * `nonresidue`: Specify the quadratic non-residue used to construct the
+///   extension, in the format accepted by `MontFp!`.
+///
+/// The Frobenius coefficients are computed at compile time, and the
+/// multiplication by the non-residue is specialized when it is `-1`.
+#[proc_macro_derive(Fp2Config, attributes(fp, nonresidue))]
+pub fn fp2_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let fp = fetch_attr("fp", &ast.attrs).expect("Please supply a fp attribute");
+    let nonresidue =
+        fetch_attr("nonresidue", &ast.attrs).expect("Please supply a nonresidue attribute");
+    tower::fp2_config_helper(&fp, &nonresidue, ast.ident).into()
+}
+
+/// Derive the `Fp6Config` trait from a cubic non-residue.
+///
+/// The attributes available to this macro are
+/// * `fp2_config`: Specify the `Fp2Config` of the field underlying the
+///   extension.
+/// * `nonresidue_c0` and `nonresidue_c1`: Specify the coefficients of the
+///   cubic non-residue `c0 + c1 * u` used to construct the extension, in the
+///   format accepted by `MontFp!`.
+///
+/// The Frobenius coefficients are computed at compile time, and the
+/// multiplication by the non-residue is specialized when it is `1 + u` or
+/// `u`. The type must also implement `Clone` and `Copy`.
+#[proc_macro_derive(Fp6Config, attributes(fp2_config, nonresidue_c0, nonresidue_c1))]
+pub fn fp6_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let fp2_config =
+        fetch_attr("fp2_config", &ast.attrs).expect("Please supply a fp2_config attribute");
+    let nonresidue_c0 =
+        fetch_attr("nonresidue_c0", &ast.attrs).expect("Please supply a nonresidue_c0 attribute");
+    let nonresidue_c1 =
+        fetch_attr("nonresidue_c1", &ast.attrs).expect("Please supply a nonresidue_c1 attribute");
+    tower::fp6_config_helper(&fp2_config, &nonresidue_c0, &nonresidue_c1, ast.ident).into()
+}
+
+/// Derive the `Fp12Config` trait for a quadratic extension of an `Fp6` with
+/// the non-residue `v`.
+///
+/// The only attribute available to this macro is `fp6_config`, which
+/// specifies the `Fp6Config` of the field underlying the extension. The
+/// Frobenius coefficients are computed at compile time. The type must also
+/// implement `Clone` and `Copy`.
+#[proc_macro_derive(Fp12Config, attributes(fp6_config))]
+pub fn fp12_config(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let fp6_config =
+        fetch_attr("fp6_config", &ast.attrs).expect("Please supply a fp6_config attribute");
+    tower::fp12_config_helper(&fp6_config, ast.ident).into()
+}
+
 const ARG_MSG: &str = "Failed to parse unroll threshold; must be a positive integer";
 
 /// Attribute used to unroll for loops found inside a function block.
@@ -130,6 +222,42 @@ fn fetch_attr(name: &str, attrs: &[syn::Attribute]) -> Option<String> {
     None
 }
 
+#[test]
+fn test_find_generator() {
+    let modulus = BigUint::from(17u8);
+    assert_eq!(
+        montgomery::find_generator(&modulus, None),
+        BigUint::from(3u8)
+    );
+
+    // BLS12-381's scalar field, whose specified generator is 7.
+    let modulus: BigUint =
+        "52435875175126190479447740508185965837690552500527637822603658699938581184513"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        montgomery::find_generator(&modulus, None),
+        BigUint::from(5u8)
+    );
+    assert!(montgomery::is_suitable_generator(
+        &BigUint::from(7u8),
+        &modulus,
+        None
+    ));
+    assert!(!montgomery::is_suitable_generator(
+        &BigUint::from(4u8),
+        &modulus,
+        None
+    ));
+    // `p - 1` is divisible by 3, and 5 is a cube but 7 is not.
+    let five = BigUint::from(5u8);
+    assert!(!montgomery::is_suitable_generator(&five, &modulus, Some(3)));
+    assert_eq!(
+        montgomery::find_generator(&modulus, Some(3)),
+        BigUint::from(7u8)
+    );
+}
+
 #[test]
 fn test_str_to_limbs() {
     use num_bigint::Sign::*;
@@ -198,3 +326,33 @@ fn test_str_to_limbs() {
     ];
     assert_eq!(&limbs, &expected_limbs);
 }
+
+#[test]
+fn test_eval_expr() {
+    use num_bigint::BigInt;
+
+    let cases = [
+        ("2^127 - 1", (BigInt::from(1) << 127) - 1),
+        ("-0x1f", BigInt::from(-31)),
+        ("0b101 * 3", BigInt::from(15)),
+        ("-2^2", BigInt::from(-4)),
+        ("2^3^2", BigInt::from(512)),
+        ("(1 + 2) * -3", BigInt::from(-9)),
+        (" 0o17 + 1 ", BigInt::from(16)),
+        ("10 - 2 - 3", BigInt::from(5)),
+        ("2 * 3^2", BigInt::from(18)),
+    ];
+    for (expr, expected) in cases {
+        assert_eq!(utils::eval_expr(expr), expected, "{expr}");
+    }
+    for expr in ["", "2^", "1 +", "(1", "0x", "1 2", "2^-1", "abc"] {
+        assert!(
+            std::panic::catch_unwind(|| utils::eval_expr(expr)).is_err(),
+            "{expr}"
+        );
+    }
+
+    let (is_positive, limbs) = utils::str_to_limbs("-(2^64 + 3)");
+    assert!(!is_positive);
+    assert_eq!(&limbs, &["3u64".to_string(), "1u64".to_string()]);
+}
@@ -19,13 +19,45 @@ use square::square_in_place_impl;
 mod sum_of_products;
 use sum_of_products::sum_of_products_impl;
 
+mod sqrt;
+use sqrt::sqrt_precomp_impl;
+
 use crate::utils;
 
+/// Returns whether `generator` is a quadratic non-residue modulo `modulus`,
+/// and, if `small_subgroup_base` is specified, not a `small_subgroup_base`-th
+/// power, so that it generates both the 2-adic and the small subgroup.
+pub fn is_suitable_generator(
+    generator: &BigUint,
+    modulus: &BigUint,
+    small_subgroup_base: Option<u32>,
+) -> bool {
+    let modulus_minus_one = modulus - BigUint::one();
+    let is_non_residue =
+        |base: u32| generator.modpow(&(&modulus_minus_one / base), modulus) != BigUint::one();
+    is_non_residue(2) && small_subgroup_base.into_iter().all(is_non_residue)
+}
+
+/// Returns the smallest integer larger than 1 that satisfies
+/// [`is_suitable_generator`].
+pub fn find_generator(modulus: &BigUint, small_subgroup_base: Option<u32>) -> BigUint {
+    let mut generator = BigUint::from(2u8);
+    while !is_suitable_generator(&generator, modulus, small_subgroup_base) {
+        generator += 1u8;
+        assert!(
+            &generator < modulus,
+            "No suitable generator exists for this modulus"
+        );
+    }
+    generator
+}
+
 pub fn mont_config_helper(
     modulus: BigUint,
     generator: BigUint,
     small_subgroup_base: Option<u32>,
     small_subgroup_power: Option<u32>,
+    inversion_strategy: Option<proc_macro2::Ident>,
     config_name: proc_macro2::Ident,
 ) -> proc_macro2::TokenStream {
     let mut limbs = 1usize;
@@ -39,8 +71,10 @@ pub fn mont_config_helper(
 
     // modulus - 1 = 2^s * t
     let mut trace = &modulus - BigUint::from_str("1").unwrap();
+    let mut two_adicity = 0u32;
     while !trace.bit(0) {
         trace >>= 1u8;
+        two_adicity += 1;
     }
 
     // Compute 2^s root of unity given the generator
@@ -50,6 +84,27 @@ pub fn mont_config_helper(
         (..) => panic!("Must specify both `small_subgroup_base` and `small_subgroup_power`"),
     };
     let two_adic_root_of_unity = generator.modpow(&trace, &modulus);
+    let sqrt_precomp = sqrt_precomp_impl(
+        &modulus,
+        two_adicity,
+        &trace,
+        &two_adic_root_of_unity,
+        limbs,
+    );
+    // The chain `w_0, ..., w_s` of 2-adic roots of unity, where `w_s` is
+    // `two_adic_root_of_unity` and `w_(k - 1) = w_k^2`, and their inverses.
+    let root_chain = |root: BigUint| {
+        let mut chain = vec![root];
+        for _ in 0..two_adicity {
+            let next = chain.last().unwrap().modpow(&BigUint::from(2u8), &modulus);
+            chain.push(next);
+        }
+        chain.reverse();
+        chain.iter().map(|w| w.to_string()).collect::<Vec<_>>()
+    };
+    let two_adic_roots_of_unity = root_chain(two_adic_root_of_unity.clone());
+    let two_adic_roots_of_unity_inv =
+        root_chain(two_adic_root_of_unity.modpow(&(&modulus - BigUint::from(2u8)), &modulus));
     let large_subgroup_generator = remaining_subgroup_size
         .as_ref()
         .map(|e| generator.modpow(e, &modulus).to_string());
@@ -99,6 +154,12 @@ pub fn mont_config_helper(
         quote::quote! {}
     };
 
+    let inversion_strategy = inversion_strategy.map(|strategy| {
+        quote::quote! {
+            const INVERSION_STRATEGY: InversionStrategy = InversionStrategy::#strategy;
+        }
+    });
+
     quote::quote! {
         const _: () = {
             use ark_ff::{fields::Fp, BigInt, BigInteger, biginteger::arithmetic as fa, fields::*};
@@ -113,8 +174,20 @@ pub fn mont_config_helper(
 
                 const TWO_ADIC_ROOT_OF_UNITY: F = ark_ff::MontFp!(#two_adic_root_of_unity);
 
+                const TWO_ADIC_ROOTS_OF_UNITY: &'static [F] = &[
+                    #( ark_ff::MontFp!(#two_adic_roots_of_unity) ),*
+                ];
+
+                const TWO_ADIC_ROOTS_OF_UNITY_INV: &'static [F] = &[
+                    #( ark_ff::MontFp!(#two_adic_roots_of_unity_inv) ),*
+                ];
+
                 #mixed_radix
 
+                #inversion_strategy
+
+                #sqrt_precomp
+
                 #[inline(always)]
                 fn add_assign(a: &mut F, b: &F) {
                     #add_assign
@@ -0,0 +1,82 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+/// The number of bits of the discrete logarithm recovered per table lookup
+/// by `SqrtPrecomputation::TonelliShanksWithTables`. Each table holds
+/// `2^SQRT_WINDOW_SIZE` elements.
+const SQRT_WINDOW_SIZE: u32 = 6;
+
+/// Returns the definition of `SQRT_PRECOMP` using
+/// `SqrtPrecomputation::TonelliShanksWithTables`, or nothing if the default
+/// precomputation should be used instead. Tables are only worth it when the
+/// two-adicity spans more than a single window; they are not supported for
+/// two-adicities larger than 64.
+pub(super) fn sqrt_precomp_impl(
+    modulus: &BigUint,
+    two_adicity: u32,
+    trace: &BigUint,
+    two_adic_root_of_unity: &BigUint,
+    limbs: usize,
+) -> proc_macro2::TokenStream {
+    let w = SQRT_WINDOW_SIZE;
+    if two_adicity <= w || two_adicity > 64 {
+        return quote::quote! {};
+    }
+    let g = two_adic_root_of_unity;
+    // `g` must generate the subgroup of order `2^two_adicity`, which is the
+    // case if the generator is a quadratic non-residue.
+    let minus_one = modulus - 1u8;
+    if g.modpow(&(BigUint::one() << (two_adicity - 1)), modulus) != minus_one {
+        return quote::quote! {};
+    }
+
+    let r = (BigUint::one() << (64 * limbs)) % modulus;
+    let to_montgomery = |x: &BigUint| {
+        let mut digits = (x * &r % modulus).to_u64_digits();
+        digits.resize(limbs, 0);
+        quote::quote! { F::new_unchecked(BigInt([ #( #digits ),* ])) }
+    };
+    let g_inv = g.modpow(&(modulus - 2u8), modulus);
+
+    // `tables[i][d] = g^(-d * 2^(i * w))`.
+    let num_windows = two_adicity.div_ceil(w);
+    let tables = (0..num_windows).map(|i| {
+        let base = g_inv.modpow(&(BigUint::one() << (i * w)), modulus);
+        let mut power = BigUint::one();
+        let entries = (0..1u32 << w).map(|_| {
+            let entry = to_montgomery(&power);
+            power = &power * &base % modulus;
+            entry
+        });
+        quote::quote! { &[ #( #entries ),* ] }
+    });
+
+    // Pairs `(key(g^(d * 2^(two_adicity - w))), d)`, sorted by key, where the
+    // key is the lowest limb of the canonical representation.
+    let base = g.modpow(&(BigUint::one() << (two_adicity - w)), modulus);
+    let mut power = BigUint::one();
+    let mut discrete_log_table = Vec::new();
+    for d in 0..1u32 << w {
+        let key = power.iter_u64_digits().next().unwrap_or(0);
+        discrete_log_table.push((key, d));
+        power = &power * &base % modulus;
+    }
+    discrete_log_table.sort_unstable();
+    if discrete_log_table.windows(2).any(|p| p[0].0 == p[1].0) {
+        return quote::quote! {};
+    }
+    let (keys, digits): (Vec<_>, Vec<_>) = discrete_log_table.into_iter().unzip();
+
+    let trace_minus_one_div_two = (trace >> 1u8).to_u64_digits();
+
+    quote::quote! {
+        const SQRT_PRECOMP: Option<SqrtPrecomputation<F>> =
+            Some(SqrtPrecomputation::TonelliShanksWithTables {
+                two_adicity: #two_adicity,
+                window_size: #w,
+                trace_of_modulus_minus_one_div_two: &[ #( #trace_minus_one_div_two ),* ],
+                tables: &[ #( #tables ),* ],
+                discrete_log_table: &[ #( (#keys, #digits) ),* ],
+            });
+    }
+}
@@ -0,0 +1,166 @@
+use num_bigint::BigInt;
+use quote::quote;
+use syn::Type;
+
+use crate::utils;
+
+/// Parses the value of a type attribute.
+fn parse_type(ty: &str, attr: &str) -> Type {
+    syn::parse_str(ty).unwrap_or_else(|_| panic!("attribute {attr} should be a type"))
+}
+
+/// Returns whether the constant expression `expr` evaluates to `value`.
+fn evaluates_to(expr: &str, value: i64) -> bool {
+    utils::eval_expr(expr) == BigInt::from(value)
+}
+
+pub fn fp2_config_helper(
+    fp: &str,
+    nonresidue: &str,
+    config_name: proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    let fp = parse_type(fp, "fp");
+
+    // When the non-residue is -1, multiplying by it is a negation.
+    let specializations = if evaluates_to(nonresidue, -1) {
+        quote! {
+            #[inline(always)]
+            fn mul_fp_by_nonresidue_in_place(fe: &mut Self::Fp) -> &mut Self::Fp {
+                ark_ff::AdditiveGroup::neg_in_place(fe)
+            }
+
+            #[inline(always)]
+            fn mul_fp_by_nonresidue_and_add(y: &mut Self::Fp, x: &Self::Fp) {
+                ark_ff::AdditiveGroup::neg_in_place(y);
+                *y += x;
+            }
+
+            #[inline(always)]
+            fn mul_fp_by_nonresidue_plus_one_and_add(y: &mut Self::Fp, x: &Self::Fp) {
+                *y = *x;
+            }
+
+            #[inline(always)]
+            fn sub_and_mul_fp_by_nonresidue(y: &mut Self::Fp, x: &Self::Fp) {
+                *y += x;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        const _: () = {
+            use ark_ff::fields::*;
+
+            #[automatically_derived]
+            impl Fp2Config for #config_name {
+                type Fp = #fp;
+
+                const NONRESIDUE: #fp = ark_ff::MontFp!(#nonresidue);
+
+                const FROBENIUS_COEFF_FP2_C1: &'static [#fp] =
+                    &fp2_frobenius_coeffs(Self::NONRESIDUE);
+
+                #specializations
+            }
+        };
+    }
+}
+
+pub fn fp6_config_helper(
+    fp2_config: &str,
+    nonresidue_c0: &str,
+    nonresidue_c1: &str,
+    config_name: proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    let fp2_config = parse_type(fp2_config, "fp2_config");
+
+    // Multiplying `c0 + c1 * u` by `1 + u` or by `u` only takes additions and
+    // a multiplication by the quadratic non-residue `u^2`.
+    let specializations = if !evaluates_to(nonresidue_c1, 1) {
+        quote! {}
+    } else if evaluates_to(nonresidue_c0, 1) {
+        quote! {
+            #[inline(always)]
+            fn mul_fp2_by_nonresidue_in_place(
+                fe: &mut Fp2<Self::Fp2Config>,
+            ) -> &mut Fp2<Self::Fp2Config> {
+                // (c0 + c1 * u) * (1 + u) = (c0 + c1 * u^2) + (c0 + c1) * u
+                let mut c0 = fe.c1;
+                <#fp2_config as Fp2Config>::mul_fp_by_nonresidue_and_add(&mut c0, &fe.c0);
+                fe.c1 += &fe.c0;
+                fe.c0 = c0;
+                fe
+            }
+        }
+    } else if evaluates_to(nonresidue_c0, 0) {
+        quote! {
+            #[inline(always)]
+            fn mul_fp2_by_nonresidue_in_place(
+                fe: &mut Fp2<Self::Fp2Config>,
+            ) -> &mut Fp2<Self::Fp2Config> {
+                // (c0 + c1 * u) * u = c1 * u^2 + c0 * u
+                let mut c0 = fe.c1;
+                <#fp2_config as Fp2Config>::mul_fp_by_nonresidue_in_place(&mut c0);
+                fe.c1 = fe.c0;
+                fe.c0 = c0;
+                fe
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        const _: () = {
+            use ark_ff::fields::*;
+
+            #[automatically_derived]
+            impl Fp6Config for #config_name {
+                type Fp2Config = #fp2_config;
+
+                const NONRESIDUE: Fp2<#fp2_config> =
+                    Fp2::<#fp2_config>::new(ark_ff::MontFp!(#nonresidue_c0), ark_ff::MontFp!(#nonresidue_c1));
+
+                const FROBENIUS_COEFF_FP6_C1: &'static [Fp2<#fp2_config>] =
+                    &fp6_frobenius_coeffs_c1(Self::NONRESIDUE);
+
+                const FROBENIUS_COEFF_FP6_C2: &'static [Fp2<#fp2_config>] =
+                    &fp6_frobenius_coeffs_c2(Self::NONRESIDUE);
+
+                #specializations
+            }
+        };
+    }
+}
+
+pub fn fp12_config_helper(
+    fp6_config: &str,
+    config_name: proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    let fp6_config = parse_type(fp6_config, "fp6_config");
+
+    quote! {
+        const _: () = {
+            use ark_ff::{fields::*, AdditiveGroup};
+
+            type Fp2ConfigOf = <#fp6_config as Fp6Config>::Fp2Config;
+
+            #[automatically_derived]
+            impl Fp12Config for #config_name {
+                type Fp6Config = #fp6_config;
+
+                const NONRESIDUE: Fp6<#fp6_config> =
+                    Fp6::<#fp6_config>::new(
+                        Fp2::<Fp2ConfigOf>::ZERO,
+                        Fp2::<Fp2ConfigOf>::ONE,
+                        Fp2::<Fp2ConfigOf>::ZERO,
+                    );
+
+                const FROBENIUS_COEFF_FP12_C1: &'static [Fp2<Fp2ConfigOf>] =
+                    &fp12_frobenius_coeffs_c1(<#fp6_config as Fp6Config>::NONRESIDUE);
+            }
+        };
+    }
+}
@@ -1,7 +1,10 @@
-use std::str::FromStr;
+use std::{
+    iter::Peekable,
+    str::{CharIndices, FromStr},
+};
 
 use num_bigint::{BigInt, Sign};
-use num_traits::Num;
+use num_traits::{Num, ToPrimitive};
 use proc_macro::TokenStream;
 use syn::{Expr, Lit};
 
@@ -27,23 +30,7 @@ pub fn str_to_limbs(num: &str) -> (bool, Vec<String>) {
 }
 
 pub fn str_to_limbs_u64(num: &str) -> (bool, Vec<u64>) {
-    let is_negative = num.starts_with('-');
-    let num = if is_negative { &num[1..] } else { num };
-    let number = if num.starts_with("0x") || num.starts_with("0X") {
-        // We are in hexadecimal
-        BigInt::from_str_radix(&num[2..], 16)
-    } else if num.starts_with("0o") || num.starts_with("0O") {
-        // We are in octal
-        BigInt::from_str_radix(&num[2..], 8)
-    } else if num.starts_with("0b") || num.starts_with("0B") {
-        // We are in binary
-        BigInt::from_str_radix(&num[2..], 2)
-    } else {
-        // We are in decimal
-        BigInt::from_str(num)
-    }
-    .expect("could not parse to bigint");
-    let number = if is_negative { -number } else { number };
+    let number = eval_expr(num);
     let (sign, digits) = number.to_radix_le(16);
 
     let limbs = digits
@@ -60,3 +47,137 @@ pub fn str_to_limbs_u64(num: &str) -> (bool, Vec<u64>) {
     let sign_is_positive = sign != Sign::Minus;
     (sign_is_positive, limbs)
 }
+
+/// Evaluates a constant integer expression, made of integer literals in
+/// decimal, or in hexadecimal, octal or binary with a `0x`, `0o` or `0b`
+/// prefix, combined with `+`, `-`, `*`, `^` (exponentiation) and parentheses.
+///
+/// Panics if the expression is malformed.
+pub fn eval_expr(expr: &str) -> BigInt {
+    let mut parser = ExprParser {
+        expr,
+        chars: expr.char_indices().peekable(),
+    };
+    let value = parser.sum();
+    if let Some((_, c)) = parser.next_token() {
+        panic!("unexpected character `{c}` in `{expr}`");
+    }
+    value
+}
+
+/// A recursive descent parser for the expressions accepted by [`eval_expr`],
+/// which evaluates the expression as it is parsed.
+struct ExprParser<'a> {
+    expr: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl ExprParser<'_> {
+    /// Skips whitespace and returns the next character, without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    /// Skips whitespace and consumes the next character.
+    fn next_token(&mut self) -> Option<(usize, char)> {
+        self.peek();
+        self.chars.next()
+    }
+
+    /// `sum := product (('+' | '-') product)*`
+    fn sum(&mut self) -> BigInt {
+        let mut value = self.product();
+        loop {
+            match self.peek() {
+                Some('+') => {
+                    self.chars.next();
+                    value += self.product();
+                },
+                Some('-') => {
+                    self.chars.next();
+                    value -= self.product();
+                },
+                _ => return value,
+            }
+        }
+    }
+
+    /// `product := unary ('*' unary)*`
+    fn product(&mut self) -> BigInt {
+        let mut value = self.unary();
+        while self.peek() == Some('*') {
+            self.chars.next();
+            value *= self.unary();
+        }
+        value
+    }
+
+    /// `unary := ('-' | '+') unary | power`
+    fn unary(&mut self) -> BigInt {
+        match self.peek() {
+            Some('-') => {
+                self.chars.next();
+                -self.unary()
+            },
+            Some('+') => {
+                self.chars.next();
+                self.unary()
+            },
+            _ => self.power(),
+        }
+    }
+
+    /// `power := atom ('^' unary)?`, so that `-2^2 = -4` and `2^3^2 = 2^9`.
+    fn power(&mut self) -> BigInt {
+        let base = self.atom();
+        if self.peek() != Some('^') {
+            return base;
+        }
+        self.chars.next();
+        let exp = self.unary();
+        let exp = exp
+            .to_u32()
+            .unwrap_or_else(|| panic!("invalid exponent `{exp}` in `{}`", self.expr));
+        base.pow(exp)
+    }
+
+    /// `atom := literal | '(' sum ')'`
+    fn atom(&mut self) -> BigInt {
+        match self.next_token() {
+            Some((_, '(')) => {
+                let value = self.sum();
+                match self.next_token() {
+                    Some((_, ')')) => value,
+                    _ => panic!("missing `)` in `{}`", self.expr),
+                }
+            },
+            Some((start, c)) if c.is_ascii_digit() => {
+                let mut end = start + 1;
+                while let Some((i, _)) = self.chars.next_if(|(_, c)| c.is_ascii_alphanumeric()) {
+                    end = i + 1;
+                }
+                parse_literal(&self.expr[start..end]).unwrap_or_else(|| {
+                    panic!("could not parse `{}` to bigint", &self.expr[start..end])
+                })
+            },
+            _ => panic!("expected an integer in `{}`", self.expr),
+        }
+    }
+}
+
+/// Parses an unsigned integer literal in decimal, or in hexadecimal, octal or
+/// binary with a `0x`, `0o` or `0b` prefix.
+fn parse_literal(literal: &str) -> Option<BigInt> {
+    let (digits, radix) = match literal.get(..2) {
+        Some("0x" | "0X") => (&literal[2..], 16),
+        Some("0o" | "0O") => (&literal[2..], 8),
+        Some("0b" | "0B") => (&literal[2..], 2),
+        _ => (literal, 10),
+    };
+    if radix == 10 {
+        BigInt::from_str(digits).ok()
+    } else {
+        BigInt::from_str_radix(digits, radix).ok()
+    }
+}
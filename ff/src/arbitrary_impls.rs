@@ -0,0 +1,104 @@
+//! [`arbitrary`] support for big integers and field elements, enabled by the
+//! `arbitrary` feature.
+//!
+//! Prime field elements are zero, one or minus one with small probability,
+//! to exercise edge cases. Otherwise, they are read as integers of
+//! `MODULUS_BIT_SIZE` bits, and integers that are not smaller than the
+//! modulus are rejected. Elements of extension fields are built from
+//! arbitrary coefficients.
+use crate::{
+    AdditiveGroup, BigInt, CubicExtConfig, CubicExtField, Field, Fp, FpConfig, PrimeField,
+    QuadExtConfig, QuadExtField,
+};
+use arbitrary::{size_hint, Arbitrary, Error, Result, Unstructured};
+
+impl<'a, const N: usize> Arbitrary<'a> for BigInt<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u64; N]>::size_hint(depth)
+    }
+}
+
+impl<'a, P: FpConfig<N>, const N: usize> Arbitrary<'a> for Fp<P, N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        match u.int_in_range(0..=15u8)? {
+            0 => Ok(Self::ZERO),
+            1 => Ok(Self::ONE),
+            2 => Ok(-Self::ONE),
+            _ => {
+                let mut repr = BigInt::<N>::arbitrary(u)?;
+                let shave_bits = 64 * N as u32 - Self::MODULUS_BIT_SIZE;
+                repr.0[N - 1] &= u64::MAX.checked_shr(shave_bits).unwrap_or(0);
+                Self::from_bigint(repr).ok_or(Error::IncorrectFormat)
+            },
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(u8::size_hint(depth), BigInt::<N>::size_hint(depth))
+    }
+}
+
+impl<'a, P: QuadExtConfig> Arbitrary<'a> for QuadExtField<P>
+where
+    P::BaseField: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let coeff = P::BaseField::size_hint(depth);
+        size_hint::and(coeff, coeff)
+    }
+}
+
+impl<'a, P: CubicExtConfig> Arbitrary<'a> for CubicExtField<P>
+where
+    P::BaseField: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let coeff = P::BaseField::size_hint(depth);
+        size_hint::and_all(&[coeff, coeff, coeff])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use ark_std::{rand::RngCore, test_rng, vec};
+    use ark_test_curves::{
+        ark_ff::{BigInt, One, PrimeField, Zero},
+        bls12_381::{Fq, Fq12, Fq2},
+    };
+
+    #[test]
+    fn test_arbitrary() {
+        let mut bytes = vec![0u8; 1 << 16];
+        test_rng().fill_bytes(&mut bytes);
+        let mut u = Unstructured::new(&bytes);
+        let (mut zeros, mut ones, mut minus_ones, mut rejected) = (0, 0, 0, 0);
+        for _ in 0..200 {
+            match Fq::arbitrary(&mut u) {
+                Ok(a) if a.is_zero() => zeros += 1,
+                Ok(a) if a.is_one() => ones += 1,
+                Ok(a) if (-a).is_one() => minus_ones += 1,
+                Ok(a) => assert!(a.into_bigint() < Fq::MODULUS),
+                Err(_) => rejected += 1,
+            }
+        }
+        assert!(zeros > 0 && ones > 0 && minus_ones > 0 && rejected > 0);
+
+        let elems = (0..100).filter_map(|_| Fq12::arbitrary(&mut u).ok());
+        assert!(elems.count() > 0);
+        assert_eq!(Fq2::size_hint(0).0, 2 * Fq::size_hint(0).0);
+        assert_eq!(BigInt::<4>::size_hint(0), (32, Some(32)));
+    }
+}
@@ -24,6 +24,11 @@ use ark_std::{
     vec::*,
 };
 use num_bigint::BigUint;
+use num_traits::Num;
+#[cfg(feature = "ct")]
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
 use zeroize::Zeroize;
 
 #[macro_use]
@@ -233,8 +238,44 @@ impl<const N: usize> BigInt<N> {
         ((N - 1) * 64) as u32 + (64 - self.0[N - 1].leading_zeros())
     }
 
+    /// Computes the double-width product of `self` and `other`, returned as
+    /// its low and high halves `(lo, hi)`, so that
+    /// `self * other = hi * 2^(64 * N) + lo`.
+    ///
+    /// See also [`BigInteger::mul_low`] and [`BigInteger::mul_high`] for
+    /// computing only one of the halves.
+    ///
+    /// # Example
+    /// ```
+    /// # use ark_ff::BigInt;
+    /// const A: BigInt<2> = BigInt::new([u64::MAX, u64::MAX]);
+    /// // (2^128 - 1)^2 = (2^128 - 2) * 2^128 + 1
+    /// const PRODUCT: (BigInt<2>, BigInt<2>) = A.widening_mul(&A);
+    /// assert_eq!(PRODUCT.0, BigInt::new([1, 0]));
+    /// assert_eq!(PRODUCT.1, BigInt::new([u64::MAX - 1, u64::MAX]));
+    /// ```
+    #[inline]
+    pub const fn widening_mul(&self, other: &Self) -> (Self, Self) {
+        let (mut lo, mut hi) = ([0u64; N], [0u64; N]);
+        const_for!((i in 0..N) {
+            let mut carry = 0;
+            const_for!((j in 0..N) {
+                let k = i + j;
+                if k >= N {
+                    hi[k - N] = mac_with_carry!(hi[k - N], self.0[i], other.0[j], &mut carry);
+                } else {
+                    lo[k] = mac_with_carry!(lo[k], self.0[i], other.0[j], &mut carry);
+                }
+            });
+            hi[i] = carry;
+        });
+        (Self(lo), Self(hi))
+    }
+
+    /// Returns `self - other` and whether the subtraction borrowed. Unlike
+    /// [`BigInteger::sub_with_borrow`], this can be used in `const` contexts.
     #[inline]
-    pub(crate) const fn const_sub_with_borrow(mut self, other: &Self) -> (Self, bool) {
+    pub const fn const_sub_with_borrow(mut self, other: &Self) -> (Self, bool) {
         let mut borrow = 0;
 
         const_for!((i in 0..N) {
@@ -244,8 +285,10 @@ impl<const N: usize> BigInt<N> {
         (self, borrow != 0)
     }
 
+    /// Returns `self + other` and whether the addition overflowed. Unlike
+    /// [`BigInteger::add_with_carry`], this can be used in `const` contexts.
     #[inline]
-    pub(crate) const fn const_add_with_carry(mut self, other: &Self) -> (Self, bool) {
+    pub const fn const_add_with_carry(mut self, other: &Self) -> (Self, bool) {
         let mut carry = 0;
 
         crate::const_for!((i in 0..N) {
@@ -289,6 +332,35 @@ impl<const N: usize> BigInt<N> {
             crate::const_helpers::R2Buffer::<N>([0u64; N], [0u64; N], 1);
         const_modulo!(two_pow_n_times_64_square, self)
     }
+
+    /// Computes the Barrett constant `floor(2^(2b) / self) - 2^b`, where `b` is
+    /// the number of bits of `self`.
+    ///
+    /// The quotient `floor(2^(2b) / self)` lies in `(2^b, 2^(b + 1))`, so its
+    /// leading bit is dropped to make the result fit into `N` limbs.
+    #[doc(hidden)]
+    pub const fn barrett_mu(&self) -> Self {
+        assert!(!self.const_is_zero());
+        let num_bits = self.const_num_bits() as usize;
+        let mut remainder = Self::zero();
+        let mut mu = Self::zero();
+        let mut i = 2 * num_bits as isize;
+        let mut carry;
+        while i >= 0 {
+            (remainder, carry) = remainder.const_mul2_with_carry();
+            if i as usize == 2 * num_bits {
+                remainder.0[0] |= 1;
+            }
+            if remainder.const_geq(self) || carry {
+                remainder = remainder.const_sub_with_borrow(self).0;
+                if (i as usize) < num_bits {
+                    mu.0[i as usize / 64] |= 1 << (i as usize % 64);
+                }
+            }
+            i -= 1;
+        }
+        mu
+    }
 }
 
 impl<const N: usize> BigInteger for BigInt<N> {
@@ -384,20 +456,7 @@ impl<const N: usize> BigInteger for BigInt<N> {
             let zero = Self::zero();
             return (zero, zero);
         }
-
-        let mut r = crate::const_helpers::MulBuffer::<N>::zeroed();
-
-        let mut carry = 0;
-
-        for i in 0..N {
-            for j in 0..N {
-                r[i + j] = mac_with_carry!(r[i + j], self.0[i], other.0[j], &mut carry);
-            }
-            r.b1[i] = carry;
-            carry = 0;
-        }
-
-        (Self(r.b0), Self(r.b1))
+        self.widening_mul(other)
     }
 
     #[inline]
@@ -589,6 +648,41 @@ impl<const N: usize> PartialOrd for BigInt<N> {
     }
 }
 
+#[cfg(feature = "ct")]
+impl<const N: usize> ConstantTimeEq for BigInt<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<const N: usize> ConditionallySelectable for BigInt<N> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut result = *a;
+        for (r, b) in result.0.iter_mut().zip(&b.0) {
+            r.conditional_assign(b, choice);
+        }
+        result
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<const N: usize> ConstantTimeGreater for BigInt<N> {
+    /// Returns `Choice(1)` if `self > other`, computed as the borrow of
+    /// `other - self`.
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let mut tmp = *other;
+        let borrow = tmp.sub_with_borrow(self);
+        Choice::from(borrow as u8)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<const N: usize> ConstantTimeLess for BigInt<N> {}
+
 impl<const N: usize> Distribution<BigInt<N>> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInt<N> {
         let mut res = [0u64; N];
@@ -655,23 +749,15 @@ impl<const N: usize> TryFrom<BigUint> for BigInt<N> {
     /// Returns `Err(())` if the bit size of `val` is more than `N * 64`.
     #[inline]
     fn try_from(val: num_bigint::BigUint) -> Result<BigInt<N>, Self::Error> {
-        let bytes = val.to_bytes_le();
-
-        if bytes.len() > N * 8 {
+        let digits = val.iter_u64_digits();
+        if digits.len() > N {
             Err(())
         } else {
             let mut limbs = [0u64; N];
-
-            bytes
-                .chunks(8)
-                .into_iter()
-                .enumerate()
-                .for_each(|(i, chunk)| {
-                    let mut chunk_padded = [0u8; 8];
-                    chunk_padded[..chunk.len()].copy_from_slice(chunk);
-                    limbs[i] = u64::from_le_bytes(chunk_padded)
-                });
-
+            limbs
+                .iter_mut()
+                .zip(digits)
+                .for_each(|(limb, digit)| *limb = digit);
             Ok(Self(limbs))
         }
     }
@@ -680,8 +766,14 @@ impl<const N: usize> TryFrom<BigUint> for BigInt<N> {
 impl<const N: usize> FromStr for BigInt<N> {
     type Err = ();
 
+    /// Parses a decimal or `0x`-prefixed hexadecimal integer, returning an
+    /// error if it does not fit in `N` limbs.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let biguint = BigUint::from_str(s).map_err(|_| ())?;
+        let biguint = match s.strip_prefix("0x") {
+            Some(hex) => BigUint::from_str_radix(hex, 16),
+            None => BigUint::from_str(s),
+        }
+        .map_err(|_| ())?;
         Self::try_from(biguint)
     }
 }
@@ -689,7 +781,11 @@ impl<const N: usize> FromStr for BigInt<N> {
 impl<const N: usize> From<BigInt<N>> for BigUint {
     #[inline]
     fn from(val: BigInt<N>) -> num_bigint::BigUint {
-        BigUint::from_bytes_le(&val.to_bytes_le())
+        let digits = val
+            .0
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32]);
+        BigUint::new(digits.collect())
     }
 }
 
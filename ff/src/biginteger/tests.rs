@@ -1,4 +1,7 @@
-use crate::{biginteger::BigInteger, UniformRand};
+use crate::{
+    biginteger::{BigInt, BigInteger},
+    UniformRand,
+};
 use num_bigint::BigUint;
 
 // Test elementary math operations for BigInteger.
@@ -279,3 +282,21 @@ fn test_biginteger832() {
     use crate::biginteger::BigInteger832 as B;
     test_biginteger(B::new([u64::MAX; 13]), B::new([0u64; 13]));
 }
+
+#[test]
+fn test_widening_mul() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..100 {
+        let a = BigInt::<4>::rand(&mut rng);
+        let b = BigInt::<4>::rand(&mut rng);
+        let (lo, hi) = a.widening_mul(&b);
+        let expected = BigUint::from(a) * BigUint::from(b);
+        assert_eq!(BigUint::from(lo) + (BigUint::from(hi) << 256), expected);
+        assert_eq!(lo, a.mul_low(&b));
+        assert_eq!(hi, a.mul_high(&b));
+    }
+    let max = BigInt::<3>::new([u64::MAX; 3]);
+    let (lo, hi) = max.widening_mul(&max);
+    assert_eq!(lo, BigInt::one());
+    assert_eq!(hi, BigInt::new([u64::MAX - 1, u64::MAX, u64::MAX]));
+}
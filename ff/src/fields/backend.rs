@@ -0,0 +1,67 @@
+use crate::{batch_inversion, vec_ops, Field};
+
+/// A backend for arithmetic on large vectors of field elements.
+///
+/// Code that spends most of its time in field-vector operations can take a
+/// backend as a parameter, so that these operations can be offloaded, e.g. to
+/// a GPU, by a backend implemented in a separate crate.
+///
+/// Every method has a default implementation that runs on the CPU, using
+/// [`vec_ops`], so that a backend only needs to override the operations it
+/// accelerates. [`CpuBackend`] uses the default implementations throughout.
+///
+/// Currently, only the pointwise arithmetic on `ark_poly::Evaluations` is
+/// available with an explicit backend, through its `*_with_backend` methods.
+/// The FFTs of `ark_poly` and the MSMs of `ark_ec` always run on the CPU: their
+/// butterflies and bucket additions are not expressed as the vector
+/// operations of this trait, so a backend has no effect on them.
+///
+/// Binary operations panic if their operands have different lengths.
+///
+/// # Example
+/// ```
+/// # use ark_ff::{CpuBackend, FieldBackend};
+/// # use ark_test_curves::bls12_381::Fr;
+/// let mut a = vec![Fr::from(2u64), Fr::from(3u64)];
+/// let b = vec![Fr::from(5u64), Fr::from(7u64)];
+/// CpuBackend.mul_assign(&mut a, &b);
+/// assert_eq!(a, vec![Fr::from(10u64), Fr::from(21u64)]);
+/// assert_eq!(CpuBackend.dot_product(&a, &b), Fr::from(197u64));
+/// ```
+pub trait FieldBackend<F: Field>: Send + Sync {
+    /// Sets `a[i] += b[i]` for all `i`.
+    fn add_assign(&self, a: &mut [F], b: &[F]) {
+        vec_ops::add_assign(a, b);
+    }
+
+    /// Sets `a[i] -= b[i]` for all `i`.
+    fn sub_assign(&self, a: &mut [F], b: &[F]) {
+        vec_ops::sub_assign(a, b);
+    }
+
+    /// Sets `a[i] *= b[i]` for all `i`.
+    fn mul_assign(&self, a: &mut [F], b: &[F]) {
+        vec_ops::mul_assign(a, b);
+    }
+
+    /// Sets `a[i] *= c` for all `i`.
+    fn scale(&self, a: &mut [F], c: &F) {
+        vec_ops::scale(a, c);
+    }
+
+    /// Sets `a[i] = a[i]^(-1)` for all `i`. Zero elements are left unchanged.
+    fn batch_inverse(&self, a: &mut [F]) {
+        batch_inversion(a);
+    }
+
+    /// Returns `sum([a_i * b_i])`.
+    fn dot_product(&self, a: &[F], b: &[F]) -> F {
+        vec_ops::inner_product(a, b)
+    }
+}
+
+/// The default [`FieldBackend`], which runs on the CPU.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuBackend;
+
+impl<F: Field> FieldBackend<F> for CpuBackend {}
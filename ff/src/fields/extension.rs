@@ -0,0 +1,111 @@
+use crate::Field;
+
+/// A field that is constructed as an extension of [`Self::BaseField`], such
+/// as [`QuadExtField`](crate::QuadExtField) and
+/// [`CubicExtField`](crate::CubicExtField).
+///
+/// Norms and traces between intermediate levels of a tower are obtained by
+/// composing the relative ones, since both are transitive: e.g. the norm of
+/// an element of `Fp12 = Fp6[w]` relative to `Fp2` is
+/// `a.relative_norm().relative_norm()`. The absolute norm and trace, down to
+/// the base prime field, are [`Field::norm`] and [`Field::trace`].
+///
+/// # Examples
+/// ```
+/// # use ark_std::{test_rng, UniformRand};
+/// # use ark_ff::{ExtensionField, Field};
+/// # use ark_test_curves::bls12_381::Fq12;
+/// let a = Fq12::rand(&mut test_rng());
+/// // The norm and trace relative to `Fq2`.
+/// let norm = a.relative_norm().relative_norm();
+/// let trace = a.relative_trace().relative_trace();
+/// assert_eq!(Field::norm(&norm), Field::norm(&a));
+/// assert_eq!(trace.trace(), a.trace());
+/// ```
+pub trait ExtensionField: Field {
+    /// The field that this field is an extension of.
+    type BaseField: Field<BasePrimeField = Self::BasePrimeField>;
+
+    /// Returns the norm of `self` relative to [`Self::BaseField`], i.e. the
+    /// product of the conjugates of `self` over [`Self::BaseField`].
+    #[must_use]
+    fn relative_norm(&self) -> Self::BaseField;
+
+    /// Returns the trace of `self` relative to [`Self::BaseField`], i.e. the
+    /// sum of the conjugates of `self` over [`Self::BaseField`].
+    #[must_use]
+    fn relative_trace(&self) -> Self::BaseField;
+}
+
+/// Computes the absolute norm of `elem`; see [`Field::norm`].
+pub(crate) fn norm<F: Field>(elem: &F) -> F::BasePrimeField {
+    let mut conjugate = *elem;
+    let mut product = *elem;
+    for _ in 1..F::extension_degree() {
+        conjugate.frobenius_map_in_place(1);
+        product *= &conjugate;
+    }
+    // The norm is fixed by the Frobenius map, so it lies in the prime field,
+    // and is the first coordinate of `product`.
+    product.to_base_prime_field_elements().next().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, vec::*, UniformRand};
+    use ark_test_curves::{
+        ark_ff::{ExtensionField, Field},
+        bls12_381::{Fq12, Fq2, Fq6},
+        mnt6_753::Fq3,
+    };
+
+    /// Checks the absolute norm and trace against the product and sum of the
+    /// conjugates.
+    fn check_norm_and_trace<F: Field>() {
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a = F::rand(rng);
+            let b = F::rand(rng);
+            let conjugates: Vec<F> = (0..F::extension_degree() as usize)
+                .map(|i| a.frobenius_map(i))
+                .collect();
+            let product: F = conjugates.iter().product();
+            let sum: F = conjugates.iter().sum();
+            assert_eq!(F::from_base_prime_field(Field::norm(&a)), product);
+            assert_eq!(F::from_base_prime_field(a.trace()), sum);
+            assert_eq!(Field::norm(&(a * b)), Field::norm(&a) * Field::norm(&b));
+            assert_eq!((a + b).trace(), a.trace() + b.trace());
+        }
+    }
+
+    /// Checks the relative norm and trace against the absolute ones.
+    fn check_relative_norm_and_trace<F: ExtensionField>() {
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a = F::rand(rng);
+            let b = F::rand(rng);
+            assert_eq!(Field::norm(&a.relative_norm()), Field::norm(&a));
+            assert_eq!(a.relative_trace().trace(), a.trace());
+            assert_eq!(
+                (a * b).relative_norm(),
+                a.relative_norm() * b.relative_norm()
+            );
+            assert_eq!(
+                (a + b).relative_trace(),
+                a.relative_trace() + b.relative_trace()
+            );
+        }
+    }
+
+    #[test]
+    fn test_norm_and_trace() {
+        check_norm_and_trace::<Fq2>();
+        check_norm_and_trace::<Fq6>();
+        check_norm_and_trace::<Fq12>();
+        check_norm_and_trace::<Fq3>();
+        check_relative_norm_and_trace::<Fq2>();
+        check_relative_norm_and_trace::<Fq6>();
+        check_relative_norm_and_trace::<Fq12>();
+        check_relative_norm_and_trace::<Fq3>();
+    }
+}
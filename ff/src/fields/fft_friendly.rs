@@ -1,3 +1,5 @@
+use ark_std::{borrow::Cow, vec::*};
+
 /// The interface for fields that are able to be used in FFTs.
 pub trait FftField: crate::Field {
     /// The generator of the multiplicative group of the field
@@ -11,6 +13,17 @@ pub trait FftField: crate::Field {
     /// 2^s root of unity computed by GENERATOR^t
     const TWO_ADIC_ROOT_OF_UNITY: Self;
 
+    /// The chain of 2-adic roots of unity `[w_0, w_1, ..., w_s]`, where
+    /// `w_s = TWO_ADIC_ROOT_OF_UNITY` and `w_(k - 1) = w_k^2`, or an empty
+    /// slice if it is not precomputed. Use
+    /// [`FftField::two_adic_roots_of_unity`] to get the chain in either case.
+    const TWO_ADIC_ROOTS_OF_UNITY: &'static [Self] = &[];
+
+    /// The inverses of the elements of `TWO_ADIC_ROOTS_OF_UNITY`, or an empty
+    /// slice if they are not precomputed. Use
+    /// [`FftField::two_adic_roots_of_unity_inv`] to get them in either case.
+    const TWO_ADIC_ROOTS_OF_UNITY_INV: &'static [Self] = &[];
+
     /// An integer `b` such that there exists a multiplicative subgroup
     /// of size `b^k` for some integer `k`.
     const SMALL_SUBGROUP_BASE: Option<u32> = None;
@@ -24,6 +37,47 @@ pub trait FftField: crate::Field {
     /// FFT.
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self> = None;
 
+    /// Returns the chain of 2-adic roots of unity `[w_0, w_1, ..., w_s]`,
+    /// where `s = TWO_ADICITY`, `w_s = TWO_ADIC_ROOT_OF_UNITY` and
+    /// `w_(k - 1) = w_k^2`, so that `w_k` is a primitive `2^k`-th root of
+    /// unity.
+    ///
+    /// This borrows `TWO_ADIC_ROOTS_OF_UNITY` if it is precomputed, and
+    /// computes the chain by repeated squaring otherwise.
+    ///
+    /// ```
+    /// # use ark_ff::{FftField, Field};
+    /// # use ark_test_curves::bls12_381::Fr;
+    /// let roots = Fr::two_adic_roots_of_unity();
+    /// assert_eq!(roots.len(), Fr::TWO_ADICITY as usize + 1);
+    /// assert_eq!(roots[3], Fr::get_root_of_unity(8).unwrap());
+    /// assert_eq!(roots[3] * Fr::two_adic_roots_of_unity_inv()[3], Fr::ONE);
+    /// ```
+    fn two_adic_roots_of_unity() -> Cow<'static, [Self]> {
+        if Self::TWO_ADIC_ROOTS_OF_UNITY.is_empty() {
+            Cow::Owned(squaring_chain(
+                Self::TWO_ADIC_ROOT_OF_UNITY,
+                Self::TWO_ADICITY,
+            ))
+        } else {
+            Cow::Borrowed(Self::TWO_ADIC_ROOTS_OF_UNITY)
+        }
+    }
+
+    /// Returns the inverses of the elements of
+    /// [`FftField::two_adic_roots_of_unity`].
+    ///
+    /// This borrows `TWO_ADIC_ROOTS_OF_UNITY_INV` if it is precomputed, and
+    /// computes the chain by repeated squaring otherwise.
+    fn two_adic_roots_of_unity_inv() -> Cow<'static, [Self]> {
+        if Self::TWO_ADIC_ROOTS_OF_UNITY_INV.is_empty() {
+            let root_inv = Self::TWO_ADIC_ROOT_OF_UNITY.inverse().unwrap();
+            Cow::Owned(squaring_chain(root_inv, Self::TWO_ADICITY))
+        } else {
+            Cow::Borrowed(Self::TWO_ADIC_ROOTS_OF_UNITY_INV)
+        }
+    }
+
     /// Returns the root of unity of order n, if one exists.
     /// If no small multiplicative subgroup is defined, this is the 2-adic root
     /// of unity of order n (for n a power of 2).
@@ -73,11 +127,26 @@ pub trait FftField: crate::Field {
 
             // Compute the generator for the multiplicative subgroup.
             // It should be 2^(log_size_of_group) root of unity.
-            omega = Self::TWO_ADIC_ROOT_OF_UNITY;
-            for _ in log_size_of_group..Self::TWO_ADICITY {
-                omega.square_in_place();
-            }
+            omega = match Self::TWO_ADIC_ROOTS_OF_UNITY.get(log_size_of_group as usize) {
+                Some(root) => *root,
+                None => {
+                    let mut omega = Self::TWO_ADIC_ROOT_OF_UNITY;
+                    for _ in log_size_of_group..Self::TWO_ADICITY {
+                        omega.square_in_place();
+                    }
+                    omega
+                },
+            };
         }
         Some(omega)
     }
 }
+
+/// Returns `[root^(2^s), ..., root^2, root]`.
+fn squaring_chain<F: crate::Field>(root: F, s: u32) -> Vec<F> {
+    let mut chain = vec![root; s as usize + 1];
+    for k in (0..s as usize).rev() {
+        chain[k] = chain[k + 1].square();
+    }
+    chain
+}
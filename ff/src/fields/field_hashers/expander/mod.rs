@@ -6,62 +6,96 @@ use core::marker::PhantomData;
 use ark_std::vec::*;
 
 use arrayvec::ArrayVec;
-use digest::{ExtendableOutput, FixedOutputReset, Update};
+use digest::{core_api::BlockSizeUser, ExtendableOutput, FixedOutputReset, Update};
 
+/// Trait for expanding a message into a uniformly random byte string, as in
+/// section 5.3 of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
 pub trait Expander {
+    /// Expands `msg` into `length` uniformly random bytes.
     fn expand(&self, msg: &[u8], length: usize) -> Vec<u8>;
 }
 const MAX_DST_LENGTH: usize = 255;
 
 const LONG_DST_PREFIX: &[u8; 17] = b"H2C-OVERSIZE-DST-";
 
-/// Implements section [5.3.3](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-16#section-5.3.3)
-/// "Using DSTs longer than 255 bytes" of the
-/// [IRTF CFRG hash-to-curve draft #16](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-16#section-5.3.3).
-pub struct DST(arrayvec::ArrayVec<u8, MAX_DST_LENGTH>);
+/// A domain separation tag (DST) for `expand_message_xmd` or
+/// `expand_message_xof`, as in section 3.1 of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+///
+/// The specification limits tags to 255 bytes. Longer tags are reduced to a
+/// hash of the tag, as in section 5.3.3 "Using DSTs longer than 255 bytes",
+/// which depends on the hash function of the expander: the expanders, and
+/// hence the field hashers and hash-to-curve built on top of them, apply this
+/// reduction automatically.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DomainSeparationTag(ArrayVec<u8, MAX_DST_LENGTH>);
+
+impl DomainSeparationTag {
+    /// Returns the tag `dst` as is, or `None` if it is longer than 255 bytes,
+    /// for protocols that do not want to rely on the reduction of long tags.
+    pub fn new(dst: &[u8]) -> Option<Self> {
+        ArrayVec::try_from(dst).ok().map(Self)
+    }
 
-impl DST {
-    pub fn new_xmd<H: FixedOutputReset + Default>(dst: &[u8]) -> DST {
-        let array = if dst.len() > MAX_DST_LENGTH {
+    /// Returns the tag used by `expand_message_xmd` with the hash function
+    /// `H` for `dst`, reducing it to `H("H2C-OVERSIZE-DST-" || dst)` if it
+    /// is longer than 255 bytes.
+    pub fn new_xmd<H: FixedOutputReset + Default>(dst: &[u8]) -> Self {
+        Self::new(dst).unwrap_or_else(|| {
             let mut long = H::default();
             long.update(&LONG_DST_PREFIX[..]);
-            long.update(&dst);
-            ArrayVec::try_from(long.finalize_fixed().as_ref()).unwrap()
-        } else {
-            ArrayVec::try_from(dst).unwrap()
-        };
-        DST(array)
+            long.update(dst);
+            Self(ArrayVec::try_from(long.finalize_fixed().as_ref()).unwrap())
+        })
     }
 
-    #[allow(dead_code)]
-    pub fn new_xof<H: ExtendableOutput + Default>(dst: &[u8], k: usize) -> DST {
-        let array = if dst.len() > MAX_DST_LENGTH {
+    /// Returns the tag used by `expand_message_xof` with the extendable-output
+    /// function `H`, for the target security level of `k` bits, for `dst`,
+    /// reducing it to `H("H2C-OVERSIZE-DST-" || dst, ceil(2 * k / 8))` if it
+    /// is longer than 255 bytes.
+    pub fn new_xof<H: ExtendableOutput + Default>(dst: &[u8], k: usize) -> Self {
+        Self::new(dst).unwrap_or_else(|| {
             let mut long = H::default();
             long.update(&LONG_DST_PREFIX[..]);
-            long.update(&dst);
+            long.update(dst);
 
             let mut new_dst = [0u8; MAX_DST_LENGTH];
             let new_dst = &mut new_dst[0..((2 * k + 7) >> 3)];
             long.finalize_xof_into(new_dst);
-            ArrayVec::try_from(&*new_dst).unwrap()
-        } else {
-            ArrayVec::try_from(dst).unwrap()
-        };
-        DST(array)
+            Self(ArrayVec::try_from(&*new_dst).unwrap())
+        })
+    }
+
+    /// Returns the bytes of the tag, of length at most 255.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_ref()
     }
 
-    pub fn update<H: Update>(&self, h: &mut H) {
+    /// Feeds `DST_prime = DST || I2OSP(len(DST), 1)` to `h`.
+    pub(super) fn update<H: Update>(&self, h: &mut H) {
         h.update(self.0.as_ref());
         // I2OSP(len,1) https://www.rfc-editor.org/rfc/rfc8017.txt
         h.update(&[self.0.len() as u8]);
     }
 }
 
-#[allow(dead_code)]
-pub(super) struct ExpanderXof<H: ExtendableOutput + Clone + Default> {
+/// The `expand_message_xof` expander from section 5.3.2 of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html), based on an
+/// extendable-output function such as SHAKE128 or SHAKE256.
+pub struct ExpanderXof<H: ExtendableOutput + Clone + Default> {
     pub(super) xofer: PhantomData<H>,
-    pub(super) dst: Vec<u8>,
-    pub(super) k: usize,
+    pub(super) dst: DomainSeparationTag,
+}
+
+impl<H: ExtendableOutput + Clone + Default> ExpanderXof<H> {
+    /// Creates an expander with the domain separation tag `dst`, for the
+    /// target security level of `k` bits.
+    pub fn new(dst: &[u8], k: usize) -> Self {
+        ExpanderXof {
+            xofer: PhantomData,
+            dst: DomainSeparationTag::new_xof::<H>(dst, k),
+        }
+    }
 }
 
 impl<H: ExtendableOutput + Clone + Default> Expander for ExpanderXof<H> {
@@ -73,17 +107,32 @@ impl<H: ExtendableOutput + Clone + Default> Expander for ExpanderXof<H> {
         let lib_str = (n as u16).to_be_bytes();
         xofer.update(&lib_str);
 
-        DST::new_xof::<H>(self.dst.as_ref(), self.k).update(&mut xofer);
+        self.dst.update(&mut xofer);
         xofer.finalize_boxed(n).into_vec()
     }
 }
 
-pub(super) struct ExpanderXmd<H: FixedOutputReset + Default + Clone> {
+/// The `expand_message_xmd` expander from section 5.3.1 of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html), based on a
+/// fixed-output hash function such as SHA-256.
+pub struct ExpanderXmd<H: FixedOutputReset + Default + Clone> {
     pub(super) hasher: PhantomData<H>,
-    pub(super) dst: Vec<u8>,
+    pub(super) dst: DomainSeparationTag,
     pub(super) block_size: usize,
 }
 
+impl<H: FixedOutputReset + BlockSizeUser + Default + Clone> ExpanderXmd<H> {
+    /// Creates an expander with the domain separation tag `dst`.
+    pub fn new(dst: &[u8]) -> Self {
+        use digest::typenum::Unsigned;
+        ExpanderXmd {
+            hasher: PhantomData,
+            dst: DomainSeparationTag::new_xmd::<H>(dst),
+            block_size: H::BlockSize::to_usize(),
+        }
+    }
+}
+
 static Z_PAD: [u8; 256] = [0u8; 256];
 
 impl<H: FixedOutputReset + Default + Clone> Expander for ExpanderXmd<H> {
@@ -97,7 +146,7 @@ impl<H: FixedOutputReset + Default + Clone> Expander for ExpanderXmd<H> {
             "The ratio of desired output to the output size of hash function is too large!"
         );
 
-        let dst_prime = DST::new_xmd::<H>(self.dst.as_ref());
+        let dst_prime = &self.dst;
         // Represent `len_in_bytes` as a 2-byte array.
         // As per I2OSP method outlined in https://tools.ietf.org/pdf/rfc8017.pdf,
         // The program should abort if integer that we're trying to convert is too large.
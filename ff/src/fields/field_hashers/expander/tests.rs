@@ -5,10 +5,9 @@ use sha3::{Shake128, Shake256};
 use std::{
     fs::{read_dir, File},
     io::BufReader,
-    marker::PhantomData,
 };
 
-use super::{Expander, ExpanderXmd, ExpanderXof};
+use super::{DomainSeparationTag, Expander, ExpanderXmd, ExpanderXof};
 
 #[derive(Debug, serde_derive::Serialize, serde_derive::Deserialize)]
 pub struct ExpanderVector {
@@ -79,7 +78,14 @@ fn do_test(data: ExpanderVector) -> Result<(), Failed> {
         _ => unimplemented!(),
     };
     let exp = get_expander(exp_id, data.dst.as_bytes(), data.k);
+    let dst = get_dst(exp_id, data.dst.as_bytes(), data.k);
     for v in data.vectors.iter() {
+        let mut dst_prime = dst.as_bytes().to_vec();
+        dst_prime.push(dst.as_bytes().len() as u8);
+        if dst_prime != hex::decode(&v.dst_prime).unwrap() {
+            return Err(format!("Expander: {}\nwrong DST_prime", data.hash).into());
+        }
+
         let len = usize::from_str_radix(v.len_in_bytes.trim_start_matches("0x"), 16).unwrap();
         let got = exp.expand(v.msg.as_bytes(), len);
         let want = hex::decode(&v.uniform_bytes).unwrap();
@@ -94,38 +100,54 @@ fn do_test(data: ExpanderVector) -> Result<(), Failed> {
     Ok(())
 }
 
-fn get_expander(id: ExpID, _dst: &[u8], k: usize) -> Box<dyn Expander> {
-    let dst = _dst.to_vec();
+fn get_expander(id: ExpID, dst: &[u8], k: usize) -> Box<dyn Expander> {
+    match id {
+        ExpID::XMD(h) => match h {
+            HashID::SHA256 => Box::new(ExpanderXmd::<Sha256>::new(dst)),
+            HashID::SHA384 => Box::new(ExpanderXmd::<Sha384>::new(dst)),
+            HashID::SHA512 => Box::new(ExpanderXmd::<Sha512>::new(dst)),
+        },
+        ExpID::XOF(x) => match x {
+            XofID::SHAKE128 => Box::new(ExpanderXof::<Shake128>::new(dst, k)),
+            XofID::SHAKE256 => Box::new(ExpanderXof::<Shake256>::new(dst, k)),
+        },
+    }
+}
 
+fn get_dst(id: ExpID, dst: &[u8], k: usize) -> DomainSeparationTag {
     match id {
         ExpID::XMD(h) => match h {
-            HashID::SHA256 => Box::new(ExpanderXmd {
-                hasher: PhantomData::<Sha256>,
-                block_size: 64,
-                dst,
-            }),
-            HashID::SHA384 => Box::new(ExpanderXmd {
-                hasher: PhantomData::<Sha384>,
-                block_size: 128,
-                dst,
-            }),
-            HashID::SHA512 => Box::new(ExpanderXmd {
-                hasher: PhantomData::<Sha512>,
-                block_size: 128,
-                dst,
-            }),
+            HashID::SHA256 => DomainSeparationTag::new_xmd::<Sha256>(dst),
+            HashID::SHA384 => DomainSeparationTag::new_xmd::<Sha384>(dst),
+            HashID::SHA512 => DomainSeparationTag::new_xmd::<Sha512>(dst),
         },
         ExpID::XOF(x) => match x {
-            XofID::SHAKE128 => Box::new(ExpanderXof {
-                xofer: PhantomData::<Shake128>,
-                k,
-                dst,
-            }),
-            XofID::SHAKE256 => Box::new(ExpanderXof {
-                xofer: PhantomData::<Shake256>,
-                k,
-                dst,
-            }),
+            XofID::SHAKE128 => DomainSeparationTag::new_xof::<Shake128>(dst, k),
+            XofID::SHAKE256 => DomainSeparationTag::new_xof::<Shake256>(dst, k),
         },
     }
 }
+
+#[test]
+fn domain_separation_tag_length() {
+    let short = [0x61; 255];
+    let long = [0x61; 256];
+    assert_eq!(DomainSeparationTag::new(&short).unwrap().as_bytes(), &short);
+    assert!(DomainSeparationTag::new(&long).is_none());
+    assert_eq!(
+        DomainSeparationTag::new_xmd::<Sha256>(&short),
+        DomainSeparationTag::new(&short).unwrap()
+    );
+    assert_eq!(
+        DomainSeparationTag::new_xmd::<Sha256>(&long)
+            .as_bytes()
+            .len(),
+        32
+    );
+    assert_eq!(
+        DomainSeparationTag::new_xof::<Shake128>(&long, 128)
+            .as_bytes()
+            .len(),
+        32
+    );
+}
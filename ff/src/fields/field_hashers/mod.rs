@@ -1,13 +1,12 @@
 mod expander;
+pub use expander::{DomainSeparationTag, Expander, ExpanderXmd, ExpanderXof};
 
-use core::marker::PhantomData;
+pub mod xmd;
+pub mod xof;
 
 use crate::{Field, PrimeField};
 
-use digest::{FixedOutputReset, XofReader};
-use expander::Expander;
-
-use self::expander::ExpanderXmd;
+use digest::{core_api::BlockSizeUser, ExtendableOutput, FixedOutputReset, XofReader};
 
 /// Trait for hashing messages to field elements.
 pub trait HashToField<F: Field>: Sized {
@@ -22,6 +21,17 @@ pub trait HashToField<F: Field>: Sized {
     fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N];
 }
 
+/// Trait for hashing messages made of elements of the field `F` to elements
+/// of `F`, as done by algebraic sponges such as Poseidon or Rescue.
+///
+/// Unlike [`HashToField`], implementors are instantiated by the caller, who
+/// supplies their parameters (round constants, MDS matrix, domain separation,
+/// ...), and are cheap to evaluate inside arithmetic circuits over `F`.
+pub trait AlgebraicHashToField<F: Field> {
+    /// Hash an arbitrary `msg` of field elements to `N` elements of `F`.
+    fn hash_elements_to_field<const N: usize>(&self, msg: &[F]) -> [F; N];
+}
+
 /// This field hasher constructs a Hash-To-Field based on a fixed-output hash function,
 /// like SHA2, SHA3 or Blake2.
 /// The implementation aims to follow the specification in [Hashing to Elliptic Curves (draft)](https://tools.ietf.org/pdf/draft-irtf-cfrg-hash-to-curve-13.pdf).
@@ -38,27 +48,24 @@ pub trait HashToField<F: Field>: Sized {
 ///
 /// assert_eq!(field_elements.len(), 2);
 /// ```
-pub struct DefaultFieldHasher<H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize = 128> {
+pub struct DefaultFieldHasher<
+    H: FixedOutputReset + BlockSizeUser + Default + Clone,
+    const SEC_PARAM: usize = 128,
+> {
     expander: ExpanderXmd<H>,
     len_per_base_elem: usize,
 }
 
-impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> HashToField<F>
-    for DefaultFieldHasher<H, SEC_PARAM>
+impl<F: Field, H: FixedOutputReset + BlockSizeUser + Default + Clone, const SEC_PARAM: usize>
+    HashToField<F> for DefaultFieldHasher<H, SEC_PARAM>
 {
     fn new(dst: &[u8]) -> Self {
         // The final output of `hash_to_field` will be an array of field
         // elements from F::BaseField, each of size `len_per_elem`.
         let len_per_base_elem = get_len_per_elem::<F, SEC_PARAM>();
 
-        let expander = ExpanderXmd {
-            hasher: PhantomData,
-            dst: dst.to_vec(),
-            block_size: len_per_base_elem,
-        };
-
         DefaultFieldHasher {
-            expander,
+            expander: ExpanderXmd::new(dst),
             len_per_base_elem,
         }
     }
@@ -71,19 +78,71 @@ impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> Ha
         let len_in_bytes = N * m * self.len_per_base_elem;
         let uniform_bytes = self.expander.expand(message, len_in_bytes);
 
-        let cb = |i| {
-            let base_prime_field_elem = |j| {
-                let elm_offset = self.len_per_base_elem * (j + i * m);
-                F::BasePrimeField::from_be_bytes_mod_order(
-                    &uniform_bytes[elm_offset..][..self.len_per_base_elem],
-                )
-            };
-            F::from_base_prime_field_elems((0..m).map(base_prime_field_elem)).unwrap()
-        };
-        ark_std::array::from_fn::<F, N, _>(cb)
+        let mut elems = field_elems_from_uniform_bytes(&uniform_bytes, self.len_per_base_elem);
+        ark_std::array::from_fn::<F, N, _>(|_| elems.next().unwrap())
     }
 }
 
+/// This field hasher constructs a Hash-To-Field based on an extendable-output
+/// function, like SHAKE128 or SHAKE256, using `expand_message_xof` from
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+///
+/// # Examples
+///
+/// ```
+/// use ark_ff::fields::field_hashers::{HashToField, XofFieldHasher};
+/// use ark_test_curves::bls12_381::Fq;
+/// use sha3::Shake128;
+///
+/// let hasher = <XofFieldHasher<Shake128> as HashToField<Fq>>::new(&[1, 2, 3]);
+/// let field_elements: [Fq; 2] = hasher.hash_to_field(b"Hello, World!");
+///
+/// assert_eq!(field_elements.len(), 2);
+/// ```
+pub struct XofFieldHasher<H: ExtendableOutput + Default + Clone, const SEC_PARAM: usize = 128> {
+    expander: ExpanderXof<H>,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, H: ExtendableOutput + Default + Clone, const SEC_PARAM: usize> HashToField<F>
+    for XofFieldHasher<H, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        XofFieldHasher {
+            expander: ExpanderXof::new(dst, SEC_PARAM),
+            len_per_base_elem: get_len_per_elem::<F, SEC_PARAM>(),
+        }
+    }
+
+    fn hash_to_field<const N: usize>(&self, message: &[u8]) -> [F; N] {
+        let m = F::extension_degree() as usize;
+        let len_in_bytes = N * m * self.len_per_base_elem;
+        let uniform_bytes = self.expander.expand(message, len_in_bytes);
+
+        let mut elems = field_elems_from_uniform_bytes(&uniform_bytes, self.len_per_base_elem);
+        ark_std::array::from_fn::<F, N, _>(|_| elems.next().unwrap())
+    }
+}
+
+/// Splits `uniform_bytes` into consecutive field elements of `F`, each made
+/// up of `F::extension_degree()` base prime field elements of
+/// `len_per_base_elem` bytes, as in steps 3 to 9 of `hash_to_field` in
+/// section 5.2 of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+fn field_elems_from_uniform_bytes<F: Field>(
+    uniform_bytes: &[u8],
+    len_per_base_elem: usize,
+) -> impl Iterator<Item = F> + '_ {
+    let m = F::extension_degree() as usize;
+    uniform_bytes
+        .chunks_exact(m * len_per_base_elem)
+        .map(move |elem_bytes| {
+            let base_prime_field_elems = elem_bytes
+                .chunks_exact(len_per_base_elem)
+                .map(F::BasePrimeField::from_be_bytes_mod_order);
+            F::from_base_prime_field_elems(base_prime_field_elems).unwrap()
+        })
+}
+
 pub fn hash_to_field<F: Field, H: XofReader, const SEC_PARAM: usize>(h: &mut H) -> F {
     // The final output of `hash_to_field` will be an array of field
     // elements from F::BaseField, each of size `len_per_elem`.
@@ -0,0 +1,83 @@
+//! Hashing to field elements using `expand_message_xmd`, independently of
+//! any curve.
+use super::{field_elems_from_uniform_bytes, get_len_per_elem, Expander, ExpanderXmd};
+use crate::Field;
+use ark_std::vec::*;
+use digest::{core_api::BlockSizeUser, FixedOutputReset};
+
+/// Hashes `msg` to `count` elements of `F`, following `hash_to_field` from
+/// section 5.2 of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+/// with `expand_message_xmd` instantiated with the hash function `H`, and
+/// security parameter `k = 128`.
+///
+/// This is useful for deriving challenges in Fiat–Shamir transcripts without
+/// going through hash-to-curve.
+///
+/// # Panics
+///
+/// Panics if the number of bytes to be produced is at least `2^16`, or
+/// larger than `255` times the output size of `H`.
+///
+/// # Example
+/// ```
+/// use ark_ff::field_hashers::xmd::hash_to_field;
+/// use ark_test_curves::bls12_381::Fr;
+/// use sha2::Sha256;
+///
+/// let challenges = hash_to_field::<Fr, Sha256>(b"transcript", b"MY-PROTOCOL-V1", 2);
+/// assert_eq!(challenges.len(), 2);
+/// ```
+pub fn hash_to_field<F: Field, H: FixedOutputReset + BlockSizeUser + Default + Clone>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Vec<F> {
+    let len_per_base_elem = get_len_per_elem::<F, 128>();
+    let len_in_bytes = count * F::extension_degree() as usize * len_per_base_elem;
+    let uniform_bytes = ExpanderXmd::<H>::new(dst).expand(msg, len_in_bytes);
+    field_elems_from_uniform_bytes(&uniform_bytes, len_per_base_elem).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{str::FromStr, string::ToString};
+    use ark_test_curves::{
+        ark_ff::field_hashers::{xmd::hash_to_field, DefaultFieldHasher, HashToField},
+        bls12_381::{Fq, Fq2},
+    };
+    use sha2::Sha256;
+
+    // Test vectors from Appendix J.9.1 of RFC 9380 (BLS12381G1_XMD:SHA-256_SSWU_RO_).
+    #[test]
+    fn test_rfc_9380_vectors() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let vectors = [
+            (
+                &b""[..],
+                "0x0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f",
+                "0x019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9",
+            ),
+            (
+                &b"abc"[..],
+                "0x0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951",
+                "0x003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139",
+            ),
+        ];
+        let parse = |hex: &str| {
+            let n = num_bigint::BigUint::parse_bytes(hex[2..].as_bytes(), 16).unwrap();
+            Fq::from_str(&n.to_string()).unwrap()
+        };
+        for (msg, u0, u1) in vectors {
+            let u = hash_to_field::<Fq, Sha256>(msg, dst, 2);
+            assert_eq!(u, [parse(u0), parse(u1)]);
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_default_field_hasher() {
+        let dst = b"test-dst";
+        let hasher = <DefaultFieldHasher<Sha256> as HashToField<Fq2>>::new(dst);
+        let expected: [Fq2; 3] = hasher.hash_to_field(b"message");
+        assert_eq!(hash_to_field::<Fq2, Sha256>(b"message", dst, 3), expected);
+    }
+}
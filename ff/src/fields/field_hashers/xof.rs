@@ -0,0 +1,63 @@
+//! Hashing to field elements using `expand_message_xof`, independently of
+//! any curve.
+use super::{field_elems_from_uniform_bytes, get_len_per_elem, Expander, ExpanderXof};
+use crate::Field;
+use ark_std::vec::*;
+use digest::ExtendableOutput;
+
+/// Hashes `msg` to `count` elements of `F`, following `hash_to_field` from
+/// section 5.2 of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+/// with `expand_message_xof` instantiated with the extendable-output function
+/// `H`, and security parameter `k = 128`.
+///
+/// # Panics
+///
+/// Panics if the number of bytes to be produced is at least `2^16`.
+///
+/// # Example
+/// ```
+/// use ark_ff::field_hashers::xof::hash_to_field;
+/// use ark_test_curves::bls12_381::Fr;
+/// use sha3::Shake128;
+///
+/// let challenges = hash_to_field::<Fr, Shake128>(b"transcript", b"MY-PROTOCOL-V1", 2);
+/// assert_eq!(challenges.len(), 2);
+/// ```
+pub fn hash_to_field<F: Field, H: ExtendableOutput + Default + Clone>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Vec<F> {
+    let len_per_base_elem = get_len_per_elem::<F, 128>();
+    let len_in_bytes = count * F::extension_degree() as usize * len_per_base_elem;
+    let uniform_bytes = ExpanderXof::<H>::new(dst, 128).expand(msg, len_in_bytes);
+    field_elems_from_uniform_bytes(&uniform_bytes, len_per_base_elem).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::{
+        ark_ff::field_hashers::{xof::hash_to_field, HashToField, XofFieldHasher},
+        bls12_381::{Fq, Fq2},
+    };
+    use sha3::{Shake128, Shake256};
+
+    #[test]
+    fn test_agrees_with_xof_field_hasher() {
+        let dst = b"test-dst";
+        let hasher = <XofFieldHasher<Shake128> as HashToField<Fq>>::new(dst);
+        let expected: [Fq; 2] = hasher.hash_to_field(b"message");
+        assert_eq!(hash_to_field::<Fq, Shake128>(b"message", dst, 2), expected);
+
+        let hasher = <XofFieldHasher<Shake256> as HashToField<Fq2>>::new(dst);
+        let expected: [Fq2; 3] = hasher.hash_to_field(b"message");
+        assert_eq!(hash_to_field::<Fq2, Shake256>(b"message", dst, 3), expected);
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let a = hash_to_field::<Fq, Shake128>(b"message", b"dst-a", 1);
+        let b = hash_to_field::<Fq, Shake128>(b"message", b"dst-b", 1);
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{fields::utils::bits_window, BitIteratorBE, Field};
+use ark_std::{cfg_iter, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Precomputed powers of a fixed base `g`, for exponentiating `g` by many
+/// exponents without performing any squarings.
+///
+/// The exponent is split into windows of `window_size` bits, and the table
+/// holds `g^(j * 2^(i * window_size))` for every window `i` and every
+/// `0 <= j < 2^window_size`, so that each exponentiation only takes one
+/// multiplication per non-zero window. This is useful for repeatedly
+/// exponentiating a fixed generator, e.g. of the target group of a pairing.
+///
+/// # Example
+/// ```
+/// # use ark_ff::{FixedBase, Field};
+/// # use ark_std::{test_rng, UniformRand};
+/// # use ark_test_curves::bls12_381::Fq2;
+/// let g = Fq2::rand(&mut test_rng());
+/// let table = FixedBase::new(g, 256, 4);
+/// assert_eq!(table.pow_with_table([5u64, 7]), Some(g.pow([5u64, 7])));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBase<F: Field> {
+    base: F,
+    max_bits: usize,
+    window_size: usize,
+    /// `table[i][j] = base^(j * 2^(i * window_size))`.
+    table: Vec<Vec<F>>,
+}
+
+impl<F: Field> FixedBase<F> {
+    /// Precomputes the powers of `base` needed for exponents of at most
+    /// `max_bits` bits, using windows of `window_size` bits.
+    ///
+    /// The table holds `ceil(max_bits / window_size) * 2^window_size`
+    /// elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` is zero or larger than 16.
+    pub fn new(base: F, max_bits: usize, window_size: usize) -> Self {
+        assert!(
+            (1..=16).contains(&window_size),
+            "window size must be between 1 and 16"
+        );
+        let num_windows = max_bits.div_ceil(window_size);
+        let mut table = Vec::with_capacity(num_windows);
+        let mut window_base = base;
+        for _ in 0..num_windows {
+            let mut row = Vec::with_capacity(1 << window_size);
+            row.push(F::one());
+            for j in 1..1 << window_size {
+                row.push(row[j - 1] * window_base);
+            }
+            window_base = row[(1 << window_size) - 1] * window_base;
+            table.push(row);
+        }
+        Self {
+            base,
+            max_bits,
+            window_size,
+            table,
+        }
+    }
+
+    /// Returns the base of the exponentiations.
+    pub fn base(&self) -> F {
+        self.base
+    }
+
+    /// Returns the maximum number of bits of an exponent.
+    pub fn max_bits(&self) -> usize {
+        self.max_bits
+    }
+
+    /// Returns `base^exp`, where `exp` is an integer represented with `u64`
+    /// limbs, least significant limb first.
+    ///
+    /// This returns `None` when `exp` has more than `max_bits` bits.
+    pub fn pow_with_table<S: AsRef<[u64]>>(&self, exp: S) -> Option<F> {
+        let exp = exp.as_ref();
+        if BitIteratorBE::without_leading_zeros(exp).count() > self.max_bits {
+            return None;
+        }
+        let mut res = F::one();
+        for (i, row) in self.table.iter().enumerate() {
+            let digit = bits_window(exp, i * self.window_size, self.window_size);
+            if digit != 0 {
+                res *= row[digit];
+            }
+        }
+        Some(res)
+    }
+
+    /// Returns `base^exp` for every exponent in `exps`, or `None` if any of
+    /// them has more than `max_bits` bits.
+    pub fn pow_batch<S: AsRef<[u64]> + Sync>(&self, exps: &[S]) -> Option<Vec<F>> {
+        cfg_iter!(exps)
+            .map(|exp| self.pow_with_table(exp))
+            .collect()
+    }
+}
@@ -4,6 +4,7 @@ use ark_serialize::{
     CanonicalSerializeWithFlags, EmptyFlags, Flags,
 };
 use ark_std::{
+    borrow::{Borrow, BorrowMut},
     fmt::{Debug, Display},
     hash::Hash,
     iter::*,
@@ -38,6 +39,24 @@ pub use cyclotomic::*;
 mod sqrt;
 pub use sqrt::*;
 
+mod nth_root;
+
+mod quadratic;
+
+mod extension;
+pub use extension::*;
+
+mod fixed_base;
+pub use fixed_base::*;
+
+mod multi_pow;
+
+mod backend;
+pub use backend::*;
+
+mod secret;
+pub use secret::*;
+
 #[cfg(feature = "parallel")]
 use ark_std::cmp::max;
 #[cfg(feature = "parallel")]
@@ -259,10 +278,16 @@ pub trait Field:
     fn legendre(&self) -> LegendreSymbol;
 
     /// Returns the square root of self, if it exists.
+    ///
+    /// In characteristic two, every element has a unique square root, which is
+    /// computed with the Frobenius map when no `SQRT_PRECOMP` is given.
     #[must_use]
     fn sqrt(&self) -> Option<Self> {
         match Self::SQRT_PRECOMP {
             Some(tv) => tv.sqrt(self),
+            None if Self::characteristic() == [2] => {
+                Some(self.frobenius_map(Self::extension_degree() as usize - 1))
+            },
             None => unimplemented!(),
         }
     }
@@ -275,6 +300,116 @@ pub trait Field:
         })
     }
 
+    /// Returns the square roots of the elements of `elems`, if they exist, in
+    /// the same order as [`Self::sqrt`] would.
+    ///
+    /// Implementations may override this to share work across the inputs.
+    /// By default, fields with a [`Self::SQRT_PRECOMP`] batch the initial
+    /// exponentiation of the square root algorithm, see
+    /// [`SqrtPrecomputation::batch_sqrt`]. Quadratic extensions batch the
+    /// inversions of the complex method and the square roots in their base
+    /// field, using Montgomery's trick, or the exponentiations of the complex
+    /// method over `F_p[u] / (u^2 + 1)` with `p = 3 (mod 4)`.
+    fn batch_sqrt(elems: &[Self]) -> Vec<Option<Self>> {
+        match Self::SQRT_PRECOMP {
+            Some(tv) => tv.batch_sqrt(elems),
+            None => elems.iter().map(Self::sqrt).collect(),
+        }
+    }
+
+    /// Returns an `n`-th root of `self`, if one exists.
+    ///
+    /// Let `q` be the order of the field. If `n` is coprime to `q - 1`, every
+    /// element has a unique `n`-th root, which is computed with a single
+    /// exponentiation; this is the case e.g. for cube roots when
+    /// `q = 2 (mod 3)`. Otherwise, roots are computed one prime factor of
+    /// `gcd(n, q - 1)` at a time, using the Adleman-Manders-Miller
+    /// generalization of Tonelli-Shanks, which is slow when `gcd(n, q - 1)`
+    /// has large prime factors.
+    ///
+    /// For `n = 0`, this returns `Some(1)` if `self` is one, and `None`
+    /// otherwise. This function runs in variable time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_test_curves::{Field, bls12_381::Fr};
+    /// let a = Fr::rand(&mut test_rng());
+    /// let cube = a.pow([3]);
+    /// assert_eq!(cube.nth_root(3).unwrap().pow([3]), cube);
+    /// ```
+    #[must_use]
+    fn nth_root(&self, n: u64) -> Option<Self> {
+        nth_root::nth_root(self, n)
+    }
+
+    /// Returns a cube root of `self`, if one exists. See [`Self::nth_root`].
+    #[must_use]
+    fn cube_root(&self) -> Option<Self> {
+        self.nth_root(3)
+    }
+
+    /// Returns the absolute trace of `self`, i.e. the sum of its conjugates
+    /// `self^(p^i)` for `i` in `0..Self::extension_degree()`, where `p` is the
+    /// characteristic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_test_curves::{Field, bls12_381::Fq2};
+    /// let a = Fq2::rand(&mut test_rng());
+    /// assert_eq!(a.trace(), a.c0.double());
+    /// ```
+    #[must_use]
+    fn trace(&self) -> Self::BasePrimeField {
+        quadratic::trace(self)
+    }
+
+    /// Returns the absolute norm of `self`, i.e. the product of its conjugates
+    /// `self^(p^i)` for `i` in `0..Self::extension_degree()`, where `p` is the
+    /// characteristic.
+    ///
+    /// [`QuadExtField`] and [`CubicExtField`] have an inherent `norm` method,
+    /// which computes the norm relative to their base field and takes
+    /// precedence in method-call syntax; call this one as `Field::norm(&a)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_test_curves::{Field, bls12_381::{Fq, Fq2}};
+    /// let a = Fq2::rand(&mut test_rng());
+    /// assert_eq!(Field::norm(&a), a.c0.square() + a.c1.square());
+    /// assert_eq!(Field::norm(&Fq::from(3u64)), Fq::from(3u64));
+    /// ```
+    #[must_use]
+    fn norm(&self) -> Self::BasePrimeField {
+        extension::norm(self)
+    }
+
+    /// Returns a root of `x^2 + b * x + c`, if one exists.
+    ///
+    /// In odd characteristic, this computes the square root of the
+    /// discriminant. In characteristic two, where the usual formula divides
+    /// by zero, the equation is reduced to `y^2 + y = u`, which has a
+    /// solution if and only if `u` has trace zero, and is then solved with a
+    /// linear combination of the conjugates of `u`. The other root is
+    /// `-b - x`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_ff::{BinaryField128b, Field};
+    /// let mut rng = test_rng();
+    /// let (x, y) = (BinaryField128b::rand(&mut rng), BinaryField128b::rand(&mut rng));
+    /// // (X - x) (X - y) = X^2 + (x + y) X + x y
+    /// let root = BinaryField128b::solve_quadratic(&(x + y), &(x * y)).unwrap();
+    /// assert!(root == x || root == y);
+    /// ```
+    #[must_use]
+    fn solve_quadratic(b: &Self, c: &Self) -> Option<Self> {
+        quadratic::solve_quadratic(b, c)
+    }
+
     /// Returns `self * self`.
     #[must_use]
     fn square(&self) -> Self;
@@ -300,6 +435,33 @@ pub trait Field:
         sum
     }
 
+    /// Sets `a[i] *= b[i]` for every `i`.
+    ///
+    /// Implementations may override this to perform several multiplications
+    /// in parallel, e.g. using SIMD instructions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    fn mul_batch(a: &mut [Self], b: &[Self]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut().zip(b).for_each(|(a, b)| *a *= b);
+    }
+
+    /// Returns `sum([a_i * b_i])` for slices `a` and `b` of any length.
+    ///
+    /// Implementations may override this to accumulate the unreduced products
+    /// and reduce only once at the end, which is much faster than reducing
+    /// after every multiplication for long vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    fn dot_product(a: &[Self], b: &[Self]) -> Self {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).map(|(a, b)| *a * b).sum()
+    }
+
     /// Sets `self` to `self^s`, where `s = Self::BasePrimeField::MODULUS^power`.
     /// This is also called the Frobenius automorphism.
     fn frobenius_map_in_place(&mut self, power: usize);
@@ -315,8 +477,22 @@ pub trait Field:
 
     /// Returns `self^exp`, where `exp` is an integer represented with `u64` limbs,
     /// least significant limb first.
+    ///
+    /// This is the same as [`Field::pow_vartime`], and must not be used with
+    /// secret exponents. See [`Field::pow_ct`] for a constant-time variant.
     #[must_use]
     fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        self.pow_vartime(exp)
+    }
+
+    /// Returns `self^exp`, where `exp` is an integer represented with `u64` limbs,
+    /// least significant limb first.
+    ///
+    /// This uses square-and-multiply, skipping the leading zeros and the
+    /// multiplications for zero bits of `exp`, so its running time depends on
+    /// the value of `exp`.
+    #[must_use]
+    fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
         let mut res = Self::one();
 
         for i in crate::BitIteratorBE::without_leading_zeros(exp) {
@@ -329,6 +505,57 @@ pub trait Field:
         res
     }
 
+    /// Returns `self^exp`, where `exp` is an integer represented with `u64` limbs,
+    /// least significant limb first, in time that only depends on the number of
+    /// limbs of `exp`.
+    ///
+    /// This uses a fixed window of 4 bits over all limbs of `exp`, including
+    /// leading zeros, and selects each table entry with a linear scan through
+    /// [`ConditionallySelectable`](subtle::ConditionallySelectable), so that
+    /// neither the sequence of operations nor the memory access pattern
+    /// depends on `exp`. Assuming that the arithmetic of `Self` is itself
+    /// constant-time, this is suitable for secret exponents. With the `ct`
+    /// feature, this holds for prime fields using
+    /// [`MontBackend`](crate::MontBackend) and for their extensions, but not
+    /// for the other prime field backends.
+    ///
+    /// ```
+    /// # use ark_ff::Field;
+    /// # use ark_test_curves::bls12_381::Fq2;
+    /// let a = Fq2::from(3u64);
+    /// assert_eq!(a.pow_ct([0x1234, 0]), a.pow([0x1234]));
+    /// ```
+    #[cfg(feature = "ct")]
+    #[must_use]
+    fn pow_ct<S: AsRef<[u64]>>(&self, exp: S) -> Self
+    where
+        Self: subtle::ConditionallySelectable,
+    {
+        use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+        // `table[i] = self^i`.
+        let mut table = [Self::one(); 16];
+        for i in 1..16 {
+            table[i] = table[i - 1] * self;
+        }
+
+        let mut res = Self::one();
+        for limb in exp.as_ref().iter().rev() {
+            for shift in (0..64).step_by(4).rev() {
+                for _ in 0..4 {
+                    res.square_in_place();
+                }
+                let digit = (limb >> shift) & 0xf;
+                let mut entry = Self::one();
+                for (j, table_entry) in table.iter().enumerate() {
+                    entry.conditional_assign(table_entry, (j as u64).ct_eq(&digit));
+                }
+                res *= entry;
+            }
+        }
+        res
+    }
+
     /// Exponentiates a field element `f` by a number represented with `u64`
     /// limbs, using a precomputed table containing as many powers of 2 of
     /// `f` as the 1 + the floor of log2 of the exponent `exp`, starting
@@ -347,6 +574,31 @@ pub trait Field:
         Some(res)
     }
 
+    /// Returns `prod([bases_i^exps_i])`, where each exponent is an integer
+    /// represented with `u64` limbs, least significant limb first.
+    ///
+    /// This uses Pippenger's bucket method in the exponent, so that squarings
+    /// are shared across all bases, which is much faster than exponentiating
+    /// each base separately. With the `parallel` feature, the windows of the
+    /// exponents are processed in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bases` and `exps` have different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_test_curves::{Field, bls12_381::Fq2};
+    /// let mut rng = test_rng();
+    /// let (a, b) = (Fq2::rand(&mut rng), Fq2::rand(&mut rng));
+    /// assert_eq!(Fq2::multi_pow(&[a, b], &[[3u64], [5]]), a.pow([3]) * b.pow([5]));
+    /// ```
+    #[must_use]
+    fn multi_pow<S: AsRef<[u64]> + Sync>(bases: &[Self], exps: &[S]) -> Self {
+        multi_pow::multi_pow(bases, exps)
+    }
+
     fn mul_by_base_prime_field(&self, elem: &Self::BasePrimeField) -> Self;
 }
 
@@ -355,15 +607,54 @@ pub fn batch_inversion<F: Field>(v: &mut [F]) {
     batch_inversion_and_mul(v, &F::one());
 }
 
-#[cfg(not(feature = "parallel"))]
 // Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
 pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    batch_inversion_and_mul_generic(v, coeff);
+}
+
+/// Given an iterator over mutable references to field elements {v_i}, replace
+/// each v_i by v_i^(-1). Zero elements are left unchanged.
+///
+/// This allows inverting elements that are not contiguous in memory, e.g.
+/// `batch_inverse_iter(points.iter_mut().map(|p| &mut p.z))`, without first
+/// copying them into a temporary vector.
+pub fn batch_inverse_iter<'a, F: Field>(iter: impl IntoIterator<Item = &'a mut F>) {
+    batch_inverse_iter_and_mul(iter, &F::one());
+}
+
+/// Given an iterator over mutable references to field elements {v_i}, replace
+/// each v_i by coeff * v_i^(-1). Zero elements are left unchanged.
+pub fn batch_inverse_iter_and_mul<'a, F: Field>(
+    iter: impl IntoIterator<Item = &'a mut F>,
+    coeff: &F,
+) {
+    let mut refs = iter.into_iter().collect::<Vec<_>>();
+    batch_inversion_and_mul_generic(&mut refs, coeff);
+}
+
+/// Inverts every `stride`-th element of `v`, starting with `v[0]`. Elements at
+/// other positions, and zero elements, are left unchanged.
+///
+/// To start at a different offset, pass `&mut v[offset..]`.
+///
+/// # Panics
+///
+/// Panics if `stride` is zero.
+pub fn batch_inverse_strided<F: Field>(v: &mut [F], stride: usize) {
+    batch_inverse_iter(v.iter_mut().step_by(stride));
+}
+
+#[cfg(not(feature = "parallel"))]
+// Given a vector of (references to) field elements {v_i}, compute the vector
+// {coeff * v_i^(-1)}
+fn batch_inversion_and_mul_generic<F: Field, T: BorrowMut<F> + Send>(v: &mut [T], coeff: &F) {
     serial_batch_inversion_and_mul(v, coeff);
 }
 
 #[cfg(feature = "parallel")]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
-pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+// Given a vector of (references to) field elements {v_i}, compute the vector
+// {coeff * v_i^(-1)}
+fn batch_inversion_and_mul_generic<F: Field, T: BorrowMut<F> + Send>(v: &mut [T], coeff: &F) {
     // Divide the vector v evenly between all available cores
     let min_elements_per_thread = 1;
     let num_cpus_available = rayon::current_num_threads();
@@ -376,9 +667,42 @@ pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     });
 }
 
-/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+/// Given a vector of (references to) field elements {v_i}, compute the vector
+/// {coeff * v_i^(-1)}.
 /// This method is explicitly single-threaded.
-fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+fn serial_batch_inversion_and_mul<F: Field, T: BorrowMut<F>>(v: &mut [T], coeff: &F) {
+    let mut prod = vec![F::ZERO; v.len()];
+    serial_batch_inversion_and_mul_with_scratch(v, coeff, &mut prod);
+}
+
+/// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
+/// without allocating, storing intermediate products in `scratch`.
+/// Zero elements are left unchanged.
+///
+/// If `scratch` is shorter than `v`, then `v` is processed in chunks of
+/// `scratch.len()` elements, at the cost of one field inversion per chunk.
+///
+/// # Panics
+///
+/// Panics if `scratch` is empty and `v` is not.
+pub fn batch_inversion_in_place_no_alloc<F: Field>(v: &mut [F], scratch: &mut [F]) {
+    assert!(
+        !scratch.is_empty() || v.is_empty(),
+        "scratch buffer must not be empty"
+    );
+    for chunk in v.chunks_mut(scratch.len().max(1)) {
+        serial_batch_inversion_and_mul_with_scratch(chunk, &F::ONE, scratch);
+    }
+}
+
+/// Given a vector of (references to) field elements {v_i}, compute the vector
+/// {coeff * v_i^(-1)}, using `prod` to store intermediate products.
+/// `prod` must be at least as long as `v`.
+fn serial_batch_inversion_and_mul_with_scratch<F: Field, T: BorrowMut<F>>(
+    v: &mut [T],
+    coeff: &F,
+    prod: &mut [F],
+) {
     // Montgomery’s Trick and Fast Implementation of Masked AES
     // Genelle, Prouff and Quisquater
     // Section 3.2
@@ -386,11 +710,15 @@ fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     // coeff
 
     // First pass: compute [a, ab, abc, ...]
-    let mut prod = Vec::with_capacity(v.len());
+    let mut num_nonzero = 0;
     let mut tmp = F::one();
-    for f in v.iter().filter(|f| !f.is_zero()) {
-        tmp.mul_assign(f);
-        prod.push(tmp);
+    for f in v.iter() {
+        let f: &F = f.borrow();
+        if !f.is_zero() {
+            tmp.mul_assign(f);
+            prod[num_nonzero] = tmp;
+            num_nonzero += 1;
+        }
     }
 
     // Invert `tmp`.
@@ -401,16 +729,17 @@ fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
 
     // Second pass: iterate backwards to compute inverses
     for (f, s) in v.iter_mut()
+        .map(BorrowMut::<F>::borrow_mut)
         // Backwards
         .rev()
         // Ignore normalized elements
         .filter(|f| !f.is_zero())
         // Backwards, skip last element, fill in one for last term.
-        .zip(prod.into_iter().rev().skip(1).chain(Some(F::one())))
+        .zip(prod[..num_nonzero].iter().rev().skip(1).chain(Some(&F::ONE)))
     {
         // tmp := tmp * f; f := tmp * s = 1/f
         let new_tmp = tmp * *f;
-        *f = tmp * &s;
+        *f = tmp * s;
         tmp = new_tmp;
     }
 }
@@ -444,7 +773,10 @@ mod no_std_tests {
     // The rest of imports are caused by cargo not resolving the deps properly
     // from this crate and from ark_test_curves
     use ark_test_curves::{
-        ark_ff::{batch_inversion, batch_inversion_and_mul, PrimeField},
+        ark_ff::{
+            batch_inverse_iter, batch_inverse_iter_and_mul, batch_inverse_strided, batch_inversion,
+            batch_inversion_and_mul, batch_inversion_in_place_no_alloc, Field, PrimeField,
+        },
         bls12_381::Fr,
     };
 
@@ -473,6 +805,64 @@ mod no_std_tests {
         }
     }
 
+    #[test]
+    fn test_batch_inverse_iter() {
+        let rng = &mut test_rng();
+        let mut pairs = (0..100)
+            .map(|_| (Fr::rand(rng), Fr::rand(rng)))
+            .collect::<Vec<_>>();
+        pairs[17].1 = Fr::zero();
+        let original = pairs.clone();
+
+        batch_inverse_iter(pairs.iter_mut().map(|(_, z)| z));
+        for ((x, z), (x_orig, z_orig)) in pairs.iter().zip(&original) {
+            assert_eq!(x, x_orig);
+            assert_eq!(*z, z_orig.inverse().unwrap_or_default());
+        }
+
+        let coeff = Fr::rand(rng);
+        let mut pairs = original.clone();
+        batch_inverse_iter_and_mul(pairs.iter_mut().map(|(x, _)| x), &coeff);
+        for ((x, z), (x_orig, z_orig)) in pairs.iter().zip(&original) {
+            assert_eq!(*x, coeff * x_orig.inverse().unwrap());
+            assert_eq!(z, z_orig);
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse_strided() {
+        let rng = &mut test_rng();
+        let original = (0..101).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        for stride in [1, 2, 3, 7, 200] {
+            let mut v = original.clone();
+            batch_inverse_strided(&mut v[1..], stride);
+            assert_eq!(v[0], original[0]);
+            for (i, (a, a_orig)) in v.iter().zip(&original).enumerate().skip(1) {
+                if (i - 1) % stride == 0 {
+                    assert_eq!(*a, a_orig.inverse().unwrap());
+                } else {
+                    assert_eq!(a, a_orig);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_inversion_in_place_no_alloc() {
+        let rng = &mut test_rng();
+        let mut original = (0..100).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        original[42] = Fr::zero();
+        for scratch_len in [1, 7, 100, 150] {
+            let mut v = original.clone();
+            let mut scratch = vec![Fr::zero(); scratch_len];
+            batch_inversion_in_place_no_alloc(&mut v, &mut scratch);
+            for (a, a_orig) in v.iter().zip(&original) {
+                assert_eq!(*a, a_orig.inverse().unwrap_or_default());
+            }
+        }
+        batch_inversion_in_place_no_alloc::<Fr>(&mut [], &mut []);
+    }
+
     #[test]
     pub fn test_from_ints() {
         let felt2 = Fr::one() + Fr::one();
@@ -516,6 +906,104 @@ mod no_std_tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn test_from_uniform_bytes() {
+        let mut rng = ark_std::test_rng();
+        let modulus: BigUint = Fr::MODULUS.into();
+        for _ in 0..100 {
+            let mut bytes = [0u8; 64];
+            bytes.iter_mut().for_each(|b| *b = u8::rand(&mut rng));
+            let expected = BigUint::from_bytes_le(&bytes) % &modulus;
+            let actual: BigUint = Fr::from_uniform_bytes(&bytes).into();
+            assert_eq!(actual, expected);
+        }
+        assert_eq!(Fr::from_uniform_bytes(&[0u8; 48]), Fr::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_uniform_bytes_too_short() {
+        let _ = Fr::from_uniform_bytes(&[0u8; 47]);
+    }
+
+    macro_rules! check_two_adic_roots_of_unity {
+        ($field:ty) => {{
+            let roots = <$field>::two_adic_roots_of_unity();
+            let roots_inv = <$field>::two_adic_roots_of_unity_inv();
+            let s = <$field>::TWO_ADICITY as usize;
+            assert_eq!(roots.len(), s + 1);
+            assert_eq!(roots_inv.len(), s + 1);
+            assert_eq!(roots[0], <$field>::ONE);
+            assert_eq!(roots[s], <$field>::TWO_ADIC_ROOT_OF_UNITY);
+            for k in 1..=s {
+                assert_eq!(roots[k].square(), roots[k - 1]);
+                assert_eq!(roots[k] * roots_inv[k], <$field>::ONE);
+                assert_eq!(<$field>::get_root_of_unity(1 << k), Some(roots[k]));
+            }
+        }};
+    }
+
+    #[test]
+    fn test_two_adic_roots_of_unity() {
+        use crate::Field as _;
+        use ark_test_curves::ark_ff::FftField as _;
+
+        // The chain is precomputed by the `MontConfig` derive for `Fr`, and
+        // computed at runtime for `Goldilocks`.
+        assert_eq!(Fr::TWO_ADIC_ROOTS_OF_UNITY.len(), 33);
+        check_two_adic_roots_of_unity!(Fr);
+        assert!(crate::Goldilocks::TWO_ADIC_ROOTS_OF_UNITY.is_empty());
+        check_two_adic_roots_of_unity!(crate::Goldilocks);
+    }
+
+    #[test]
+    fn test_bigint_batch() {
+        let mut rng = ark_std::test_rng();
+        for n in [0, 1, 65, 5000] {
+            let mut elems: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+            if n > 0 {
+                elems[0] = Fr::zero();
+            }
+            let ints = Fr::into_bigint_batch(&elems);
+            let expected: Vec<_> = elems.iter().map(|e| e.into_bigint()).collect();
+            assert_eq!(ints, expected);
+            assert_eq!(Fr::from_bigint_batch(&ints), Some(elems));
+        }
+
+        let mut ints = vec![Fr::one().into_bigint(); 100];
+        ints[70] = Fr::MODULUS;
+        assert_eq!(Fr::from_bigint_batch(&ints), None);
+    }
+
+    #[test]
+    fn test_into_bigint_bytes() {
+        use ark_test_curves::ark_ff::BigInteger;
+
+        let mut rng = ark_std::test_rng();
+        for _ in 0..100 {
+            let a = Fr::rand(&mut rng);
+            let mut le = [0xffu8; 40];
+            let mut be = [0xffu8; 40];
+            a.into_bigint_bytes_le(&mut le);
+            a.into_bigint_bytes_be(&mut be);
+            assert_eq!(le[..32], a.into_bigint().to_bytes_le()[..]);
+            assert_eq!(be[8..], a.into_bigint().to_bytes_be()[..]);
+            assert!(le[32..].iter().chain(&be[..8]).all(|b| *b == 0));
+            assert_eq!(Fr::from_le_bytes_mod_order(&le), a);
+            assert_eq!(Fr::from_be_bytes_mod_order(&be), a);
+
+            let n = BigUint::from_bytes_le(&le) + BigUint::from(u128::MAX) * &BigUint::from(a);
+            assert_eq!(Fr::from(n), a + Fr::from(u128::MAX) * a);
+            assert_eq!(BigUint::from(a.into_bigint()), BigUint::from(a));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_into_bigint_bytes_too_short() {
+        Fr::one().into_bigint_bytes_le(&mut [0u8; 31]);
+    }
+
     #[test]
     fn test_from_be_bytes_mod_order() {
         // Each test vector is a byte array,
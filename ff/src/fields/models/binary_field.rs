@@ -0,0 +1,859 @@
+use super::fp::{Fp32, Fp32Config};
+use crate::{
+    fields::{Field, LegendreSymbol},
+    AdditiveGroup, One, SqrtPrecomputation, Zero,
+};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
+    ValidationError,
+};
+use ark_std::{
+    cmp::*,
+    fmt,
+    io::{Read, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+};
+use zeroize::Zeroize;
+
+/// The prime field `GF(2)`, which is the base prime field of every
+/// [`BinaryField`].
+pub type BinaryField1b = Fp32<BinaryField1bConfig>;
+
+pub struct BinaryField1bConfig;
+
+impl Fp32Config for BinaryField1bConfig {
+    const MODULUS: u32 = 2;
+
+    const GENERATOR: u32 = 1;
+
+    const TWO_ADIC_ROOT_OF_UNITY: u32 = 1;
+}
+
+/// A trait that specifies the configuration of a binary field
+/// `GF(2^n) = GF(2)[X] / (f(X))`, where `f(X) = X^n + g(X)` is irreducible
+/// over `GF(2)`.
+pub trait BinaryFieldConfig: 'static + Send + Sync + Sized {
+    /// The degree `n` of the field over `GF(2)`. Must be a power of two,
+    /// between 2 and 128.
+    const DEGREE: u32;
+
+    /// The coefficients of `g(X) = f(X) - X^n`, where `f(X)` is the
+    /// irreducible polynomial defining the field. The coefficient of `X^i` is
+    /// stored in bit `i`.
+    const MODULUS: u128;
+}
+
+/// A trait that specifies a level `GF(2^n)` of the binary tower
+/// `GF(2) ⊂ GF(2^2) ⊂ ... ⊂ GF(2^128)`, by the embedding of the previous level
+/// `GF(2^(n/2))` into it.
+///
+/// Each level is defined by its own irreducible polynomial, so the embedding
+/// is the field homomorphism which maps the generator `X` of the subfield to
+/// [`Self::SUBFIELD_GENERATOR`], a root of the polynomial defining the
+/// subfield.
+pub trait BinaryTowerConfig: BinaryFieldConfig {
+    /// The previous level of the tower, whose degree is half of
+    /// [`BinaryFieldConfig::DEGREE`].
+    type Subfield: BinaryFieldConfig;
+
+    /// The image of the generator `X` of [`Self::Subfield`], in the polynomial
+    /// basis of this field.
+    const SUBFIELD_GENERATOR: u128;
+}
+
+/// An element of the binary field `GF(2^n) = GF(2)[X] / (f(X))`, where `n` and
+/// `f(X)` are specified by `P`.
+///
+/// Elements are represented in the polynomial basis `1, X, ..., X^(n - 1)`:
+/// the coefficient of `X^i` is stored in bit `i`. Addition is thus a single
+/// XOR, and multiplication is a carry-less product followed by a reduction
+/// modulo `f(X)`. The carry-less product uses the `PCLMULQDQ` instruction on
+/// `x86_64` and the `PMULL` instruction on `aarch64` when the corresponding
+/// target features are enabled, and a portable implementation otherwise.
+#[derive(Educe)]
+#[educe(Default, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryField<P: BinaryFieldConfig>(
+    /// The coefficients of the element in the polynomial basis.
+    pub u128,
+    #[doc(hidden)] pub PhantomData<P>,
+);
+
+impl<P: BinaryFieldConfig> BinaryField<P> {
+    /// A mask for the bits that may be set in the representation of an
+    /// element.
+    const MASK: u128 = u128::MAX >> (128 - P::DEGREE);
+
+    /// Construct a new field element from the coefficients of its
+    /// polynomial-basis representation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` has bits set at positions `n` or higher.
+    #[inline]
+    pub const fn new(value: u128) -> Self {
+        assert!(value & !Self::MASK == 0, "value out of range");
+        Self(value, PhantomData)
+    }
+
+    /// Reduces the polynomial `lo + hi * X^128` modulo `f(X)`.
+    #[inline(always)]
+    fn reduce(mut lo: u128, mut hi: u128) -> u128 {
+        if P::DEGREE == 128 {
+            while hi != 0 {
+                let (l, h) = clmul128(hi, P::MODULUS);
+                lo ^= l;
+                hi = h;
+            }
+        } else {
+            debug_assert_eq!(hi, 0);
+            let mut overflow = lo >> P::DEGREE;
+            while overflow != 0 {
+                lo = (lo & Self::MASK) ^ clmul128(overflow, P::MODULUS).0;
+                overflow = lo >> P::DEGREE;
+            }
+        }
+        lo
+    }
+
+    /// Computes the unreduced product of `a` and `b`, as `(lo, hi)` such that
+    /// `a * b = lo + hi * X^128`.
+    #[inline(always)]
+    fn unreduced_mul(a: u128, b: u128) -> (u128, u128) {
+        if P::DEGREE <= 64 {
+            (clmul64(a as u64, b as u64), 0)
+        } else {
+            clmul128(a, b)
+        }
+    }
+}
+
+impl<P: BinaryTowerConfig> BinaryField<P> {
+    /// Embeds an element of the previous level of the tower into this field,
+    /// by mapping the generator `X` of the subfield to
+    /// [`BinaryTowerConfig::SUBFIELD_GENERATOR`]. This is an injective field
+    /// homomorphism.
+    pub fn embed(element: BinaryField<P::Subfield>) -> Self {
+        let generator = Self::new(P::SUBFIELD_GENERATOR);
+        let mut power = Self::ONE;
+        let mut result = Self::ZERO;
+        for i in 0..P::Subfield::DEGREE {
+            if (element.0 >> i) & 1 == 1 {
+                result += power;
+            }
+            power *= generator;
+        }
+        result
+    }
+
+    /// Lifts `self` back to the previous level of the tower, i.e. returns the
+    /// preimage of `self` under [`Self::embed`], or `None` if `self` does not
+    /// lie in the subfield.
+    pub fn try_project(&self) -> Option<BinaryField<P::Subfield>> {
+        // Gaussian elimination over `GF(2)` on the images of the basis
+        // `1, X, ..., X^(n/2 - 1)` of the subfield. Each row is stored at the
+        // index of its leading bit, along with the combination of basis
+        // elements it is the image of.
+        let generator = Self::new(P::SUBFIELD_GENERATOR);
+        let mut rows = [(0u128, 0u128); 128];
+        let mut power = Self::ONE;
+        for i in 0..P::Subfield::DEGREE {
+            let mut row = (power.0, 1u128 << i);
+            // The images are linearly independent, so `row` never vanishes.
+            while rows[127 - row.0.leading_zeros() as usize].0 != 0 {
+                let pivot = rows[127 - row.0.leading_zeros() as usize];
+                row = (row.0 ^ pivot.0, row.1 ^ pivot.1);
+            }
+            rows[127 - row.0.leading_zeros() as usize] = row;
+            power *= generator;
+        }
+
+        let mut target = (self.0, 0u128);
+        while target.0 != 0 {
+            let pivot = rows[127 - target.0.leading_zeros() as usize];
+            if pivot.0 == 0 {
+                return None;
+            }
+            target = (target.0 ^ pivot.0, target.1 ^ pivot.1);
+        }
+        Some(BinaryField::new(target.1))
+    }
+}
+
+/// Computes the carry-less product of two polynomials of degree less than 64.
+#[cfg(all(target_arch = "x86_64", target_feature = "pclmulqdq"))]
+#[inline(always)]
+#[allow(unsafe_code)]
+fn clmul64(a: u64, b: u64) -> u128 {
+    use core::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_cvtsi64_si128};
+    // SAFETY: the `pclmulqdq` target feature (which implies `sse2`) is enabled.
+    unsafe {
+        let product = _mm_clmulepi64_si128(
+            _mm_cvtsi64_si128(a as i64),
+            _mm_cvtsi64_si128(b as i64),
+            0x00,
+        );
+        core::mem::transmute::<__m128i, u128>(product)
+    }
+}
+
+/// Computes the carry-less product of two polynomials of degree less than 64.
+#[cfg(all(target_arch = "aarch64", target_feature = "aes"))]
+#[inline(always)]
+#[allow(unsafe_code)]
+fn clmul64(a: u64, b: u64) -> u128 {
+    // SAFETY: the `aes` target feature (which implies `neon`) is enabled.
+    unsafe { core::arch::aarch64::vmull_p64(a, b) }
+}
+
+/// Computes the carry-less product of two polynomials of degree less than 64.
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "pclmulqdq"),
+    all(target_arch = "aarch64", target_feature = "aes")
+)))]
+#[inline(always)]
+fn clmul64(a: u64, b: u64) -> u128 {
+    let a = a as u128;
+    let mut result = 0u128;
+    for i in 0..64 {
+        // Select `a << i` without branching on the bits of `b`.
+        let mask = 0u128.wrapping_sub(((b >> i) & 1) as u128);
+        result ^= (a << i) & mask;
+    }
+    result
+}
+
+/// Computes the carry-less product of two polynomials of degree less than 128,
+/// as `(lo, hi)` such that `a * b = lo + hi * X^128`.
+#[inline(always)]
+fn clmul128(a: u128, b: u128) -> (u128, u128) {
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+    // Karatsuba multiplication.
+    let lo = clmul64(a0, b0);
+    let hi = clmul64(a1, b1);
+    let mid = clmul64(a0 ^ a1, b0 ^ b1) ^ lo ^ hi;
+    (lo ^ (mid << 64), hi ^ (mid >> 64))
+}
+
+impl<P: BinaryFieldConfig> Zero for BinaryField<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<P: BinaryFieldConfig> One for BinaryField<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl<P: BinaryFieldConfig> AdditiveGroup for BinaryField<P> {
+    type Scalar = Self;
+
+    const ZERO: Self = Self(0, PhantomData);
+
+    /// In characteristic two, `2 * a = 0` for all `a`.
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0 = 0;
+        self
+    }
+
+    /// In characteristic two, `-a = a` for all `a`.
+    #[inline]
+    fn neg_in_place(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl<P: BinaryFieldConfig> Field for BinaryField<P> {
+    type BasePrimeField = BinaryField1b;
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+
+    const ONE: Self = Self(1, PhantomData);
+
+    fn extension_degree() -> u64 {
+        P::DEGREE as u64
+    }
+
+    fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+        Self(elem.0 as u128, PhantomData)
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self::BasePrimeField> {
+        let value = self.0;
+        (0..P::DEGREE).map(move |i| BinaryField1b::from((value >> i) & 1 == 1))
+    }
+
+    fn from_base_prime_field_elems(
+        elems: impl IntoIterator<Item = Self::BasePrimeField>,
+    ) -> Option<Self> {
+        let mut value = 0u128;
+        let mut len = 0;
+        for (i, elem) in elems.into_iter().enumerate() {
+            if i >= P::DEGREE as usize {
+                return None;
+            }
+            value |= (elem.0 as u128) << i;
+            len += 1;
+        }
+        (len == P::DEGREE as usize).then_some(Self(value, PhantomData))
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            return None;
+        }
+        let output_byte_size = buffer_byte_size(P::DEGREE as usize + F::BIT_SIZE);
+        let mut buffer = [0u8; 17];
+        let len = bytes.len().min(output_byte_size);
+        buffer[..len].copy_from_slice(&bytes[..len]);
+        let flags = F::from_u8_remove_flags(&mut buffer[output_byte_size - 1])?;
+        let mut value = [0u8; 16];
+        value.copy_from_slice(&buffer[..16]);
+        Some((
+            Self(u128::from_le_bytes(value) & Self::MASK, PhantomData),
+            flags,
+        ))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let (lo, hi) = Self::unreduced_mul(self.0, self.0);
+        self.0 = Self::reduce(lo, hi);
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // The multiplicative group has order `2^n - 1`, so
+            // `a^{-1} = a^(2^n - 2)`.
+            let exp = Self::MASK - 1;
+            Some(self.pow([exp as u64, (exp >> 64) as u64]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// The Frobenius map over `GF(2)` is `a -> a^2`.
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        for _ in 0..power % P::DEGREE as usize {
+            self.square_in_place();
+        }
+    }
+
+    /// Every element of a binary field is a square.
+    fn legendre(&self) -> LegendreSymbol {
+        if self.is_zero() {
+            LegendreSymbol::Zero
+        } else {
+            LegendreSymbol::QuadraticResidue
+        }
+    }
+
+    /// Since squaring is a bijection, the square root of `a` is
+    /// `a^(2^(n - 1))`.
+    fn sqrt(&self) -> Option<Self> {
+        Some(self.frobenius_map(P::DEGREE as usize - 1))
+    }
+
+    fn sum_of_products<const T: usize>(a: &[Self; T], b: &[Self; T]) -> Self {
+        // Addition commutes with reduction, so we only need to reduce once.
+        let (lo, hi) = a.iter().zip(b).fold((0, 0), |(lo, hi), (a, b)| {
+            let (l, h) = Self::unreduced_mul(a.0, b.0);
+            (lo ^ l, hi ^ h)
+        });
+        Self(Self::reduce(lo, hi), PhantomData)
+    }
+
+    fn mul_by_base_prime_field(&self, elem: &Self::BasePrimeField) -> Self {
+        if elem.is_zero() {
+            Self::ZERO
+        } else {
+            *self
+        }
+    }
+}
+
+/// `BinaryField` elements are ordered by their polynomial-basis
+/// representation, read as an integer.
+impl<P: BinaryFieldConfig> Ord for BinaryField<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<P: BinaryFieldConfig> PartialOrd for BinaryField<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: BinaryFieldConfig> Zeroize for BinaryField<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Integers are mapped to `GF(2)`, i.e. reduced modulo two.
+impl<P: BinaryFieldConfig> From<bool> for BinaryField<P> {
+    fn from(other: bool) -> Self {
+        Self(other as u128, PhantomData)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl<P: BinaryFieldConfig> From<$int> for BinaryField<P> {
+                fn from(other: $int) -> Self {
+                    Self::from(other & 1 == 1)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<P: BinaryFieldConfig> Neg for BinaryField<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<P: BinaryFieldConfig> Distribution<BinaryField<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BinaryField<P> {
+        BinaryField(rng.gen::<u128>() & BinaryField::<P>::MASK, PhantomData)
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> Add<&'a BinaryField<P>> for BinaryField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> Sub<&'a BinaryField<P>> for BinaryField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> Mul<&'a BinaryField<P>> for BinaryField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &Self) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> Div<&'a BinaryField<P>> for BinaryField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, other: &Self) -> Self {
+        self.mul_assign(&other.inverse().unwrap());
+        self
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> AddAssign<&'a Self> for BinaryField<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> SubAssign<&'a Self> for BinaryField<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> MulAssign<&'a Self> for BinaryField<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let (lo, hi) = Self::unreduced_mul(self.0, other.0);
+        self.0 = Self::reduce(lo, hi);
+    }
+}
+
+impl<'a, P: BinaryFieldConfig> DivAssign<&'a Self> for BinaryField<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl_additive_ops_from_ref!(BinaryField, BinaryFieldConfig);
+impl_multiplicative_ops_from_ref!(BinaryField, BinaryFieldConfig);
+
+impl<P: BinaryFieldConfig> fmt::Debug for BinaryField<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+/// Outputs the polynomial-basis representation of `self` in hexadecimal.
+impl<P: BinaryFieldConfig> fmt::Display for BinaryField<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl<P: BinaryFieldConfig> CanonicalSerializeWithFlags for BinaryField<P> {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let output_byte_size = buffer_byte_size(P::DEGREE as usize + F::BIT_SIZE);
+        let mut bytes = [0u8; 17];
+        bytes[..16].copy_from_slice(&self.0.to_le_bytes());
+        bytes[output_byte_size - 1] |= flags.u8_bitmask();
+        writer.write_all(&bytes[..output_byte_size])?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(P::DEGREE as usize + F::BIT_SIZE)
+    }
+}
+
+impl<P: BinaryFieldConfig> CanonicalSerialize for BinaryField<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl<P: BinaryFieldConfig> CanonicalDeserializeWithFlags for BinaryField<P> {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let output_byte_size = buffer_byte_size(P::DEGREE as usize + F::BIT_SIZE);
+        let mut bytes = [0u8; 17];
+        reader.read_exact(&mut bytes[..output_byte_size])?;
+        let flags = F::from_u8_remove_flags(&mut bytes[output_byte_size - 1])
+            .ok_or(SerializationError::UnexpectedFlags)?;
+        let mut value = [0u8; 16];
+        value.copy_from_slice(&bytes[..16]);
+        let value = u128::from_le_bytes(value);
+        if value & !Self::MASK != 0 || bytes[16] != 0 {
+            return Err(ValidationError::NonCanonicalFieldEncoding.into());
+        }
+        Ok((Self(value, PhantomData), flags))
+    }
+}
+
+impl<P: BinaryFieldConfig> Valid for BinaryField<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<P: BinaryFieldConfig> CanonicalDeserialize for BinaryField<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
+    }
+}
+
+/// The field `GF(2^2) = GF(2)[X] / (X^2 + X + 1)`.
+pub type BinaryField2b = BinaryField<BinaryField2bConfig>;
+
+pub struct BinaryField2bConfig;
+
+impl BinaryFieldConfig for BinaryField2bConfig {
+    const DEGREE: u32 = 2;
+    const MODULUS: u128 = 0b11;
+}
+
+/// The field `GF(2^4) = GF(2)[X] / (X^4 + X + 1)`.
+pub type BinaryField4b = BinaryField<BinaryField4bConfig>;
+
+pub struct BinaryField4bConfig;
+
+impl BinaryFieldConfig for BinaryField4bConfig {
+    const DEGREE: u32 = 4;
+    const MODULUS: u128 = 0b11;
+}
+
+impl BinaryTowerConfig for BinaryField4bConfig {
+    type Subfield = BinaryField2bConfig;
+    const SUBFIELD_GENERATOR: u128 = 0x6;
+}
+
+/// The field `GF(2^8) = GF(2)[X] / (X^8 + X^4 + X^3 + X + 1)`, as used in AES.
+pub type BinaryField8b = BinaryField<BinaryField8bConfig>;
+
+pub struct BinaryField8bConfig;
+
+impl BinaryFieldConfig for BinaryField8bConfig {
+    const DEGREE: u32 = 8;
+    const MODULUS: u128 = 0x1B;
+}
+
+impl BinaryTowerConfig for BinaryField8bConfig {
+    type Subfield = BinaryField4bConfig;
+    const SUBFIELD_GENERATOR: u128 = 0xE1;
+}
+
+/// The field `GF(2^16) = GF(2)[X] / (X^16 + X^5 + X^3 + X + 1)`.
+pub type BinaryField16b = BinaryField<BinaryField16bConfig>;
+
+pub struct BinaryField16bConfig;
+
+impl BinaryFieldConfig for BinaryField16bConfig {
+    const DEGREE: u32 = 16;
+    const MODULUS: u128 = 0x2B;
+}
+
+impl BinaryTowerConfig for BinaryField16bConfig {
+    type Subfield = BinaryField8bConfig;
+    const SUBFIELD_GENERATOR: u128 = 0x6881;
+}
+
+/// The field `GF(2^32) = GF(2)[X] / (X^32 + X^7 + X^3 + X^2 + 1)`.
+pub type BinaryField32b = BinaryField<BinaryField32bConfig>;
+
+pub struct BinaryField32bConfig;
+
+impl BinaryFieldConfig for BinaryField32bConfig {
+    const DEGREE: u32 = 32;
+    const MODULUS: u128 = 0x8D;
+}
+
+impl BinaryTowerConfig for BinaryField32bConfig {
+    type Subfield = BinaryField16bConfig;
+    const SUBFIELD_GENERATOR: u128 = 0x10BA_142D;
+}
+
+/// The field `GF(2^64) = GF(2)[X] / (X^64 + X^4 + X^3 + X + 1)`.
+pub type BinaryField64b = BinaryField<BinaryField64bConfig>;
+
+pub struct BinaryField64bConfig;
+
+impl BinaryFieldConfig for BinaryField64bConfig {
+    const DEGREE: u32 = 64;
+    const MODULUS: u128 = 0x1B;
+}
+
+impl BinaryTowerConfig for BinaryField64bConfig {
+    type Subfield = BinaryField32bConfig;
+    const SUBFIELD_GENERATOR: u128 = 0x0B0D_02F8_BFB7_EB13;
+}
+
+/// The field `GF(2^128) = GF(2)[X] / (X^128 + X^7 + X^2 + X + 1)`, whose
+/// modulus is the GHASH polynomial.
+pub type BinaryField128b = BinaryField<BinaryField128bConfig>;
+
+pub struct BinaryField128bConfig;
+
+impl BinaryFieldConfig for BinaryField128bConfig {
+    const DEGREE: u32 = 128;
+    const MODULUS: u128 = 0x87;
+}
+
+impl BinaryTowerConfig for BinaryField128bConfig {
+    type Subfield = BinaryField64bConfig;
+    const SUBFIELD_GENERATOR: u128 = 0x196F_20F8_C1F3_19C5_6ABE_5797_AF39_F9C1;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::{test_rng, vec::Vec, UniformRand};
+
+    fn test_field_axioms<F: Field<BasePrimeField = BinaryField1b>>() {
+        let mut rng = test_rng();
+        let degree = F::extension_degree() as usize;
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let b = F::rand(&mut rng);
+            let c = F::rand(&mut rng);
+            assert_eq!(a + a, F::ZERO);
+            assert_eq!(-a, a);
+            assert_eq!(a - b, a + b);
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * b, b * a);
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a * a, a.square());
+            assert_eq!((a + b).square(), a.square() + b.square());
+            assert_eq!(F::sum_of_products(&[a, b], &[b, c]), a * b + b * c);
+            if !a.is_zero() {
+                assert_eq!(a * a.inverse().unwrap(), F::ONE);
+            }
+            assert_eq!(a.sqrt().unwrap().square(), a);
+            assert_eq!(a.frobenius_map(1), a.square());
+            assert_eq!(a.frobenius_map(degree), a);
+            let elems = a.to_base_prime_field_elements().collect::<Vec<_>>();
+            assert_eq!(elems.len(), degree);
+            assert_eq!(F::from_base_prime_field_elems(elems), Some(a));
+
+            let mut bytes = Vec::new();
+            a.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), degree.div_ceil(8));
+            assert_eq!(F::deserialize_compressed(&bytes[..]).unwrap(), a);
+        }
+        assert!(F::ZERO.inverse().is_none());
+        assert_eq!(F::from(3u64), F::ONE);
+        assert_eq!(F::from(-2i64), F::ZERO);
+        assert!(F::from_base_prime_field_elems([BinaryField1b::ONE]).is_none());
+    }
+
+    #[test]
+    fn test_binary_fields() {
+        test_field_axioms::<BinaryField2b>();
+        test_field_axioms::<BinaryField4b>();
+        test_field_axioms::<BinaryField8b>();
+        test_field_axioms::<BinaryField16b>();
+        test_field_axioms::<BinaryField32b>();
+        test_field_axioms::<BinaryField64b>();
+        test_field_axioms::<BinaryField128b>();
+    }
+
+    fn test_tower_embedding<P: BinaryTowerConfig>() {
+        let mut rng = test_rng();
+        type Sub<P> = BinaryField<<P as BinaryTowerConfig>::Subfield>;
+        let embed = BinaryField::<P>::embed;
+        assert_eq!(embed(Sub::<P>::ONE), BinaryField::ONE);
+        for _ in 0..20 {
+            let a = Sub::<P>::rand(&mut rng);
+            let b = Sub::<P>::rand(&mut rng);
+            assert_eq!(embed(a + b), embed(a) + embed(b));
+            assert_eq!(embed(a * b), embed(a) * embed(b));
+            assert_eq!(embed(a).try_project(), Some(a));
+
+            // The subfield consists of the fixed points of `x -> x^(2^(n/2))`.
+            let c = BinaryField::<P>::rand(&mut rng);
+            let in_subfield = c.frobenius_map(P::Subfield::DEGREE as usize) == c;
+            assert_eq!(c.try_project().is_some(), in_subfield);
+        }
+    }
+
+    #[test]
+    fn test_binary_tower() {
+        test_tower_embedding::<BinaryField4bConfig>();
+        test_tower_embedding::<BinaryField8bConfig>();
+        test_tower_embedding::<BinaryField16bConfig>();
+        test_tower_embedding::<BinaryField32bConfig>();
+        test_tower_embedding::<BinaryField64bConfig>();
+        test_tower_embedding::<BinaryField128bConfig>();
+
+        // Embeddings compose along the tower.
+        let a = BinaryField2b::new(0b10);
+        let b = BinaryField8b::embed(BinaryField4b::embed(a));
+        assert_eq!(b.square() + b + BinaryField8b::ONE, BinaryField8b::ZERO);
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        // Every nonzero element `a` of `GF(2^n)` satisfies `a^(2^n - 1) = 1`.
+        let mut rng = test_rng();
+        let a = BinaryField8b::rand(&mut rng);
+        assert_eq!(a.pow([255]), BinaryField8b::ONE);
+        let a = BinaryField128b::rand(&mut rng);
+        assert_eq!(a.pow([u64::MAX, u64::MAX]), BinaryField128b::ONE);
+    }
+
+    #[test]
+    fn test_aes_field() {
+        // Examples from FIPS 197, Section 4.2.
+        let a = BinaryField8b::new(0x57);
+        assert_eq!(a * BinaryField8b::new(0x83), BinaryField8b::new(0xC1));
+        assert_eq!(a * BinaryField8b::new(0x13), BinaryField8b::new(0xFE));
+        assert_eq!(
+            BinaryField8b::new(0x53).inverse(),
+            Some(BinaryField8b::new(0xCA))
+        );
+    }
+
+    #[test]
+    fn test_gf2() {
+        let one = BinaryField1b::ONE;
+        assert_eq!(one + one, BinaryField1b::ZERO);
+        assert_eq!(one.sqrt(), Some(one));
+        assert_eq!(BinaryField1b::characteristic(), &[2]);
+        assert_eq!(BinaryField128b::characteristic(), &[2]);
+    }
+}
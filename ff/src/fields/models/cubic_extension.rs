@@ -1,5 +1,5 @@
 use crate::{
-    fields::{Field, PrimeField},
+    fields::{utils::split_top_level, ExtensionField, Field, PrimeField},
     AdditiveGroup, LegendreSymbol, One, SqrtPrecomputation, ToConstraintField, UniformRand, Zero,
 };
 use ark_serialize::{
@@ -16,10 +16,28 @@ use ark_std::{
         distributions::{Distribution, Standard},
         Rng,
     },
+    str::FromStr,
     vec::*,
 };
 use zeroize::Zeroize;
 
+/// The algorithm used to multiply elements of an extension field in terms of
+/// multiplications in its base field.
+///
+/// Which one is fastest depends on the relative cost of multiplications and
+/// additions in the base field, and thus on the target: Karatsuba is a good
+/// default, and schoolbook multiplication avoids extra additions on small
+/// targets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TowerMulStrategy {
+    /// Karatsuba multiplication, which takes 3 base field multiplications
+    /// for quadratic extensions and 6 for cubic extensions.
+    Karatsuba,
+    /// Schoolbook multiplication, which takes 4 base field multiplications
+    /// for quadratic extensions and 9 for cubic extensions.
+    Schoolbook,
+}
+
 /// Defines a Cubic extension field from a cubic non-residue.
 pub trait CubicExtConfig: 'static + Send + Sync + Sized {
     /// The prime field that this cubic extension is eventually an extension of.
@@ -43,6 +61,9 @@ pub trait CubicExtConfig: 'static + Send + Sync + Sized {
     /// The cubic non-residue used to construct the extension.
     const NONRESIDUE: Self::BaseField;
 
+    /// The algorithm used for multiplication.
+    const MUL_STRATEGY: TowerMulStrategy = TowerMulStrategy::Karatsuba;
+
     /// Coefficients for the Frobenius automorphism.
     const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff];
     const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff];
@@ -132,6 +153,20 @@ impl<P: CubicExtConfig> CubicExtField<P> {
     }
 }
 
+impl<P: CubicExtConfig> ExtensionField for CubicExtField<P> {
+    type BaseField = P::BaseField;
+
+    fn relative_norm(&self) -> P::BaseField {
+        Self::norm(self)
+    }
+
+    /// The conjugates of `X` are `X`, `omega * X` and `omega^2 * X`, where
+    /// `omega` is a primitive cube root of unity, so the trace is `3 * c0`.
+    fn relative_trace(&self) -> P::BaseField {
+        self.c0.double() + self.c0
+    }
+}
+
 impl<P: CubicExtConfig> Zero for CubicExtField<P> {
     fn zero() -> Self {
         Self::new(P::BaseField::ZERO, P::BaseField::ZERO, P::BaseField::ZERO)
@@ -322,6 +357,14 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         }
     }
 
+    fn trace(&self) -> Self::BasePrimeField {
+        self.relative_trace().trace()
+    }
+
+    fn norm(&self) -> Self::BasePrimeField {
+        Field::norm(&self.relative_norm())
+    }
+
     fn frobenius_map_in_place(&mut self, power: usize) {
         self.c0.frobenius_map_in_place(power);
         self.c1.frobenius_map_in_place(power);
@@ -365,6 +408,30 @@ impl<P: CubicExtConfig> PartialOrd for CubicExtField<P> {
     }
 }
 
+#[cfg(feature = "ct")]
+impl<P: CubicExtConfig> subtle::ConstantTimeEq for CubicExtField<P>
+where
+    P::BaseField: subtle::ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: CubicExtConfig> subtle::ConditionallySelectable for CubicExtField<P>
+where
+    P::BaseField: subtle::ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+            P::BaseField::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
 impl<P: CubicExtConfig> Zeroize for CubicExtField<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -566,10 +633,6 @@ impl<'a, P: CubicExtConfig> MulAssign<&'a Self> for CubicExtField<P> {
     #[inline]
     #[allow(clippy::many_single_char_names)]
     fn mul_assign(&mut self, other: &Self) {
-        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
-        // AbstractPairing-Friendly
-        // Fields.pdf; Section 4 (Karatsuba)
-
         let a = other.c0;
         let b = other.c1;
         let c = other.c2;
@@ -578,17 +641,31 @@ impl<'a, P: CubicExtConfig> MulAssign<&'a Self> for CubicExtField<P> {
         let e = self.c1;
         let f = self.c2;
 
-        let ad = d * &a;
-        let be = e * &b;
-        let cf = f * &c;
-
-        let x = (e + &f) * &(b + &c) - &be - &cf;
-        let y = (d + &e) * &(a + &b) - &ad - &be;
-        let z = (d + &f) * &(a + &c) - &ad + &be - &cf;
-
-        self.c0 = ad + &P::mul_base_field_by_nonresidue(x);
-        self.c1 = y + &P::mul_base_field_by_nonresidue(cf);
-        self.c2 = z;
+        match P::MUL_STRATEGY {
+            TowerMulStrategy::Karatsuba => {
+                // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+                // AbstractPairing-Friendly
+                // Fields.pdf; Section 4 (Karatsuba)
+                let ad = d * &a;
+                let be = e * &b;
+                let cf = f * &c;
+
+                let x = (e + &f) * &(b + &c) - &be - &cf;
+                let y = (d + &e) * &(a + &b) - &ad - &be;
+                let z = (d + &f) * &(a + &c) - &ad + &be - &cf;
+
+                self.c0 = ad + &P::mul_base_field_by_nonresidue(x);
+                self.c1 = y + &P::mul_base_field_by_nonresidue(cf);
+                self.c2 = z;
+            },
+            TowerMulStrategy::Schoolbook => {
+                let x = e * &c + &(f * &b);
+                let y = f * &c;
+                self.c0 = d * &a + &P::mul_base_field_by_nonresidue(x);
+                self.c1 = d * &b + &(e * &a) + &P::mul_base_field_by_nonresidue(y);
+                self.c2 = d * &c + &(e * &b) + &(f * &a);
+            },
+        }
     }
 }
 
@@ -605,6 +682,31 @@ impl<P: CubicExtConfig> fmt::Display for CubicExtField<P> {
     }
 }
 
+impl<P: CubicExtConfig> FromStr for CubicExtField<P>
+where
+    P::BaseField: FromStr,
+{
+    type Err = ();
+
+    /// Parses the output of `Display`, i.e. `CubicExtField(c0, c1, c2)`, where
+    /// the coefficients are parsed as elements of the base field.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .strip_prefix("CubicExtField(")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(())?;
+        match split_top_level(inner, ", ")[..] {
+            [c0, c1, c2] => {
+                let c0 = c0.parse().map_err(|_| ())?;
+                let c1 = c1.parse().map_err(|_| ())?;
+                let c2 = c2.parse().map_err(|_| ())?;
+                Ok(Self::new(c0, c1, c2))
+            },
+            _ => Err(()),
+        }
+    }
+}
+
 impl<P: CubicExtConfig> CanonicalSerializeWithFlags for CubicExtField<P> {
     #[inline]
     fn serialize_with_flags<W: Write, F: Flags>(
@@ -702,11 +804,43 @@ mod cube_ext_tests {
     use super::*;
     use ark_std::test_rng;
     use ark_test_curves::{
-        ark_ff::Field,
-        bls12_381::{Fq, Fq2, Fq6},
+        ark_ff::{Field, Fp6, Fp6Config, TowerMulStrategy},
+        bls12_381::{Fq, Fq2, Fq2Config, Fq6, Fq6Config},
         mnt6_753::Fq3,
     };
 
+    macro_rules! fq6_config_with_strategy {
+        ($name:ident, $strategy:ident) => {
+            #[derive(Clone, Copy)]
+            struct $name;
+
+            impl Fp6Config for $name {
+                type Fp2Config = Fq2Config;
+                const NONRESIDUE: Fq2 = Fq6Config::NONRESIDUE;
+                const MUL_STRATEGY: TowerMulStrategy = TowerMulStrategy::$strategy;
+                const FROBENIUS_COEFF_FP6_C1: &'static [Fq2] = Fq6Config::FROBENIUS_COEFF_FP6_C1;
+                const FROBENIUS_COEFF_FP6_C2: &'static [Fq2] = Fq6Config::FROBENIUS_COEFF_FP6_C2;
+            }
+        };
+    }
+
+    fq6_config_with_strategy!(SchoolbookConfig, Schoolbook);
+
+    fn mul_with_strategy<P: Fp6Config<Fp2Config = Fq2Config>>(a: Fq6, b: Fq6) -> Fq6 {
+        let c = Fp6::<P>::new(a.c0, a.c1, a.c2) * Fp6::<P>::new(b.c0, b.c1, b.c2);
+        Fq6::new(c.c0, c.c1, c.c2)
+    }
+
+    #[test]
+    fn test_mul_strategies() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a: Fq6 = rng.gen();
+            let b: Fq6 = rng.gen();
+            assert_eq!(mul_with_strategy::<SchoolbookConfig>(a, b), a * b);
+        }
+    }
+
     #[test]
     fn test_norm_for_towers() {
         // First, test the simple fp3
@@ -0,0 +1,857 @@
+//! Prime fields whose modulus is only known at runtime.
+//!
+//! [`Fp`](crate::Fp) requires the modulus to be fixed at compile time via an
+//! [`FpConfig`](crate::FpConfig). This is not always possible, for instance
+//! when field parameters are read from a configuration file. [`DynFp`] holds
+//! the (precomputed) parameters of such a field, and [`DynFpElem`] is an
+//! element of that field that borrows these parameters.
+//!
+//! Elements are stored in Montgomery form, exactly like in
+//! [`MontBackend`](crate::MontBackend), but all constants are computed
+//! once in [`DynFp::new`].
+//!
+//! Since [`Field`](crate::Field) and [`PrimeField`] take the modulus, the
+//! identities and the FFT constants from associated constants of the type,
+//! runtime fields cannot implement them. Elements of a field that also has a
+//! [`PrimeField`] type convert to and from it with
+//! [`DynFp::from_prime_field`] and [`DynFpElem::into_prime_field`].
+//!
+//! ```rust
+//! use ark_ff::{BigInt, DynFp};
+//! use ark_std::str::FromStr;
+//!
+//! let modulus = BigInt::<1>::from_str("17").unwrap();
+//! let field = DynFp::new(modulus).unwrap();
+//!
+//! let a = field.from_u64(9);
+//! let b = field.from_u64(10);
+//! assert_eq!(a + b, field.from_u64(2));
+//! assert_eq!(a * b, field.from_u64(5));
+//! assert_eq!(a / b * b, a);
+//! assert_eq!(a.square().sqrt().map(|s| s.square()), Some(a.square()));
+//! ```
+use crate::{
+    biginteger::arithmetic as fa, BigInt, BigInteger, BitIteratorBE, LegendreSymbol, PrimeField,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    io::{Read, Write},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+};
+
+/// The parameters of a prime field `F_p` whose modulus `p` is supplied at
+/// runtime. `p` must fit into `N` 64-bit limbs.
+///
+/// All constants required for Montgomery arithmetic, as well as for
+/// Tonelli-Shanks square roots, are computed once on construction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynFp<const N: usize> {
+    /// The modulus `p` of the field.
+    modulus: BigInt<N>,
+    /// `R = 2^(64 * N) mod p`, i.e. one in Montgomery form.
+    r: BigInt<N>,
+    /// `R^2 mod p`.
+    r2: BigInt<N>,
+    /// `-p^{-1} mod 2^64`.
+    inv: u64,
+    /// `(p - 1) / 2`.
+    modulus_minus_one_div_two: BigInt<N>,
+    /// The largest `s` such that `2^s` divides `p - 1`.
+    two_adicity: u32,
+    /// `(t - 1) / 2`, where `p - 1 = 2^s * t` for odd `t`.
+    trace_minus_one_div_two: BigInt<N>,
+    /// A quadratic non-residue raised to the power `t`, in Montgomery form.
+    quadratic_nonresidue_to_trace: BigInt<N>,
+}
+
+impl<const N: usize> DynFp<N> {
+    /// Constructs the field of integers modulo `modulus`.
+    ///
+    /// Returns `None` if `modulus` is not an odd prime. Primality is checked
+    /// with the Miller-Rabin test, which is deterministic for moduli below
+    /// `3.3 * 10^24`; larger composites are only accepted if they are
+    /// constructed specifically to pass it.
+    pub fn new(modulus: BigInt<N>) -> Option<Self> {
+        if N == 0 || modulus.is_even() || modulus <= BigInt::from(1u64) {
+            return None;
+        }
+
+        // Compute -p^{-1} mod 2^64; see `montgomery_backend::inv`.
+        let mut inv = 1u64;
+        for _ in 0..63 {
+            inv = inv.wrapping_mul(inv);
+            inv = inv.wrapping_mul(modulus.0[0]);
+        }
+
+        let trace = modulus.two_adic_coefficient();
+        let mut field = Self {
+            modulus,
+            r: modulus.montgomery_r(),
+            r2: modulus.montgomery_r2(),
+            inv: inv.wrapping_neg(),
+            modulus_minus_one_div_two: modulus.divide_by_2_round_down(),
+            two_adicity: modulus.two_adic_valuation(),
+            trace_minus_one_div_two: trace.divide_by_2_round_down(),
+            quadratic_nonresidue_to_trace: BigInt::zero(),
+        };
+        if !field.is_prime() {
+            return None;
+        }
+
+        // Search for a small quadratic non-residue; for an odd prime, half of
+        // the non-zero elements are non-residues, so this terminates quickly.
+        let mut candidate = 2u64;
+        let qnr = loop {
+            let elem = field.from_u64(candidate);
+            if elem.legendre().is_qnr() {
+                break elem;
+            }
+            candidate = candidate.checked_add(1)?;
+        };
+        let quadratic_nonresidue_to_trace = qnr.pow(trace).value;
+        field.quadratic_nonresidue_to_trace = quadratic_nonresidue_to_trace;
+        Some(field)
+    }
+
+    /// Constructs the field with the same modulus as `F`.
+    pub fn for_prime_field<F: PrimeField<BigInt = BigInt<N>>>() -> Self {
+        Self::new(F::MODULUS).unwrap()
+    }
+
+    /// Converts an element of `F` into an element of this field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus of `F` differs from that of this field.
+    pub fn from_prime_field<F: PrimeField<BigInt = BigInt<N>>>(&self, elem: F) -> DynFpElem<'_, N> {
+        assert_eq!(F::MODULUS, self.modulus, "moduli differ");
+        self.from_bigint(elem.into_bigint()).unwrap()
+    }
+
+    /// Returns the modulus `p` of the field.
+    #[inline]
+    pub fn modulus(&self) -> BigInt<N> {
+        self.modulus
+    }
+
+    /// Returns the size of the modulus in bits.
+    #[inline]
+    pub fn modulus_bit_size(&self) -> u32 {
+        self.modulus.num_bits()
+    }
+
+    /// Returns the two-adicity of `p - 1`.
+    #[inline]
+    pub fn two_adicity(&self) -> u32 {
+        self.two_adicity
+    }
+
+    /// Returns the additive identity of the field.
+    #[inline]
+    pub fn zero(&self) -> DynFpElem<'_, N> {
+        DynFpElem::new_unchecked(self, BigInt::zero())
+    }
+
+    /// Returns the multiplicative identity of the field.
+    #[inline]
+    pub fn one(&self) -> DynFpElem<'_, N> {
+        DynFpElem::new_unchecked(self, self.r)
+    }
+
+    /// Constructs a field element from an integer in the range `0..p`.
+    /// Returns `None` if the integer is outside this range.
+    pub fn from_bigint(&self, int: BigInt<N>) -> Option<DynFpElem<'_, N>> {
+        if int >= self.modulus {
+            None
+        } else {
+            Some(DynFpElem::new_unchecked(
+                self,
+                self.mont_mul(&int, &self.r2),
+            ))
+        }
+    }
+
+    /// Constructs a field element from a `u64`, reducing it modulo `p`.
+    pub fn from_u64(&self, int: u64) -> DynFpElem<'_, N> {
+        let int = if N == 1 {
+            BigInt::from(int % self.modulus.0[0])
+        } else {
+            BigInt::from(int)
+        };
+        // For `N > 1` the modulus is larger than `2^64` if the top limbs are
+        // non-zero; otherwise fall back to a modular reduction.
+        match self.from_bigint(int) {
+            Some(elem) => elem,
+            None => self.from_le_bytes_mod_order(&int.to_bytes_le()),
+        }
+    }
+
+    /// Reads bytes in little-endian, and converts them to a field element,
+    /// reducing modulo `p` if necessary.
+    pub fn from_le_bytes_mod_order(&self, bytes: &[u8]) -> DynFpElem<'_, N> {
+        let window_size = self.from_bigint_reduced(BigInt::from(256u64));
+        bytes.iter().rev().fold(self.zero(), |mut res, byte| {
+            res *= window_size;
+            res += self.from_bigint_reduced(BigInt::from(*byte));
+            res
+        })
+    }
+
+    /// Reads bytes in big-endian, and converts them to a field element,
+    /// reducing modulo `p` if necessary.
+    pub fn from_be_bytes_mod_order(&self, bytes: &[u8]) -> DynFpElem<'_, N> {
+        let mut bytes_copy = bytes.to_vec();
+        bytes_copy.reverse();
+        self.from_le_bytes_mod_order(&bytes_copy)
+    }
+
+    /// Samples a uniformly random field element.
+    pub fn rand<R: Rng + ?Sized>(&self, rng: &mut R) -> DynFpElem<'_, N> {
+        let num_bits = self.modulus_bit_size() as usize;
+        loop {
+            let mut value: BigInt<N> = Standard.sample(rng);
+            // Mask away all bits above the modulus size.
+            for (i, limb) in value.0.iter_mut().enumerate() {
+                let limb_start = 64 * i;
+                if limb_start >= num_bits {
+                    *limb = 0;
+                } else if num_bits - limb_start < 64 {
+                    *limb &= (1u64 << (num_bits - limb_start)) - 1;
+                }
+            }
+            if value < self.modulus {
+                return DynFpElem::new_unchecked(self, value);
+            }
+        }
+    }
+
+    /// Checks that the modulus, which is odd and larger than one, is prime
+    /// with the Miller-Rabin test, using the first twenty primes as bases.
+    fn is_prime(&self) -> bool {
+        const BASES: [u64; 20] = [
+            2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+        ];
+        let minus_one = -self.one();
+        let trace = self.modulus.two_adic_coefficient();
+        'bases: for base in BASES {
+            if self.modulus == BigInt::from(base) {
+                return true;
+            }
+            // Write `p - 1 = 2^s * t` for odd `t`. If `p` is prime, then
+            // either `base^t = 1`, or `base^(2^i * t) = -1` for some `i < s`.
+            let mut x = self.from_u64(base).pow(trace);
+            if x.is_one() || x == minus_one {
+                continue;
+            }
+            for _ in 1..self.two_adicity {
+                x = x.square();
+                if x == minus_one {
+                    continue 'bases;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    /// Converts small integers, which are guaranteed to be less than `p`
+    /// for the moduli we care about, but reduces otherwise.
+    fn from_bigint_reduced(&self, mut int: BigInt<N>) -> DynFpElem<'_, N> {
+        while int >= self.modulus {
+            int.sub_with_borrow(&self.modulus);
+        }
+        DynFpElem::new_unchecked(self, self.mont_mul(&int, &self.r2))
+    }
+
+    /// Montgomery multiplication, i.e. computes `a * b * R^{-1} mod p`.
+    /// This mirrors the generic (carry-aware) CIOS path of
+    /// [`MontConfig`](crate::MontConfig).
+    fn mont_mul(&self, a: &BigInt<N>, b: &BigInt<N>) -> BigInt<N> {
+        let (mut lo, mut hi) = ([0u64; N], [0u64; N]);
+        for i in 0..N {
+            let mut carry = 0;
+            for j in 0..N {
+                let k = i + j;
+                if k >= N {
+                    hi[k - N] = fa::mac_with_carry(hi[k - N], a.0[i], b.0[j], &mut carry);
+                } else {
+                    lo[k] = fa::mac_with_carry(lo[k], a.0[i], b.0[j], &mut carry);
+                }
+            }
+            hi[i] = carry;
+        }
+        // Montgomery reduction
+        let mut carry2 = 0;
+        for i in 0..N {
+            let tmp = lo[i].wrapping_mul(self.inv);
+            let mut carry = 0;
+            fa::mac_discard(lo[i], tmp, self.modulus.0[0], &mut carry);
+            for j in 1..N {
+                let k = i + j;
+                if k >= N {
+                    hi[k - N] = fa::mac_with_carry(hi[k - N], tmp, self.modulus.0[j], &mut carry);
+                } else {
+                    lo[k] = fa::mac_with_carry(lo[k], tmp, self.modulus.0[j], &mut carry);
+                }
+            }
+            carry2 = fa::adc(&mut hi[i], carry, carry2);
+        }
+        let mut result = BigInt::new(hi);
+        if carry2 != 0 || result >= self.modulus {
+            result.sub_with_borrow(&self.modulus);
+        }
+        result
+    }
+
+    /// Converts out of Montgomery form.
+    fn mont_reduce(&self, a: &BigInt<N>) -> BigInt<N> {
+        let mut r = a.0;
+        for i in 0..N {
+            let k = r[i].wrapping_mul(self.inv);
+            let mut carry = 0;
+
+            fa::mac_with_carry(r[i], k, self.modulus.0[0], &mut carry);
+            for j in 1..N {
+                r[(j + i) % N] =
+                    fa::mac_with_carry(r[(j + i) % N], k, self.modulus.0[j], &mut carry);
+            }
+            r[i % N] = carry;
+        }
+        BigInt::new(r)
+    }
+}
+
+impl<const N: usize> CanonicalSerialize for DynFp<N> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.modulus.serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.modulus.serialized_size(compress)
+    }
+}
+
+impl<const N: usize> Valid for DynFp<N> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> CanonicalDeserialize for DynFp<N> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let modulus = BigInt::<N>::deserialize_with_mode(reader, compress, validate)?;
+        Self::new(modulus).ok_or(SerializationError::InvalidData)
+    }
+}
+
+/// An element of the prime field described by a [`DynFp`].
+///
+/// Binary operations between elements of different fields are a logic error,
+/// and panic in debug builds.
+#[derive(Copy, Clone)]
+pub struct DynFpElem<'a, const N: usize> {
+    field: &'a DynFp<N>,
+    /// The element in Montgomery form.
+    value: BigInt<N>,
+}
+
+impl<'a, const N: usize> DynFpElem<'a, N> {
+    /// Constructs an element from an integer that is already in Montgomery
+    /// form and reduced modulo `field.modulus()`.
+    #[inline]
+    pub fn new_unchecked(field: &'a DynFp<N>, value: BigInt<N>) -> Self {
+        Self { field, value }
+    }
+
+    /// Returns the field this element belongs to.
+    #[inline]
+    pub fn field(&self) -> &'a DynFp<N> {
+        self.field
+    }
+
+    /// Converts the element to an integer in the range `0..p`.
+    #[inline]
+    pub fn into_bigint(self) -> BigInt<N> {
+        self.field.mont_reduce(&self.value)
+    }
+
+    /// Converts the element into an element of `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus of `F` differs from that of the field of `self`.
+    pub fn into_prime_field<F: PrimeField<BigInt = BigInt<N>>>(self) -> F {
+        assert_eq!(F::MODULUS, self.field.modulus, "moduli differ");
+        F::from_bigint(self.into_bigint()).unwrap()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.value == self.field.r
+    }
+
+    /// Returns `2 * self`.
+    #[must_use]
+    #[inline]
+    pub fn double(&self) -> Self {
+        *self + self
+    }
+
+    /// Returns `self * self`.
+    #[must_use]
+    #[inline]
+    pub fn square(&self) -> Self {
+        *self * self
+    }
+
+    /// Returns `self^exp`, where `exp` is an integer represented with `u64` limbs,
+    /// least significant limb first.
+    #[must_use]
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = self.field.one();
+        for i in BitIteratorBE::without_leading_zeros(exp) {
+            res = res.square();
+            if i {
+                res *= self;
+            }
+        }
+        res
+    }
+
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        // Guajardo Kumar Paar Pelzl
+        // Efficient Software-Implementation of Finite Fields with Applications to
+        // Cryptography
+        // Algorithm 16 (BEA for Inversion in Fp)
+        let field = self.field;
+        let modulus = field.modulus;
+        let modulus_has_spare_bit = modulus.0[N - 1] >> 63 == 0;
+        let one = BigInt::from(1u64);
+
+        let mut u = self.value;
+        let mut v = modulus;
+        let mut b = Self::new_unchecked(field, field.r2); // Avoids unnecessary reduction step.
+        let mut c = field.zero();
+
+        while u != one && v != one {
+            while u.is_even() {
+                u.div2();
+
+                if b.value.is_even() {
+                    b.value.div2();
+                } else {
+                    let carry = b.value.add_with_carry(&modulus);
+                    b.value.div2();
+                    if !modulus_has_spare_bit && carry {
+                        b.value.0[N - 1] |= 1 << 63;
+                    }
+                }
+            }
+
+            while v.is_even() {
+                v.div2();
+
+                if c.value.is_even() {
+                    c.value.div2();
+                } else {
+                    let carry = c.value.add_with_carry(&modulus);
+                    c.value.div2();
+                    if !modulus_has_spare_bit && carry {
+                        c.value.0[N - 1] |= 1 << 63;
+                    }
+                }
+            }
+
+            if v < u {
+                u.sub_with_borrow(&v);
+                b -= &c;
+            } else {
+                v.sub_with_borrow(&u);
+                c -= &b;
+            }
+        }
+
+        if u == one {
+            Some(b)
+        } else {
+            Some(c)
+        }
+    }
+
+    /// Returns the quadratic residuosity of `self`, computed via Euler's criterion.
+    pub fn legendre(&self) -> LegendreSymbol {
+        let s = self.pow(self.field.modulus_minus_one_div_two);
+        if s.is_zero() {
+            LegendreSymbol::Zero
+        } else if s.is_one() {
+            LegendreSymbol::QuadraticResidue
+        } else {
+            LegendreSymbol::QuadraticNonResidue
+        }
+    }
+
+    /// Returns a square root of `self`, if it exists.
+    /// This uses the Tonelli-Shanks algorithm, which works for every modulus.
+    #[must_use]
+    pub fn sqrt(&self) -> Option<Self> {
+        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
+        if self.is_zero() {
+            return Some(*self);
+        }
+        let field = self.field;
+        let two_adicity = field.two_adicity as usize;
+        let mut z = Self::new_unchecked(field, field.quadratic_nonresidue_to_trace);
+        let mut w = self.pow(field.trace_minus_one_div_two);
+        let mut x = w * self;
+        let mut b = x * w;
+
+        let mut v = two_adicity;
+
+        while !b.is_one() {
+            let mut k = 0usize;
+
+            let mut b2k = b;
+            while !b2k.is_one() {
+                // invariant: b2k = b^(2^k) after entering this loop
+                b2k = b2k.square();
+                k += 1;
+                if k == two_adicity {
+                    // self^(t * 2^k) = self^(p - 1) / 2 = -1, so no square root exists.
+                    return None;
+                }
+            }
+            let j = v - k;
+            w = z;
+            for _ in 1..j {
+                w = w.square();
+            }
+
+            z = w.square();
+            b *= z;
+            x *= w;
+            v = k;
+        }
+        (x.square() == *self).then_some(x)
+    }
+
+    #[inline]
+    fn check_same_field(&self, other: &Self) {
+        debug_assert!(
+            core::ptr::eq(self.field, other.field) || self.field == other.field,
+            "operands belong to different fields"
+        );
+    }
+}
+
+impl<const N: usize> PartialEq for DynFpElem<'_, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.field.modulus == other.field.modulus
+    }
+}
+
+impl<const N: usize> Eq for DynFpElem<'_, N> {}
+
+impl<const N: usize> Hash for DynFpElem<'_, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.field.modulus.hash(state);
+        self.value.hash(state);
+    }
+}
+
+impl<const N: usize> Debug for DynFpElem<'_, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.into_bigint(), f)
+    }
+}
+
+/// Outputs a string containing the value of `self`,
+/// represented as a decimal without leading zeroes.
+impl<const N: usize> Display for DynFpElem<'_, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.into_bigint(), f)
+    }
+}
+
+impl<const N: usize> zeroize::Zeroize for DynFpElem<'_, N> {
+    // The field parameters are public and do not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<const N: usize> CanonicalSerialize for DynFpElem<'_, N> {
+    /// Serializes the canonical (non-Montgomery) representation of `self`.
+    /// Use [`DynFp::from_bigint`] to deserialize.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.into_bigint().serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.value.serialized_size(compress)
+    }
+}
+
+impl<const N: usize> Neg for DynFpElem<'_, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        if !self.is_zero() {
+            let mut tmp = self.field.modulus;
+            tmp.sub_with_borrow(&self.value);
+            self.value = tmp;
+        }
+        self
+    }
+}
+
+impl<const N: usize> AddAssign<&Self> for DynFpElem<'_, N> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.check_same_field(other);
+        let carry = self.value.add_with_carry(&other.value);
+        if carry || self.value >= self.field.modulus {
+            self.value.sub_with_borrow(&self.field.modulus);
+        }
+    }
+}
+
+impl<const N: usize> SubAssign<&Self> for DynFpElem<'_, N> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.check_same_field(other);
+        // If `other` is larger than `self`, add the modulus to self first.
+        if other.value > self.value {
+            self.value.add_with_carry(&self.field.modulus);
+        }
+        self.value.sub_with_borrow(&other.value);
+    }
+}
+
+impl<const N: usize> MulAssign<&Self> for DynFpElem<'_, N> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        self.check_same_field(other);
+        self.value = self.field.mont_mul(&self.value, &other.value);
+    }
+}
+
+/// Computes `self *= other.inverse()` if `other.inverse()` is `Some`, and
+/// panics otherwise.
+impl<const N: usize> DivAssign<&Self> for DynFpElem<'_, N> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        *self *= &other.inverse().unwrap();
+    }
+}
+
+macro_rules! impl_dyn_fp_ops {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)*) => {$(
+        impl<const N: usize> $assign_trait<Self> for DynFpElem<'_, N> {
+            #[inline]
+            fn $assign_method(&mut self, other: Self) {
+                $assign_trait::$assign_method(self, &other)
+            }
+        }
+
+        impl<const N: usize> $trait<&Self> for DynFpElem<'_, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, other: &Self) -> Self {
+                $assign_trait::$assign_method(&mut self, other);
+                self
+            }
+        }
+
+        impl<const N: usize> $trait<Self> for DynFpElem<'_, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, other: Self) -> Self {
+                $assign_trait::$assign_method(&mut self, &other);
+                self
+            }
+        }
+    )*};
+}
+
+impl_dyn_fp_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::{rand::Rng, string::ToString, test_rng, vec::*};
+    use ark_test_curves::{
+        ark_ff::{Field, PrimeField, UniformRand},
+        bls12_381::Fr,
+        secp256k1::Fq,
+    };
+
+    const ITERATIONS: usize = 100;
+
+    macro_rules! check_against {
+        ($field:ty, $n:literal) => {{
+            let mut rng = test_rng();
+            let dyn_field = DynFp::<$n>::new(BigInt::new(<$field>::MODULUS.0)).unwrap();
+            let to_dyn = |f: $field| {
+                dyn_field
+                    .from_bigint(BigInt::new(f.into_bigint().0))
+                    .unwrap()
+            };
+
+            assert!(dyn_field.one().is_one());
+            assert!(dyn_field.zero().is_zero());
+            assert_eq!(dyn_field.two_adicity(), <$field>::TWO_ADICITY);
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let b = <$field>::rand(&mut rng);
+                let (da, db) = (to_dyn(a), to_dyn(b));
+
+                assert_eq!(da.into_bigint().0, a.into_bigint().0);
+                assert_eq!(da + db, to_dyn(a + b));
+                assert_eq!(da - db, to_dyn(a - b));
+                assert_eq!(da * db, to_dyn(a * b));
+                assert_eq!(-da, to_dyn(-a));
+                assert_eq!(da.double(), to_dyn(a.double()));
+                assert_eq!(da.square(), to_dyn(a.square()));
+                assert_eq!(da.inverse(), a.inverse().map(to_dyn));
+                assert_eq!(da.pow([7u64, 3]), to_dyn(a.pow([7u64, 3])));
+                assert_eq!(da.legendre() as i8, a.legendre() as i8);
+                assert_eq!(
+                    da.sqrt().map(|s| s.square()),
+                    a.sqrt().map(|s| to_dyn(s.square()))
+                );
+                assert_eq!(da.square().sqrt().map(|s| s.square()), Some(da.square()));
+                assert_eq!(da.to_string(), a.to_string());
+
+                let bytes: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+                assert_eq!(
+                    dyn_field.from_le_bytes_mod_order(&bytes),
+                    to_dyn(<$field>::from_le_bytes_mod_order(&bytes))
+                );
+                assert_eq!(
+                    dyn_field.from_u64(u64::MAX),
+                    to_dyn(<$field>::from(u64::MAX))
+                );
+                assert!(dyn_field.rand(&mut rng).into_bigint() < dyn_field.modulus());
+            }
+            assert_eq!(dyn_field.zero().inverse(), None);
+        }};
+    }
+
+    #[test]
+    fn test_against_bls12_381_fr() {
+        check_against!(Fr, 4);
+    }
+
+    #[test]
+    fn test_against_secp256k1_fq() {
+        // The secp256k1 base field modulus has no spare bit.
+        check_against!(Fq, 4);
+    }
+
+    #[test]
+    fn test_small_modulus() {
+        let field = DynFp::new(BigInt::<2>::from(17u64)).unwrap();
+        let a = field.from_u64(9);
+        let b = field.from_u64(10);
+        assert_eq!(a, field.from_u64(26));
+        assert_eq!(a - b, field.from_u64(16));
+        assert_eq!(a + b, field.from_u64(2));
+        assert_eq!(a * b, field.from_u64(5));
+        assert_eq!(a.square(), field.from_u64(13));
+        assert_eq!(a / b, a * b.inverse().unwrap());
+        assert_eq!(
+            field.from_u64(3).legendre(),
+            LegendreSymbol::QuadraticNonResidue
+        );
+    }
+
+    #[test]
+    fn test_prime_field_conversion() {
+        use crate::Goldilocks;
+
+        let mut rng = test_rng();
+        let field = DynFp::for_prime_field::<Goldilocks>();
+        for _ in 0..ITERATIONS {
+            let a = Goldilocks::rand(&mut rng);
+            let b = Goldilocks::rand(&mut rng);
+            let (da, db) = (field.from_prime_field(a), field.from_prime_field(b));
+            assert_eq!(da.into_prime_field::<Goldilocks>(), a);
+            assert_eq!((da * db).into_prime_field::<Goldilocks>(), a * b);
+        }
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        assert!(DynFp::new(BigInt::<1>::from(0u64)).is_none());
+        assert!(DynFp::new(BigInt::<1>::from(1u64)).is_none());
+        assert!(DynFp::new(BigInt::<1>::from(16u64)).is_none());
+        // Composite moduli, including the Carmichael number `561 = 3 * 11 * 17`
+        // and the square of a 61-bit prime.
+        assert!(DynFp::new(BigInt::<1>::from(9u64)).is_none());
+        assert!(DynFp::new(BigInt::<1>::from(561u64)).is_none());
+        let mersenne_61 = (1u128 << 61) - 1;
+        let composite = mersenne_61 * mersenne_61;
+        assert!(DynFp::new(BigInt::<2>::new([
+            composite as u64,
+            (composite >> 64) as u64
+        ]))
+        .is_none());
+
+        for prime in [3u64, 5, 7, 71, 73, (1 << 61) - 1] {
+            assert!(DynFp::new(BigInt::<1>::from(prime)).is_some());
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        let field = DynFp::new(BigInt::new(Fr::MODULUS.0)).unwrap();
+        let mut bytes = Vec::new();
+        field.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            DynFp::<4>::deserialize_compressed(&bytes[..]).unwrap(),
+            field
+        );
+    }
+}
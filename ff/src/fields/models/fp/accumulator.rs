@@ -0,0 +1,225 @@
+use super::{montgomery_backend::montgomery_reduce, Fp, MontBackend, MontConfig};
+use crate::{biginteger::arithmetic as fa, const_helpers::MulBuffer, BigInt, BigInteger};
+use ark_std::{marker::PhantomData, ops::AddAssign};
+
+/// An accumulator for sums of products of prime field elements using the
+/// Montgomery backend, which only performs a modular reduction when calling
+/// [`reduce`](Self::reduce).
+///
+/// Terms are accumulated as unreduced double-width integers in `2N + 1`
+/// limbs. Every term is smaller than `R^2`, where `R = 2^(64 * N)`, so the
+/// accumulator has room for up to `2^64 - 1` terms. It counts the pending
+/// terms, and once that headroom is exhausted, it reduces the sum, which then
+/// counts as a single term, so that the limbs never overflow.
+///
+/// The bounds on which [`reduce`](Self::reduce) relies are checked at compile
+/// time: instantiating the accumulator with an unsuitable modulus, e.g. an
+/// even one, fails to compile.
+///
+/// # Example
+/// ```
+/// # use ark_ff::FpAccumulator;
+/// # use ark_std::{test_rng, UniformRand};
+/// # use ark_test_curves::bls12_381::Fr;
+/// let mut rng = test_rng();
+/// let (a, b, c) = (Fr::rand(&mut rng), Fr::rand(&mut rng), Fr::rand(&mut rng));
+/// let mut acc = FpAccumulator::new();
+/// acc.add_product(&a, &b);
+/// acc.add_product(&b, &c);
+/// acc += &c;
+/// assert_eq!(acc.reduce(), a * b + b * c + c);
+/// ```
+pub struct FpAccumulator<T: MontConfig<N>, const N: usize> {
+    lo: BigInt<N>,
+    hi: BigInt<N>,
+    top: u64,
+    terms: u64,
+    _config: PhantomData<T>,
+}
+
+impl<T: MontConfig<N>, const N: usize> FpAccumulator<T, N> {
+    /// The number of terms that fit in the accumulator: the sum of `k` terms
+    /// is smaller than `k * R^2`, which fits in `2N + 1` limbs for `k < 2^64`.
+    const MAX_TERMS: u64 = u64::MAX;
+
+    /// Asserts at compile time that the headroom suffices for
+    /// [`reduce`](Self::reduce): its Montgomery reductions need an odd
+    /// modulus, and bound the sum of `k` terms, each smaller than
+    /// `MODULUS * R`, by `(k + 1) * MODULUS` after the first reduction, which
+    /// must not exceed `MODULUS * R`. For `k = MAX_TERMS`, this holds as soon
+    /// as `R = 2^(64 * N) >= 2^64`.
+    const HEADROOM: () = assert!(
+        N >= 1 && T::MODULUS.0[0] & 1 == 1,
+        "FpAccumulator requires an odd modulus of at least one limb"
+    );
+
+    /// Returns an accumulator holding zero.
+    pub const fn new() -> Self {
+        let () = Self::HEADROOM;
+        Self {
+            lo: BigInt([0; N]),
+            hi: BigInt([0; N]),
+            top: 0,
+            terms: 0,
+            _config: PhantomData,
+        }
+    }
+
+    /// Adds `a * b` to the accumulator, without reducing it.
+    #[inline]
+    pub fn add_product(&mut self, a: &Fp<MontBackend<T, N>, N>, b: &Fp<MontBackend<T, N>, N>) {
+        let (lo, hi) = a.0.mul(&b.0);
+        self.add_term(&lo, &hi);
+    }
+
+    /// Reduces the accumulated sum to a field element.
+    pub fn reduce(&self) -> Fp<MontBackend<T, N>, N> {
+        // The accumulator holds `t = sum(a_i * b_i) * R^2 (mod MODULUS)`, since
+        // field elements are stored in Montgomery form. Two Montgomery
+        // reductions compute `t * R^-2 (mod MODULUS)`, which is then brought
+        // back into Montgomery form by a multiplication by `R^2`.
+        //
+        // Every term is smaller than `MODULUS * R`, so for `k < 2^64` terms,
+        // after the first reduction `t < (k + 1) * MODULUS <= MODULUS * R`, and
+        // after the second, `t < 2 * MODULUS`.
+        let mut r = MulBuffer {
+            b0: self.lo.0,
+            b1: self.hi.0,
+        };
+        // `top < 2^64 - 1` since it counts at most one carry per term.
+        let top = self.top + montgomery_reduce::<T, N>(&mut r) as u64;
+        let mut b1 = [0u64; N];
+        b1[0] = top;
+        let mut r = MulBuffer { b0: r.b1, b1 };
+        let carry = montgomery_reduce::<T, N>(&mut r);
+        let mut result = BigInt(r.b1);
+        if carry || result >= T::MODULUS {
+            result.sub_with_borrow(&T::MODULUS);
+        }
+        Fp::new_unchecked(result) * Fp::new_unchecked(T::R2)
+    }
+
+    /// Adds the term `hi * R + lo`, which must be smaller than `R^2`, to the
+    /// accumulator, reducing it first if it is full.
+    #[inline(always)]
+    fn add_term(&mut self, lo: &BigInt<N>, hi: &BigInt<N>) {
+        if self.terms == Self::MAX_TERMS {
+            self.compact();
+        }
+        self.add_wide(lo, hi, 0);
+        self.terms += 1;
+    }
+
+    /// Adds the `2N + 1`-limb integer `top * R^2 + hi * R + lo` to the
+    /// accumulator. The caller must ensure that the sum fits.
+    #[inline(always)]
+    fn add_wide(&mut self, lo: &BigInt<N>, hi: &BigInt<N>, top: u64) {
+        let mut carry = 0;
+        for (limb, x) in self.lo.0.iter_mut().zip(&lo.0) {
+            carry = fa::adc(limb, *x, carry);
+        }
+        for (limb, x) in self.hi.0.iter_mut().zip(&hi.0) {
+            carry = fa::adc(limb, *x, carry);
+        }
+        self.top += top + carry;
+    }
+
+    /// Replaces the accumulated sum by its reduction, which takes up a single
+    /// term.
+    fn compact(&mut self) {
+        let sum = self.reduce();
+        *self = Self::new();
+        *self += &sum;
+    }
+}
+
+impl<T: MontConfig<N>, const N: usize> Clone for FpAccumulator<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: MontConfig<N>, const N: usize> Copy for FpAccumulator<T, N> {}
+
+impl<T: MontConfig<N>, const N: usize> Default for FpAccumulator<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adds a field element to the accumulator, without reducing it.
+impl<'a, T: MontConfig<N>, const N: usize> AddAssign<&'a Fp<MontBackend<T, N>, N>>
+    for FpAccumulator<T, N>
+{
+    #[inline]
+    fn add_assign(&mut self, other: &'a Fp<MontBackend<T, N>, N>) {
+        // An element `a` is stored as `a * R`, so it is added as `a * R * R`,
+        // to match the scale of the accumulated products.
+        self.add_term(&BigInt::zero(), &other.0);
+    }
+}
+
+/// Merges two accumulators, e.g. ones that were filled in parallel.
+impl<T: MontConfig<N>, const N: usize> AddAssign for FpAccumulator<T, N> {
+    #[inline]
+    fn add_assign(&mut self, mut other: Self) {
+        if other.terms > Self::MAX_TERMS - self.terms {
+            self.compact();
+            other.compact();
+        }
+        self.add_wide(&other.lo, &other.hi, other.top);
+        self.terms += other.terms;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdditiveGroup;
+    use ark_std::{test_rng, UniformRand};
+
+    struct Config;
+    impl MontConfig<4> for Config {
+        // The scalar field of BLS12-381.
+        const MODULUS: BigInt<4> = BigInt!(
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513"
+        );
+        const GENERATOR: Fp<MontBackend<Self, 4>, 4> = Fp::new_unchecked(BigInt::zero());
+        const TWO_ADIC_ROOT_OF_UNITY: Fp<MontBackend<Self, 4>, 4> =
+            Fp::new_unchecked(BigInt::zero());
+    }
+    type F = Fp<MontBackend<Config, 4>, 4>;
+
+    #[test]
+    fn test_accumulator_headroom() {
+        let rng = &mut test_rng();
+        let elems = (0..8)
+            .map(|_| F::rand(rng))
+            .collect::<ark_std::vec::Vec<_>>();
+
+        let mut acc = FpAccumulator::<Config, 4>::new();
+        let mut expected = F::from(0u8);
+        for pair in elems.chunks(2) {
+            acc.add_product(&pair[0], &pair[1]);
+            expected += pair[0] * pair[1];
+        }
+        assert_eq!(acc.reduce(), expected);
+
+        // Pretend that the accumulator is nearly full: the next terms must
+        // reduce it rather than overflow its limbs.
+        acc.terms = FpAccumulator::<Config, 4>::MAX_TERMS - 1;
+        for pair in elems.chunks(2) {
+            acc.add_product(&pair[0], &pair[1]);
+            acc += &pair[0];
+            expected += pair[0] * pair[1] + pair[0];
+        }
+        assert_eq!(acc.reduce(), expected);
+        assert!(acc.terms < 16);
+
+        let mut other = acc;
+        other.terms = FpAccumulator::<Config, 4>::MAX_TERMS - 1;
+        acc += other;
+        assert_eq!(acc.reduce(), expected.double());
+        assert_eq!(acc.terms, 2);
+    }
+}
@@ -0,0 +1,93 @@
+use super::{Fp32, Fp32Config};
+use crate::{
+    fields::{Fp2, Fp2Config, Fp4, Fp4Config, QuadExtField},
+    FftField,
+};
+
+/// The BabyBear prime field, i.e. the field of integers modulo
+/// `p = 15 * 2^27 + 1`.
+///
+/// The multiplicative group has a subgroup of order `2^27`, so this field
+/// supports radix-2 FFTs of size up to `2^27` directly.
+pub type BabyBear = Fp32<BabyBearConfig>;
+
+/// An [`Fp32Config`] for the BabyBear prime `p = 15 * 2^27 + 1`.
+pub struct BabyBearConfig;
+
+impl Fp32Config for BabyBearConfig {
+    const MODULUS: u32 = 15 * (1 << 27) + 1;
+
+    const GENERATOR: u32 = 31;
+
+    /// `GENERATOR^15`
+    const TWO_ADIC_ROOT_OF_UNITY: u32 = 440564289;
+}
+
+/// The quadratic extension `F_p[u] / (u^2 - 11)` of [`BabyBear`].
+pub type BabyBearFp2 = Fp2<BabyBearFp2Config>;
+
+pub struct BabyBearFp2Config;
+
+impl Fp2Config for BabyBearFp2Config {
+    type Fp = BabyBear;
+
+    /// `11`
+    const NONRESIDUE: BabyBear = BabyBear::new(11);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [BabyBear] = &[
+        // NONRESIDUE^((p^0 - 1) / 2)
+        BabyBear::new(1),
+        // NONRESIDUE^((p^1 - 1) / 2)
+        BabyBear::new(BabyBearConfig::MODULUS - 1),
+    ];
+}
+
+impl FftField for BabyBearFp2 {
+    /// `13 + u`
+    const GENERATOR: Self = QuadExtField::new(BabyBear::new(13), BabyBear::new(1));
+
+    const TWO_ADICITY: u32 = 28;
+
+    /// `GENERATOR^((p^2 - 1) / 2^28)`
+    const TWO_ADIC_ROOT_OF_UNITY: Self =
+        QuadExtField::new(BabyBear::new(0), BabyBear::new(372766025));
+}
+
+/// The quartic extension `F_p[v] / (v^4 - 11)` of [`BabyBear`], constructed
+/// as the quadratic extension `F_{p^2}[v] / (v^2 - u)` of [`BabyBearFp2`].
+pub type BabyBearFp4 = Fp4<BabyBearFp4Config>;
+
+pub struct BabyBearFp4Config;
+
+impl Fp4Config for BabyBearFp4Config {
+    type Fp2Config = BabyBearFp2Config;
+
+    /// `u`
+    const NONRESIDUE: BabyBearFp2 = QuadExtField::new(BabyBear::new(0), BabyBear::new(1));
+
+    /// Coefficients for the Frobenius automorphism, i.e.
+    /// `11^((p^k - 1) / 4)` for `k = 0, 1, 2, 3`.
+    const FROBENIUS_COEFF_FP4_C1: &'static [BabyBear] = &[
+        BabyBear::new(1),
+        BabyBear::new(1728404513),
+        BabyBear::new(2013265920),
+        BabyBear::new(284861408),
+    ];
+}
+
+impl FftField for BabyBearFp4 {
+    /// `4 + v`
+    const GENERATOR: Self = QuadExtField::new(
+        QuadExtField::new(BabyBear::new(4), BabyBear::new(0)),
+        QuadExtField::new(BabyBear::new(0), BabyBear::new(1)),
+    );
+
+    const TWO_ADICITY: u32 = 29;
+
+    /// `GENERATOR^((p^4 - 1) / 2^29)`
+    const TWO_ADIC_ROOT_OF_UNITY: Self = QuadExtField::new(
+        QuadExtField::new(BabyBear::new(0), BabyBear::new(0)),
+        QuadExtField::new(BabyBear::new(0), BabyBear::new(1621129351)),
+    );
+}
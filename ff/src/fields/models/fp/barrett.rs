@@ -0,0 +1,192 @@
+use super::{canonical, Fp, FpConfig};
+use crate::{BigInt, BigInteger, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+
+/// A trait that specifies the constants for a prime field whose elements are
+/// stored in canonical form and multiplied using Barrett reduction.
+///
+/// Unlike [`MontConfig`](super::MontConfig), converting between integers and
+/// field elements is free, which benefits workloads dominated by
+/// (de)serialization and additions, or that interoperate with systems storing
+/// canonical representations. Multiplication is somewhat slower than with
+/// Montgomery reduction.
+pub trait BarrettConfig<const N: usize>: 'static + Sync + Send + Sized {
+    /// The modulus of the field.
+    const MODULUS: BigInt<N>;
+
+    /// A multiplicative generator of the field, in canonical form.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `Self::MODULUS - 1`.
+    const GENERATOR: BigInt<N>;
+
+    /// 2^s root of unity computed by GENERATOR^t, in canonical form.
+    const TWO_ADIC_ROOT_OF_UNITY: BigInt<N>;
+
+    /// An integer `b` such that there exists a multiplicative subgroup
+    /// of size `b^k` for some integer `k`.
+    const SMALL_SUBGROUP_BASE: Option<u32> = None;
+
+    /// The integer `k` such that there exists a multiplicative subgroup
+    /// of size `Self::SMALL_SUBGROUP_BASE^k`.
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = None;
+
+    /// GENERATOR^((MODULUS-1) / (2^s *
+    /// SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY)), in canonical form.
+    /// Used for mixed-radix FFT.
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInt<N>> = None;
+
+    /// Precomputed material for use when computing square roots.
+    /// The default is to use the standard Tonelli-Shanks algorithm,
+    /// or a single exponentiation when `MODULUS % 4 == 3`.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Fp<BarrettBackend<Self, N>, N>>> =
+        canonical::sqrt_precomputation::<BarrettBackend<Self, N>, N>();
+}
+
+/// An [`FpConfig`] that stores elements in canonical form and reduces products
+/// using Barrett reduction.
+pub struct BarrettBackend<T: BarrettConfig<N>, const N: usize>(PhantomData<T>);
+
+/// A prime field whose elements are stored in canonical form; see
+/// [`BarrettConfig`].
+pub type BarrettFp<T, const N: usize> = Fp<BarrettBackend<T, N>, N>;
+
+impl<T: BarrettConfig<N>, const N: usize> BarrettBackend<T, N> {
+    /// The number of bits `b` of the modulus.
+    const MODULUS_BITS: u32 = T::MODULUS.const_num_bits();
+
+    /// `floor(2^(2b) / MODULUS) - 2^b`.
+    const MU: BigInt<N> = T::MODULUS.barrett_mu();
+
+    #[inline(always)]
+    const fn from_canonical(value: BigInt<N>) -> BarrettFp<T, N> {
+        Fp(value, PhantomData)
+    }
+
+    /// Reduces the `2N`-limb integer `hi * 2^(64N) + lo`, which must be
+    /// smaller than `MODULUS^2`.
+    #[inline]
+    fn reduce(lo: BigInt<N>, hi: BigInt<N>) -> BigInt<N> {
+        let b = Self::MODULUS_BITS;
+        // `floor(x / 2^b)`; this fits into `N` limbs because `x < 2^(2b)`.
+        let shift_right = |lo: BigInt<N>, hi: BigInt<N>| (lo >> b) | (hi << (64 * N as u32 - b));
+
+        // Estimate the quotient as `floor(q1 * mu / 2^b)`, where
+        // `q1 = floor(x / 2^b)` and `mu = 2^b + MU`. The estimate is at most
+        // three less than `floor(x / MODULUS)`, and never larger.
+        let q1 = shift_right(lo, hi);
+        let (mu_lo, mu_hi) = q1.mul(&Self::MU);
+        let mut q = q1;
+        q.add_with_carry(&shift_right(mu_lo, mu_hi));
+
+        // The remainder `x - q * MODULUS` is smaller than `4 * MODULUS`, so it
+        // fits into `N` limbs plus one extra word.
+        let (qp_lo, qp_hi) = q.mul(&T::MODULUS);
+        let mut r = lo;
+        let borrow = r.sub_with_borrow(&qp_lo);
+        let mut r_hi = hi.0[0].wrapping_sub(qp_hi.0[0]).wrapping_sub(borrow as u64);
+        while r_hi != 0 || r >= T::MODULUS {
+            let borrow = r.sub_with_borrow(&T::MODULUS);
+            r_hi = r_hi.wrapping_sub(borrow as u64);
+        }
+        r
+    }
+}
+
+impl<T: BarrettConfig<N>, const N: usize> FpConfig<N> for BarrettBackend<T, N> {
+    const MODULUS: BigInt<N> = T::MODULUS;
+
+    const GENERATOR: BarrettFp<T, N> = Self::from_canonical(T::GENERATOR);
+
+    const ZERO: BarrettFp<T, N> = Self::from_canonical(BigInt::zero());
+
+    const ONE: BarrettFp<T, N> = Self::from_canonical(BigInt::one());
+
+    const TWO_ADICITY: u32 = T::MODULUS.two_adic_valuation();
+
+    const TWO_ADIC_ROOT_OF_UNITY: BarrettFp<T, N> = Self::from_canonical(T::TWO_ADIC_ROOT_OF_UNITY);
+
+    const SMALL_SUBGROUP_BASE: Option<u32> = T::SMALL_SUBGROUP_BASE;
+
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = T::SMALL_SUBGROUP_BASE_ADICITY;
+
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BarrettFp<T, N>> =
+        match T::LARGE_SUBGROUP_ROOT_OF_UNITY {
+            Some(root) => Some(Self::from_canonical(root)),
+            None => None,
+        };
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<BarrettFp<T, N>>> = T::SQRT_PRECOMP;
+
+    #[inline(always)]
+    fn add_assign(a: &mut BarrettFp<T, N>, b: &BarrettFp<T, N>) {
+        canonical::add_assign(a, b)
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut BarrettFp<T, N>, b: &BarrettFp<T, N>) {
+        canonical::sub_assign(a, b)
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut BarrettFp<T, N>) {
+        canonical::double_in_place(a)
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut BarrettFp<T, N>) {
+        canonical::neg_in_place(a)
+    }
+
+    #[inline]
+    fn mul_assign(a: &mut BarrettFp<T, N>, b: &BarrettFp<T, N>) {
+        let (lo, hi) = a.0.mul(&b.0);
+        a.0 = Self::reduce(lo, hi);
+    }
+
+    fn sum_of_products<const M: usize>(
+        a: &[BarrettFp<T, N>; M],
+        b: &[BarrettFp<T, N>; M],
+    ) -> BarrettFp<T, N> {
+        a.iter().zip(b).fold(Self::ZERO, |mut acc, (a, b)| {
+            acc += *a * b;
+            acc
+        })
+    }
+
+    #[inline]
+    fn square_in_place(a: &mut BarrettFp<T, N>) {
+        let (lo, hi) = a.0.mul(&a.0);
+        a.0 = Self::reduce(lo, hi);
+    }
+
+    fn inverse(a: &BarrettFp<T, N>) -> Option<BarrettFp<T, N>> {
+        canonical::inverse(a)
+    }
+
+    #[inline]
+    fn from_bigint(other: BigInt<N>) -> Option<BarrettFp<T, N>> {
+        canonical::from_bigint(other)
+    }
+
+    #[inline]
+    fn into_bigint(other: BarrettFp<T, N>) -> BigInt<N> {
+        other.0
+    }
+}
+
+impl<T: BarrettConfig<N>, const N: usize> BarrettFp<T, N> {
+    /// Construct a new field element from an integer in canonical form.
+    /// This is primarily intended for constructing constant field elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is not smaller than `T::MODULUS`.
+    #[inline]
+    pub const fn new(element: BigInt<N>) -> Self {
+        assert!(
+            element.const_sub_with_borrow(&T::MODULUS).1,
+            "element is not reduced"
+        );
+        BarrettBackend::<T, N>::from_canonical(element)
+    }
+}
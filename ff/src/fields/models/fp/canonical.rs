@@ -0,0 +1,121 @@
+//! Arithmetic shared by the backends that store elements in canonical form,
+//! i.e. as integers in `0..P::MODULUS`.
+use super::{Fp, FpConfig};
+use crate::{BigInt, BigInteger, PrimeField, SqrtPrecomputation, Zero};
+use ark_std::marker::PhantomData;
+
+struct Constants<P: FpConfig<N>, const N: usize>(PhantomData<P>);
+
+impl<P: FpConfig<N>, const N: usize> Constants<P, N> {
+    /// `(MODULUS + 1) / 4`. Only meaningful when `MODULUS % 4 == 3`.
+    const MODULUS_PLUS_ONE_DIV_FOUR: BigInt<N> = P::MODULUS
+        .const_shr()
+        .const_shr()
+        .const_add_with_carry(&BigInt::one())
+        .0;
+}
+
+/// Returns the square root precomputation for a field whose elements are
+/// stored in canonical form: a single exponentiation when
+/// `MODULUS % 4 == 3`, and Tonelli-Shanks otherwise.
+pub(super) const fn sqrt_precomputation<P: FpConfig<N>, const N: usize>(
+) -> Option<SqrtPrecomputation<Fp<P, N>>> {
+    match P::MODULUS.mod_4() {
+        3 => Some(SqrtPrecomputation::Case3Mod4 {
+            modulus_plus_one_div_four: &Constants::<P, N>::MODULUS_PLUS_ONE_DIV_FOUR.0,
+        }),
+        _ => Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: P::TWO_ADICITY,
+            quadratic_nonresidue_to_trace: P::TWO_ADIC_ROOT_OF_UNITY,
+            trace_of_modulus_minus_one_div_two: &<Fp<P, N>>::TRACE_MINUS_ONE_DIV_TWO.0,
+        }),
+    }
+}
+
+#[inline(always)]
+pub(super) fn add_assign<P: FpConfig<N>, const N: usize>(a: &mut Fp<P, N>, b: &Fp<P, N>) {
+    let carry = a.0.add_with_carry(&b.0);
+    a.subtract_modulus_with_carry(carry);
+}
+
+#[inline(always)]
+pub(super) fn sub_assign<P: FpConfig<N>, const N: usize>(a: &mut Fp<P, N>, b: &Fp<P, N>) {
+    a.sub_assign_reduced(b);
+}
+
+#[inline(always)]
+pub(super) fn double_in_place<P: FpConfig<N>, const N: usize>(a: &mut Fp<P, N>) {
+    let carry = a.0.mul2();
+    a.subtract_modulus_with_carry(carry);
+}
+
+#[inline(always)]
+pub(super) fn neg_in_place<P: FpConfig<N>, const N: usize>(a: &mut Fp<P, N>) {
+    a.neg_in_place_reduced();
+}
+
+pub(super) fn inverse<P: FpConfig<N>, const N: usize>(a: &Fp<P, N>) -> Option<Fp<P, N>> {
+    if a.is_zero() {
+        return None;
+    }
+    // Guajardo Kumar Paar Pelzl
+    // Efficient Software-Implementation of Finite Fields with Applications to
+    // Cryptography
+    // Algorithm 16 (BEA for Inversion in Fp)
+
+    let one = BigInt::from(1u64);
+    let modulus_has_spare_bit = P::MODULUS.0[N - 1] >> 63 == 0;
+
+    let mut u = a.0;
+    let mut v = P::MODULUS;
+    let mut b = P::ONE;
+    let mut c = P::ZERO;
+
+    // Sets `x = x / 2 mod MODULUS`.
+    let halve = |x: &mut Fp<P, N>| {
+        if x.0.is_even() {
+            x.0.div2();
+        } else {
+            let carry = x.0.add_with_carry(&P::MODULUS);
+            x.0.div2();
+            if !modulus_has_spare_bit && carry {
+                (x.0).0[N - 1] |= 1 << 63;
+            }
+        }
+    };
+
+    while u != one && v != one {
+        while u.is_even() {
+            u.div2();
+            halve(&mut b);
+        }
+
+        while v.is_even() {
+            v.div2();
+            halve(&mut c);
+        }
+
+        if v < u {
+            u.sub_with_borrow(&v);
+            b -= &c;
+        } else {
+            v.sub_with_borrow(&u);
+            c -= &b;
+        }
+    }
+
+    if u == one {
+        Some(b)
+    } else {
+        Some(c)
+    }
+}
+
+#[inline]
+pub(super) fn from_bigint<P: FpConfig<N>, const N: usize>(other: BigInt<N>) -> Option<Fp<P, N>> {
+    if other < P::MODULUS {
+        Some(Fp(other, PhantomData))
+    } else {
+        None
+    }
+}
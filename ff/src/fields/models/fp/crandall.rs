@@ -0,0 +1,234 @@
+use super::{canonical, Fp, FpConfig};
+use crate::{BigInt, BigInteger, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+
+/// A trait that specifies the constants for a prime field whose modulus is a
+/// pseudo-Mersenne (or Crandall) prime `2^K - C`, for a small `C`.
+///
+/// Elements are stored in canonical form, and products are reduced using
+/// `2^K = C (mod MODULUS)`: the bits of a product above position `K` are
+/// multiplied by `C` and added back onto the low `K` bits, which only
+/// requires a multiplication by a single word instead of a full Montgomery
+/// reduction.
+pub trait CrandallConfig<const N: usize>: 'static + Sync + Send + Sized {
+    /// The exponent `K` of the modulus `2^K - C`.
+    /// Must satisfy `64 * (N - 1) < K <= 64 * N`.
+    const K: u32;
+
+    /// The offset `C` of the modulus `2^K - C`.
+    /// Reduction is fastest when `C^2 < 2^K`.
+    const C: u64;
+
+    /// A multiplicative generator of the field, in canonical form.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `MODULUS - 1`.
+    const GENERATOR: BigInt<N>;
+
+    /// 2^s root of unity computed by GENERATOR^t, in canonical form.
+    const TWO_ADIC_ROOT_OF_UNITY: BigInt<N>;
+
+    /// An integer `b` such that there exists a multiplicative subgroup
+    /// of size `b^k` for some integer `k`.
+    const SMALL_SUBGROUP_BASE: Option<u32> = None;
+
+    /// The integer `k` such that there exists a multiplicative subgroup
+    /// of size `Self::SMALL_SUBGROUP_BASE^k`.
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = None;
+
+    /// GENERATOR^((MODULUS-1) / (2^s *
+    /// SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY)), in canonical form.
+    /// Used for mixed-radix FFT.
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInt<N>> = None;
+
+    /// Precomputed material for use when computing square roots.
+    /// The default is to use the standard Tonelli-Shanks algorithm,
+    /// or a single exponentiation when `MODULUS % 4 == 3`.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Fp<CrandallBackend<Self, N>, N>>> =
+        canonical::sqrt_precomputation::<CrandallBackend<Self, N>, N>();
+}
+
+/// An [`FpConfig`] for primes of the form `2^K - C`; see [`CrandallConfig`].
+pub struct CrandallBackend<T: CrandallConfig<N>, const N: usize>(PhantomData<T>);
+
+/// A prime field whose modulus has the form `2^K - C`; see
+/// [`CrandallConfig`].
+pub type CrandallFp<T, const N: usize> = Fp<CrandallBackend<T, N>, N>;
+
+impl<T: CrandallConfig<N>, const N: usize> CrandallBackend<T, N> {
+    /// `2^K - 1`, i.e. a mask for the low `K` bits.
+    const MASK: BigInt<N> = {
+        let mut mask = BigInt([u64::MAX; N]);
+        if T::K < 64 * N as u32 {
+            mask.0[N - 1] = (1 << (T::K % 64)) - 1;
+        }
+        mask
+    };
+
+    #[inline(always)]
+    const fn from_canonical(value: BigInt<N>) -> CrandallFp<T, N> {
+        Fp(value, PhantomData)
+    }
+
+    /// Computes `a + b * c`, where `a` is given as `N` limbs plus an extra
+    /// word, and returns the result in the same form.
+    #[inline(always)]
+    fn mul_word_and_add(a: &BigInt<N>, b: &BigInt<N>, c: u64) -> (BigInt<N>, u64) {
+        let mut result = BigInt::<N>::zero();
+        let mut carry = 0u128;
+        for ((r, a), b) in result.0.iter_mut().zip(&a.0).zip(&b.0) {
+            let tmp = *a as u128 + *b as u128 * c as u128 + carry;
+            *r = tmp as u64;
+            carry = tmp >> 64;
+        }
+        (result, carry as u64)
+    }
+
+    /// Reduces the `2N`-limb integer `hi * 2^(64N) + lo`, which must be
+    /// smaller than `MODULUS^2`.
+    #[inline]
+    fn reduce(lo: BigInt<N>, hi: BigInt<N>) -> BigInt<N> {
+        let k = T::K;
+        let shift = 64 * N as u32 - k;
+
+        // x = x_hi * 2^K + x_lo = x_lo + C * x_hi (mod MODULUS), where
+        // `x_hi < 2^K` since `x < 2^(2K)`.
+        let x_hi = (lo >> k) | (hi << shift);
+        let (mut r, mut r_hi) = Self::mul_word_and_add(&(lo & Self::MASK), &x_hi, T::C);
+
+        // Now `r < 2^K * (C + 1)`, so the bits above position `K` fit into a
+        // single word. Fold them back in until `r < 2^K`; this takes at most
+        // two iterations when `C^2 < 2^K`.
+        loop {
+            let top = (r >> k).0[0] | (r_hi << shift);
+            if top == 0 {
+                break;
+            }
+            r &= Self::MASK;
+            let mut carry = top as u128 * T::C as u128;
+            for limb in r.0.iter_mut() {
+                let tmp = *limb as u128 + carry as u64 as u128;
+                *limb = tmp as u64;
+                carry = (carry >> 64) + (tmp >> 64);
+            }
+            r_hi = carry as u64;
+        }
+
+        // `r < 2^K < 2 * MODULUS`.
+        if r >= Self::MODULUS {
+            r.sub_with_borrow(&Self::MODULUS);
+        }
+        r
+    }
+}
+
+impl<T: CrandallConfig<N>, const N: usize> FpConfig<N> for CrandallBackend<T, N> {
+    const MODULUS: BigInt<N> = {
+        assert!(64 * (N as u32 - 1) < T::K && T::K <= 64 * N as u32);
+        let mut two_to_k = BigInt::<N>::zero();
+        if T::K < 64 * N as u32 {
+            two_to_k.0[T::K as usize / 64] = 1 << (T::K % 64);
+        }
+        // If `K = 64 * N`, this computes `2^(64 * N) - C` by wrapping around.
+        let mut c = BigInt::<N>::zero();
+        c.0[0] = T::C;
+        two_to_k.const_sub_with_borrow(&c).0
+    };
+
+    const GENERATOR: CrandallFp<T, N> = Self::from_canonical(T::GENERATOR);
+
+    const ZERO: CrandallFp<T, N> = Self::from_canonical(BigInt::zero());
+
+    const ONE: CrandallFp<T, N> = Self::from_canonical(BigInt::one());
+
+    const TWO_ADICITY: u32 = Self::MODULUS.two_adic_valuation();
+
+    const TWO_ADIC_ROOT_OF_UNITY: CrandallFp<T, N> =
+        Self::from_canonical(T::TWO_ADIC_ROOT_OF_UNITY);
+
+    const SMALL_SUBGROUP_BASE: Option<u32> = T::SMALL_SUBGROUP_BASE;
+
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = T::SMALL_SUBGROUP_BASE_ADICITY;
+
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<CrandallFp<T, N>> =
+        match T::LARGE_SUBGROUP_ROOT_OF_UNITY {
+            Some(root) => Some(Self::from_canonical(root)),
+            None => None,
+        };
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<CrandallFp<T, N>>> = T::SQRT_PRECOMP;
+
+    #[inline(always)]
+    fn add_assign(a: &mut CrandallFp<T, N>, b: &CrandallFp<T, N>) {
+        canonical::add_assign(a, b)
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut CrandallFp<T, N>, b: &CrandallFp<T, N>) {
+        canonical::sub_assign(a, b)
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut CrandallFp<T, N>) {
+        canonical::double_in_place(a)
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut CrandallFp<T, N>) {
+        canonical::neg_in_place(a)
+    }
+
+    #[inline]
+    fn mul_assign(a: &mut CrandallFp<T, N>, b: &CrandallFp<T, N>) {
+        let (lo, hi) = a.0.mul(&b.0);
+        a.0 = Self::reduce(lo, hi);
+    }
+
+    fn sum_of_products<const M: usize>(
+        a: &[CrandallFp<T, N>; M],
+        b: &[CrandallFp<T, N>; M],
+    ) -> CrandallFp<T, N> {
+        a.iter().zip(b).fold(Self::ZERO, |mut acc, (a, b)| {
+            acc += *a * b;
+            acc
+        })
+    }
+
+    #[inline]
+    fn square_in_place(a: &mut CrandallFp<T, N>) {
+        let (lo, hi) = a.0.mul(&a.0);
+        a.0 = Self::reduce(lo, hi);
+    }
+
+    fn inverse(a: &CrandallFp<T, N>) -> Option<CrandallFp<T, N>> {
+        canonical::inverse(a)
+    }
+
+    #[inline]
+    fn from_bigint(other: BigInt<N>) -> Option<CrandallFp<T, N>> {
+        canonical::from_bigint(other)
+    }
+
+    #[inline]
+    fn into_bigint(other: CrandallFp<T, N>) -> BigInt<N> {
+        other.0
+    }
+}
+
+impl<T: CrandallConfig<N>, const N: usize> CrandallFp<T, N> {
+    /// Construct a new field element from an integer in canonical form.
+    /// This is primarily intended for constructing constant field elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is not smaller than the modulus.
+    #[inline]
+    pub const fn new(element: BigInt<N>) -> Self {
+        assert!(
+            element
+                .const_sub_with_borrow(&CrandallBackend::<T, N>::MODULUS)
+                .1,
+            "element is not reduced"
+        );
+        CrandallBackend::<T, N>::from_canonical(element)
+    }
+}
@@ -0,0 +1,191 @@
+//! Constant-time operations on prime field elements, enabled by the `ct`
+//! feature.
+//!
+//! With this feature enabled, addition, subtraction, negation and the
+//! reductions performed by [`MontBackend`](super::MontBackend) are
+//! branch-free, and this module provides constant-time comparison,
+//! conditional selection, inversion and square roots via the [`subtle`]
+//! crate.
+//!
+//! The following operations remain variable-time, and must not be used on
+//! secret values: [`Field::inverse`], [`Field::sqrt`], [`Field::legendre`],
+//! the [`Ord`] and [`PartialEq`] implementations, and [`Field::pow`] when the
+//! exponent is secret. Use [`Field::pow_ct`] for secret exponents instead.
+//!
+//! Only the arithmetic of [`MontBackend`](super::MontBackend), including its
+//! 32-bit digit variant, is made branch-free by this feature, and so are
+//! quadratic and cubic extensions of such fields, whose arithmetic is
+//! expressed in terms of that of the base field. The other prime field
+//! backends, i.e. the Barrett, Crandall and IFMA backends, the single-word
+//! fields such as Goldilocks, BabyBear and Mersenne31, and
+//! [`DynFp`](crate::DynFp), still branch on the values of their operands, and
+//! are not constant-time even with this feature.
+use super::{Fp, FpConfig};
+use crate::{BigInt, BigInteger, Field, PrimeField};
+use ark_std::marker::PhantomData;
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
+};
+
+impl<P: FpConfig<N>, const N: usize> ConstantTimeEq for Fp<P, N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> ConditionallySelectable for Fp<P, N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fp(BigInt::conditional_select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+/// Compares field elements viewing them as integers in the range
+/// `0..P::MODULUS`, like the [`Ord`] implementation.
+impl<P: FpConfig<N>, const N: usize> ConstantTimeGreater for Fp<P, N> {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.into_bigint().ct_gt(&other.into_bigint())
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> ConstantTimeLess for Fp<P, N> {}
+
+impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
+    /// Returns `Choice(1)` if `self` is zero, in constant time.
+    #[inline]
+    pub fn ct_is_zero(&self) -> Choice {
+        self.ct_eq(&P::ZERO)
+    }
+
+    /// Computes the multiplicative inverse of `self` in constant time, using
+    /// Fermat's little theorem. The result is none if `self` is zero.
+    pub fn ct_inverse(&self) -> CtOption<Self> {
+        let mut exp = P::MODULUS;
+        exp.sub_with_borrow(&BigInt::from(2u64));
+        CtOption::new(self.pow(exp), !self.ct_is_zero())
+    }
+
+    /// Computes a square root of `self` in constant time, using the
+    /// constant-time variant of Tonelli-Shanks from
+    /// <https://eprint.iacr.org/2012/685> (Algorithm 5). The result is none
+    /// if `self` is a quadratic non-residue.
+    ///
+    /// The root that is returned is not necessarily the same as the one
+    /// returned by [`Field::sqrt`].
+    pub fn ct_sqrt(&self) -> CtOption<Self> {
+        // `w = self^((t - 1) / 2)`, where `MODULUS - 1 = 2^s * t`.
+        let w = self.pow(Self::TRACE_MINUS_ONE_DIV_TWO);
+
+        let mut v = P::TWO_ADICITY;
+        let mut x = *self * w;
+        let mut b = x * w;
+        let mut z = P::TWO_ADIC_ROOT_OF_UNITY;
+
+        // Each iteration runs in time independent of `b`, by always running
+        // the inner loop to its maximum length.
+        for max_v in (1..=P::TWO_ADICITY).rev() {
+            let mut k = 1;
+            let mut tmp = b.square();
+            let mut j_less_than_v = Choice::from(1);
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.ct_eq(&P::ONE);
+                let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = Self::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, tmp_is_one);
+                z = Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * z;
+            x = Self::conditional_select(&result, &x, b.ct_eq(&P::ONE));
+            z.square_in_place();
+            b *= z;
+            v = k;
+        }
+
+        CtOption::new(x, x.square().ct_eq(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{ark_ff::Field, bls12_381, mnt6_753, secp256k1};
+    use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater};
+
+    macro_rules! test_ct {
+        ($name:ident, $field:ty) => {
+            #[test]
+            fn $name() {
+                let rng = &mut test_rng();
+                for _ in 0..100 {
+                    let a = <$field>::rand(rng);
+                    let b = <$field>::rand(rng);
+                    assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                    assert!(bool::from(a.ct_eq(&a)));
+                    assert_eq!(bool::from(a.ct_gt(&b)), a > b);
+                    assert_eq!(<$field>::conditional_select(&a, &b, 0.into()), a);
+                    assert_eq!(<$field>::conditional_select(&a, &b, 1.into()), b);
+
+                    assert_eq!(Option::from(a.ct_inverse()), a.inverse());
+                    let ct_sqrt: Option<$field> = a.ct_sqrt().into();
+                    assert_eq!(ct_sqrt.is_some(), a.sqrt().is_some());
+                    if let Some(root) = ct_sqrt {
+                        assert_eq!(root.square(), a);
+                    }
+                    let square = a.square();
+                    let root: $field = Option::from(square.ct_sqrt()).unwrap();
+                    assert_eq!(root.square(), square);
+                }
+                let exp = [u64::rand(rng), u64::rand(rng), 0];
+                let a = <$field>::rand(rng);
+                assert_eq!(a.pow_ct(exp), a.pow(exp));
+                assert_eq!(a.pow_ct([0u64; 4]), <$field>::from(1u8));
+                let zero = <$field>::from(0u8);
+                assert!(bool::from(zero.ct_is_zero()));
+                assert!(bool::from(zero.ct_inverse().is_none()));
+                assert_eq!(Option::from(zero.ct_sqrt()), Some(zero));
+                let max = -<$field>::from(1u8);
+                assert!(bool::from(max.ct_gt(&<$field>::from(1u8))));
+            }
+        };
+    }
+
+    macro_rules! test_pow_ct_extension {
+        ($name:ident, $field:ty) => {
+            #[test]
+            fn $name() {
+                let rng = &mut test_rng();
+                for _ in 0..10 {
+                    let a = <$field>::rand(rng);
+                    let exp = [0; 4].map(|_: u64| u64::rand(rng));
+                    assert_eq!(a.pow_ct(exp), a.pow_vartime(exp));
+                    assert_eq!(a.pow_ct([exp[0], 0, 0]), a.pow_vartime([exp[0]]));
+                }
+                let a = <$field>::rand(rng);
+                assert_eq!(a.pow_ct([0u64; 2]), <$field>::from(1u8));
+                assert_eq!(a.pow_ct([1u64, 0]), a);
+
+                let b = <$field>::rand(rng);
+                assert!(bool::from(a.ct_eq(&a)));
+                assert!(!bool::from(a.ct_eq(&b)));
+                assert_eq!(<$field>::conditional_select(&a, &b, 0.into()), a);
+                assert_eq!(<$field>::conditional_select(&a, &b, 1.into()), b);
+            }
+        };
+    }
+
+    test_pow_ct_extension!(test_pow_ct_bls12_381_fq2, bls12_381::Fq2);
+    test_pow_ct_extension!(test_pow_ct_bls12_381_fq6, bls12_381::Fq6);
+    test_pow_ct_extension!(test_pow_ct_bls12_381_fq12, bls12_381::Fq12);
+    test_pow_ct_extension!(test_pow_ct_mnt6_753_fq3, mnt6_753::Fq3);
+
+    test_ct!(test_bls12_381_fq, bls12_381::Fq);
+    test_ct!(test_bls12_381_fr, bls12_381::Fr);
+    test_ct!(test_secp256k1_fq, secp256k1::Fq);
+}
@@ -0,0 +1,660 @@
+use crate::{
+    AdditiveGroup, BigInt, FftField, Field, LegendreSymbol, One, PrimeField, SqrtPrecomputation,
+    Zero,
+};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
+    ValidationError,
+};
+use ark_std::{
+    cmp::*,
+    fmt,
+    io::{Read, Write},
+    iter,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    str::FromStr,
+};
+use zeroize::Zeroize;
+
+/// A trait that specifies the constants and the modular reduction for a
+/// prime field whose modulus fits into 32 bits.
+///
+/// Elements of such fields are stored in canonical form in a single `u32`,
+/// and every product of two elements fits into a `u64`. Consequently,
+/// arithmetic only requires native 64-bit operations, instead of the
+/// multi-limb Montgomery arithmetic of [`MontBackend`](super::MontBackend).
+pub trait Fp32Config: 'static + Send + Sync + Sized {
+    /// The modulus of the field.
+    const MODULUS: u32;
+
+    /// A multiplicative generator of the field, in canonical form.
+    /// `Self::GENERATOR` is an element having multiplicative order
+    /// `Self::MODULUS - 1`.
+    const GENERATOR: u32;
+
+    /// 2^s root of unity computed by GENERATOR^t, in canonical form.
+    const TWO_ADIC_ROOT_OF_UNITY: u32;
+
+    /// An integer `b` such that there exists a multiplicative subgroup
+    /// of size `b^k` for some integer `k`.
+    const SMALL_SUBGROUP_BASE: Option<u32> = None;
+
+    /// The integer `k` such that there exists a multiplicative subgroup
+    /// of size `Self::SMALL_SUBGROUP_BASE^k`.
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = None;
+
+    /// GENERATOR^((MODULUS-1) / (2^s *
+    /// SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY)), in canonical form.
+    /// Used for mixed-radix FFT.
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<u32> = None;
+
+    /// Precomputed material for use when computing square roots.
+    /// The default is to use the standard Tonelli-Shanks algorithm,
+    /// or a single exponentiation when `MODULUS % 4 == 3`.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Fp32<Self>>> = fp32_sqrt_precomputation::<Self>();
+
+    /// (MODULUS + 1) / 4. Used for square root precomputations.
+    #[doc(hidden)]
+    const MODULUS_PLUS_ONE_DIV_FOUR: [u64; 1] = [(Self::MODULUS as u64 + 1) / 4];
+
+    /// Reduces an arbitrary `u64` modulo `Self::MODULUS`.
+    ///
+    /// The default implementation relies on the compiler to turn the
+    /// division by the constant modulus into a multiplication. Moduli with a
+    /// special shape should override this method.
+    #[inline(always)]
+    fn reduce(x: u64) -> u32 {
+        (x % Self::MODULUS as u64) as u32
+    }
+}
+
+/// An element of the prime field whose modulus is given by `P`, which fits
+/// into 32 bits.
+///
+/// Unlike [`Fp`](super::Fp), whose limbs are 64 bits wide, elements are
+/// stored in a single `u32`, which halves their size in memory.
+#[derive(Educe)]
+#[educe(Default, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct Fp32<P: Fp32Config>(
+    /// The canonical representative of the element, in `0..P::MODULUS`.
+    pub u32,
+    #[doc(hidden)] pub PhantomData<P>,
+);
+
+impl<P: Fp32Config> Fp32<P> {
+    /// Construct a new field element from a `u32`, reducing it modulo
+    /// `P::MODULUS` if necessary. This is primarily intended for constructing
+    /// constant field elements.
+    #[inline]
+    pub const fn new(value: u32) -> Self {
+        Self(value % P::MODULUS, PhantomData)
+    }
+
+    /// A mask for the bits that may be set in the representation of an
+    /// element.
+    const MASK: u32 = u32::MAX >> (32 - Self::MODULUS_BIT_SIZE);
+}
+
+const fn fp32_sqrt_precomputation<P: Fp32Config>() -> Option<SqrtPrecomputation<Fp32<P>>> {
+    match P::MODULUS % 4 {
+        3 => Some(SqrtPrecomputation::Case3Mod4 {
+            modulus_plus_one_div_four: &P::MODULUS_PLUS_ONE_DIV_FOUR,
+        }),
+        _ => Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: <Fp32<P>>::TWO_ADICITY,
+            quadratic_nonresidue_to_trace: Fp32(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData),
+            trace_of_modulus_minus_one_div_two: &<Fp32<P>>::TRACE_MINUS_ONE_DIV_TWO.0,
+        }),
+    }
+}
+
+impl<P: Fp32Config> fmt::Debug for Fp32<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<P: Fp32Config> Zero for Fp32<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<P: Fp32Config> One for Fp32<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl<P: Fp32Config> AdditiveGroup for Fp32<P> {
+    type Scalar = Self;
+
+    const ZERO: Self = Self(0, PhantomData);
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self += *self;
+        self
+    }
+
+    #[inline]
+    fn neg_in_place(&mut self) -> &mut Self {
+        if self.0 != 0 {
+            self.0 = P::MODULUS - self.0;
+        }
+        self
+    }
+}
+
+impl<P: Fp32Config> Field for Fp32<P> {
+    type BasePrimeField = Self;
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = P::SQRT_PRECOMP;
+
+    const ONE: Self = Self(1, PhantomData);
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+        elem
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self::BasePrimeField> {
+        iter::once(*self)
+    }
+
+    fn from_base_prime_field_elems(
+        elems: impl IntoIterator<Item = Self::BasePrimeField>,
+    ) -> Option<Self> {
+        let mut elems = elems.into_iter();
+        let elem = elems.next()?;
+        if elems.next().is_some() {
+            return None;
+        }
+        Some(elem)
+    }
+
+    #[inline]
+    fn characteristic() -> &'static [u64] {
+        Self::MODULUS.as_ref()
+    }
+
+    fn sum_of_products<const T: usize>(a: &[Self; T], b: &[Self; T]) -> Self {
+        // The accumulator is less than `MODULUS` and each product is at most
+        // `(MODULUS - 1)^2`, so their sum always fits into a `u64`.
+        let sum = a.iter().zip(b).fold(0u32, |acc, (a, b)| {
+            P::reduce(acc as u64 + a.0 as u64 * b.0 as u64)
+        });
+        Self(sum, PhantomData)
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            return None;
+        }
+        let output_byte_size = buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + F::BIT_SIZE);
+        let mut buffer = [0u8; 5];
+        let len = bytes.len().min(output_byte_size);
+        buffer[..len].copy_from_slice(&bytes[..len]);
+        let flags = F::from_u8_remove_flags(&mut buffer[output_byte_size - 1])?;
+        let mut value = [0u8; 4];
+        value.copy_from_slice(&buffer[..4]);
+        let value = u32::from_le_bytes(value) & Self::MASK;
+        (value < P::MODULUS).then_some((Self(value, PhantomData), flags))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        self.0 = P::reduce(self.0 as u64 * self.0 as u64);
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Fermat's little theorem: a^{-1} = a^(p - 2).
+            Some(self.pow([P::MODULUS as u64 - 2]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// The Frobenius map has no effect in a prime field.
+    #[inline]
+    fn frobenius_map_in_place(&mut self, _: usize) {}
+
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        // Checking for zero first is required in `GF(2)`, where the exponent
+        // below is zero.
+        if self.is_zero() {
+            return LegendreSymbol::Zero;
+        }
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(Self::MODULUS_MINUS_ONE_DIV_TWO);
+        if s.is_one() {
+            LegendreSymbol::QuadraticResidue
+        } else {
+            LegendreSymbol::QuadraticNonResidue
+        }
+    }
+
+    /// `Fp32` is already a "BasePrimeField", so it's just mul by self
+    #[inline]
+    fn mul_by_base_prime_field(&self, elem: &Self::BasePrimeField) -> Self {
+        *self * elem
+    }
+}
+
+impl<P: Fp32Config> PrimeField for Fp32<P> {
+    type BigInt = BigInt<1>;
+    const MODULUS: Self::BigInt = BigInt([P::MODULUS as u64]);
+    const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt = Self::MODULUS.divide_by_2_round_down();
+    const MODULUS_BIT_SIZE: u32 = 32 - P::MODULUS.leading_zeros();
+    const TRACE: Self::BigInt = Self::MODULUS.two_adic_coefficient();
+    const TRACE_MINUS_ONE_DIV_TWO: Self::BigInt = Self::TRACE.divide_by_2_round_down();
+
+    #[inline]
+    fn from_bigint(r: BigInt<1>) -> Option<Self> {
+        (r.0[0] < P::MODULUS as u64).then_some(Self(r.0[0] as u32, PhantomData))
+    }
+
+    #[inline]
+    fn into_bigint(self) -> BigInt<1> {
+        BigInt([self.0 as u64])
+    }
+}
+
+impl<P: Fp32Config> FftField for Fp32<P> {
+    const GENERATOR: Self = Self(P::GENERATOR, PhantomData);
+    const TWO_ADICITY: u32 = (P::MODULUS - 1).trailing_zeros();
+    const TWO_ADIC_ROOT_OF_UNITY: Self = Self(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData);
+    const SMALL_SUBGROUP_BASE: Option<u32> = P::SMALL_SUBGROUP_BASE;
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = P::SMALL_SUBGROUP_BASE_ADICITY;
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self> = match P::LARGE_SUBGROUP_ROOT_OF_UNITY {
+        Some(root) => Some(Self(root, PhantomData)),
+        None => None,
+    };
+}
+
+/// Note that this implementation of `Ord` compares field elements viewing
+/// them as integers in the range 0, 1, ..., `P::MODULUS` - 1, just like the
+/// implementation for [`Fp`](super::Fp).
+impl<P: Fp32Config> Ord for Fp32<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<P: Fp32Config> PartialOrd for Fp32<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp32Config> Zeroize for Fp32<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: Fp32Config> From<u128> for Fp32<P> {
+    fn from(other: u128) -> Self {
+        Self((other % P::MODULUS as u128) as u32, PhantomData)
+    }
+}
+
+impl<P: Fp32Config> From<u64> for Fp32<P> {
+    fn from(other: u64) -> Self {
+        Self(P::reduce(other), PhantomData)
+    }
+}
+
+impl<P: Fp32Config> From<bool> for Fp32<P> {
+    fn from(other: bool) -> Self {
+        Self::from(u64::from(other))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($int:ty),*) => {
+        $(
+            impl<P: Fp32Config> From<$int> for Fp32<P> {
+                fn from(other: $int) -> Self {
+                    Self::from(u64::from(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32);
+
+macro_rules! impl_from_signed {
+    ($($int:ty),*) => {
+        $(
+            impl<P: Fp32Config> From<$int> for Fp32<P> {
+                fn from(other: $int) -> Self {
+                    let abs = Self::from(other.unsigned_abs());
+                    if other.is_positive() {
+                        abs
+                    } else {
+                        -abs
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128);
+
+impl<P: Fp32Config> Neg for Fp32<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(mut self) -> Self {
+        self.neg_in_place();
+        self
+    }
+}
+
+impl<P: Fp32Config> Distribution<Fp32<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp32<P> {
+        loop {
+            let value = rng.gen::<u32>() & Fp32::<P>::MASK;
+            if value < P::MODULUS {
+                return Fp32(value, PhantomData);
+            }
+        }
+    }
+}
+
+impl<'a, P: Fp32Config> Add<&'a Fp32<P>> for Fp32<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: Fp32Config> Sub<&'a Fp32<P>> for Fp32<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: Fp32Config> Mul<&'a Fp32<P>> for Fp32<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &Self) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<'a, P: Fp32Config> Div<&'a Fp32<P>> for Fp32<P> {
+    type Output = Self;
+
+    /// Returns `self * other.inverse()` if `other.inverse()` is `Some`, and
+    /// panics otherwise.
+    #[inline]
+    fn div(mut self, other: &Self) -> Self {
+        self.mul_assign(&other.inverse().unwrap());
+        self
+    }
+}
+
+impl<'a, P: Fp32Config> AddAssign<&'a Self> for Fp32<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // Cannot overflow, since `MODULUS < 2^32`.
+        let sum = self.0 as u64 + other.0 as u64;
+        self.0 = if sum >= P::MODULUS as u64 {
+            (sum - P::MODULUS as u64) as u32
+        } else {
+            sum as u32
+        };
+    }
+}
+
+impl<'a, P: Fp32Config> SubAssign<&'a Self> for Fp32<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 = if other.0 > self.0 {
+            (self.0 as u64 + P::MODULUS as u64 - other.0 as u64) as u32
+        } else {
+            self.0 - other.0
+        };
+    }
+}
+
+impl<'a, P: Fp32Config> MulAssign<&'a Self> for Fp32<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        self.0 = P::reduce(self.0 as u64 * other.0 as u64);
+    }
+}
+
+impl<'a, P: Fp32Config> DivAssign<&'a Self> for Fp32<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl_additive_ops_from_ref!(Fp32, Fp32Config);
+impl_multiplicative_ops_from_ref!(Fp32, Fp32Config);
+
+impl<P: Fp32Config> FromStr for Fp32<P> {
+    type Err = ();
+
+    /// Interprets a decimal or `0x`-prefixed hexadecimal integer, optionally
+    /// preceded by a `-` sign, as a prime field element.
+    /// Returns an error for a blank string, and if the absolute value of the
+    /// integer is not smaller than the modulus.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let a = Self::from_bigint(s.parse()?).ok_or(())?;
+        Ok(if negative { -a } else { a })
+    }
+}
+
+/// Outputs a string containing the value of `self`,
+/// represented as a decimal without leading zeroes.
+impl<P: Fp32Config> fmt::Display for Fp32<P> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Elements are serialized in the same format as by [`Fp`](super::Fp), i.e.
+/// as the little-endian bytes of their canonical representative, truncated to
+/// the size of the modulus.
+impl<P: Fp32Config> CanonicalSerializeWithFlags for Fp32<P> {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let output_byte_size = buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + F::BIT_SIZE);
+        let mut bytes = [0u8; 5];
+        bytes[..4].copy_from_slice(&self.0.to_le_bytes());
+        bytes[output_byte_size - 1] |= flags.u8_bitmask();
+        writer.write_all(&bytes[..output_byte_size])?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + F::BIT_SIZE)
+    }
+}
+
+impl<P: Fp32Config> CanonicalSerialize for Fp32<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl<P: Fp32Config> CanonicalDeserializeWithFlags for Fp32<P> {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let output_byte_size = buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + F::BIT_SIZE);
+        let mut bytes = [0u8; 5];
+        reader.read_exact(&mut bytes[..output_byte_size])?;
+        let flags = F::from_u8_remove_flags(&mut bytes[output_byte_size - 1])
+            .ok_or(SerializationError::UnexpectedFlags)?;
+        let mut value = [0u8; 4];
+        value.copy_from_slice(&bytes[..4]);
+        let value = u32::from_le_bytes(value);
+        if value >= P::MODULUS || bytes[4] != 0 {
+            return Err(ValidationError::NonCanonicalFieldEncoding.into());
+        }
+        Ok((Self(value, PhantomData), flags))
+    }
+}
+
+impl<P: Fp32Config> Valid for Fp32<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<P: Fp32Config> CanonicalDeserialize for Fp32<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
+    }
+}
+
+impl<P: Fp32Config> From<num_bigint::BigUint> for Fp32<P> {
+    #[inline]
+    fn from(val: num_bigint::BigUint) -> Self {
+        Self(
+            (val % P::MODULUS).iter_u32_digits().next().unwrap_or(0),
+            PhantomData,
+        )
+    }
+}
+
+impl<P: Fp32Config> From<Fp32<P>> for num_bigint::BigUint {
+    #[inline(always)]
+    fn from(other: Fp32<P>) -> Self {
+        other.0.into()
+    }
+}
+
+impl<P: Fp32Config> From<Fp32<P>> for BigInt<1> {
+    #[inline(always)]
+    fn from(fp: Fp32<P>) -> Self {
+        fp.into_bigint()
+    }
+}
+
+impl<P: Fp32Config> From<BigInt<1>> for Fp32<P> {
+    /// Converts `Self::BigInteger` into `Self`
+    #[inline(always)]
+    fn from(int: BigInt<1>) -> Self {
+        Self::from_bigint(int).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mersenne31;
+    use ark_std::{mem::size_of, vec::Vec};
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size_of::<Mersenne31>(), 4);
+    }
+
+    #[test]
+    fn test_serialization_matches_fp() {
+        // `2^31 - 2`, serialized as the little-endian bytes of a 31-bit
+        // integer, like the elements of `Fp`.
+        let a = -Mersenne31::ONE;
+        let mut bytes = Vec::new();
+        a.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes, [0xFE, 0xFF, 0xFF, 0x7F]);
+        assert!(Mersenne31::deserialize_compressed(&[0xFF, 0xFF, 0xFF, 0x7F][..]).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use super::{Fp, Fp64, FpConfig};
+use crate::{BigInt, Field, SqrtPrecomputation};
+use ark_std::marker::PhantomData;
+
+/// The Goldilocks prime field, i.e. the field of integers modulo
+/// `p = 2^64 - 2^32 + 1`.
+///
+/// Elements are stored in canonical (non-Montgomery) form, and all arithmetic
+/// exploits the shape of the modulus; see [`GoldilocksBackend`].
+pub type Goldilocks = Fp64<GoldilocksBackend>;
+
+/// An [`FpConfig`] for the Goldilocks prime `p = 2^64 - 2^32 + 1`.
+///
+/// Since `2^64 = 2^32 - 1 (mod p)` and `2^96 = -1 (mod p)`, a 128-bit product
+/// can be reduced with a handful of 64-bit additions and subtractions, instead
+/// of a generic Montgomery reduction.
+pub struct GoldilocksBackend;
+
+impl GoldilocksBackend {
+    /// The modulus `p = 2^64 - 2^32 + 1`.
+    pub const MODULUS_U64: u64 = 0xFFFF_FFFF_0000_0001;
+
+    /// `2^64 - p = 2^32 - 1`.
+    const EPSILON: u64 = 0xFFFF_FFFF;
+
+    #[inline(always)]
+    const fn from_canonical(value: u64) -> Goldilocks {
+        Fp(BigInt([value]), PhantomData)
+    }
+
+    #[inline(always)]
+    fn value(a: &Goldilocks) -> u64 {
+        (a.0).0[0]
+    }
+
+    /// Computes `a + b mod p` for `a, b < p`.
+    #[inline(always)]
+    const fn add(a: u64, b: u64) -> u64 {
+        let (sum, carry) = a.overflowing_add(b);
+        if carry {
+            // `a + b = sum + 2^64 = sum + EPSILON (mod p)`, and `sum + EPSILON < p`.
+            sum + Self::EPSILON
+        } else if sum >= Self::MODULUS_U64 {
+            sum - Self::MODULUS_U64
+        } else {
+            sum
+        }
+    }
+
+    /// Computes `a - b mod p` for `a, b < p`.
+    #[inline(always)]
+    const fn sub(a: u64, b: u64) -> u64 {
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow {
+            // `a - b = diff - 2^64 = diff - EPSILON (mod p)`, and `diff > EPSILON`.
+            diff - Self::EPSILON
+        } else {
+            diff
+        }
+    }
+
+    /// Reduces a 128-bit integer modulo `p`.
+    #[inline(always)]
+    pub const fn reduce128(x: u128) -> u64 {
+        let x_lo = x as u64;
+        let x_hi = (x >> 64) as u64;
+        let x_hi_hi = x_hi >> 32;
+        let x_hi_lo = x_hi & Self::EPSILON;
+
+        // x = x_lo + 2^64 * x_hi_lo + 2^96 * x_hi_hi
+        //   = x_lo + EPSILON * x_hi_lo - x_hi_hi (mod p)
+        let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+        if borrow {
+            // Cannot underflow, since `t0 >= 2^64 - 2^32`.
+            t0 -= Self::EPSILON;
+        }
+        let t1 = x_hi_lo * Self::EPSILON;
+        let (mut res, carry) = t0.overflowing_add(t1);
+        if carry {
+            // Cannot overflow, since `t1 <= (2^32 - 1)^2`.
+            res += Self::EPSILON;
+        }
+        // `res < 2^64 < 2p`, so a single subtraction suffices.
+        if res >= Self::MODULUS_U64 {
+            res - Self::MODULUS_U64
+        } else {
+            res
+        }
+    }
+}
+
+impl FpConfig<1> for GoldilocksBackend {
+    const MODULUS: BigInt<1> = BigInt([Self::MODULUS_U64]);
+
+    const GENERATOR: Goldilocks = Self::from_canonical(7);
+
+    const ZERO: Goldilocks = Self::from_canonical(0);
+
+    const ONE: Goldilocks = Self::from_canonical(1);
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// `7^((p - 1) / 2^32)`
+    const TWO_ADIC_ROOT_OF_UNITY: Goldilocks = Self::from_canonical(1753635133440165772);
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Goldilocks>> =
+        Some(SqrtPrecomputation::TonelliShanks {
+            two_adicity: Self::TWO_ADICITY,
+            quadratic_nonresidue_to_trace: Self::TWO_ADIC_ROOT_OF_UNITY,
+            // `(t - 1) / 2`, where `t = (p - 1) / 2^32 = 2^32 - 1`.
+            trace_of_modulus_minus_one_div_two: &[0x7FFF_FFFF],
+        });
+
+    #[inline(always)]
+    fn add_assign(a: &mut Goldilocks, b: &Goldilocks) {
+        *a = Self::from_canonical(Self::add(Self::value(a), Self::value(b)));
+    }
+
+    #[inline(always)]
+    fn sub_assign(a: &mut Goldilocks, b: &Goldilocks) {
+        *a = Self::from_canonical(Self::sub(Self::value(a), Self::value(b)));
+    }
+
+    #[inline(always)]
+    fn double_in_place(a: &mut Goldilocks) {
+        let a_val = Self::value(a);
+        *a = Self::from_canonical(Self::add(a_val, a_val));
+    }
+
+    #[inline(always)]
+    fn neg_in_place(a: &mut Goldilocks) {
+        *a = Self::from_canonical(Self::sub(0, Self::value(a)));
+    }
+
+    #[inline(always)]
+    fn mul_assign(a: &mut Goldilocks, b: &Goldilocks) {
+        let product = Self::value(a) as u128 * Self::value(b) as u128;
+        *a = Self::from_canonical(Self::reduce128(product));
+    }
+
+    fn sum_of_products<const T: usize>(a: &[Goldilocks; T], b: &[Goldilocks; T]) -> Goldilocks {
+        let sum = a.iter().zip(b).fold(0u64, |acc, (a, b)| {
+            let product = Self::value(a) as u128 * Self::value(b) as u128;
+            Self::add(acc, Self::reduce128(product))
+        });
+        Self::from_canonical(sum)
+    }
+
+    #[inline(always)]
+    fn square_in_place(a: &mut Goldilocks) {
+        let a_val = Self::value(a) as u128;
+        *a = Self::from_canonical(Self::reduce128(a_val * a_val));
+    }
+
+    fn inverse(a: &Goldilocks) -> Option<Goldilocks> {
+        if Self::value(a) == 0 {
+            None
+        } else {
+            // Fermat's little theorem: a^{-1} = a^(p - 2).
+            Some(a.pow([Self::MODULUS_U64 - 2]))
+        }
+    }
+
+    #[inline]
+    fn from_bigint(other: BigInt<1>) -> Option<Goldilocks> {
+        if other.0[0] < Self::MODULUS_U64 {
+            Some(Self::from_canonical(other.0[0]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn into_bigint(other: Goldilocks) -> BigInt<1> {
+        other.0
+    }
+}
@@ -0,0 +1,225 @@
+//! Vectorized Montgomery multiplication using AVX-512 IFMA.
+//!
+//! Eight field elements are multiplied at a time: each 512-bit register holds
+//! the same 52-bit limb of eight different elements, and the
+//! `vpmadd52{lo,hi}uq` instructions accumulate the low and high halves of the
+//! 104-bit limb products.
+//!
+//! Since the Montgomery radix `2^(52 * L)` used by the vectorized
+//! multiplication differs from the radix `R = 2^(64 * N)` of [`MontBackend`],
+//! each product is followed by a second Montgomery multiplication by the
+//! constant `2^(104 * L - 64 * N) mod MODULUS`, which converts the result back
+//! into the representation used by [`MontBackend`].
+use super::{Fp, MontBackend, MontConfig};
+use crate::BigInt;
+use ark_std::marker::PhantomData;
+use core::arch::x86_64::*;
+
+/// The largest number of 64-bit limbs supported by the vectorized
+/// multiplication.
+pub(super) const MAX_NUM_LIMBS: usize = 8;
+
+/// The number of 52-bit limbs needed to represent `MAX_NUM_LIMBS` 64-bit
+/// limbs.
+const MAX_NUM_LIMBS_52: usize = num_limbs_52(MAX_NUM_LIMBS);
+
+/// The number of field elements processed at a time.
+const LANES: usize = 8;
+
+const MASK_52: u64 = (1 << 52) - 1;
+
+const fn num_limbs_52(n: usize) -> usize {
+    (64 * n + 51) / 52
+}
+
+/// Converts `x` from 64-bit limbs to 52-bit limbs.
+const fn to_radix_52<const N: usize>(x: &[u64; N]) -> [u64; MAX_NUM_LIMBS_52] {
+    let mut result = [0u64; MAX_NUM_LIMBS_52];
+    let mut j = 0;
+    while j < num_limbs_52(N) {
+        let (limb, shift) = ((52 * j) / 64, (52 * j) % 64);
+        let mut value = x[limb] >> shift;
+        if shift > 12 && limb + 1 < N {
+            value |= x[limb + 1] << (64 - shift);
+        }
+        result[j] = value & MASK_52;
+        j += 1;
+    }
+    result
+}
+
+/// Converts `x` from 52-bit limbs to 64-bit limbs.
+fn from_radix_52<const N: usize>(x: &[u64; MAX_NUM_LIMBS_52]) -> [u64; N] {
+    let mut result = [0u64; N];
+    for (j, value) in x.iter().enumerate().take(num_limbs_52(N)) {
+        let (limb, shift) = ((52 * j) / 64, (52 * j) % 64);
+        result[limb] |= value << shift;
+        if shift > 12 && limb + 1 < N {
+            result[limb + 1] |= value >> (64 - shift);
+        }
+    }
+    result
+}
+
+/// Computes `2^(104 * L - 64 * N) mod MODULUS`, by doubling `R` modulo
+/// `MODULUS` a total of `104 * L - 128 * N` times.
+const fn correction<T: MontConfig<N>, const N: usize>() -> BigInt<N> {
+    let mut result = T::R;
+    let mut i = 0;
+    while i < 104 * num_limbs_52(N) - 128 * N {
+        let (doubled, carry) = result.const_add_with_carry(&result);
+        let (reduced, borrow) = doubled.const_sub_with_borrow(&T::MODULUS);
+        result = if carry || !borrow { reduced } else { doubled };
+        i += 1;
+    }
+    result
+}
+
+struct Constants<T: MontConfig<N>, const N: usize>(PhantomData<T>);
+
+impl<T: MontConfig<N>, const N: usize> Constants<T, N> {
+    const NUM_LIMBS_52: usize = num_limbs_52(N);
+    const MODULUS: [u64; MAX_NUM_LIMBS_52] = to_radix_52(&T::MODULUS.0);
+    const CORRECTION: [u64; MAX_NUM_LIMBS_52] = to_radix_52(&correction::<T, N>().0);
+    /// `-MODULUS^{-1} mod 2^52`
+    const INV: u64 = T::INV & MASK_52;
+}
+
+/// Sets `a[i] *= b[i]` for every `i`.
+pub(super) fn mul_batch<T: MontConfig<N>, const N: usize>(
+    a: &mut [Fp<MontBackend<T, N>, N>],
+    b: &[Fp<MontBackend<T, N>, N>],
+) {
+    let mut a_chunks = a.chunks_exact_mut(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let b_remainder = b_chunks.remainder();
+    for (a, b) in (&mut a_chunks).zip(b_chunks) {
+        // SAFETY: this module is only compiled when the `avx512ifma` target
+        // feature (which implies `avx512f`) is enabled.
+        #[allow(unsafe_code)]
+        unsafe {
+            mul_chunk::<T, N>(a, b)
+        };
+    }
+    for (a, b) in a_chunks.into_remainder().iter_mut().zip(b_remainder) {
+        T::mul_assign(a, b);
+    }
+}
+
+/// Multiplies `LANES` pairs of field elements.
+#[target_feature(enable = "avx512f,avx512ifma")]
+#[allow(unsafe_code)]
+unsafe fn mul_chunk<T: MontConfig<N>, const N: usize>(
+    a: &mut [Fp<MontBackend<T, N>, N>],
+    b: &[Fp<MontBackend<T, N>, N>],
+) {
+    let num_limbs = Constants::<T, N>::NUM_LIMBS_52;
+    let modulus = splat(&Constants::<T, N>::MODULUS);
+    let correction = splat(&Constants::<T, N>::CORRECTION);
+    let inv = _mm512_set1_epi64(Constants::<T, N>::INV as i64);
+
+    let product = mont_mul(&transpose(a), &transpose(b), &modulus, inv, num_limbs);
+    let product = mont_mul(&product, &correction, &modulus, inv, num_limbs);
+
+    let mut limbs = [[0u64; MAX_NUM_LIMBS_52]; LANES];
+    for (j, limb) in product.iter().enumerate().take(num_limbs) {
+        let lanes: [u64; LANES] = core::mem::transmute(*limb);
+        for (element, lane) in limbs.iter_mut().zip(lanes) {
+            element[j] = lane;
+        }
+    }
+    for (a, limbs) in a.iter_mut().zip(&limbs) {
+        (a.0).0 = from_radix_52(limbs);
+    }
+}
+
+/// Converts `LANES` field elements into 52-bit limbs, such that the `j`-th
+/// register holds the `j`-th limb of each element.
+#[target_feature(enable = "avx512f,avx512ifma")]
+#[allow(unsafe_code)]
+unsafe fn transpose<T: MontConfig<N>, const N: usize>(
+    elements: &[Fp<MontBackend<T, N>, N>],
+) -> [__m512i; MAX_NUM_LIMBS_52] {
+    let mut lanes = [[0u64; LANES]; MAX_NUM_LIMBS_52];
+    for (i, element) in elements.iter().enumerate() {
+        let limbs = to_radix_52(&(element.0).0);
+        for j in 0..Constants::<T, N>::NUM_LIMBS_52 {
+            lanes[j][i] = limbs[j];
+        }
+    }
+    let mut result = [_mm512_setzero_si512(); MAX_NUM_LIMBS_52];
+    for (result, lane) in result.iter_mut().zip(lanes) {
+        *result = core::mem::transmute::<[u64; LANES], __m512i>(lane);
+    }
+    result
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+#[allow(unsafe_code)]
+unsafe fn splat(limbs: &[u64; MAX_NUM_LIMBS_52]) -> [__m512i; MAX_NUM_LIMBS_52] {
+    let mut result = [_mm512_setzero_si512(); MAX_NUM_LIMBS_52];
+    for (result, limb) in result.iter_mut().zip(limbs) {
+        *result = _mm512_set1_epi64(*limb as i64);
+    }
+    result
+}
+
+/// Computes `a * b * 2^(-52 * num_limbs) mod modulus` in each lane, for
+/// `a, b < modulus`. The result is fully reduced.
+#[target_feature(enable = "avx512f,avx512ifma")]
+#[allow(unsafe_code)]
+unsafe fn mont_mul(
+    a: &[__m512i; MAX_NUM_LIMBS_52],
+    b: &[__m512i; MAX_NUM_LIMBS_52],
+    modulus: &[__m512i; MAX_NUM_LIMBS_52],
+    inv: __m512i,
+    num_limbs: usize,
+) -> [__m512i; MAX_NUM_LIMBS_52] {
+    let zero = _mm512_setzero_si512();
+    let mask = _mm512_set1_epi64(MASK_52 as i64);
+
+    // The accumulators are not normalized inside the loop: each of them
+    // receives fewer than `4 * num_limbs <= 40` values below `2^52`, so they
+    // cannot overflow.
+    let mut t = [zero; MAX_NUM_LIMBS_52 + 1];
+    for i in 0..num_limbs {
+        for j in 0..num_limbs {
+            t[j] = _mm512_madd52lo_epu64(t[j], a[j], b[i]);
+            t[j + 1] = _mm512_madd52hi_epu64(t[j + 1], a[j], b[i]);
+        }
+        // Only the low 52 bits of `t[0]` are used by the multiplication.
+        let k = _mm512_madd52lo_epu64(zero, t[0], inv);
+        for j in 0..num_limbs {
+            t[j] = _mm512_madd52lo_epu64(t[j], k, modulus[j]);
+            t[j + 1] = _mm512_madd52hi_epu64(t[j + 1], k, modulus[j]);
+        }
+        // The low 52 bits of `t[0]` are now zero, so we can shift by one limb.
+        let carry = _mm512_srli_epi64(t[0], 52);
+        for j in 0..num_limbs {
+            t[j] = t[j + 1];
+        }
+        t[num_limbs] = zero;
+        t[0] = _mm512_add_epi64(t[0], carry);
+    }
+
+    // Normalize the limbs; the result is now less than `2 * modulus`.
+    for j in 0..num_limbs - 1 {
+        let carry = _mm512_srli_epi64(t[j], 52);
+        t[j] = _mm512_and_si512(t[j], mask);
+        t[j + 1] = _mm512_add_epi64(t[j + 1], carry);
+    }
+
+    // Conditionally subtract the modulus.
+    let mut borrow = zero;
+    let mut result = [zero; MAX_NUM_LIMBS_52];
+    for j in 0..num_limbs {
+        let diff = _mm512_sub_epi64(_mm512_sub_epi64(t[j], modulus[j]), borrow);
+        borrow = _mm512_srli_epi64(diff, 63);
+        result[j] = _mm512_and_si512(diff, mask);
+    }
+    let keep = _mm512_cmpneq_epi64_mask(borrow, zero);
+    for j in 0..num_limbs {
+        result[j] = _mm512_mask_blend_epi64(keep, result[j], t[j]);
+    }
+    result
+}
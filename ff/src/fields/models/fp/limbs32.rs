@@ -0,0 +1,168 @@
+//! Montgomery multiplication with 32-bit digits.
+//!
+//! On targets without a native 64×64→128 multiplier (e.g. Cortex-M, RISC-V32
+//! and `wasm32`), each 64-bit multiply-accumulate in the CIOS loop of
+//! [`MontConfig`] is emulated by several 32-bit multiplications. Instead, this
+//! module treats each 64-bit limb as two 32-bit digits and runs CIOS with
+//! radix `2^32` over the `2 * N` digits.
+//!
+//! Since `R = 2^(64 * N) = (2^32)^(2 * N)`, the Montgomery form is unchanged:
+//! elements are still stored in, and serialized from, the same `BigInt<N>`,
+//! and only the multiplication routine differs.
+use super::{Fp, MontBackend, MontConfig};
+
+/// A multi-precision integer stored as `2 * N` little-endian 32-bit digits.
+type Digits<const N: usize> = [[u32; 2]; N];
+
+#[inline(always)]
+fn to_digits<const N: usize>(limbs: &[u64; N]) -> Digits<N> {
+    limbs.map(|limb| [limb as u32, (limb >> 32) as u32])
+}
+
+#[inline(always)]
+fn from_digits<const N: usize>(digits: &Digits<N>) -> [u64; N] {
+    digits.map(|[lo, hi]| lo as u64 | ((hi as u64) << 32))
+}
+
+#[inline(always)]
+fn get<const N: usize>(digits: &Digits<N>, i: usize) -> u32 {
+    digits[i / 2][i % 2]
+}
+
+#[inline(always)]
+fn set<const N: usize>(digits: &mut Digits<N>, i: usize, value: u32) {
+    digits[i / 2][i % 2] = value;
+}
+
+/// Computes `a + b * c + carry`, returning the low digit and setting `carry`
+/// to the high digit. The result cannot overflow a `u64`.
+#[inline(always)]
+fn mac_with_carry(a: u32, b: u32, c: u32, carry: &mut u32) -> u32 {
+    let tmp = a as u64 + (b as u64 * c as u64) + *carry as u64;
+    *carry = (tmp >> 32) as u32;
+    tmp as u32
+}
+
+/// Computes `a + carry`, returning the low digit and setting `carry` to the
+/// high digit.
+#[inline(always)]
+fn adc(a: u32, carry: &mut u32) -> u32 {
+    let tmp = a as u64 + *carry as u64;
+    *carry = (tmp >> 32) as u32;
+    tmp as u32
+}
+
+/// Sets `a = a * b * R^{-1} mod T::MODULUS`, using 32-bit digits.
+pub(super) fn mul_assign<T: MontConfig<N>, const N: usize>(
+    a: &mut Fp<MontBackend<T, N>, N>,
+    b: &Fp<MontBackend<T, N>, N>,
+) {
+    let num_digits = 2 * N;
+    // `-MODULUS^{-1} mod 2^32`.
+    let inv = T::INV as u32;
+    let a_digits = to_digits(&(a.0).0);
+    let b_digits = to_digits(&(b.0).0);
+    let modulus = to_digits(&T::MODULUS.0);
+
+    // The accumulator consists of `num_digits` digits in `t`, followed by the
+    // two digits `t_hi` and `t_hi_hi`.
+    let mut t: Digits<N> = [[0; 2]; N];
+    let mut t_hi = 0u32;
+    for i in 0..num_digits {
+        let b_i = get(&b_digits, i);
+        let mut carry = 0;
+        for j in 0..num_digits {
+            let t_j = mac_with_carry(get(&t, j), get(&a_digits, j), b_i, &mut carry);
+            set(&mut t, j, t_j);
+        }
+        t_hi = adc(t_hi, &mut carry);
+        let t_hi_hi = carry;
+
+        let k = get(&t, 0).wrapping_mul(inv);
+        let mut carry = 0;
+        mac_with_carry(get(&t, 0), k, get(&modulus, 0), &mut carry);
+        for j in 1..num_digits {
+            let t_j = mac_with_carry(get(&t, j), k, get(&modulus, j), &mut carry);
+            set(&mut t, j - 1, t_j);
+        }
+        set(&mut t, num_digits - 1, adc(t_hi, &mut carry));
+        t_hi = t_hi_hi + carry;
+    }
+
+    (a.0).0 = from_digits(&t);
+    if T::MODULUS_HAS_SPARE_BIT {
+        a.subtract_modulus();
+    } else {
+        a.subtract_modulus_with_carry(t_hi != 0);
+    }
+}
+
+/// Sets `a = a^2 * R^{-1} mod T::MODULUS`, using 32-bit digits.
+#[inline(always)]
+pub(super) fn square_in_place<T: MontConfig<N>, const N: usize>(a: &mut Fp<MontBackend<T, N>, N>) {
+    let b = *a;
+    mul_assign(a, &b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BigInt;
+    use ark_std::{test_rng, vec::Vec, UniformRand};
+    use num_bigint::BigUint;
+
+    macro_rules! test_mul {
+        ($name:ident, $n:literal, $modulus:literal) => {
+            #[test]
+            fn $name() {
+                // Only the modulus is used by the multiplication routines.
+                struct Config;
+                impl MontConfig<$n> for Config {
+                    const MODULUS: BigInt<$n> = BigInt!($modulus);
+                    const GENERATOR: Fp<MontBackend<Self, $n>, $n> =
+                        Fp::new_unchecked(BigInt::zero());
+                    const TWO_ADIC_ROOT_OF_UNITY: Fp<MontBackend<Self, $n>, $n> =
+                        Fp::new_unchecked(BigInt::zero());
+                }
+
+                let modulus = BigUint::from(Config::MODULUS);
+                let r = BigUint::from(1u8) << (64 * $n);
+                let r_inv = r.modpow(&(&modulus - 2u8), &modulus);
+                let to_fp = |x: BigUint| {
+                    Fp::<MontBackend<Config, $n>, $n>::new_unchecked(
+                        (x % &modulus).try_into().unwrap(),
+                    )
+                };
+
+                let rng = &mut test_rng();
+                let elems = [BigUint::from(0u8), BigUint::from(1u8), &modulus - 1u8]
+                    .into_iter()
+                    .chain((0..1000).map(|_| BigInt::<$n>::rand(rng).into()))
+                    .map(to_fp)
+                    .collect::<Vec<_>>();
+                for (a, b) in elems.iter().zip(elems.iter().rev()) {
+                    let expected = BigUint::from(a.0) * BigUint::from(b.0) * &r_inv % &modulus;
+                    let mut c = *a;
+                    mul_assign(&mut c, b);
+                    assert_eq!(BigUint::from(c.0), expected);
+
+                    let expected = BigUint::from(a.0).pow(2) * &r_inv % &modulus;
+                    let mut d = *a;
+                    square_in_place(&mut d);
+                    assert_eq!(BigUint::from(d.0), expected);
+                }
+            }
+        };
+    }
+
+    // BLS12-381 base field.
+    test_mul!(test_bls12_381_fq, 6, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787");
+    // secp256k1 base field, which has no spare bit.
+    test_mul!(
+        test_secp256k1_fq,
+        4,
+        "115792089237316195423570985008687907853269984665640564039457584007908834671663"
+    );
+    // MNT4-753 base field.
+    test_mul!(test_mnt4_753_fq, 12, "41898490967918953402344214791240637128170709919953949071783502921025352812571106773058893763790338921418070971888253786114353726529584385201591605722013126468931404347949840543007986327743462853720628051692141265303114721689601");
+}
@@ -0,0 +1,146 @@
+use super::{Fp32, Fp32Config};
+use crate::{
+    fields::{Fp2, Fp2Config, QuadExtConfig, QuadExtField},
+    AdditiveGroup, FftField,
+};
+
+/// The Mersenne-31 prime field, i.e. the field of integers modulo
+/// `p = 2^31 - 1`.
+///
+/// Since `p - 1 = 2 * 3^2 * 7 * 11 * 31 * 151 * 331` has two-adicity one,
+/// FFTs are typically performed over [`Mersenne31Fp2`] or [`Mersenne31Fp4`].
+pub type Mersenne31 = Fp32<Mersenne31Config>;
+
+/// An [`Fp32Config`] for the Mersenne prime `p = 2^31 - 1`.
+///
+/// Since `2^31 = 1 (mod p)`, a 62-bit product can be reduced by folding its
+/// high bits onto its low bits, instead of performing a division.
+pub struct Mersenne31Config;
+
+impl Fp32Config for Mersenne31Config {
+    const MODULUS: u32 = (1 << 31) - 1;
+
+    const GENERATOR: u32 = 7;
+
+    /// `-1`, since the two-adicity of `p - 1` is one.
+    const TWO_ADIC_ROOT_OF_UNITY: u32 = Self::MODULUS - 1;
+
+    #[inline(always)]
+    fn reduce(x: u64) -> u32 {
+        const P: u64 = Mersenne31Config::MODULUS as u64;
+        // x = x_lo + 2^31 * x_hi = x_lo + x_hi (mod p).
+        // After the first fold, `x < 2^34`; after the second, `x < 2^31 + 8`.
+        let x = (x & P) + (x >> 31);
+        let x = (x & P) + (x >> 31);
+        if x >= P {
+            (x - P) as u32
+        } else {
+            x as u32
+        }
+    }
+}
+
+/// The quadratic extension `F_p[i] / (i^2 + 1)` of [`Mersenne31`].
+///
+/// Since `p = 3 (mod 4)`, `-1` is a quadratic non-residue, and the
+/// multiplicative group of this field has order `p^2 - 1 = 2^32 * t` for odd
+/// `t`.
+pub type Mersenne31Fp2 = Fp2<Mersenne31Fp2Config>;
+
+pub struct Mersenne31Fp2Config;
+
+impl Fp2Config for Mersenne31Fp2Config {
+    type Fp = Mersenne31;
+
+    /// `-1`
+    const NONRESIDUE: Mersenne31 = Mersenne31::new(Mersenne31Config::MODULUS - 1);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Mersenne31] = &[
+        // NONRESIDUE^((p^0 - 1) / 2)
+        Mersenne31::new(1),
+        // NONRESIDUE^((p^1 - 1) / 2)
+        Mersenne31::new(Mersenne31Config::MODULUS - 1),
+    ];
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Mersenne31) -> &mut Mersenne31 {
+        fe.neg_in_place()
+    }
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_and_add(y: &mut Mersenne31, x: &Mersenne31) {
+        y.neg_in_place();
+        *y += x;
+    }
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_plus_one_and_add(y: &mut Mersenne31, x: &Mersenne31) {
+        *y = *x;
+    }
+
+    #[inline(always)]
+    fn sub_and_mul_fp_by_nonresidue(y: &mut Mersenne31, x: &Mersenne31) {
+        *y += x;
+    }
+}
+
+impl FftField for Mersenne31Fp2 {
+    /// `12 + i`
+    const GENERATOR: Self = QuadExtField::new(Mersenne31::new(12), Mersenne31::new(1));
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// `GENERATOR^((p^2 - 1) / 2^32)`
+    const TWO_ADIC_ROOT_OF_UNITY: Self =
+        QuadExtField::new(Mersenne31::new(1166849849), Mersenne31::new(1117296306));
+}
+
+/// The quadratic extension `F_{p^2}[j] / (j^2 - (2 + i))` of
+/// [`Mersenne31Fp2`].
+///
+/// The multiplicative group of this field has order `p^4 - 1 = 2^33 * t` for
+/// odd `t`.
+pub type Mersenne31Fp4 = QuadExtField<Mersenne31Fp4Config>;
+
+pub struct Mersenne31Fp4Config;
+
+impl QuadExtConfig for Mersenne31Fp4Config {
+    type BasePrimeField = Mersenne31;
+    type BaseField = Mersenne31Fp2;
+    type FrobCoeff = Mersenne31Fp2;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 4;
+
+    /// `2 + i`
+    const NONRESIDUE: Mersenne31Fp2 = QuadExtField::new(Mersenne31::new(2), Mersenne31::new(1));
+
+    /// Coefficients for the Frobenius automorphism, i.e.
+    /// `NONRESIDUE^((p^k - 1) / 2)` for `k = 0, 1, 2, 3`.
+    const FROBENIUS_COEFF_C1: &'static [Mersenne31Fp2] = &[
+        QuadExtField::new(Mersenne31::new(1), Mersenne31::new(0)),
+        QuadExtField::new(Mersenne31::new(21189756), Mersenne31::new(42379512)),
+        QuadExtField::new(Mersenne31::new(2147483646), Mersenne31::new(0)),
+        QuadExtField::new(Mersenne31::new(2126293891), Mersenne31::new(2105104135)),
+    ];
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Mersenne31Fp2, power: usize) {
+        *fe *= &Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+    }
+}
+
+impl FftField for Mersenne31Fp4 {
+    /// `4 + (1 + i) * j`
+    const GENERATOR: Self = QuadExtField::new(
+        QuadExtField::new(Mersenne31::new(4), Mersenne31::new(0)),
+        QuadExtField::new(Mersenne31::new(1), Mersenne31::new(1)),
+    );
+
+    const TWO_ADICITY: u32 = 33;
+
+    /// `GENERATOR^((p^4 - 1) / 2^33)`
+    const TWO_ADIC_ROOT_OF_UNITY: Self = QuadExtField::new(
+        QuadExtField::new(Mersenne31::new(0), Mersenne31::new(0)),
+        QuadExtField::new(Mersenne31::new(895431516), Mersenne31::new(2110488000)),
+    );
+}
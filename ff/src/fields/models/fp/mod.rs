@@ -1,25 +1,63 @@
 use crate::{
-    AdditiveGroup, BigInt, BigInteger, FftField, Field, LegendreSymbol, One, PrimeField,
-    SqrtPrecomputation, Zero,
+    vec_ops::CHUNK_SIZE, AdditiveGroup, BigInt, BigInteger, FftField, Field, LegendreSymbol, One,
+    PrimeField, SqrtPrecomputation, Zero,
 };
 use ark_serialize::{
     buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
     CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
+    ValidationError,
 };
 use ark_std::{
+    cfg_chunks, cfg_chunks_mut,
     cmp::*,
     fmt::{Display, Formatter, Result as FmtResult},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
     string::*,
+    vec::*,
 };
 use core::iter;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[macro_use]
 mod montgomery_backend;
 pub use montgomery_backend::*;
 
+mod accumulator;
+pub use accumulator::*;
+
+#[cfg(all(feature = "asm", target_arch = "x86_64", target_feature = "avx512ifma"))]
+mod ifma;
+
+mod limbs32;
+
+mod canonical;
+
+mod safegcd;
+
+#[cfg(feature = "ct")]
+mod ct;
+
+mod goldilocks;
+pub use goldilocks::*;
+
+mod fp32;
+pub use fp32::*;
+
+mod mersenne31;
+pub use mersenne31::*;
+
+mod babybear;
+pub use babybear::*;
+
+mod barrett;
+pub use barrett::*;
+
+mod crandall;
+pub use crandall::*;
+
 /// A trait that specifies the configuration of a prime field.
 /// Also specifies how to perform arithmetic on field elements.
 pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
@@ -47,6 +85,15 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     /// 2^s root of unity computed by GENERATOR^t
     const TWO_ADIC_ROOT_OF_UNITY: Fp<Self, N>;
 
+    /// The chain of 2-adic roots of unity `[w_0, w_1, ..., w_s]`, where
+    /// `w_s = TWO_ADIC_ROOT_OF_UNITY` and `w_(k - 1) = w_k^2`, or an empty
+    /// slice if it is not precomputed.
+    const TWO_ADIC_ROOTS_OF_UNITY: &'static [Fp<Self, N>] = &[];
+
+    /// The inverses of the elements of `TWO_ADIC_ROOTS_OF_UNITY`, or an empty
+    /// slice if they are not precomputed.
+    const TWO_ADIC_ROOTS_OF_UNITY_INV: &'static [Fp<Self, N>] = &[];
+
     /// An integer `b` such that there exists a multiplicative subgroup
     /// of size `b^k` for some integer `k`.
     const SMALL_SUBGROUP_BASE: Option<u32> = None;
@@ -83,6 +130,33 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     /// Compute the inner product `<a, b>`.
     fn sum_of_products<const T: usize>(a: &[Fp<Self, N>; T], b: &[Fp<Self, N>; T]) -> Fp<Self, N>;
 
+    /// Set `a[i] *= b[i]` for every `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    fn mul_batch(a: &mut [Fp<Self, N>], b: &[Fp<Self, N>]) {
+        assert_eq!(a.len(), b.len());
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(a, b)| Self::mul_assign(a, b));
+    }
+
+    /// Compute the inner product `<a, b>` of slices of any length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    fn dot_product(a: &[Fp<Self, N>], b: &[Fp<Self, N>]) -> Fp<Self, N> {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).fold(Self::ZERO, |mut sum, (a, b)| {
+            let mut product = *a;
+            Self::mul_assign(&mut product, b);
+            Self::add_assign(&mut sum, &product);
+            sum
+        })
+    }
+
     /// Set a *= a.
     fn square_in_place(a: &mut Fp<Self, N>);
 
@@ -97,6 +171,38 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     /// Convert a field element to an integer in the range `0..(Self::MODULUS -
     /// 1)`.
     fn into_bigint(other: Fp<Self, N>) -> BigInt<N>;
+
+    /// Convert every element of `a` to an integer, as [`Self::into_bigint`]
+    /// does, and write the results to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `out` have different lengths.
+    fn into_bigint_batch(a: &[Fp<Self, N>], out: &mut [BigInt<N>]) {
+        assert_eq!(a.len(), out.len());
+        out.iter_mut()
+            .zip(a)
+            .for_each(|(out, a)| *out = Self::into_bigint(*a));
+    }
+
+    /// Convert every integer in `a` to a field element, as
+    /// [`Self::from_bigint`] does, and write the results to `out`. Returns
+    /// `false` if some integer is not smaller than `Self::MODULUS`, in which
+    /// case the contents of `out` are unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `out` have different lengths.
+    fn from_bigint_batch(a: &[BigInt<N>], out: &mut [Fp<Self, N>]) -> bool {
+        assert_eq!(a.len(), out.len());
+        for (out, a) in out.iter_mut().zip(a) {
+            match Self::from_bigint(*a) {
+                Some(a) => *out = a,
+                None => return false,
+            }
+        }
+        true
+    }
 }
 
 /// Represents an element of the prime field F_p, where `p == P::MODULUS`.
@@ -134,11 +240,10 @@ impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
 
     #[inline]
     fn subtract_modulus(&mut self) {
-        if self.is_geq_modulus() {
-            self.0.sub_with_borrow(&Self::MODULUS);
-        }
+        self.subtract_modulus_with_carry(false)
     }
 
+    #[cfg(not(feature = "ct"))]
     #[inline]
     fn subtract_modulus_with_carry(&mut self, carry: bool) {
         if carry || self.is_geq_modulus() {
@@ -146,6 +251,61 @@ impl<P: FpConfig<N>, const N: usize> Fp<P, N> {
         }
     }
 
+    /// Branch-free version of the conditional subtraction, so that the
+    /// arithmetic does not leak whether a reduction step was taken.
+    #[cfg(feature = "ct")]
+    #[inline]
+    fn subtract_modulus_with_carry(&mut self, carry: bool) {
+        use subtle::ConditionallySelectable;
+        let mut reduced = self.0;
+        let borrow = reduced.sub_with_borrow(&Self::MODULUS);
+        self.0
+            .conditional_assign(&reduced, subtle::Choice::from((carry | !borrow) as u8));
+    }
+
+    /// Sets `self = self - other`, where both `self` and `other` are in
+    /// `0..P::MODULUS`.
+    #[inline(always)]
+    fn sub_assign_reduced(&mut self, other: &Self) {
+        let borrow = self.0.sub_with_borrow(&other.0);
+        self.add_modulus_with_borrow(borrow);
+    }
+
+    #[cfg(not(feature = "ct"))]
+    #[inline(always)]
+    fn add_modulus_with_borrow(&mut self, borrow: bool) {
+        if borrow {
+            self.0.add_with_carry(&P::MODULUS);
+        }
+    }
+
+    #[cfg(feature = "ct")]
+    #[inline(always)]
+    fn add_modulus_with_borrow(&mut self, borrow: bool) {
+        use subtle::ConditionallySelectable;
+        let mut tmp = self.0;
+        tmp.add_with_carry(&P::MODULUS);
+        self.0
+            .conditional_assign(&tmp, subtle::Choice::from(borrow as u8));
+    }
+
+    /// Sets `self = -self`, where `self` is in `0..P::MODULUS`.
+    #[inline(always)]
+    fn neg_in_place_reduced(&mut self) {
+        let mut tmp = P::MODULUS;
+        tmp.sub_with_borrow(&self.0);
+        #[cfg(not(feature = "ct"))]
+        if !self.0.is_zero() {
+            self.0 = tmp;
+        }
+        #[cfg(feature = "ct")]
+        {
+            use subtle::{ConditionallySelectable, ConstantTimeEq};
+            let is_zero = self.0.ct_eq(&BigInt::zero());
+            self.0.conditional_assign(&tmp, !is_zero);
+        }
+    }
+
     fn num_bits_to_shave() -> usize {
         64 * N - (Self::MODULUS_BIT_SIZE as usize)
     }
@@ -244,6 +404,16 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         P::sum_of_products(a, b)
     }
 
+    #[inline]
+    fn mul_batch(a: &mut [Self], b: &[Self]) {
+        P::mul_batch(a, b)
+    }
+
+    #[inline]
+    fn dot_product(a: &[Self], b: &[Self]) -> Self {
+        P::dot_product(a, b)
+    }
+
     #[inline]
     fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         if F::BIT_SIZE > 8 {
@@ -324,11 +494,14 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
     fn legendre(&self) -> LegendreSymbol {
         use crate::fields::LegendreSymbol::*;
 
+        // Checking for zero first is required in `GF(2)`, where the exponent
+        // below is zero.
+        if self.is_zero() {
+            return Zero;
+        }
         // s = self^((MODULUS - 1) // 2)
         let s = self.pow(Self::MODULUS_MINUS_ONE_DIV_TWO);
-        if s.is_zero() {
-            Zero
-        } else if s.is_one() {
+        if s.is_one() {
             QuadraticResidue
         } else {
             QuadraticNonResidue
@@ -358,12 +531,30 @@ impl<P: FpConfig<N>, const N: usize> PrimeField for Fp<P, N> {
     fn into_bigint(self) -> BigInt<N> {
         P::into_bigint(self)
     }
+
+    fn into_bigint_batch(elems: &[Self]) -> Vec<BigInt<N>> {
+        let mut res = vec![BigInt::zero(); elems.len()];
+        cfg_chunks_mut!(res, CHUNK_SIZE)
+            .zip(cfg_chunks!(elems, CHUNK_SIZE))
+            .for_each(|(out, a)| P::into_bigint_batch(a, out));
+        res
+    }
+
+    fn from_bigint_batch(ints: &[BigInt<N>]) -> Option<Vec<Self>> {
+        let mut res = vec![Self::ZERO; ints.len()];
+        let in_range = cfg_chunks_mut!(res, CHUNK_SIZE)
+            .zip(cfg_chunks!(ints, CHUNK_SIZE))
+            .all(|(out, a)| P::from_bigint_batch(a, out));
+        in_range.then_some(res)
+    }
 }
 
 impl<P: FpConfig<N>, const N: usize> FftField for Fp<P, N> {
     const GENERATOR: Self = P::GENERATOR;
     const TWO_ADICITY: u32 = P::TWO_ADICITY;
     const TWO_ADIC_ROOT_OF_UNITY: Self = P::TWO_ADIC_ROOT_OF_UNITY;
+    const TWO_ADIC_ROOTS_OF_UNITY: &'static [Self] = P::TWO_ADIC_ROOTS_OF_UNITY;
+    const TWO_ADIC_ROOTS_OF_UNITY_INV: &'static [Self] = P::TWO_ADIC_ROOTS_OF_UNITY_INV;
     const SMALL_SUBGROUP_BASE: Option<u32> = P::SMALL_SUBGROUP_BASE;
     const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = P::SMALL_SUBGROUP_BASE_ADICITY;
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self> = P::LARGE_SUBGROUP_ROOT_OF_UNITY;
@@ -623,7 +814,7 @@ impl<P: FpConfig<N>, const N: usize> CanonicalDeserializeWithFlags for Fp<P, N>
         let self_integer = masked_bytes.to_bigint();
         Self::from_bigint(self_integer)
             .map(|v| (v, flags))
-            .ok_or(SerializationError::InvalidData)
+            .ok_or(ValidationError::NonCanonicalFieldEncoding.into())
     }
 }
 
@@ -646,23 +837,17 @@ impl<P: FpConfig<N>, const N: usize> CanonicalDeserialize for Fp<P, N> {
 impl<P: FpConfig<N>, const N: usize> FromStr for Fp<P, N> {
     type Err = ();
 
-    /// Interpret a string of numbers as a (congruent) prime field element.
-    /// Does not accept unnecessary leading zeroes or a blank string.
+    /// Interprets a decimal or `0x`-prefixed hexadecimal integer, optionally
+    /// preceded by a `-` sign, as a prime field element.
+    /// Returns an error for a blank string, and if the absolute value of the
+    /// integer is not smaller than the modulus.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use num_bigint::{BigInt, BigUint};
-        use num_traits::Signed;
-
-        let modulus = BigInt::from(P::MODULUS);
-        let mut a = BigInt::from_str(s).map_err(|_| ())? % &modulus;
-        if a.is_negative() {
-            a += modulus
-        }
-        BigUint::try_from(a)
-            .map_err(|_| ())
-            .and_then(TryFrom::try_from)
-            .ok()
-            .and_then(Self::from_bigint)
-            .ok_or(())
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let a = Self::from_bigint(s.parse()?).ok_or(())?;
+        Ok(if negative { -a } else { a })
     }
 }
 
@@ -992,7 +1177,11 @@ impl<P: FpConfig<N>, const N: usize> zeroize::Zeroize for Fp<P, N> {
 impl<P: FpConfig<N>, const N: usize> From<num_bigint::BigUint> for Fp<P, N> {
     #[inline]
     fn from(val: num_bigint::BigUint) -> Fp<P, N> {
-        Fp::<P, N>::from_le_bytes_mod_order(&val.to_bytes_le())
+        let digits = val.iter_u64_digits();
+        crate::fields::prime::from_be_bytes_iter_mod_order(
+            8 * digits.len(),
+            digits.rev().flat_map(u64::to_be_bytes),
+        )
     }
 }
 
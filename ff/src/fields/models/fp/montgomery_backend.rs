@@ -1,10 +1,39 @@
-use super::{Fp, FpConfig};
+use super::{Fp, FpAccumulator, FpConfig};
 use crate::{
-    biginteger::arithmetic as fa, BigInt, BigInteger, PrimeField, SqrtPrecomputation, Zero,
+    biginteger::arithmetic as fa, const_helpers::MulBuffer, BigInt, BigInteger, PrimeField,
+    SqrtPrecomputation, Zero,
 };
 use ark_ff_macros::unroll_for_loops;
 use ark_std::marker::PhantomData;
 
+/// The algorithm used to invert elements of a prime field with
+/// [`MontConfig::inverse`].
+///
+/// # Example
+/// ```
+/// use ark_ff::fields::{Field, Fp256, MontBackend, MontConfig};
+///
+/// #[derive(MontConfig)]
+/// #[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+/// #[inversion_strategy = "BernsteinYang"]
+/// pub struct FrConfig;
+/// pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+///
+/// let a = Fr::from(3u64);
+/// assert_eq!(a * a.inverse().unwrap(), Fr::ONE);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InversionStrategy {
+    /// The binary extended Euclidean algorithm, whose running time depends on
+    /// the element being inverted.
+    BinaryEuclid,
+    /// The safegcd algorithm of [Bernstein and Yang](https://eprint.iacr.org/2019/266),
+    /// whose running time only depends on the bit size of the modulus. It is
+    /// also faster than the binary extended Euclidean algorithm for large
+    /// moduli.
+    BernsteinYang,
+}
+
 /// A trait that specifies the constants and arithmetic procedures
 /// for Montgomery arithmetic over the prime field defined by `MODULUS`.
 ///
@@ -59,6 +88,16 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// 2^s root of unity computed by GENERATOR^t
     const TWO_ADIC_ROOT_OF_UNITY: Fp<MontBackend<Self, N>, N>;
 
+    /// The chain of 2-adic roots of unity `[w_0, w_1, ..., w_s]`, where
+    /// `w_s = TWO_ADIC_ROOT_OF_UNITY` and `w_(k - 1) = w_k^2`, or an empty
+    /// slice if it is not precomputed. The
+    /// [`MontConfig`][`ark_ff_macros::MontConfig`] derive macro precomputes it.
+    const TWO_ADIC_ROOTS_OF_UNITY: &'static [Fp<MontBackend<Self, N>, N>] = &[];
+
+    /// The inverses of the elements of `TWO_ADIC_ROOTS_OF_UNITY`, or an empty
+    /// slice if they are not precomputed.
+    const TWO_ADIC_ROOTS_OF_UNITY_INV: &'static [Fp<MontBackend<Self, N>, N>] = &[];
+
     /// An integer `b` such that there exists a multiplicative subgroup
     /// of size `b^k` for some integer `k`.
     const SMALL_SUBGROUP_BASE: Option<u32> = None;
@@ -72,11 +111,24 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// Used for mixed-radix FFT.
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Fp<MontBackend<Self, N>, N>> = None;
 
+    /// The algorithm used by [`Self::inverse`]. The
+    /// [`MontConfig`][`ark_ff_macros::MontConfig`] derive macro sets it from
+    /// the `inversion_strategy` attribute.
+    const INVERSION_STRATEGY: InversionStrategy = InversionStrategy::BinaryEuclid;
+
     /// Precomputed material for use when computing square roots.
     /// The default is to use the standard Tonelli-Shanks algorithm.
     const SQRT_PRECOMP: Option<SqrtPrecomputation<Fp<MontBackend<Self, N>, N>>> =
         sqrt_precomputation::<N, Self>();
 
+    /// (MODULUS + 1) / 2. Used for square root precomputations with Cipolla's
+    /// algorithm.
+    #[doc(hidden)]
+    const MODULUS_PLUS_ONE_DIV_TWO: BigInt<N> = Self::MODULUS
+        .const_shr()
+        .const_add_with_carry(&BigInt::one())
+        .0;
+
     /// (MODULUS + 1) / 4 when MODULUS % 4 == 3. Used for square root precomputations.
     #[doc(hidden)]
     const MODULUS_PLUS_ONE_DIV_FOUR: Option<BigInt<N>> = {
@@ -110,11 +162,7 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// Sets `a = a - b`.
     #[inline(always)]
     fn sub_assign(a: &mut Fp<MontBackend<Self, N>, N>, b: &Fp<MontBackend<Self, N>, N>) {
-        // If `other` is larger than `self`, add the modulus to self first.
-        if b.0 > a.0 {
-            a.0.add_with_carry(&Self::MODULUS);
-        }
-        a.0.sub_with_borrow(&b.0);
+        a.sub_assign_reduced(b);
     }
 
     /// Sets `a = 2 * a`.
@@ -133,11 +181,7 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// Sets `a = -a`.
     #[inline(always)]
     fn neg_in_place(a: &mut Fp<MontBackend<Self, N>, N>) {
-        if !a.is_zero() {
-            let mut tmp = Self::MODULUS;
-            tmp.sub_with_borrow(&a.0);
-            a.0 = tmp;
-        }
+        a.neg_in_place_reduced();
     }
 
     /// This modular multiplication algorithm uses Montgomery
@@ -149,16 +193,22 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     #[unroll_for_loops(12)]
     #[inline(always)]
     fn mul_assign(a: &mut Fp<MontBackend<Self, N>, N>, b: &Fp<MontBackend<Self, N>, N>) {
+        // On 32-bit targets, avoid emulating 64x64->128 multiplications.
+        if cfg!(target_pointer_width = "32") {
+            super::limbs32::mul_assign(a, b);
+            return;
+        }
         // No-carry optimisation applied to CIOS
         if Self::CAN_USE_NO_CARRY_MUL_OPT {
-            if N <= 6
-                && N > 1
-                && cfg!(all(
-                    feature = "asm",
-                    target_feature = "bmi2",
-                    target_feature = "adx",
-                    target_arch = "x86_64"
-                ))
+            if N > 1
+                && ((N <= 12
+                    && cfg!(all(
+                        feature = "asm",
+                        target_feature = "bmi2",
+                        target_feature = "adx",
+                        target_arch = "x86_64"
+                    )))
+                    || (N <= 6 && cfg!(all(feature = "asm", target_arch = "aarch64"))))
             {
                 #[cfg(
                     all(
@@ -178,6 +228,24 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
                     4 => { ark_ff_asm::x86_64_asm_mul!(4, (a.0).0, (b.0).0); },
                     5 => { ark_ff_asm::x86_64_asm_mul!(5, (a.0).0, (b.0).0); },
                     6 => { ark_ff_asm::x86_64_asm_mul!(6, (a.0).0, (b.0).0); },
+                    7 => { ark_ff_asm::x86_64_asm_mul!(7, (a.0).0, (b.0).0); },
+                    8 => { ark_ff_asm::x86_64_asm_mul!(8, (a.0).0, (b.0).0); },
+                    9 => { ark_ff_asm::x86_64_asm_mul!(9, (a.0).0, (b.0).0); },
+                    10 => { ark_ff_asm::x86_64_asm_mul!(10, (a.0).0, (b.0).0); },
+                    11 => { ark_ff_asm::x86_64_asm_mul!(11, (a.0).0, (b.0).0); },
+                    12 => { ark_ff_asm::x86_64_asm_mul!(12, (a.0).0, (b.0).0); },
+                    _ => unsafe { ark_std::hint::unreachable_unchecked() },
+                };
+
+                #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+                #[allow(unsafe_code, unused_mut)]
+                #[rustfmt::skip]
+                match N {
+                    2 => { ark_ff_asm::aarch64_asm_mul!(2, (a.0).0, (b.0).0); },
+                    3 => { ark_ff_asm::aarch64_asm_mul!(3, (a.0).0, (b.0).0); },
+                    4 => { ark_ff_asm::aarch64_asm_mul!(4, (a.0).0, (b.0).0); },
+                    5 => { ark_ff_asm::aarch64_asm_mul!(5, (a.0).0, (b.0).0); },
+                    6 => { ark_ff_asm::aarch64_asm_mul!(6, (a.0).0, (b.0).0); },
                     _ => unsafe { ark_std::hint::unreachable_unchecked() },
                 };
             } else {
@@ -218,6 +286,10 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     #[inline(always)]
     #[unroll_for_loops(12)]
     fn square_in_place(a: &mut Fp<MontBackend<Self, N>, N>) {
+        if cfg!(target_pointer_width = "32") {
+            super::limbs32::square_in_place(a);
+            return;
+        }
         if N == 1 {
             // We default to multiplying with `a` using the `Mul` impl
             // for the N == 1 case
@@ -225,13 +297,14 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
             return;
         }
         if Self::CAN_USE_NO_CARRY_SQUARE_OPT
-            && (2..=6).contains(&N)
-            && cfg!(all(
-                feature = "asm",
-                target_feature = "bmi2",
-                target_feature = "adx",
-                target_arch = "x86_64"
-            ))
+            && (((2..=12).contains(&N)
+                && cfg!(all(
+                    feature = "asm",
+                    target_feature = "bmi2",
+                    target_feature = "adx",
+                    target_arch = "x86_64"
+                )))
+                || ((2..=6).contains(&N) && cfg!(all(feature = "asm", target_arch = "aarch64"))))
         {
             #[cfg(all(
                 feature = "asm",
@@ -247,13 +320,30 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
                 4 => { ark_ff_asm::x86_64_asm_square!(4, (a.0).0); },
                 5 => { ark_ff_asm::x86_64_asm_square!(5, (a.0).0); },
                 6 => { ark_ff_asm::x86_64_asm_square!(6, (a.0).0); },
+                7 => { ark_ff_asm::x86_64_asm_square!(7, (a.0).0); },
+                8 => { ark_ff_asm::x86_64_asm_square!(8, (a.0).0); },
+                9 => { ark_ff_asm::x86_64_asm_square!(9, (a.0).0); },
+                10 => { ark_ff_asm::x86_64_asm_square!(10, (a.0).0); },
+                11 => { ark_ff_asm::x86_64_asm_square!(11, (a.0).0); },
+                12 => { ark_ff_asm::x86_64_asm_square!(12, (a.0).0); },
+                _ => unsafe { ark_std::hint::unreachable_unchecked() },
+            };
+            #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+            #[allow(unsafe_code, unused_mut)]
+            #[rustfmt::skip]
+            match N {
+                2 => { ark_ff_asm::aarch64_asm_square!(2, (a.0).0); },
+                3 => { ark_ff_asm::aarch64_asm_square!(3, (a.0).0); },
+                4 => { ark_ff_asm::aarch64_asm_square!(4, (a.0).0); },
+                5 => { ark_ff_asm::aarch64_asm_square!(5, (a.0).0); },
+                6 => { ark_ff_asm::aarch64_asm_square!(6, (a.0).0); },
                 _ => unsafe { ark_std::hint::unreachable_unchecked() },
             };
             a.subtract_modulus();
             return;
         }
 
-        let mut r = crate::const_helpers::MulBuffer::<N>::zeroed();
+        let mut r = MulBuffer::<N>::zeroed();
 
         let mut carry = 0;
         for i in 0..(N - 1) {
@@ -274,26 +364,19 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
             r[2 * i] = fa::mac_with_carry(r[2 * i], (a.0).0[i], (a.0).0[i], &mut carry);
             carry = fa::adc(&mut r[2 * i + 1], 0, carry);
         }
-        // Montgomery reduction
-        let mut carry2 = 0;
-        for i in 0..N {
-            let k = r[i].wrapping_mul(Self::INV);
-            carry = 0;
-            fa::mac_discard(r[i], k, Self::MODULUS.0[0], &mut carry);
-            for j in 1..N {
-                r[j + i] = fa::mac_with_carry(r[j + i], k, Self::MODULUS.0[j], &mut carry);
-            }
-            carry2 = fa::adc(&mut r.b1[i], carry, carry2);
-        }
+        let carry = montgomery_reduce::<Self, N>(&mut r);
         (a.0).0.copy_from_slice(&r.b1);
         if Self::MODULUS_HAS_SPARE_BIT {
             a.subtract_modulus();
         } else {
-            a.subtract_modulus_with_carry(carry2 != 0);
+            a.subtract_modulus_with_carry(carry);
         }
     }
 
     fn inverse(a: &Fp<MontBackend<Self, N>, N>) -> Option<Fp<MontBackend<Self, N>, N>> {
+        if Self::INVERSION_STRATEGY == InversionStrategy::BernsteinYang {
+            return super::safegcd::inverse(a);
+        }
         if a.is_zero() {
             return None;
         }
@@ -491,8 +574,105 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
                 .sum()
         }
     }
+
+    /// Set `a[i] *= b[i]` for every `i`.
+    ///
+    /// With the `asm` feature enabled on `x86_64` targets supporting AVX-512
+    /// IFMA, this performs eight multiplications at a time using 52-bit
+    /// limbs. Otherwise, it falls back to [`Self::mul_assign`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    fn mul_batch(a: &mut [Fp<MontBackend<Self, N>, N>], b: &[Fp<MontBackend<Self, N>, N>]) {
+        assert_eq!(a.len(), b.len());
+        #[cfg(all(feature = "asm", target_arch = "x86_64", target_feature = "avx512ifma"))]
+        if N <= super::ifma::MAX_NUM_LIMBS {
+            return super::ifma::mul_batch::<Self, N>(a, b);
+        }
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(a, b)| Self::mul_assign(a, b));
+    }
+
+    /// Compute the inner product `<a, b>` of slices of any length.
+    ///
+    /// The double-width products are accumulated without any reduction in an
+    /// [`FpAccumulator`], which is reduced once at the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    fn dot_product(
+        a: &[Fp<MontBackend<Self, N>, N>],
+        b: &[Fp<MontBackend<Self, N>, N>],
+    ) -> Fp<MontBackend<Self, N>, N> {
+        assert_eq!(a.len(), b.len());
+        let mut acc = FpAccumulator::new();
+        for (a, b) in a.iter().zip(b) {
+            acc.add_product(a, b);
+        }
+        acc.reduce()
+    }
+
+    /// Convert every element of `a` to an integer, and write the results to
+    /// `out`.
+    ///
+    /// Since Montgomery reduction is a Montgomery multiplication by the
+    /// integer 1, this goes through [`Self::mul_batch`], which is vectorized
+    /// with AVX-512 IFMA when available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `out` have different lengths.
+    fn into_bigint_batch(a: &[Fp<MontBackend<Self, N>, N>], out: &mut [BigInt<N>]) {
+        assert_eq!(a.len(), out.len());
+        let one = [Fp::new_unchecked(BigInt::from(1u64)); BATCH_CONVERSION_SIZE];
+        let mut buffer = [Fp::new_unchecked(BigInt::zero()); BATCH_CONVERSION_SIZE];
+        for (a, out) in a
+            .chunks(BATCH_CONVERSION_SIZE)
+            .zip(out.chunks_mut(BATCH_CONVERSION_SIZE))
+        {
+            let buffer = &mut buffer[..a.len()];
+            buffer.copy_from_slice(a);
+            Self::mul_batch(buffer, &one[..a.len()]);
+            out.iter_mut().zip(buffer).for_each(|(out, a)| *out = a.0);
+        }
+    }
+
+    /// Convert every integer in `a` to a field element, and write the results
+    /// to `out`. Returns `false` if some integer is not smaller than
+    /// `Self::MODULUS`.
+    ///
+    /// The conversions to Montgomery form are Montgomery multiplications by
+    /// `R^2`, which go through [`Self::mul_batch`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `out` have different lengths.
+    fn from_bigint_batch(a: &[BigInt<N>], out: &mut [Fp<MontBackend<Self, N>, N>]) -> bool {
+        assert_eq!(a.len(), out.len());
+        if a.iter().any(|a| *a >= Self::MODULUS) {
+            return false;
+        }
+        let r2 = [Fp::new_unchecked(Self::R2); BATCH_CONVERSION_SIZE];
+        for (a, out) in a
+            .chunks(BATCH_CONVERSION_SIZE)
+            .zip(out.chunks_mut(BATCH_CONVERSION_SIZE))
+        {
+            out.iter_mut()
+                .zip(a)
+                .for_each(|(out, a)| *out = Fp::new_unchecked(*a));
+            Self::mul_batch(out, &r2[..a.len()]);
+        }
+        true
+    }
 }
 
+/// The number of elements converted together by
+/// [`MontConfig::into_bigint_batch`] and [`MontConfig::from_bigint_batch`].
+const BATCH_CONVERSION_SIZE: usize = 64;
+
 /// Compute -M^{-1} mod 2^64.
 pub const fn inv<T: MontConfig<N>, const N: usize>() -> u64 {
     // We compute this as follows.
@@ -540,6 +720,27 @@ pub const fn can_use_no_carry_square_optimization<T: MontConfig<N>, const N: usi
     top_two_bits_are_zero && !all_remaining_bits_are_one
 }
 
+/// Montgomery-reduces the `2N`-limb integer `t` held in `r`: afterwards,
+/// `r.b1` holds the lower `N` limbs of `t * R^-1 (mod MODULUS)`, computed
+/// as `(t + m * MODULUS) / R` for the `m < R` that makes the division exact,
+/// and the returned carry is its limb `N`. The result is smaller than
+/// `t / R + MODULUS`.
+#[inline(always)]
+#[unroll_for_loops(12)]
+pub(crate) fn montgomery_reduce<T: MontConfig<N>, const N: usize>(r: &mut MulBuffer<N>) -> bool {
+    let mut carry2 = 0;
+    for i in 0..N {
+        let k = r[i].wrapping_mul(T::INV);
+        let mut carry = 0;
+        fa::mac_discard(r[i], k, T::MODULUS.0[0], &mut carry);
+        for j in 1..N {
+            r[j + i] = fa::mac_with_carry(r[j + i], k, T::MODULUS.0[j], &mut carry);
+        }
+        carry2 = fa::adc(&mut r.b1[i], carry, carry2);
+    }
+    carry2 != 0
+}
+
 pub const fn sqrt_precomputation<const N: usize, T: MontConfig<N>>(
 ) -> Option<SqrtPrecomputation<Fp<MontBackend<T, N>, N>>> {
     match T::MODULUS.mod_4() {
@@ -558,17 +759,50 @@ pub const fn sqrt_precomputation<const N: usize, T: MontConfig<N>>(
     }
 }
 
+/// Returns the precomputation for computing square roots with Cipolla's
+/// algorithm, which can be used to override the default
+/// [`MontConfig::SQRT_PRECOMP`] for fields with a large two-adicity.
+///
+/// # Usage
+/// ```rust
+/// # use ark_ff::{BigInt, fields::{cipolla_sqrt_precomputation, Fp64, MontBackend, MontConfig}, Field, MontFp, SqrtPrecomputation};
+/// pub struct FConfig;
+///
+/// impl MontConfig<1> for FConfig {
+///     // 2^64 - 2^32 + 1, which has two-adicity 32.
+///     const MODULUS: BigInt<1> = BigInt!("18446744069414584321");
+///     const GENERATOR: F = MontFp!("7");
+///     const TWO_ADIC_ROOT_OF_UNITY: F = MontFp!("1753635133440165772");
+///     const SQRT_PRECOMP: Option<SqrtPrecomputation<F>> =
+///         cipolla_sqrt_precomputation::<1, Self>();
+/// }
+/// pub type F = Fp64<MontBackend<FConfig, 1>>;
+///
+/// let four = F::from(4u64);
+/// assert_eq!(four.sqrt().unwrap().square(), four);
+/// ```
+pub const fn cipolla_sqrt_precomputation<const N: usize, T: MontConfig<N>>(
+) -> Option<SqrtPrecomputation<Fp<MontBackend<T, N>, N>>> {
+    Some(SqrtPrecomputation::Cipolla {
+        modulus_plus_one_div_two: &T::MODULUS_PLUS_ONE_DIV_TWO.0,
+    })
+}
+
 /// Construct a [`Fp<MontBackend<T, N>, N>`] element from a literal string. This
 /// should be used primarily for constructing constant field elements; in a
 /// non-const context, [`Fp::from_str`](`ark_std::str::FromStr::from_str`) is
 /// preferable.
 ///
+/// The string may contain an integer of any size, which is reduced modulo the
+/// field modulus, written in decimal or in hexadecimal, octal or binary with a
+/// `0x`, `0o` or `0b` prefix. It may also contain a constant expression
+/// combining such integers with `+`, `-`, `*`, `^` (exponentiation) and
+/// parentheses, which is evaluated over the integers at compile time.
+///
 /// # Panics
 ///
-/// If the integer represented by the string cannot fit in the number
-/// of limbs of the `Fp`, this macro results in a
-/// * compile-time error if used in a const context
-/// * run-time error otherwise.
+/// If the string is not a valid expression, this macro results in a
+/// compile-time error.
 ///
 /// # Usage
 ///
@@ -579,11 +813,15 @@ pub const fn sqrt_precomputation<const N: usize, T: MontConfig<N>>(
 /// use ark_bls12_381::Fq;
 /// const ONE: Fq = MontFp!("1");
 /// const NEG_ONE: Fq = MontFp!("-1");
+/// const SIXTEEN: Fq = MontFp!("0x10");
+/// const MERSENNE: Fq = MontFp!("2^127 - 1");
 ///
 /// fn check_correctness() {
 ///     assert_eq!(ONE, Fq::one());
 ///     assert_eq!(Fq::from_str("1").unwrap(), ONE);
 ///     assert_eq!(NEG_ONE, -Fq::one());
+///     assert_eq!(SIXTEEN, Fq::from(16u8));
+///     assert_eq!(MERSENNE, Fq::from(u128::MAX >> 1));
 /// }
 /// ```
 #[macro_export]
@@ -600,6 +838,61 @@ pub use MontFp;
 
 pub struct MontBackend<T: MontConfig<N>, const N: usize>(PhantomData<T>);
 
+impl<T: MontConfig<N>, const N: usize> MontBackend<T, N> {
+    /// The Montgomery radix `R = 2^(64 * N) mod MODULUS`. A field element `a`
+    /// is stored as `a * R mod MODULUS`.
+    pub const R: BigInt<N> = T::R;
+
+    /// `R^2 mod MODULUS`. Reducing `x * R2` puts an integer `x` into
+    /// Montgomery form.
+    pub const R2: BigInt<N> = T::R2;
+
+    /// `-MODULUS^-1 mod 2^64`, which is used in every step of a Montgomery
+    /// reduction.
+    pub const INV: u64 = T::INV;
+
+    /// Computes the Montgomery reduction of the `2N`-limb integer
+    /// `t = lo + hi * 2^(64 * N)`, i.e. returns the field element whose
+    /// Montgomery representation is `t * R^-1 mod MODULUS`.
+    ///
+    /// This is the second half of a Montgomery multiplication: for field
+    /// elements `a` and `b`, `mont_reduce(a.0.mul(&b.0))` is `a * b`. Code
+    /// that fuses several operations can thus add up products as
+    /// double-width integers, and reduce only once.
+    ///
+    /// The result is fully reduced if `t < MODULUS * R`, which holds for a
+    /// single product of field elements, and for sums of several products
+    /// when the modulus is sufficiently smaller than `R`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ark_ff::{BigInt, BigInteger, MontBackend};
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_test_curves::bls12_381::{Fq, FqConfig};
+    /// let mut rng = test_rng();
+    /// let (a, b, c) = (Fq::rand(&mut rng), Fq::rand(&mut rng), Fq::rand(&mut rng));
+    /// // A 381-bit modulus leaves room for a few unreduced products.
+    /// let (mut lo, mut hi) = a.0.mul(&b.0);
+    /// let (lo2, hi2) = c.0.mul(&c.0);
+    /// let carry = lo.add_with_carry(&lo2);
+    /// hi.add_with_carry(&hi2);
+    /// hi.add_with_carry(&BigInt::from(carry as u64));
+    /// assert_eq!(MontBackend::<FqConfig, 6>::mont_reduce((lo, hi)), a * b + c * c);
+    /// ```
+    #[inline]
+    pub fn mont_reduce((lo, hi): (BigInt<N>, BigInt<N>)) -> Fp<Self, N> {
+        let mut r = MulBuffer { b0: lo.0, b1: hi.0 };
+        let carry = montgomery_reduce::<T, N>(&mut r);
+        let mut a = Fp::new_unchecked(BigInt(r.b1));
+        if T::MODULUS_HAS_SPARE_BIT {
+            a.subtract_modulus();
+        } else {
+            a.subtract_modulus_with_carry(carry);
+        }
+        a
+    }
+}
+
 impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
     /// The modulus of the field.
     const MODULUS: crate::BigInt<N> = T::MODULUS;
@@ -619,6 +912,8 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
 
     const TWO_ADICITY: u32 = Self::MODULUS.two_adic_valuation();
     const TWO_ADIC_ROOT_OF_UNITY: Fp<Self, N> = T::TWO_ADIC_ROOT_OF_UNITY;
+    const TWO_ADIC_ROOTS_OF_UNITY: &'static [Fp<Self, N>] = T::TWO_ADIC_ROOTS_OF_UNITY;
+    const TWO_ADIC_ROOTS_OF_UNITY_INV: &'static [Fp<Self, N>] = T::TWO_ADIC_ROOTS_OF_UNITY_INV;
     const SMALL_SUBGROUP_BASE: Option<u32> = T::SMALL_SUBGROUP_BASE;
     const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = T::SMALL_SUBGROUP_BASE_ADICITY;
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Fp<Self, N>> = T::LARGE_SUBGROUP_ROOT_OF_UNITY;
@@ -655,6 +950,16 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
         T::sum_of_products(a, b)
     }
 
+    #[inline]
+    fn mul_batch(a: &mut [Fp<Self, N>], b: &[Fp<Self, N>]) {
+        T::mul_batch(a, b)
+    }
+
+    #[inline]
+    fn dot_product(a: &[Fp<Self, N>], b: &[Fp<Self, N>]) -> Fp<Self, N> {
+        T::dot_product(a, b)
+    }
+
     #[inline]
     #[allow(unused_braces, clippy::absurd_extreme_comparisons)]
     fn square_in_place(a: &mut Fp<Self, N>) {
@@ -674,6 +979,16 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
     fn into_bigint(a: Fp<Self, N>) -> BigInt<N> {
         T::into_bigint(a)
     }
+
+    #[inline]
+    fn into_bigint_batch(a: &[Fp<Self, N>], out: &mut [BigInt<N>]) {
+        T::into_bigint_batch(a, out)
+    }
+
+    #[inline]
+    fn from_bigint_batch(a: &[BigInt<N>], out: &mut [Fp<Self, N>]) -> bool {
+        T::from_bigint_batch(a, out)
+    }
 }
 
 impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
@@ -692,7 +1007,7 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         if r.const_is_zero() {
             r
         } else {
-            r = r.mul(&Fp(T::R2, PhantomData));
+            r = r.const_mul(&Fp(T::R2, PhantomData));
             r
         }
     }
@@ -713,8 +1028,9 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         self.0.const_is_zero()
     }
 
-    #[doc(hidden)]
-    const fn const_neg(self) -> Self {
+    /// Returns `-self`. Unlike [`Neg`](ark_std::ops::Neg), this can be used in
+    /// `const` contexts.
+    pub const fn const_neg(self) -> Self {
         if !self.const_is_zero() {
             Self::new_unchecked(Self::sub_with_borrow(&T::MODULUS, &self.0))
         } else {
@@ -722,17 +1038,120 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         }
     }
 
+    /// Returns `self + other`. Unlike [`Add`](ark_std::ops::Add), this can be
+    /// used in `const` contexts.
+    pub const fn const_add(self, other: &Self) -> Self {
+        let (sum, carry) = self.0.const_add_with_carry(&other.0);
+        Self::new_unchecked(sum).const_subtract_modulus_with_carry(carry)
+    }
+
+    /// Returns `self - other`. Unlike [`Sub`](ark_std::ops::Sub), this can be
+    /// used in `const` contexts.
+    pub const fn const_sub(self, other: &Self) -> Self {
+        let (diff, borrow) = self.0.const_sub_with_borrow(&other.0);
+        if borrow {
+            Self::new_unchecked(diff.const_add_with_carry(&T::MODULUS).0)
+        } else {
+            Self::new_unchecked(diff)
+        }
+    }
+
+    /// Returns `self * other`. Unlike [`Mul`](ark_std::ops::Mul), this can be
+    /// used in `const` contexts.
+    pub const fn const_mul(self, other: &Self) -> Self {
+        let (carry, res) = self.mul_without_cond_subtract(other);
+        if T::MODULUS_HAS_SPARE_BIT {
+            res.const_subtract_modulus()
+        } else {
+            res.const_subtract_modulus_with_carry(carry)
+        }
+    }
+
+    /// Returns `self^exp`, where `exp` is an integer represented with `u64`
+    /// limbs, least significant limb first. Unlike [`Field::pow`], this can be
+    /// used in `const` contexts.
+    ///
+    /// [`Field::pow`]: crate::Field::pow
+    pub const fn const_pow(self, exp: &[u64]) -> Self {
+        let mut res = Self::new_unchecked(T::R);
+        let mut i = 64 * exp.len();
+        while i > 0 {
+            i -= 1;
+            res = res.const_mul(&res);
+            if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                res = res.const_mul(&self);
+            }
+        }
+        res
+    }
+
+    /// Returns `self^-1`, computed as `self^(MODULUS - 2)`. Unlike
+    /// [`Field::inverse`], this can be used in `const` contexts, e.g. to
+    /// derive constants from other constants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, which results in a compile-time error in a
+    /// `const` context.
+    ///
+    /// # Example
+    /// ```
+    /// # use ark_ff::{Field, MontFp};
+    /// # use ark_test_curves::bls12_381::Fr;
+    /// const COFACTOR: &[u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+    /// const COFACTOR_INV: Fr = Fr::from_limbs_mod_order(COFACTOR).const_inverse();
+    /// assert_eq!(
+    ///     COFACTOR_INV,
+    ///     MontFp!("52435875175126190458656871551744051925719901746859129887267498875565241663483")
+    /// );
+    /// ```
+    ///
+    /// [`Field::inverse`]: crate::Field::inverse
+    pub const fn const_inverse(self) -> Self {
+        assert!(!self.const_is_zero(), "cannot invert zero");
+        let mut two = BigInt::<N>([0; N]);
+        two.0[0] = 2;
+        self.const_pow(&Self::sub_with_borrow(&T::MODULUS, &two).0)
+    }
+
+    /// Returns the canonical representation of `self`, by performing a
+    /// Montgomery reduction. Unlike [`PrimeField::into_bigint`], this can be
+    /// used in `const` contexts.
+    ///
+    /// [`PrimeField::into_bigint`]: crate::PrimeField::into_bigint
+    pub const fn const_into_bigint(self) -> BigInt<N> {
+        let mut one = BigInt::<N>([0; N]);
+        one.0[0] = 1;
+        self.const_mul(&Self::new_unchecked(one)).0
+    }
+
+    /// Interprets an integer of any size, represented with `u64` limbs, least
+    /// significant limb first, as a field element by reducing it modulo
+    /// `MODULUS`. This can be used in `const` contexts.
+    pub const fn from_limbs_mod_order(limbs: &[u64]) -> Self {
+        Self::from_sign_and_limbs(true, limbs)
+    }
+
     /// Interpret a set of limbs (along with a sign) as a field element.
     /// For *internal* use only; please use the `ark_ff::MontFp` macro instead
     /// of this method
     #[doc(hidden)]
     pub const fn from_sign_and_limbs(is_positive: bool, limbs: &[u64]) -> Self {
-        let mut repr = BigInt::<N>([0; N]);
-        assert!(limbs.len() <= N);
-        crate::const_for!((i in 0..(limbs.len())) {
-            repr.0[i] = limbs[i];
+        // The limbs are reduced in chunks of `N`, from the most significant
+        // chunk downwards, as `res = res * 2^(64 * N) + chunk`.
+        let r = Self::new(T::R);
+        let num_chunks = limbs.len().div_ceil(N);
+        let mut res = Self::new_unchecked(BigInt([0; N]));
+        crate::const_for!((c in 0..num_chunks) {
+            let start = (num_chunks - 1 - c) * N;
+            let mut repr = BigInt::<N>([0; N]);
+            crate::const_for!((i in 0..N) {
+                if start + i < limbs.len() {
+                    repr.0[i] = limbs[start + i];
+                }
+            });
+            res = res.const_mul(&r).const_add(&Self::new(repr));
         });
-        let res = Self::new(repr);
         if is_positive {
             res
         } else {
@@ -777,15 +1196,6 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         (carry2 != 0, self)
     }
 
-    const fn mul(self, other: &Self) -> Self {
-        let (carry, res) = self.mul_without_cond_subtract(other);
-        if T::MODULUS_HAS_SPARE_BIT {
-            res.const_subtract_modulus()
-        } else {
-            res.const_subtract_modulus_with_carry(carry)
-        }
-    }
-
     const fn const_is_valid(&self) -> bool {
         crate::const_for!((i in 0..N) {
             if (self.0).0[N - i - 1] < T::MODULUS.0[N - i - 1] {
@@ -820,7 +1230,7 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
 
 #[cfg(test)]
 mod test {
-    use ark_std::{str::FromStr, vec::*};
+    use ark_std::{str::FromStr, string::ToString, vec::*};
     use ark_test_curves::secp256k1::Fr;
     use num_bigint::{BigInt, BigUint, Sign};
 
@@ -840,6 +1250,125 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_mont_macro_expressions() {
+        use ark_test_curves::{ark_ff::PrimeField, MontFp};
+
+        let modulus = BigUint::from(Fr::MODULUS);
+        let wide = (BigUint::from(1u8) << 512) + 5u8;
+        let expected = Fr::from(wide % &modulus);
+        assert_eq!(MontFp!("2^512 + 5"), expected);
+        assert_eq!(MontFp!("-(2^512 + 5)"), -expected);
+        assert_eq!(MontFp!("0x1f * (2 - 3)"), -Fr::from(31u8));
+
+        let modulus_str = modulus.to_string();
+        let (is_positive, limbs) = str_to_limbs_u64(&modulus_str);
+        assert_eq!(Fr::from_sign_and_limbs(is_positive, &limbs), Fr::from(0u8));
+    }
+
+    #[test]
+    fn test_const_arithmetic() {
+        use ark_std::{test_rng, UniformRand};
+        use ark_test_curves::ark_ff::{Field, PrimeField};
+
+        let rng = &mut test_rng();
+        for _ in 0..100 {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            assert_eq!(a.const_add(&b), a + b);
+            assert_eq!(a.const_sub(&b), a - b);
+            assert_eq!(a.const_mul(&b), a * b);
+            assert_eq!(a.const_neg(), -a);
+            assert_eq!(a.const_pow(&[5, 7]), a.pow([5, 7]));
+            assert_eq!(a.const_into_bigint(), a.into_bigint());
+            if let Some(inverse) = a.inverse() {
+                assert_eq!(a.const_inverse(), inverse);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mont_reduce() {
+        use ark_std::{test_rng, UniformRand};
+        use ark_test_curves::{
+            ark_ff::{BigInt as ArkBigInt, BigInteger, MontBackend, One},
+            bls12_381, secp256k1,
+        };
+
+        type FrBackend = MontBackend<secp256k1::FrConfig, 4>;
+        type FqBackend = MontBackend<bls12_381::FqConfig, 6>;
+        let rng = &mut test_rng();
+        for _ in 0..100 {
+            // The modulus of `Fr` has no spare bit.
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            assert_eq!(FrBackend::mont_reduce(a.0.mul(&b.0)), a * b);
+            let (a, b) = (bls12_381::Fq::rand(rng), bls12_381::Fq::rand(rng));
+            assert_eq!(FqBackend::mont_reduce(a.0.mul(&b.0)), a * b);
+        }
+        // `R2 * R^-1` is the Montgomery representation of one, and `R * R^-1`
+        // is the integer one.
+        let (zero, one) = (ArkBigInt::zero(), ArkBigInt::one());
+        assert!(FrBackend::mont_reduce((FrBackend::R2, zero)).is_one());
+        assert_eq!(FrBackend::mont_reduce((FrBackend::R, zero)).0, one);
+    }
+
+    type F<T, const N: usize> = crate::Fp<super::MontBackend<T, N>, N>;
+
+    /// Defines a `MontConfig` for a prime modulus that is `3 (mod 4)`, so that
+    /// its two-adic root of unity is `-1`.
+    macro_rules! spilled_config {
+        ($name:ident, $n:literal, $modulus:literal, $generator:literal) => {
+            struct $name;
+
+            impl super::MontConfig<$n> for $name {
+                const MODULUS: crate::BigInt<$n> = crate::BigInt!($modulus);
+                const GENERATOR: F<Self, $n> = crate::MontFp!($generator);
+                const TWO_ADIC_ROOT_OF_UNITY: F<Self, $n> = crate::MontFp!("-1");
+            }
+        };
+    }
+
+    // Moduli of 7, 8 and 12 limbs, for which the x86-64 `asm` backend keeps part
+    // of the accumulator in a spill buffer.
+    spilled_config!(Config7, 7, "157614138816658772563292670273832469479774383800222707776467747338487680239840196116238555705551187564885746267776466105853828179288671", "13");
+    spilled_config!(Config8, 8, "2190412969740968034158895695643550721859337962500057626645311752246174982993133924643775037697915722388680646271847478811758557081873885236957936884021767", "3");
+    spilled_config!(Config12, 12, "379783499194131842261211884610159439145609606569314964349171093319491497163054520738907010993769614042071392845430794193633213308107133651713401879686680021588025328797793891470445763679595788359835046712026208363170772283775041443", "2");
+
+    /// Checks `mul_assign` and `square_in_place`, which use the assembly
+    /// backend when it is enabled, against multiplication of `BigUint`s.
+    fn check_mul_and_square<T: super::MontConfig<N>, const N: usize>() {
+        use ark_std::{test_rng, One, UniformRand, Zero};
+
+        // The assembly is only used if this optimization applies.
+        assert!(T::CAN_USE_NO_CARRY_MUL_OPT && T::CAN_USE_NO_CARRY_SQUARE_OPT);
+
+        let modulus = BigUint::from(T::MODULUS);
+        let rng = &mut test_rng();
+        let edge_cases = [F::<T, N>::zero(), F::one(), -F::one()];
+        let random = (0..1000).map(|_| F::<T, N>::rand(rng)).collect::<Vec<_>>();
+        for (a, b) in edge_cases
+            .iter()
+            .flat_map(|a| edge_cases.iter().map(move |b| (*a, *b)))
+            .chain(random.iter().copied().zip(random.iter().rev().copied()))
+        {
+            let (a_int, b_int) = (BigUint::from(a), BigUint::from(b));
+
+            let mut product = a;
+            T::mul_assign(&mut product, &b);
+            assert_eq!(BigUint::from(product), &a_int * &b_int % &modulus);
+
+            let mut square = a;
+            T::square_in_place(&mut square);
+            assert_eq!(BigUint::from(square), &a_int * &a_int % &modulus);
+        }
+    }
+
+    #[test]
+    fn test_spilled_mul_and_square() {
+        check_mul_and_square::<Config7, 7>();
+        check_mul_and_square::<Config8, 8>();
+        check_mul_and_square::<Config12, 12>();
+    }
+
     fn str_to_limbs_u64(num: &str) -> (bool, Vec<u64>) {
         let (sign, digits) = BigInt::from_str(num)
             .expect("could not parse to bigint")
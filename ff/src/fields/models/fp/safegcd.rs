@@ -0,0 +1,256 @@
+//! Inversion in prime fields with the safegcd algorithm of Bernstein and
+//! Yang, as described in [Fast constant-time gcd computation and modular
+//! inversion](https://eprint.iacr.org/2019/266), using the batching of
+//! divsteps of [libsecp256k1](https://github.com/bitcoin-core/secp256k1/blob/master/doc/safegcd_implementation.md).
+//!
+//! The number of iterations only depends on the bit size of the modulus, and
+//! each iteration is branch-free, so the running time does not depend on the
+//! element being inverted, apart from whether it is zero.
+use super::{Fp, MontBackend, MontConfig};
+use crate::{BigInt, Zero};
+use ark_std::marker::PhantomData;
+
+/// The number of divsteps that are batched together.
+const BATCH: usize = 62;
+
+struct Constants<T: MontConfig<N>, const N: usize>(PhantomData<T>);
+
+impl<T: MontConfig<N>, const N: usize> Constants<T, N> {
+    /// The number of batches of divsteps that suffice to reach `g = 0` for
+    /// any input, from the bound of Theorem 11.2 of Bernstein and Yang.
+    const ITERATIONS: usize = {
+        let bits = T::MODULUS.const_num_bits() as usize;
+        ((49 * bits + 80) / 17 + BATCH) / BATCH
+    };
+
+    /// Whether the modulus is smaller than 2^63, in which case the entries
+    /// of the transition matrices have to be reduced before being used as
+    /// field elements.
+    const SMALL_MODULUS: bool = N == 1 && T::MODULUS.0[0] >> 63 == 0;
+
+    /// `(R / 2^62)^ITERATIONS`, which removes both the factor of `2^-62`
+    /// introduced by each batch of divsteps, and the factor of `R^-1`
+    /// introduced by each multiplication by a transition matrix.
+    const SCALE: Fp<MontBackend<T, N>, N> = {
+        let half = Fp::<MontBackend<T, N>, N>::new(T::MODULUS_PLUS_ONE_DIV_TWO);
+        Fp::<MontBackend<T, N>, N>::new_unchecked(T::R2)
+            .const_mul(&half.const_pow(&[BATCH as u64]))
+            .const_pow(&[Self::ITERATIONS as u64])
+    };
+
+    const NEG_SCALE: Fp<MontBackend<T, N>, N> = Self::SCALE.const_neg();
+}
+
+/// A signed integer `lo + hi * 2^(64 * N)` in two's complement.
+#[derive(Clone, Copy)]
+struct Signed<const N: usize> {
+    lo: [u64; N],
+    hi: i64,
+}
+
+impl<const N: usize> Signed<N> {
+    /// Returns `(u * a + v * b) / 2^62`, where the division must be exact.
+    #[inline]
+    fn linear_combination(u: i64, a: &Self, v: i64, b: &Self) -> Self {
+        let (u, v) = (i128::from(u), i128::from(v));
+        let mut t = [0u64; N];
+        let mut acc = 0i128;
+        for i in 0..N {
+            acc += u * i128::from(a.lo[i]) + v * i128::from(b.lo[i]);
+            t[i] = acc as u64;
+            acc >>= 64;
+        }
+        acc += u * i128::from(a.hi) + v * i128::from(b.hi);
+        let top = acc as i64;
+
+        let mut lo = [0u64; N];
+        for i in 0..N - 1 {
+            lo[i] = (t[i] >> BATCH) | (t[i + 1] << (64 - BATCH));
+        }
+        lo[N - 1] = (t[N - 1] >> BATCH) | ((top as u64) << (64 - BATCH));
+        Self {
+            lo,
+            hi: top >> BATCH,
+        }
+    }
+}
+
+/// Performs 62 divsteps on the low 64 bits of `f` and `g`, and returns the
+/// transition matrix `[u, v, q, r]`, scaled by `2^62`, such that
+/// `(u * f + v * g, q * f + r * g) / 2^62` are the values of `f` and `g`
+/// after these divsteps. `|u| + |v|` and `|q| + |r|` are at most `2^62`.
+#[inline]
+fn divsteps(delta: &mut i64, mut f: u64, mut g: u64) -> [i64; 4] {
+    let (mut u, mut v, mut q, mut r) = (1i64, 0i64, 0i64, 1i64);
+    for _ in 0..BATCH {
+        // c1 is -1 if delta > 0, and c2 is -1 if g is odd.
+        let c1 = delta.wrapping_neg() >> 63;
+        let c2 = -((g & 1) as i64);
+        // Conditionally negate f, u and v.
+        let x = (f ^ c1 as u64).wrapping_sub(c1 as u64);
+        let y = (u ^ c1) - c1;
+        let z = (v ^ c1) - c1;
+        // Conditionally add the negated values to g, q and r.
+        g = g.wrapping_add(x & c2 as u64);
+        q += y & c2;
+        r += z & c2;
+        // If delta > 0 and g was odd, swap: negate delta and add the new
+        // values of g, q and r to f, u and v.
+        let c1 = c1 & c2;
+        *delta = ((*delta ^ c1) - c1) + 1;
+        f = f.wrapping_add(g & c1 as u64);
+        u += q & c1;
+        v += r & c1;
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+    [u, v, q, r]
+}
+
+/// Returns the field element whose Montgomery representation is `x` modulo
+/// the modulus, so that multiplying by it multiplies by `x * R^-1`.
+#[inline]
+fn from_small<T: MontConfig<N>, const N: usize>(x: i64) -> Fp<MontBackend<T, N>, N> {
+    let mut res = BigInt::<N>::zero();
+    if Constants::<T, N>::SMALL_MODULUS {
+        res.0[0] = x.rem_euclid(T::MODULUS.0[0] as i64) as u64;
+    } else {
+        // Since |x| <= 2^62 < MODULUS, the result is either |x| or
+        // MODULUS - |x|.
+        let sign = (x >> 63) as u64;
+        let abs = (x as u64 ^ sign).wrapping_sub(sign);
+        let mut borrow = abs;
+        for i in 0..N {
+            let (neg, b1) = T::MODULUS.0[i].overflowing_sub(borrow);
+            let pos = if i == 0 { abs } else { 0 };
+            res.0[i] = (pos & !sign) | (neg & sign);
+            borrow = b1 as u64;
+        }
+    }
+    Fp::new_unchecked(res)
+}
+
+/// Computes the inverse of `a`, or returns `None` if `a` is zero.
+pub(super) fn inverse<T: MontConfig<N>, const N: usize>(
+    a: &Fp<MontBackend<T, N>, N>,
+) -> Option<Fp<MontBackend<T, N>, N>> {
+    if a.is_zero() {
+        return None;
+    }
+    // We invert the Montgomery representation `a * R` of `a` as an integer,
+    // maintaining `d * (a * R) = f * 2^(62 * j) * R^(1 - j)` and
+    // `e * (a * R) = g * 2^(62 * j) * R^(1 - j)` modulo the modulus after `j`
+    // batches of divsteps. Eventually `g = 0` and `f = ±1`.
+    let mut delta = 1i64;
+    let mut f = Signed {
+        lo: T::MODULUS.0,
+        hi: 0,
+    };
+    let mut g = Signed { lo: (a.0).0, hi: 0 };
+    let mut d = Fp::<MontBackend<T, N>, N>::zero();
+    let mut e = Fp::new_unchecked(T::R2);
+    for _ in 0..Constants::<T, N>::ITERATIONS {
+        let [u, v, q, r] = divsteps(&mut delta, f.lo[0], g.lo[0]);
+        (f, g) = (
+            Signed::linear_combination(u, &f, v, &g),
+            Signed::linear_combination(q, &f, r, &g),
+        );
+        let [u, v, q, r] = [u, v, q, r].map(from_small::<T, N>);
+        (d, e) = (d * u + e * v, d * q + e * r);
+    }
+    // Select the scaling factor according to the sign of `f`.
+    let sign = f.hi as u64;
+    let mut scale = Constants::<T, N>::SCALE;
+    let neg_scale = Constants::<T, N>::NEG_SCALE;
+    for (s, n) in (scale.0).0.iter_mut().zip((neg_scale.0).0) {
+        *s = (*s & !sign) | (n & sign);
+    }
+    Some(d * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fields::{Fp, InversionStrategy, MontBackend, MontConfig},
+        BigInt, BigInteger, Field, One, PrimeField, UniformRand, Zero,
+    };
+    use ark_std::test_rng;
+
+    macro_rules! safegcd_config {
+        ($name:ident, $n:literal, [$($limb:expr),*], $generator:expr) => {
+            struct $name;
+
+            impl MontConfig<$n> for $name {
+                const MODULUS: BigInt<$n> = BigInt::new([$($limb),*]);
+
+                const GENERATOR: Fp<MontBackend<Self, $n>, $n> = {
+                    let mut g = BigInt::<$n>::zero();
+                    g.0[0] = $generator;
+                    Fp::<MontBackend<Self, $n>, $n>::new(g)
+                };
+
+                const TWO_ADIC_ROOT_OF_UNITY: Fp<MontBackend<Self, $n>, $n> =
+                    Self::GENERATOR.const_pow(
+                        &<Fp<MontBackend<Self, $n>, $n> as PrimeField>::TRACE.0,
+                    );
+
+                const INVERSION_STRATEGY: InversionStrategy = InversionStrategy::BernsteinYang;
+            }
+        };
+    }
+
+    // The scalar field of BLS12-381.
+    safegcd_config!(
+        Bls12Config,
+        4,
+        [
+            0xffffffff00000001,
+            0x53bda402fffe5bfe,
+            0x3339d80809a1d805,
+            0x73eda753299d7d48
+        ],
+        7
+    );
+    // The base field of secp256k1, whose modulus has no spare bit.
+    safegcd_config!(
+        Secp256k1Config,
+        4,
+        [
+            0xfffffffefffffc2f,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff
+        ],
+        3
+    );
+    safegcd_config!(LargeSingleLimbConfig, 1, [0xffffffffffffffc5], 2);
+    safegcd_config!(SmallConfig, 1, [65537], 3);
+
+    fn check_inverse<F: PrimeField>() {
+        let rng = &mut test_rng();
+        assert!(F::zero().inverse().is_none());
+        assert_eq!(F::one().inverse(), Some(F::one()));
+        assert_eq!((-F::one()).inverse(), Some(-F::one()));
+        let max = F::from_bigint(F::MODULUS_MINUS_ONE_DIV_TWO).unwrap();
+        for a in (0..1000).map(|_| F::rand(rng)).chain([max, F::from(2u64)]) {
+            if a.is_zero() {
+                continue;
+            }
+            let inv = a.inverse().unwrap();
+            assert_eq!(a * inv, F::one());
+            // Compare with Fermat's little theorem.
+            let mut exp = F::MODULUS;
+            exp.sub_with_borrow(&F::BigInt::from(2u64));
+            assert_eq!(inv, a.pow(exp));
+        }
+    }
+
+    #[test]
+    fn test_safegcd_inverse() {
+        check_inverse::<Fp<MontBackend<Bls12Config, 4>, 4>>();
+        check_inverse::<Fp<MontBackend<Secp256k1Config, 4>, 4>>();
+        check_inverse::<Fp<MontBackend<LargeSingleLimbConfig, 1>, 1>>();
+        check_inverse::<Fp<MontBackend<SmallConfig, 1>, 1>>();
+    }
+}
@@ -1,11 +1,13 @@
 use super::quadratic_extension::{QuadExtConfig, QuadExtField};
 use crate::{
     fields::{
+        batch_inversion,
         fp6_3over2::{Fp6, Fp6Config},
-        Field, Fp2, Fp2Config as Fp2ConfigTrait,
+        Field, Fp2, Fp2Config as Fp2ConfigTrait, TowerMulStrategy,
     },
-    AdditiveGroup, CyclotomicMultSubgroup, Zero,
+    AdditiveGroup, BitIteratorLE, CyclotomicMultSubgroup, Zero,
 };
+use ark_std::vec::Vec;
 use core::{
     marker::PhantomData,
     ops::{AddAssign, Not, SubAssign},
@@ -13,6 +15,8 @@ use core::{
 
 type Fp2Config<P> = <<P as Fp12Config>::Fp6Config as Fp6Config>::Fp2Config;
 
+pub use ark_ff_macros::Fp12Config;
+
 pub trait Fp12Config: 'static + Send + Sync + Copy {
     type Fp6Config: Fp6Config;
 
@@ -20,6 +24,11 @@ pub trait Fp12Config: 'static + Send + Sync + Copy {
     /// see [[DESD06, Section 6.1]](https://eprint.iacr.org/2006/471.pdf).
     const NONRESIDUE: Fp6<Self::Fp6Config>;
 
+    /// The algorithm used for multiplication in `Fp12` in terms of
+    /// multiplications in `Fp6`. The algorithm used in `Fp6` itself is
+    /// chosen by [`Fp6Config::MUL_STRATEGY`].
+    const MUL_STRATEGY: TowerMulStrategy = TowerMulStrategy::Karatsuba;
+
     /// Coefficients for the Frobenius automorphism.
     const FROBENIUS_COEFF_FP12_C1: &'static [Fp2<Fp2Config<Self>>];
 
@@ -47,6 +56,8 @@ impl<P: Fp12Config> QuadExtConfig for Fp12ConfigWrapper<P> {
 
     const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
 
+    const MUL_STRATEGY: TowerMulStrategy = P::MUL_STRATEGY;
+
     const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP12_C1;
 
     #[inline(always)]
@@ -110,6 +121,117 @@ impl<P: Fp12Config> Fp12<P> {
         P::mul_fp6_by_nonresidue_in_place(&mut self.c0);
         self.c0.add_assign(&aa);
     }
+
+    /// Squares `self`, an element of the cyclotomic subgroup, in the
+    /// compressed representation of
+    /// [\[Kar10\]](https://eprint.iacr.org/2010/542).
+    ///
+    /// Only `c1.c0`, `c0.c2`, `c0.c1` and `c1.c2` are read and updated, which
+    /// are `g2`, `g3`, `g4` and `g5` in the notation of \[Kar10\]. The other
+    /// two coefficients are left stale, and must be recovered with
+    /// [`Self::batch_decompress_cyclotomic`] before `self` is used otherwise.
+    /// This is cheaper than
+    /// [`CyclotomicMultSubgroup::cyclotomic_square_in_place`], but
+    /// decompression costs an inversion, so it only pays off for long runs of
+    /// squarings.
+    ///
+    /// Like [`CyclotomicMultSubgroup::cyclotomic_square_in_place`], this
+    /// requires the square of the characteristic to be 1 modulo 6.
+    pub fn compressed_cyclotomic_square_in_place(&mut self) -> &mut Self {
+        let fp2_nr = <P::Fp6Config as Fp6Config>::mul_fp2_by_nonresidue;
+
+        let g2 = self.c1.c0;
+        let g3 = self.c0.c2;
+        let g4 = self.c0.c1;
+        let g5 = self.c1.c2;
+
+        // h2 = 2 * (g2 + 3 * ξ * g4 * g5)
+        let tmp = fp2_nr(g4 * &g5);
+        let mut h2 = tmp.double() + &tmp + &g2;
+        h2.double_in_place();
+
+        // h3 = 3 * (g4^2 + ξ * g5^2) - 2 * g3
+        let tmp = g4.square() + &fp2_nr(g5.square());
+        let h3 = tmp.double() + &tmp - &g3.double();
+
+        // h4 = 3 * (g2^2 + ξ * g3^2) - 2 * g4
+        let tmp = g2.square() + &fp2_nr(g3.square());
+        let h4 = tmp.double() + &tmp - &g4.double();
+
+        // h5 = 2 * (g5 + 3 * g2 * g3)
+        let tmp = g2 * &g3;
+        let mut h5 = tmp.double() + &tmp + &g5;
+        h5.double_in_place();
+
+        self.c1.c0 = h2;
+        self.c0.c2 = h3;
+        self.c0.c1 = h4;
+        self.c1.c2 = h5;
+        self
+    }
+
+    /// Recovers the coefficients `c0.c0` and `c1.c1` of elements of the
+    /// cyclotomic subgroup that were squared with
+    /// [`Self::compressed_cyclotomic_square_in_place`], with a single
+    /// inversion for the whole batch.
+    pub fn batch_decompress_cyclotomic(elems: &mut [Self]) {
+        let fp2_nr = <P::Fp6Config as Fp6Config>::mul_fp2_by_nonresidue;
+
+        let (numerators, mut denominators): (Vec<_>, Vec<_>) = elems
+            .iter()
+            .map(|f| {
+                let (g2, g3, g4, g5) = (f.c1.c0, f.c0.c2, f.c0.c1, f.c1.c2);
+                if g2.is_zero() {
+                    // g1 = 2 * g4 * g5 / g3
+                    ((g4 * &g5).double(), g3)
+                } else {
+                    // g1 = (ξ * g5^2 + 3 * g4^2 - 2 * g3) / (4 * g2)
+                    let g4_square = g4.square();
+                    let numerator =
+                        fp2_nr(g5.square()) + &g4_square.double() + &g4_square - &g3.double();
+                    (numerator, g2.double().double())
+                }
+            })
+            .unzip();
+        // `g2 = g3 = 0` only holds for the identity, whose `g1` is zero, so
+        // the zero denominator can be left as is.
+        batch_inversion(&mut denominators);
+
+        for ((f, numerator), denominator_inv) in elems.iter_mut().zip(numerators).zip(denominators)
+        {
+            let g1 = numerator * &denominator_inv;
+            // g0 = ξ * (2 * g1^2 + g2 * g5 - 3 * g3 * g4) + 1
+            let g3g4 = f.c0.c2 * &f.c0.c1;
+            let tmp = g1.square().double() + &(f.c1.c0 * &f.c1.c2) - &g3g4.double() - &g3g4;
+            f.c0.c0 = fp2_nr(tmp) + &Fp2::<Fp2Config<P>>::ONE;
+            f.c1.c1 = g1;
+        }
+    }
+
+    /// Computes `self^exp` for `self` in the cyclotomic subgroup, with the
+    /// squarings of [`Self::compressed_cyclotomic_square_in_place`]. The
+    /// powers `self^(2^i)` for the set bits `i` of `exp` are decompressed
+    /// together with [`Self::batch_decompress_cyclotomic`] and multiplied.
+    ///
+    /// This is faster than [`CyclotomicMultSubgroup::cyclotomic_exp`] for
+    /// exponents with few set bits, such as the parameter `x` of BLS12-381.
+    pub fn cyclotomic_exp_compressed(&self, exp: impl AsRef<[u64]>) -> Self {
+        if !characteristic_square_mod_6_is_one(Self::characteristic()) {
+            return self.cyclotomic_exp(exp);
+        }
+        let mut powers = Vec::new();
+        let mut f = *self;
+        for (i, bit) in BitIteratorLE::without_trailing_zeros(exp).enumerate() {
+            if i > 0 {
+                f.compressed_cyclotomic_square_in_place();
+            }
+            if bit {
+                powers.push(f);
+            }
+        }
+        Self::batch_decompress_cyclotomic(&mut powers);
+        powers.iter().fold(Self::ONE, |acc, power| acc * power)
+    }
 }
 
 pub const fn characteristic_square_mod_6_is_one(characteristic: &[u64]) -> bool {
@@ -2,6 +2,8 @@ use super::quadratic_extension::{QuadExtConfig, QuadExtField};
 use crate::{fields::PrimeField, CyclotomicMultSubgroup, Zero};
 use core::{marker::PhantomData, ops::Not};
 
+pub use ark_ff_macros::Fp2Config;
+
 /// Trait that specifies constants and methods for defining degree-two extension fields.
 pub trait Fp2Config: 'static + Send + Sync + Sized {
     /// Base prime field underlying this extension.
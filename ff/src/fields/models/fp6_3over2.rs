@@ -1,14 +1,20 @@
-use super::cubic_extension::{CubicExtConfig, CubicExtField};
+use super::cubic_extension::{CubicExtConfig, CubicExtField, TowerMulStrategy};
 use crate::fields::{
     AddAssign, CyclotomicMultSubgroup, Fp2, Fp2Config, MulAssign, SqrtPrecomputation, SubAssign,
 };
 use core::marker::PhantomData;
 
+pub use ark_ff_macros::Fp6Config;
+
 pub trait Fp6Config: 'static + Send + Sync + Copy {
     type Fp2Config: Fp2Config;
 
     const NONRESIDUE: Fp2<Self::Fp2Config>;
 
+    /// The algorithm used for multiplication in `Fp6` in terms of
+    /// multiplications in `Fp2`.
+    const MUL_STRATEGY: TowerMulStrategy = TowerMulStrategy::Karatsuba;
+
     /// Determines the algorithm for computing square roots.
     const SQRT_PRECOMP: Option<SqrtPrecomputation<Fp6<Self>>> = None;
 
@@ -41,6 +47,8 @@ impl<P: Fp6Config> CubicExtConfig for Fp6ConfigWrapper<P> {
 
     const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
 
+    const MUL_STRATEGY: TowerMulStrategy = P::MUL_STRATEGY;
+
     const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP6_C1;
     const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP6_C2;
 
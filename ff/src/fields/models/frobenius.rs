@@ -0,0 +1,257 @@
+//! Compile-time computation of the Frobenius coefficients of [`Fp2Config`],
+//! [`Fp6Config`] and [`Fp12Config`] from their non-residues, for prime fields
+//! with a Montgomery backend.
+//!
+//! # Example
+//! ```
+//! # use ark_ff::{
+//! #     fields::{fp6_frobenius_coeffs_c1, fp6_frobenius_coeffs_c2, Fp6Config},
+//! #     MontFp,
+//! # };
+//! # use ark_test_curves::bls12_381::{Fq2, Fq2Config, Fq6Config};
+//! #[derive(Clone, Copy)]
+//! pub struct MyFq6Config;
+//!
+//! impl Fp6Config for MyFq6Config {
+//!     type Fp2Config = Fq2Config;
+//!
+//!     const NONRESIDUE: Fq2 = Fq2::new(MontFp!("1"), MontFp!("1"));
+//!
+//!     const FROBENIUS_COEFF_FP6_C1: &'static [Fq2] = &fp6_frobenius_coeffs_c1(Self::NONRESIDUE);
+//!     const FROBENIUS_COEFF_FP6_C2: &'static [Fq2] = &fp6_frobenius_coeffs_c2(Self::NONRESIDUE);
+//! }
+//!
+//! assert_eq!(MyFq6Config::FROBENIUS_COEFF_FP6_C1, Fq6Config::FROBENIUS_COEFF_FP6_C1);
+//! assert_eq!(MyFq6Config::FROBENIUS_COEFF_FP6_C2, Fq6Config::FROBENIUS_COEFF_FP6_C2);
+//! ```
+//!
+//! The [`Fp2Config`](macro@crate::fields::Fp2Config),
+//! [`Fp6Config`](macro@crate::fields::Fp6Config) and
+//! [`Fp12Config`](macro@crate::fields::Fp12Config) derive macros build on
+//! these functions to generate a whole tower from its non-residues:
+//! ```
+//! # use ark_ff::fields::{Field, Fp12, Fp12Config, Fp2Config, Fp6Config};
+//! # use ark_std::{test_rng, UniformRand};
+//! # use ark_test_curves::bls12_381::{Fq, Fq12};
+//! #[derive(Fp2Config)]
+//! #[fp = "Fq"]
+//! #[nonresidue = "-1"]
+//! pub struct MyFq2Config;
+//!
+//! #[derive(Clone, Copy, Fp6Config)]
+//! #[fp2_config = "MyFq2Config"]
+//! #[nonresidue_c0 = "1"]
+//! #[nonresidue_c1 = "1"]
+//! pub struct MyFq6Config;
+//!
+//! #[derive(Clone, Copy, Fp12Config)]
+//! #[fp6_config = "MyFq6Config"]
+//! pub struct MyFq12Config;
+//!
+//! // This is the same field as `Fq12`.
+//! let a = Fq12::rand(&mut test_rng());
+//! let b = Fp12::<MyFq12Config>::from_base_prime_field_elems(a.to_base_prime_field_elements())
+//!     .unwrap();
+//! assert!(b.square().to_base_prime_field_elements().eq(a.square().to_base_prime_field_elements()));
+//! assert!(b
+//!     .frobenius_map(1)
+//!     .to_base_prime_field_elements()
+//!     .eq(a.frobenius_map(1).to_base_prime_field_elements()));
+//! ```
+//!
+//! [`Fp6Config`]: crate::fields::Fp6Config
+//! [`Fp12Config`]: crate::fields::Fp12Config
+use crate::{
+    fields::{Fp, Fp2, Fp2Config, MontBackend, MontConfig},
+    BigInt,
+};
+
+/// Returns `(MODULUS - 1) / divisor`.
+///
+/// # Panics
+///
+/// Panics if `divisor` does not divide `MODULUS - 1`.
+const fn modulus_minus_one_div<T: MontConfig<N>, const N: usize>(divisor: u64) -> BigInt<N> {
+    // The modulus is odd, so subtracting one does not borrow.
+    let mut quotient = T::MODULUS;
+    quotient.0[0] -= 1;
+    let mut remainder = 0u128;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let current = (remainder << 64) | quotient.0[i] as u128;
+        quotient.0[i] = (current / divisor as u128) as u64;
+        remainder = current % divisor as u128;
+    }
+    assert!(
+        remainder == 0,
+        "the modulus minus one must be divisible by the degree of the extension"
+    );
+    quotient
+}
+
+/// Returns `a * b` in `Fp2<P>`.
+const fn fp2_mul<P, T, const N: usize>(a: &Fp2<P>, b: &Fp2<P>) -> Fp2<P>
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    let a1b1 = a.c1.const_mul(&b.c1);
+    Fp2::new(
+        a.c0.const_mul(&b.c0)
+            .const_add(&a1b1.const_mul(&P::NONRESIDUE)),
+        a.c0.const_mul(&b.c1).const_add(&a.c1.const_mul(&b.c0)),
+    )
+}
+
+/// Returns `a^exp` in `Fp2<P>`, where `exp` is represented with `u64` limbs,
+/// least significant limb first.
+const fn fp2_pow<P, T, const N: usize>(a: &Fp2<P>, exp: &[u64]) -> Fp2<P>
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    let mut res: Fp2<P> = fp2_one();
+    let mut i = 64 * exp.len();
+    while i > 0 {
+        i -= 1;
+        res = fp2_mul(&res, &res);
+        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+            res = fp2_mul(&res, a);
+        }
+    }
+    res
+}
+
+/// Returns one in `Fp2<P>`.
+const fn fp2_one<P, T, const N: usize>() -> Fp2<P>
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    Fp2::new(Fp::new_unchecked(T::R), Fp::new_unchecked(BigInt([0; N])))
+}
+
+/// Returns `[nonresidue^((p^i - 1) / divisor) for i in 0..K]`, where `p` is
+/// the characteristic.
+const fn frobenius_coeffs<P, T, const N: usize, const K: usize>(
+    nonresidue: &Fp2<P>,
+    divisor: u64,
+) -> [Fp2<P>; K]
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    // Since `(p^i - 1) / divisor = p * (p^(i - 1) - 1) / divisor + (p - 1) / divisor`,
+    // each coefficient is the Frobenius map, i.e. the conjugate, of the
+    // previous one, times the first one.
+    let first = fp2_pow(nonresidue, &modulus_minus_one_div::<T, N>(divisor).0);
+    let mut coeffs: [Fp2<P>; K] = [fp2_one(); K];
+    let mut i = 1;
+    while i < K {
+        let conjugate = Fp2::new(coeffs[i - 1].c0, coeffs[i - 1].c1.const_neg());
+        coeffs[i] = fp2_mul(&conjugate, &first);
+        i += 1;
+    }
+    coeffs
+}
+
+/// Returns the coefficients [`Fp2Config::FROBENIUS_COEFF_FP2_C1`] for the
+/// quadratic non-residue `nonresidue`, i.e.
+/// `nonresidue^((p^i - 1) / 2)` for `i` in `0..2`.
+pub const fn fp2_frobenius_coeffs<T: MontConfig<N>, const N: usize>(
+    nonresidue: Fp<MontBackend<T, N>, N>,
+) -> [Fp<MontBackend<T, N>, N>; 2] {
+    [
+        Fp::new_unchecked(T::R),
+        nonresidue.const_pow(&modulus_minus_one_div::<T, N>(2).0),
+    ]
+}
+
+/// Returns the coefficients [`Fp6Config::FROBENIUS_COEFF_FP6_C1`] for the
+/// cubic non-residue `nonresidue`, i.e. `nonresidue^((p^i - 1) / 3)` for `i`
+/// in `0..6`.
+///
+/// # Panics
+///
+/// Panics if `p - 1` is not divisible by 3.
+///
+/// [`Fp6Config::FROBENIUS_COEFF_FP6_C1`]: crate::fields::Fp6Config::FROBENIUS_COEFF_FP6_C1
+pub const fn fp6_frobenius_coeffs_c1<P, T, const N: usize>(nonresidue: Fp2<P>) -> [Fp2<P>; 6]
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    frobenius_coeffs(&nonresidue, 3)
+}
+
+/// Returns the coefficients [`Fp6Config::FROBENIUS_COEFF_FP6_C2`] for the
+/// cubic non-residue `nonresidue`, i.e. `nonresidue^((2p^i - 2) / 3)` for
+/// `i` in `0..6`.
+///
+/// # Panics
+///
+/// Panics if `p - 1` is not divisible by 3.
+///
+/// [`Fp6Config::FROBENIUS_COEFF_FP6_C2`]: crate::fields::Fp6Config::FROBENIUS_COEFF_FP6_C2
+pub const fn fp6_frobenius_coeffs_c2<P, T, const N: usize>(nonresidue: Fp2<P>) -> [Fp2<P>; 6]
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    let mut coeffs: [Fp2<P>; 6] = frobenius_coeffs(&nonresidue, 3);
+    let mut i = 0;
+    while i < 6 {
+        coeffs[i] = fp2_mul(&coeffs[i], &coeffs[i]);
+        i += 1;
+    }
+    coeffs
+}
+
+/// Returns the coefficients [`Fp12Config::FROBENIUS_COEFF_FP12_C1`] for an
+/// `Fp12` built on top of an `Fp6` with cubic non-residue `fp6_nonresidue`,
+/// i.e. `fp6_nonresidue^((p^i - 1) / 6)` for `i` in `0..12`.
+///
+/// # Panics
+///
+/// Panics if `p - 1` is not divisible by 6.
+///
+/// [`Fp12Config::FROBENIUS_COEFF_FP12_C1`]: crate::fields::Fp12Config::FROBENIUS_COEFF_FP12_C1
+pub const fn fp12_frobenius_coeffs_c1<P, T, const N: usize>(fp6_nonresidue: Fp2<P>) -> [Fp2<P>; 12]
+where
+    P: Fp2Config<Fp = Fp<MontBackend<T, N>, N>>,
+    T: MontConfig<N>,
+{
+    frobenius_coeffs(&fp6_nonresidue, 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::{
+        ark_ff::fields::{
+            fp12_frobenius_coeffs_c1, fp2_frobenius_coeffs, fp6_frobenius_coeffs_c1,
+            fp6_frobenius_coeffs_c2, Fp12Config, Fp2Config, Fp6Config,
+        },
+        bls12_381::{Fq12Config, Fq2Config, Fq6Config},
+    };
+
+    #[test]
+    fn test_bls12_381_frobenius_coeffs() {
+        assert_eq!(
+            fp2_frobenius_coeffs(Fq2Config::NONRESIDUE),
+            Fq2Config::FROBENIUS_COEFF_FP2_C1
+        );
+        assert_eq!(
+            fp6_frobenius_coeffs_c1(Fq6Config::NONRESIDUE),
+            Fq6Config::FROBENIUS_COEFF_FP6_C1
+        );
+        assert_eq!(
+            fp6_frobenius_coeffs_c2(Fq6Config::NONRESIDUE),
+            Fq6Config::FROBENIUS_COEFF_FP6_C2
+        );
+        assert_eq!(
+            fp12_frobenius_coeffs_c1(Fq6Config::NONRESIDUE),
+            Fq12Config::FROBENIUS_COEFF_FP12_C1
+        );
+    }
+}
@@ -1,6 +1,15 @@
 pub mod fp;
 pub use self::fp::*;
 
+pub mod dyn_fp;
+pub use self::dyn_fp::*;
+
+pub mod binary_field;
+pub use self::binary_field::*;
+
+pub mod ternary_field;
+pub use self::ternary_field::*;
+
 pub mod fp2;
 pub use self::fp2::*;
 
@@ -18,6 +27,12 @@ pub use self::fp6_3over2::*;
 pub mod fp12_2over3over2;
 pub use self::fp12_2over3over2::*;
 
+pub mod torus;
+pub use self::torus::*;
+
+pub mod frobenius;
+pub use self::frobenius::*;
+
 #[macro_use]
 pub mod quadratic_extension;
 pub use quadratic_extension::*;
@@ -1,6 +1,11 @@
 use crate::{
     biginteger::BigInteger,
-    fields::{Field, LegendreSymbol, PrimeField},
+    fields::{
+        batch_inversion,
+        prime::{batch_pow, BATCH_POW_CHUNK_SIZE},
+        utils::split_top_level,
+        ExtensionField, Field, LegendreSymbol, PrimeField, TowerMulStrategy,
+    },
     AdditiveGroup, One, SqrtPrecomputation, ToConstraintField, UniformRand, Zero,
 };
 use ark_serialize::{
@@ -8,6 +13,7 @@ use ark_serialize::{
     CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
 };
 use ark_std::{
+    cfg_chunks,
     cmp::*,
     fmt,
     io::{Read, Write},
@@ -17,10 +23,14 @@ use ark_std::{
         distributions::{Distribution, Standard},
         Rng,
     },
+    str::FromStr,
     vec::*,
 };
 use zeroize::Zeroize;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Defines a Quadratic extension field from a quadratic non-residue.
 pub trait QuadExtConfig: 'static + Send + Sync + Sized {
     /// The prime field that this quadratic extension is eventually an extension of.
@@ -41,6 +51,10 @@ pub trait QuadExtConfig: 'static + Send + Sync + Sized {
     /// The quadratic non-residue used to construct the extension.
     const NONRESIDUE: Self::BaseField;
 
+    /// The algorithm used for multiplication when the base field is itself an
+    /// extension field.
+    const MUL_STRATEGY: TowerMulStrategy = TowerMulStrategy::Karatsuba;
+
     /// Coefficients for the Frobenius automorphism.
     const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff];
 
@@ -159,6 +173,103 @@ impl<P: QuadExtConfig> QuadExtField<P> {
         self.c0 *= element;
         self.c1 *= element;
     }
+
+    /// Whether this is an extension `F_p[u] / (u^2 + 1)` of a prime field with
+    /// `p = 3 (mod 4)`, in which square roots are computed with
+    /// [`Self::sqrt_imaginary_basis`].
+    fn has_imaginary_basis() -> bool {
+        P::BaseField::extension_degree() == 1
+            && P::BasePrimeField::MODULUS.as_ref()[0] % 4 == 3
+            && P::NONRESIDUE == -P::BaseField::ONE
+    }
+
+    /// Square root based on the complex method for `q = 3 (mod 4)`, which
+    /// only needs two exponentiations. See
+    /// https://eprint.iacr.org/2012/685.pdf (page 9, algorithm 9)
+    fn sqrt_imaginary_basis(&self) -> Option<Self> {
+        let (x0, alpha) = self.sqrt_imaginary_basis_start(self.pow(Self::imaginary_basis_exp()))?;
+        if alpha == -Self::ONE {
+            // Multiply by `u`, which is a square root of -1.
+            Some(Self::new(-x0.c1, x0.c0))
+        } else {
+            let b = (Self::ONE + alpha).pow(P::BasePrimeField::MODULUS_MINUS_ONE_DIV_TWO);
+            Some(b * x0)
+        }
+    }
+
+    /// The exponent `(p - 3) / 4` of [`Self::sqrt_imaginary_basis`].
+    fn imaginary_basis_exp() -> <P::BasePrimeField as PrimeField>::BigInt {
+        // Since `p = 3 (mod 4)`, `(p - 3) / 4 = floor(p / 4)`.
+        let mut exp = P::BasePrimeField::MODULUS;
+        exp >>= 2;
+        exp
+    }
+
+    /// Given `a1 = self^((p - 3) / 4)`, returns `None` if `self` is not a
+    /// square, and `(x0, alpha)` for `x0 = self^((p + 1) / 4)` and
+    /// `alpha = self^((p - 1) / 2)` otherwise.
+    fn sqrt_imaginary_basis_start(&self, a1: Self) -> Option<(Self, Self)> {
+        // alpha = self^((p - 1) / 2)
+        let alpha = a1.square() * self;
+        // a0 = alpha^(p + 1) = self^((p^2 - 1) / 2)
+        let a0 = alpha.frobenius_map(1) * alpha;
+        if a0 == -Self::ONE {
+            return None;
+        }
+        // x0 = self^((p + 1) / 4)
+        Some((a1 * self, alpha))
+    }
+
+    /// Computes [`Self::sqrt_imaginary_basis`] for all elements of `elems`,
+    /// performing both exponentiations in lockstep across the inputs with
+    /// [`batch_pow`], in chunks of [`BATCH_POW_CHUNK_SIZE`].
+    fn batch_sqrt_imaginary_basis(elems: &[Self]) -> Vec<Option<Self>> {
+        let exp = Self::imaginary_basis_exp();
+        cfg_chunks!(elems, BATCH_POW_CHUNK_SIZE)
+            .flat_map(|chunk| {
+                let starts: Vec<_> = chunk
+                    .iter()
+                    .zip(batch_pow(chunk, exp))
+                    .map(|(elem, a1)| elem.sqrt_imaginary_basis_start(a1))
+                    .collect();
+                let minus_one = -Self::ONE;
+                let bases: Vec<_> = starts
+                    .iter()
+                    .flatten()
+                    .filter(|(_, alpha)| *alpha != minus_one)
+                    .map(|(_, alpha)| Self::ONE + alpha)
+                    .collect();
+                let mut bs =
+                    batch_pow(&bases, P::BasePrimeField::MODULUS_MINUS_ONE_DIV_TWO).into_iter();
+                starts
+                    .into_iter()
+                    .map(|start| {
+                        start.map(|(x0, alpha)| {
+                            if alpha == minus_one {
+                                Self::new(-x0.c1, x0.c0)
+                            } else {
+                                bs.next().unwrap() * x0
+                            }
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl<P: QuadExtConfig> ExtensionField for QuadExtField<P> {
+    type BaseField = P::BaseField;
+
+    fn relative_norm(&self) -> P::BaseField {
+        Self::norm(self)
+    }
+
+    /// The conjugate of `c0 + c1 * X` is `c0 - c1 * X`, so the trace is
+    /// `2 * c0`.
+    fn relative_trace(&self) -> P::BaseField {
+        self.c0.double()
+    }
 }
 
 impl<P: QuadExtConfig> Zero for QuadExtField<P> {
@@ -350,6 +461,14 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
         }
     }
 
+    fn trace(&self) -> Self::BasePrimeField {
+        self.relative_trace().trace()
+    }
+
+    fn norm(&self) -> Self::BasePrimeField {
+        Field::norm(&self.relative_norm())
+    }
+
     fn frobenius_map_in_place(&mut self, power: usize) {
         self.c0.frobenius_map_in_place(power);
         self.c1.frobenius_map_in_place(power);
@@ -371,6 +490,9 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
     }
 
     fn sqrt(&self) -> Option<Self> {
+        if Self::has_imaginary_basis() {
+            return self.sqrt_imaginary_basis();
+        }
         // Square root based on the complex method. See
         // https://eprint.iacr.org/2012/685.pdf (page 15, algorithm 8)
         if self.c1.is_zero() {
@@ -437,6 +559,69 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
         })
     }
 
+    /// Computes the square roots with the same complex method as
+    /// [`Self::sqrt`], but batches the square roots in the base field across
+    /// all inputs, and replaces the per-element inversions by a single batch
+    /// inversion. Over `F_p[u] / (u^2 + 1)` with `p = 3 (mod 4)`, the
+    /// exponentiations of [`Self::sqrt_imaginary_basis`] are batched instead.
+    fn batch_sqrt(elems: &[Self]) -> Vec<Option<Self>> {
+        if Self::has_imaginary_basis() {
+            return Self::batch_sqrt_imaginary_basis(elems);
+        }
+        let mut result = vec![None; elems.len()];
+        // Elements with `c1 = 0` take a different path in `Self::sqrt`.
+        let (general, special): (Vec<usize>, Vec<usize>) =
+            (0..elems.len()).partition(|&i| !elems[i].c1.is_zero());
+        for i in special {
+            result[i] = elems[i].sqrt();
+        }
+
+        let alphas: Vec<_> = general.iter().map(|&i| elems[i].norm()).collect();
+        let (general, alphas): (Vec<usize>, Vec<P::BaseField>) = general
+            .into_iter()
+            .zip(P::BaseField::batch_sqrt(&alphas))
+            .filter_map(|(i, alpha)| Some((i, alpha?)))
+            .unzip();
+
+        // Compute `(p+1)/2` as `1/2`.
+        let mut two_inv = P::BasePrimeField::MODULUS;
+        two_inv.add_with_carry(&1u64.into());
+        two_inv.div2();
+        let two_inv = P::BaseField::from_base_prime_field(P::BasePrimeField::from(two_inv));
+        let mut deltas: Vec<_> = general
+            .iter()
+            .zip(&alphas)
+            .map(|(&i, alpha)| (*alpha + &elems[i].c0) * &two_inv)
+            .collect();
+        let mut c0s = P::BaseField::batch_sqrt(&deltas);
+        // If `delta` is not a square, then `delta - alpha` is.
+        let non_squares: Vec<usize> = (0..c0s.len()).filter(|&j| c0s[j].is_none()).collect();
+        for &j in &non_squares {
+            deltas[j] -= &alphas[j];
+        }
+        let retried: Vec<_> = non_squares.iter().map(|&j| deltas[j]).collect();
+        let retried = P::BaseField::batch_sqrt(&retried);
+        for (j, c0) in non_squares.into_iter().zip(retried) {
+            c0s[j] = c0;
+        }
+        let c0s: Vec<_> = c0s
+            .into_iter()
+            .map(|c0| c0.expect("Delta must have a square root"))
+            .collect();
+
+        let mut c0_invs = c0s.clone();
+        batch_inversion(&mut c0_invs);
+        for ((i, c0), c0_inv) in general.into_iter().zip(c0s).zip(c0_invs) {
+            let sqrt_cand = Self::new(c0, elems[i].c1 * &two_inv * &c0_inv);
+            // As in `Self::sqrt`, the candidate is a square root if and only
+            // if the input is a square.
+            if sqrt_cand.square() == elems[i] {
+                result[i] = Some(sqrt_cand);
+            }
+        }
+        result
+    }
+
     fn mul_by_base_prime_field(&self, elem: &Self::BasePrimeField) -> Self {
         let mut result = *self;
         result.c0 = result.c0.mul_by_base_prime_field(elem);
@@ -464,6 +649,29 @@ impl<P: QuadExtConfig> PartialOrd for QuadExtField<P> {
     }
 }
 
+#[cfg(feature = "ct")]
+impl<P: QuadExtConfig> subtle::ConstantTimeEq for QuadExtField<P>
+where
+    P::BaseField: subtle::ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: QuadExtConfig> subtle::ConditionallySelectable for QuadExtField<P>
+where
+    P::BaseField: subtle::ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
 impl<P: QuadExtConfig> Zeroize for QuadExtField<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -656,6 +864,15 @@ impl<'a, P: QuadExtConfig> MulAssign<&'a Self> for QuadExtField<P> {
                 P::BaseField::sum_of_products(&[self.c0, self.c1], &[other.c0, other.c1]),
                 P::BaseField::sum_of_products(&c1_input, &[other.c1, other.c0]),
             )
+        } else if P::MUL_STRATEGY == TowerMulStrategy::Schoolbook {
+            let mut v0 = self.c0;
+            v0 *= &other.c0;
+            let mut v1 = self.c1;
+            v1 *= &other.c1;
+
+            self.c1 = self.c0 * &other.c1 + &(self.c1 * &other.c0);
+            self.c0 = v1;
+            P::mul_base_field_by_nonresidue_and_add(&mut self.c0, &v0);
         } else {
             // Karatsuba multiplication;
             // Guide to Pairing-based cryprography, Algorithm 5.16.
@@ -687,6 +904,31 @@ impl<P: QuadExtConfig> fmt::Display for QuadExtField<P> {
     }
 }
 
+impl<P: QuadExtConfig> FromStr for QuadExtField<P>
+where
+    P::BaseField: FromStr,
+{
+    type Err = ();
+
+    /// Parses the output of `Display`, i.e. `QuadExtField(c0 + c1 * u)`, where
+    /// the coefficients are parsed as elements of the base field.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .strip_prefix("QuadExtField(")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(())?;
+        match split_top_level(inner, " + ")[..] {
+            [c0, c1] => {
+                let c1 = c1.strip_suffix(" * u").ok_or(())?;
+                let c0 = c0.parse().map_err(|_| ())?;
+                let c1 = c1.parse().map_err(|_| ())?;
+                Ok(Self::new(c0, c1))
+            },
+            _ => Err(()),
+        }
+    }
+}
+
 impl<P: QuadExtConfig> CanonicalSerializeWithFlags for QuadExtField<P> {
     #[inline]
     fn serialize_with_flags<W: Write, F: Flags>(
@@ -775,10 +1017,75 @@ mod quad_ext_tests {
     use super::*;
     use ark_std::test_rng;
     use ark_test_curves::{
-        ark_ff::Field,
-        bls12_381::{Fq, Fq2},
+        ark_ff::{Field, Fp12, Fp12Config, Fp2, Fp2Config, MontFp, TowerMulStrategy},
+        bls12_381::{Fq, Fq12, Fq12Config, Fq2, Fq6, Fq6Config, Fr},
     };
 
+    #[derive(Clone, Copy)]
+    struct SchoolbookConfig;
+
+    impl Fp12Config for SchoolbookConfig {
+        type Fp6Config = Fq6Config;
+        const NONRESIDUE: Fq6 = Fq12Config::NONRESIDUE;
+        const MUL_STRATEGY: TowerMulStrategy = TowerMulStrategy::Schoolbook;
+        const FROBENIUS_COEFF_FP12_C1: &'static [Fq2] = Fq12Config::FROBENIUS_COEFF_FP12_C1;
+    }
+
+    #[test]
+    fn test_schoolbook_mul() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a: Fq12 = rng.gen();
+            let b: Fq12 = rng.gen();
+            let c = Fp12::<SchoolbookConfig>::new(a.c0, a.c1)
+                * Fp12::<SchoolbookConfig>::new(b.c0, b.c1);
+            assert_eq!(Fq12::new(c.c0, c.c1), a * b);
+        }
+    }
+
+    /// A quadratic extension of a field with `p = 1 (mod 4)`, in which square
+    /// roots are computed with the general complex method.
+    struct FrQuadConfig;
+
+    impl Fp2Config for FrQuadConfig {
+        type Fp = Fr;
+        const NONRESIDUE: Fr = MontFp!("7");
+        const FROBENIUS_COEFF_FP2_C1: &'static [Fr] = &[MontFp!("1"), MontFp!("-1")];
+    }
+
+    fn check_batch_sqrt<F: Field>(rng: &mut impl Rng) {
+        let mut elems: Vec<F> = (0..100).map(|_| F::rand(rng)).collect();
+        elems.extend((0..100).map(|_| F::rand(rng).square()));
+        elems.extend((0..10).map(|_| F::from_base_prime_field(F::BasePrimeField::rand(rng))));
+        elems.push(F::zero());
+        let expected: Vec<_> = elems.iter().map(|a| a.sqrt()).collect();
+        assert_eq!(F::batch_sqrt(&elems), expected);
+    }
+
+    #[test]
+    fn test_batch_sqrt() {
+        let mut rng = test_rng();
+        check_batch_sqrt::<Fp2<FrQuadConfig>>(&mut rng);
+        check_batch_sqrt::<Fq2>(&mut rng);
+    }
+
+    #[test]
+    fn test_batch_sqrt_imaginary_basis() {
+        // The BLS12-381 `Fq2` is `Fq[u] / (u^2 + 1)` with `q = 3 (mod 4)`, so
+        // `batch_sqrt` takes the batched imaginary-basis path.
+        assert!(Fq2::has_imaginary_basis());
+        assert!(!Fp2::<FrQuadConfig>::has_imaginary_basis());
+        let mut rng = test_rng();
+        let mut elems: Vec<Fq2> = (0..50).map(|_| Fq2::rand(&mut rng)).collect();
+        elems.extend((0..50).map(|_| Fq2::rand(&mut rng).square()));
+        // Non-squares of `Fq`, whose `alpha` is -1.
+        elems.extend((0..10).map(|_| Fq2::new(-Fq::rand(&mut rng).square(), Fq::ZERO)));
+        elems.push(Fq2::ZERO);
+        let expected: Vec<_> = elems.iter().map(Fq2::sqrt_imaginary_basis).collect();
+        assert_eq!(Fq2::batch_sqrt_imaginary_basis(&elems), expected);
+        assert_eq!(Fq2::batch_sqrt(&elems), expected);
+    }
+
     #[test]
     fn test_from_base_prime_field_elements() {
         let ext_degree = Fq2::extension_degree() as usize;
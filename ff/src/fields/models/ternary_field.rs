@@ -0,0 +1,115 @@
+use super::{
+    fp::{Fp32, Fp32Config},
+    Fp2, Fp2Config,
+};
+use crate::AdditiveGroup;
+
+/// The prime field `GF(3)`.
+///
+/// Fields of characteristic three are supported by the generic models, with
+/// the caveat that cubic extensions of the form `F[X] / (X^3 - NONRESIDUE)`
+/// do not exist, since every element is a cube.
+///
+/// Cube roots are computed with the inverse of the Frobenius map.
+pub type TernaryField1t = Fp32<TernaryField1tConfig>;
+
+pub struct TernaryField1tConfig;
+
+impl Fp32Config for TernaryField1tConfig {
+    const MODULUS: u32 = 3;
+
+    const GENERATOR: u32 = 2;
+
+    /// `-1`, since the two-adicity of `p - 1` is one.
+    const TWO_ADIC_ROOT_OF_UNITY: u32 = 2;
+}
+
+/// The field `GF(3^2) = GF(3)[i] / (i^2 + 1)`.
+pub type TernaryField2t = Fp2<TernaryField2tConfig>;
+
+pub struct TernaryField2tConfig;
+
+impl Fp2Config for TernaryField2tConfig {
+    type Fp = TernaryField1t;
+
+    /// `-1`
+    const NONRESIDUE: TernaryField1t = TernaryField1t::new(2);
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [TernaryField1t] = &[
+        // NONRESIDUE^((p^0 - 1) / 2)
+        TernaryField1t::new(1),
+        // NONRESIDUE^((p^1 - 1) / 2)
+        TernaryField1t::new(2),
+    ];
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut TernaryField1t) -> &mut TernaryField1t {
+        fe.neg_in_place()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Field, LegendreSymbol, PrimeField, Zero};
+    use ark_std::{rand::Rng, test_rng, vec::Vec, UniformRand};
+
+    fn test_field_axioms<F: Field<BasePrimeField = TernaryField1t>>() {
+        let mut rng = test_rng();
+        let degree = F::extension_degree() as usize;
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let b = F::rand(&mut rng);
+            assert_eq!(a + a + a, F::ZERO);
+            assert_eq!(a.double(), -a);
+            assert_eq!((a + b).pow([3]), a.pow([3]) + b.pow([3]));
+            assert_eq!(a.frobenius_map(1), a.pow([3]));
+            assert_eq!(a.frobenius_map(degree), a);
+            assert_eq!(a.cube_root().unwrap().pow([3]), a);
+            match a.legendre() {
+                LegendreSymbol::Zero => assert!(a.is_zero()),
+                LegendreSymbol::QuadraticResidue => assert_eq!(a.sqrt().unwrap().square(), a),
+                LegendreSymbol::QuadraticNonResidue => assert!(a.sqrt().is_none()),
+            }
+            if !a.is_zero() {
+                assert_eq!(a * a.inverse().unwrap(), F::ONE);
+            }
+            let elems = a.to_base_prime_field_elements().collect::<Vec<_>>();
+            assert_eq!(F::from_base_prime_field_elems(elems), Some(a));
+        }
+        assert_eq!(F::from(3u64), F::ZERO);
+        assert_eq!(F::from(-1i64), F::from(2u64));
+    }
+
+    #[test]
+    fn test_ternary_fields() {
+        test_field_axioms::<TernaryField1t>();
+        test_field_axioms::<TernaryField2t>();
+    }
+
+    #[test]
+    fn test_gf3() {
+        let two = TernaryField1t::from(2u64);
+        assert_eq!(TernaryField1t::characteristic(), &[3]);
+        assert_eq!(TernaryField1t::MODULUS_BIT_SIZE, 2);
+        assert_eq!(two.legendre(), LegendreSymbol::QuadraticNonResidue);
+        assert_eq!(TernaryField1t::ZERO.legendre(), LegendreSymbol::Zero);
+        assert_eq!(two.inverse(), Some(two));
+        // Every element of `GF(3)` is a square in `GF(9)`.
+        let a = TernaryField2t::new(two, TernaryField1t::ZERO);
+        assert_eq!(a.sqrt().unwrap().square(), a);
+    }
+
+    #[test]
+    fn test_random_bytes() {
+        let mut rng = test_rng();
+        let mut counts = [0usize; 3];
+        for _ in 0..300 {
+            if let Some(a) = TernaryField1t::from_random_bytes(&[rng.gen::<u8>()]) {
+                counts[a.into_bigint().0[0] as usize] += 1;
+            }
+        }
+        assert!(counts.iter().all(|&count| count > 0));
+    }
+}
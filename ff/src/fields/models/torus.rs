@@ -0,0 +1,272 @@
+use super::quadratic_extension::{QuadExtConfig, QuadExtField};
+use crate::{Field, One, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, Flags, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    io::{Read, Write},
+    ops::{Mul, MulAssign},
+};
+
+/// An element of the algebraic torus `T2` over `P::BaseField`, i.e. an element
+/// `x` of norm one in the quadratic extension `QuadExtField<P>`, stored in
+/// compressed form with half as many coefficients.
+///
+/// Writing the extension as `F[w] / (w^2 - P::NONRESIDUE)`, every such `x`
+/// other than the identity can be written uniquely as `(m + w) / (m - w)` for
+/// some `m` in `F`, and only `m` is stored. In particular, this compresses
+/// elements of the cyclotomic subgroup of `Fp12`, such as the outputs of a
+/// pairing, to an element of `Fp6`.
+///
+/// Multiplication and squaring work directly on the compressed form, but each
+/// costs an inversion in `F`, so long chains of operations are faster on
+/// decompressed elements.
+///
+/// See Rubin and Silverberg --- Torus-Based Cryptography; Section 5.
+///
+/// # Example
+/// ```
+/// # use ark_ff::{Field, Torus};
+/// # use ark_serialize::CanonicalSerialize;
+/// # use ark_std::{test_rng, UniformRand};
+/// # use ark_test_curves::bls12_381::Fq12;
+/// let a = Fq12::rand(&mut test_rng());
+/// // Map `a` into the cyclotomic subgroup.
+/// let a = a.inverse().unwrap() * a.frobenius_map(6);
+/// let compressed = Torus::compress(&a).unwrap();
+/// assert_eq!(compressed.decompress(), a);
+/// assert_eq!(compressed.compressed_size() * 2, a.compressed_size());
+/// ```
+#[derive(Educe)]
+#[educe(Default, Hash, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Torus<P: QuadExtConfig> {
+    /// The parameter `m` of the element, or `None` for the identity.
+    m: Option<P::BaseField>,
+}
+
+impl<P: QuadExtConfig> Torus<P> {
+    /// The identity element.
+    pub const IDENTITY: Self = Self { m: None };
+
+    /// Returns whether `self` is the identity.
+    pub fn is_identity(&self) -> bool {
+        self.m.is_none()
+    }
+
+    /// Compresses `x`, or returns `None` if the norm of `x` is not one.
+    pub fn compress(x: &QuadExtField<P>) -> Option<Self> {
+        if !x.norm().is_one() {
+            return None;
+        }
+        if x.c1.is_zero() {
+            // `x` is either 1, or -1 = (0 + w) / (0 - w).
+            let m = (!x.c0.is_one()).then(P::BaseField::zero);
+            Some(Self { m })
+        } else {
+            // m = (1 + c0) / c1
+            let m = (P::BaseField::one() + x.c0) * x.c1.inverse().unwrap();
+            Some(Self { m: Some(m) })
+        }
+    }
+
+    /// Returns the element of `QuadExtField<P>` represented by `self`.
+    pub fn decompress(&self) -> QuadExtField<P> {
+        match self.m {
+            None => QuadExtField::one(),
+            Some(m) => {
+                // (m + w) / (m - w) = (m^2 + NONRESIDUE + 2 * m * w) / (m^2 - NONRESIDUE),
+                // where `m^2 - NONRESIDUE` is non-zero since the non-residue is not a square.
+                let m_square = m.square();
+                let denominator = (m_square - P::NONRESIDUE).inverse().unwrap();
+                QuadExtField::new(
+                    (m_square + P::NONRESIDUE) * denominator,
+                    m.double() * denominator,
+                )
+            },
+        }
+    }
+
+    /// Returns `self^2`.
+    pub fn square(&self) -> Self {
+        // m' = (m^2 + NONRESIDUE) / (2 * m)
+        let m = self.m.and_then(|m| {
+            m.double()
+                .inverse()
+                .map(|denominator| (m.square() + P::NONRESIDUE) * denominator)
+        });
+        Self { m }
+    }
+
+    /// Returns the inverse of `self`, which is its conjugate.
+    pub fn inverse(&self) -> Self {
+        Self {
+            m: self.m.map(|m| -m),
+        }
+    }
+}
+
+impl<P: QuadExtConfig> From<Torus<P>> for QuadExtField<P> {
+    fn from(x: Torus<P>) -> Self {
+        x.decompress()
+    }
+}
+
+impl<'a, P: QuadExtConfig> MulAssign<&'a Self> for Torus<P> {
+    fn mul_assign(&mut self, other: &Self) {
+        // m'' = (m * m' + NONRESIDUE) / (m + m')
+        self.m = match (self.m, other.m) {
+            (None, m) | (m, None) => m,
+            (Some(m1), Some(m2)) => (m1 + m2)
+                .inverse()
+                .map(|denominator| (m1 * m2 + P::NONRESIDUE) * denominator),
+        };
+    }
+}
+
+impl<P: QuadExtConfig> MulAssign<Self> for Torus<P> {
+    fn mul_assign(&mut self, other: Self) {
+        *self *= &other;
+    }
+}
+
+impl<'a, P: QuadExtConfig> Mul<&'a Self> for Torus<P> {
+    type Output = Self;
+
+    fn mul(mut self, other: &Self) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: QuadExtConfig> Mul<Self> for Torus<P> {
+    type Output = Self;
+
+    fn mul(mut self, other: Self) -> Self {
+        self *= &other;
+        self
+    }
+}
+
+/// Flags marking the identity in the serialization of a [`Torus`] element.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum TorusFlags {
+    #[default]
+    NotIdentity,
+    Identity,
+}
+
+impl Flags for TorusFlags {
+    const BIT_SIZE: usize = 1;
+
+    fn u8_bitmask(&self) -> u8 {
+        match self {
+            Self::NotIdentity => 0,
+            Self::Identity => 1 << 7,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        if value & (1 << 7) == 0 {
+            Some(Self::NotIdentity)
+        } else {
+            Some(Self::Identity)
+        }
+    }
+}
+
+impl<P: QuadExtConfig> CanonicalSerialize for Torus<P> {
+    /// Serializes `m`, or zero with a flag bit set for the identity.
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        match self.m {
+            None => P::BaseField::zero().serialize_with_flags(writer, TorusFlags::Identity),
+            Some(m) => m.serialize_with_flags(writer, TorusFlags::NotIdentity),
+        }
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        P::BaseField::zero().serialized_size_with_flags::<TorusFlags>()
+    }
+}
+
+impl<P: QuadExtConfig> Valid for Torus<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.m.check()
+    }
+}
+
+impl<P: QuadExtConfig> CanonicalDeserialize for Torus<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        _compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let (m, flags): (P::BaseField, TorusFlags) =
+            CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+        let result = match flags {
+            TorusFlags::Identity if !m.is_zero() => return Err(SerializationError::InvalidData),
+            TorusFlags::Identity => Self::IDENTITY,
+            TorusFlags::NotIdentity => Self { m: Some(m) },
+        };
+        if validate == Validate::Yes {
+            result.check()?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::Rng, test_rng, vec::*, UniformRand};
+    use ark_test_curves::{
+        ark_ff::{Field, One, Torus},
+        bls12_381::{Fq12, Fq6},
+    };
+
+    fn rand_cyclotomic<R: Rng>(rng: &mut R) -> Fq12 {
+        let a = Fq12::rand(rng);
+        a.inverse().unwrap() * a.frobenius_map(6)
+    }
+
+    #[test]
+    fn test_torus_arithmetic() {
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let a = rand_cyclotomic(rng);
+            let b = rand_cyclotomic(rng);
+            let ta = Torus::compress(&a).unwrap();
+            let tb = Torus::compress(&b).unwrap();
+            assert_eq!(ta.decompress(), a);
+            assert_eq!((ta * tb).decompress(), a * b);
+            assert_eq!(ta.square().decompress(), a.square());
+            assert_eq!(ta.inverse().decompress(), a.inverse().unwrap());
+            assert!((ta * ta.inverse()).is_identity());
+        }
+        assert!(Torus::compress(&Fq12::rand(rng)).is_none());
+
+        let one = Fq12::one();
+        let identity = Torus::compress(&one).unwrap();
+        assert!(identity.is_identity());
+        assert_eq!(identity.decompress(), one);
+        let minus_one = Torus::compress(&-one).unwrap();
+        assert_eq!(minus_one.decompress(), -one);
+        assert!(minus_one.square().is_identity());
+    }
+
+    #[test]
+    fn test_torus_serialization() {
+        let rng = &mut test_rng();
+        let a = rand_cyclotomic(rng);
+        for x in [Torus::compress(&a).unwrap(), Torus::IDENTITY] {
+            let mut bytes = Vec::new();
+            x.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), Fq6::one().compressed_size());
+            assert_eq!(Torus::deserialize_compressed(&bytes[..]).unwrap(), x);
+        }
+    }
+}
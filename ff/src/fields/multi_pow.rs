@@ -0,0 +1,61 @@
+use crate::{fields::utils::bits_window, Field};
+use ark_std::{cfg_into_iter, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Computes `prod([bases_i^exps_i])`; see [`Field::multi_pow`].
+pub(crate) fn multi_pow<F: Field, S: AsRef<[u64]> + Sync>(bases: &[F], exps: &[S]) -> F {
+    assert_eq!(bases.len(), exps.len());
+    let num_bits = exps
+        .iter()
+        .map(|exp| {
+            let exp = exp.as_ref();
+            exp.iter()
+                .rposition(|limb| *limb != 0)
+                .map_or(0, |i| 64 * (i + 1) - exp[i].leading_zeros() as usize)
+        })
+        .max()
+        .unwrap_or(0);
+
+    let c = if bases.len() < 32 {
+        3
+    } else {
+        (ark_std::log2(bases.len()) * 69 / 100) as usize + 2
+    };
+
+    // Each window is handled independently: the bases are sorted into
+    // buckets according to the corresponding digit of their exponent, and
+    // the window's result is `prod([bucket_j^j])`.
+    let window_results: Vec<F> = cfg_into_iter!((0..num_bits).step_by(c).collect::<Vec<_>>())
+        .map(|w_start| {
+            let mut buckets = vec![F::one(); (1 << c) - 1];
+            for (base, exp) in bases.iter().zip(exps) {
+                let digit = bits_window(exp.as_ref(), w_start, c);
+                if digit != 0 {
+                    buckets[digit - 1] *= base;
+                }
+            }
+
+            // `running_product` is `prod([bucket_k])` for `k >= j`, so
+            // multiplying all of them gives `prod([bucket_j^j])`.
+            let mut running_product = F::one();
+            let mut res = F::one();
+            for bucket in buckets.into_iter().rev() {
+                running_product *= bucket;
+                res *= running_product;
+            }
+            res
+        })
+        .collect();
+
+    // Combine the windows from the most significant one downwards.
+    window_results
+        .into_iter()
+        .rev()
+        .fold(F::one(), |mut total, window| {
+            for _ in 0..c {
+                total.square_in_place();
+            }
+            total * window
+        })
+}
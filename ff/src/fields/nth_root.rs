@@ -0,0 +1,193 @@
+use crate::{Field, UniformRand};
+use ark_std::{
+    rand::{rngs::StdRng, SeedableRng},
+    vec::*,
+};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+/// Computes an `n`-th root of `elem`; see [`Field::nth_root`].
+pub(crate) fn nth_root<F: Field>(elem: &F, n: u64) -> Option<F> {
+    if n == 0 {
+        return elem.is_one().then(F::one);
+    }
+    if n == 1 || elem.is_zero() {
+        return Some(*elem);
+    }
+    if F::characteristic() == [n] {
+        // Raising to the power of the characteristic is the Frobenius map,
+        // which is a bijection, e.g. for cube roots in characteristic 3.
+        return Some(elem.frobenius_map(F::extension_degree() as usize - 1));
+    }
+
+    let order = multiplicative_order::<F>();
+    let g = gcd(n, (&order % n).iter_u64_digits().next().unwrap_or(0));
+
+    // `elem` is an `n`-th power if and only if it is a `g`-th power.
+    let order_div_g = &order / g;
+    if !elem.pow(order_div_g.to_u64_digits()).is_one() {
+        return None;
+    }
+
+    // If `y^g = elem` and `u * n = g (mod q - 1)`, then `(y^u)^n = elem`.
+    // When `g = 1` this is the only exponentiation required, which is the case
+    // e.g. for cube roots when `q = 2 (mod 3)`.
+    let u = mod_inverse(&BigUint::from(n / g), &order_div_g);
+
+    // Compute a `g`-th root by taking one prime root at a time. At each step,
+    // out of the `r` possible `r`-th roots, pick one that is still a power of
+    // the remaining degree.
+    let mut y = *elem;
+    let mut remaining = g;
+    for r in prime_factors(g) {
+        remaining /= r;
+        let (mut x, root_of_unity) = prime_root(&y, r, &order)?;
+        if remaining > 1 {
+            let exp = (&order / remaining).to_u64_digits();
+            let mut attempts = 1;
+            while !x.pow(&exp).is_one() {
+                x *= root_of_unity;
+                attempts += 1;
+                debug_assert!(attempts <= r);
+            }
+        }
+        y = x;
+    }
+    Some(y.pow(u.to_u64_digits()))
+}
+
+/// Computes an `r`-th root of `elem`, for a prime `r` dividing `order = q - 1`,
+/// using the generalization of Tonelli-Shanks due to Adleman, Manders and
+/// Miller. Also returns the primitive `r`-th root of unity that was used.
+fn prime_root<F: Field>(elem: &F, r: u64, order: &BigUint) -> Option<(F, F)> {
+    // `q - 1 = r^s * t`, where `t` is coprime to `r`.
+    let mut s = 0;
+    let mut t = order.clone();
+    while (&t % r).is_zero() {
+        t /= r;
+        s += 1;
+    }
+
+    let (nonresidue, root_of_unity) = rth_power_nonresidue::<F>(r, order);
+    // `z` has order exactly `r^s`.
+    let z = nonresidue.pow(t.to_u64_digits());
+
+    // With `u = r^-1 (mod t)`, `x = elem^u` satisfies `x^r = elem * b`, where
+    // `b` lies in the subgroup of order `r^(s - 1)`. Each iteration multiplies
+    // `x` by a power of `z` that reduces the order of `b`, until `b = 1`.
+    let mut x = elem.pow(mod_inverse(&BigUint::from(r), &t).to_u64_digits());
+    let mut b = x.pow([r]) * elem.inverse()?;
+    while !b.is_one() {
+        // Find the smallest `m` such that `b^(r^m) = 1`.
+        let mut m = 0;
+        let mut b_pow = b;
+        let mut zeta = b;
+        while !b_pow.is_one() {
+            zeta = b_pow;
+            b_pow = b_pow.pow([r]);
+            m += 1;
+        }
+        if m == s {
+            return None;
+        }
+
+        // `zeta = b^(r^(m - 1))` is a primitive `r`-th root of unity; find
+        // its discrete logarithm `j` with respect to `root_of_unity`.
+        let mut j = 1u64;
+        let mut power = root_of_unity;
+        while power != zeta {
+            power *= root_of_unity;
+            j += 1;
+        }
+
+        // `d = z^(r^(s - m - 1))` satisfies `d^(r^m) = root_of_unity`, so
+        // multiplying `x` by `d^-j` cancels `zeta`.
+        let d = (0..s - m - 1).fold(z, |d, _| d.pow([r]));
+        let e = d.pow([j]).inverse()?;
+        x *= e;
+        b *= e.pow([r]);
+    }
+    Some((x, root_of_unity))
+}
+
+/// Returns an element of `F` that is not an `r`-th power, for a prime `r`
+/// dividing `order = q - 1`, together with the primitive `r`-th root of unity
+/// `nonresidue^((q - 1) / r)`.
+fn rth_power_nonresidue<F: Field>(r: u64, order: &BigUint) -> (F, F) {
+    // A fraction `(r - 1) / r` of all elements are not `r`-th powers.
+    let exp = (order / r).to_u64_digits();
+    let mut rng = StdRng::seed_from_u64(r);
+    loop {
+        let candidate = F::rand(&mut rng);
+        let root_of_unity = candidate.pow(&exp);
+        if !candidate.is_zero() && !root_of_unity.is_one() {
+            return (candidate, root_of_unity);
+        }
+    }
+}
+
+/// Returns `q - 1`, where `q` is the order of `F`.
+fn multiplicative_order<F: Field>() -> BigUint {
+    let characteristic = F::characteristic()
+        .iter()
+        .rev()
+        .fold(BigUint::zero(), |acc, limb| (acc << 64) + *limb);
+    characteristic.pow(F::extension_degree() as u32) - 1u32
+}
+
+/// Returns the prime factors of `n`, with multiplicity, in increasing order.
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d <= n / d {
+        while n % d == 0 {
+            factors.push(d);
+            n /= d;
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns `a^-1 (mod m)`, for `a` coprime to `m`.
+fn mod_inverse(a: &BigUint, m: &BigUint) -> BigUint {
+    let (mut r0, mut r1) = (m.clone(), a % m);
+    let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+    while !r1.is_zero() {
+        let q = &r0 / &r1;
+        (r0, r1) = (r1.clone(), r0 - &q * &r1);
+        (t0, t1) = (t1.clone(), t0 - BigInt::from(q) * t1);
+    }
+    debug_assert!(r0.is_one());
+    let m = BigInt::from(m.clone());
+    (((t0 % &m) + &m) % &m).magnitude().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gcd, mod_inverse, prime_factors};
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(360), vec![2, 2, 2, 3, 3, 5]);
+        assert_eq!(prime_factors(97), vec![97]);
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        let m = BigUint::from(1_000_000_006u64);
+        let a = BigUint::from(3u64);
+        assert_eq!((mod_inverse(&a, &m) * &a) % &m, BigUint::from(1u64));
+        assert_eq!(mod_inverse(&a, &BigUint::from(1u64)), BigUint::from(0u64));
+    }
+}
@@ -1,7 +1,9 @@
-use crate::{BigInteger, FftField, Field};
+use crate::{BigInteger, BitIteratorBE, FftField, Field, LegendreSymbol};
 
-use ark_std::{cmp::min, str::FromStr};
+use ark_std::{cfg_chunks, cfg_iter, cmp::min, str::FromStr, vec::*};
 use num_bigint::BigUint;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// The interface for a prime field, i.e. the field of integers modulo a prime $p$.
 /// In the following example we'll use the prime field underlying the BLS12-381 G1 curve.
@@ -57,40 +59,244 @@ pub trait PrimeField:
     /// Converts an element of the prime field into an integer in the range 0..(p - 1).
     fn into_bigint(self) -> Self::BigInt;
 
+    /// Converts every element of `elems` into an integer, as
+    /// [`PrimeField::into_bigint`] does.
+    ///
+    /// Implementations may override this to convert many elements at once,
+    /// which is faster for e.g. the scalars of an MSM. With the `parallel`
+    /// feature, the elements are converted in parallel.
+    ///
+    /// ```
+    /// # use ark_ff::PrimeField;
+    /// # use ark_test_curves::bls12_381::Fr;
+    /// let elems = [Fr::from(1u64), -Fr::from(1u64)];
+    /// let ints = Fr::into_bigint_batch(&elems);
+    /// assert_eq!(ints, vec![elems[0].into_bigint(), elems[1].into_bigint()]);
+    /// assert_eq!(Fr::from_bigint_batch(&ints), Some(elems.to_vec()));
+    /// ```
+    fn into_bigint_batch(elems: &[Self]) -> Vec<Self::BigInt> {
+        cfg_iter!(elems).map(|e| e.into_bigint()).collect()
+    }
+
+    /// Converts every integer in `ints` into a field element, as
+    /// [`PrimeField::from_bigint`] does. Returns `None` if some integer is not
+    /// in the range 0..(p - 1).
+    ///
+    /// Implementations may override this to convert many integers at once.
+    fn from_bigint_batch(ints: &[Self::BigInt]) -> Option<Vec<Self>> {
+        cfg_iter!(ints).map(|i| Self::from_bigint(*i)).collect()
+    }
+
     /// Reads bytes in big-endian, and converts them to a field element.
     /// If the integer represented by `bytes` is larger than the modulus `p`, this method
     /// performs the appropriate reduction.
     fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
-        let mut bytes_copy = bytes.to_vec();
-        bytes_copy.reverse();
-        Self::from_le_bytes_mod_order(&bytes_copy)
+        from_be_bytes_iter_mod_order(bytes.len(), bytes.iter().copied())
     }
 
     /// Reads bytes in little-endian, and converts them to a field element.
     /// If the integer represented by `bytes` is larger than the modulus `p`, this method
     /// performs the appropriate reduction.
     fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
-        let num_modulus_bytes = ((Self::MODULUS_BIT_SIZE + 7) / 8) as usize;
-        let num_bytes_to_directly_convert = min(num_modulus_bytes - 1, bytes.len());
-        // Copy the leading little-endian bytes directly into a field element.
-        // The number of bytes directly converted must be less than the
-        // number of bytes needed to represent the modulus, as we must begin
-        // modular reduction once the data is of the same number of bytes as the
-        // modulus.
-        let (bytes, bytes_to_directly_convert) =
-            bytes.split_at(bytes.len() - num_bytes_to_directly_convert);
-        // Guaranteed to not be None, as the input is less than the modulus size.
-        let mut res = Self::from_random_bytes(&bytes_to_directly_convert).unwrap();
-
-        // Update the result, byte by byte.
-        // We go through existing field arithmetic, which handles the reduction.
-        // TODO: If we need higher speeds, parse more bytes at once, or implement
-        // modular multiplication by a u64
-        let window_size = Self::from(256u64);
-        for byte in bytes.iter().rev() {
-            res *= window_size;
-            res += Self::from(*byte);
+        from_be_bytes_iter_mod_order(bytes.len(), bytes.iter().rev().copied())
+    }
+
+    /// Writes the little-endian byte representation of `self.into_bigint()`
+    /// to `out`, padded with zeros, without allocating. This is equivalent to
+    /// `self.into_bigint().to_bytes_le()` when `out` has the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `(Self::MODULUS_BIT_SIZE + 7) / 8` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_ff::{BigInteger, PrimeField};
+    /// # use ark_test_curves::bls12_381::Fr;
+    /// let a = -Fr::from(2u64);
+    /// let mut bytes = [0u8; 32];
+    /// a.into_bigint_bytes_le(&mut bytes);
+    /// assert_eq!(bytes.to_vec(), a.into_bigint().to_bytes_le());
+    /// assert_eq!(Fr::from_le_bytes_mod_order(&bytes), a);
+    /// ```
+    fn into_bigint_bytes_le(&self, out: &mut [u8]) {
+        write_bytes_le::<Self>(out.iter_mut(), self.into_bigint())
+    }
+
+    /// Writes the big-endian byte representation of `self.into_bigint()` to
+    /// `out`, padded with zeros, without allocating. This is equivalent to
+    /// `self.into_bigint().to_bytes_be()` when `out` has the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `(Self::MODULUS_BIT_SIZE + 7) / 8` bytes.
+    fn into_bigint_bytes_be(&self, out: &mut [u8]) {
+        write_bytes_le::<Self>(out.iter_mut().rev(), self.into_bigint())
+    }
+
+    /// Reads `L` uniformly random bytes in little-endian, and reduces them
+    /// modulo `p`.
+    ///
+    /// Unlike [`Field::from_random_bytes`], which fails on integers larger
+    /// than the modulus, this always succeeds, and since `L` bytes are at least
+    /// 128 bits longer than the modulus, the distribution of the output is
+    /// statistically indistinguishable from uniform. For instance, 64 bytes
+    /// suffice for moduli of up to 384 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `8 * L < Self::MODULUS_BIT_SIZE + 128`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_ff::PrimeField;
+    /// # use ark_test_curves::bls12_381::Fr;
+    /// let a = Fr::from_uniform_bytes(&[0xff; 64]);
+    /// assert_eq!(a, Fr::from_le_bytes_mod_order(&[0xff; 64]));
+    /// ```
+    fn from_uniform_bytes<const L: usize>(bytes: &[u8; L]) -> Self {
+        assert!(
+            8 * L >= Self::MODULUS_BIT_SIZE as usize + 128,
+            "too few bytes for a uniform reduction"
+        );
+        // Convert chunks of one byte less than the modulus, which are thus
+        // smaller than the modulus, and combine them with Horner's rule.
+        let chunk_size = ((Self::MODULUS_BIT_SIZE + 7) / 8) as usize - 1;
+        if chunk_size == 0 {
+            return Self::from_le_bytes_mod_order(bytes);
+        }
+        let shift = Self::from(256u64).pow([chunk_size as u64]);
+        bytes
+            .chunks(chunk_size)
+            .rev()
+            .fold(Self::zero(), |res, chunk| {
+                res * shift + Self::from_random_bytes(chunk).unwrap()
+            })
+    }
+
+    /// Returns the Legendre symbols of all elements of `elems`, as computed
+    /// by [`Field::legendre`].
+    ///
+    /// This is faster than calling [`Field::legendre`] on each element: the
+    /// exponentiations by `(p - 1) / 2` are performed in lockstep using a
+    /// fixed window, so that the multiplications at each step can go through
+    /// [`Field::mul_batch`], and with the `parallel` feature, chunks of
+    /// `elems` are processed in parallel.
+    ///
+    /// ```
+    /// # use ark_ff::{Field, PrimeField};
+    /// # use ark_std::{test_rng, UniformRand};
+    /// # use ark_test_curves::bls12_381::Fq;
+    /// let mut rng = test_rng();
+    /// let elems: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    /// let symbols = Fq::batch_legendre(&elems);
+    /// assert!(elems.iter().zip(symbols).all(|(e, s)| e.legendre() == s));
+    /// ```
+    fn batch_legendre(elems: &[Self]) -> Vec<LegendreSymbol> {
+        cfg_chunks!(elems, BATCH_POW_CHUNK_SIZE)
+            .flat_map(|chunk| {
+                batch_pow(chunk, Self::MODULUS_MINUS_ONE_DIV_TWO)
+                    .into_iter()
+                    .map(|s| {
+                        if s.is_zero() {
+                            LegendreSymbol::Zero
+                        } else if s.is_one() {
+                            LegendreSymbol::QuadraticResidue
+                        } else {
+                            LegendreSymbol::QuadraticNonResidue
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// Converts the integer of `len` bytes yielded by `bytes`, most significant
+/// byte first, to a field element, reducing it modulo `p`.
+pub(crate) fn from_be_bytes_iter_mod_order<F: PrimeField>(
+    len: usize,
+    mut bytes: impl Iterator<Item = u8>,
+) -> F {
+    let num_modulus_bytes = ((F::MODULUS_BIT_SIZE + 7) / 8) as usize;
+    // Copy the leading bytes directly into an integer. The number of bytes
+    // directly converted must be less than the number of bytes needed to
+    // represent the modulus, as we must begin modular reduction once the data
+    // is of the same number of bytes as the modulus.
+    let num_bytes_to_directly_convert = min(num_modulus_bytes - 1, len);
+    let mut repr = F::BigInt::default();
+    for (i, byte) in (0..num_bytes_to_directly_convert).rev().zip(&mut bytes) {
+        repr.as_mut()[i / 8] |= u64::from(byte) << (8 * (i % 8));
+    }
+    // Guaranteed to not be None, as the integer is less than the modulus.
+    let mut res = F::from_bigint(repr).unwrap();
+
+    // Update the result, byte by byte.
+    // We go through existing field arithmetic, which handles the reduction.
+    // TODO: If we need higher speeds, parse more bytes at once, or implement
+    // modular multiplication by a u64
+    let window_size = F::from(256u64);
+    for byte in bytes {
+        res *= window_size;
+        res += F::from(byte);
+    }
+    res
+}
+
+/// Writes the bytes of `repr`, least significant first, to `out`, padded with
+/// zeros.
+fn write_bytes_le<'a, F: PrimeField>(
+    out: impl ExactSizeIterator<Item = &'a mut u8>,
+    repr: F::BigInt,
+) {
+    assert!(
+        out.len() >= ((F::MODULUS_BIT_SIZE + 7) / 8) as usize,
+        "output buffer too short"
+    );
+    let bytes = repr.as_ref().iter().flat_map(|limb| limb.to_le_bytes());
+    for (o, byte) in out.zip(bytes.chain(ark_std::iter::repeat(0))) {
+        *o = byte;
+    }
+}
+
+/// The number of elements exponentiated together by
+/// [`PrimeField::batch_legendre`] and [`Field::batch_sqrt`]. This bounds the
+/// size of the window tables.
+pub(crate) const BATCH_POW_CHUNK_SIZE: usize = 1 << 10;
+
+/// The window size used by [`batch_pow`].
+const BATCH_POW_WINDOW_SIZE: usize = 4;
+
+/// Computes `base^exp` for every element of `bases`, processing all bases in
+/// lockstep with a fixed window over the bits of `exp`.
+pub(crate) fn batch_pow<F: Field>(bases: &[F], exp: impl AsRef<[u64]>) -> Vec<F> {
+    // `table[k][i] = bases[i]^k`.
+    let mut table = vec![vec![F::one(); bases.len()], bases.to_vec()];
+    for k in 2..1 << BATCH_POW_WINDOW_SIZE {
+        let mut next = table[k - 1].clone();
+        F::mul_batch(&mut next, bases);
+        table.push(next);
+    }
+
+    let bits: Vec<bool> = BitIteratorBE::without_leading_zeros(exp).collect();
+    let padding =
+        (BATCH_POW_WINDOW_SIZE - bits.len() % BATCH_POW_WINDOW_SIZE) % BATCH_POW_WINDOW_SIZE;
+    let bits: Vec<bool> = ark_std::iter::repeat(false)
+        .take(padding)
+        .chain(bits)
+        .collect();
+
+    let mut result = vec![F::one(); bases.len()];
+    for window in bits.chunks(BATCH_POW_WINDOW_SIZE) {
+        for r in &mut result {
+            for _ in 0..BATCH_POW_WINDOW_SIZE {
+                r.square_in_place();
+            }
+        }
+        let k = window.iter().fold(0, |k, &bit| (k << 1) | bit as usize);
+        if k != 0 {
+            F::mul_batch(&mut result, &table[k]);
         }
-        res
     }
+    result
 }
@@ -0,0 +1,137 @@
+use crate::{Field, One, Zero};
+
+/// Computes the absolute trace of `elem`; see [`Field::trace`].
+pub(crate) fn trace<F: Field>(elem: &F) -> F::BasePrimeField {
+    let mut conjugate = *elem;
+    let mut sum = *elem;
+    for _ in 1..F::extension_degree() {
+        conjugate.frobenius_map_in_place(1);
+        sum += &conjugate;
+    }
+    // The trace is fixed by the Frobenius map, so it lies in the prime field,
+    // and is the first coordinate of `sum`.
+    sum.to_base_prime_field_elements().next().unwrap()
+}
+
+/// Computes a root of `x^2 + b * x + c`; see [`Field::solve_quadratic`].
+pub(crate) fn solve_quadratic<F: Field>(b: &F, c: &F) -> Option<F> {
+    if F::characteristic() != [2] {
+        // x = (-b + sqrt(b^2 - 4c)) / 2
+        let discriminant = b.square() - c.double().double();
+        let root = discriminant.sqrt()?;
+        return Some((root - b) * F::ONE.double().inverse().unwrap());
+    }
+    match b.inverse() {
+        // x^2 = -c = c always has a solution, since squaring is a bijection.
+        None => c.sqrt(),
+        // Substituting `x = b * y` gives `y^2 + y = c / b^2`.
+        Some(b_inv) => solve_artin_schreier(&(*c * b_inv.square())).map(|y| y * b),
+    }
+}
+
+/// Computes a root of `y^2 + y = u` in characteristic two, which exists if and
+/// only if the trace of `u` is zero.
+///
+/// Given an element `delta` of trace one, the root is
+/// `sum_{i = 1}^{n - 1} (sum_{j = 0}^{i - 1} delta^(2^j)) * u^(2^i)`,
+/// where `n` is the extension degree; when `n` is odd, `delta = 1` and this
+/// is the half-trace of `u^2`. See Lidl and Niederreiter --- Finite Fields;
+/// Theorem 2.25.
+fn solve_artin_schreier<F: Field>(u: &F) -> Option<F> {
+    if !trace(u).is_zero() {
+        return None;
+    }
+    let delta = trace_one_element::<F>();
+    let mut root = F::zero();
+    let mut delta_sum = F::zero();
+    let mut delta_conjugate = delta;
+    let mut u_conjugate = *u;
+    for _ in 1..F::extension_degree() {
+        delta_sum += &delta_conjugate;
+        delta_conjugate.square_in_place();
+        u_conjugate.square_in_place();
+        root += delta_sum * &u_conjugate;
+    }
+    Some(root)
+}
+
+/// Returns an element of trace one in characteristic two.
+fn trace_one_element<F: Field>() -> F {
+    let degree = F::extension_degree() as usize;
+    if degree % 2 == 1 {
+        return F::one();
+    }
+    // The trace is a nonzero linear map, so some basis element has trace one.
+    (1..degree)
+        .map(|k| {
+            let coords = (0..degree).map(|i| F::BasePrimeField::from(i == k));
+            F::from_base_prime_field_elems(coords).unwrap()
+        })
+        .find(|basis_elem| trace(basis_elem).is_one())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AdditiveGroup, BinaryField128b, BinaryField16b, BinaryField1b, BinaryField2b,
+        BinaryField8b, Field, TernaryField1t, TernaryField2t, Zero,
+    };
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{
+        ark_ff::{AdditiveGroup as _, Field as _},
+        bls12_381::Fq2,
+    };
+
+    fn test_solve_quadratic<F: Field>() {
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let (x, y) = (F::rand(&mut rng), F::rand(&mut rng));
+            // (X - x) (X - y) = X^2 - (x + y) X + x y
+            let root = F::solve_quadratic(&-(x + y), &(x * y)).unwrap();
+            assert!(root == x || root == y);
+
+            let a = F::rand(&mut rng);
+            assert_eq!((a + a.frobenius_map(1)).trace(), a.trace().double());
+            let (b, c) = (F::rand(&mut rng), F::rand(&mut rng));
+            match F::solve_quadratic(&b, &c) {
+                Some(x) => assert!((x.square() + b * x + c).is_zero()),
+                None => assert!(F::solve_quadratic(&-b, &c).is_none()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_fields() {
+        test_solve_quadratic::<BinaryField1b>();
+        test_solve_quadratic::<BinaryField2b>();
+        test_solve_quadratic::<BinaryField8b>();
+        test_solve_quadratic::<BinaryField16b>();
+        test_solve_quadratic::<BinaryField128b>();
+
+        // `y^2 + y = u` has a root if and only if the trace of `u` is zero.
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let u = BinaryField8b::rand(&mut rng);
+            let root = BinaryField8b::solve_quadratic(&BinaryField8b::ONE, &u);
+            assert_eq!(root.is_some(), u.trace().is_zero());
+        }
+    }
+
+    #[test]
+    fn test_ternary_fields() {
+        test_solve_quadratic::<TernaryField1t>();
+        test_solve_quadratic::<TernaryField2t>();
+    }
+
+    #[test]
+    fn test_odd_characteristic() {
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let (x, y) = (Fq2::rand(&mut rng), Fq2::rand(&mut rng));
+            let root = Fq2::solve_quadratic(&-(x + y), &(x * y)).unwrap();
+            assert!(root == x || root == y);
+            assert_eq!(x.trace(), x.c0.double());
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::UniformRand;
+use ark_std::{
+    fmt,
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A wrapper around a secret value, such as a field element or a scalar used
+/// as a private key, that zeroizes the value when it is dropped.
+///
+/// Field elements and curve points are `Copy`, so they cannot zeroize
+/// themselves on drop; wrap them in a `SecretScalar` instead. The wrapped
+/// value is only accessible through [`expose_secret`](Self::expose_secret)
+/// and [`expose_secret_mut`](Self::expose_secret_mut), and is redacted from
+/// the `Debug` output.
+///
+/// Note that copies of the value made before wrapping it, or obtained by
+/// copying out of the wrapper, are not zeroized.
+///
+/// # Example
+/// ```
+/// # use ark_ff::SecretScalar;
+/// # use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::Fr;
+///
+/// let mut rng = ark_std::test_rng();
+/// let sk = SecretScalar::<Fr>::rand(&mut rng);
+/// let pk_exponent = *sk.expose_secret() + Fr::from(1u64);
+/// // `sk` is zeroized here.
+/// drop(sk);
+/// # let _ = pk_exponent;
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretScalar<F: Zeroize>(F);
+
+impl<F: Zeroize> SecretScalar<F> {
+    /// Wraps `value`.
+    pub fn new(value: F) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the secret value.
+    pub fn expose_secret(&self) -> &F {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret value.
+    pub fn expose_secret_mut(&mut self) -> &mut F {
+        &mut self.0
+    }
+}
+
+impl<F: Zeroize> From<F> for SecretScalar<F> {
+    fn from(value: F) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<F: Zeroize> Zeroize for SecretScalar<F> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<F: Zeroize> Drop for SecretScalar<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<F: Zeroize> ZeroizeOnDrop for SecretScalar<F> {}
+
+impl<F: Zeroize> fmt::Debug for SecretScalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretScalar([REDACTED])")
+    }
+}
+
+impl<F: Zeroize + UniformRand> Distribution<SecretScalar<F>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SecretScalar<F> {
+        SecretScalar::new(F::rand(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{format, test_rng};
+    use ark_test_curves::{ark_ff::Zero, bls12_381::Fr};
+
+    #[test]
+    fn test_secret_scalar() {
+        let mut rng = test_rng();
+        let value = Fr::rand(&mut rng);
+        let mut secret = SecretScalar::new(value);
+        assert_eq!(*secret.expose_secret(), value);
+        assert_eq!(format!("{:?}", secret), "SecretScalar([REDACTED])");
+
+        secret.zeroize();
+        assert!(secret.expose_secret().is_zero());
+    }
+}
@@ -1,3 +1,11 @@
+use crate::{
+    fields::prime::{batch_pow, BATCH_POW_CHUNK_SIZE},
+    PrimeField,
+};
+use ark_std::{cfg_chunks, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Indication of the field element's quadratic residuosity
 ///
 /// # Examples
@@ -75,10 +83,81 @@ pub enum SqrtPrecomputation<F: crate::Field> {
     Case3Mod4 {
         modulus_plus_one_div_four: &'static [u64],
     },
+    /// Cipolla's algorithm works for all elements, and its running time does
+    /// not depend on the two-adicity of the modulus. This makes it preferable
+    /// to Tonelli-Shanks for fields with a large two-adicity. The square root
+    /// it returns may be the negation of the one found by Tonelli-Shanks.
+    Cipolla {
+        modulus_plus_one_div_two: &'static [u64],
+    },
+    /// Tonelli-Shanks with precomputed tables, which recovers the discrete
+    /// logarithm of `elem^t` in the subgroup of order `2^two_adicity` a window
+    /// of `window_size` bits at a time, instead of one bit at a time.
+    /// This is emitted by `#[derive(MontConfig)]` for fields with a large
+    /// two-adicity.
+    ///
+    /// Let `g` be a generator of the subgroup of order `2^two_adicity`, and
+    /// `key(x)` be the lowest limb of the canonical representation of `x`.
+    /// Then the tables must satisfy
+    /// * `tables[i][d] = g^(-d * 2^(i * window_size))` for all
+    ///   `0 <= i < ceil(two_adicity / window_size)` and `0 <= d < 2^window_size`.
+    /// * `discrete_log_table` contains the pairs
+    ///   `(key(g^(d * 2^(two_adicity - window_size))), d)` for all
+    ///   `0 <= d < 2^window_size`, sorted by key, and the keys are distinct.
+    ///
+    /// `two_adicity` can be at most 64. The square roots are the same as
+    /// those found by [`Self::TonelliShanks`].
+    TonelliShanksWithTables {
+        two_adicity: u32,
+        window_size: u32,
+        trace_of_modulus_minus_one_div_two: &'static [u64],
+        tables: &'static [&'static [F]],
+        discrete_log_table: &'static [(u64, u32)],
+    },
 }
 
 impl<F: crate::Field> SqrtPrecomputation<F> {
     pub fn sqrt(&self, elem: &F) -> Option<F> {
+        self.sqrt_with(elem, |exp| elem.pow(exp))
+    }
+
+    /// Returns the square roots of the elements of `elems`, as computed by
+    /// [`Self::sqrt`].
+    ///
+    /// Except for Cipolla's algorithm, every method starts with an
+    /// exponentiation by a fixed exponent, which is the most expensive step.
+    /// These exponentiations are performed in lockstep using a fixed window,
+    /// as in [`PrimeField::batch_legendre`], and with the `parallel` feature,
+    /// chunks of `elems` are processed in parallel.
+    pub fn batch_sqrt(&self, elems: &[F]) -> Vec<Option<F>> {
+        let exp = match self {
+            Self::TonelliShanks {
+                trace_of_modulus_minus_one_div_two,
+                ..
+            }
+            | Self::TonelliShanksWithTables {
+                trace_of_modulus_minus_one_div_two,
+                ..
+            } => *trace_of_modulus_minus_one_div_two,
+            Self::Case3Mod4 {
+                modulus_plus_one_div_four,
+            } => *modulus_plus_one_div_four,
+            Self::Cipolla { .. } => return elems.iter().map(|elem| self.sqrt(elem)).collect(),
+        };
+        cfg_chunks!(elems, BATCH_POW_CHUNK_SIZE)
+            .flat_map(|chunk| {
+                chunk
+                    .iter()
+                    .zip(batch_pow(chunk, exp))
+                    .map(|(elem, power)| self.sqrt_with(elem, |_| power))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Computes the square root of `elem`, where `pow(exp)` must return
+    /// `elem^exp`.
+    fn sqrt_with(&self, elem: &F, pow: impl FnOnce(&[u64]) -> F) -> Option<F> {
         match self {
             Self::TonelliShanks {
                 two_adicity,
@@ -96,7 +175,7 @@ impl<F: crate::Field> SqrtPrecomputation<F> {
                 // Check at the end of the algorithm if x was a square root
                 // Begin Tonelli-Shanks
                 let mut z = *quadratic_nonresidue_to_trace;
-                let mut w = elem.pow(trace_of_modulus_minus_one_div_two);
+                let mut w = pow(trace_of_modulus_minus_one_div_two);
                 let mut x = w * elem;
                 let mut b = x * &w;
 
@@ -141,9 +220,109 @@ impl<F: crate::Field> SqrtPrecomputation<F> {
             Self::Case3Mod4 {
                 modulus_plus_one_div_four,
             } => {
-                let result = elem.pow(modulus_plus_one_div_four.as_ref());
+                let result = pow(modulus_plus_one_div_four);
                 (result.square() == *elem).then_some(result)
             },
+            Self::Cipolla {
+                modulus_plus_one_div_two,
+            } => {
+                if elem.is_zero() {
+                    return Some(F::zero());
+                }
+                if !elem.legendre().is_qr() {
+                    return None;
+                }
+                // Find `a` such that `a^2 - elem` is a quadratic non-residue. Half of
+                // all candidates work, so this takes two attempts on average.
+                let mut a = F::one();
+                let mut nonresidue = a.square() - elem;
+                loop {
+                    match nonresidue.legendre() {
+                        LegendreSymbol::QuadraticNonResidue => break,
+                        // `a` is itself a square root of `elem`.
+                        LegendreSymbol::Zero => return Some(a),
+                        LegendreSymbol::QuadraticResidue => {
+                            a += F::one();
+                            nonresidue = a.square() - elem;
+                        },
+                    }
+                }
+
+                // Compute `(a + w)^((p + 1) / 2)` in `F[w] / (w^2 - nonresidue)`, whose
+                // `w`-coefficient is zero.
+                let mut x = (F::one(), F::zero());
+                for bit in crate::BitIteratorBE::without_leading_zeros(modulus_plus_one_div_two) {
+                    let cross = x.0 * x.1;
+                    x = (x.0.square() + x.1.square() * nonresidue, cross + cross);
+                    if bit {
+                        x = (x.0 * a + x.1 * nonresidue, x.0 + x.1 * a);
+                    }
+                }
+                debug_assert!(x.1.is_zero());
+                Some(x.0)
+            },
+            Self::TonelliShanksWithTables {
+                two_adicity,
+                window_size,
+                trace_of_modulus_minus_one_div_two,
+                tables,
+                discrete_log_table,
+            } => {
+                if elem.is_zero() {
+                    return Some(F::zero());
+                }
+                let (s, w) = (*two_adicity, *window_size);
+                let num_windows = s.div_ceil(w);
+                let mask = (1u64 << w) - 1;
+
+                // `x = elem^((t + 1) / 2)` and `a = elem^t = g^e` for some `e`; if
+                // `elem` is a square, then `e` is even and `x * g^(-e / 2)` is a
+                // square root of `elem`.
+                let v = pow(trace_of_modulus_minus_one_div_two);
+                let x = v * elem;
+                let mut a = x * v;
+
+                // Recover `e` from its least significant window upwards. After the
+                // windows below `pos` are known, `a = g^(e')`, where `e'` is `e`
+                // with these windows cleared, so raising `a` to `2^(s - w - pos)`
+                // gives an element of order dividing `2^w` whose discrete
+                // logarithm is the window of `e'` starting at `pos`. The last
+                // window is aligned to the top, and overlaps with known bits.
+                let mut e = 0u64;
+                for i in 0..num_windows {
+                    let pos = if i + 1 == num_windows { s - w } else { i * w };
+                    let mut y = a;
+                    for _ in 0..s - w - pos {
+                        y.square_in_place();
+                    }
+                    let key = y
+                        .to_base_prime_field_elements()
+                        .next()?
+                        .into_bigint()
+                        .as_ref()[0];
+                    let index = discrete_log_table
+                        .binary_search_by_key(&key, |&(k, _)| k)
+                        .ok()?;
+                    let digit = discrete_log_table[index].1 as u64;
+                    if i + 1 < num_windows {
+                        a *= tables[i as usize][digit as usize];
+                    }
+                    e += digit << pos;
+                }
+
+                if e & 1 == 1 {
+                    return None;
+                }
+                let half_e = e >> 1;
+                let root = tables.iter().enumerate().fold(x, |root, (i, table)| {
+                    root * table[((half_e >> (i as u32 * w)) & mask) as usize]
+                });
+                debug_assert_eq!(root.square(), *elem);
+                // `root = x * g^(2^s - e / 2)`, while Tonelli-Shanks multiplies `x`
+                // by `g^(2^(s - 1) - e / 2)` unless `e` is zero. The two differ by
+                // `g^(2^(s - 1)) = -1`, and we return the same root.
+                Some(if half_e == 0 { root } else { -root })
+            },
         }
     }
 }
@@ -1,3 +1,5 @@
+use ark_std::vec::*;
+
 /// Calculates the k-adicity of n, i.e., the number of trailing 0s in a base-k
 /// representation.
 pub fn k_adicity(k: u64, mut n: u64) -> u32 {
@@ -12,3 +14,38 @@ pub fn k_adicity(k: u64, mut n: u64) -> u32 {
     }
     r
 }
+
+/// Returns the `size` bits of the integer `limbs` (least significant limb
+/// first) starting at bit `offset`, where `size < 64`.
+pub(crate) fn bits_window(limbs: &[u64], offset: usize, size: usize) -> usize {
+    let (limb, shift) = (offset / 64, offset % 64);
+    let mut window = limbs.get(limb).map_or(0, |l| l >> shift);
+    if shift + size > 64 {
+        window |= limbs.get(limb + 1).map_or(0, |l| l << (64 - shift));
+    }
+    (window & ((1 << size) - 1)) as usize
+}
+
+/// Splits `s` at every occurrence of `sep` that is not enclosed in
+/// parentheses, e.g. to parse the `Display` output of extension fields.
+pub(crate) fn split_top_level<'a>(s: &'a str, sep: &str) -> Vec<&'a str> {
+    let bytes = s.as_bytes();
+    let mut parts = Vec::new();
+    let (mut depth, mut start, mut i) = (0usize, 0, 0);
+    while i < bytes.len() {
+        if depth == 0 && bytes[i..].starts_with(sep.as_bytes()) {
+            parts.push(&s[start..i]);
+            i += sep.len();
+            start = i;
+            continue;
+        }
+        match bytes[i] {
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            _ => {},
+        }
+        i += 1;
+    }
+    parts.push(&s[start..]);
+    parts
+}
@@ -37,11 +37,30 @@ pub use ark_std::UniformRand;
 mod to_field_vec;
 pub use to_field_vec::ToConstraintField;
 
+pub mod thread_pool;
+
+pub mod vec_ops;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(feature = "num-traits-impls")]
+mod num_traits_impls;
+
 #[doc(hidden)]
 pub use ark_ff_asm::*;
 #[doc(hidden)]
 pub use ark_std::vec;
 
+#[cfg(feature = "ct")]
+pub use subtle;
+
 pub mod prelude {
     pub use crate::{
         biginteger::BigInteger,
@@ -0,0 +1,160 @@
+//! [`num_traits`] support for field elements, enabled by the
+//! `num-traits-impls` feature.
+//!
+//! Together with [`Zero`] and [`One`], which are always implemented, this
+//! implements [`Pow`], [`Inv`] and [`Num`] for prime fields and their
+//! extensions, so that they can be used with generic numeric code.
+//!
+//! Note that when [`Pow`] is in scope, `a.pow(exp)` resolves to [`Pow::pow`]
+//! rather than to [`Field::pow`].
+//!
+//! # Example
+//! ```
+//! # use ark_test_curves::bls12_381::Fq2;
+//! use num_traits::{Inv, Num, One, Pow};
+//!
+//! fn horner<T: Num + Copy>(coeffs: &[T], x: T) -> T {
+//!     coeffs.iter().rev().fold(T::zero(), |acc, &c| acc * x + c)
+//! }
+//!
+//! let x = Fq2::from_str_radix("ff", 16).unwrap();
+//! assert_eq!(x, Fq2::from(255u64));
+//! assert_eq!(horner(&[Fq2::one(), Fq2::one()], x), x + Fq2::one());
+//! assert_eq!(x.inv() * x, Fq2::one());
+//! assert_eq!(Pow::pow(x, 3u64), x * x * x);
+//! ```
+use crate::{
+    CubicExtConfig, CubicExtField, Field, Fp, Fp32, Fp32Config, FpConfig, PrimeField,
+    QuadExtConfig, QuadExtField,
+};
+use ark_std::ops::Rem;
+use num_bigint::BigUint;
+use num_traits::{Inv, Num, Pow, Zero};
+
+/// Parses an integer in base `radix`, optionally preceded by a `-` sign, as
+/// an element of `F`. Returns an error if the absolute value of the integer
+/// is not smaller than the modulus.
+fn prime_field_from_str_radix<F: PrimeField>(s: &str, radix: u32) -> Result<F, ()> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let int = BigUint::from_str_radix(s, radix).map_err(|_| ())?;
+    if int >= F::MODULUS.into() {
+        return Err(());
+    }
+    let a = F::from(int);
+    Ok(if negative { -a } else { a })
+}
+
+macro_rules! impl_num_traits {
+    ([$($generics:tt)*] $field:ty) => {
+        impl<$($generics)*> Pow<u64> for $field {
+            type Output = Self;
+
+            fn pow(self, exp: u64) -> Self {
+                Field::pow(&self, [exp])
+            }
+        }
+
+        impl<$($generics)*> Pow<u32> for $field {
+            type Output = Self;
+
+            fn pow(self, exp: u32) -> Self {
+                Field::pow(&self, [u64::from(exp)])
+            }
+        }
+
+        impl<$($generics)*> Pow<usize> for $field {
+            type Output = Self;
+
+            fn pow(self, exp: usize) -> Self {
+                Field::pow(&self, [exp as u64])
+            }
+        }
+
+        impl<$($generics)*> Inv for $field {
+            type Output = Self;
+
+            /// Returns the inverse of `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is zero.
+            fn inv(self) -> Self {
+                self.inverse().expect("division by zero")
+            }
+        }
+
+        impl<$($generics)*> Rem for $field {
+            type Output = Self;
+
+            /// Returns zero, since every nonzero element divides every element.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `other` is zero.
+            fn rem(self, other: Self) -> Self {
+                assert!(!other.is_zero(), "division by zero");
+                Self::zero()
+            }
+        }
+
+        impl<$($generics)*> Num for $field {
+            type FromStrRadixErr = ();
+
+            /// Parses an integer in base `radix`, optionally preceded by a
+            /// `-` sign, as an element of the base prime field. Returns an
+            /// error if the absolute value of the integer is not smaller than
+            /// the modulus.
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, ()> {
+                prime_field_from_str_radix(s, radix).map(Self::from_base_prime_field)
+            }
+        }
+    };
+}
+
+impl_num_traits!([P: FpConfig<N>, const N: usize] Fp<P, N>);
+impl_num_traits!([P: Fp32Config] Fp32<P>);
+impl_num_traits!([P: QuadExtConfig] QuadExtField<P>);
+impl_num_traits!([P: CubicExtConfig] CubicExtField<P>);
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{
+        ark_ff::{Field, PrimeField},
+        bls12_381::{Fq, Fq12, Fr},
+    };
+    use num_traits::{Inv, Num, One, Pow, Zero};
+
+    #[test]
+    fn test_num_traits() {
+        let rng = &mut test_rng();
+        let a = Fr::rand(rng);
+        assert_eq!(Pow::pow(a, 5u32), Field::pow(&a, [5]));
+        assert_eq!(Pow::pow(a, 0usize), Fr::one());
+        assert_eq!(a.inv(), a.inverse().unwrap());
+        assert_eq!(a % a, Fr::zero());
+
+        assert_eq!(Fr::from_str_radix("-11", 2).unwrap(), -Fr::from(3u64));
+        assert_eq!(Fr::from_str_radix("zz", 36).unwrap(), Fr::from(1295u64));
+        let modulus = Fr::MODULUS.to_string();
+        assert!(Fr::from_str_radix(&modulus, 10).is_err());
+        assert!(Fr::from_str_radix("12", 2).is_err());
+
+        let b = Fq12::rand(rng);
+        assert_eq!(Pow::pow(b, 3u64), b * b * b);
+        assert_eq!(b.inv() * b, Fq12::one());
+        assert_eq!(
+            Fq12::from_str_radix("7", 10).unwrap(),
+            Fq12::from_base_prime_field(Fq::from(7u64))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_zero() {
+        let _ = Fr::zero().inv();
+    }
+}
@@ -0,0 +1,292 @@
+//! [`serde`] support for big integers and field elements, enabled by the
+//! `serde` feature.
+//!
+//! Human-readable formats (such as JSON) represent big integers and prime
+//! field elements as decimal strings, and accept both decimal and
+//! `0x`-prefixed hexadecimal strings when deserializing. Elements of
+//! extension fields are represented as tuples of their coefficients.
+//!
+//! Binary formats use the compressed canonical byte representation given by
+//! [`CanonicalSerialize`], and check that deserialized values are valid.
+use crate::{
+    BigInt, CubicExtConfig, CubicExtField, Fp, Fp32, Fp32Config, FpConfig, QuadExtConfig,
+    QuadExtField,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, vec::*};
+use serde::{
+    de::{self, Error as _, SeqAccess, Visitor},
+    ser::Error as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes `value` as its compressed canonical byte representation.
+fn serialize_canonical<T: CanonicalSerialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(S::Error::custom)?;
+    serializer.serialize_bytes(&bytes)
+}
+
+/// Deserializes a value from its compressed canonical byte representation,
+/// checking that it is valid.
+fn deserialize_canonical<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a byte array")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+
+    let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+    T::deserialize_compressed(&bytes[..]).map_err(D::Error::custom)
+}
+
+/// Deserializes a string, and parses it with `parse`.
+fn deserialize_str<'de, T, D: Deserializer<'de>>(
+    deserializer: D,
+    expected: &'static str,
+    parse: fn(&str) -> Option<T>,
+) -> Result<T, D::Error> {
+    struct StrVisitor<T> {
+        expected: &'static str,
+        parse: fn(&str) -> Option<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for StrVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.expected)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            (self.parse)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+
+    deserializer.deserialize_str(StrVisitor { expected, parse })
+}
+
+impl<const N: usize> Serialize for BigInt<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for BigInt<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str(
+                deserializer,
+                "a decimal or hexadecimal integer that fits in the limbs",
+                |s| s.parse().ok(),
+            )
+        } else {
+            deserialize_canonical(deserializer)
+        }
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Serialize for Fp<P, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, P: FpConfig<N>, const N: usize> Deserialize<'de> for Fp<P, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str(
+                deserializer,
+                "a decimal or hexadecimal integer smaller than the modulus",
+                |s| s.parse().ok(),
+            )
+        } else {
+            deserialize_canonical(deserializer)
+        }
+    }
+}
+
+impl<P: Fp32Config> Serialize for Fp32<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, P: Fp32Config> Deserialize<'de> for Fp32<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str(
+                deserializer,
+                "a decimal or hexadecimal integer smaller than the modulus",
+                |s| s.parse().ok(),
+            )
+        } else {
+            deserialize_canonical(deserializer)
+        }
+    }
+}
+
+impl<P: QuadExtConfig> Serialize for QuadExtField<P>
+where
+    P::BaseField: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            (&self.c0, &self.c1).serialize(serializer)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, P: QuadExtConfig> Deserialize<'de> for QuadExtField<P>
+where
+    P::BaseField: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let (c0, c1) = Deserialize::deserialize(deserializer)?;
+            Ok(Self::new(c0, c1))
+        } else {
+            deserialize_canonical(deserializer)
+        }
+    }
+}
+
+impl<P: CubicExtConfig> Serialize for CubicExtField<P>
+where
+    P::BaseField: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            (&self.c0, &self.c1, &self.c2).serialize(serializer)
+        } else {
+            serialize_canonical(self, serializer)
+        }
+    }
+}
+
+impl<'de, P: CubicExtConfig> Deserialize<'de> for CubicExtField<P>
+where
+    P::BaseField: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let (c0, c1, c2) = Deserialize::deserialize(deserializer)?;
+            Ok(Self::new(c0, c1, c2))
+        } else {
+            deserialize_canonical(deserializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{format, test_rng, vec::Vec, UniformRand};
+    use ark_test_curves::{
+        ark_ff::{BigInt, BigInteger, MontFp, PrimeField},
+        bls12_381::{Fq, Fq12, Fq2, Fr},
+    };
+
+    #[test]
+    fn test_json() {
+        let rng = &mut test_rng();
+        let a = Fr::rand(rng);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, format!("\"{a}\""));
+        assert_eq!(serde_json::from_str::<Fr>(&json).unwrap(), a);
+
+        let three: Fq = MontFp!("3");
+        assert_eq!(serde_json::from_str::<Fq>("\"0x3\"").unwrap(), three);
+        let modulus = format!("\"{}\"", Fr::MODULUS);
+        assert!(serde_json::from_str::<Fr>(&modulus).is_err());
+        assert!(serde_json::from_str::<Fr>("\"abc\"").is_err());
+
+        let b = Fq2::rand(rng);
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(json, format!("[\"{}\",\"{}\"]", b.c0, b.c1));
+        assert_eq!(serde_json::from_str::<Fq2>(&json).unwrap(), b);
+
+        let c = Fq12::rand(rng);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(serde_json::from_str::<Fq12>(&json).unwrap(), c);
+
+        let n = BigInt::<2>([5, 1]);
+        let json = serde_json::to_string(&n).unwrap();
+        assert_eq!(json, "\"18446744073709551621\"");
+        assert_eq!(serde_json::from_str::<BigInt<2>>(&json).unwrap(), n);
+        assert_eq!(
+            serde_json::from_str::<BigInt<2>>("\"0x10000000000000005\"").unwrap(),
+            n
+        );
+    }
+    #[test]
+    fn test_bincode() {
+        let rng = &mut test_rng();
+        let a = Fr::rand(rng);
+        let bytes = bincode::serialize(&a).unwrap();
+        let mut compressed = Vec::new();
+        a.serialize_compressed(&mut compressed).unwrap();
+        // bincode prefixes the byte array with its length as a `u64`.
+        assert_eq!(bytes[..8], (compressed.len() as u64).to_le_bytes());
+        assert_eq!(bytes[8..], compressed[..]);
+        assert_eq!(bincode::deserialize::<Fr>(&bytes).unwrap(), a);
+
+        let mut modulus = bytes[..8].to_vec();
+        modulus.extend(Fr::MODULUS.to_bytes_le());
+        assert!(bincode::deserialize::<Fr>(&modulus).is_err());
+        assert!(bincode::deserialize::<Fr>(&bytes[..bytes.len() - 1]).is_err());
+
+        let b = Fq2::rand(rng);
+        let bytes = bincode::serialize(&b).unwrap();
+        assert_eq!(bincode::deserialize::<Fq2>(&bytes).unwrap(), b);
+
+        let c = Fq12::rand(rng);
+        let bytes = bincode::serialize(&c).unwrap();
+        assert_eq!(bincode::deserialize::<Fq12>(&bytes).unwrap(), c);
+
+        let n = BigInt::<2>([5, 1]);
+        let bytes = bincode::serialize(&n).unwrap();
+        assert_eq!(bincode::deserialize::<BigInt<2>>(&bytes).unwrap(), n);
+    }
+}
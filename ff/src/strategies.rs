@@ -0,0 +1,170 @@
+//! [`proptest`] strategies for big integers and field elements, enabled by the
+//! `proptest` feature.
+//!
+//! The strategies generate boundary values, such as zero, one and minus one,
+//! with higher probability than uniformly random values. They also back the
+//! [`Arbitrary`] implementations, so that e.g. `any::<Fp<P, N>>()` can be
+//! used directly.
+//!
+//! # Example
+//! ```
+//! # use ark_ff::{strategies, PrimeField};
+//! # use ark_test_curves::bls12_381::{Fq, Fq2};
+//! # use proptest::prelude::*;
+//! proptest! {
+//!     #[test]
+//!     fn test_distributive(a in strategies::field::<Fq2>(), b: Fq2, c: Fq2) {
+//!         prop_assert_eq!(a * (b + c), a * b + a * c);
+//!     }
+//!
+//!     #[test]
+//!     fn test_out_of_range(repr in strategies::out_of_range::<Fq>()) {
+//!         prop_assert!(Fq::from_bigint(repr).is_none());
+//!     }
+//! }
+//! # test_distributive();
+//! # test_out_of_range();
+//! ```
+use crate::{
+    BigInt, BigInteger, CubicExtConfig, CubicExtField, Field, Fp, FpConfig, PrimeField,
+    QuadExtConfig, QuadExtField,
+};
+use proptest::{
+    arbitrary::Arbitrary,
+    collection::vec,
+    prelude::{any, BoxedStrategy, Just, Strategy},
+    prop_oneof,
+};
+
+/// Returns a strategy for big integers, which generates zero, one and the
+/// largest integer with higher probability.
+pub fn big_int<const N: usize>() -> impl Strategy<Value = BigInt<N>> {
+    prop_oneof![
+        1 => Just(BigInt::zero()),
+        1 => Just(BigInt::one()),
+        1 => Just(BigInt([u64::MAX; N])),
+        7 => vec(any::<u64>(), N).prop_map(|limbs| BigInt(limbs.try_into().unwrap())),
+    ]
+}
+
+/// Returns a strategy for elements of the prime field `F`, which generates
+/// zero, one and minus one with higher probability.
+pub fn prime_field<F: PrimeField>() -> impl Strategy<Value = F> {
+    // 128 extra bits make the reduction statistically uniform.
+    let num_bytes = F::MODULUS_BIT_SIZE as usize / 8 + 17;
+    prop_oneof![
+        1 => Just(F::zero()),
+        1 => Just(F::one()),
+        1 => Just(-F::one()),
+        7 => vec(any::<u8>(), num_bytes).prop_map(|bytes| F::from_le_bytes_mod_order(&bytes)),
+    ]
+}
+
+/// Returns a strategy for elements of the field `F`, whose coefficients over
+/// the base prime field are generated by [`prime_field`]. Zero and one are
+/// also generated with higher probability.
+pub fn field<F: Field>() -> impl Strategy<Value = F> {
+    let degree = F::extension_degree() as usize;
+    prop_oneof![
+        1 => Just(F::zero()),
+        1 => Just(F::one()),
+        8 => vec(prime_field::<F::BasePrimeField>(), degree)
+            .prop_map(|elems| F::from_base_prime_field_elems(elems).unwrap()),
+    ]
+}
+
+/// Returns a strategy for integers that are not smaller than the modulus of
+/// `F`, which must thus be rejected by [`PrimeField::from_bigint`] and by
+/// deserialization. It generates the modulus itself and the integers just
+/// above it with higher probability.
+pub fn out_of_range<F: PrimeField>() -> impl Strategy<Value = F::BigInt> {
+    prop_oneof![
+        1 => Just(0u64),
+        3 => 1u64..256,
+        6 => any::<u64>(),
+    ]
+    .prop_map(|offset| {
+        // Fall back to the modulus if `MODULUS + offset` overflows.
+        let mut repr = F::MODULUS;
+        if repr.add_with_carry(&offset.into()) {
+            F::MODULUS
+        } else {
+            repr
+        }
+    })
+}
+
+impl<const N: usize> Arbitrary for BigInt<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        big_int().boxed()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Arbitrary for Fp<P, N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prime_field().boxed()
+    }
+}
+
+impl<P: QuadExtConfig> Arbitrary for QuadExtField<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        field().boxed()
+    }
+}
+
+impl<P: CubicExtConfig> Arbitrary for CubicExtField<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        field().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::vec::*;
+    use ark_test_curves::{
+        ark_ff::{strategies, BigInt, BigInteger, PrimeField},
+        bls12_381::{Fq, Fq12, Fr},
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(a: Fq12) {
+            let mut bytes = Vec::new();
+            a.serialize_compressed(&mut bytes).unwrap();
+            prop_assert_eq!(Fq12::deserialize_compressed(&bytes[..]).unwrap(), a);
+        }
+
+        #[test]
+        fn test_bigint_roundtrip(n: BigInt<4>) {
+            prop_assert_eq!(BigInt::<4>::from_bits_le(&n.to_bits_le()), n);
+        }
+
+        #[test]
+        fn test_out_of_range(repr in strategies::out_of_range::<Fr>()) {
+            prop_assert!(repr >= Fr::MODULUS);
+            prop_assert!(Fr::from_bigint(repr).is_none());
+            let mut bytes = Vec::new();
+            repr.serialize_compressed(&mut bytes).unwrap();
+            prop_assert!(Fr::deserialize_compressed(&bytes[..]).is_err());
+        }
+
+        #[test]
+        fn test_prime_field(a in strategies::prime_field::<Fq>()) {
+            prop_assert!(a.into_bigint() < Fq::MODULUS);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! The thread pool in which the parallel kernels of arkworks run.
+//!
+//! With the `parallel` feature, kernels such as multi-scalar
+//! multiplications, batch normalization of curve points, and FFTs run in
+//! rayon's global thread pool by default. There are two ways to run them in
+//! a different pool, e.g. to isolate concurrent proofs from each other, or
+//! to bound the parallelism of each one:
+//!
+//! - A computation that is run with [`rayon::ThreadPool::install`] runs all
+//!   of its kernels in that pool.
+//! - Kernels that are not run in a specific pool run in the pool installed
+//!   with [`set_thread_pool`], if any.
+//!
+//! Without the `parallel` feature, kernels run on the calling thread.
+
+#[cfg(feature = "parallel")]
+use std::sync::{Arc, RwLock};
+
+#[cfg(feature = "parallel")]
+static THREAD_POOL: RwLock<Option<Arc<rayon::ThreadPool>>> = RwLock::new(None);
+
+/// Installs `pool` as the thread pool of the kernels that are not run in a
+/// specific pool, or restores rayon's global pool if `pool` is `None`.
+/// Returns the previously installed pool.
+#[cfg(feature = "parallel")]
+pub fn set_thread_pool(pool: Option<Arc<rayon::ThreadPool>>) -> Option<Arc<rayon::ThreadPool>> {
+    core::mem::replace(&mut *THREAD_POOL.write().unwrap(), pool)
+}
+
+/// Returns the pool installed with [`set_thread_pool`], if any.
+#[cfg(feature = "parallel")]
+pub fn thread_pool() -> Option<Arc<rayon::ThreadPool>> {
+    THREAD_POOL.read().unwrap().clone()
+}
+
+/// Runs the kernel `f` in the pool installed with [`set_thread_pool`], unless
+/// it is already running in a thread pool, or no pool is installed.
+#[inline]
+pub fn in_thread_pool<R: Send>(f: impl FnOnce() -> R + Send) -> R {
+    #[cfg(feature = "parallel")]
+    if rayon::current_thread_index().is_none() {
+        if let Some(pool) = thread_pool() {
+            return pool.install(f);
+        }
+    }
+    f()
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_pool() {
+        let pool = |threads| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            Arc::new(pool)
+        };
+        let previous = set_thread_pool(Some(pool(3)));
+        assert_eq!(in_thread_pool(rayon::current_num_threads), 3);
+        // A pool that is passed explicitly takes precedence.
+        let explicit = pool(2);
+        assert_eq!(
+            explicit.install(|| in_thread_pool(rayon::current_num_threads)),
+            2
+        );
+        assert!(set_thread_pool(previous).is_some());
+    }
+}
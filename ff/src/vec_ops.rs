@@ -0,0 +1,160 @@
+//! Elementwise operations on vectors of field elements.
+//!
+//! With the `parallel` feature, the operations split their inputs into
+//! chunks of [`CHUNK_SIZE`] elements, which are processed in parallel.
+//! Binary operations panic if their operands have different lengths.
+//!
+//! # Example
+//! ```
+//! # use ark_ff::vec_ops;
+//! # use ark_test_curves::bls12_381::Fr;
+//! let a = [Fr::from(1u64), Fr::from(2u64)];
+//! let b = [Fr::from(3u64), Fr::from(4u64)];
+//! let mut c = a;
+//! // c = a + 2 * b
+//! vec_ops::add_scaled(&mut c, &b, &Fr::from(2u64));
+//! assert_eq!(c, [Fr::from(7u64), Fr::from(10u64)]);
+//! assert_eq!(vec_ops::inner_product(&a, &b), Fr::from(11u64));
+//! ```
+use crate::Field;
+use ark_std::{cfg_chunks, cfg_chunks_mut, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of elements in each chunk that is processed by a single
+/// thread. Chunks are large enough that the overhead of spawning tasks is
+/// negligible compared to the arithmetic.
+pub const CHUNK_SIZE: usize = 1 << 12;
+
+/// Sets `a[i] += b[i]` for all `i`.
+pub fn add_assign<F: Field>(a: &mut [F], b: &[F]) {
+    zip_with(a, b, |a, b| a.iter_mut().zip(b).for_each(|(a, b)| *a += b));
+}
+
+/// Sets `a[i] -= b[i]` for all `i`.
+pub fn sub_assign<F: Field>(a: &mut [F], b: &[F]) {
+    zip_with(a, b, |a, b| a.iter_mut().zip(b).for_each(|(a, b)| *a -= b));
+}
+
+/// Sets `a[i] *= b[i]` for all `i`.
+pub fn mul_assign<F: Field>(a: &mut [F], b: &[F]) {
+    zip_with(a, b, F::mul_batch);
+}
+
+/// Sets `a[i] *= c` for all `i`.
+pub fn scale<F: Field>(a: &mut [F], c: &F) {
+    cfg_chunks_mut!(a, CHUNK_SIZE).for_each(|a| a.iter_mut().for_each(|a| *a *= c));
+}
+
+/// Sets `a[i] += b[i] * c` for all `i`.
+pub fn add_scaled<F: Field>(a: &mut [F], b: &[F], c: &F) {
+    zip_with(a, b, |a, b| {
+        a.iter_mut().zip(b).for_each(|(a, b)| *a += *b * c)
+    });
+}
+
+/// Sets `a[i] += b[i] * c[i]` for all `i`.
+pub fn mul_add_assign<F: Field>(a: &mut [F], b: &[F], c: &[F]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), c.len());
+    cfg_chunks_mut!(a, CHUNK_SIZE)
+        .zip(cfg_chunks!(b, CHUNK_SIZE))
+        .zip(cfg_chunks!(c, CHUNK_SIZE))
+        .for_each(|((a, b), c)| {
+            for ((a, b), c) in a.iter_mut().zip(b).zip(c) {
+                *a += *b * c;
+            }
+        });
+}
+
+/// Returns `a[i] + b[i]` for all `i`.
+pub fn add<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut res = a.to_vec();
+    add_assign(&mut res, b);
+    res
+}
+
+/// Returns `a[i] - b[i]` for all `i`.
+pub fn sub<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut res = a.to_vec();
+    sub_assign(&mut res, b);
+    res
+}
+
+/// Returns `a[i] * b[i]` for all `i`.
+pub fn mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut res = a.to_vec();
+    mul_assign(&mut res, b);
+    res
+}
+
+/// Returns `sum([a_i * b_i])`, using [`Field::dot_product`] on each chunk.
+pub fn inner_product<F: Field>(a: &[F], b: &[F]) -> F {
+    assert_eq!(a.len(), b.len());
+    cfg_chunks!(a, CHUNK_SIZE)
+        .zip(cfg_chunks!(b, CHUNK_SIZE))
+        .map(|(a, b)| F::dot_product(a, b))
+        .sum()
+}
+
+/// Applies `f` to corresponding chunks of `a` and `b`.
+fn zip_with<F: Field>(a: &mut [F], b: &[F], f: impl Fn(&mut [F], &[F]) + Send + Sync) {
+    assert_eq!(a.len(), b.len());
+    cfg_chunks_mut!(a, CHUNK_SIZE)
+        .zip(cfg_chunks!(b, CHUNK_SIZE))
+        .for_each(|(a, b)| f(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, vec::*, UniformRand};
+    use ark_test_curves::{
+        ark_ff::{vec_ops::*, Field},
+        bls12_381::{Fq2, Fr},
+    };
+
+    fn check_vec_ops<F: Field>() {
+        let rng = &mut test_rng();
+        // Cover several chunks, the last of which is partial.
+        let n = 3 * CHUNK_SIZE + 5;
+        let a: Vec<F> = (0..n).map(|_| F::rand(rng)).collect();
+        let b: Vec<F> = (0..n).map(|_| F::rand(rng)).collect();
+        let c: Vec<F> = (0..n).map(|_| F::rand(rng)).collect();
+        let s = F::rand(rng);
+
+        let sum = add(&a, &b);
+        let diff = sub(&a, &b);
+        let prod = mul(&a, &b);
+        let mut scaled = a.clone();
+        scale(&mut scaled, &s);
+        let mut axpy = a.clone();
+        add_scaled(&mut axpy, &b, &s);
+        let mut fma = a.clone();
+        mul_add_assign(&mut fma, &b, &c);
+        for i in 0..n {
+            assert_eq!(sum[i], a[i] + b[i]);
+            assert_eq!(diff[i], a[i] - b[i]);
+            assert_eq!(prod[i], a[i] * b[i]);
+            assert_eq!(scaled[i], a[i] * s);
+            assert_eq!(axpy[i], a[i] + b[i] * s);
+            assert_eq!(fma[i], a[i] + b[i] * c[i]);
+        }
+
+        let expected: F = a.iter().zip(&b).map(|(a, b)| *a * b).sum();
+        assert_eq!(inner_product(&a, &b), expected);
+        assert_eq!(inner_product::<F>(&[], &[]), F::ZERO);
+    }
+
+    #[test]
+    fn test_vec_ops() {
+        check_vec_ops::<Fr>();
+        check_vec_ops::<Fq2>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch() {
+        let mut a = vec![Fr::from(1u64); 2];
+        mul_add_assign(&mut a, &[Fr::from(1u64); 2], &[Fr::from(1u64)]);
+    }
+}
@@ -171,32 +171,36 @@ impl<F: FftField> EvaluationDomain<F> for MixedRadixEvaluationDomain<F> {
 
     #[inline]
     fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        if !self.offset.is_one() {
-            Self::distribute_powers(coeffs, self.offset);
-        }
-        coeffs.resize(self.size(), T::zero());
-        best_fft(
-            coeffs,
-            self.group_gen,
-            self.log_size_of_group,
-            serial_mixed_radix_fft::<T, F>,
-        )
+        ark_ff::thread_pool::in_thread_pool(|| {
+            if !self.offset.is_one() {
+                Self::distribute_powers(coeffs, self.offset);
+            }
+            coeffs.resize(self.size(), T::zero());
+            best_fft(
+                coeffs,
+                self.group_gen,
+                self.log_size_of_group,
+                serial_mixed_radix_fft::<T, F>,
+            )
+        })
     }
 
     #[inline]
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        evals.resize(self.size(), T::zero());
-        best_fft(
-            evals,
-            self.group_gen_inv,
-            self.log_size_of_group,
-            serial_mixed_radix_fft::<T, F>,
-        );
-        if self.offset.is_one() {
-            ark_std::cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
-        } else {
-            Self::distribute_powers_and_mul_by_const(evals, self.offset_inv, self.size_inv);
-        }
+        ark_ff::thread_pool::in_thread_pool(|| {
+            evals.resize(self.size(), T::zero());
+            best_fft(
+                evals,
+                self.group_gen_inv,
+                self.log_size_of_group,
+                serial_mixed_radix_fft::<T, F>,
+            );
+            if self.offset.is_one() {
+                ark_std::cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
+            } else {
+                Self::distribute_powers_and_mul_by_const(evals, self.offset_inv, self.size_inv);
+            }
+        })
     }
 
     /// Return an iterator over the elements of the domain.
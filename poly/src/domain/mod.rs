@@ -23,6 +23,11 @@ pub use general::GeneralEvaluationDomain;
 pub use mixed_radix::MixedRadixEvaluationDomain;
 pub use radix2::Radix2EvaluationDomain;
 
+/// The number of evaluations multiplied by a single call to
+/// [`Field::mul_batch`](ark_ff::Field::mul_batch) when multiplying
+/// evaluation vectors.
+pub(crate) const MUL_BATCH_CHUNK_SIZE: usize = 1024;
+
 /// Defines a domain over which finite field (I)FFTs can be performed. The
 /// size of the supported FFT depends on the size of the multiplicative
 /// subgroup. For efficiency, we recommend that the field has at least one large
@@ -319,9 +324,9 @@ pub trait EvaluationDomain<F: FftField>:
         assert_eq!(self_evals.len(), other_evals.len());
         let mut result = self_evals.to_vec();
 
-        ark_std::cfg_iter_mut!(result)
-            .zip(other_evals)
-            .for_each(|(a, b)| *a *= b);
+        ark_std::cfg_chunks_mut!(result, MUL_BATCH_CHUNK_SIZE)
+            .zip(ark_std::cfg_chunks!(other_evals, MUL_BATCH_CHUNK_SIZE))
+            .for_each(|(a, b)| F::mul_batch(a, b));
 
         result
     }
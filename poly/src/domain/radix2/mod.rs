@@ -145,18 +145,22 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
 
     #[inline]
     fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        if coeffs.len() * DEGREE_AWARE_FFT_THRESHOLD_FACTOR <= self.size() {
-            self.degree_aware_fft_in_place(coeffs);
-        } else {
-            coeffs.resize(self.size(), T::zero());
-            self.in_order_fft_in_place(coeffs);
-        }
+        ark_ff::thread_pool::in_thread_pool(|| {
+            if coeffs.len() * DEGREE_AWARE_FFT_THRESHOLD_FACTOR <= self.size() {
+                self.degree_aware_fft_in_place(coeffs);
+            } else {
+                coeffs.resize(self.size(), T::zero());
+                self.in_order_fft_in_place(coeffs);
+            }
+        })
     }
 
     #[inline]
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        evals.resize(self.size(), T::zero());
-        self.in_order_ifft_in_place(&mut *evals);
+        ark_ff::thread_pool::in_thread_pool(|| {
+            evals.resize(self.size(), T::zero());
+            self.in_order_ifft_in_place(&mut *evals);
+        })
     }
 
     /// Return an iterator over the elements of the domain.
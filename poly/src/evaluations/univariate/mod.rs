@@ -3,16 +3,13 @@
 use crate::{
     univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain,
 };
-use ark_ff::{batch_inversion, FftField};
+use ark_ff::{CpuBackend, FftField, FieldBackend};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{
     ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Sub, SubAssign},
     vec::*,
 };
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
 /// Stores a UV polynomial in evaluation form.
 #[derive(Clone, PartialEq, Eq, Hash, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Evaluations<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
@@ -52,6 +49,37 @@ impl<F: FftField, D: EvaluationDomain<F>> Evaluations<F, D> {
     pub fn domain(&self) -> D {
         self.domain
     }
+
+    /// Sets `self = self + other`, using `backend` for the arithmetic.
+    pub fn add_assign_with_backend<B: FieldBackend<F>>(&mut self, other: &Self, backend: &B) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        backend.add_assign(&mut self.evals, &other.evals);
+    }
+
+    /// Sets `self = self - other`, using `backend` for the arithmetic.
+    pub fn sub_assign_with_backend<B: FieldBackend<F>>(&mut self, other: &Self, backend: &B) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        backend.sub_assign(&mut self.evals, &other.evals);
+    }
+
+    /// Sets `self = self * other`, using `backend` for the arithmetic.
+    pub fn mul_assign_with_backend<B: FieldBackend<F>>(&mut self, other: &Self, backend: &B) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        backend.mul_assign(&mut self.evals, &other.evals);
+    }
+
+    /// Sets `self = self / other`, using `backend` for the arithmetic.
+    pub fn div_assign_with_backend<B: FieldBackend<F>>(&mut self, other: &Self, backend: &B) {
+        assert_eq!(self.domain, other.domain, "domains are unequal");
+        let mut other_inv = other.evals.clone();
+        backend.batch_inverse(&mut other_inv);
+        backend.mul_assign(&mut self.evals, &other_inv);
+    }
+
+    /// Sets `self = self * elem`, using `backend` for the arithmetic.
+    pub fn scale_with_backend<B: FieldBackend<F>>(&mut self, elem: &F, backend: &B) {
+        backend.scale(&mut self.evals, elem);
+    }
 }
 
 impl<F: FftField, D: EvaluationDomain<F>> Index<usize> for Evaluations<F, D> {
@@ -80,10 +108,7 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> MulAssign<&'a Evaluations<F, D>>
 {
     #[inline]
     fn mul_assign(&mut self, other: &'a Evaluations<F, D>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        ark_std::cfg_iter_mut!(self.evals)
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a *= b);
+        self.mul_assign_with_backend(other, &CpuBackend);
     }
 }
 
@@ -93,9 +118,7 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> Mul<F> for &'a Evaluations<F, D> {
     #[inline]
     fn mul(self, elem: F) -> Evaluations<F, D> {
         let mut result = self.clone();
-        ark_std::cfg_iter_mut!(result.evals).for_each(|e| {
-            *e *= elem;
-        });
+        result.scale_with_backend(&elem, &CpuBackend);
         result
     }
 }
@@ -118,10 +141,7 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> AddAssign<&'a Evaluations<F, D>>
 {
     #[inline]
     fn add_assign(&mut self, other: &'a Evaluations<F, D>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        ark_std::cfg_iter_mut!(self.evals)
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a += b);
+        self.add_assign_with_backend(other, &CpuBackend);
     }
 }
 
@@ -143,10 +163,7 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> SubAssign<&'a Evaluations<F, D>>
 {
     #[inline]
     fn sub_assign(&mut self, other: &'a Evaluations<F, D>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        ark_std::cfg_iter_mut!(self.evals)
-            .zip(&other.evals)
-            .for_each(|(a, b)| *a -= b);
+        self.sub_assign_with_backend(other, &CpuBackend);
     }
 }
 
@@ -168,11 +185,60 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> DivAssign<&'a Evaluations<F, D>>
 {
     #[inline]
     fn div_assign(&mut self, other: &'a Evaluations<F, D>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        let mut other_copy = other.clone();
-        batch_inversion(other_copy.evals.as_mut_slice());
-        ark_std::cfg_iter_mut!(self.evals)
-            .zip(&other_copy.evals)
-            .for_each(|(a, b)| *a *= b);
+        self.div_assign_with_backend(other, &CpuBackend);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EvaluationDomain, Evaluations, GeneralEvaluationDomain};
+    use ark_ff::{Field, FieldBackend, UniformRand};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts the calls to a backend that otherwise runs on the CPU.
+    #[derive(Default)]
+    struct CountingBackend(AtomicUsize);
+
+    impl<F: Field> FieldBackend<F> for CountingBackend {
+        fn mul_assign(&self, a: &mut [F], b: &[F]) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            ark_ff::vec_ops::mul_assign(a, b);
+        }
+
+        fn batch_inverse(&self, a: &mut [F]) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            ark_ff::batch_inversion(a);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_with_backend() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        let mut rand_evals = || {
+            let evals: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+            Evaluations::from_vec_and_domain(evals, domain)
+        };
+        let a = rand_evals();
+        let b = rand_evals();
+        let backend = CountingBackend::default();
+
+        let mut c = a.clone();
+        c.mul_assign_with_backend(&b, &backend);
+        assert_eq!(c, &a * &b);
+        c.div_assign_with_backend(&b, &backend);
+        assert_eq!(c, a);
+        assert_eq!(backend.0.load(Ordering::Relaxed), 3);
+
+        c.add_assign_with_backend(&b, &backend);
+        assert_eq!(c, &a + &b);
+        c.sub_assign_with_backend(&b, &backend);
+        assert_eq!(c, a);
+        let two = Fr::from(2u64);
+        c.scale_with_backend(&two, &backend);
+        assert_eq!(c, &a * two);
+        assert_eq!(backend.0.load(Ordering::Relaxed), 3);
     }
 }
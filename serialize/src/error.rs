@@ -12,6 +12,49 @@ pub enum SerializationError {
     UnexpectedFlags,
     /// During serialization, we countered an I/O error.
     IoError(io::Error),
+    /// During deserialization, a field element or curve point failed
+    /// validation.
+    ValidationError(ValidationError),
+}
+
+/// The reason a deserialized field element or curve point is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// An integer is not the canonical encoding of a field element, i.e. it
+    /// is not less than the modulus.
+    NonCanonicalFieldEncoding,
+    /// The point at infinity was encoded with non-zero coordinates.
+    InvalidInfinityFlags,
+    /// The point does not satisfy the curve equation, or, for compressed
+    /// encodings, there is no point with the given coordinate.
+    NotOnCurve,
+    /// The point is on the curve, but not in the prime-order subgroup.
+    NotInSubgroup,
+    /// Values precomputed from a point, such as the line coefficients of a
+    /// prepared pairing input, do not match that point.
+    InvalidPrecomputation,
+}
+
+impl ark_std::error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ValidationError::NonCanonicalFieldEncoding => {
+                write!(f, "the field element encoding is not canonical")
+            },
+            ValidationError::InvalidInfinityFlags => {
+                write!(f, "the point at infinity has non-zero coordinates")
+            },
+            ValidationError::NotOnCurve => write!(f, "the point is not on the curve"),
+            ValidationError::NotInSubgroup => {
+                write!(f, "the point is not in the prime-order subgroup")
+            },
+            ValidationError::InvalidPrecomputation => {
+                write!(f, "the precomputed values do not match the point")
+            },
+        }
+    }
 }
 
 impl ark_std::error::Error for SerializationError {}
@@ -22,6 +65,12 @@ impl From<io::Error> for SerializationError {
     }
 }
 
+impl From<ValidationError> for SerializationError {
+    fn from(e: ValidationError) -> SerializationError {
+        SerializationError::ValidationError(e)
+    }
+}
+
 impl fmt::Display for SerializationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
@@ -32,6 +81,7 @@ impl fmt::Display for SerializationError {
             SerializationError::InvalidData => write!(f, "the input buffer contained invalid data"),
             SerializationError::UnexpectedFlags => write!(f, "the call expects empty flags"),
             SerializationError::IoError(err) => write!(f, "I/O error: {:?}", err),
+            SerializationError::ValidationError(err) => write!(f, "invalid data: {}", err),
         }
     }
 }
@@ -0,0 +1,28 @@
+//! Prime field `Fp` where `p = 15 * 2^27 + 1`, together with its degree-two
+//! and degree-four extensions.
+pub use ark_ff::fields::{BabyBear as Fq, BabyBearFp2 as Fq2, BabyBearFp4 as Fq4};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{AdditiveGroup, Field, PrimeField};
+    use ark_std::{test_rng, UniformRand};
+
+    test_field!(fq; Fq; prime);
+    test_field!(fq2; Fq2; fft);
+    test_field!(fq4; Fq4; fft);
+
+    #[test]
+    fn test_fq4_is_binomial_extension() {
+        // `v^4 = 11`
+        let v = Fq4::new(Fq2::ZERO, Fq2::ONE);
+        assert_eq!(v.pow([4]), Fq4::from(11u64));
+
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = Fq4::rand(&mut rng);
+            assert_eq!(a.frobenius_map(1), a.pow(Fq::MODULUS));
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{
+    fields::{
+        cipolla_sqrt_precomputation, BarrettConfig, BarrettFp, Fp256, MontBackend, MontConfig,
+    },
+    BigInt, MontFp, SqrtPrecomputation,
+};
 
 #[derive(MontConfig)]
 #[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
@@ -8,6 +13,47 @@ use ark_ff::fields::{Fp256, MontBackend, MontConfig};
 pub struct FrConfig;
 pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
 
+/// The same field as [`Fr`], with elements stored in canonical form and
+/// multiplied using Barrett reduction.
+pub struct FrBarrettConfig;
+pub type FrBarrett = BarrettFp<FrBarrettConfig, 4>;
+
+impl BarrettConfig<4> for FrBarrettConfig {
+    const MODULUS: BigInt<4> = FrConfig::MODULUS;
+
+    const GENERATOR: BigInt<4> = BigInt!("7");
+
+    /// `GENERATOR^((p - 1) / 2^32)`
+    const TWO_ADIC_ROOT_OF_UNITY: BigInt<4> =
+        BigInt!("10238227357739495823651030575849232062558860180284477541189508159991286009131");
+
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+
+    /// `GENERATOR^((p - 1) / (2^32 * 3))`
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInt<4>> = Some(BigInt!(
+        "27190014779860110908007675333221278800259766657973320723066279455788162251031"
+    ));
+}
+
+/// The same field as [`Fr`], computing square roots with Cipolla's algorithm
+/// instead of Tonelli-Shanks.
+pub struct FrCipollaConfig;
+pub type FrCipolla = Fp256<MontBackend<FrCipollaConfig, 4>>;
+
+impl MontConfig<4> for FrCipollaConfig {
+    const MODULUS: BigInt<4> = FrConfig::MODULUS;
+
+    const GENERATOR: FrCipolla = MontFp!("7");
+
+    const TWO_ADIC_ROOT_OF_UNITY: FrCipolla =
+        MontFp!("10238227357739495823651030575849232062558860180284477541189508159991286009131");
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<FrCipolla>> =
+        cipolla_sqrt_precomputation::<4, Self>();
+}
+
 #[test]
 fn test_inv() {
     assert_eq!(FrConfig::INV, 0xffff_fffe_ffff_ffff);
@@ -1,11 +1,17 @@
 use crate::bls12_381::{g1_swu_iso, Fq, Fr};
 use ark_ec::{
+    bls12,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
-    scalar_mul::glv::GLVConfig,
+    scalar_mul::{
+        self,
+        glv::{self, GLVConfig},
+    },
     short_weierstrass::{self, Affine, Projective},
 };
 use ark_ff::{BigInt, MontFp, PrimeField, Zero};
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
 
 pub type G1Affine = Affine<Config>;
 pub type G1Projective = Projective<Config>;
@@ -22,8 +28,7 @@ impl CurveConfig for Config {
 
     /// COFACTOR_INV = COFACTOR^{-1} mod r
     /// = 52435875175126190458656871551744051925719901746859129887267498875565241663483
-    #[rustfmt::skip]
-    const COFACTOR_INV: Fr = MontFp!("52435875175126190458656871551744051925719901746859129887267498875565241663483");
+    const COFACTOR_INV: Fr = Fr::from_limbs_mod_order(Self::COFACTOR).const_inverse();
 }
 
 impl short_weierstrass::SWCurveConfig for Config {
@@ -37,6 +42,9 @@ impl short_weierstrass::SWCurveConfig for Config {
     /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
     const GENERATOR: G1Affine = G1Affine::new_unchecked(G1_GENERATOR_X, G1_GENERATOR_Y);
 
+    /// EFFECTIVE_COFACTOR = 1 - x = 15132376222941642753
+    const EFFECTIVE_COFACTOR: &'static [u64] = &[0xd201000000010001];
+
     #[inline(always)]
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
@@ -47,6 +55,15 @@ impl short_weierstrass::SWCurveConfig for Config {
         GLVConfig::glv_mul_projective(*p, s)
     }
 
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Fr]) -> Result<G1Projective, usize> {
+        glv::msm(bases, scalars)
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        bls12::g1::is_in_correct_subgroup_assuming_on_curve::<crate::bls12_381::Config>(p)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G1Affine) -> G1Affine {
         // Using the effective cofactor, as explained in
@@ -54,8 +71,21 @@ impl short_weierstrass::SWCurveConfig for Config {
         //
         // It is enough to multiply by (x - 1), instead of (x - 1)^2 / 3
         // sqrt(76329603384216526031706109802092473003*3) = 15132376222941642753
-        let h_eff: &[u64] = &[0xd201000000010001];
-        Config::mul_affine(p, h_eff).into()
+        Config::mul_affine(p, Self::EFFECTIVE_COFACTOR).into()
+    }
+
+    fn clear_cofactor_projective(p: &G1Projective) -> G1Projective {
+        // As in `clear_cofactor`. This avoids the GLV multiplication of
+        // `mul_projective`, which is only correct in the prime-order subgroup.
+        scalar_mul::sw_double_and_add_projective(p, Self::EFFECTIVE_COFACTOR)
+    }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<G1Affine>, SerializationError> {
+        G1Affine::deserialize_default_compressed_batch(reader, count, validate)
     }
 }
 
@@ -112,7 +142,7 @@ impl GLVConfig for Config {
 #[cfg(test)]
 mod test {
     use super::*;
-    use ark_ec::CurveGroup;
+    use ark_ec::{AffineRepr, CurveGroup};
     use ark_std::UniformRand;
 
     #[test]
@@ -132,4 +162,218 @@ mod test {
         let g_s_affine_fast = G1Projective::normalize_batch(&g_s);
         assert_eq!(g_s_affine_naive.as_ref(), g_s_affine_fast.as_slice());
     }
+
+    #[test]
+    fn deserialization_errors() {
+        use ark_serialize::{
+            CanonicalDeserialize, CanonicalSerialize, SerializationError, ValidationError,
+        };
+        use ark_std::vec::Vec;
+
+        fn validation_error(bytes: &[u8], compressed: bool) -> Option<ValidationError> {
+            let result = if compressed {
+                G1Affine::deserialize_compressed(bytes)
+            } else {
+                G1Affine::deserialize_uncompressed(bytes)
+            };
+            match result {
+                Err(SerializationError::ValidationError(e)) => Some(e),
+                _ => None,
+            }
+        }
+
+        let mut rng = ark_std::test_rng();
+
+        let not_on_curve = G1Affine::new_unchecked(Fq::from(1u64), Fq::from(1u64));
+        assert_eq!(
+            G1Affine::try_new(not_on_curve.x, not_on_curve.y),
+            Err(ValidationError::NotOnCurve)
+        );
+        let mut bytes = Vec::new();
+        not_on_curve.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(
+            validation_error(&bytes, false),
+            Some(ValidationError::NotOnCurve)
+        );
+
+        let not_in_subgroup = loop {
+            let x = Fq::rand(&mut rng);
+            if let Some(p) = G1Affine::get_point_from_x_unchecked(x, false) {
+                if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    break p;
+                }
+            }
+        };
+        assert_eq!(
+            G1Affine::try_new(not_in_subgroup.x, not_in_subgroup.y),
+            Err(ValidationError::NotInSubgroup)
+        );
+        let mut bytes = Vec::new();
+        not_in_subgroup.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            validation_error(&bytes, true),
+            Some(ValidationError::NotInSubgroup)
+        );
+
+        let mut bytes = Vec::new();
+        G1Affine::identity()
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        bytes[0] = 1;
+        assert_eq!(
+            validation_error(&bytes, true),
+            Some(ValidationError::InvalidInfinityFlags)
+        );
+
+        // An x-coordinate of 2^381 - 1, which exceeds the modulus.
+        let mut bytes = [0xff; 48];
+        bytes[47] = 0x1f;
+        assert_eq!(
+            validation_error(&bytes, true),
+            Some(ValidationError::NonCanonicalFieldEncoding)
+        );
+
+        let p = G1Projective::rand(&mut rng).into_affine();
+        assert_eq!(G1Affine::try_new(p.x, p.y), Ok(p));
+    }
+
+    #[test]
+    fn subgroup_check_matches_order_check() {
+        let mut rng = ark_std::test_rng();
+
+        let mut checked_outside = false;
+        for _ in 0..20 {
+            let p = loop {
+                let x = Fq::rand(&mut rng);
+                if let Some(p) = G1Affine::get_point_from_x_unchecked(x, false) {
+                    break p;
+                }
+            };
+            let in_subgroup = p.mul_bigint(Fr::MODULUS).is_zero();
+            assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+            checked_outside |= !in_subgroup;
+
+            let q = p.clear_cofactor();
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+            let mut q_projective = p.into_group();
+            q_projective.clear_cofactor_in_place();
+            assert_eq!(q_projective, q);
+        }
+        assert!(checked_outside);
+        assert!(G1Affine::identity().is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn batch_subgroup_check() {
+        use ark_std::vec::Vec;
+
+        let mut rng = ark_std::test_rng();
+
+        let mut points = (0..50)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+        points.push(G1Affine::identity());
+        assert!(G1Affine::batch_is_in_correct_subgroup(&points, &mut rng));
+        assert!(G1Affine::batch_is_in_correct_subgroup(&[], &mut rng));
+
+        let not_in_subgroup = loop {
+            let x = Fq::rand(&mut rng);
+            if let Some(p) = G1Affine::get_point_from_x_unchecked(x, false) {
+                if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    break p;
+                }
+            }
+        };
+        // Keep only the component outside the prime-order subgroup.
+        let torsion = not_in_subgroup.mul_bigint(Fr::MODULUS).into_affine();
+        for bad in [not_in_subgroup, torsion] {
+            let mut batch = points.clone();
+            batch[17] = bad;
+            assert!(!G1Affine::batch_is_in_correct_subgroup(&batch, &mut rng));
+        }
+    }
+
+    #[test]
+    fn vec_deserialization_checks() {
+        use ark_serialize::{
+            CanonicalDeserialize, CanonicalSerialize, SerializationError, ValidationError,
+        };
+        use ark_std::vec::Vec;
+
+        fn validation_error(points: &[G1Affine]) -> Option<ValidationError> {
+            let mut bytes = Vec::new();
+            points.serialize_uncompressed(&mut bytes).unwrap();
+            match Vec::<G1Affine>::deserialize_uncompressed(&bytes[..]) {
+                Ok(deserialized) => {
+                    assert_eq!(deserialized, points);
+                    None
+                },
+                Err(SerializationError::ValidationError(e)) => Some(e),
+                Err(e) => panic!("unexpected error: {e}"),
+            }
+        }
+
+        let mut rng = ark_std::test_rng();
+
+        let mut points = (0..50)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+        points.push(G1Affine::identity());
+        assert_eq!(validation_error(&points), None);
+
+        let not_in_subgroup = loop {
+            let x = Fq::rand(&mut rng);
+            if let Some(p) = G1Affine::get_point_from_x_unchecked(x, false) {
+                if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    break p;
+                }
+            }
+        };
+        let mut batch = points.clone();
+        batch[3] = not_in_subgroup;
+        assert_eq!(
+            validation_error(&batch),
+            Some(ValidationError::NotInSubgroup)
+        );
+
+        batch[40] = G1Affine::new_unchecked(Fq::from(1u64), Fq::from(1u64));
+        assert_eq!(validation_error(&batch), Some(ValidationError::NotOnCurve));
+    }
+
+    #[test]
+    fn compressed_batch_deserialization() {
+        use ark_serialize::{CanonicalSerialize, SerializationError, Validate, ValidationError};
+        use ark_std::vec::Vec;
+
+        let mut rng = ark_std::test_rng();
+
+        let mut points = (0..50)
+            .map(|_| G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+        points[10] = G1Affine::identity();
+        let mut bytes = Vec::new();
+        for p in &points {
+            p.serialize_compressed(&mut bytes).unwrap();
+        }
+        let deserialized =
+            G1Affine::deserialize_compressed_batch(&bytes[..], points.len(), Validate::Yes)
+                .unwrap();
+        assert_eq!(deserialized, points);
+
+        // An x-coordinate with no point on the curve.
+        let x = loop {
+            let x = Fq::rand(&mut rng);
+            if G1Affine::get_point_from_x_unchecked(x, false).is_none() {
+                break x;
+            }
+        };
+        x.serialize_compressed(&mut bytes[48 * 20..48 * 21])
+            .unwrap();
+        assert!(matches!(
+            G1Affine::deserialize_compressed_batch(&bytes[..], points.len(), Validate::No),
+            Err(SerializationError::ValidationError(
+                ValidationError::NotOnCurve
+            ))
+        ));
+    }
 }
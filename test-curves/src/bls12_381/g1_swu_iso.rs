@@ -22,8 +22,7 @@ impl CurveConfig for SwuIsoConfig {
 
     /// COFACTOR_INV = COFACTOR^{-1} mod r
     /// = 52435875175126190458656871551744051925719901746859129887267498875565241663483
-    #[rustfmt::skip]
-    const COFACTOR_INV: Fr = MontFp!("52435875175126190458656871551744051925719901746859129887267498875565241663483");
+    const COFACTOR_INV: Fr = Fr::from_limbs_mod_order(Self::COFACTOR).const_inverse();
 }
 
 // https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/
@@ -58,6 +57,10 @@ impl SWUConfig for SwuIsoConfig {
     const ZETA: Fq = MontFp!("11");
 }
 
+// The 11-isogeny from E' to E.
+// These tables are regenerated by `generate_wb_params` from
+// `ec/src/hashing/curve_maps/curve_map_parameter_helper.sage`, passing the
+// coefficients of `SwuIsoConfig` as `A_prime` and `B_prime`.
 pub const ISOGENY_MAP_TO_G1 : IsogenyMap<'_, SwuIsoConfig, g1::Config, > = IsogenyMap {
     x_map_numerator : &[
         MontFp!("2712959285290305970661081772124144179193819192423276218370281158706191519995889425075952244140278856085036081760695"),
@@ -130,6 +133,7 @@ pub const ISOGENY_MAP_TO_G1 : IsogenyMap<'_, SwuIsoConfig, g1::Config, > = Isoge
 #[cfg(test)]
 mod test {
     use super::*;
+    use ark_algebra_test_templates::test_isogeny_map;
 
     #[test]
     fn test_gen() {
@@ -137,4 +141,6 @@ mod test {
         assert!(gen.is_on_curve());
         assert!(gen.is_in_correct_subgroup_assuming_on_curve());
     }
+
+    test_isogeny_map!(isogeny_map; ISOGENY_MAP_TO_G1; SwuIsoConfig; 11);
 }
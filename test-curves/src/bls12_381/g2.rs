@@ -1,14 +1,11 @@
-use core::ops::Neg;
-
 use crate::bls12_381::{g1, g2_swu_iso, Fq, Fq2, Fr, FQ_ZERO};
 use ark_ec::{
-    bls12::{self, Bls12Config},
+    bls12,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
-    short_weierstrass::{self, Affine, Projective},
-    AffineRepr, CurveGroup, PrimeGroup,
+    short_weierstrass,
 };
-use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, Zero};
+use ark_ff::{MontFp, Zero};
 
 pub type G2Affine = bls12::G2Affine<crate::bls12_381::Config>;
 pub type G2Projective = bls12::G2Projective<crate::bls12_381::Config>;
@@ -37,10 +34,7 @@ impl CurveConfig for Config {
 
     /// COFACTOR_INV = COFACTOR^{-1} mod r
     /// 26652489039290660355457965112010883481355318854675681319708643586776743290055
-    #[rustfmt::skip]
-    const COFACTOR_INV: Fr = MontFp!(
-        "26652489039290660355457965112010883481355318854675681319708643586776743290055"
-    );
+    const COFACTOR_INV: Fr = Fr::from_limbs_mod_order(Self::COFACTOR).const_inverse();
 }
 
 impl short_weierstrass::SWCurveConfig for Config {
@@ -53,54 +47,40 @@ impl short_weierstrass::SWCurveConfig for Config {
     /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
     const GENERATOR: G2Affine = G2Affine::new_unchecked(G2_GENERATOR_X, G2_GENERATOR_Y);
 
+    /// EFFECTIVE_COFACTOR = h_eff = (3 * x^2 - 3) * COFACTOR, from RFC 9380,
+    /// Section 8.8.2, which [`Self::clear_cofactor`] computes with the
+    /// endomorphism ψ
+    /// = 209869847837335686905080341498658477663839067235703451875306851526599783796572738804459333109033834234622528588876978987822447936461846631641690358257586228683615991308971558879306463436166481
+    #[rustfmt::skip]
+    const EFFECTIVE_COFACTOR: &'static [u64] = &[
+        0xe8020005aaa95551,
+        0x59894c0adebbf6b4,
+        0xe954cbc06689f6a3,
+        0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0,
+        0x329c2f178731db95,
+        0x9986ff031508ffe1,
+        0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528,
+        0x0bc69f08f2ee75b3,
+    ];
+
     #[inline(always)]
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
 
     fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
-        // Algorithm from Section 4 of https://eprint.iacr.org/2021/1130.
-        //
-        // Checks that [p]P = [X]P
-
-        let mut x_times_point =
-            point.mul_bigint(BigInt::new([crate::bls12_381::Config::X[0], 0, 0, 0]));
-        if crate::bls12_381::Config::X_IS_NEGATIVE {
-            x_times_point = -x_times_point;
-        }
-
-        let p_times_point = p_power_endomorphism(point);
-
-        x_times_point.eq(&p_times_point)
+        bls12::g2::is_in_correct_subgroup_assuming_on_curve::<crate::bls12_381::Config>(point)
     }
 
     #[inline]
     fn clear_cofactor(p: &G2Affine) -> G2Affine {
-        // Based on Section 4.1 of https://eprint.iacr.org/2017/419.pdf
-        // [h(ψ)]P = [x^2 − x − 1]P + [x − 1]ψ(P) + (ψ^2)(2P)
-
-        // x = -15132376222941642752
-        // When multiplying, use -c1 instead, and then negate the result. That's much
-        // more efficient, since the scalar -c1 has less limbs and a much lower Hamming
-        // weight.
-        let x: &'static [u64] = crate::bls12_381::Config::X;
-        let p_projective = p.into_group();
-
-        // [x]P
-        let x_p = Config::mul_affine(p, x).neg();
-        // ψ(P)
-        let psi_p = p_power_endomorphism(p);
-        // (ψ^2)(2P)
-        let mut psi2_p2 = double_p_power_endomorphism(&p_projective.double());
-
-        // tmp = [x^2]P + [x]ψ(P)
-        let tmp = (x_p + psi_p).mul_bigint(x).neg();
-
-        // add up all the terms
-        psi2_p2 += tmp;
-        psi2_p2 -= x_p;
-        psi2_p2 -= psi_p;
-        (psi2_p2 - p_projective).into_affine()
+        bls12::g2::clear_cofactor::<crate::bls12_381::Config>(p)
+    }
+
+    fn clear_cofactor_projective(p: &G2Projective) -> G2Projective {
+        bls12::g2::clear_cofactor_projective::<crate::bls12_381::Config>(p)
     }
 }
 
@@ -146,44 +126,28 @@ pub const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
        "1028732146235106349975324479215795277384839936929757896155643118032610843298655225875571310552543014690878354869257")
 );
 
+#[deprecated(
+    since = "0.5.0",
+    note = "use `ark_ec::bls12::g2::double_mul_by_char` instead"
+)]
 pub const DOUBLE_P_POWER_ENDOMORPHISM: Fq2 = Fq2::new(
     MontFp!("4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436"),
     FQ_ZERO
 );
 
-pub fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
-    // The p-power endomorphism for G2 is defined as follows:
-    // 1. Note that G2 is defined on curve E': y^2 = x^3 + 4(u+1). To map a point
-    // (x, y) in E' to (s, t) in E,    one set s = x / ((u+1) ^ (1/3)), t = y /
-    // ((u+1) ^ (1/2)), because E: y^2 = x^3 + 4. 2. Apply the Frobenius
-    // endomorphism (s, t) => (s', t'), another point on curve E,    where s' =
-    // s^p, t' = t^p. 3. Map the point from E back to E'; that is,
-    //    one set x' = s' * ((u+1) ^ (1/3)), y' = t' * ((u+1) ^ (1/2)).
-    //
-    // To sum up, it maps
-    // (x,y) -> (x^p / ((u+1)^((p-1)/3)), y^p / ((u+1)^((p-1)/2)))
-    // as implemented in the code as follows.
-
-    let mut res = *p;
-    res.x.frobenius_map_in_place(1);
-    res.y.frobenius_map_in_place(1);
-
-    let tmp_x = res.x;
-    res.x.c0 = -P_POWER_ENDOMORPHISM_COEFF_0.c1 * tmp_x.c1;
-    res.x.c1 = P_POWER_ENDOMORPHISM_COEFF_0.c1 * tmp_x.c0;
-    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
-
-    res
+/// Computes `ψ(p)`, where `ψ` is the untwist-Frobenius-twist endomorphism.
+#[deprecated(since = "0.5.0", note = "use `ark_ec::bls12::g2::mul_by_char` instead")]
+pub fn p_power_endomorphism(p: &G2Affine) -> G2Affine {
+    bls12::g2::mul_by_char::<crate::bls12_381::Config>(*p)
 }
 
 /// For a p-power endomorphism psi(P), compute psi(psi(P))
-pub fn double_p_power_endomorphism(p: &Projective<Config>) -> Projective<Config> {
-    let mut res = *p;
-
-    res.x *= DOUBLE_P_POWER_ENDOMORPHISM;
-    res.y = res.y.neg();
-
-    res
+#[deprecated(
+    since = "0.5.0",
+    note = "use `ark_ec::bls12::g2::double_mul_by_char` instead"
+)]
+pub fn double_p_power_endomorphism(p: &G2Projective) -> G2Projective {
+    bls12::g2::double_mul_by_char::<crate::bls12_381::Config>(*p)
 }
 
 // Config from the [IETF draft v16, section E.3](https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-3-isogeny-map-for-bls12-381).
@@ -193,3 +157,136 @@ impl WBConfig for Config {
     const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
         g2_swu_iso::ISOGENY_MAP_TO_G2;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ec::AffineRepr;
+    use ark_ff::PrimeField;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn clear_cofactor_matches_h_eff() {
+        let mut rng = ark_std::test_rng();
+
+        let h_eff = <Config as short_weierstrass::SWCurveConfig>::EFFECTIVE_COFACTOR;
+
+        for _ in 0..10 {
+            let p = loop {
+                let x = Fq2::rand(&mut rng);
+                if let Some(p) = G2Affine::get_point_from_x_unchecked(x, false) {
+                    break p;
+                }
+            };
+            assert_eq!(p.clear_cofactor(), p.mul_bigint(h_eff));
+        }
+        assert!(G2Affine::identity().clear_cofactor().is_zero());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_endomorphisms_match_psi() {
+        use ark_ec::CurveGroup;
+        use ark_ff::Field;
+
+        let mut rng = ark_std::test_rng();
+        let p = G2Affine::rand(&mut rng);
+        let psi_p = p_power_endomorphism(&p);
+        assert_eq!(psi_p, bls12::g2::mul_by_char::<crate::bls12_381::Config>(p));
+        let psi2_p = double_p_power_endomorphism(&p.into_group());
+        assert_eq!(p_power_endomorphism(&psi_p), psi2_p.into_affine());
+        assert_eq!(
+            DOUBLE_P_POWER_ENDOMORPHISM,
+            Fq2::new(P_POWER_ENDOMORPHISM_COEFF_0.norm(), FQ_ZERO)
+        );
+    }
+
+    #[test]
+    fn projective_clear_cofactor_matches_affine() {
+        use ark_ec::{AdditiveGroup, CurveGroup};
+        use ark_std::vec::Vec;
+
+        let mut rng = ark_std::test_rng();
+
+        let points = (0..10)
+            .map(|_| loop {
+                let x = Fq2::rand(&mut rng);
+                if let Some(p) = G2Affine::get_point_from_x_unchecked(x, false) {
+                    break p;
+                }
+            })
+            .collect::<Vec<_>>();
+        // Doubling and subtracting leaves the points with `z != 1`.
+        let mut projective = points
+            .iter()
+            .map(|p| p.into_group().double() - p)
+            .collect::<Vec<_>>();
+        G2Projective::batch_clear_cofactor(&mut projective);
+        for (p, q) in points.iter().zip(&projective) {
+            assert_eq!(p.clear_cofactor(), q.into_affine());
+        }
+    }
+
+    #[test]
+    fn malformed_prepared_is_rejected() {
+        use crate::bls12_381::G2Prepared;
+        use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+        use ark_std::vec::Vec;
+
+        let mut rng = ark_std::test_rng();
+        let mut prepared = G2Prepared::from(G2Projective::rand(&mut rng));
+        prepared.ell_coeffs.pop();
+        let mut bytes = Vec::new();
+        prepared.serialize_compressed(&mut bytes).unwrap();
+        assert!(G2Prepared::deserialize_compressed(&bytes[..]).is_err());
+        let unchecked =
+            G2Prepared::deserialize_with_mode(&bytes[..], Compress::Yes, Validate::No).unwrap();
+        assert_eq!(unchecked, prepared);
+    }
+
+    #[test]
+    fn untwist_is_compatible_with_psi() {
+        use crate::bls12_381::{Config, Fq12};
+        use ark_ec::{bls12::Bls12Config, short_weierstrass::SWCurveConfig, CurveGroup};
+        use ark_ff::Field;
+
+        let mut rng = ark_std::test_rng();
+        let b = Fq12::from_base_prime_field(g1::Config::COEFF_B);
+        for _ in 0..10 {
+            let p = G2Affine::rand(&mut rng);
+            let (x, y) = bls12::g2::untwist::<Config>(&p).unwrap();
+            assert_eq!(y.square(), x.square() * x + b);
+
+            // `ψ(p) = [x]p` for `p` in G2, with `x` negative, so the untwist
+            // of `[x]p` must be the Frobenius of the untwist of `p`.
+            let psi_p = p.mul_bigint(Config::X).into_affine();
+            let (psi_x, psi_y) = bls12::g2::untwist::<Config>(&(-psi_p)).unwrap();
+            assert_eq!(psi_x, x.frobenius_map(1));
+            assert_eq!(psi_y, y.frobenius_map(1));
+        }
+        assert!(bls12::g2::untwist::<Config>(&G2Affine::identity()).is_none());
+    }
+
+    #[test]
+    fn subgroup_check_matches_order_check() {
+        let mut rng = ark_std::test_rng();
+
+        let mut checked_outside = false;
+        for _ in 0..10 {
+            let p = loop {
+                let x = Fq2::rand(&mut rng);
+                if let Some(p) = G2Affine::get_point_from_x_unchecked(x, false) {
+                    break p;
+                }
+            };
+            let in_subgroup = p.mul_bigint(Fr::MODULUS).is_zero();
+            assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+            checked_outside |= !in_subgroup;
+
+            let q = p.clear_cofactor();
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+        }
+        assert!(checked_outside);
+        assert!(G2Affine::identity().is_in_correct_subgroup_assuming_on_curve());
+    }
+}
@@ -76,6 +76,10 @@ impl SWUConfig for SwuIsoConfig {
     const ZETA: Fq2 = Fq2::new(MontFp!("-2"), MontFp!("-1"));
 }
 
+// The 3-isogeny from E' to E.
+// These tables are regenerated by `generate_wb_params` from
+// `ec/src/hashing/curve_maps/curve_map_parameter_helper.sage`, passing the
+// coefficients of `SwuIsoConfig` as `A_prime` and `B_prime`.
 pub const ISOGENY_MAP_TO_G2  : IsogenyMap<'_, SwuIsoConfig, g2::Config> = IsogenyMap {
     x_map_numerator: &[
         Fq2::new(
@@ -140,6 +144,7 @@ mod test {
     use crate::bls12_381::g2_swu_iso;
 
     use super::*;
+    use ark_algebra_test_templates::test_isogeny_map;
 
     #[test]
     fn test_gen() {
@@ -147,4 +152,6 @@ mod test {
         assert!(gen.is_on_curve());
         assert!(gen.is_in_correct_subgroup_assuming_on_curve());
     }
+
+    test_isogeny_map!(isogeny_map; ISOGENY_MAP_TO_G2; SwuIsoConfig; 3);
 }
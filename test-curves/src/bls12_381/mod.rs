@@ -34,7 +34,7 @@ mod tests;
 pub use pairing::*;
 #[cfg(feature = "bls12_381_curve")]
 mod pairing {
-    use super::{g1, g2, Fq, Fq12Config, Fq2Config, Fq6Config};
+    use super::{g1, g2, Fq, Fq12Config, Fq2, Fq2Config, Fq6Config};
     use ark_ec::bls12::{Bls12, Bls12Config, TwistType};
 
     pub type Bls12_381 = Bls12<Config>;
@@ -45,6 +45,8 @@ mod pairing {
         const X: &'static [u64] = &[0xd201000000010000];
         const X_IS_NEGATIVE: bool = true;
         const TWIST_TYPE: TwistType = TwistType::M;
+        const TWIST_MUL_BY_Q_X: Fq2 = g2::P_POWER_ENDOMORPHISM_COEFF_0;
+        const TWIST_MUL_BY_Q_Y: Fq2 = g2::P_POWER_ENDOMORPHISM_COEFF_1;
         type Fp = Fq;
         type Fp2Config = Fq2Config;
         type Fp6Config = Fq6Config;
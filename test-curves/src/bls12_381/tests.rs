@@ -2,6 +2,8 @@ use crate::bls12_381::*;
 use ark_algebra_test_templates::*;
 
 test_field!(fr; Fr; mont_prime_field);
+test_field!(fr_barrett; FrBarrett; prime);
+test_field!(fr_cipolla; FrCipolla; mont_prime_field);
 #[cfg(feature = "bls12_381_curve")]
 test_field!(fq; Fq; mont_prime_field);
 #[cfg(feature = "bls12_381_curve")]
@@ -24,3 +26,66 @@ test_pairing!(pairing; crate::bls12_381::Bls12_381);
 test_h2c!(g1_h2c; "./src/testdata"; "BLS12381G1"; crate::bls12_381::g1::Config; crate::bls12_381::Fq; crate::bls12_381::Fq; 1);
 #[cfg(feature = "bls12_381_curve")]
 test_h2c!(g2_hc2; "./src/testdata"; "BLS12381G2"; crate::bls12_381::g2::Config; crate::bls12_381::Fq2; crate::bls12_381::Fq; 2);
+#[cfg(feature = "bls12_381_curve")]
+test_h2c!(g1_e2c; "./src/testdata"; "BLS12381G1"; crate::bls12_381::g1::Config; crate::bls12_381::Fq; crate::bls12_381::Fq; 1; "NU"; encode);
+#[cfg(feature = "bls12_381_curve")]
+test_h2c!(g2_e2c; "./src/testdata"; "BLS12381G2"; crate::bls12_381::g2::Config; crate::bls12_381::Fq2; crate::bls12_381::Fq; 2; "NU"; encode);
+
+#[cfg(feature = "bls12_381_curve")]
+#[test]
+fn final_exponentiation_hard_parts_agree() {
+    use ark_std::{test_rng, UniformRand};
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let f = Fq12::rand(&mut rng);
+        let f = Bls12_381::final_exponentiation_easy_part(f).unwrap();
+        assert_eq!(
+            Bls12_381::final_exponentiation_hard_part(&f),
+            Bls12_381::final_exponentiation_hard_part_fuentes(&f),
+        );
+        assert_eq!(
+            Bls12_381::final_exponentiation_hard_part(&f),
+            Bls12_381::final_exponentiation_hard_part_compressed(&f),
+        );
+    }
+}
+
+#[cfg(feature = "bls12_381_curve")]
+#[test]
+fn compressed_cyclotomic_exp() {
+    use ark_ff::{CyclotomicMultSubgroup, Field};
+    use ark_std::{test_rng, UniformRand};
+
+    let mut rng = test_rng();
+    let exps: [&[u64]; 4] = [&[], &[1], &[0xd201000000010000], &[u64::MAX, 0x1234]];
+    for _ in 0..5 {
+        let f = Fq12::rand(&mut rng);
+        let f = Bls12_381::final_exponentiation_easy_part(f).unwrap();
+        for exp in exps {
+            assert_eq!(f.cyclotomic_exp_compressed(exp), f.cyclotomic_exp(exp));
+        }
+    }
+    assert_eq!(
+        Fq12::ONE.cyclotomic_exp_compressed([0xd201000000010000]),
+        Fq12::ONE
+    );
+}
+
+#[cfg(feature = "bls12_381_curve")]
+#[test]
+fn hash_to_scalar_matches_hash_to_field() {
+    use ark_ec::{pairing::Pairing, PrimeGroup};
+    use ark_ff::field_hashers::{xmd::hash_to_field, DefaultFieldHasher};
+
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    let msgs: [&[u8]; 3] = [b"", b"abc", b"abcdef0123456789"];
+    for msg in msgs {
+        let scalar = G1Projective::hash_to_scalar::<DefaultFieldHasher<Sha256>>(msg, dst);
+        assert_eq!(scalar, hash_to_field::<Fr, Sha256>(msg, dst, 1)[0]);
+        assert_eq!(
+            Bls12_381::hash_to_scalar::<DefaultFieldHasher<Sha256>>(msg, dst),
+            scalar
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Prime field `Fp` where `p = 2^255 - 19`, instantiated both with the
+//! pseudo-Mersenne [`CrandallBackend`](ark_ff::CrandallBackend) and with the
+//! generic Montgomery backend.
+use ark_ff::{
+    fields::{CrandallConfig, CrandallFp, Fp256, MontBackend},
+    BigInt,
+};
+
+pub struct FqConfig;
+
+impl CrandallConfig<4> for FqConfig {
+    const K: u32 = 255;
+    const C: u64 = 19;
+    const GENERATOR: BigInt<4> = BigInt!("2");
+    const TWO_ADIC_ROOT_OF_UNITY: BigInt<4> =
+        BigInt!("19681161376707505956807079304988542015446066515923890162744021073123829784752");
+}
+
+pub type Fq = CrandallFp<FqConfig, 4>;
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[generator = "2"]
+pub struct FqMontConfig;
+pub type FqMont = Fp256<MontBackend<FqMontConfig, 4>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{AdditiveGroup, Field, PrimeField};
+    use ark_std::{test_rng, UniformRand};
+
+    test_field!(fq; Fq; prime);
+    test_field!(fq_mont; FqMont; mont_prime_field);
+
+    #[test]
+    fn test_agrees_with_montgomery_backend() {
+        let mut rng = test_rng();
+        let to_mont = |a: Fq| FqMont::from_bigint(a.into_bigint()).unwrap();
+        let edge = [
+            Fq::from(0u64),
+            Fq::from(1u64),
+            Fq::from(19u64),
+            -Fq::from(1u64),
+            -Fq::from(19u64),
+        ];
+        let elems = edge
+            .into_iter()
+            .chain((0..1000).map(|_| Fq::rand(&mut rng)))
+            .collect::<ark_std::vec::Vec<_>>();
+        for (a, b) in elems.iter().zip(elems.iter().rev()) {
+            assert_eq!(to_mont(*a + b), to_mont(*a) + to_mont(*b));
+            assert_eq!(to_mont(*a - b), to_mont(*a) - to_mont(*b));
+            assert_eq!(to_mont(*a * b), to_mont(*a) * to_mont(*b));
+            assert_eq!(to_mont(a.square()), to_mont(*a).square());
+            assert_eq!(to_mont(a.double()), to_mont(*a).double());
+            assert_eq!(to_mont(-*a), -to_mont(*a));
+            assert_eq!(a.inverse().map(to_mont), to_mont(*a).inverse());
+            assert_eq!(
+                a.sqrt().map(|s| to_mont(s.square())),
+                to_mont(*a).sqrt().map(|s| s.square())
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! A 2-cycle of small prime-order short Weierstrass curves, used to test
+//! [`CurveCycle`]. As for the Pasta curves, each curve has `j`-invariant
+//! zero, and the order of each curve is the size of the base field of the
+//! other.
+//!
+//! Curve information:
+//! * p = 4611686024869847761
+//! * q = 4611686020574880463
+//! * E1: y^2 = x^3 + 7 over Fp, of order q
+//! * E2: y^2 = x^3 + 3 over Fq, of order p
+use ark_ec::{
+    models::CurveConfig,
+    short_weierstrass::{self, SWCurveConfig},
+    CurveCycle,
+};
+use ark_ff::{
+    fields::{Fp64, MontBackend},
+    AdditiveGroup, Field, MontFp,
+};
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "4611686024869847761"]
+#[generator = "7"]
+pub struct FpConfig;
+pub type Fp = Fp64<MontBackend<FpConfig, 1>>;
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "4611686020574880463"]
+#[generator = "3"]
+pub struct FqConfig;
+pub type Fq = Fp64<MontBackend<FqConfig, 1>>;
+
+pub type E1Affine = short_weierstrass::Affine<E1Config>;
+pub type E1Projective = short_weierstrass::Projective<E1Config>;
+pub type E2Affine = short_weierstrass::Affine<E2Config>;
+pub type E2Projective = short_weierstrass::Projective<E2Config>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct E1Config;
+
+impl CurveConfig for E1Config {
+    type BaseField = Fp;
+    type ScalarField = Fq;
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod q = 1
+    const COFACTOR_INV: Fq = Fq::ONE;
+}
+
+impl SWCurveConfig for E1Config {
+    /// COEFF_A = 0
+    const COEFF_A: Fp = Fp::ZERO;
+
+    /// COEFF_B = 7
+    const COEFF_B: Fp = MontFp!("7");
+
+    const GENERATOR: E1Affine =
+        E1Affine::new_unchecked(MontFp!("1"), MontFp!("1152443625532579343"));
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::ZERO
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct E2Config;
+
+impl CurveConfig for E2Config {
+    type BaseField = Fq;
+    type ScalarField = Fp;
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod p = 1
+    const COFACTOR_INV: Fp = Fp::ONE;
+}
+
+impl SWCurveConfig for E2Config {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = Fq::ZERO;
+
+    /// COEFF_B = 3
+    const COEFF_B: Fq = MontFp!("3");
+
+    const GENERATOR: E2Affine = E2Affine::new_unchecked(MontFp!("1"), MontFp!("2"));
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::ZERO
+    }
+}
+
+/// The cycle formed by [`E1Projective`] and [`E2Projective`].
+pub struct Cycle;
+
+impl CurveCycle for Cycle {
+    type E1 = E1Projective;
+    type E2 = E2Projective;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{PrimeField, UniformRand};
+    use ark_std::test_rng;
+
+    test_field!(fp; Fp; mont_prime_field);
+    test_field!(fq; Fq; mont_prime_field);
+    test_group!(e1; E1Projective; sw);
+    test_group!(e2; E2Projective; sw);
+
+    #[test]
+    fn test_cycle() {
+        assert!(Cycle::is_consistent());
+
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            // Since q < p, scalars of E1 always have an image.
+            let s = Fq::rand(rng);
+            let t = Cycle::e1_scalar_to_e2_scalar(&s).unwrap();
+            assert_eq!(s.into_bigint().0, t.into_bigint().0);
+            assert_eq!(Cycle::e2_scalar_to_e1_scalar(&t), Some(s));
+        }
+
+        let q = Fp::from(Fq::MODULUS);
+        assert_eq!(Cycle::e2_scalar_to_e1_scalar(&q), None);
+        assert_eq!(Cycle::e2_scalar_to_e1_scalar(&-Fp::ONE), None);
+        assert_eq!(
+            Cycle::e2_scalar_to_e1_scalar(&(q - Fp::ONE)),
+            Some(-Fq::ONE)
+        );
+    }
+}
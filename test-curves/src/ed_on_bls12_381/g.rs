@@ -1,9 +1,12 @@
 use crate::ed_on_bls12_381::{Fq, Fr};
 use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Config,
     models::CurveConfig,
     twisted_edwards::{self, MontCurveConfig, TECurveConfig},
 };
 use ark_ff::MontFp;
+use ark_serialize::{SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
 
 pub type Affine = twisted_edwards::Affine<EdwardsConfig>;
 pub type Projective = twisted_edwards::Projective<EdwardsConfig>;
@@ -62,6 +65,14 @@ impl TECurveConfig for EdwardsConfig {
     fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
         -elem
     }
+
+    fn deserialize_compressed_batch<R: Read>(
+        reader: R,
+        count: usize,
+        validate: Validate,
+    ) -> Result<Vec<Affine>, SerializationError> {
+        Affine::deserialize_default_compressed_batch(reader, count, validate)
+    }
 }
 
 impl MontCurveConfig for EdwardsConfig {
@@ -71,6 +82,29 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// Elligator hash to curve JubJub
+// sage: find_z_ell2(GF(52435875175126190479447740508185965837690552500527637822603658699938581184513))
+// 5
+//
+// sage: Fq = GF(52435875175126190479447740508185965837690552500527637822603658699938581184513)
+// sage: COEFF_A = Fq(40962)
+// sage: COEFF_B = Fq(-40964)
+// sage: 1/COEFF_B^2
+// 19676371192118968049803995723067767322369844622612809987311387700305851196142
+// sage: COEFF_A/COEFF_B
+// 9628519018340474679875156334893438995974717701127060143092098445975442038616
+impl Elligator2Config for EdwardsConfig {
+    const Z: Fq = MontFp!("5");
+
+    /// This must be equal to 1/(MontCurveConfig::COEFF_B)^2;
+    const ONE_OVER_COEFF_B_SQUARE: Fq =
+        MontFp!("19676371192118968049803995723067767322369844622612809987311387700305851196142");
+
+    /// This must be equal to MontCurveConfig::COEFF_A/MontCurveConfig::COEFF_B;
+    const COEFF_A_OVER_COEFF_B: Fq =
+        MontFp!("9628519018340474679875156334893438995974717701127060143092098445975442038616");
+}
+
 const GENERATOR_X: Fq =
     MontFp!("8076246640662884909881801758704306714034609987455869804520522091855516602923");
 const GENERATOR_Y: Fq =
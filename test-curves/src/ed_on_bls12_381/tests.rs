@@ -1,6 +1,112 @@
 use crate::ed_on_bls12_381::*;
 use ark_algebra_test_templates::*;
+use ark_ec::{
+    twisted_edwards::{self, TECoordinates, TECurveConfig},
+    AdditiveGroup, AffineRepr, CurveConfig, CurveGroup,
+};
+use ark_ff::{Field, PrimeField};
+use ark_std::{UniformRand, Zero};
 
 test_field!(fr; Fr; mont_prime_field);
 test_field!(fq; Fq; mont_prime_field);
 test_group!(g; Projective; te);
+
+/// Jubjub, with projective rather than extended coordinates.
+#[derive(Clone, Default, PartialEq, Eq)]
+struct ProjectiveConfig;
+
+impl CurveConfig for ProjectiveConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = EdwardsConfig::COFACTOR;
+    const COFACTOR_INV: Fr = EdwardsConfig::COFACTOR_INV;
+}
+
+impl TECurveConfig for ProjectiveConfig {
+    const COEFF_A: Fq = <EdwardsConfig as TECurveConfig>::COEFF_A;
+    const COEFF_D: Fq = <EdwardsConfig as TECurveConfig>::COEFF_D;
+    const GENERATOR: twisted_edwards::Affine<Self> = twisted_edwards::Affine::new_unchecked(
+        <EdwardsConfig as TECurveConfig>::GENERATOR.x,
+        <EdwardsConfig as TECurveConfig>::GENERATOR.y,
+    );
+    const COORDINATES: TECoordinates = TECoordinates::Projective;
+
+    type MontCurveConfig = EdwardsConfig;
+
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        -elem
+    }
+}
+
+test_group!(g_projective; twisted_edwards::Projective<ProjectiveConfig>; curve);
+
+#[test]
+fn small_order_and_torsion_free() {
+    let mut rng = ark_std::test_rng();
+
+    let identity = Affine::zero();
+    assert!(identity.has_small_order());
+    assert!(identity.is_torsion_free());
+
+    // The points of order two and four.
+    let order_two = Affine::new_unchecked(Fq::ZERO, -Fq::ONE);
+    let order_four = Affine::new_unchecked(
+        <EdwardsConfig as TECurveConfig>::COEFF_A
+            .inverse()
+            .and_then(|a_inv| a_inv.sqrt())
+            .unwrap(),
+        Fq::ZERO,
+    );
+    for p in [order_two, order_four] {
+        assert!(p.is_on_curve());
+        assert!(p.has_small_order());
+        assert!(!p.is_torsion_free());
+    }
+
+    for _ in 0..10 {
+        let p = loop {
+            let y = Fq::rand(&mut rng);
+            if let Some(p) = Affine::get_point_from_y_unchecked(y, false) {
+                break p;
+            }
+        };
+        let torsion = p.mul_bigint(Fr::MODULUS);
+        assert!(torsion.into_affine().has_small_order());
+        assert_eq!(torsion.into_affine().is_torsion_free(), torsion.is_zero());
+
+        let g = Projective::rand(&mut rng);
+        assert!(!g.into_affine().has_small_order());
+        assert!(g.into_affine().is_torsion_free());
+
+        let mixed = (g + torsion).into_affine();
+        assert!(!mixed.has_small_order());
+        assert_eq!(mixed.is_torsion_free(), torsion.is_zero());
+    }
+}
+
+#[test]
+fn compressed_batch_deserialization() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Validate};
+    use ark_std::vec::Vec;
+
+    let mut rng = ark_std::test_rng();
+
+    let points = (0..50)
+        .map(|_| Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let mut bytes = Vec::new();
+    for p in &points {
+        p.serialize_compressed(&mut bytes).unwrap();
+    }
+    let deserialized =
+        Affine::deserialize_compressed_batch(&bytes[..], points.len(), Validate::Yes).unwrap();
+    assert_eq!(deserialized, points);
+
+    let one_by_one = bytes
+        .chunks(bytes.len() / points.len())
+        .map(|chunk| Affine::deserialize_compressed(chunk).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(deserialized, one_by_one);
+}
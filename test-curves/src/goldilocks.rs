@@ -0,0 +1,52 @@
+//! Prime field `Fp` where `p = 2^64 - 2^32 + 1`, instantiated both with the
+//! specialized [`GoldilocksBackend`](ark_ff::GoldilocksBackend) and with the
+//! generic Montgomery backend.
+use ark_ff::fields::{Fp64, MontBackend};
+
+pub use ark_ff::fields::Goldilocks as Fq;
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct FqMontConfig;
+pub type FqMont = Fp64<MontBackend<FqMontConfig, 1>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{AdditiveGroup, Field, PrimeField};
+    use ark_std::{test_rng, UniformRand};
+
+    test_field!(fq; Fq; prime);
+    test_field!(fq_mont; FqMont; mont_prime_field);
+
+    #[test]
+    fn test_agrees_with_montgomery_backend() {
+        let mut rng = test_rng();
+        let to_mont = |a: Fq| FqMont::from_bigint(a.into_bigint()).unwrap();
+        let edge = [
+            Fq::from(0u64),
+            Fq::from(1u64),
+            -Fq::from(1u64),
+            Fq::from(u32::MAX),
+        ];
+        let elems = edge
+            .into_iter()
+            .chain((0..1000).map(|_| Fq::rand(&mut rng)))
+            .collect::<ark_std::vec::Vec<_>>();
+        for (a, b) in elems.iter().zip(elems.iter().rev()) {
+            assert_eq!(to_mont(*a + b), to_mont(*a) + to_mont(*b));
+            assert_eq!(to_mont(*a - b), to_mont(*a) - to_mont(*b));
+            assert_eq!(to_mont(*a * b), to_mont(*a) * to_mont(*b));
+            assert_eq!(to_mont(a.square()), to_mont(*a).square());
+            assert_eq!(to_mont(a.double()), to_mont(*a).double());
+            assert_eq!(to_mont(-*a), -to_mont(*a));
+            assert_eq!(a.inverse().map(to_mont), to_mont(*a).inverse());
+            assert_eq!(
+                a.sqrt().map(|s| to_mont(s.square())),
+                to_mont(*a).sqrt().map(|s| s.square())
+            );
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! A Jacobi quartic curve over the Goldilocks field, used to test the
+//! Jacobi quartic model, together with the birationally equivalent short
+//! Weierstrass curve.
+//!
+//! Curve information:
+//! * Base field: q = 18446744069414584321
+//! * Scalar field: r = 9223372035541187701
+//! * Curve equation: y^2 = dx^4 + 2ax^2 + 1, where
+//!    * a = 1
+//!    * d = 280, which is not a square in the base field
+//! * Curve order: 2 * r
+use ark_ec::{jacobi_quartic, models::CurveConfig, short_weierstrass};
+use ark_ff::{
+    fields::{Fp64, MontBackend},
+    MontFp,
+};
+
+pub use crate::goldilocks::FqMont as Fq;
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "9223372035541187701"]
+#[generator = "6"]
+pub struct FrConfig;
+pub type Fr = Fp64<MontBackend<FrConfig, 1>>;
+
+pub type Affine = jacobi_quartic::Affine<JacobiQuarticConfig>;
+pub type Projective = jacobi_quartic::Projective<JacobiQuarticConfig>;
+
+pub type SWAffine = short_weierstrass::Affine<JacobiQuarticConfig>;
+pub type SWProjective = short_weierstrass::Projective<JacobiQuarticConfig>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct JacobiQuarticConfig;
+
+impl CurveConfig for JacobiQuarticConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// COFACTOR = 2
+    const COFACTOR: &'static [u64] = &[2];
+
+    /// COFACTOR^(-1) mod r = 4611686017770593851
+    const COFACTOR_INV: Fr = MontFp!("4611686017770593851");
+}
+
+impl jacobi_quartic::JQCurveConfig for JacobiQuarticConfig {
+    const COEFF_A: Fq = MontFp!("1");
+
+    const COEFF_D: Fq = MontFp!("280");
+
+    const GENERATOR: Affine = Affine::new_unchecked(
+        MontFp!("6635518337609636639"),
+        MontFp!("2438548281225093629"),
+    );
+
+    type SWCurveConfig = JacobiQuarticConfig;
+
+    /// Multiplication by `a` is the identity here.
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        elem
+    }
+}
+
+impl short_weierstrass::SWCurveConfig for JacobiQuarticConfig {
+    /// COEFF_A = -4 * (a^2 + 3 * d) / 3
+    const COEFF_A: Fq = MontFp!("6148914689804860319");
+
+    /// COEFF_B = 16 * a * (a^2 - 9 * d) / 27
+    const COEFF_B: Fq = MontFp!("3416063716558254863");
+
+    const GENERATOR: SWAffine = SWAffine::new_unchecked(
+        MontFp!("9045264533841518704"),
+        MontFp!("5080647716940788409"),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+
+    test_field!(fr; Fr; mont_prime_field);
+    test_group!(g; Projective; jq);
+    test_group!(sw_g; SWProjective; sw);
+}
@@ -31,3 +31,17 @@ pub mod bn384_small_two_adicity;
 pub mod secp256k1;
 
 pub mod fp128;
+
+pub mod babybear;
+
+pub mod curve25519;
+
+pub mod cycle;
+
+pub mod goldilocks;
+
+pub mod jacobi_quartic;
+
+pub mod twisted_hessian;
+
+pub mod mersenne31;
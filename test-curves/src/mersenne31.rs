@@ -0,0 +1,35 @@
+//! Prime field `Fp` where `p = 2^31 - 1`, together with its degree-two and
+//! degree-four extensions.
+pub use ark_ff::fields::{Mersenne31 as Fq, Mersenne31Fp2 as Fq2, Mersenne31Fp4 as Fq4};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+    use ark_ff::{Field, Fp32Config, Mersenne31Config, PrimeField};
+    use ark_std::{test_rng, UniformRand};
+
+    test_field!(fq; Fq; prime);
+    test_field!(fq2; Fq2; fft);
+    test_field!(fq4; Fq4; fft);
+
+    #[test]
+    fn test_reduce() {
+        let mut rng = test_rng();
+        let p = Mersenne31Config::MODULUS as u64;
+        let edge = [0, 1, p - 1, p, p + 1, 2 * p, (p - 1) * (p - 1), u64::MAX];
+        let elems = edge
+            .into_iter()
+            .chain((0..1000).map(|_| u64::rand(&mut rng)));
+        for x in elems {
+            assert_eq!(Mersenne31Config::reduce(x) as u64, x % p);
+        }
+    }
+
+    #[test]
+    fn test_characteristic() {
+        assert_eq!(Fq::MODULUS.0, [(1 << 31) - 1]);
+        assert_eq!(Fq2::extension_degree(), 2);
+        assert_eq!(Fq4::extension_degree(), 4);
+    }
+}
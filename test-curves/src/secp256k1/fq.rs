@@ -1,7 +1,25 @@
-use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{
+    fields::{BarrettConfig, BarrettFp, Fp256, MontBackend, MontConfig},
+    BigInt,
+};
 
 #[derive(MontConfig)]
 #[modulus = "115792089237316195423570985008687907853269984665640564039457584007908834671663"]
 #[generator = "3"]
 pub struct FqConfig;
 pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+/// The same field as [`Fq`], with elements stored in canonical form and
+/// multiplied using Barrett reduction.
+pub struct FqBarrettConfig;
+pub type FqBarrett = BarrettFp<FqBarrettConfig, 4>;
+
+impl BarrettConfig<4> for FqBarrettConfig {
+    const MODULUS: BigInt<4> = FqConfig::MODULUS;
+
+    const GENERATOR: BigInt<4> = BigInt!("3");
+
+    /// `-1`, since the two-adicity of `p - 1` is one.
+    const TWO_ADIC_ROOT_OF_UNITY: BigInt<4> =
+        BigInt!("115792089237316195423570985008687907853269984665640564039457584007908834671662");
+}
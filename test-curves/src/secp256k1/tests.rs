@@ -1,6 +1,29 @@
-use crate::secp256k1::{Fq, Fr, G1Projective};
+use crate::secp256k1::{Fq, FqBarrett, Fr, G1Projective};
 use ark_algebra_test_templates::{test_field, test_group};
+use ark_ff::{AdditiveGroup, Field, PrimeField};
+use ark_std::{test_rng, UniformRand};
 
 test_field!(fq; Fq; mont_prime_field);
+test_field!(fq_barrett; FqBarrett; prime);
 test_field!(fr; Fr; mont_prime_field);
 test_group!(g1; G1Projective);
+
+#[test]
+fn test_barrett_agrees_with_montgomery_backend() {
+    let mut rng = test_rng();
+    let to_mont = |a: FqBarrett| Fq::from_bigint(a.into_bigint()).unwrap();
+    let edge = [FqBarrett::ZERO, FqBarrett::ONE, -FqBarrett::ONE];
+    let elems = edge
+        .into_iter()
+        .chain((0..1000).map(|_| FqBarrett::rand(&mut rng)))
+        .collect::<ark_std::vec::Vec<_>>();
+    for (a, b) in elems.iter().zip(elems.iter().rev()) {
+        assert_eq!(to_mont(*a + b), to_mont(*a) + to_mont(*b));
+        assert_eq!(to_mont(*a - b), to_mont(*a) - to_mont(*b));
+        assert_eq!(to_mont(*a * b), to_mont(*a) * to_mont(*b));
+        assert_eq!(to_mont(a.square()), to_mont(*a).square());
+        assert_eq!(to_mont(a.double()), to_mont(*a).double());
+        assert_eq!(to_mont(-*a), -to_mont(*a));
+        assert_eq!(a.inverse().map(to_mont), to_mont(*a).inverse());
+    }
+}
@@ -0,0 +1,68 @@
+//! A twisted Hessian curve over the Goldilocks field, used to test the
+//! twisted Hessian model.
+//!
+//! Curve information:
+//! * Base field: q = 18446744069414584321
+//! * Scalar field: r = 6148914691400156069
+//! * Curve equation: ax^3 + y^3 + 1 = dxy, where
+//!    * a = 2, which is not a cube in the base field
+//!    * d = 54
+//! * Curve order: 3 * r
+use ark_ec::{models::CurveConfig, twisted_hessian};
+use ark_ff::{
+    fields::{Fp64, MontBackend},
+    AdditiveGroup, MontFp,
+};
+
+pub use crate::goldilocks::FqMont as Fq;
+
+#[derive(ark_ff::MontConfig)]
+#[modulus = "6148914691400156069"]
+#[generator = "2"]
+pub struct FrConfig;
+pub type Fr = Fp64<MontBackend<FrConfig, 1>>;
+
+pub type Affine = twisted_hessian::Affine<HessianConfig>;
+pub type Projective = twisted_hessian::Projective<HessianConfig>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct HessianConfig;
+
+impl CurveConfig for HessianConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// COFACTOR = 3
+    const COFACTOR: &'static [u64] = &[3];
+
+    /// COFACTOR^(-1) mod r = 2049638230466718690
+    const COFACTOR_INV: Fr = MontFp!("2049638230466718690");
+}
+
+impl twisted_hessian::THCurveConfig for HessianConfig {
+    const COEFF_A: Fq = MontFp!("2");
+
+    const COEFF_D: Fq = MontFp!("54");
+
+    const GENERATOR: Affine = Affine::new_unchecked(
+        MontFp!("14342491888626595716"),
+        MontFp!("17826655967335005110"),
+    );
+
+    const IDENTITY: Affine = Affine::new_unchecked(Fq::ZERO, MontFp!("-1"));
+
+    /// Multiplication by `a` is simply doubling here.
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        elem.double()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_algebra_test_templates::*;
+
+    test_field!(fr; Fr; mont_prime_field);
+    test_group!(g; Projective; curve);
+}
@@ -307,6 +307,37 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_fixed_base_pow() {
+            use ark_ff::FixedBase;
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            let base = <$field>::rand(rng);
+            for window_size in [1, 3, 4] {
+                let table = FixedBase::new(base, 130, window_size);
+                assert_eq!(table.pow_with_table([0u64]), Some(<$field>::one()));
+                let exps: Vec<[u64; 3]> = (0..10).map(|_| [u64::rand(rng), u64::rand(rng), u64::rand(rng) >> 62]).collect();
+                let expected: Vec<_> = exps.iter().map(|exp| base.pow(exp)).collect();
+                assert_eq!(table.pow_batch(&exps), Some(expected));
+                assert_eq!(table.pow_with_table([0, 0, 4]), None);
+            }
+        }
+
+        #[test]
+        fn test_multi_pow() {
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            assert_eq!(<$field>::multi_pow::<[u64; 1]>(&[], &[]), <$field>::one());
+            for len in [1, 2, 5, 40] {
+                let bases: Vec<$field> = (0..len).map(|_| <$field>::rand(rng)).collect();
+                let exps: Vec<[u64; 2]> = (0..len).map(|_| [u64::rand(rng), u64::rand(rng)]).collect();
+                let expected: $field = bases.iter().zip(&exps).map(|(b, e)| b.pow(e)).product();
+                assert_eq!(<$field>::multi_pow(&bases, &exps), expected, "length: {len}");
+            }
+        }
+
         #[test]
         fn test_sum_of_products_tests() {
             use ark_std::{UniformRand, rand::Rng};
@@ -326,6 +357,43 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_mul_batch() {
+            use ark_ff::Field;
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            for len in [0, 1, 7, 8, 9, 17, 100] {
+                let a: Vec<$field> = (0..len).map(|_| <$field>::rand(rng)).collect();
+                let b: Vec<$field> = (0..len).map(|_| <$field>::rand(rng)).collect();
+                let expected: Vec<_> = a.iter().zip(&b).map(|(a, b)| *a * b).collect();
+                let mut result = a.clone();
+                <$field>::mul_batch(&mut result, &b);
+                assert_eq!(result, expected, "length: {len}");
+            }
+
+            let max = -<$field>::one();
+            let mut result = vec![max; 16];
+            <$field>::mul_batch(&mut result, &[max; 16]);
+            assert!(result.iter().all(|r| *r == max * max));
+        }
+
+        #[test]
+        fn test_dot_product() {
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            for len in [0, 1, 2, 3, 8, 33, 257] {
+                let a: Vec<$field> = (0..len).map(|_| <$field>::rand(rng)).collect();
+                let b: Vec<$field> = (0..len).map(|_| <$field>::rand(rng)).collect();
+                let expected: $field = a.iter().zip(&b).map(|(a, b)| *a * b).sum();
+                assert_eq!(<$field>::dot_product(&a, &b), expected, "length: {len}");
+            }
+
+            let max = vec![-<$field>::one(); 1000];
+            assert_eq!(<$field>::dot_product(&max, &max), <$field>::from(1000u64));
+        }
+
         #[test]
         fn test_sqrt() {
             if <$field>::SQRT_PRECOMP.is_some() {
@@ -344,6 +412,8 @@ macro_rules! __test_field {
                     if let Some(mut b) = a.sqrt() {
                         b.square_in_place();
                         assert_eq!(a, b);
+                    } else {
+                        assert!(a.legendre().is_qnr());
                     }
 
                     let a = <$field>::rand(rng);
@@ -353,6 +423,61 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_batch_sqrt() {
+            if <$field>::SQRT_PRECOMP.is_some() {
+                use ark_std::UniformRand;
+                let rng = &mut test_rng();
+
+                let mut elems: Vec<$field> =
+                    (0..ITERATIONS).map(|_| <$field>::rand(rng)).collect();
+                elems.extend((0..ITERATIONS).map(|_| <$field>::rand(rng).square()));
+                elems.push(<$field>::zero());
+                let expected: Vec<_> = elems.iter().map(|a| a.sqrt()).collect();
+                assert_eq!(<$field>::batch_sqrt(&elems), expected);
+                assert!(<$field>::batch_sqrt(&[]).is_empty());
+            }
+        }
+
+        #[test]
+        fn test_nth_root() {
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            assert!(<$field>::zero().nth_root(3).unwrap().is_zero());
+            assert_eq!(<$field>::one().nth_root(0), Some(<$field>::one()));
+
+            for _ in 0..10 {
+                let a = <$field>::rand(rng);
+                assert_eq!(a.nth_root(1), Some(a));
+                for n in [2u64, 3, 4, 5, 6, 12] {
+                    let b = a.pow([n]);
+                    let root = b.nth_root(n).unwrap();
+                    assert_eq!(root.pow([n]), b, "n = {n}");
+
+                    if let Some(root) = a.nth_root(n) {
+                        assert_eq!(root.pow([n]), a, "n = {n}");
+                    }
+                }
+                if let Some(root) = a.cube_root() {
+                    assert_eq!(root.pow([3]), a);
+                }
+            }
+        }
+
+        #[test]
+        fn test_from_str() {
+            use ark_std::{str::FromStr, string::ToString, UniformRand};
+            let rng = &mut test_rng();
+
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(rng);
+                assert_eq!(<$field>::from_str(&a.to_string()), Ok(a));
+            }
+            assert!(<$field>::from_str("").is_err());
+            assert!(<$field>::from_str("QuadExtField(1)").is_err());
+            assert!(<$field>::from_str("CubicExtField(1, 2)").is_err());
+        }
 
         #[test]
         fn test_mul_by_base_field_elem() {
@@ -445,6 +570,42 @@ macro_rules! __test_field {
             $crate::fields::prime_field_sum_of_products_test_helper::<_, 10>(a_max, b_max);
         }
 
+        #[test]
+        fn test_batch_legendre() {
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            assert!(<$field>::batch_legendre(&[]).is_empty());
+            for len in [1, 7, 100] {
+                let mut elems: Vec<$field> = (0..len).map(|_| <$field>::rand(rng)).collect();
+                elems[0] = <$field>::zero();
+                let expected: Vec<_> = elems.iter().map(|e| e.legendre()).collect();
+                assert_eq!(<$field>::batch_legendre(&elems), expected, "length: {len}");
+            }
+        }
+
+        #[test]
+        fn test_from_str_range() {
+            use ark_std::{format, str::FromStr};
+            use $crate::num_bigint::BigUint;
+
+            let modulus = BigUint::from(<$field>::MODULUS);
+            let minus_one = <$field>::from(modulus.clone() - 1u8);
+            assert_eq!(<$field>::from_str("0"), Ok(<$field>::zero()));
+            assert_eq!(<$field>::from_str("-1"), Ok(-<$field>::one()));
+            assert_eq!(<$field>::from_str("0x1f"), Ok(<$field>::from(31u8)));
+            assert_eq!(<$field>::from_str(&format!("{}", modulus.clone() - 1u8)), Ok(minus_one));
+            assert_eq!(<$field>::from_str(&format!("{:#x}", modulus.clone() - 1u8)), Ok(minus_one));
+            assert!(<$field>::from_str(&format!("{modulus}")).is_err());
+            assert!(<$field>::from_str(&format!("-{modulus}")).is_err());
+            assert!(<$field>::from_str(&format!("{modulus:#x}")).is_err());
+            assert!(<$field>::from_str(&format!("{}", modulus.clone() + 1u8)).is_err());
+            assert!(<$field>::from_str(&format!("{:#x}", modulus.clone() * 2u8 + 31u8)).is_err());
+            assert!(<$field>::from_str("1.5").is_err());
+            assert!(<$field>::from_str("0x").is_err());
+            assert!(<$field>::from_str("-").is_err());
+        }
+
         #[test]
         fn test_constants() {
             use ark_ff::{FpConfig, BigInteger, SqrtPrecomputation};
@@ -463,6 +624,12 @@ macro_rules! __test_field {
                 assert_eq!(&modulus_plus_one_div_four[..len], &check);
                 assert!(modulus_plus_one_div_four[len..].iter().all(|l| *l == 0));
             }
+            if let Some(SqrtPrecomputation::Cipolla { modulus_plus_one_div_two }) = <$field>::SQRT_PRECOMP {
+                let check = ((&modulus + 1u8) / 2u8).to_u64_digits();
+                let len = check.len();
+                assert_eq!(&modulus_plus_one_div_two[..len], &check);
+                assert!(modulus_plus_one_div_two[len..].iter().all(|l| *l == 0));
+            }
 
             let mut two_adicity = 0;
             let mut trace = modulus_minus_one;
@@ -478,6 +645,46 @@ macro_rules! __test_field {
             let two_adic_root_of_unity: BigUint = <$field>::TWO_ADIC_ROOT_OF_UNITY.into();
             let generator: BigUint = <$field>::GENERATOR.into_bigint().into();
             assert_eq!(two_adic_root_of_unity, generator.modpow(&trace, &modulus));
+            if let Some(SqrtPrecomputation::TonelliShanksWithTables {
+                two_adicity,
+                window_size,
+                trace_of_modulus_minus_one_div_two,
+                tables,
+                discrete_log_table,
+            }) = <$field>::SQRT_PRECOMP {
+                assert_eq!(two_adicity, <$field>::TWO_ADICITY);
+                assert_eq!(trace_of_modulus_minus_one_div_two, &trace_minus_one_div_two.to_u64_digits()[..]);
+                assert_eq!(tables.len() as u32, two_adicity.div_ceil(window_size));
+                let g_inv = two_adic_root_of_unity.modpow(&(&modulus - 2u8), &modulus);
+                for (i, table) in tables.iter().enumerate() {
+                    assert_eq!(table.len(), 1 << window_size);
+                    let base = g_inv.modpow(&(BigUint::from(1u8) << (i as u32 * window_size)), &modulus);
+                    for (d, entry) in table.iter().enumerate() {
+                        let entry: BigUint = entry.into_bigint().into();
+                        assert_eq!(entry, base.modpow(&d.into(), &modulus));
+                    }
+                }
+                assert_eq!(discrete_log_table.len(), 1 << window_size);
+                assert!(discrete_log_table.windows(2).all(|p| p[0].0 < p[1].0));
+                let base = two_adic_root_of_unity.modpow(&(BigUint::from(1u8) << (two_adicity - window_size)), &modulus);
+                for (key, d) in discrete_log_table {
+                    let power = base.modpow(&(*d).into(), &modulus);
+                    assert_eq!(power.iter_u64_digits().next().unwrap_or(0), *key);
+                }
+
+                // The tables must find the same square roots as Tonelli-Shanks.
+                let tonelli_shanks = SqrtPrecomputation::TonelliShanks {
+                    two_adicity,
+                    quadratic_nonresidue_to_trace: <$field>::TWO_ADIC_ROOT_OF_UNITY,
+                    trace_of_modulus_minus_one_div_two,
+                };
+                let mut rng = test_rng();
+                for _ in 0..ITERATIONS {
+                    let a = <$field>::rand(&mut rng);
+                    assert_eq!(a.sqrt(), tonelli_shanks.sqrt(&a));
+                    assert_eq!(a.square().sqrt(), tonelli_shanks.sqrt(&a.square()));
+                }
+            }
             match (<$field>::SMALL_SUBGROUP_BASE, <$field>::SMALL_SUBGROUP_BASE_ADICITY) {
                 (Some(base), Some(adicity)) => {
                     let mut e = generator;
@@ -4,7 +4,7 @@ use ark_ec::{
     AffineRepr, CurveGroup, PrimeGroup,
 };
 use ark_ff::{BigInteger, PrimeField};
-use ark_std::{ops::Mul, UniformRand};
+use ark_std::{ops::Mul, vec::*, UniformRand, Zero};
 
 pub fn glv_scalar_decomposition<P: GLVConfig>() {
     let mut rng = ark_std::test_rng();
@@ -75,3 +75,25 @@ pub fn glv_affine<P: GLVConfig>() {
         assert_eq!(k_g, k_g_2);
     }
 }
+
+pub fn glv_msm<P: GLVConfig>() {
+    // check that glv_msm indeed computes the multi-scalar multiplication
+    let mut rng = ark_std::test_rng();
+
+    let bases = (0..100)
+        .map(|_| Affine::<P>::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let scalars = (0..100)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let naive = bases
+        .iter()
+        .zip(&scalars)
+        .map(|(b, s)| sw_double_and_add_affine(b, &s.into_bigint()))
+        .sum::<Projective<P>>();
+    assert_eq!(<P as GLVConfig>::glv_msm(&bases, &scalars), naive);
+    assert_eq!(
+        <P as GLVConfig>::glv_msm(&bases[..0], &scalars),
+        Projective::<P>::zero()
+    );
+}
@@ -113,8 +113,32 @@ macro_rules! __test_group {
                         let bad_table = bad_context.table(a);
                         assert_eq!(context.mul_with_table(&bad_table, &b), None);
                     }
+
+                    let affine_table = context.affine_table(a);
+                    assert_eq!(
+                        context.mul_with_affine_table::<$group>(&affine_table, &b),
+                        Some(a * b)
+                    );
+                    let table = context.precompute(a);
+                    assert_eq!(table.context(), context);
+                    assert_eq!(table.mul(&b), a * b);
+                    assert_eq!(table.batch_mul(&[b, c]), vec![a * b, a * c]);
+
+                    let d = <$group>::rand(&mut rng);
+                    assert_eq!(context.multi_mul(&[a, d], &[b, c]), a * b + d * c);
                 }
 
+                // a * b + d * c with interleaved wNAFs
+                let d = <$group>::rand(&mut rng);
+                assert_eq!(<$group>::multi_mul2(&b, a, &c, d), a * b + d * c);
+                assert_eq!(<$group>::multi_mul2(&zero, a, &c, d), d * c);
+                assert_eq!(
+                    <$group>::multi_mul(&[a, d, a], &[b, c, -c]),
+                    a * b + d * c - a * c
+                );
+                assert_eq!(<$group>::multi_mul(&[a, d], &[b]), a * b);
+                assert_eq!(<$group>::multi_mul(&[], &[b]), <$group>::zero());
+
                 // num_scalars != scalars.len()
                 let mut scalars = vec![ScalarField::rand(&mut rng); 100];
                 scalars[0] = ScalarField::zero();
@@ -192,11 +216,31 @@ macro_rules! __test_group {
             $crate::msm::test_var_base_msm::<$group>();
         }
 
+        #[test]
+        fn test_msm_with_config() {
+            $crate::msm::test_msm_with_config::<$group>();
+        }
+
+        #[test]
+        fn test_fixed_base_msm() {
+            $crate::msm::test_fixed_base_msm::<$group>();
+        }
+
         #[test]
         fn test_chunked_pippenger() {
             $crate::msm::test_chunked_pippenger::<$group>();
         }
 
+        #[test]
+        fn test_streaming_pippenger() {
+            $crate::msm::test_streaming_pippenger::<$group>();
+        }
+
+        #[test]
+        fn test_msm_accumulator() {
+            $crate::msm::test_msm_accumulator::<$group>();
+        }
+
         #[test]
         fn test_hashmap_pippenger() {
             $crate::msm::test_hashmap_pippenger::<$group>();
@@ -301,6 +345,14 @@ macro_rules! __test_group {
                     if !g1.is_zero() {
                         let g1 = Affine::from(g1);
                         assert!(g1.is_in_correct_subgroup_assuming_on_curve());
+                        // `p` is outside of the prime-order subgroup, where
+                        // `clear_cofactor` must multiply by the effective cofactor.
+                        let h_eff = <Config as SWCurveConfig>::EFFECTIVE_COFACTOR;
+                        assert_eq!(p.clear_cofactor().into_group(), <Config as SWCurveConfig>::mul_affine(&p, h_eff));
+                        // `clear_cofactor_projective` must agree with `clear_cofactor`.
+                        let mut q = p.into_group();
+                        q.clear_cofactor_in_place();
+                        assert_eq!(q, p.clear_cofactor());
                         break;
                     }
                 }
@@ -330,6 +382,30 @@ macro_rules! __test_group {
                 }
             }
         }
+
+        #[test]
+        fn test_sw_compressed_batch_deserialization() {
+            let mut rng = ark_std::test_rng();
+            let mut points = (0..ITERATIONS / 10)
+                .map(|_| Affine::rand(&mut rng))
+                .collect::<Vec<_>>();
+            points.push(Affine::zero());
+
+            let mut bytes = Vec::new();
+            for p in &points {
+                p.serialize_compressed(&mut bytes).unwrap();
+            }
+            for validate in [Validate::Yes, Validate::No] {
+                let mut reader = &bytes[..];
+                let expected = points
+                    .iter()
+                    .map(|_| Affine::deserialize_with_mode(&mut reader, Compress::Yes, validate).unwrap())
+                    .collect::<Vec<_>>();
+                let batch = Affine::deserialize_compressed_batch(&bytes[..], points.len(), validate).unwrap();
+                assert_eq!(batch, expected);
+                assert_eq!(batch, points);
+            }
+        }
     };
     ($group:ty; te) => {
         $crate::__test_group!($group; curve);
@@ -382,6 +458,106 @@ macro_rules! __test_group {
             assert_eq!(a, <Config as MontCurveConfig>::COEFF_A);
             assert_eq!(b, <Config as MontCurveConfig>::COEFF_B);
         }
+
+        #[test]
+        fn test_te_compressed_batch_deserialization() {
+            let mut rng = ark_std::test_rng();
+            let mut points = (0..ITERATIONS / 10)
+                .map(|_| Affine::rand(&mut rng))
+                .collect::<Vec<_>>();
+            points.push(Affine::zero());
+
+            let mut bytes = Vec::new();
+            for p in &points {
+                p.serialize_compressed(&mut bytes).unwrap();
+            }
+            for validate in [Validate::Yes, Validate::No] {
+                let mut reader = &bytes[..];
+                let expected = points
+                    .iter()
+                    .map(|_| Affine::deserialize_with_mode(&mut reader, Compress::Yes, validate).unwrap())
+                    .collect::<Vec<_>>();
+                let batch = Affine::deserialize_compressed_batch(&bytes[..], points.len(), validate).unwrap();
+                assert_eq!(batch, expected);
+                assert_eq!(batch, points);
+            }
+        }
+    };
+    ($group:ty; jq) => {
+        $crate::__test_group!($group; curve);
+
+        #[test]
+        fn test_jq_properties() {
+            use ark_ec::jacobi_quartic::JQCurveConfig;
+            let rng = &mut ark_std::test_rng();
+
+            let generator = <$group>::generator().into_affine();
+            assert!(generator.is_on_curve());
+            assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+            for _ in 0..ITERATIONS {
+                let f = BaseField::rand(rng);
+                assert_eq!(
+                    <Config as JQCurveConfig>::mul_by_a(f),
+                    f * <Config as JQCurveConfig>::COEFF_A
+                );
+            }
+            // The point (0, -1) has order two.
+            let t = Affine::new_unchecked(BaseField::zero(), -BaseField::one());
+            assert!(t.is_on_curve());
+            assert!(!t.is_zero());
+            assert!(t.into_group().double().is_zero());
+        }
+
+        #[test]
+        fn test_weierstrass_conversion() {
+            use ark_ec::{jacobi_quartic::JQCurveConfig, short_weierstrass};
+            type SWConfig = <Config as JQCurveConfig>::SWCurveConfig;
+            let rng = &mut ark_std::test_rng();
+
+            let a = <Config as JQCurveConfig>::COEFF_A;
+            let d = <Config as JQCurveConfig>::COEFF_D;
+            let three = BaseField::from(3u8);
+            // A = -4 * (a^2 + 3 * d) / 3
+            let sw_a = -(a.square() + three * d).double().double() * three.inverse().unwrap();
+            // B = 16 * a * (a^2 - 9 * d) / 27
+            let sw_b = BaseField::from(16u8)
+                * a
+                * (a.square() - three.square() * d)
+                * (three.square() * three).inverse().unwrap();
+            assert_eq!(sw_a, <SWConfig as SWCurveConfig>::COEFF_A);
+            assert_eq!(sw_b, <SWConfig as SWCurveConfig>::COEFF_B);
+
+            let zero = Affine::zero();
+            assert!(zero.to_weierstrass().is_zero());
+            assert_eq!(
+                Affine::from_weierstrass(short_weierstrass::Affine::identity()),
+                zero
+            );
+
+            let t = Affine::new_unchecked(BaseField::zero(), -BaseField::one());
+            let sw_t = t.to_weierstrass();
+            assert!(sw_t.is_on_curve());
+            assert!(sw_t.y.is_zero());
+            assert_eq!(Affine::from_weierstrass(sw_t), t);
+
+            for _ in 0..ITERATIONS {
+                let p = Affine::rand(rng);
+                let q = Affine::rand(rng);
+                let sw_p = p.to_weierstrass();
+                let sw_q = q.to_weierstrass();
+                assert!(sw_p.is_on_curve());
+                assert!(sw_p.is_in_correct_subgroup_assuming_on_curve());
+                assert_eq!(Affine::from_weierstrass(sw_p), p);
+                assert_eq!(
+                    (p + q).into_affine().to_weierstrass(),
+                    (sw_p + sw_q).into_affine()
+                );
+                assert_eq!(
+                    (p + t).into_affine().to_weierstrass(),
+                    (sw_p + sw_t).into_affine()
+                );
+            }
+        }
     };
     ($group:ty; glv) => {
         type Config = <$group as CurveGroup>::Config;
@@ -403,6 +579,11 @@ macro_rules! __test_group {
             $crate::glv::glv_projective::<Config>();
             $crate::glv::glv_affine::<Config>();
         }
+
+        #[test]
+        fn test_glv_msm() {
+            $crate::glv::glv_msm::<Config>();
+        }
     }
 }
 
@@ -1,8 +1,15 @@
 use ark_ec::{
-    scalar_mul::variable_base::{ChunkedPippenger, HashMapPippenger, VariableBaseMSM},
+    scalar_mul::{
+        fixed_base::FixedBaseMSM,
+        variable_base::{
+            ChunkedPippenger, HashMapPippenger, MsmAccumulator, MsmConfig, StreamingPippenger,
+            VariableBaseMSM,
+        },
+    },
     ScalarMul,
 };
 use ark_ff::{PrimeField, UniformRand};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use ark_std::vec::*;
 
 fn naive_var_base_msm<G: ScalarMul>(bases: &[G::MulBase], scalars: &[G::ScalarField]) -> G {
@@ -29,6 +36,125 @@ pub fn test_var_base_msm<G: VariableBaseMSM>() {
     let fast = G::msm(g.as_slice(), v.as_slice()).unwrap();
 
     assert_eq!(naive, fast);
+
+    // Scalars close to the modulus have their most significant bits set,
+    // which exercises the carries out of the top window, for several window
+    // sizes.
+    for size in [1, 31, 32, 100] {
+        let v = (0..size)
+            .map(|i| -G::ScalarField::from(i as u64))
+            .collect::<Vec<_>>();
+        let naive = naive_var_base_msm::<G>(&g[..size], v.as_slice());
+        assert_eq!(naive, G::msm(&g[..size], v.as_slice()).unwrap());
+    }
+
+    // Mix zero, unit, one-limb, two-limb and full-size scalars.
+    let v = (0..SAMPLES)
+        .map(|i| match i % 5 {
+            0 => G::ScalarField::from(0u64),
+            1 => G::ScalarField::from(1u64),
+            2 => G::ScalarField::from(u64::MAX - i as u64),
+            3 => G::ScalarField::from(u128::MAX - i as u128),
+            _ => G::ScalarField::rand(&mut rng),
+        })
+        .collect::<Vec<_>>();
+    let naive = naive_var_base_msm::<G>(g.as_slice(), v.as_slice());
+    assert_eq!(naive, G::msm(g.as_slice(), v.as_slice()).unwrap());
+
+    // Repeat a base and its negation, with few distinct scalars, so that
+    // bucket additions involve equal and opposite points.
+    let g = (0..SAMPLES)
+        .map(|i| if i % 3 == 0 { -g[0] } else { g[0] })
+        .collect::<Vec<_>>();
+    let v = (0..SAMPLES)
+        .map(|i| -G::ScalarField::from((i % 4) as u64 + 2))
+        .collect::<Vec<_>>();
+    let naive = naive_var_base_msm::<G>(g.as_slice(), v.as_slice());
+    assert_eq!(naive, G::msm(g.as_slice(), v.as_slice()).unwrap());
+}
+
+pub fn test_msm_with_config<G: VariableBaseMSM>() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::batch_convert_to_mul_base(&g);
+
+    let naive = naive_var_base_msm::<G>(g.as_slice(), v.as_slice());
+    let configs = [
+        MsmConfig::default(),
+        MsmConfig {
+            window: Some(2),
+            ..Default::default()
+        },
+        MsmConfig {
+            window: Some(9),
+            threads: Some(2),
+            ..Default::default()
+        },
+        // Small enough to split the MSM into chunks.
+        MsmConfig {
+            max_mem: Some(1 << 16),
+            ..Default::default()
+        },
+    ];
+    for config in configs {
+        assert_eq!(Ok(naive), G::msm_with_config(&g, &v, &config));
+    }
+    assert_eq!(
+        Err(SAMPLES - 1),
+        G::msm_with_config(&g, &v[1..], &MsmConfig::default())
+    );
+}
+
+pub fn test_fixed_base_msm<G: VariableBaseMSM>()
+where
+    G::MulBase: CanonicalSerialize + CanonicalDeserialize,
+{
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::batch_convert_to_mul_base(&g);
+
+    let naive = naive_var_base_msm::<G>(g.as_slice(), v.as_slice());
+    let table = FixedBaseMSM::<G>::new(&g);
+    assert_eq!(table.num_bases(), SAMPLES);
+    assert_eq!(naive, table.msm_fixed(&v));
+
+    // Use fewer scalars than bases, including zero and scalars close to the
+    // modulus, for several window sizes.
+    for window in [2, 3, 7] {
+        let table = FixedBaseMSM::<G>::with_window_size(&g[..100], window);
+        for size in [0, 1, 31, 100] {
+            let v = (0..size)
+                .map(|i| -G::ScalarField::from(i as u64))
+                .collect::<Vec<_>>();
+            let naive = naive_var_base_msm::<G>(&g[..size], v.as_slice());
+            assert_eq!(naive, table.msm_fixed(&v));
+        }
+    }
+
+    for compress in [Compress::Yes, Compress::No] {
+        let mut serialized = Vec::new();
+        table
+            .serialize_with_mode(&mut serialized, compress)
+            .unwrap();
+        assert_eq!(serialized.len(), table.serialized_size(compress));
+        let deserialized =
+            FixedBaseMSM::<G>::deserialize_with_mode(&serialized[..], compress, Validate::Yes)
+                .unwrap();
+        assert_eq!(deserialized.window_size(), table.window_size());
+        assert_eq!(naive, deserialized.msm_fixed(&v));
+    }
 }
 
 pub fn test_chunked_pippenger<G: VariableBaseMSM>() {
@@ -52,6 +178,65 @@ pub fn test_chunked_pippenger<G: VariableBaseMSM>() {
     assert_eq!(arkworks, mine);
 }
 
+pub fn test_streaming_pippenger<G: VariableBaseMSM>() {
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::batch_convert_to_mul_base(&g);
+
+    let arkworks = G::msm(g.as_slice(), v.as_slice()).unwrap();
+
+    // Flush the buffer several times, the last time with a partial buffer.
+    let mut p = StreamingPippenger::<G>::new(300);
+    for (s, g) in v.iter().zip(&g) {
+        p.add(g, s.into_bigint());
+    }
+    assert_eq!(arkworks, p.finalize());
+
+    assert_eq!(arkworks, G::msm_stream(g.iter().zip(&v)));
+    assert_eq!(G::zero(), G::msm_stream(g.iter().zip(&v).take(0)));
+}
+
+pub fn test_msm_accumulator<G: VariableBaseMSM>() {
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::batch_convert_to_mul_base(&g);
+
+    let arkworks = G::msm(g.as_slice(), v.as_slice()).unwrap();
+
+    // Accumulate rounds of different sizes into two accumulators, and merge
+    // them.
+    let mut acc = MsmAccumulator::<G>::new();
+    let mut other = MsmAccumulator::<G>::new();
+    let mut start = 0;
+    for (round, size) in [1, 10, 100, 413, 500].into_iter().enumerate() {
+        let range = start..start + size;
+        if round % 2 == 0 {
+            acc.extend(&g[range.clone()], &v[range]);
+        } else {
+            for (b, s) in g[range.clone()].iter().zip(&v[range]) {
+                other.push(b, s);
+            }
+        }
+        start += size;
+    }
+    assert_eq!(start, SAMPLES);
+    acc.merge(other);
+    assert_eq!(arkworks, acc.finalize());
+    assert_eq!(G::zero(), MsmAccumulator::<G>::default().finalize());
+}
+
 pub fn test_hashmap_pippenger<G: VariableBaseMSM>() {
     const SAMPLES: usize = 1 << 10;
 
@@ -50,6 +50,180 @@ macro_rules! test_pairing {
                 }
             }
 
+            #[test]
+            fn test_multi_miller_loop_prepared() {
+                use ark_ec::AffineRepr;
+                use ark_std::vec::Vec;
+
+                let rng = &mut test_rng();
+                // Cover empty, partial and several chunks of pairs, and a zero point.
+                for n in [0, 1, 4, 5, 9] {
+                    let mut a = (0..n)
+                        .map(|_| <$Pairing as Pairing>::G1::rand(rng).into_affine())
+                        .collect::<Vec<_>>();
+                    let b = (0..n)
+                        .map(|_| <$Pairing as Pairing>::G2::rand(rng).into_affine())
+                        .collect::<Vec<_>>();
+                    if n > 2 {
+                        a[2] = <$Pairing as Pairing>::G1Affine::zero();
+                    }
+                    let pairs = a.iter().zip(&b).map(|(p, q)| (p.into(), q.into()));
+                    let ans1 = <$Pairing>::final_exponentiation(
+                        <$Pairing>::multi_miller_loop_prepared(pairs),
+                    );
+                    let ans2 = <$Pairing>::multi_pairing(&a, &b);
+                    assert_eq!(ans1, Some(ans2));
+                }
+            }
+
+            #[test]
+            fn test_pairing_checker() {
+                let rng = &mut test_rng();
+                let mut checker = PairingChecker::<$Pairing>::new();
+                assert!(checker.clone().finalize(rng));
+                for _ in 0..5 {
+                    let a = <$Pairing as Pairing>::G1::rand(rng);
+                    let b = <$Pairing as Pairing>::G2::rand(rng);
+                    let s = <$Pairing as Pairing>::ScalarField::rand(rng);
+                    checker.add_equation(a * s, b, a, b * s);
+                }
+                let a = <$Pairing as Pairing>::G1::rand(rng);
+                let b = <$Pairing as Pairing>::G2::rand(rng);
+                checker.add_product([(a, b), (-a, b)]);
+                assert_eq!(checker.len(), 6);
+                assert!(checker.clone().finalize(rng));
+
+                let s = <$Pairing as Pairing>::ScalarField::rand(rng);
+                checker.add_equation(a * s, b, a, b);
+                assert!(!checker.finalize(rng));
+            }
+
+            #[test]
+            fn test_g2_prepared_serialization() {
+                use ark_serialize::*;
+                use ark_std::vec::Vec;
+
+                let rng = &mut test_rng();
+                let a = <$Pairing as Pairing>::G1::rand(rng);
+                let b = <$Pairing as Pairing>::G2::rand(rng);
+                let expected = <$Pairing>::pairing(a, b);
+                for compress in [Compress::Yes, Compress::No] {
+                    let prepared = <$Pairing as Pairing>::G2Prepared::from(&b);
+                    let mut bytes = Vec::new();
+                    prepared.serialize_with_mode(&mut bytes, compress).unwrap();
+                    assert_eq!(bytes.len(), prepared.serialized_size(compress));
+                    let prepared = <$Pairing as Pairing>::G2Prepared::deserialize_with_mode(
+                        &bytes[..],
+                        compress,
+                        Validate::Yes,
+                    )
+                    .unwrap();
+                    assert_eq!(<$Pairing>::pairing(a, prepared), expected);
+
+                    // Line coefficients that do not belong to the point are rejected.
+                    let mid = bytes.len() / 2;
+                    bytes[mid] ^= 1;
+                    assert!(<$Pairing as Pairing>::G2Prepared::deserialize_with_mode(
+                        &bytes[..],
+                        compress,
+                        Validate::Yes,
+                    )
+                    .is_err());
+
+                    let prepared =
+                        <$Pairing as Pairing>::G2Prepared::from(<$Pairing as Pairing>::G2::zero());
+                    let mut bytes = Vec::new();
+                    prepared.serialize_with_mode(&mut bytes, compress).unwrap();
+                    assert!(<$Pairing as Pairing>::G2Prepared::deserialize_with_mode(
+                        &bytes[..],
+                        compress,
+                        Validate::Yes,
+                    )
+                    .is_ok());
+                }
+            }
+
+            #[test]
+            fn test_pairing_output_validation() {
+                use ark_serialize::*;
+                use ark_std::vec::Vec;
+
+                let rng = &mut test_rng();
+                for _ in 0..ITERATIONS {
+                    let a = <$Pairing as Pairing>::G1::rand(rng);
+                    let b = <$Pairing as Pairing>::G2::rand(rng);
+                    assert!(<$Pairing>::pairing(a, b).is_valid());
+
+                    let f = <$Pairing as Pairing>::TargetField::rand(rng);
+                    assert!(!PairingOutput::<$Pairing>(f).is_valid());
+                    let mut bytes = Vec::new();
+                    f.serialize_compressed(&mut bytes).unwrap();
+                    assert!(PairingOutput::<$Pairing>::deserialize_compressed(&bytes[..]).is_err());
+                    let unchecked =
+                        PairingOutput::<$Pairing>::deserialize_compressed_unchecked(&bytes[..])
+                            .unwrap();
+                    assert_eq!(unchecked.0, f);
+                }
+                assert!(PairingOutput::<$Pairing>::zero().is_valid());
+            }
+
+            #[test]
+            fn test_target_mul_bigint() {
+                use ark_std::rand::Rng;
+
+                let rng = &mut test_rng();
+                let order = <<$Pairing as Pairing>::ScalarField>::characteristic();
+                for _ in 0..ITERATIONS {
+                    let f = PairingOutput::<$Pairing>::rand(rng);
+                    let s = <$Pairing as Pairing>::ScalarField::rand(rng);
+                    let e = s.into_bigint();
+                    assert_eq!(f.mul_bigint(e).0, f.0.pow(e));
+                    assert_eq!(f * s, f.mul_bigint(e));
+
+                    // Exponents larger than the group order.
+                    let wide = [(); 8].map(|_| rng.gen::<u64>());
+                    assert_eq!(f.mul_bigint(wide).0, f.0.pow(wide));
+                    assert!(f.mul_bigint(order).is_zero());
+                }
+            }
+
+            #[test]
+            fn test_miller_loop_output_arithmetic() {
+                use ark_std::vec::Vec;
+
+                let rng = &mut test_rng();
+                let a = (0..3)
+                    .map(|_| <$Pairing as Pairing>::G1::rand(rng))
+                    .collect::<Vec<_>>();
+                let b = (0..3)
+                    .map(|_| <$Pairing as Pairing>::G2::rand(rng))
+                    .collect::<Vec<_>>();
+                let s = <$Pairing as Pairing>::ScalarField::rand(rng);
+                let expected = <$Pairing>::multi_pairing(&a, &b);
+
+                let loops = a
+                    .iter()
+                    .zip(&b)
+                    .map(|(a, b)| <$Pairing>::miller_loop(a, b))
+                    .collect::<Vec<_>>();
+                let product = loops.iter().product::<MillerLoopOutput<$Pairing>>();
+                assert_eq!(product.final_exponentiation().unwrap(), expected);
+
+                let mut acc = MillerLoopOutput::<$Pairing>::default();
+                for f in loops.iter() {
+                    acc *= f;
+                }
+                let acc = &acc * &(loops[0] * s);
+                assert_eq!(
+                    acc.final_exponentiation().unwrap(),
+                    expected + <$Pairing>::pairing(a[0], b[0]) * s
+                );
+                assert!(MillerLoopOutput::<$Pairing>::default()
+                    .final_exponentiation()
+                    .unwrap()
+                    .is_zero());
+            }
+
             #[test]
             fn test_final_exp() {
                 for _ in 0..ITERATIONS {